@@ -0,0 +1,537 @@
+use std::fmt::{self, Display, Formatter};
+use std::process::Stdio;
+use std::sync::{Arc, LazyLock};
+
+use jiff::{SignedDuration, Timestamp};
+use reqsign::google::{
+    Credential as GoogleCredential, DefaultCredentialProvider as GoogleCredentialProvider,
+    RequestSigner as GoogleRequestSigner,
+};
+use reqsign::{Signer, default_context};
+use reqwest_middleware::ClientWithMiddleware;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::{debug, trace};
+use url::Url;
+
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user_once;
+
+use crate::credentials::{Authentication, Credentials, sign_request};
+use crate::{Index, KeyringProvider};
+
+/// Refresh credentials this long before they expire.
+const EXPIRY_TOLERANCE: SignedDuration = SignedDuration::from_secs(60);
+
+/// How long to reuse credentials for which the provider doesn't report an expiry.
+const DEFAULT_LIFETIME: SignedDuration = SignedDuration::from_mins(5);
+
+/// The OAuth scope required to access Google Artifact Registry.
+const GOOGLE_CLOUD_PLATFORM_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+/// The Microsoft Entra resource ID for Azure DevOps.
+const AZURE_DEVOPS_RESOURCE: &str = "499b84ac-1321-427f-aa17-267ca6975798";
+
+/// The signer for Google Artifact Registry requests, which caches and refreshes the underlying
+/// access token.
+static GOOGLE_SIGNER: LazyLock<Signer<GoogleCredential>> = LazyLock::new(|| {
+    Signer::new(
+        default_context(),
+        GoogleCredentialProvider::new(),
+        GoogleRequestSigner::new("artifactregistry.googleapis.com")
+            .with_scope(GOOGLE_CLOUD_PLATFORM_SCOPE),
+    )
+});
+
+/// A source of (typically short-lived) credentials for an index.
+///
+/// Unlike credentials embedded in an index URL or stored in a netrc file, credentials from a
+/// provider are requested on demand and refreshed automatically when they expire.
+#[derive(
+    Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CredentialProvider {
+    /// Read credentials from the system keyring (e.g., the macOS Keychain, the Windows Credential
+    /// Manager, or the Secret Service on Linux), as stored by `uv auth login`.
+    Keyring,
+    /// Request an authorization token for AWS CodeArtifact, using the default AWS credential chain
+    /// (e.g., environment variables, the shared credentials file, or an instance profile).
+    AwsCodeartifact,
+    /// Request an access token for Google Artifact Registry, using Application Default
+    /// Credentials.
+    GcpArtifactRegistry,
+    /// Request an access token for Azure Artifacts, using the Azure CLI (`az`).
+    AzureArtifacts,
+}
+
+impl Display for CredentialProvider {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Keyring => write!(f, "keyring"),
+            Self::AwsCodeartifact => write!(f, "aws-codeartifact"),
+            Self::GcpArtifactRegistry => write!(f, "gcp-artifact-registry"),
+            Self::AzureArtifacts => write!(f, "azure-artifacts"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CredentialProviderError {
+    #[error(
+        "Expected an AWS CodeArtifact URL (e.g., `https://<domain>-<owner>.d.codeartifact.<region>.amazonaws.com/pypi/<repository>/simple/`), but received: {0}"
+    )]
+    NotCodeArtifact(DisplaySafeUrl),
+    #[error("No credentials found in the system keyring for: {0}")]
+    MissingKeyringEntry(DisplaySafeUrl),
+    #[error("No HTTP client is available to request a token")]
+    MissingClient,
+    #[error("Failed to sign request")]
+    Sign(#[from] reqsign::Error),
+    #[error(transparent)]
+    Middleware(#[from] reqwest_middleware::Error),
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error("Failed to run `{0}`")]
+    Command(String, #[source] std::io::Error),
+    #[error("`{0}` failed: {1}")]
+    CommandStatus(String, String),
+    #[error("Received an unexpected response from `{0}`")]
+    Json(String, #[source] serde_json::Error),
+}
+
+/// Credentials from a [`CredentialProvider`], along with their expiry.
+#[derive(Debug, Clone)]
+struct ProvidedCredentials {
+    authentication: Arc<Authentication>,
+    /// The time after which the credentials should be refreshed, if they expire.
+    refresh_at: Option<Timestamp>,
+}
+
+/// Fetches credentials for an index from a [`CredentialProvider`].
+#[async_trait::async_trait]
+pub(crate) trait FetchCredentials: fmt::Debug + Send + Sync {
+    /// Fetch credentials for the given index, along with their expiry (if any).
+    async fn fetch(
+        &self,
+        provider: CredentialProvider,
+        index: &Index,
+        username: Option<&str>,
+        client: Option<&ClientWithMiddleware>,
+    ) -> Result<(Authentication, Option<Timestamp>), CredentialProviderError>;
+}
+
+/// Fetches credentials by querying the [`CredentialProvider`] itself.
+#[derive(Debug)]
+struct ProviderFetcher;
+
+#[async_trait::async_trait]
+impl FetchCredentials for ProviderFetcher {
+    async fn fetch(
+        &self,
+        provider: CredentialProvider,
+        index: &Index,
+        username: Option<&str>,
+        client: Option<&ClientWithMiddleware>,
+    ) -> Result<(Authentication, Option<Timestamp>), CredentialProviderError> {
+        provider.fetch(index, username, client).await
+    }
+}
+
+/// A cache of credentials from [`CredentialProvider`]s, keyed by index.
+///
+/// Each index has its own lock, such that a slow provider (e.g., a CLI invocation) for one index
+/// doesn't block requests to other indexes, while concurrent requests to the same index share a
+/// single fetch.
+#[derive(Debug)]
+pub(crate) struct CredentialProviderCache {
+    credentials:
+        std::sync::Mutex<FxHashMap<DisplaySafeUrl, Arc<Mutex<Option<ProvidedCredentials>>>>>,
+    fetcher: Arc<dyn FetchCredentials>,
+}
+
+impl Default for CredentialProviderCache {
+    fn default() -> Self {
+        Self::new(Arc::new(ProviderFetcher))
+    }
+}
+
+impl CredentialProviderCache {
+    /// Create a [`CredentialProviderCache`] that fetches credentials with the given fetcher.
+    pub(crate) fn new(fetcher: Arc<dyn FetchCredentials>) -> Self {
+        Self {
+            credentials: std::sync::Mutex::default(),
+            fetcher,
+        }
+    }
+
+    /// Return the cache entry for the index, creating it if necessary.
+    fn entry(&self, index: &Index) -> Arc<Mutex<Option<ProvidedCredentials>>> {
+        let mut credentials = self
+            .credentials
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        credentials.entry(index.url.clone()).or_default().clone()
+    }
+
+    /// Return the credentials for the index from its provider, refreshing them if they've expired.
+    ///
+    /// If `rejected` is provided, the cached credentials are refreshed if they're the same as the
+    /// rejected credentials; if another request already refreshed them, the new credentials are
+    /// returned as-is.
+    ///
+    /// Returns `None` (with a warning) if the provider fails.
+    pub(crate) async fn get(
+        &self,
+        provider: CredentialProvider,
+        index: &Index,
+        username: Option<&str>,
+        client: Option<&ClientWithMiddleware>,
+        rejected: Option<&Arc<Authentication>>,
+    ) -> Option<Arc<Authentication>> {
+        // Hold the index's lock across the fetch, to avoid requesting the same token concurrently.
+        let entry = self.entry(index);
+        let mut cached = entry.lock().await;
+        if let Some(credentials) = cached.as_ref() {
+            if rejected.is_some_and(|rejected| Arc::ptr_eq(rejected, &credentials.authentication)) {
+                debug!(
+                    "Refreshing rejected credentials for {} from {provider}",
+                    index.url
+                );
+            } else if credentials
+                .refresh_at
+                .is_none_or(|refresh_at| Timestamp::now() < refresh_at)
+            {
+                return Some(credentials.authentication.clone());
+            } else {
+                debug!(
+                    "Refreshing expired credentials for {} from {provider}",
+                    index.url
+                );
+            }
+        }
+
+        match self.fetcher.fetch(provider, index, username, client).await {
+            Ok((authentication, expires_at)) => {
+                debug!("Found credentials for {} from {provider}", index.url);
+                let authentication = Arc::new(authentication);
+                *cached = Some(ProvidedCredentials {
+                    authentication: authentication.clone(),
+                    refresh_at: expires_at.map(|expires_at| {
+                        expires_at
+                            .saturating_sub(EXPIRY_TOLERANCE)
+                            .unwrap_or(expires_at)
+                    }),
+                });
+                Some(authentication)
+            }
+            Err(err) => {
+                *cached = None;
+                let mut message = err.to_string();
+                let mut source = std::error::Error::source(&err);
+                while let Some(err) = source {
+                    message.push_str(": ");
+                    message.push_str(&err.to_string());
+                    source = err.source();
+                }
+                warn_user_once!(
+                    "Failed to fetch credentials for `{}` from the `{provider}` credential provider: {message}",
+                    index.url
+                );
+                None
+            }
+        }
+    }
+}
+
+impl CredentialProvider {
+    /// Fetch credentials for the given index, along with their expiry (if any).
+    async fn fetch(
+        self,
+        index: &Index,
+        username: Option<&str>,
+        client: Option<&ClientWithMiddleware>,
+    ) -> Result<(Authentication, Option<Timestamp>), CredentialProviderError> {
+        match self {
+            Self::Keyring => {
+                let username = username.unwrap_or("__token__");
+                trace!(
+                    "Checking system keyring for credentials for {username}@{}",
+                    index.root_url
+                );
+                let credentials = KeyringProvider::native()
+                    .fetch(&index.root_url, Some(username))
+                    .await
+                    .ok_or_else(|| {
+                        CredentialProviderError::MissingKeyringEntry(index.root_url.clone())
+                    })?;
+                Ok((Authentication::from(credentials), None))
+            }
+            Self::AwsCodeartifact => {
+                let (token, expires_at) = fetch_codeartifact_token(&index.url, client).await?;
+                Ok((
+                    Authentication::from(Credentials::basic(Some("aws".to_string()), Some(token))),
+                    Some(expires_at),
+                ))
+            }
+            Self::GcpArtifactRegistry => {
+                // Sign a request to extract the access token, such that the signer's cached token
+                // is reused (and refreshed) across requests.
+                let request =
+                    reqwest::Request::new(reqwest::Method::GET, Url::from(index.url.clone()));
+                let request = sign_request(&GOOGLE_SIGNER, request).await?;
+                let credentials =
+                    Credentials::from_request(&request).ok_or(CredentialProviderError::Sign(
+                        reqsign::Error::unexpected("no access token was provided by the signer"),
+                    ))?;
+                Ok((
+                    Authentication::from(credentials),
+                    Some(
+                        Timestamp::now()
+                            .saturating_add(DEFAULT_LIFETIME)
+                            .unwrap_or(Timestamp::MAX),
+                    ),
+                ))
+            }
+            Self::AzureArtifacts => {
+                let (token, expires_at) = fetch_azure_token().await?;
+                Ok((
+                    Authentication::from(Credentials::bearer(token.into_bytes())),
+                    Some(expires_at),
+                ))
+            }
+        }
+    }
+}
+
+/// The components of an AWS CodeArtifact repository URL.
+#[derive(Debug, PartialEq, Eq)]
+struct CodeArtifactDomain<'a> {
+    domain: &'a str,
+    owner: &'a str,
+    region: &'a str,
+    /// The DNS suffix of the AWS partition (e.g., `amazonaws.com` or `amazonaws.com.cn`).
+    dns_suffix: &'a str,
+}
+
+impl<'a> CodeArtifactDomain<'a> {
+    /// Parse a CodeArtifact URL, like
+    /// `https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/`.
+    fn from_url(url: &'a Url) -> Option<Self> {
+        let host = url.host_str()?;
+        let (prefix, suffix) = host.split_once(".d.codeartifact.")?;
+        let (region, dns_suffix) = suffix.split_once('.')?;
+        if !matches!(dns_suffix, "amazonaws.com" | "amazonaws.com.cn") {
+            return None;
+        }
+        let (domain, owner) = prefix.rsplit_once('-')?;
+        Some(Self {
+            domain,
+            owner,
+            region,
+            dns_suffix,
+        })
+    }
+}
+
+/// Request an authorization token from the CodeArtifact API.
+///
+/// See: <https://docs.aws.amazon.com/codeartifact/latest/APIReference/API_GetAuthorizationToken.html>
+async fn fetch_codeartifact_token(
+    url: &DisplaySafeUrl,
+    client: Option<&ClientWithMiddleware>,
+) -> Result<(String, Timestamp), CredentialProviderError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        authorization_token: String,
+        /// The expiry of the token, in seconds since the Unix epoch.
+        expiration: f64,
+    }
+
+    let CodeArtifactDomain {
+        domain,
+        owner,
+        region,
+        dns_suffix,
+    } = CodeArtifactDomain::from_url(url)
+        .ok_or_else(|| CredentialProviderError::NotCodeArtifact(url.clone()))?;
+    let client = client.ok_or(CredentialProviderError::MissingClient)?;
+
+    let mut endpoint = Url::parse(&format!(
+        "https://codeartifact.{region}.{dns_suffix}/v1/authorization-token"
+    ))
+    .map_err(|_| CredentialProviderError::NotCodeArtifact(url.clone()))?;
+    endpoint
+        .query_pairs_mut()
+        .append_pair("domain", domain)
+        .append_pair("domain-owner", owner);
+
+    debug!("Requesting CodeArtifact authorization token for `{domain}` in `{region}`");
+    let signer = reqsign::aws::default_signer("codeartifact", region);
+    let request = client.post(endpoint).build()?;
+    let request = sign_request(&signer, request).await?;
+    let response = client.execute(request).await?.error_for_status()?;
+    let bytes = response.bytes().await?;
+    let Response {
+        authorization_token,
+        expiration,
+    } = serde_json::from_slice(&bytes)
+        .map_err(|err| CredentialProviderError::Json("codeartifact".to_string(), err))?;
+
+    #[expect(clippy::cast_possible_truncation)]
+    let expires_at = Timestamp::from_second(expiration as i64).unwrap_or(Timestamp::MAX);
+    Ok((authorization_token, expires_at))
+}
+
+/// Request an access token for Azure DevOps from the Azure CLI.
+///
+/// See: <https://learn.microsoft.com/en-us/cli/azure/account#az-account-get-access-token>
+async fn fetch_azure_token() -> Result<(String, Timestamp), CredentialProviderError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        access_token: String,
+        /// The expiry of the token, in seconds since the Unix epoch (Azure CLI 2.54 and later).
+        #[serde(rename = "expires_on")]
+        expires_on: Option<i64>,
+    }
+
+    let program = if cfg!(windows) { "az.cmd" } else { "az" };
+    debug!("Requesting Azure DevOps access token with `{program}`");
+    let output = Command::new(program)
+        .args([
+            "account",
+            "get-access-token",
+            "--resource",
+            AZURE_DEVOPS_RESOURCE,
+            "--output",
+            "json",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|err| CredentialProviderError::Command(program.to_string(), err))?;
+    if !output.status.success() {
+        return Err(CredentialProviderError::CommandStatus(
+            program.to_string(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let Response {
+        access_token,
+        expires_on,
+    } = serde_json::from_slice(&output.stdout)
+        .map_err(|err| CredentialProviderError::Json(program.to_string(), err))?;
+    let expires_at = expires_on
+        .and_then(|expires_on| Timestamp::from_second(expires_on).ok())
+        .unwrap_or_else(|| {
+            Timestamp::now()
+                .saturating_add(DEFAULT_LIFETIME)
+                .unwrap_or(Timestamp::MAX)
+        });
+    Ok((access_token, expires_at))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    /// Credentials returned by a dummy provider, along with their expiry.
+    type DummyCredentials = (Credentials, Option<Timestamp>);
+
+    /// A [`FetchCredentials`] that returns the given credentials, in order, instead of querying
+    /// credential providers.
+    #[derive(Debug)]
+    pub(crate) struct DummyFetcher(std::sync::Mutex<VecDeque<DummyCredentials>>);
+
+    impl DummyFetcher {
+        pub(crate) fn new(responses: impl IntoIterator<Item = DummyCredentials>) -> Self {
+            Self(std::sync::Mutex::new(responses.into_iter().collect()))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl FetchCredentials for DummyFetcher {
+        async fn fetch(
+            &self,
+            _provider: CredentialProvider,
+            index: &Index,
+            _username: Option<&str>,
+            _client: Option<&ClientWithMiddleware>,
+        ) -> Result<(Authentication, Option<Timestamp>), CredentialProviderError> {
+            self.0
+                .lock()
+                .unwrap()
+                .pop_front()
+                .map(|(credentials, expires_at)| (Authentication::from(credentials), expires_at))
+                .ok_or_else(|| CredentialProviderError::MissingKeyringEntry(index.url.clone()))
+        }
+    }
+
+    #[test]
+    fn codeartifact_domain() {
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/my-repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(
+            CodeArtifactDomain::from_url(&url),
+            Some(CodeArtifactDomain {
+                domain: "my-domain",
+                owner: "111122223333",
+                region: "us-west-2",
+                dns_suffix: "amazonaws.com",
+            })
+        );
+
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.cn-north-1.amazonaws.com.cn/pypi/my-repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(
+            CodeArtifactDomain::from_url(&url),
+            Some(CodeArtifactDomain {
+                domain: "my-domain",
+                owner: "111122223333",
+                region: "cn-north-1",
+                dns_suffix: "amazonaws.com.cn",
+            })
+        );
+
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.example.com/pypi/my-repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(CodeArtifactDomain::from_url(&url), None);
+
+        let url = Url::parse("https://pypi.org/simple/").unwrap();
+        assert_eq!(CodeArtifactDomain::from_url(&url), None);
+    }
+
+    #[test]
+    fn parse_provider() {
+        #[derive(Deserialize)]
+        struct Settings {
+            provider: CredentialProvider,
+        }
+
+        for provider in [
+            CredentialProvider::Keyring,
+            CredentialProvider::AwsCodeartifact,
+            CredentialProvider::GcpArtifactRegistry,
+            CredentialProvider::AzureArtifacts,
+        ] {
+            let settings: Settings = toml::from_str(&format!("provider = \"{provider}\"")).unwrap();
+            assert_eq!(settings.provider, provider);
+        }
+    }
+}
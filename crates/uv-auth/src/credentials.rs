@@ -11,6 +11,7 @@ use http::Uri;
 use netrc::Netrc;
 use reqsign::aws::DefaultSigner as AwsDefaultSigner;
 use reqsign::google::DefaultSigner as GcsDefaultSigner;
+use reqsign::{Signer, SigningCredential};
 use reqwest::Request;
 use reqwest::header::HeaderValue;
 use serde::{Deserialize, Serialize};
@@ -479,69 +480,49 @@ impl Authentication {
     ///
     /// Any existing credentials will be overridden.
     #[must_use]
-    pub(crate) async fn authenticate(&self, mut request: Request) -> Request {
+    pub(crate) async fn authenticate(&self, request: Request) -> Request {
         match self {
             Self::Credentials(credentials) => credentials.authenticate(request),
-            Self::AwsSigner(signer) => {
-                // Build an `http::Request` from the `reqwest::Request`.
-                // SAFETY: If we have a valid `reqwest::Request`, we expect (e.g.) the URL to be valid.
-                let uri = Uri::from_str(request.url().as_str()).unwrap();
-                let mut http_req = http::Request::builder()
-                    .method(request.method().clone())
-                    .uri(uri)
-                    .body(())
-                    .unwrap();
-                *http_req.headers_mut() = request.headers().clone();
-
-                // Sign the parts.
-                let (mut parts, ()) = http_req.into_parts();
-                signer
-                    .sign(&mut parts, None)
-                    .await
-                    .expect("AWS signing should succeed");
-
-                // Copy over the signed headers.
-                request.headers_mut().extend(parts.headers);
-
-                // Copy over the signed path and query, if any.
-                if let Some(path_and_query) = parts.uri.path_and_query() {
-                    request.url_mut().set_path(path_and_query.path());
-                    request.url_mut().set_query(path_and_query.query());
-                }
-                request
-            }
-            Self::GcsSigner(signer) => {
-                // Build an `http::Request` from the `reqwest::Request`.
-                // SAFETY: If we have a valid `reqwest::Request`, we expect (e.g.) the URL to be valid.
-                let uri = Uri::from_str(request.url().as_str()).unwrap();
-                let mut http_req = http::Request::builder()
-                    .method(request.method().clone())
-                    .uri(uri)
-                    .body(())
-                    .unwrap();
-                *http_req.headers_mut() = request.headers().clone();
-
-                // Sign the parts.
-                let (mut parts, ()) = http_req.into_parts();
-                signer
-                    .sign(&mut parts, None)
-                    .await
-                    .expect("GCS signing should succeed");
-
-                // Copy over the signed headers.
-                request.headers_mut().extend(parts.headers);
-
-                // Copy over the signed path and query, if any.
-                if let Some(path_and_query) = parts.uri.path_and_query() {
-                    request.url_mut().set_path(path_and_query.path());
-                    request.url_mut().set_query(path_and_query.query());
-                }
-                request
-            }
+            Self::AwsSigner(signer) => sign_request(signer, request)
+                .await
+                .expect("AWS signing should succeed"),
+            Self::GcsSigner(signer) => sign_request(signer, request)
+                .await
+                .expect("GCS signing should succeed"),
         }
     }
 }
 
+/// Sign a [`Request`] with the given [`Signer`], e.g., to apply AWS Signature Version 4 authentication.
+pub(crate) async fn sign_request<K: SigningCredential>(
+    signer: &Signer<K>,
+    mut request: Request,
+) -> reqsign::Result<Request> {
+    // Build an `http::Request` from the `reqwest::Request`.
+    // SAFETY: If we have a valid `reqwest::Request`, we expect (e.g.) the URL to be valid.
+    let uri = Uri::from_str(request.url().as_str()).unwrap();
+    let mut http_req = http::Request::builder()
+        .method(request.method().clone())
+        .uri(uri)
+        .body(())
+        .unwrap();
+    *http_req.headers_mut() = request.headers().clone();
+
+    // Sign the parts.
+    let (mut parts, ()) = http_req.into_parts();
+    signer.sign(&mut parts, None).await?;
+
+    // Copy over the signed headers.
+    request.headers_mut().extend(parts.headers);
+
+    // Copy over the signed path and query, if any.
+    if let Some(path_and_query) = parts.uri.path_and_query() {
+        request.url_mut().set_path(path_and_query.path());
+        request.url_mut().set_query(path_and_query.query());
+    }
+    Ok(request)
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_debug_snapshot;
@@ -0,0 +1,231 @@
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{debug, trace, warn};
+
+use uv_static::EnvVars;
+
+use crate::Credentials;
+
+/// The key under which Docker stores the credentials for Docker Hub.
+const DOCKER_HUB_KEY: &str = "https://index.docker.io/v1/";
+
+/// The contents of a Docker configuration file (e.g., `~/.docker/config.json`).
+///
+/// See: <https://docs.docker.com/reference/cli/docker/#docker-cli-configuration-file-configjson-properties>
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DockerConfig {
+    /// Credentials stored inline, keyed by registry.
+    #[serde(default)]
+    auths: FxHashMap<String, DockerAuth>,
+    /// The credential helper to use for all registries (e.g., `desktop` or `osxkeychain`).
+    creds_store: Option<String>,
+    /// Per-registry credential helpers, which take precedence over `credsStore`.
+    #[serde(default)]
+    cred_helpers: FxHashMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DockerAuth {
+    /// The base64-encoded `username:password` pair.
+    auth: Option<String>,
+}
+
+/// The response from a `docker-credential-<helper> get` invocation.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HelperCredentials {
+    username: String,
+    secret: String,
+}
+
+impl DockerConfig {
+    /// Read the Docker configuration from `$DOCKER_CONFIG/config.json` or
+    /// `~/.docker/config.json`, if it exists.
+    pub fn from_settings() -> Option<Self> {
+        let path = if let Some(dir) = std::env::var_os(EnvVars::DOCKER_CONFIG) {
+            PathBuf::from(dir).join("config.json")
+        } else {
+            std::env::home_dir()?.join(".docker").join("config.json")
+        };
+        let content = match fs_err::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                warn!("Failed to read Docker configuration: {err}");
+                return None;
+            }
+        };
+        serde_json::from_str(&content)
+            .inspect_err(|err| {
+                warn!(
+                    "Failed to parse Docker configuration at `{}`: {err}",
+                    path.display()
+                );
+            })
+            .ok()
+    }
+
+    /// Return the credentials for the given registry (e.g., `ghcr.io`), if any.
+    ///
+    /// Per-registry credential helpers take precedence over the default credential store, which
+    /// takes precedence over credentials stored inline in the configuration file.
+    pub async fn credentials_for(&self, registry: &str) -> Option<Credentials> {
+        let key = Self::key_for(registry);
+
+        if let Some(helper) = self.cred_helpers.get(key).or(self.creds_store.as_ref()) {
+            if let Some(credentials) = Self::fetch_helper(helper, key).await {
+                return Some(credentials);
+            }
+        }
+
+        let auth = self
+            .auths
+            .iter()
+            .find(|(server, _)| Self::registry_of(server) == Self::registry_of(key))
+            .and_then(|(_, auth)| auth.auth.as_deref())?;
+        let decoded = BASE64_STANDARD
+            .decode(auth)
+            .inspect_err(|err| warn!("Failed to decode Docker credentials for `{key}`: {err}"))
+            .ok()?;
+        let decoded = String::from_utf8(decoded).ok()?;
+        let (username, password) = decoded.split_once(':')?;
+        trace!("Found credentials for `{registry}` in Docker configuration");
+        Some(Credentials::basic(
+            Some(username.to_string()),
+            Some(password.to_string()),
+        ))
+    }
+
+    /// Return the key under which Docker stores credentials for the given registry.
+    fn key_for(registry: &str) -> &str {
+        match registry {
+            "docker.io" | "index.docker.io" | "registry-1.docker.io" => DOCKER_HUB_KEY,
+            registry => registry,
+        }
+    }
+
+    /// Strip the scheme and path from a Docker configuration key (e.g.,
+    /// `https://ghcr.io/v1/` becomes `ghcr.io`).
+    fn registry_of(server: &str) -> &str {
+        let server = server
+            .strip_prefix("https://")
+            .or_else(|| server.strip_prefix("http://"))
+            .unwrap_or(server);
+        server.split('/').next().unwrap_or(server)
+    }
+
+    /// Retrieve credentials from a Docker credential helper.
+    ///
+    /// See: <https://github.com/docker/docker-credential-helpers>
+    async fn fetch_helper(helper: &str, server: &str) -> Option<Credentials> {
+        let program = format!("docker-credential-{helper}");
+        debug!("Fetching credentials for `{server}` with `{program}`");
+
+        let mut child = Command::new(&program)
+            .arg("get")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .inspect_err(|err| warn!("Failure running `{program}` command: {err}"))
+            .ok()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(server.as_bytes())
+                .await
+                .inspect_err(|err| warn!("Failed to write to `{program}`: {err}"))
+                .ok()?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .inspect_err(|err| warn!("Failed to wait for `{program}` output: {err}"))
+            .ok()?;
+
+        // Helpers exit with a non-zero status (e.g., "credentials not found in native keychain")
+        // when no credentials are stored for the server.
+        if !output.status.success() {
+            trace!(
+                "No credentials for `{server}` in `{program}`: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return None;
+        }
+
+        let credentials: HelperCredentials = serde_json::from_slice(&output.stdout)
+            .inspect_err(|err| warn!("Failed to parse response from `{program}`: {err}"))
+            .ok()?;
+
+        // An identity token must be exchanged via OAuth, which isn't supported.
+        if credentials.username == "<token>" {
+            debug!("Ignoring identity token for `{server}` from `{program}`");
+            return None;
+        }
+
+        Some(Credentials::basic(
+            Some(credentials.username),
+            Some(credentials.secret),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn inline_credentials() {
+        let config: DockerConfig = serde_json::from_str(
+            r#"{
+                "auths": {
+                    "ghcr.io": { "auth": "dXNlcjpwYXNz" },
+                    "https://index.docker.io/v1/": { "auth": "aHViOnNlY3JldA==" },
+                    "https://registry.example.com/v2/": { "auth": "ZXhhbXBsZTpzM2NyM3Q6eA==" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let credentials = config.credentials_for("ghcr.io").await.unwrap();
+        assert_eq!(credentials.username(), Some("user"));
+        assert_eq!(credentials.password(), Some("pass"));
+
+        let credentials = config.credentials_for("docker.io").await.unwrap();
+        assert_eq!(credentials.username(), Some("hub"));
+        assert_eq!(credentials.password(), Some("secret"));
+
+        let credentials = config
+            .credentials_for("registry.example.com")
+            .await
+            .unwrap();
+        assert_eq!(credentials.username(), Some("example"));
+        assert_eq!(credentials.password(), Some("s3cr3t:x"));
+
+        assert!(config.credentials_for("quay.io").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_helper() {
+        // If the helper can't be run, fall back to the inline credentials.
+        let config: DockerConfig = serde_json::from_str(
+            r#"{
+                "auths": { "ghcr.io": { "auth": "dXNlcjpwYXNz" } },
+                "credHelpers": { "ghcr.io": "uv-test-missing-helper" }
+            }"#,
+        )
+        .unwrap();
+
+        let credentials = config.credentials_for("ghcr.io").await.unwrap();
+        assert_eq!(credentials.username(), Some("user"));
+    }
+}
@@ -4,6 +4,8 @@ use rustc_hash::FxHashSet;
 use url::Url;
 use uv_redacted::DisplaySafeUrl;
 
+use crate::CredentialProvider;
+
 /// When to use authentication.
 #[derive(
     Copy,
@@ -59,6 +61,8 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// The provider of credentials for the index, if any.
+    pub credential_provider: Option<CredentialProvider>,
 }
 
 impl Index {
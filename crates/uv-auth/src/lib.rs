@@ -1,9 +1,12 @@
 pub use access_token::AccessToken;
 pub use cache::CredentialsCache;
+pub use credential_provider::CredentialProvider;
 pub use credentials::{Credentials, Username};
+pub use docker::DockerConfig;
 pub use index::{AuthPolicy, Index, Indexes};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
+pub use providers::S3Bucket;
 pub use pyx::{
     DEFAULT_TOLERANCE_SECS, PyxJwt, PyxOAuthTokens, PyxTokenStore, PyxTokens, TokenStoreError,
 };
@@ -13,7 +16,9 @@ pub use store::{AuthBackend, AuthScheme, TextCredentialStore, TomlCredentialErro
 
 mod access_token;
 mod cache;
+mod credential_provider;
 mod credentials;
+mod docker;
 mod index;
 mod keyring;
 mod middleware;
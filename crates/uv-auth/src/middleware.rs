@@ -13,6 +13,7 @@ use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_warnings::owo_colors::OwoColorize;
 
+use crate::credential_provider::CredentialProviderCache;
 use crate::credentials::Authentication;
 use crate::providers::{GcsEndpointProvider, HuggingFaceProvider, S3EndpointProvider};
 use crate::pyx::{DEFAULT_TOLERANCE_SECS, PyxTokenStore};
@@ -23,7 +24,7 @@ use crate::{
     index::{AuthPolicy, Indexes},
     realm::Realm,
 };
-use crate::{Index, TextCredentialStore};
+use crate::{CredentialProvider, Index, TextCredentialStore};
 
 /// Cached check for whether we're running in Dependabot.
 static IS_DEPENDABOT: LazyLock<bool> =
@@ -172,6 +173,8 @@ pub struct AuthMiddleware {
     s3_credential_state: Mutex<S3CredentialState>,
     /// Cached GCS credentials to avoid running the credential helper multiple times.
     gcs_credential_state: Mutex<GcsCredentialState>,
+    /// Cached credentials from the credential providers configured for each index.
+    provider_credentials: CredentialProviderCache,
     preview: Preview,
 }
 
@@ -196,6 +199,7 @@ impl AuthMiddleware {
             pyx_token_state: Mutex::new(TokenState::Uninitialized),
             s3_credential_state: Mutex::new(S3CredentialState::Uninitialized),
             gcs_credential_state: Mutex::new(GcsCredentialState::Uninitialized),
+            provider_credentials: CredentialProviderCache::default(),
             preview: Preview::default(),
         }
     }
@@ -276,6 +280,17 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure how credentials are fetched from the [`CredentialProvider`]s of each index.
+    #[cfg(test)]
+    #[must_use]
+    fn with_credential_fetcher(
+        mut self,
+        fetcher: Arc<dyn crate::credential_provider::FetchCredentials>,
+    ) -> Self {
+        self.provider_credentials = CredentialProviderCache::new(fetcher);
+        self
+    }
+
     /// Configure the [`PyxTokenStore`] to use for persistent credentials.
     #[must_use]
     pub fn with_pyx_token_store(mut self, token_store: PyxTokenStore) -> Self {
@@ -346,6 +361,24 @@ impl Middleware for AuthMiddleware {
         let credentials: Option<Arc<Authentication>> = if matches!(auth_policy, AuthPolicy::Never) {
             None
         } else {
+            // If the index has a credential provider, use it unless the request already includes
+            // a password.
+            if let Some(index) = index
+                && let Some(provider) = index.credential_provider
+                && request_credentials
+                    .as_ref()
+                    .is_none_or(|credentials| credentials.password().is_none())
+            {
+                let username = request_credentials
+                    .as_ref()
+                    .and_then(|credentials| credentials.username());
+                return self
+                    .complete_request_with_provider(
+                        provider, index, username, request, extensions, next, &url,
+                    )
+                    .await;
+            }
+
             if let Some(request_credentials) = request_credentials {
                 return self
                     .complete_request_with_request_credentials(
@@ -567,6 +600,64 @@ impl AuthMiddleware {
         result
     }
 
+    /// Complete the request with credentials from the index's [`CredentialProvider`].
+    ///
+    /// If the credentials are rejected, they're refreshed and the request is retried once.
+    async fn complete_request_with_provider(
+        &self,
+        provider: CredentialProvider,
+        index: &Index,
+        username: Option<&str>,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+        url: &DisplaySafeUrl,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(credentials) = self
+            .provider_credentials
+            .get(provider, index, username, self.base_client.as_ref(), None)
+            .await
+        else {
+            trace!("Attempting unauthenticated request for {url}");
+            return next.run(request, extensions).await;
+        };
+
+        let retry_request = request.try_clone();
+        trace!("Attempting request for {url} with credentials from {provider}");
+        let request = credentials.authenticate(request).await;
+        let response = next.clone().run(request, extensions).await?;
+        if !matches!(
+            response.status(),
+            StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED
+        ) {
+            return Ok(response);
+        }
+        let Some(retry_request) = retry_request else {
+            return Ok(response);
+        };
+
+        // The credentials may have been revoked or expired early, so refresh them.
+        debug!(
+            "Request for {url} failed with {}, refreshing credentials from {provider}",
+            response.status()
+        );
+        let Some(credentials) = self
+            .provider_credentials
+            .get(
+                provider,
+                index,
+                username,
+                self.base_client.as_ref(),
+                Some(&credentials),
+            )
+            .await
+        else {
+            return Ok(response);
+        };
+        let retry_request = credentials.authenticate(retry_request).await;
+        next.run(retry_request, extensions).await
+    }
+
     /// Use known request credentials to complete the request.
     async fn complete_request_with_request_credentials(
         &self,
@@ -933,6 +1024,7 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::Index;
+    use crate::credential_provider::tests::DummyFetcher;
     use crate::credentials::Password;
 
     use super::*;
@@ -2099,11 +2191,13 @@ mod tests {
                 url: DisplaySafeUrl::from_url(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from_url(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                credential_provider: None,
             },
             Index {
                 url: DisplaySafeUrl::from_url(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from_url(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                credential_provider: None,
             },
         ]);
 
@@ -2207,6 +2301,7 @@ mod tests {
             url: DisplaySafeUrl::from_url(index_url.clone()),
             root_url: DisplaySafeUrl::from_url(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            credential_provider: None,
         }]);
 
         let client = test_client_builder()
@@ -2266,6 +2361,7 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            credential_provider: None,
         }])
     }
 
@@ -2667,4 +2763,147 @@ mod tests {
 
         Ok(())
     }
+
+    /// Create a client for an index whose credential provider returns the given credentials, in
+    /// order.
+    fn provider_client(
+        server: &MockServer,
+        responses: impl IntoIterator<Item = (Credentials, Option<jiff::Timestamp>)>,
+    ) -> Result<(ClientWithMiddleware, Url), Error> {
+        let url = Url::parse(&server.uri())?.join("simple/")?;
+        let indexes = Indexes::from_indexes(vec![Index {
+            url: DisplaySafeUrl::from_url(url.clone()),
+            root_url: DisplaySafeUrl::from_url(url.clone()),
+            auth_policy: AuthPolicy::Auto,
+            credential_provider: Some(CredentialProvider::AwsCodeartifact),
+        }]);
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(indexes)
+                    .with_credential_fetcher(Arc::new(DummyFetcher::new(responses))),
+            )
+            .build();
+        Ok((client, url))
+    }
+
+    #[test(tokio::test)]
+    async fn test_credential_provider_refresh_expired() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(basic_auth("aws", "token-1"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(basic_auth("aws", "token-2"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        // The first token is within the expiry tolerance, so it's used once and then refreshed.
+        let now = jiff::Timestamp::now();
+        let (client, url) = provider_client(
+            &server,
+            [
+                (
+                    Credentials::basic(Some("aws".to_string()), Some("token-1".to_string())),
+                    Some(now),
+                ),
+                (
+                    Credentials::basic(Some("aws".to_string()), Some("token-2".to_string())),
+                    Some(now.saturating_add(jiff::SignedDuration::from_hours(1))?),
+                ),
+            ],
+        )?;
+
+        for _ in 0..3 {
+            assert_eq!(client.get(url.clone()).send().await?.status(), 200);
+        }
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_credential_provider_retry_rejected() -> Result<(), Error> {
+        for status in [401, 403] {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(basic_auth("aws", "token-1"))
+                .respond_with(ResponseTemplate::new(status))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(basic_auth("aws", "token-2"))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(2)
+                .mount(&server)
+                .await;
+
+            // Neither token reports an expiry, so the second is only fetched after the first is
+            // rejected.
+            let (client, url) = provider_client(
+                &server,
+                [
+                    (
+                        Credentials::basic(Some("aws".to_string()), Some("token-1".to_string())),
+                        None,
+                    ),
+                    (
+                        Credentials::basic(Some("aws".to_string()), Some("token-2".to_string())),
+                        None,
+                    ),
+                ],
+            )?;
+
+            assert_eq!(
+                client.get(url.clone()).send().await?.status(),
+                200,
+                "A request rejected with {status} should be retried with refreshed credentials"
+            );
+            assert_eq!(
+                client.get(url.clone()).send().await?.status(),
+                200,
+                "The refreshed credentials should be reused"
+            );
+
+            server.verify().await;
+        }
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_credential_provider_rejected_without_refresh() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(basic_auth("aws", "token-1"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The provider has no further credentials, so the rejection is returned as-is.
+        let (client, url) = provider_client(
+            &server,
+            [(
+                Credentials::basic(Some("aws".to_string()), Some("token-1".to_string())),
+                None,
+            )],
+        )?;
+
+        assert_eq!(client.get(url).send().await?.status(), 401);
+
+        server.verify().await;
+        Ok(())
+    }
 }
@@ -3,15 +3,17 @@ use std::sync::LazyLock;
 
 use reqsign::aws::DefaultSigner as AwsDefaultSigner;
 use reqsign::google::DefaultSigner as GcsDefaultSigner;
+use reqwest::Request;
 use tracing::debug;
 use url::Url;
 
 use uv_preview::{Preview, PreviewFeature};
+use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
 use crate::Credentials;
-use crate::credentials::Token;
+use crate::credentials::{Token, sign_request};
 use crate::realm::{Realm, RealmRef};
 
 /// The [`Realm`] for the Hugging Face platform.
@@ -91,19 +93,87 @@ impl S3EndpointProvider {
     /// This is potentially expensive as it may invoke credential helpers, so the result
     /// should be cached.
     pub(crate) fn create_signer() -> AwsDefaultSigner {
-        // TODO(charlie): Can `reqsign` infer the region for us? Profiles, for example,
-        // often have a region set already.
-        let region = std::env::var(EnvVars::AWS_REGION)
-            .map(Cow::Owned)
-            .unwrap_or_else(|_| {
-                std::env::var(EnvVars::AWS_DEFAULT_REGION)
-                    .map(Cow::Owned)
-                    .unwrap_or_else(|_| Cow::Borrowed("us-east-1"))
-            });
-        reqsign::aws::default_signer("s3", &region)
+        reqsign::aws::default_signer("s3", &s3_region())
     }
 }
 
+/// Returns the configured AWS region.
+fn s3_region() -> Cow<'static, str> {
+    // TODO(charlie): Can `reqsign` infer the region for us? Profiles, for example,
+    // often have a region set already.
+    std::env::var(EnvVars::AWS_REGION)
+        .map(Cow::Owned)
+        .unwrap_or_else(|_| {
+            std::env::var(EnvVars::AWS_DEFAULT_REGION)
+                .map(Cow::Owned)
+                .unwrap_or_else(|_| Cow::Borrowed("us-east-1"))
+        })
+}
+
+/// The S3 signer shared across all [`S3Bucket`]s, such that credentials are only loaded once.
+static S3_SIGNER: LazyLock<AwsDefaultSigner> = LazyLock::new(S3EndpointProvider::create_signer);
+
+/// A prefix in an S3 bucket, addressed via an `s3://{bucket}/{prefix}` URL.
+#[derive(Debug, Clone)]
+pub struct S3Bucket {
+    /// The HTTPS URL of the prefix, with a trailing slash.
+    url: DisplaySafeUrl,
+    signer: AwsDefaultSigner,
+}
+
+impl S3Bucket {
+    /// Resolve an `s3://{bucket}/{prefix}` URL, returning `None` for any other URL.
+    ///
+    /// If `UV_S3_ENDPOINT_URL` is set, objects are addressed with path-style URLs on that
+    /// endpoint (e.g., a self-hosted service); otherwise, they're addressed with virtual-hosted-style URLs on
+    /// Amazon S3 in the configured region.
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let endpoint = std::env::var(EnvVars::UV_S3_ENDPOINT_URL)
+            .ok()
+            .and_then(|endpoint| Url::parse(&endpoint).ok());
+        let url = s3_https_url(url, endpoint.as_ref(), &s3_region())?;
+        Some(Self {
+            url,
+            signer: S3_SIGNER.clone(),
+        })
+    }
+
+    /// The HTTPS URL of the prefix, with a trailing slash.
+    pub fn url(&self) -> &DisplaySafeUrl {
+        &self.url
+    }
+
+    /// Sign a request to an object in the bucket with AWS Signature Version 4.
+    pub async fn sign(&self, request: Request) -> Result<Request, reqsign::Error> {
+        sign_request(&self.signer, request).await
+    }
+}
+
+/// Convert an `s3://{bucket}/{prefix}` URL to the HTTPS URL of the prefix, with a trailing slash.
+fn s3_https_url(url: &Url, endpoint: Option<&Url>, region: &str) -> Option<DisplaySafeUrl> {
+    if url.scheme() != "s3" {
+        return None;
+    }
+    let bucket = url.host_str().filter(|bucket| !bucket.is_empty())?;
+    let prefix = url.path().trim_matches('/');
+    let mut https = match endpoint {
+        Some(endpoint) => {
+            let mut endpoint = endpoint.clone();
+            let path = format!("{}/{bucket}", endpoint.path().trim_end_matches('/'));
+            endpoint.set_path(&path);
+            endpoint
+        }
+        None => Url::parse(&format!("https://{bucket}.s3.{region}.amazonaws.com")).ok()?,
+    };
+    let path = if prefix.is_empty() {
+        format!("{}/", https.path().trim_end_matches('/'))
+    } else {
+        format!("{}/{prefix}/", https.path().trim_end_matches('/'))
+    };
+    https.set_path(&path);
+    Some(DisplaySafeUrl::from_url(https))
+}
+
 /// The [`Url`] for the GCS endpoint, if set.
 static GCS_ENDPOINT_REALM: LazyLock<Option<Realm>> = LazyLock::new(|| {
     let gcs_endpoint_url = std::env::var(EnvVars::UV_GCS_ENDPOINT_URL).ok()?;
@@ -143,3 +213,43 @@ impl GcsEndpointProvider {
         reqsign::google::default_signer("storage.googleapis.com")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::s3_https_url;
+
+    #[test]
+    fn s3_https_url_virtual_hosted() {
+        let url = Url::parse("s3://bucket/uv/cache").unwrap();
+        assert_eq!(
+            s3_https_url(&url, None, "eu-west-1").unwrap().as_str(),
+            "https://bucket.s3.eu-west-1.amazonaws.com/uv/cache/"
+        );
+
+        let url = Url::parse("s3://bucket").unwrap();
+        assert_eq!(
+            s3_https_url(&url, None, "us-east-1").unwrap().as_str(),
+            "https://bucket.s3.us-east-1.amazonaws.com/"
+        );
+    }
+
+    #[test]
+    fn s3_https_url_path_style() {
+        let url = Url::parse("s3://bucket/uv/").unwrap();
+        let endpoint = Url::parse("http://localhost:9000").unwrap();
+        assert_eq!(
+            s3_https_url(&url, Some(&endpoint), "us-east-1")
+                .unwrap()
+                .as_str(),
+            "http://localhost:9000/bucket/uv/"
+        );
+    }
+
+    #[test]
+    fn s3_https_url_other_scheme() {
+        let url = Url::parse("https://bucket/uv").unwrap();
+        assert_eq!(s3_https_url(&url, None, "us-east-1"), None);
+    }
+}
@@ -8,7 +8,6 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use futures::TryStreamExt;
-use reqwest_retry::policies::ExponentialBackoff;
 use std::fmt;
 use thiserror::Error;
 use tokio::io::{AsyncRead, ReadBuf};
@@ -17,7 +16,7 @@ use url::Url;
 use uv_distribution_filename::SourceDistExtension;
 
 use uv_cache::{Cache, CacheBucket, CacheEntry, Error as CacheError};
-use uv_client::{BaseClient, RetryState};
+use uv_client::{BaseClient, RetryState, UvRetryPolicy};
 use uv_extract::{Error as ExtractError, stream};
 use uv_pep440::Version;
 use uv_platform::Platform;
@@ -164,7 +163,7 @@ pub async fn bin_install(
     binary: Binary,
     version: &Version,
     client: &BaseClient,
-    retry_policy: &ExponentialBackoff,
+    retry_policy: &UvRetryPolicy,
     cache: &Cache,
     reporter: &dyn Reporter,
 ) -> Result<PathBuf, Error> {
@@ -233,7 +232,7 @@ async fn download_and_unpack_with_retry(
     binary: Binary,
     version: &Version,
     client: &BaseClient,
-    retry_policy: &ExponentialBackoff,
+    retry_policy: &UvRetryPolicy,
     cache: &Cache,
     reporter: &dyn Reporter,
     platform_name: &str,
@@ -241,7 +240,7 @@ async fn download_and_unpack_with_retry(
     download_url: &Url,
     cache_entry: &CacheEntry,
 ) -> Result<PathBuf, Error> {
-    let mut retry_state = RetryState::start(*retry_policy, download_url.clone());
+    let mut retry_state = RetryState::start(retry_policy.clone(), download_url.clone());
 
     loop {
         let result = download_and_unpack(
@@ -0,0 +1,226 @@
+//! Run a user-specified command to build extension modules before assembling a wheel.
+//!
+//! The hook is run with the Python interpreter that invoked the build backend. Any files it
+//! writes to `UV_BUILD_HOOK_OUTPUT_DIR` are added to the wheel, relative to the wheel root, and
+//! the wheel is tagged for the interpreter's Python version, ABI and platform.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::debug;
+
+use uv_distribution_filename::{WheelFilename, WheelFilenameError};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_static::EnvVars;
+
+#[derive(Debug, Error)]
+pub enum BuildHookError {
+    #[error(
+        "`tool.uv.build-backend.build-hook` requires a Python interpreter, build the project through a PEP 517 build frontend such as `uv build`"
+    )]
+    MissingPython,
+    #[error("`tool.uv.build-backend.build-hook` must not be empty")]
+    EmptyCommand,
+    #[error("Failed to query the Python interpreter: {}", _0.user_display())]
+    QueryInterpreter(PathBuf, #[source] io::Error),
+    #[error("Invalid output from querying the Python interpreter: {}", _0.user_display())]
+    InterpreterInfo(PathBuf, #[source] serde_json::Error),
+    #[error("Failed to compute the wheel tags for the Python interpreter: {}", _0.user_display())]
+    Tags(PathBuf, #[source] WheelFilenameError),
+    #[error("Failed to run build hook `{0}`")]
+    Spawn(String, #[source] io::Error),
+    #[error("Build hook `{0}` failed with {1}")]
+    Failed(String, std::process::ExitStatus),
+}
+
+/// The subset of the interpreter information required for running a build hook.
+#[derive(Debug, Deserialize)]
+struct InterpreterInfo {
+    implementation: String,
+    version: (u8, u8),
+    abiflags: String,
+    soabi: Option<String>,
+    ext_suffix: Option<String>,
+    platform: String,
+}
+
+const QUERY_INTERPRETER: &str = r#"
+import json, sys, sysconfig
+print(json.dumps({
+    "implementation": sys.implementation.name,
+    "version": sys.version_info[:2],
+    "abiflags": getattr(sys, "abiflags", ""),
+    "soabi": sysconfig.get_config_var("SOABI"),
+    "ext_suffix": sysconfig.get_config_var("EXT_SUFFIX"),
+    "platform": sysconfig.get_platform(),
+}))
+"#;
+
+/// The Python interpreter a build hook compiles extension modules for.
+#[derive(Debug)]
+pub(crate) struct BuildHookTarget {
+    python: PathBuf,
+    info: InterpreterInfo,
+}
+
+impl BuildHookTarget {
+    /// Query the Python interpreter that invoked the build backend.
+    pub(crate) fn from_env() -> Result<Self, BuildHookError> {
+        let python = std::env::var_os(EnvVars::UV_INTERNAL__BUILD_BACKEND_PYTHON)
+            .map(PathBuf::from)
+            .ok_or(BuildHookError::MissingPython)?;
+        Self::query(python)
+    }
+
+    fn query(python: PathBuf) -> Result<Self, BuildHookError> {
+        let output = Command::new(&python)
+            .args(["-c", QUERY_INTERPRETER])
+            .output()
+            .map_err(|err| BuildHookError::QueryInterpreter(python.clone(), err))?;
+        if !output.status.success() {
+            return Err(BuildHookError::QueryInterpreter(
+                python,
+                io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+            ));
+        }
+        let info = serde_json::from_slice(&output.stdout)
+            .map_err(|err| BuildHookError::InterpreterInfo(python.clone(), err))?;
+        debug!("Build hook target interpreter: {info:?}");
+        Ok(Self { python, info })
+    }
+
+    /// The filename of a wheel for the target interpreter.
+    ///
+    /// Like `bdist_wheel`, the platform tag is derived from `sysconfig.get_platform()`, so the
+    /// wheel is tagged e.g. `linux_x86_64` rather than `manylinux_2_28_x86_64`.
+    pub(crate) fn wheel_filename(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Result<WheelFilename, BuildHookError> {
+        let (major, minor) = self.info.version;
+        let soabi_tag = self
+            .info
+            .soabi
+            .as_deref()
+            .map(|soabi| soabi.replace(['-', '.'], "_"));
+        let (python_tag, abi_tag) = match self.info.implementation.as_str() {
+            "cpython" => (
+                format!("cp{major}{minor}"),
+                format!("cp{major}{minor}{}", self.info.abiflags),
+            ),
+            "pypy" => (
+                format!("pp{major}{minor}"),
+                soabi_tag.unwrap_or_else(|| "none".to_string()),
+            ),
+            _ => (
+                format!("py{major}{minor}"),
+                soabi_tag.unwrap_or_else(|| "none".to_string()),
+            ),
+        };
+        let platform_tag = self.info.platform.replace(['-', '.'], "_");
+        WheelFilename::from_str(&format!(
+            "{}-{version}-{python_tag}-{abi_tag}-{platform_tag}.whl",
+            name.as_dist_info_name()
+        ))
+        .map_err(|err| BuildHookError::Tags(self.python.clone(), err))
+    }
+
+    /// Run the build hook in the source tree, writing its artifacts to the output directory.
+    pub(crate) fn run(
+        &self,
+        command: &[String],
+        source_tree: &Path,
+        output_dir: &Path,
+    ) -> Result<(), BuildHookError> {
+        let [program, args @ ..] = command else {
+            return Err(BuildHookError::EmptyCommand);
+        };
+        let command_display = command.join(" ");
+        debug!("Running build hook: `{command_display}`");
+        let mut cmd = Command::new(program);
+        cmd.args(args)
+            .current_dir(source_tree)
+            .env(EnvVars::UV_BUILD_HOOK_OUTPUT_DIR, output_dir)
+            .env(EnvVars::UV_BUILD_HOOK_PYTHON, &self.python);
+        if let Some(ext_suffix) = &self.info.ext_suffix {
+            cmd.env(EnvVars::UV_BUILD_HOOK_EXT_SUFFIX, ext_suffix);
+        }
+        // Point PyO3-based extension modules at the target interpreter.
+        if std::env::var_os(EnvVars::PYO3_PYTHON).is_none() {
+            cmd.env(EnvVars::PYO3_PYTHON, &self.python);
+        }
+        // The build backend reports the built filename on stdout, so forward the hook's stdout to
+        // stderr.
+        let status = cmd
+            .stdout(io::stderr())
+            .status()
+            .map_err(|err| BuildHookError::Spawn(command_display.clone(), err))?;
+        if !status.success() {
+            return Err(BuildHookError::Failed(command_display, status));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filename(info: InterpreterInfo) -> String {
+        let target = BuildHookTarget {
+            python: PathBuf::from("python"),
+            info,
+        };
+        target
+            .wheel_filename(
+                &PackageName::from_str("foo-bar").unwrap(),
+                &Version::from_str("1.0.0").unwrap(),
+            )
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn wheel_tags() {
+        assert_eq!(
+            filename(InterpreterInfo {
+                implementation: "cpython".to_string(),
+                version: (3, 13),
+                abiflags: "t".to_string(),
+                soabi: Some("cpython-313t-x86_64-linux-gnu".to_string()),
+                ext_suffix: Some(".cpython-313t-x86_64-linux-gnu.so".to_string()),
+                platform: "linux-x86_64".to_string(),
+            }),
+            "foo_bar-1.0.0-cp313-cp313t-linux_x86_64.whl"
+        );
+        assert_eq!(
+            filename(InterpreterInfo {
+                implementation: "cpython".to_string(),
+                version: (3, 12),
+                abiflags: String::new(),
+                soabi: Some("cpython-312-darwin".to_string()),
+                ext_suffix: Some(".cpython-312-darwin.so".to_string()),
+                platform: "macosx-11.0-arm64".to_string(),
+            }),
+            "foo_bar-1.0.0-cp312-cp312-macosx_11_0_arm64.whl"
+        );
+        assert_eq!(
+            filename(InterpreterInfo {
+                implementation: "pypy".to_string(),
+                version: (3, 10),
+                abiflags: String::new(),
+                soabi: Some("pypy310-pp73".to_string()),
+                ext_suffix: Some(".pypy310-pp73-win_amd64.pyd".to_string()),
+                platform: "win-amd64".to_string(),
+            }),
+            "foo_bar-1.0.0-pp310-pypy310_pp73-win_amd64.whl"
+        );
+    }
+}
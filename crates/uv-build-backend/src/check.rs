@@ -0,0 +1,504 @@
+//! Sanity checks for built wheels and source distributions, run by `uv build --check`.
+//!
+//! The checks apply to distributions built by any build backend, they catch problems that would
+//! otherwise only surface when uploading or installing the distribution.
+
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use flate2::bufread::GzDecoder;
+use fs_err::File;
+use rustc_hash::{FxHashMap, FxHashSet};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use thiserror::Error;
+use tracing::debug;
+
+use crate::Error as BuildBackendError;
+
+/// The largest distribution that passes the checks, PyPI's default upload limit.
+pub const MAX_DISTRIBUTION_SIZE: u64 = 100 * 1024 * 1024;
+
+/// The smallest timestamp a zip file can store, 1980-01-01T00:00:00Z.
+const MIN_ZIP_TIMESTAMP: u64 = 315_532_800;
+
+/// Directories that should never be part of a distribution.
+const FORBIDDEN_DIRECTORIES: &[&str] = &[
+    ".git",
+    ".hg",
+    ".svn",
+    ".venv",
+    ".tox",
+    ".nox",
+    "__pycache__",
+    ".mypy_cache",
+    ".pytest_cache",
+    ".ruff_cache",
+];
+
+/// Files that should never be part of a distribution, as they are either build leftovers or may
+/// contain credentials.
+const FORBIDDEN_FILES: &[&str] = &[".DS_Store", ".env", ".pypirc", "pyvenv.cfg"];
+
+/// A problem found in a built distribution.
+#[derive(Debug, Error)]
+pub enum ArtifactCheckError {
+    #[error("`{0}` is an absolute path")]
+    AbsolutePath(String),
+    #[error("`{0}` points outside of the archive")]
+    ParentPath(String),
+    #[error("`{0}` links to `{1}`, which is outside of the archive")]
+    LinkOutside(String, String),
+    #[error("`{0}` should not be part of a distribution")]
+    Forbidden(String),
+    #[error("`{0}` has the timestamp {1}, which is newer than `SOURCE_DATE_EPOCH` ({2})")]
+    Timestamp(String, u64, u64),
+    #[error(
+        "The distribution is {0} bytes, which exceeds the size budget of {MAX_DISTRIBUTION_SIZE} bytes"
+    )]
+    TooLarge(u64),
+    #[error("Expected a single top-level directory, found `{0}` and `{1}`")]
+    MultipleTopLevel(String, String),
+    #[error("Missing `PKG-INFO` in the top-level directory")]
+    MissingPkgInfo,
+    #[error("Expected exactly one `.dist-info` directory, found {0}")]
+    DistInfoCount(usize),
+    #[error("Missing `{0}`")]
+    MissingRecord(String),
+    #[error("Invalid `RECORD` entry for `{0}`: {1}")]
+    InvalidRecord(String, String),
+    #[error("`{0}` is not listed in `RECORD`")]
+    NotInRecord(String),
+    #[error("`{0}` is listed in `RECORD`, but missing from the wheel")]
+    MissingFromWheel(String),
+    #[error("The hash of `{0}` doesn't match `RECORD`")]
+    HashMismatch(String),
+    #[error("The size of `{0}` doesn't match `RECORD`: expected {1} bytes, found {2} bytes")]
+    SizeMismatch(String, u64, u64),
+}
+
+/// Check a built wheel for absolute paths, consistency with its `RECORD`, timestamps newer than
+/// `source_date_epoch`, the size budget and forbidden files.
+pub fn check_wheel(
+    wheel: &Path,
+    source_date_epoch: Option<u64>,
+) -> Result<Vec<ArtifactCheckError>, BuildBackendError> {
+    let mut failures = Vec::new();
+    check_size(wheel, &mut failures)?;
+
+    let zip_error = |err| BuildBackendError::ZipRead(wheel.to_path_buf(), err);
+    let mut archive = zip::ZipArchive::new(File::open(wheel)?).map_err(zip_error)?;
+
+    // Read all files for the comparison with the `RECORD`.
+    let mut files: FxHashMap<String, Vec<u8>> = FxHashMap::default();
+    let mut dist_info_dirs = FxHashSet::default();
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).map_err(zip_error)?;
+        let name = file.name().to_string();
+        check_path(&name, &mut failures);
+        if let Some(epoch) = source_date_epoch {
+            if let Some(timestamp) = file.last_modified().and_then(zip_timestamp) {
+                // Zip files can't represent timestamps before 1980.
+                if timestamp > epoch.max(MIN_ZIP_TIMESTAMP) {
+                    failures.push(ArtifactCheckError::Timestamp(
+                        name.clone(),
+                        timestamp,
+                        epoch,
+                    ));
+                }
+            }
+        }
+        if let Some((top_level, _)) = name.split_once('/') {
+            if top_level.ends_with(".dist-info") {
+                dist_info_dirs.insert(top_level.to_string());
+            }
+        }
+        if file.is_dir() {
+            continue;
+        }
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+        files.insert(name, contents);
+    }
+
+    let dist_info_dirs: Vec<String> = dist_info_dirs.into_iter().collect();
+    let [dist_info_dir] = dist_info_dirs.as_slice() else {
+        failures.push(ArtifactCheckError::DistInfoCount(dist_info_dirs.len()));
+        return Ok(failures);
+    };
+    let record_path = format!("{dist_info_dir}/RECORD");
+    let Some(record) = files.get(&record_path) else {
+        failures.push(ArtifactCheckError::MissingRecord(record_path));
+        return Ok(failures);
+    };
+    check_record(&record_path, record, &files, &mut failures);
+
+    Ok(failures)
+}
+
+/// Check a built source distribution for absolute paths and links, the archive layout,
+/// timestamps newer than `source_date_epoch`, the size budget and forbidden files.
+///
+/// Only `.tar.gz` source distributions are checked.
+pub fn check_source_dist(
+    source_dist: &Path,
+    source_date_epoch: Option<u64>,
+) -> Result<Vec<ArtifactCheckError>, BuildBackendError> {
+    let mut failures = Vec::new();
+    if !source_dist
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".tar.gz"))
+    {
+        debug!(
+            "Skipping checks for source distribution that isn't a `.tar.gz`: {}",
+            source_dist.display()
+        );
+        return Ok(failures);
+    }
+    check_size(source_dist, &mut failures)?;
+
+    let reader = BufReader::new(File::open(source_dist)?);
+    let mut archive = tar::Archive::new(GzDecoder::new(reader));
+    let mut top_level: Option<String> = None;
+    let mut multiple_top_level = false;
+    let mut has_pkg_info = false;
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+        check_path(&name, &mut failures);
+
+        if let Some(link) = entry.link_name_bytes() {
+            let link = String::from_utf8_lossy(&link).into_owned();
+            if is_absolute(&link) || escapes_archive(&name, &link) {
+                failures.push(ArtifactCheckError::LinkOutside(name.clone(), link));
+            }
+        }
+
+        if let Some(epoch) = source_date_epoch {
+            let timestamp = entry.header().mtime()?;
+            if timestamp > epoch {
+                failures.push(ArtifactCheckError::Timestamp(
+                    name.clone(),
+                    timestamp,
+                    epoch,
+                ));
+            }
+        }
+
+        let (first, rest) = name.split_once('/').unwrap_or((&name, ""));
+        match &top_level {
+            Some(top_level) if top_level != first => {
+                if !multiple_top_level {
+                    failures.push(ArtifactCheckError::MultipleTopLevel(
+                        top_level.clone(),
+                        first.to_string(),
+                    ));
+                    multiple_top_level = true;
+                }
+            }
+            Some(_) => {}
+            None => top_level = Some(first.to_string()),
+        }
+        if rest == "PKG-INFO" {
+            has_pkg_info = true;
+        }
+    }
+    if !has_pkg_info {
+        failures.push(ArtifactCheckError::MissingPkgInfo);
+    }
+
+    Ok(failures)
+}
+
+/// The URL-safe base64 encoded digest of a file, if the algorithm is supported.
+fn digest(algorithm: &str, contents: &[u8]) -> Option<String> {
+    let digest = match algorithm {
+        "sha256" => Sha256::digest(contents).to_vec(),
+        "sha384" => Sha384::digest(contents).to_vec(),
+        "sha512" => Sha512::digest(contents).to_vec(),
+        _ => return None,
+    };
+    Some(BASE64_URL_SAFE_NO_PAD.encode(digest))
+}
+
+/// Compare the files in the wheel against its `RECORD`.
+fn check_record(
+    record_path: &str,
+    record: &[u8],
+    files: &FxHashMap<String, Vec<u8>>,
+    failures: &mut Vec<ArtifactCheckError>,
+) {
+    let mut recorded = FxHashSet::default();
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(record);
+    for row in reader.records() {
+        let row = match row {
+            Ok(row) => row,
+            Err(err) => {
+                failures.push(ArtifactCheckError::InvalidRecord(
+                    record_path.to_string(),
+                    err.to_string(),
+                ));
+                continue;
+            }
+        };
+        let path = row.get(0).unwrap_or_default().to_string();
+        let hash = row.get(1).unwrap_or_default();
+        let size = row.get(2).unwrap_or_default();
+        recorded.insert(path.clone());
+
+        // The `RECORD` can't contain its own hash, and signatures of the `RECORD` are created
+        // afterwards.
+        if path == record_path {
+            continue;
+        }
+        let Some(contents) = files.get(&path) else {
+            failures.push(ArtifactCheckError::MissingFromWheel(path));
+            continue;
+        };
+        let Some((algorithm, expected)) = hash.split_once('=') else {
+            failures.push(ArtifactCheckError::InvalidRecord(
+                path,
+                format!("Missing hash in `{hash}`"),
+            ));
+            continue;
+        };
+        match digest(algorithm, contents) {
+            Some(actual) if actual == expected => {}
+            Some(_) => failures.push(ArtifactCheckError::HashMismatch(path.clone())),
+            None => failures.push(ArtifactCheckError::InvalidRecord(
+                path.clone(),
+                format!("Unsupported hash algorithm `{algorithm}`"),
+            )),
+        }
+        match size.parse::<u64>() {
+            Ok(expected) if expected == contents.len() as u64 => {}
+            Ok(expected) => failures.push(ArtifactCheckError::SizeMismatch(
+                path,
+                expected,
+                contents.len() as u64,
+            )),
+            Err(_) => failures.push(ArtifactCheckError::InvalidRecord(
+                path,
+                format!("Invalid size `{size}`"),
+            )),
+        }
+    }
+
+    let signatures = [format!("{record_path}.jws"), format!("{record_path}.p7s")];
+    let mut unrecorded: Vec<_> = files
+        .keys()
+        .filter(|name| !recorded.contains(*name) && !signatures.contains(name))
+        .collect();
+    unrecorded.sort();
+    for name in unrecorded {
+        failures.push(ArtifactCheckError::NotInRecord(name.clone()));
+    }
+}
+
+/// Check that the path of an archive member stays inside the archive and isn't forbidden.
+fn check_path(name: &str, failures: &mut Vec<ArtifactCheckError>) {
+    if is_absolute(name) {
+        failures.push(ArtifactCheckError::AbsolutePath(name.to_string()));
+    } else if name.split(['/', '\\']).any(|component| component == "..") {
+        failures.push(ArtifactCheckError::ParentPath(name.to_string()));
+    }
+
+    let components: Vec<&str> = name.split(['/', '\\']).collect();
+    let forbidden = if let Some(position) = components
+        .iter()
+        .position(|component| FORBIDDEN_DIRECTORIES.contains(component))
+    {
+        format!("{}/", components[..=position].join("/"))
+    } else if components
+        .last()
+        .is_some_and(|file_name| FORBIDDEN_FILES.contains(file_name))
+    {
+        name.to_string()
+    } else {
+        return;
+    };
+    // Report forbidden directories once, not for every file they contain.
+    if !failures
+        .iter()
+        .any(|failure| matches!(failure, ArtifactCheckError::Forbidden(path) if *path == forbidden))
+    {
+        failures.push(ArtifactCheckError::Forbidden(forbidden));
+    }
+}
+
+/// Whether a path is absolute on any platform.
+fn is_absolute(path: &str) -> bool {
+    path.starts_with(['/', '\\'])
+        || path
+            .as_bytes()
+            .get(..2)
+            .is_some_and(|prefix| prefix[0].is_ascii_alphabetic() && prefix[1] == b':')
+}
+
+/// Whether a relative link target resolves to a path outside of the archive.
+fn escapes_archive(name: &str, link: &str) -> bool {
+    let mut depth: usize = name
+        .split(['/', '\\'])
+        .filter(|component| !component.is_empty())
+        .count()
+        .saturating_sub(1);
+    for component in link.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                let Some(parent) = depth.checked_sub(1) else {
+                    return true;
+                };
+                depth = parent;
+            }
+            _ => depth += 1,
+        }
+    }
+    false
+}
+
+/// Check the size of the distribution file against the size budget.
+fn check_size(path: &Path, failures: &mut Vec<ArtifactCheckError>) -> Result<(), io::Error> {
+    let size = fs_err::metadata(path)?.len();
+    if size > MAX_DISTRIBUTION_SIZE {
+        failures.push(ArtifactCheckError::TooLarge(size));
+    }
+    Ok(())
+}
+
+/// Convert a zip timestamp, which has no timezone, to seconds since the epoch, assuming UTC as
+/// wheel builders do.
+fn zip_timestamp(datetime: zip::DateTime) -> Option<u64> {
+    let days = days_from_civil(
+        i64::from(datetime.year()),
+        i64::from(datetime.month()),
+        i64::from(datetime.day()),
+    );
+    let seconds = days * 86400
+        + i64::from(datetime.hour()) * 3600
+        + i64::from(datetime.minute()) * 60
+        + i64::from(datetime.second());
+    u64::try_from(seconds).ok()
+}
+
+/// The number of days since 1970-01-01 for a date in the proleptic Gregorian calendar.
+///
+/// See: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_failures(name: &str) -> Vec<String> {
+        let mut failures = Vec::new();
+        check_path(name, &mut failures);
+        failures.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn forbidden_directory_reported_once() {
+        let mut failures = Vec::new();
+        check_path("foo-1.0/.git/HEAD", &mut failures);
+        check_path("foo-1.0/.git/objects/ab/cdef", &mut failures);
+        let failures: Vec<String> = failures.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            failures,
+            ["`foo-1.0/.git/` should not be part of a distribution"]
+        );
+    }
+
+    #[test]
+    fn paths() {
+        assert!(path_failures("foo/__init__.py").is_empty());
+        assert!(path_failures("foo-1.0.dist-info/RECORD").is_empty());
+        assert_eq!(
+            path_failures("/etc/passwd"),
+            ["`/etc/passwd` is an absolute path"]
+        );
+        assert_eq!(
+            path_failures("C:/Windows/foo.dll"),
+            ["`C:/Windows/foo.dll` is an absolute path"]
+        );
+        assert_eq!(
+            path_failures("foo/../../bar.py"),
+            ["`foo/../../bar.py` points outside of the archive"]
+        );
+        assert_eq!(
+            path_failures("foo/__pycache__/"),
+            ["`foo/__pycache__/` should not be part of a distribution"]
+        );
+        assert_eq!(
+            path_failures("foo/__pycache__/bar.cpython-312.pyc"),
+            ["`foo/__pycache__/` should not be part of a distribution"]
+        );
+        assert_eq!(
+            path_failures("foo-1.0/.env"),
+            ["`foo-1.0/.env` should not be part of a distribution"]
+        );
+    }
+
+    #[test]
+    fn links() {
+        assert!(!escapes_archive("foo-1.0/bin/tool", "../lib/tool"));
+        assert!(escapes_archive("foo-1.0/bin/tool", "../../../usr/bin/tool"));
+        assert!(escapes_archive("foo-1.0/tool", "../../tool"));
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(
+            zip_timestamp(zip::DateTime::from_date_and_time(1980, 1, 1, 0, 0, 0).unwrap()),
+            Some(MIN_ZIP_TIMESTAMP)
+        );
+        assert_eq!(
+            zip_timestamp(zip::DateTime::from_date_and_time(2024, 2, 29, 12, 30, 10).unwrap()),
+            Some(1_709_209_810)
+        );
+    }
+
+    #[test]
+    fn record() {
+        let files: FxHashMap<String, Vec<u8>> = [
+            ("foo/__init__.py", b"print('hello')\n".to_vec()),
+            ("foo/extra.py", b"".to_vec()),
+            ("foo-1.0.dist-info/RECORD", Vec::new()),
+        ]
+        .into_iter()
+        .map(|(name, contents)| (name.to_string(), contents))
+        .collect();
+        let hash = digest("sha256", &files["foo/__init__.py"]).unwrap();
+        let record = format!(
+            "foo/__init__.py,sha256={hash},16\nfoo/missing.py,sha256=abc,0\nfoo-1.0.dist-info/RECORD,,\n"
+        );
+        let mut failures = Vec::new();
+        check_record(
+            "foo-1.0.dist-info/RECORD",
+            record.as_bytes(),
+            &files,
+            &mut failures,
+        );
+        let failures: Vec<String> = failures.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            failures,
+            [
+                "The size of `foo/__init__.py` doesn't match `RECORD`: expected 16 bytes, found 15 bytes",
+                "`foo/missing.py` is listed in `RECORD`, but missing from the wheel",
+                "`foo/extra.py` is not listed in `RECORD`"
+            ]
+        );
+    }
+}
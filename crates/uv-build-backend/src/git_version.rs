@@ -0,0 +1,125 @@
+//! Derive a dynamic project version from the Git tags of the repository containing the project.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use thiserror::Error;
+use tracing::debug;
+
+use uv_pep440::{LocalSegment, Prerelease, Version, VersionParseError};
+
+#[derive(Debug, Error)]
+pub enum GitVersionError {
+    #[error("Failed to run `git`, is Git installed?")]
+    Spawn(#[source] io::Error),
+    #[error("`git describe --tags` failed, does the repository have a tag?\n{0}")]
+    Describe(String),
+    #[error("Unexpected output from `git describe --tags`: `{0}`")]
+    InvalidDescribe(String),
+    #[error("Git tag `{0}` is not a valid version")]
+    InvalidTag(String, #[source] VersionParseError),
+}
+
+/// Determine the version from the most recent Git tag reachable from the current commit.
+pub(crate) fn git_version(root: &Path) -> Result<Version, GitVersionError> {
+    let output = Command::new("git")
+        .args(["describe", "--tags", "--long", "--dirty", "--abbrev=7"])
+        .current_dir(root)
+        .output()
+        .map_err(GitVersionError::Spawn)?;
+    if !output.status.success() {
+        return Err(GitVersionError::Describe(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    let describe = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    debug!("Deriving version from `git describe` output: `{describe}`");
+    version_from_describe(&describe)
+}
+
+/// Convert the output of `git describe --tags --long --dirty` to a version.
+///
+/// The output has the format `<tag>-<distance>-g<hash>[-dirty]`, where the tag itself may contain
+/// dashes.
+fn version_from_describe(describe: &str) -> Result<Version, GitVersionError> {
+    let invalid = || GitVersionError::InvalidDescribe(describe.to_string());
+
+    let (describe_clean, dirty) = match describe.strip_suffix("-dirty") {
+        Some(describe_clean) => (describe_clean, true),
+        None => (describe, false),
+    };
+    let (rest, hash) = describe_clean.rsplit_once('-').ok_or_else(invalid)?;
+    let (tag, distance) = rest.rsplit_once('-').ok_or_else(invalid)?;
+    let distance = u64::from_str(distance).map_err(|_| invalid())?;
+    let hash = hash.strip_prefix('g').ok_or_else(invalid)?;
+
+    let tag_version = tag
+        .strip_prefix(['v', 'V'])
+        .unwrap_or(tag)
+        .parse::<Version>()
+        .map_err(|err| GitVersionError::InvalidTag(tag.to_string(), err))?;
+
+    if distance == 0 && !dirty {
+        return Ok(tag_version);
+    }
+
+    // Guess the next version: Bump the pre-release if the tag is a pre-release, otherwise bump
+    // the last release segment.
+    let next = if let Some(pre) = tag_version.pre() {
+        tag_version.only_release().with_pre(Some(Prerelease {
+            kind: pre.kind,
+            number: pre.number + 1,
+        }))
+    } else {
+        let mut release = tag_version.release().to_vec();
+        if let Some(last) = release.last_mut() {
+            *last += 1;
+        }
+        tag_version.only_release().with_release(release)
+    };
+
+    let mut local = vec![LocalSegment::String(format!("g{hash}"))];
+    if dirty {
+        local.push(LocalSegment::String("dirty".to_string()));
+    }
+    Ok(next
+        .with_epoch(tag_version.epoch())
+        .with_dev(Some(distance))
+        .with_local_segments(local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(describe: &str) -> String {
+        match version_from_describe(describe) {
+            Ok(version) => version.to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(version("v1.2.3-0-g1a2b3c4"), "1.2.3");
+        assert_eq!(version("1.2.3-0-g1a2b3c4"), "1.2.3");
+        assert_eq!(version("v1.2.3-3-g1a2b3c4"), "1.2.4.dev3+g1a2b3c4");
+        assert_eq!(
+            version("v1.2.3-0-g1a2b3c4-dirty"),
+            "1.2.4.dev0+g1a2b3c4.dirty"
+        );
+        assert_eq!(version("v2.0.0rc1-5-g1a2b3c4"), "2.0.0rc2.dev5+g1a2b3c4");
+        assert_eq!(version("v1.0.post1-2-g1a2b3c4"), "1.1.dev2+g1a2b3c4");
+        assert_eq!(version("1!2-1-g1a2b3c4"), "1!3.dev1+g1a2b3c4");
+        assert_eq!(
+            version("release-1.0-0-g1a2b3c4"),
+            "Git tag `release-1.0` is not a valid version"
+        );
+        assert_eq!(
+            version("v1.0"),
+            "Unexpected output from `git describe --tags`: `v1.0`"
+        );
+    }
+}
@@ -1,15 +1,19 @@
 use itertools::Itertools;
+mod build_hook;
+mod check;
+mod git_version;
 mod metadata;
 mod serde_verbatim;
 mod settings;
 mod source_dist;
 mod wheel;
 
+pub use check::{ArtifactCheckError, MAX_DISTRIBUTION_SIZE, check_source_dist, check_wheel};
 pub use metadata::{PyProjectToml, check_direct_build};
-pub use settings::{BuildBackendSettings, WheelDataIncludes};
+pub use settings::{BuildBackendSettings, VersionSource, WheelDataIncludes};
 pub use source_dist::{build_source_dist, list_source_dist};
 use uv_warnings::warn_user_once;
-pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
+pub use wheel::{build_editable, build_wheel, list_wheel, metadata, record_source_distribution};
 
 use std::collections::HashSet;
 use std::ffi::OsStr;
@@ -23,8 +27,11 @@ use walkdir::DirEntry;
 use uv_fs::Simplified;
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
-use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_pep440::VersionParseError;
+use uv_pypi_types::{Identifier, IdentifierParseError, MetadataError};
 
+use crate::build_hook::BuildHookError;
+use crate::git_version::GitVersionError;
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
 
@@ -38,6 +45,14 @@ pub enum Error {
     Toml(PathBuf, #[source] toml::de::Error),
     #[error("Invalid project metadata")]
     Validation(#[from] ValidationError),
+    #[error(transparent)]
+    BuildHook(#[from] BuildHookError),
+    #[error("Failed to determine the project version from Git in: {}", _0.user_display())]
+    GitVersion(PathBuf, #[source] GitVersionError),
+    #[error("Invalid metadata in: {}", _0.user_display())]
+    PkgInfo(PathBuf, #[source] MetadataError),
+    #[error("Invalid version in: {}", _0.user_display())]
+    PkgInfoVersion(PathBuf, #[source] VersionParseError),
     #[error("Invalid module name: {0}")]
     InvalidModuleName(String, #[source] IdentifierParseError),
     #[error("Unsupported glob expression in: {field}")]
@@ -63,6 +78,10 @@ pub enum Error {
     },
     #[error("Failed to write wheel zip archive")]
     Zip(#[from] zip::result::ZipError),
+    #[error("Failed to read wheel zip archive: {}", _0.user_display())]
+    ZipRead(PathBuf, #[source] zip::result::ZipError),
+    #[error("Missing `.dist-info/RECORD` in wheel: {}", _0.user_display())]
+    MissingWheelRecord(PathBuf),
     #[error("Failed to write RECORD file")]
     Csv(#[from] csv::Error),
     #[error("Failed to write JSON metadata file")]
@@ -1825,4 +1844,152 @@ mod tests {
         metadata_json_preview/__init__.py
         ");
     }
+
+    /// Include files next to the module in the wheel and the source distribution.
+    #[test]
+    fn wheel_include() {
+        let src = TempDir::new().unwrap();
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            indoc! {r#"
+            [project]
+            name = "wheel-include"
+            version = "1.0.0"
+
+            [tool.uv.build-backend]
+            wheel-include = ["wheel_include.pth", "wheel_include_data/**"]
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+        "#
+            },
+        )
+        .unwrap();
+        let module_root = src.path().join("src");
+        fs_err::create_dir_all(module_root.join("wheel_include")).unwrap();
+        File::create(module_root.join("wheel_include").join("__init__.py")).unwrap();
+        File::create(module_root.join("wheel_include.pth")).unwrap();
+        File::create(module_root.join("unrelated.txt")).unwrap();
+        fs_err::create_dir_all(module_root.join("wheel_include_data").join("nested")).unwrap();
+        File::create(
+            module_root
+                .join("wheel_include_data")
+                .join("nested")
+                .join("data.json"),
+        )
+        .unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = build(src.path(), dist.path(), Preview::default()).unwrap();
+        assert_snapshot!(build.source_dist_contents.join("\n"), @"
+        wheel_include-1.0.0/
+        wheel_include-1.0.0/PKG-INFO
+        wheel_include-1.0.0/pyproject.toml
+        wheel_include-1.0.0/src
+        wheel_include-1.0.0/src/wheel_include
+        wheel_include-1.0.0/src/wheel_include.pth
+        wheel_include-1.0.0/src/wheel_include/__init__.py
+        wheel_include-1.0.0/src/wheel_include_data
+        wheel_include-1.0.0/src/wheel_include_data/nested
+        wheel_include-1.0.0/src/wheel_include_data/nested/data.json
+        ");
+        assert_snapshot!(build.wheel_contents.join("\n"), @"
+        wheel_include-1.0.0.dist-info/
+        wheel_include-1.0.0.dist-info/METADATA
+        wheel_include-1.0.0.dist-info/RECORD
+        wheel_include-1.0.0.dist-info/WHEEL
+        wheel_include.pth
+        wheel_include/
+        wheel_include/__init__.py
+        wheel_include_data/
+        wheel_include_data/nested/
+        wheel_include_data/nested/data.json
+        ");
+    }
+
+    /// Read a dynamic version from Git tags, and from `PKG-INFO` in the source distribution.
+    #[test]
+    fn git_version() {
+        let src = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=uv", "-c", "user.email=uv@example.com"])
+                .args(["-c", "commit.gpgsign=false", "-c", "tag.gpgsign=false"])
+                .args(args)
+                .current_dir(src.path())
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            indoc! {r#"
+            [project]
+            name = "git-version"
+            dynamic = ["version"]
+
+            [tool.uv.build-backend]
+            version-source = "git"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+        "#
+            },
+        )
+        .unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("git_version")).unwrap();
+        File::create(
+            src.path()
+                .join("src")
+                .join("git_version")
+                .join("__init__.py"),
+        )
+        .unwrap();
+
+        git(&["init", "--quiet"]);
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "Initial commit"]);
+
+        let dist = TempDir::new().unwrap();
+        let err = build(src.path(), dist.path(), Preview::default()).unwrap_err();
+        assert_snapshot!(
+            format_err(&err).lines().take(2).join("\n").replace(&src.path().user_display().to_string(), "[TEMP_PATH]"),
+            @"
+        Failed to determine the project version from Git in: [TEMP_PATH]
+          Caused by: `git describe --tags` failed, does the repository have a tag?
+        "
+        );
+
+        git(&["tag", "v1.2.3"]);
+        let build = build(src.path(), dist.path(), Preview::default()).unwrap();
+        assert_eq!(
+            build.source_dist_filename.to_string(),
+            "git_version-1.2.3.tar.gz"
+        );
+        assert_eq!(
+            build.wheel_filename.to_string(),
+            "git_version-1.2.3-py3-none-any.whl"
+        );
+
+        fs_err::write(
+            src.path()
+                .join("src")
+                .join("git_version")
+                .join("__init__.py"),
+            "x = 1",
+        )
+        .unwrap();
+        git(&["commit", "--quiet", "--all", "-m", "Second commit"]);
+        let (filename, _) = list_source_dist(src.path(), MOCK_UV_VERSION, false).unwrap();
+        let version = filename.version.to_string();
+        assert!(
+            Regex::new(r"^1\.2\.4\.dev1\+g[0-9a-f]{7}$")
+                .unwrap()
+                .is_match(&version),
+            "{version}"
+        );
+    }
 }
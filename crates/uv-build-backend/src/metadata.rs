@@ -19,9 +19,11 @@ use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{
     ExtraOperator, MarkerExpression, MarkerTree, MarkerValueExtra, Requirement, VersionOrUrl,
 };
-use uv_pypi_types::{Keywords, Metadata23, ProjectUrls, VerbatimParsedUrl};
+use uv_pypi_types::{Keywords, Metadata10, Metadata23, ProjectUrls, VerbatimParsedUrl};
 
+use crate::git_version::git_version;
 use crate::serde_verbatim::SerdeVerbatim;
+use crate::settings::VersionSource;
 use crate::{BuildBackendSettings, Error, error_on_venv};
 
 /// By default, we ignore generated python files.
@@ -45,8 +47,18 @@ pub enum ValidationError {
     UnsupportedContentType(String),
     #[error("`project.description` must be a single line")]
     DescriptionNewlines,
-    #[error("Dynamic metadata is not supported")]
-    Dynamic,
+    #[error("Dynamic metadata is only supported for `version`, found: `{0}`")]
+    Dynamic(String),
+    #[error("`project.version` is required, unless `version` is listed in `project.dynamic`")]
+    MissingVersion,
+    #[error("`project.version` must not be set when `version` is listed in `project.dynamic`")]
+    StaticAndDynamicVersion,
+    #[error(
+        "`version` is listed in `project.dynamic`, but `tool.uv.build-backend.version-source` is not set"
+    )]
+    MissingVersionSource,
+    #[error("`version` is listed in `project.dynamic`, but it has not been resolved")]
+    UnresolvedVersion,
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -73,6 +85,24 @@ pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
     #[serde(rename_all = "kebab-case")]
     struct PyProjectToml {
         build_system: BuildSystem,
+        tool: Option<Tool>,
+    }
+
+    #[derive(Deserialize)]
+    struct Tool {
+        uv: Option<ToolUv>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct ToolUv {
+        build_backend: Option<BuildBackend>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct BuildBackend {
+        build_hook: Option<toml::Value>,
     }
 
     let pyproject_toml: PyProjectToml =
@@ -90,9 +120,24 @@ pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
                 return false;
             }
         };
+
+    // Build hooks need to know the Python interpreter of the build environment, which requires
+    // a PEP 517 build.
+    if pyproject_toml
+        .tool
+        .and_then(|tool| tool.uv)
+        .and_then(|uv| uv.build_backend)
+        .is_some_and(|build_backend| build_backend.build_hook.is_some())
+    {
+        debug!(
+            "Not using uv build backend direct build for source tree `{name}`, it has a build hook"
+        );
+        return false;
+    }
+
     match pyproject_toml
         .build_system
-        .check_build_system(uv_version::version())
+        .check_build_system(uv_version::version(), false)
         .as_slice()
     {
         // No warnings -> match
@@ -154,17 +199,78 @@ impl PyProjectToml {
         &self.project.name.normalized
     }
 
-    pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+    /// Returns the project version.
+    ///
+    /// Dynamic versions are resolved when parsing, so the version is only missing if the
+    /// `pyproject.toml` wasn't read through [`PyProjectToml::parse`].
+    pub(crate) fn version(&self) -> Result<&Version, ValidationError> {
+        if let Some(version) = &self.project.version {
+            return Ok(version);
+        }
+        if self
+            .project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "version")
+        {
+            Err(ValidationError::UnresolvedVersion)
+        } else {
+            Err(ValidationError::MissingVersion)
+        }
     }
 
     pub(crate) fn parse(path: &Path) -> Result<Self, Error> {
         let contents = fs_err::read_to_string(path)?;
-        let pyproject_toml =
+        let mut pyproject_toml: Self =
             toml::from_str(&contents).map_err(|err| Error::Toml(path.to_path_buf(), err))?;
+        let root = path.parent().unwrap_or(Path::new(""));
+        pyproject_toml.resolve_version(root)?;
         Ok(pyproject_toml)
     }
 
+    /// Resolve a dynamic `project.version` from the configured version source.
+    ///
+    /// When building from a source distribution, the version is read from its `PKG-INFO` instead,
+    /// since the VCS metadata isn't included in source distributions.
+    fn resolve_version(&mut self, root: &Path) -> Result<(), Error> {
+        let dynamic_version = self
+            .project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "version");
+        match (&self.project.version, dynamic_version) {
+            (Some(_), false) => return Ok(()),
+            (Some(_), true) => return Err(ValidationError::StaticAndDynamicVersion.into()),
+            (None, false) => return Err(ValidationError::MissingVersion.into()),
+            (None, true) => {}
+        }
+
+        let pkg_info = root.join("PKG-INFO");
+        let version = if pkg_info.is_file() {
+            let contents = fs_err::read(&pkg_info)?;
+            let metadata = Metadata10::parse_pkg_info(&contents)
+                .map_err(|err| Error::PkgInfo(pkg_info.clone(), err))?;
+            let version = Version::from_str(&metadata.version)
+                .map_err(|err| Error::PkgInfoVersion(pkg_info.clone(), err))?;
+            debug!("Using version from `PKG-INFO`: {version}");
+            version
+        } else {
+            match self.settings().and_then(|settings| settings.version_source) {
+                Some(VersionSource::Git) => {
+                    let version = git_version(root)
+                        .map_err(|err| Error::GitVersion(root.to_path_buf(), err))?;
+                    debug!("Using version from Git: {version}");
+                    version
+                }
+                None => return Err(ValidationError::MissingVersionSource.into()),
+            }
+        };
+        self.project.version = Some(version);
+        Ok(())
+    }
+
     pub(crate) fn readme(&self) -> Option<&Readme> {
         self.project.readme.as_ref()
     }
@@ -202,7 +308,12 @@ impl PyProjectToml {
 
     /// See [`BuildSystem::check_build_system`].
     pub fn check_build_system(&self, uv_version: &str) -> Vec<String> {
-        self.build_system.check_build_system(uv_version)
+        // A build hook may require additional build dependencies.
+        let additional_requires = self
+            .settings()
+            .is_some_and(|settings| settings.build_hook.is_some());
+        self.build_system
+            .check_build_system(uv_version, additional_requires)
     }
 
     /// Validate and convert a `pyproject.toml` to core metadata.
@@ -270,13 +381,14 @@ impl PyProjectToml {
             None => (None, None),
         };
 
-        if self
+        if let Some(field) = self
             .project
             .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
+            .iter()
+            .flatten()
+            .find(|field| *field != "version")
         {
-            return Err(ValidationError::Dynamic.into());
+            return Err(ValidationError::Dynamic(field.clone()).into());
         }
 
         let author = self
@@ -390,7 +502,7 @@ impl PyProjectToml {
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.given.clone(),
-            version: self.project.version.to_string(),
+            version: self.version()?.to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -660,7 +772,9 @@ struct Project {
     /// The name of the project.
     name: VerbatimPackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// Resolved from `tool.uv.build-backend.version-source` if listed in `dynamic`.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -709,7 +823,7 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     ///
-    /// Not supported, an error if anything but the default empty list.
+    /// Only `version` is supported, an error if anything but `version` or the default empty list.
     dynamic: Option<Vec<String>>,
 }
 
@@ -834,7 +948,14 @@ impl BuildSystem {
     /// requires = ["uv_build>=0.4.15,<0.5.0"]
     /// build-backend = "uv_build"
     /// ```
-    pub(crate) fn check_build_system(&self, uv_version: &str) -> Vec<String> {
+    ///
+    /// If `additional_requires` is set, `build-system.requires` may contain requirements other
+    /// than the uv requirement.
+    pub(crate) fn check_build_system(
+        &self,
+        uv_version: &str,
+        additional_requires: bool,
+    ) -> Vec<String> {
         let mut warnings = Vec::new();
         if self.build_backend.as_deref() != Some("uv_build") {
             warnings.push(format!(
@@ -855,7 +976,16 @@ impl BuildSystem {
             )
         };
 
-        let [uv_requirement] = &self.requires.as_slice() else {
+        let uv_requirement = if additional_requires {
+            self.requires
+                .iter()
+                .find(|requirement| requirement.name.as_str() == "uv-build")
+        } else if let [uv_requirement] = self.requires.as_slice() {
+            Some(uv_requirement)
+        } else {
+            None
+        };
+        let Some(uv_requirement) = uv_requirement else {
             warnings.push(expected());
             return warnings;
         };
@@ -1319,6 +1449,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_system_build_hook() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            version = "0.1.0"
+
+            [tool.uv.build-backend]
+            build-hook = ["python", "build_ext.py"]
+
+            [build-system]
+            requires = ["cffi", "uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+        "#};
+        let pyproject_toml: PyProjectToml = toml::from_str(contents).unwrap();
+        assert_snapshot!(
+            pyproject_toml.check_build_system("0.4.15+test").join("\n"),
+            @""
+        );
+    }
+
     #[test]
     fn build_system_no_requires_uv() {
         let contents = indoc! {r#"
@@ -1495,7 +1646,57 @@ mod tests {
             .unwrap_err();
         assert_snapshot!(format_err(err), @"
         Invalid project metadata
-          Caused by: Dynamic metadata is not supported
+          Caused by: Dynamic metadata is only supported for `version`, found: `dependencies`
+        ");
+    }
+
+    #[test]
+    fn dynamic_version_without_source() {
+        let temp_dir = TempDir::new().unwrap();
+        fs_err::write(
+            temp_dir.path().join("pyproject.toml"),
+            indoc! {r#"
+                [project]
+                name = "hello-world"
+                dynamic = ["version"]
+
+                [build-system]
+                requires = ["uv_build>=0.4.15,<0.5.0"]
+                build-backend = "uv_build"
+            "#
+            },
+        )
+        .unwrap();
+
+        let err = PyProjectToml::parse(&temp_dir.path().join("pyproject.toml")).unwrap_err();
+        assert_snapshot!(format_err(err), @"
+        Invalid project metadata
+          Caused by: `version` is listed in `project.dynamic`, but `tool.uv.build-backend.version-source` is not set
+        ");
+    }
+
+    /// A dynamic version that wasn't resolved (since the `pyproject.toml` wasn't read through
+    /// [`PyProjectToml::parse`]) is an error, not a panic.
+    #[test]
+    fn dynamic_version_unresolved() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            dynamic = ["version"]
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+        "#
+        };
+
+        let err = toml::from_str::<PyProjectToml>(contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap_err();
+        assert_snapshot!(format_err(err), @"
+        Invalid project metadata
+          Caused by: `version` is listed in `project.dynamic`, but it has not been resolved
         ");
     }
 
@@ -88,6 +88,19 @@ pub struct BuildBackendSettings {
     )]
     pub wheel_exclude: Vec<String>,
 
+    /// Glob expressions which files and directories to additionally include in the wheel,
+    /// relative to `module-root`.
+    ///
+    /// The contents of the module directory are always included. Use this option for package data
+    /// that lives next to the module directory, such as a `.pth` file or an additional top-level
+    /// module. Matching files are also included in the source distribution.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"wheel-include = ["foo.pth", "foo_data/**"]"#
+    )]
+    pub wheel_include: Vec<String>,
+
     /// Build a namespace package.
     ///
     /// Build a PEP 420 implicit namespace package, allowing more than one root `__init__.py`.
@@ -171,6 +184,44 @@ pub struct BuildBackendSettings {
         example = r#"data = { headers = "include/headers", scripts = "bin" }"#
     )]
     pub data: WheelDataIncludes,
+
+    /// A command to build extension modules, run in the project directory before assembling the
+    /// wheel.
+    ///
+    /// The command is given as a list of the program and its arguments. It's run with the Python
+    /// interpreter of the build environment in `UV_BUILD_HOOK_PYTHON` (and `PYO3_PYTHON`, unless
+    /// already set) and the extension module filename suffix for that interpreter in
+    /// `UV_BUILD_HOOK_EXT_SUFFIX`, e.g., `.cpython-312-x86_64-linux-gnu.so`. All files the
+    /// command writes to the directory in `UV_BUILD_HOOK_OUTPUT_DIR` are added to the wheel,
+    /// relative to the wheel root. For editable installs, `UV_BUILD_HOOK_OUTPUT_DIR` is the
+    /// module root instead, so that the extension modules are built in place.
+    ///
+    /// Wheels built with a build hook are tagged for the Python version, ABI, and platform of the
+    /// build interpreter. Any tools the command requires, such as `cffi` or `cmake`, need to be
+    /// added to `build-system.requires`.
+    #[option(
+        default = r#"None"#,
+        value_type = "list[str]",
+        example = r#"build-hook = ["python", "build_ext.py"]"#
+    )]
+    pub build_hook: Option<Vec<String>>,
+
+    /// The source of the project version, if `version` is listed in `project.dynamic`.
+    ///
+    /// With `git`, the version is derived from the most recent tag reachable from the current
+    /// commit, as reported by `git describe --tags`, with an optional `v` prefix removed. If the
+    /// current commit is tagged and the working tree is clean, the tag is the version. Otherwise,
+    /// the version is a development release of the next version with the commit hash as local
+    /// version, e.g., `1.2.4.dev3+g1a2b3c4` three commits after `v1.2.3`.
+    ///
+    /// The resolved version is recorded in the source distribution, so building a wheel from a
+    /// source distribution doesn't require Git.
+    #[option(
+        default = r#"None"#,
+        value_type = "str",
+        example = r#"version-source = "git""#
+    )]
+    pub version_source: Option<VersionSource>,
 }
 
 impl Default for BuildBackendSettings {
@@ -182,8 +233,11 @@ impl Default for BuildBackendSettings {
             default_excludes: true,
             source_exclude: Vec::new(),
             wheel_exclude: Vec::new(),
+            wheel_include: Vec::new(),
             namespace: false,
             data: WheelDataIncludes::default(),
+            build_hook: None,
+            version_source: None,
         }
     }
 }
@@ -199,6 +253,15 @@ pub enum ModuleName {
     Names(Vec<String>),
 }
 
+/// The source of a dynamic project version.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum VersionSource {
+    /// Derive the version from the Git tags of the repository containing the project.
+    Git,
+}
+
 /// Data includes for wheels.
 ///
 /// See `BuildBackendSettings::data`.
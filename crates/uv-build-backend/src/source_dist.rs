@@ -30,7 +30,7 @@ pub fn build_source_dist(
     let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
     let source_dist_path = source_dist_directory.join(filename.to_string());
@@ -58,7 +58,7 @@ pub fn list_source_dist(
     let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
     let mut files = FileList::new();
@@ -100,6 +100,17 @@ fn source_dist_matcher(
         .to_string();
         includes.push(format!("{}/**", globset::escape(&import_path)));
     }
+    // The wheel includes are relative to the module root.
+    let module_root = uv_fs::normalize_path(&settings.module_root)
+        .portable_display()
+        .to_string();
+    for include in &settings.wheel_include {
+        if module_root.is_empty() {
+            includes.push(include.clone());
+        } else {
+            includes.push(format!("{}/{include}", globset::escape(&module_root)));
+        }
+    }
     for include in includes {
         let glob = PortableGlobParser::Uv
             .parse(&include)
@@ -208,14 +219,14 @@ fn write_source_dist(
 
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
 
     let top_level = format!(
         "{}-{}",
         pyproject_toml.name().as_dist_info_name(),
-        pyproject_toml.version()
+        pyproject_toml.version()?
     );
 
     let metadata = pyproject_toml.to_metadata(source_tree)?;
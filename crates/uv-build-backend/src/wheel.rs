@@ -7,7 +7,7 @@ use std::fmt::{Display, Formatter};
 use std::io::{BufReader, Read, Seek, Write};
 use std::path::{Component, Path, PathBuf};
 use std::{io, mem};
-use tempfile::NamedTempFile;
+use tempfile::{NamedTempFile, TempDir};
 use tracing::{debug, trace};
 use walkdir::WalkDir;
 use zip::{CompressionMethod, ZipWriter};
@@ -19,6 +19,7 @@ use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
 use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user_once;
 
+use crate::build_hook::BuildHookTarget;
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
@@ -40,16 +41,13 @@ pub fn build_wheel(
     }
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let build_hook = pyproject_toml
+        .settings()
+        .and_then(|settings| settings.build_hook.as_deref());
+    let hook_target = build_hook
+        .map(|_| BuildHookTarget::from_env())
+        .transpose()?;
+    let filename = wheel_filename(&pyproject_toml, hook_target.as_ref())?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
@@ -58,6 +56,15 @@ pub fn build_wheel(
         fs_err::remove_file(&wheel_path)?;
     }
 
+    // Build the extension modules into a temporary directory.
+    let hook_output = if let (Some(command), Some(hook_target)) = (build_hook, &hook_target) {
+        let hook_output = tempfile::tempdir()?;
+        hook_target.run(command, source_tree, hook_output.path())?;
+        Some(hook_output)
+    } else {
+        None
+    };
+
     let temp_file = NamedTempFile::new_in(wheel_dir)?;
     let wheel_writer = ZipDirectoryWriter::new_wheel(temp_file.as_file());
 
@@ -65,6 +72,7 @@ pub fn build_wheel(
         source_tree,
         &pyproject_toml,
         &filename,
+        hook_output.as_ref().map(TempDir::path),
         uv_version,
         wheel_writer,
         show_warnings,
@@ -78,7 +86,9 @@ pub fn build_wheel(
     Ok(filename)
 }
 
-/// List the files that would be included in a source distribution and their origin.
+/// List the files that would be included in a wheel and their origin.
+///
+/// The build hook is not run, so files generated by the build hook are not listed.
 pub fn list_wheel(
     source_tree: &Path,
     uv_version: &str,
@@ -90,16 +100,7 @@ pub fn list_wheel(
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let filename = wheel_filename(&pyproject_toml, None)?;
 
     let mut files = FileList::new();
     let writer = ListWriter::new(&mut files);
@@ -107,6 +108,7 @@ pub fn list_wheel(
         source_tree,
         &pyproject_toml,
         &filename,
+        None,
         uv_version,
         writer,
         show_warnings,
@@ -119,6 +121,7 @@ fn write_wheel(
     source_tree: &Path,
     pyproject_toml: &PyProjectToml,
     filename: &WheelFilename,
+    hook_output: Option<&Path>,
     uv_version: &str,
     mut wheel_writer: impl DirectoryWriter,
     show_warnings: bool,
@@ -161,9 +164,35 @@ fn write_wheel(
         show_warnings,
     )?;
 
+    // Files generated by the build hook take precedence over files in the source tree, such as
+    // leftovers from an editable build.
+    let mut hook_files = Vec::new();
+    if let Some(hook_output) = hook_output {
+        for entry in WalkDir::new(hook_output).sort_by_file_name() {
+            let entry = entry.map_err(|err| Error::WalkDir {
+                root: hook_output.to_path_buf(),
+                err,
+            })?;
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            let entry_path = entry
+                .path()
+                .strip_prefix(hook_output)
+                .expect("walkdir starts with root")
+                .to_path_buf();
+            hook_files.push((entry_path, entry.into_path()));
+        }
+    }
+    let is_hook_file = |entry_path: &Path| {
+        hook_files
+            .iter()
+            .any(|(hook_file, _)| hook_file.as_path() == entry_path)
+    };
+
     let mut files_visited = 0;
     let mut prefix_directories = FxHashSet::default();
-    for module_relative in module_relative {
+    for module_relative in &module_relative {
         // For convenience, have directories for the whole tree in the wheel
         for ancestor in module_relative.ancestors().skip(1) {
             if ancestor == Path::new("") {
@@ -207,9 +236,19 @@ fn write_wheel(
                 trace!("Excluding from module: {}", match_path.user_display());
                 continue;
             }
+            if is_hook_file(entry_path) {
+                trace!(
+                    "Replacing with build hook output: {}",
+                    match_path.user_display()
+                );
+                continue;
+            }
 
             error_on_venv(entry.file_name(), entry.path())?;
 
+            if entry.file_type().is_dir() {
+                prefix_directories.insert(entry_path.to_path_buf());
+            }
             let entry_path = entry_path.portable_display().to_string();
             debug!("Adding to wheel: {entry_path}");
             wheel_writer.write_dir_entry(&entry, &entry_path)?;
@@ -217,13 +256,99 @@ fn write_wheel(
     }
     debug!("Visited {files_visited} files for wheel build");
 
+    // Add the additional includes next to the module
+    if !settings.wheel_include.is_empty() {
+        debug!("Adding wheel includes");
+        let include_globs = settings
+            .wheel_include
+            .iter()
+            .map(|include| {
+                PortableGlobParser::Uv
+                    .parse(include)
+                    .map_err(|err| Error::PortableGlob {
+                        field: "tool.uv.build-backend.wheel-include".to_string(),
+                        source: err,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let include_matcher =
+            GlobDirFilter::from_globs(&include_globs).map_err(|err| Error::GlobSetTooLarge {
+                field: "tool.uv.build-backend.wheel-include".to_string(),
+                source: err,
+            })?;
+
+        for entry in WalkDir::new(&src_root)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                let match_path = entry
+                    .path()
+                    .strip_prefix(source_tree)
+                    .expect("walkdir starts with root");
+                let entry_path = entry
+                    .path()
+                    .strip_prefix(&src_root)
+                    .expect("walkdir starts with root");
+                // The modules themselves were already added above.
+                !exclude_matcher.is_match(match_path)
+                    && !module_relative.iter().any(|module| module == entry_path)
+                    && include_matcher.match_directory(entry_path)
+            })
+        {
+            let entry = entry.map_err(|err| Error::WalkDir {
+                root: source_tree.to_path_buf(),
+                err,
+            })?;
+            let entry_path = entry
+                .path()
+                .strip_prefix(&src_root)
+                .expect("walkdir starts with root");
+            // Directories are created as parents of the included files.
+            if entry.file_type().is_dir()
+                || !include_matcher.match_path(entry_path)
+                || is_hook_file(entry_path)
+            {
+                continue;
+            }
+
+            error_on_venv(entry.file_name(), entry.path())?;
+
+            for ancestor in entry_path.ancestors().skip(1) {
+                if ancestor == Path::new("") {
+                    continue;
+                }
+                if prefix_directories.insert(ancestor.to_path_buf()) {
+                    wheel_writer.write_directory(&ancestor.portable_display().to_string())?;
+                }
+            }
+            let entry_path = entry_path.portable_display().to_string();
+            debug!("Adding wheel include: {entry_path}");
+            wheel_writer.write_file(&entry_path, entry.path())?;
+        }
+    }
+
+    // Add the files generated by the build hook
+    for (entry_path, path) in &hook_files {
+        for ancestor in entry_path.ancestors().skip(1) {
+            if ancestor == Path::new("") {
+                continue;
+            }
+            if prefix_directories.insert(ancestor.to_path_buf()) {
+                wheel_writer.write_directory(&ancestor.portable_display().to_string())?;
+            }
+        }
+        let entry_path = entry_path.portable_display().to_string();
+        debug!("Adding build hook output: {entry_path}");
+        wheel_writer.write_file(&entry_path, path)?;
+    }
+
     // Add the license files
     if pyproject_toml.license_files_wheel().next().is_some() {
         debug!("Adding license files");
         let license_dir = format!(
             "{}-{}.dist-info/licenses/",
             pyproject_toml.name().as_dist_info_name(),
-            pyproject_toml.version()
+            pyproject_toml.version()?
         );
 
         wheel_subdir_from_globs(
@@ -254,7 +379,7 @@ fn write_wheel(
         let data_dir = format!(
             "{}-{}.data/{}/",
             pyproject_toml.name().as_dist_info_name(),
-            pyproject_toml.version(),
+            pyproject_toml.version()?,
             name
         );
 
@@ -281,6 +406,26 @@ fn write_wheel(
     Ok(())
 }
 
+/// The wheel filename, tagged for the build hook target if the project has a build hook.
+fn wheel_filename(
+    pyproject_toml: &PyProjectToml,
+    hook_target: Option<&BuildHookTarget>,
+) -> Result<WheelFilename, Error> {
+    if let Some(hook_target) = hook_target {
+        return Ok(hook_target.wheel_filename(pyproject_toml.name(), pyproject_toml.version()?)?);
+    }
+    Ok(WheelFilename::new(
+        pyproject_toml.name().clone(),
+        pyproject_toml.version()?.clone(),
+        LanguageTag::Python {
+            major: 3,
+            minor: None,
+        },
+        AbiTag::None,
+        PlatformTag::Any,
+    ))
+}
+
 /// Build a wheel from the source tree and place it in the output directory.
 pub fn build_editable(
     source_tree: &Path,
@@ -301,16 +446,13 @@ pub fn build_editable(
 
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let build_hook = pyproject_toml
+        .settings()
+        .and_then(|settings| settings.build_hook.as_deref());
+    let hook_target = build_hook
+        .map(|_| BuildHookTarget::from_env())
+        .transpose()?;
+    let filename = wheel_filename(&pyproject_toml, hook_target.as_ref())?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
@@ -333,6 +475,11 @@ pub fn build_editable(
         show_warnings,
     )?;
 
+    // Build the extension modules in place, next to the sources linked from the `.pth` file.
+    if let (Some(command), Some(hook_target)) = (build_hook, &hook_target) {
+        hook_target.run(command, source_tree, &src_root)?;
+    }
+
     wheel_writer.write_bytes(
         &format!("{}.pth", pyproject_toml.name().as_dist_info_name()),
         src_root.as_os_str().as_encoded_bytes(),
@@ -368,16 +515,12 @@ pub fn metadata(
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let hook_target = pyproject_toml
+        .settings()
+        .and_then(|settings| settings.build_hook.as_ref())
+        .map(|_| BuildHookTarget::from_env())
+        .transpose()?;
+    let filename = wheel_filename(&pyproject_toml, hook_target.as_ref())?;
 
     debug!(
         "Writing metadata files to {}",
@@ -397,6 +540,110 @@ pub fn metadata(
     Ok(dist_info_dir)
 }
 
+/// Record the source distribution a wheel was built from in the wheel's `.dist-info` directory.
+///
+/// Adds `source_distribution.json` with the filename and SHA-256 hash of the source distribution
+/// to the wheel and updates the `RECORD`. All other files are copied unchanged, so this works for
+/// wheels built by any build backend.
+pub fn record_source_distribution(
+    wheel_path: &Path,
+    source_dist_filename: &str,
+    source_dist_sha256: &str,
+) -> Result<(), Error> {
+    let mut archive = zip::ZipArchive::new(BufReader::new(File::open(wheel_path)?))
+        .map_err(|err| Error::ZipRead(wheel_path.to_path_buf(), err))?;
+
+    // The `.dist-info` directory is the top-level directory containing the `RECORD`.
+    let Some((record_path, dist_info_dir)) = archive.file_names().find_map(|name| {
+        let dir = name.strip_suffix("/RECORD")?;
+        (dir.ends_with(".dist-info") && !dir.contains('/'))
+            .then(|| (name.to_string(), dir.to_string()))
+    }) else {
+        return Err(Error::MissingWheelRecord(wheel_path.to_path_buf()));
+    };
+    let provenance_path = format!("{dist_info_dir}/source_distribution.json");
+
+    let mut record = Vec::new();
+    let last_modified = {
+        let mut file = archive
+            .by_name(&record_path)
+            .map_err(|err| Error::ZipRead(wheel_path.to_path_buf(), err))?;
+        file.read_to_end(&mut record)?;
+        file.last_modified()
+    };
+
+    let provenance = serde_json::to_vec_pretty(&serde_json::json!({
+        "filename": source_dist_filename,
+        "hashes": {
+            "sha256": source_dist_sha256,
+        },
+    }))
+    .map_err(Error::Json)?;
+
+    let wheel_dir = wheel_path.parent().unwrap_or_else(|| Path::new("."));
+    let temp_file = NamedTempFile::new_in(wheel_dir)?;
+    let mut writer = ZipWriter::new(temp_file.as_file());
+    for index in 0..archive.len() {
+        let file = archive
+            .by_index_raw(index)
+            .map_err(|err| Error::ZipRead(wheel_path.to_path_buf(), err))?;
+        if file.name() == record_path || file.name() == provenance_path {
+            continue;
+        }
+        writer.raw_copy_file(file)?;
+    }
+
+    // Use the timestamp of the `RECORD` for the new files, to keep reproducible builds
+    // reproducible.
+    let mut options = zip::write::SimpleFileOptions::default()
+        .unix_permissions(0o644)
+        .compression_method(CompressionMethod::Deflated);
+    if let Some(last_modified) = last_modified {
+        options = options.last_modified_time(last_modified);
+    }
+    trace!("Adding {provenance_path}");
+    writer.start_file(&provenance_path, options)?;
+    writer.write_all(&provenance)?;
+
+    // Copy the existing `RECORD` entries, then add the new file and the `RECORD` itself.
+    trace!("Adding {record_path}");
+    writer.start_file(&record_path, options)?;
+    {
+        let mut record_writer = csv::Writer::from_writer(&mut writer);
+        let mut record_reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(record.as_slice());
+        for entry in record_reader.records() {
+            let entry = entry?;
+            if entry
+                .get(0)
+                .is_some_and(|path| path == record_path || path == provenance_path)
+            {
+                continue;
+            }
+            record_writer.write_record(&entry)?;
+        }
+        record_writer.write_record(&[
+            provenance_path,
+            format!(
+                "sha256={}",
+                base64.encode(Sha256::new().chain_update(&provenance).finalize())
+            ),
+            provenance.len().to_string(),
+        ])?;
+        record_writer.write_record(&[record_path, String::new(), String::new()])?;
+        record_writer.flush()?;
+    }
+    writer.finish()?;
+
+    temp_file
+        .persist(wheel_path)
+        .map_err(|err| Error::Persist(wheel_path.to_path_buf(), err.error))?;
+
+    Ok(())
+}
+
 /// An entry in the `RECORD` file.
 ///
 /// <https://packaging.python.org/en/latest/specifications/recording-installed-packages/#the-record-file>
@@ -600,7 +847,7 @@ fn write_dist_info(
     let dist_info_dir = format!(
         "{}-{}.dist-info",
         pyproject_toml.name().as_dist_info_name(),
-        pyproject_toml.version()
+        pyproject_toml.version()?
     );
 
     writer.write_directory(&dist_info_dir)?;
@@ -667,7 +914,8 @@ impl WheelInfo {
         Self {
             wheel_version: "1.0".to_string(),
             generator: format!("uv {uv_version}"),
-            root_is_purelib: true,
+            // Wheels with extension modules from a build hook are platform-specific.
+            root_is_purelib: filename.platform_tags() == [PlatformTag::Any],
             tags,
         }
     }
@@ -984,4 +1232,67 @@ mod test {
         Tag: py3-none-any
         ");
     }
+
+    /// Record the source distribution in a built wheel, keeping the `RECORD` consistent.
+    #[test]
+    fn test_record_source_distribution() {
+        let wheel_dir = TempDir::new().unwrap();
+        let built_by_uv = Path::new("../../test/packages/built-by-uv");
+        let filename = build_wheel(
+            built_by_uv,
+            wheel_dir.path(),
+            None,
+            "1.0.0+test",
+            false,
+            Preview::default(),
+        )
+        .unwrap();
+        let wheel_path = wheel_dir.path().join(filename.to_string());
+
+        record_source_distribution(
+            &wheel_path,
+            "built_by_uv-0.1.0.tar.gz",
+            "d2c0e7f5a0d6b1e3c3c2f8e1a7b9d4c5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1",
+        )
+        .unwrap();
+
+        let failures = crate::check_wheel(&wheel_path, None).unwrap();
+        assert!(failures.is_empty(), "{failures:?}");
+
+        let mut archive =
+            zip::ZipArchive::new(BufReader::new(File::open(&wheel_path).unwrap())).unwrap();
+        let mut provenance = String::new();
+        archive
+            .by_name("built_by_uv-0.1.0.dist-info/source_distribution.json")
+            .unwrap()
+            .read_to_string(&mut provenance)
+            .unwrap();
+        assert_snapshot!(provenance, @r#"
+        {
+          "filename": "built_by_uv-0.1.0.tar.gz",
+          "hashes": {
+            "sha256": "d2c0e7f5a0d6b1e3c3c2f8e1a7b9d4c5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1"
+          }
+        }
+        "#);
+    }
+
+    /// A wheel without a top-level `.dist-info/RECORD` is rejected.
+    #[test]
+    fn test_record_source_distribution_missing_record() {
+        let wheel_dir = TempDir::new().unwrap();
+        let wheel_path = wheel_dir.path().join("foo-1.0.0-py3-none-any.whl");
+        let mut writer = ZipWriter::new(File::create(&wheel_path).unwrap());
+        // A `RECORD` in a nested `.dist-info` directory doesn't count.
+        writer
+            .start_file(
+                "foo/bar-1.0.0.dist-info/RECORD",
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.finish().unwrap();
+
+        let err = record_source_distribution(&wheel_path, "foo-1.0.0.tar.gz", "0").unwrap_err();
+        assert!(matches!(err, Error::MissingWheelRecord(_)), "{err:?}");
+    }
 }
@@ -5,7 +5,7 @@
 mod error;
 mod pipreqs;
 
-use std::borrow::Cow;
+use std::cell::RefCell;
 use std::ffi::OsString;
 use std::fmt::Formatter;
 use std::fmt::Write;
@@ -26,15 +26,16 @@ use serde::{Deserialize, Deserializer};
 use tempfile::TempDir;
 use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
-use tokio::sync::{Mutex, Semaphore};
+use tokio::sync::{OnceCell, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, warn};
 use uv_auth::CredentialsCache;
-use uv_cache_key::cache_digest;
+use uv_cache::CacheBucket;
+use uv_cache_key::{cache_digest, hash_digest};
 use uv_configuration::{BuildKind, BuildOutput, NoSources};
 use uv_distribution::BuildRequires;
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations, Requirement,
-    Resolution,
+    ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations, Name, Requirement,
+    Resolution, ResolvedDist,
 };
 use uv_fs::{LockedFile, LockedFileMode};
 use uv_fs::{PythonExt, Simplified};
@@ -42,7 +43,7 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_preview::Preview;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{Interpreter, PythonEnvironment};
+use uv_python::{Interpreter, PythonEnvironment, canonicalize_executable};
 use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBuildTrait};
 use uv_warnings::warn_user_once;
@@ -218,8 +219,28 @@ impl Pep517Backend {
 /// Uses an [`Rc`] internally, clone freely.
 #[derive(Debug, Default, Clone)]
 pub struct SourceBuildContext {
-    /// An in-memory resolution of the default backend's requirements for PEP 517 builds.
-    default_resolution: Rc<Mutex<Option<Resolution>>>,
+    /// The resolved build requirements, shared by all builds with the same interpreter and build
+    /// requirements.
+    requirements: Rc<RefCell<FxHashMap<BuildRequirementsKey, Rc<OnceCell<BuildRequirements>>>>>,
+}
+
+/// Resolved build requirements, alongside the cached environment they're installed into.
+#[derive(Debug, Clone)]
+struct BuildRequirements {
+    /// The resolution of the build requirements.
+    resolution: Resolution,
+    /// The environment in the cache with the build requirements installed, if the resolution can
+    /// be cached (i.e., it doesn't include any local distributions, which may change at any time).
+    environment: Option<PythonEnvironment>,
+}
+
+/// The identity of a resolution of build requirements.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct BuildRequirementsKey {
+    /// The base interpreter of the build environment.
+    interpreter: PathBuf,
+    /// The build requirements.
+    requirements: Vec<Requirement>,
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -237,6 +258,8 @@ pub struct SourceBuild {
     project: Option<Project>,
     /// The virtual environment in which to build the source distribution.
     venv: PythonEnvironment,
+    /// The cached environment with the build requirements, layered underneath `venv`, if any.
+    requirements_environment: Option<PythonEnvironment>,
     /// Populated if `prepare_metadata_for_build_wheel` was called.
     ///
     /// > If the build frontend has previously called `prepare_metadata_for_build_wheel` and depends
@@ -371,28 +394,37 @@ impl SourceBuild {
 
         // Set up the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
+        let mut requirements_environment = None;
+        let mut build_requirements = pep517_backend.requirements.clone();
+        build_requirements.extend(extra_build_dependencies.iter().cloned());
         if build_isolation.is_isolated(package_name.as_ref()) {
-            debug!("Resolving build requirements");
-
             let dependency_sources = if extra_build_dependencies.is_empty() {
                 "`build-system.requires`"
             } else {
                 "`build-system.requires` and `extra-build-dependencies`"
             };
 
+            // Every build gets its own environment, such that a build can't affect any other
+            // build, but the build requirements are installed once, into an environment in the
+            // cache that's shared by all builds with the same requirements.
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
-                source_build_context,
-                &pep517_backend,
-                extra_build_dependencies,
+                &source_build_context,
+                interpreter,
+                build_requirements.clone(),
+                dependency_sources,
+                build_stack,
+                preview,
+            )
+            .await?;
+            requirements_environment = Self::use_requirements(
+                build_context,
+                &venv,
+                &resolved_requirements,
+                dependency_sources,
                 build_stack,
             )
             .await?;
-
-            build_context
-                .install(&resolved_requirements, &venv, build_stack)
-                .await
-                .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
         } else {
             debug!("Proceeding without build isolation");
         }
@@ -405,7 +437,7 @@ impl SourceBuild {
         let os_path = env::var_os(EnvVars::PATH);
 
         // Prepend the user supplied PATH to the existing OS PATH
-        let modified_path = if let Some(user_path) = user_path {
+        let base_path = if let Some(user_path) = user_path {
             match os_path {
                 // Prepend the user supplied PATH to the existing PATH
                 Some(env_path) => {
@@ -419,14 +451,9 @@ impl SourceBuild {
         } else {
             os_path
         };
-
-        // Prepend the venv bin directory to the modified path
-        let modified_path = if let Some(path) = modified_path {
-            let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(&path));
-            env::join_paths(venv_path).map_err(Error::BuildScriptPath)?
-        } else {
-            OsString::from(venv.scripts())
-        };
+        let mut modified_path =
+            Self::modified_path(&venv, requirements_environment.as_ref(), base_path.as_ref())
+                .map_err(Error::BuildScriptPath)?;
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
@@ -434,20 +461,18 @@ impl SourceBuild {
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            let extra_requires = get_extra_requires(
                 &runner,
                 &source_tree,
                 install_path,
                 &venv,
                 &pep517_backend,
-                build_context,
                 package_name.as_ref(),
                 package_version.as_ref(),
                 version_id,
                 locations,
                 no_sources,
                 workspace_cache,
-                build_stack,
                 build_kind,
                 level,
                 &config_settings,
@@ -457,6 +482,36 @@ impl SourceBuild {
                 credentials_cache,
             )
             .await?;
+
+            // If necessary, install the extra requirements alongside the build requirements.
+            if !extra_requires.is_empty() {
+                debug!("Installing extra requirements for build backend");
+                build_requirements.extend(extra_requires);
+                let resolved_requirements = Self::get_resolved_requirements(
+                    build_context,
+                    &source_build_context,
+                    interpreter,
+                    build_requirements,
+                    "`build-system.requires`",
+                    build_stack,
+                    preview,
+                )
+                .await?;
+                requirements_environment = Self::use_requirements(
+                    build_context,
+                    &venv,
+                    &resolved_requirements,
+                    "`build-system.requires`",
+                    build_stack,
+                )
+                .await?;
+                modified_path = Self::modified_path(
+                    &venv,
+                    requirements_environment.as_ref(),
+                    base_path.as_ref(),
+                )
+                .map_err(Error::BuildScriptPath)?;
+            }
         }
 
         Ok(Self {
@@ -465,6 +520,7 @@ impl SourceBuild {
             pep517_backend,
             project,
             venv,
+            requirements_environment,
             build_kind,
             level,
             config_settings,
@@ -478,6 +534,39 @@ impl SourceBuild {
         })
     }
 
+    /// Pass additional environment variables to the build backend, e.g., to target a different
+    /// platform.
+    ///
+    /// Unlike the variables passed to [`SourceBuild::setup`], these are only visible to the hooks
+    /// that run after setup, like `build_wheel`, and not to `get_requires_for_build_wheel`.
+    #[must_use]
+    pub fn with_environment_variables(
+        mut self,
+        environment_variables: impl IntoIterator<Item = (OsString, OsString)>,
+    ) -> Self {
+        self.environment_variables.extend(environment_variables);
+        self
+    }
+
+    /// The build backend, e.g., `setuptools.build_meta`.
+    pub fn backend(&self) -> &str {
+        &self.pep517_backend.backend
+    }
+
+    /// The environment in which the source distribution is built.
+    ///
+    /// For isolated builds, the build requirements are usually installed into
+    /// [`SourceBuild::requirements_environment`] instead.
+    pub fn environment(&self) -> &PythonEnvironment {
+        &self.venv
+    }
+
+    /// The cached environment with the build requirements, which is layered underneath
+    /// [`SourceBuild::environment`] for isolated builds.
+    pub fn requirements_environment(&self) -> Option<&PythonEnvironment> {
+        self.requirements_environment.as_ref()
+    }
+
     /// Acquire a lock on the source tree, if necessary.
     async fn acquire_lock(&self) -> Result<Option<LockedFile>, Error> {
         // Depending on the command, setuptools puts `*.egg-info`, `build/`, and `dist/` in the
@@ -507,52 +596,197 @@ impl SourceBuild {
         Ok(source_tree_lock)
     }
 
+    /// Prepend the scripts directories of the build environment and the environment with the
+    /// build requirements to the `PATH`.
+    fn modified_path(
+        venv: &PythonEnvironment,
+        requirements_environment: Option<&PythonEnvironment>,
+        base_path: Option<&OsString>,
+    ) -> Result<OsString, env::JoinPathsError> {
+        let scripts = iter::once(venv.scripts().to_path_buf())
+            .chain(requirements_environment.map(|environment| environment.scripts().to_path_buf()));
+        if let Some(path) = base_path {
+            env::join_paths(scripts.chain(env::split_paths(path)))
+        } else {
+            env::join_paths(scripts)
+        }
+    }
+
+    /// Make the build requirements available in the build environment.
+    ///
+    /// If the build requirements were installed into a cached environment, the cached environment
+    /// is added to the build environment's `site-packages` via a `.pth` file; otherwise, they're
+    /// installed into the build environment directly.
+    async fn use_requirements(
+        build_context: &impl BuildContext,
+        venv: &PythonEnvironment,
+        requirements: &BuildRequirements,
+        dependency_sources: &'static str,
+        build_stack: &BuildStack,
+    ) -> Result<Option<PythonEnvironment>, Error> {
+        let Some(environment) = &requirements.environment else {
+            build_context
+                .install(&requirements.resolution, venv, build_stack)
+                .await
+                .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+            return Ok(None);
+        };
+
+        // Like `uv run --with`, use a `.pth` file rather than `PYTHONPATH`, such that `.pth` files
+        // in the cached environment are processed too.
+        let overlay = format!(
+            "import site; {}",
+            environment
+                .site_packages()
+                .map(|path| format!("site.addsitedir(\"{}\")", path.escape_for_python()))
+                .join("; ")
+        );
+        let site_packages = venv.site_packages().next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "Build environment has no site packages directory",
+            )
+        })?;
+        fs::write(site_packages.join("_uv_build_requirements.pth"), overlay)?;
+
+        Ok(Some(environment.clone()))
+    }
+
+    /// Resolve the build requirements and install them into an environment in the cache,
+    /// reusing the result of any previous build with the same interpreter and requirements.
+    ///
+    /// Cached environments are keyed by the resolved requirements and the interpreter, such that
+    /// they're shared across uv invocations, and are never modified after they were created.
     async fn get_resolved_requirements(
         build_context: &impl BuildContext,
-        source_build_context: SourceBuildContext,
-        pep517_backend: &Pep517Backend,
-        extra_build_dependencies: Vec<Requirement>,
+        source_build_context: &SourceBuildContext,
+        interpreter: &Interpreter,
+        requirements: Vec<Requirement>,
+        dependency_sources: &'static str,
         build_stack: &BuildStack,
-    ) -> Result<Resolution, Error> {
-        Ok(
-            if pep517_backend.requirements == DEFAULT_BACKEND.requirements
-                && extra_build_dependencies.is_empty()
-            {
-                let mut resolution = source_build_context.default_resolution.lock().await;
-                if let Some(resolved_requirements) = &*resolution {
-                    resolved_requirements.clone()
-                } else {
-                    let resolved_requirements = build_context
-                        .resolve(&DEFAULT_BACKEND.requirements, build_stack)
-                        .await
-                        .map_err(|err| {
+        preview: Preview,
+    ) -> Result<BuildRequirements, Error> {
+        let key = BuildRequirementsKey {
+            interpreter: interpreter.sys_executable().to_path_buf(),
+            requirements,
+        };
+        let resolved = source_build_context
+            .requirements
+            .borrow_mut()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        if let Some(resolved) = resolved.get() {
+            debug!("Reusing resolution of build requirements");
+            return Ok(resolved.clone());
+        }
+
+        let resolved = resolved
+            .get_or_try_init(|| async {
+                debug!("Resolving build requirements");
+                let resolution = build_context
+                    .resolve(&key.requirements, build_stack)
+                    .await
+                    .map_err(|err| {
+                        if key.requirements == DEFAULT_BACKEND.requirements {
                             Error::RequirementsResolve("`setup.py` build", err.into())
-                        })?;
-                    *resolution = Some(resolved_requirements.clone());
-                    resolved_requirements
+                        } else {
+                            Error::RequirementsResolve(dependency_sources, err.into())
+                        }
+                    })?;
+
+                // Local distributions may change without changing the resolution, so they're
+                // installed into each build environment instead.
+                if resolution.distributions().any(ResolvedDist::is_local) {
+                    debug!("Not caching build environment with local build requirements");
+                    return Ok(BuildRequirements {
+                        resolution,
+                        environment: None,
+                    });
                 }
-            } else {
-                let (requirements, dependency_sources) = if extra_build_dependencies.is_empty() {
-                    (
-                        Cow::Borrowed(&pep517_backend.requirements),
-                        "`build-system.requires`",
-                    )
-                } else {
-                    // If there are extra build dependencies, we need to resolve them together with
-                    // the backend requirements.
-                    let mut requirements = pep517_backend.requirements.clone();
-                    requirements.extend(extra_build_dependencies);
-                    (
-                        Cow::Owned(requirements),
-                        "`build-system.requires` and `extra-build-dependencies`",
-                    )
-                };
-                build_context
-                    .resolve(&requirements, build_stack)
-                    .await
-                    .map_err(|err| Error::RequirementsResolve(dependency_sources, err.into()))?
-            },
-        )
+
+                let environment = Self::cached_environment(
+                    build_context,
+                    interpreter,
+                    &resolution,
+                    dependency_sources,
+                    build_stack,
+                    preview,
+                )
+                .await?;
+                Ok::<_, Error>(BuildRequirements {
+                    resolution,
+                    environment: Some(environment),
+                })
+            })
+            .await?;
+        Ok(resolved.clone())
+    }
+
+    /// Get or create the environment in the cache with the given build requirements installed.
+    async fn cached_environment(
+        build_context: &impl BuildContext,
+        interpreter: &Interpreter,
+        resolution: &Resolution,
+        dependency_sources: &'static str,
+        build_stack: &BuildStack,
+        preview: Preview,
+    ) -> Result<PythonEnvironment, Error> {
+        let cache = build_context.cache();
+
+        let resolution_hash = {
+            let mut distributions = resolution.distributions().collect::<Vec<_>>();
+            distributions.sort_unstable_by_key(|dist| dist.name());
+            hash_digest(&distributions)
+        };
+        let interpreter_hash = cache_digest(&(
+            canonicalize_executable(interpreter.sys_executable())?,
+            interpreter.python_full_version().to_string(),
+        ));
+        let cache_entry = cache.entry(
+            CacheBucket::Environments,
+            interpreter_hash,
+            format!("build-{resolution_hash}"),
+        );
+
+        if let Ok(root) = cache.resolve_link(cache_entry.path()) {
+            if let Ok(environment) = PythonEnvironment::from_root(&root, cache) {
+                debug!(
+                    "Reusing build environment at: {}",
+                    environment.root().user_display()
+                );
+                return Ok(environment);
+            }
+        }
+
+        // Create the environment in the cache, then relocate it to its content-addressed location.
+        // The environment is relocatable, since its scripts must keep working once it's moved.
+        let temp_dir = cache.venv_dir()?;
+        let venv = uv_virtualenv::create_venv(
+            temp_dir.path(),
+            interpreter.clone(),
+            uv_virtualenv::Prompt::None,
+            false,
+            uv_virtualenv::OnExisting::Remove(uv_virtualenv::RemovalReason::TemporaryEnvironment),
+            true,
+            false,
+            false,
+            preview,
+        )?;
+
+        build_context
+            .install(resolution, &venv, build_stack)
+            .await
+            .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+
+        let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
+        let environment = PythonEnvironment::from_root(cache.archive(&id), cache)?;
+        debug!(
+            "Created build environment at: {}",
+            environment.root().user_display()
+        );
+        Ok(environment)
     }
 
     /// Extract the PEP 517 backend from the `pyproject.toml` or `setup.py` file.
@@ -937,21 +1171,22 @@ fn escape_path_for_python(path: &Path) -> String {
         .replace('"', "\\\"")
 }
 
+/// Call `get_requires_for_build_*` and return the requirements that the backend requests in
+/// addition to the build requirements.
+///
 /// Not a method because we call it before the builder is completely initialized
-async fn create_pep517_build_environment(
+async fn get_extra_requires(
     runner: &PythonRunner,
     source_tree: &Path,
     install_path: &Path,
     venv: &PythonEnvironment,
     pep517_backend: &Pep517Backend,
-    build_context: &impl BuildContext,
     package_name: Option<&PackageName>,
     package_version: Option<&Version>,
     version_id: Option<&str>,
     locations: &IndexLocations,
     no_sources: NoSources,
     workspace_cache: &WorkspaceCache,
-    build_stack: &BuildStack,
     build_kind: BuildKind,
     level: BuildOutput,
     config_settings: &ConfigSettings,
@@ -959,7 +1194,7 @@ async fn create_pep517_build_environment(
     modified_path: &OsString,
     temp_dir: &TempDir,
     credentials_cache: &CredentialsCache,
-) -> Result<(), Error> {
+) -> Result<Vec<Requirement>, Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -1067,31 +1302,12 @@ async fn create_pep517_build_environment(
     // TODO(konstin): Do we still need this when we have a fast resolver?
     if extra_requires
         .iter()
-        .any(|req| !pep517_backend.requirements.contains(req))
+        .all(|req| pep517_backend.requirements.contains(req))
     {
-        debug!("Installing extra requirements for build backend");
-        let requirements: Vec<_> = pep517_backend
-            .requirements
-            .iter()
-            .cloned()
-            .chain(extra_requires)
-            .collect();
-        let resolution = build_context
-            .resolve(&requirements, build_stack)
-            .await
-            .map_err(|err| {
-                Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
-            })?;
-
-        build_context
-            .install(&resolution, venv, build_stack)
-            .await
-            .map_err(|err| {
-                Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
-            })?;
+        return Ok(Vec::new());
     }
 
-    Ok(())
+    Ok(extra_requires)
 }
 
 /// A runner that manages the execution of external python processes with a
@@ -68,31 +68,40 @@ impl CacheInfo {
         let mut directories = BTreeMap::new();
         let mut env = BTreeMap::new();
 
-        // Read the cache keys.
-        let cache_keys =
+        // Read the cache keys and the build backend.
+        let (cache_keys, build_backend) =
             if let Ok(contents) = fs_err::read_to_string(directory.join("pyproject.toml")) {
                 if let Ok(pyproject_toml) = toml::from_str::<PyProjectToml>(&contents) {
-                    pyproject_toml
-                        .tool
-                        .and_then(|tool| tool.uv)
-                        .and_then(|tool_uv| tool_uv.cache_keys)
+                    (
+                        pyproject_toml
+                            .tool
+                            .and_then(|tool| tool.uv)
+                            .and_then(|tool_uv| tool_uv.cache_keys),
+                        pyproject_toml
+                            .build_system
+                            .and_then(|build_system| build_system.build_backend),
+                    )
                 } else {
-                    None
+                    (None, None)
                 }
             } else {
-                None
+                (None, None)
             };
 
         // If no cache keys were defined, use the defaults.
         let cache_keys = cache_keys.unwrap_or_else(|| {
-            vec![
+            let mut cache_keys = vec![
                 CacheKey::Path(Cow::Borrowed("pyproject.toml")),
                 CacheKey::Path(Cow::Borrowed("setup.py")),
                 CacheKey::Path(Cow::Borrowed("setup.cfg")),
                 CacheKey::Directory {
                     dir: Cow::Borrowed("src"),
                 },
-            ]
+            ];
+            if let Some(build_backend) = build_backend.as_deref() {
+                cache_keys.extend(compiled_backend_cache_keys(build_backend));
+            }
+            cache_keys
         });
 
         // Incorporate timestamps from any direct filepaths.
@@ -222,12 +231,15 @@ impl CacheInfo {
         // If we have any globs, first cluster them using LCP and then do a single pass on each group.
         if !globs.is_empty() {
             for (glob_base, glob_patterns) in cluster_globs(&globs) {
-                let walker = globwalk::GlobWalkerBuilder::from_patterns(
-                    directory.join(glob_base),
-                    &glob_patterns,
-                )
-                .file_type(globwalk::FileType::FILE | globwalk::FileType::SYMLINK)
-                .build()?;
+                // Skip any base directories that don't exist (e.g., a `rust` directory in a
+                // project that keeps its Rust sources in `src`).
+                let glob_base = directory.join(glob_base);
+                if !glob_base.is_dir() {
+                    continue;
+                }
+                let walker = globwalk::GlobWalkerBuilder::from_patterns(glob_base, &glob_patterns)
+                    .file_type(globwalk::FileType::FILE | globwalk::FileType::SYMLINK)
+                    .build()?;
                 for entry in walker {
                     let entry = match entry {
                         Ok(entry) => entry,
@@ -314,13 +326,52 @@ impl CacheInfo {
     }
 }
 
+/// The default cache keys for build backends that compile native code (e.g., Rust or C
+/// extensions), such that editable installs are rebuilt when their sources change.
+///
+/// Only the source directories conventionally used by each backend are traversed, to avoid walking
+/// the entire project (including, e.g., the virtual environment or build directories).
+fn compiled_backend_cache_keys(build_backend: &str) -> Vec<CacheKey> {
+    match build_backend {
+        "maturin" => vec![
+            CacheKey::Path(Cow::Borrowed("Cargo.toml")),
+            CacheKey::Path(Cow::Borrowed("Cargo.lock")),
+            CacheKey::Path(Cow::Borrowed("src/**/*.rs")),
+            CacheKey::Path(Cow::Borrowed("rust/**/*.rs")),
+        ],
+        "mesonpy" => vec![
+            CacheKey::Path(Cow::Borrowed("meson.build")),
+            CacheKey::Path(Cow::Borrowed("meson.options")),
+            CacheKey::Path(Cow::Borrowed("meson_options.txt")),
+            CacheKey::Path(Cow::Borrowed("src/**/meson.build")),
+            CacheKey::Path(Cow::Borrowed(NATIVE_SOURCES)),
+        ],
+        "scikit_build_core.build" => vec![
+            CacheKey::Path(Cow::Borrowed("CMakeLists.txt")),
+            CacheKey::Path(Cow::Borrowed("src/**/CMakeLists.txt")),
+            CacheKey::Path(Cow::Borrowed(NATIVE_SOURCES)),
+        ],
+        _ => vec![],
+    }
+}
+
+/// A glob matching C, C++, Cython, and Fortran sources within the `src` directory.
+const NATIVE_SOURCES: &str = "src/**/*.{c,cc,cpp,cxx,h,hh,hpp,hxx,pyx,pxd,pxi,f,f90}";
+
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct PyProjectToml {
+    build_system: Option<BuildSystem>,
     tool: Option<Tool>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BuildSystem {
+    build_backend: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct Tool {
@@ -444,4 +495,49 @@ mod tests_unix {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cache_info_compiled_backend() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path().join("dir");
+        fs_err::create_dir_all(dir.join("src"))?;
+
+        let touch = |path: &str| -> Result<_> {
+            let path = dir.join(path);
+            fs_err::write(&path, "")?;
+            Ok(Timestamp::from_metadata(&path.metadata()?))
+        };
+
+        let cache_timestamp = || -> Result<_> { Ok(CacheInfo::from_directory(&dir)?.timestamp) };
+
+        // For a pure-Python backend, changes to the sources are ignored.
+        fs_err::write(
+            dir.join("pyproject.toml"),
+            "[build-system]\nbuild-backend = \"hatchling.build\"\n",
+        )?;
+        let pyproject_toml = Timestamp::from_metadata(&dir.join("pyproject.toml").metadata()?);
+        touch("src/lib.rs")?;
+        assert_eq!(cache_timestamp()?, Some(pyproject_toml));
+
+        // For `maturin`, changes to the Rust sources are tracked.
+        fs_err::write(
+            dir.join("pyproject.toml"),
+            "[build-system]\nbuild-backend = \"maturin\"\n",
+        )?;
+        let lib = touch("src/lib.rs")?;
+        assert_eq!(cache_timestamp()?, Some(lib));
+        let cargo_lock = touch("Cargo.lock")?;
+        assert_eq!(cache_timestamp()?, Some(cargo_lock));
+
+        // User-defined cache keys take precedence over the defaults.
+        fs_err::write(
+            dir.join("pyproject.toml"),
+            "[build-system]\nbuild-backend = \"maturin\"\n\n[tool.uv]\ncache-keys = [\"pyproject.toml\"]\n",
+        )?;
+        let pyproject_toml = Timestamp::from_metadata(&dir.join("pyproject.toml").metadata()?);
+        touch("src/lib.rs")?;
+        assert_eq!(cache_timestamp()?, Some(pyproject_toml));
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use std::io;
 use std::path::{Path, PathBuf};
+use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
 use crate::Cache;
@@ -29,6 +30,37 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR, value_hint = ValueHint::DirPath)]
     pub cache_dir: Option<PathBuf>,
+
+    /// The URL of a remote cache to share built and downloaded wheels across machines.
+    ///
+    /// Before building a source distribution, uv will check the remote cache for a wheel built
+    /// from the same source distribution, with the same build settings, for the same
+    /// interpreter and platform. After building a source distribution, uv will upload the
+    /// resulting wheel to the remote cache. Similarly, wheels downloaded from an index are
+    /// shared via the remote cache, keyed by their SHA-256 digest.
+    ///
+    /// The remote cache can be any HTTP server that supports `GET` and `PUT` requests, or an S3
+    /// bucket (e.g., `s3://bucket/prefix`).
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_REMOTE_CACHE,
+        value_hint = ValueHint::Url,
+        hide = true
+    )]
+    pub remote_cache: Option<DisplaySafeUrl>,
+
+    /// Write the cache hit, miss, and eviction counters to the given file as JSON.
+    ///
+    /// Use `-` to write the counters to stderr.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_CACHE_METRICS,
+        value_hint = ValueHint::FilePath,
+        hide = true
+    )]
+    pub cache_metrics: Option<PathBuf>,
 }
 
 impl Cache {
@@ -82,7 +114,7 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Self::from_settings(value.no_cache, value.cache_dir)
+        Ok(Self::from_settings(value.no_cache, value.cache_dir)?.with_remote(value.remote_cache))
     }
 }
 
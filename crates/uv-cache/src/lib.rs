@@ -5,20 +5,24 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
 use uv_cache_info::Timestamp;
 use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, directories};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
+use uv_redacted::DisplaySafeUrl;
 
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::metrics::{CacheEvent, CacheMetrics, CacheMetricsSnapshot, metrics};
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
+pub use crate::usage::CacheUsage;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -27,7 +31,9 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod metrics;
 mod removal;
+mod usage;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -35,6 +41,21 @@ mod wheel;
 /// Must be kept in-sync with the version in [`CacheBucket::to_str`].
 pub const ARCHIVE_VERSION: u8 = 0;
 
+/// The name of the marker file that records the last time the cache was evicted.
+const LAST_EVICTION: &str = ".last-eviction";
+
+/// The directory under the cache root that contains isolated cache namespaces.
+const NAMESPACES: &str = "namespaces";
+
+/// The directory under the cache root that contains quarantined (corrupt) archives.
+///
+/// Quarantined archives are retained for inspection until the next `uv cache prune`, which
+/// removes the directory as a dangling cache bucket.
+const QUARANTINE: &str = "quarantine-v0";
+
+/// The minimum interval between automatic evictions (see [`Cache::evict_periodically`]).
+const EVICTION_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+
 /// Error locking a cache entry or shard
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -50,22 +71,52 @@ pub enum Error {
 
 /// A [`CacheEntry`] which may or may not exist yet.
 #[derive(Debug, Clone)]
-pub struct CacheEntry(PathBuf);
+pub struct CacheEntry(PathBuf, Option<Arc<Overlay>>);
 
 impl CacheEntry {
     /// Create a new [`CacheEntry`] from a directory and a file name.
     pub fn new(dir: impl Into<PathBuf>, file: impl AsRef<Path>) -> Self {
-        Self(dir.into().join(file))
+        Self(dir.into().join(file), None)
     }
 
     /// Create a new [`CacheEntry`] from a path.
     pub fn from_path(path: impl Into<PathBuf>) -> Self {
-        Self(path.into())
+        Self(path.into(), None)
+    }
+
+    /// Create a new [`CacheEntry`] at the given path, in a cache that may overlay a shared cache.
+    fn with_overlay(path: PathBuf, overlay: Option<Arc<Overlay>>) -> Self {
+        Self(path, overlay)
+    }
+
+    /// Prepare the [`CacheEntry`] to be read.
+    ///
+    /// If the cache overlays a shared cache, and the entry only exists in the shared cache, it's
+    /// copied into the namespace, such that it can be read (and later updated) without modifying
+    /// the shared cache. Otherwise, this is a no-op.
+    pub async fn materialize(&self) -> io::Result<()> {
+        let Some(overlay) = self.1.clone() else {
+            return Ok(());
+        };
+        let path = self.0.clone();
+        tokio::task::spawn_blocking(move || overlay.materialize(&path))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    /// Prepare the [`CacheEntry`] to be read, blocking the current thread.
+    ///
+    /// See [`CacheEntry::materialize`].
+    pub fn materialize_sync(&self) -> io::Result<()> {
+        match self.1.as_deref() {
+            Some(overlay) => overlay.materialize(&self.0),
+            None => Ok(()),
+        }
     }
 
     /// Return the cache entry's parent directory.
     pub fn shard(&self) -> CacheShard {
-        CacheShard(self.dir().to_path_buf())
+        CacheShard(self.dir().to_path_buf(), self.1.clone())
     }
 
     /// Convert the [`CacheEntry`] into a [`PathBuf`].
@@ -89,7 +140,7 @@ impl CacheEntry {
     /// Create a new [`CacheEntry`] with the given file name.
     #[must_use]
     pub fn with_file(&self, file: impl AsRef<Path>) -> Self {
-        Self(self.dir().join(file))
+        Self::with_overlay(self.dir().join(file), self.1.clone())
     }
 
     /// Acquire the [`CacheEntry`] as an exclusive lock.
@@ -112,18 +163,45 @@ impl AsRef<Path> for CacheEntry {
 
 /// A subdirectory within the cache.
 #[derive(Debug, Clone)]
-pub struct CacheShard(PathBuf);
+pub struct CacheShard(PathBuf, Option<Arc<Overlay>>);
 
 impl CacheShard {
     /// Return a [`CacheEntry`] within this shard.
     pub fn entry(&self, file: impl AsRef<Path>) -> CacheEntry {
-        CacheEntry::new(&self.0, file)
+        CacheEntry::with_overlay(self.0.join(file), self.1.clone())
     }
 
     /// Return a [`CacheShard`] within this shard.
     #[must_use]
     pub fn shard(&self, dir: impl AsRef<Path>) -> Self {
-        Self(self.0.join(dir.as_ref()))
+        Self(self.0.join(dir.as_ref()), self.1.clone())
+    }
+
+    /// Return the paths of the entries in this shard, using the given listing function (e.g.,
+    /// [`uv_fs::files`]).
+    ///
+    /// If the cache overlays a shared cache, entries that only exist in the shared cache are
+    /// included at their path in the shared cache, and must only be read.
+    pub fn list<I: Iterator<Item = PathBuf>>(
+        &self,
+        list: impl Fn(PathBuf) -> io::Result<I>,
+    ) -> io::Result<Vec<PathBuf>> {
+        let mut paths = list(self.0.clone())?.collect::<Vec<_>>();
+        if let Some(shared) = self
+            .1
+            .as_deref()
+            .and_then(|overlay| overlay.shared_path(&self.0))
+        {
+            let names = paths
+                .iter()
+                .filter_map(|path| path.file_name().map(ToOwned::to_owned))
+                .collect::<FxHashSet<_>>();
+            paths.extend(
+                list(shared)?
+                    .filter(|path| path.file_name().is_none_or(|name| !names.contains(name))),
+            );
+        }
+        Ok(paths)
     }
 
     /// Acquire the cache entry as an exclusive lock.
@@ -157,6 +235,83 @@ impl Deref for CacheShard {
     }
 }
 
+/// A namespace that reads entries from the shared cache, but only writes to the namespace.
+#[derive(Debug)]
+struct Overlay {
+    /// The root of the namespace.
+    root: PathBuf,
+    /// The root of the shared cache.
+    shared: PathBuf,
+}
+
+impl Overlay {
+    /// Return the path in the shared cache that corresponds to the given path in the namespace.
+    fn shared_path(&self, path: &Path) -> Option<PathBuf> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        Some(self.shared.join(relative))
+    }
+
+    /// Copy an entry that is missing from the namespace, but exists in the shared cache, into the
+    /// namespace.
+    ///
+    /// Files, symlinks, and directories are copied. Archive links point to the shared archive
+    /// bucket, which remains readable.
+    fn materialize(&self, path: &Path) -> io::Result<()> {
+        if path.symlink_metadata().is_ok() {
+            return Ok(());
+        }
+        let (Some(source), Some(parent)) = (self.shared_path(path), path.parent()) else {
+            return Ok(());
+        };
+        let metadata = match source.symlink_metadata() {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        fs_err::create_dir_all(parent)?;
+        let result = if metadata.is_symlink() {
+            fs_err::read_link(&source).and_then(|target| {
+                #[cfg(unix)]
+                {
+                    fs_err::os::unix::fs::symlink(target, path)
+                }
+                #[cfg(windows)]
+                {
+                    fs_err::os::windows::fs::symlink_dir(target, path)
+                }
+            })
+        } else if metadata.is_dir() {
+            // Copy into a temporary directory, such that readers never observe a partial copy.
+            let temp_dir = tempfile::tempdir_in(parent)?;
+            uv_fs::copy_dir_all(&source, temp_dir.path())?;
+            fs_err::rename(temp_dir.keep(), path)
+        } else {
+            uv_fs::copy_atomic_sync(&source, path)
+        };
+        match result {
+            Ok(()) => {
+                debug!(
+                    "Copied cache entry from the shared cache: {}",
+                    source.display()
+                );
+                Ok(())
+            }
+            // Another process copied the entry concurrently.
+            Err(_) if path.symlink_metadata().is_ok() => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Return the path to an archive in the shared cache, if it's missing from the namespace.
+    fn find_archive(&self, path: &Path) -> Option<PathBuf> {
+        if path.is_dir() {
+            return None;
+        }
+        let source = self.shared_path(path)?;
+        source.is_dir().then_some(source)
+    }
+}
+
 /// The main cache abstraction.
 ///
 /// While the cache is active, it holds a read (shared) lock that prevents cache cleaning
@@ -174,6 +329,11 @@ pub struct Cache {
     /// Ensure that `uv cache` operations don't remove items from the cache that are used by another
     /// uv process.
     lock_file: Option<Arc<LockedFile>>,
+    /// A remote cache, shared across machines, that is consulted before building source
+    /// distributions and populated after building them.
+    remote: Option<Arc<DisplaySafeUrl>>,
+    /// The shared cache, if the cache is a namespace that overlays it.
+    overlay: Option<Arc<Overlay>>,
 }
 
 impl Cache {
@@ -184,6 +344,8 @@ impl Cache {
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
             lock_file: None,
+            remote: None,
+            overlay: None,
         }
     }
 
@@ -195,6 +357,8 @@ impl Cache {
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
             lock_file: None,
+            remote: None,
+            overlay: None,
         })
     }
 
@@ -211,6 +375,8 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file,
+            remote,
+            overlay,
         } = self;
 
         // Release the existing lock, avoid deadlocks from a cloned cache.
@@ -233,6 +399,8 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file: Some(Arc::new(lock_file)),
+            remote,
+            overlay,
         })
     }
 
@@ -245,6 +413,8 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file,
+            remote,
+            overlay,
         } = self;
 
         match LockedFile::acquire_no_wait(
@@ -257,16 +427,57 @@ impl Cache {
                 refresh,
                 temp_dir,
                 lock_file: Some(Arc::new(lock_file)),
+                remote,
+                overlay,
             }),
             None => Err(Self {
                 root,
                 refresh,
                 temp_dir,
                 lock_file,
+                remote,
+                overlay,
             }),
         }
     }
 
+    /// Scope the cache to a namespace within the cache root.
+    ///
+    /// Entries in a namespace are never written to the shared cache. If `overlay` is set, entries
+    /// that are missing from the namespace are read from the shared cache; otherwise, the namespace
+    /// is isolated from the shared cache entirely.
+    #[must_use]
+    pub fn with_namespace(self, namespace: Option<&str>, overlay: bool) -> Self {
+        let Some(namespace) = namespace else {
+            return self;
+        };
+        let root = self.root.join(NAMESPACES).join(namespace);
+        Self {
+            overlay: overlay.then(|| {
+                Arc::new(Overlay {
+                    root: root.clone(),
+                    shared: self.root.clone(),
+                })
+            }),
+            root,
+            ..self
+        }
+    }
+
+    /// Set the remote cache to consult before building source distributions.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<DisplaySafeUrl>) -> Self {
+        Self {
+            remote: remote.map(Arc::new),
+            ..self
+        }
+    }
+
+    /// Return the remote cache, if any.
+    pub fn remote(&self) -> Option<&DisplaySafeUrl> {
+        self.remote.as_deref()
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -284,24 +495,78 @@ impl Cache {
 
     /// Compute an entry in the cache.
     pub fn shard(&self, cache_bucket: CacheBucket, dir: impl AsRef<Path>) -> CacheShard {
-        CacheShard(self.bucket(cache_bucket).join(dir.as_ref()))
+        CacheShard(
+            self.bucket(cache_bucket).join(dir.as_ref()),
+            self.overlay.clone(),
+        )
     }
 
     /// Compute an entry in the cache.
+    ///
+    /// If the cache overlays a shared cache, use [`CacheEntry::materialize`] before reading the
+    /// entry.
     pub fn entry(
         &self,
         cache_bucket: CacheBucket,
         dir: impl AsRef<Path>,
         file: impl AsRef<Path>,
     ) -> CacheEntry {
-        CacheEntry::new(self.bucket(cache_bucket).join(dir), file)
+        CacheEntry::with_overlay(
+            self.bucket(cache_bucket).join(dir).join(file),
+            self.overlay.clone(),
+        )
     }
 
     /// Return the path to an archive in the cache.
+    ///
+    /// To read an existing archive, use [`Cache::find_archive`].
     pub fn archive(&self, id: &ArchiveId) -> PathBuf {
         self.bucket(CacheBucket::Archive).join(id)
     }
 
+    /// Return the path to an existing archive in the cache, for reading.
+    ///
+    /// If the cache overlays a shared cache, and the archive only exists in the shared cache, the
+    /// shared archive is returned. Archives are immutable, so they're read in place.
+    pub fn find_archive(&self, id: &ArchiveId) -> PathBuf {
+        let path = self.archive(id);
+        match self
+            .overlay
+            .as_deref()
+            .and_then(|overlay| overlay.find_archive(&path))
+        {
+            Some(shared) => shared,
+            None => path,
+        }
+    }
+
+    /// Return the IDs of all archives (unzipped wheels) in the cache.
+    pub fn archives(&self) -> io::Result<Vec<ArchiveId>> {
+        let mut archives = Vec::new();
+        for directory in directories(self.bucket(CacheBucket::Archive))? {
+            if let Some(name) = directory.file_name().and_then(|name| name.to_str()) {
+                let Ok(id) = ArchiveId::from_str(name);
+                archives.push(id);
+            }
+        }
+        Ok(archives)
+    }
+
+    /// Move an archive (unzipped wheel) out of the archive bucket, such that any entries that
+    /// reference it are treated as missing (and repopulated) on next use.
+    ///
+    /// Returns the path to the quarantined archive.
+    pub fn quarantine(&self, id: &ArchiveId) -> io::Result<PathBuf> {
+        let target = self.root.join(QUARANTINE).join(id);
+        fs_err::create_dir_all(self.root.join(QUARANTINE))?;
+        if target.exists() {
+            rm_rf(&target)?;
+        }
+        // Never quarantine an archive in the shared cache.
+        fs_err::rename(self.bucket(CacheBucket::Archive).join(id), &target)?;
+        Ok(target)
+    }
+
     /// Create a temporary directory to be used as a Python virtual environment.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
@@ -565,6 +830,56 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Remove the wheels built from source distributions, optionally limited to those for a
+    /// specific package, retaining the source distributions themselves.
+    pub fn remove_built_wheels(&self, name: Option<&PackageName>) -> io::Result<Removal> {
+        // Collect the set of referenced archives.
+        let references = self.find_archive_references()?;
+
+        // Collect the built wheels, along with the links to their unzipped archives.
+        let mut built_wheels = Vec::new();
+        for entry in walkdir::WalkDir::new(self.bucket(CacheBucket::SourceDistributions)) {
+            let entry = entry?;
+            let path = entry.path();
+            if !path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("whl"))
+            {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            // Wheel filenames start with the normalized package name, followed by the version.
+            if let Some(name) = name
+                && stem
+                    .split_once('-')
+                    .and_then(|(package, _)| PackageName::from_str(package).ok())
+                    .is_none_or(|package| package != *name)
+            {
+                continue;
+            }
+            built_wheels.push(path.to_path_buf());
+            built_wheels.push(path.with_extension(""));
+        }
+
+        let mut summary = Removal::default();
+        for path in built_wheels {
+            debug!("Removing built wheel entry: {}", path.display());
+            summary += rm_rf(path)?;
+        }
+
+        // Remove any archives that are no longer referenced.
+        for (target, references) in references {
+            if references.iter().all(|path| !path.exists()) {
+                debug!("Removing dangling cache entry: {}", target.display());
+                summary += rm_rf(target)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
     pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -579,6 +894,8 @@ impl Cache {
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
                 || entry.file_name() == ".lock"
+                || entry.file_name() == LAST_EVICTION
+                || entry.file_name() == NAMESPACES
             {
                 continue;
             }
@@ -691,6 +1008,145 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Evict the least-recently-used unzipped wheels and Python downloads from the cache.
+    ///
+    /// Entries that haven't been used within `max_age` are removed first. Then, if the cache still
+    /// exceeds `max_size` bytes, entries are removed in least-recently-used order until it fits.
+    ///
+    /// An entry's last use is approximated by the later of its access and modification times.
+    pub fn evict(
+        &self,
+        max_age: Option<Duration>,
+        max_size: Option<u64>,
+    ) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+
+        if max_age.is_none() && max_size.is_none() {
+            return Ok(summary);
+        }
+
+        // Collect the candidates for eviction, along with their size and last use.
+        let mut candidates = Vec::new();
+        for bucket in [CacheBucket::Archive, CacheBucket::Python] {
+            match fs_err::read_dir(self.bucket(bucket)) {
+                Ok(entries) => {
+                    for entry in entries {
+                        let entry = entry?;
+                        if entry.file_name().to_string_lossy().starts_with('.') {
+                            continue;
+                        }
+                        let path = fs_err::canonicalize(entry.path())?;
+                        let metadata = fs_err::metadata(&path)?;
+                        let modified = metadata.modified()?;
+                        let last_used = metadata
+                            .accessed()
+                            .map_or(modified, |accessed| accessed.max(modified));
+                        let size = disk_usage(&path)?;
+
+                        // Reading a directory updates its access time; restore it, so that
+                        // computing the size isn't mistaken for a use.
+                        if metadata.is_dir()
+                            && let Ok(accessed) = metadata.accessed()
+                            && let Err(err) = fs_err::File::open(&path).and_then(|file| {
+                                file.set_times(std::fs::FileTimes::new().set_accessed(accessed))
+                            })
+                        {
+                            trace!(
+                                "Failed to restore access time for {}: {err}",
+                                path.display()
+                            );
+                        }
+
+                        candidates.push((bucket, path, last_used, size));
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+                Err(err) => return Err(err),
+            }
+        }
+        candidates.sort_by_key(|(.., last_used, _)| *last_used);
+
+        // Unzipped wheels are referenced by links in other buckets, which must be removed
+        // alongside the archive.
+        let references = self.find_archive_references()?;
+        let evict = |bucket: CacheBucket, path: &Path| -> Result<Removal, io::Error> {
+            let mut removal = rm_rf(path)?;
+            if bucket == CacheBucket::Archive {
+                for reference in references.get(path).into_iter().flatten() {
+                    removal += rm_rf(reference)?;
+                }
+            }
+            metrics().record(CacheEvent::Eviction {
+                bytes: removal.total_bytes,
+            });
+            Ok(removal)
+        };
+
+        let now = SystemTime::now();
+        let mut remaining = Vec::with_capacity(candidates.len());
+        for (bucket, path, last_used, size) in candidates {
+            let age = now.duration_since(last_used).unwrap_or_default();
+            if max_age.is_some_and(|max_age| age > max_age) {
+                debug!("Evicting unused cache entry: {}", path.display());
+                summary += evict(bucket, &path)?;
+            } else {
+                remaining.push((bucket, path, size));
+            }
+        }
+
+        if let Some(max_size) = max_size {
+            // Measure the rest of the cache without descending into the candidates, which would
+            // update their access times.
+            let archive = self.bucket(CacheBucket::Archive);
+            let python = self.bucket(CacheBucket::Python);
+            let mut total = remaining.iter().map(|(.., size)| size).sum::<u64>();
+            for entry in walkdir::WalkDir::new(&self.root)
+                .into_iter()
+                .filter_entry(|entry| entry.path() != archive && entry.path() != python)
+            {
+                let entry = entry?;
+                if entry.file_type().is_file() {
+                    total += entry.metadata()?.len();
+                }
+            }
+
+            for (bucket, path, size) in remaining {
+                if total <= max_size {
+                    break;
+                }
+                debug!(
+                    "Evicting least-recently-used cache entry: {}",
+                    path.display()
+                );
+                summary += evict(bucket, &path)?;
+                total = total.saturating_sub(size);
+            }
+        }
+
+        fs_err::write(self.root.join(LAST_EVICTION), "")?;
+
+        Ok(summary)
+    }
+
+    /// Run [`Cache::evict`], unless the cache was already evicted within the last day.
+    ///
+    /// Returns `None` if the eviction was skipped.
+    pub fn evict_periodically(
+        &self,
+        max_age: Option<Duration>,
+        max_size: Option<u64>,
+    ) -> Result<Option<Removal>, io::Error> {
+        if let Ok(modified) =
+            fs_err::metadata(self.root.join(LAST_EVICTION)).and_then(|metadata| metadata.modified())
+            && modified
+                .elapsed()
+                .is_ok_and(|elapsed| elapsed < EVICTION_INTERVAL)
+        {
+            return Ok(None);
+        }
+        self.evict(max_age, max_size).map(Some)
+    }
+
     /// Find all references to entries in the archive bucket.
     ///
     /// Archive entries are often referenced by symlinks in other cache buckets. This method
@@ -802,7 +1258,7 @@ impl Cache {
         }
 
         // Reconstruct the path.
-        let path = self.archive(&link.id);
+        let path = self.find_archive(&link.id);
         path.canonicalize()
     }
 
@@ -1161,6 +1617,14 @@ pub enum CacheBucket {
     Python,
     /// Downloaded tool binaries (e.g., Ruff).
     Binaries,
+    /// Compiled bytecode, shared across environments.
+    ///
+    /// Cache structure: `bytecode-v1/<digest(header, optimization level, source)>`
+    ///
+    /// Each entry is a complete `.pyc` file. As the key includes the `.pyc` header (i.e., the
+    /// magic number, invalidation mode, and the source's timestamp or hash), entries can be hard
+    /// linked into the `__pycache__` directories of environments as-is.
+    Bytecode,
 }
 
 impl CacheBucket {
@@ -1174,7 +1638,7 @@ impl CacheBucket {
             Self::Interpreter => "interpreter-v4",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v19",
+            Self::Simple => "simple-v21",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
             Self::Wheels => "wheels-v5",
@@ -1185,6 +1649,25 @@ impl CacheBucket {
             Self::Environments => "environments-v2",
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
+            Self::Bytecode => "bytecode-v1",
+        }
+    }
+
+    /// Return a human-readable name for the kind of data stored in the bucket (e.g., `wheels`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::SourceDistributions => "built-wheels",
+            Self::FlatIndex => "flat-index",
+            Self::Git => "git",
+            Self::Interpreter => "interpreters",
+            Self::Simple => "metadata",
+            Self::Wheels => "wheels",
+            Self::Archive => "unzipped-wheels",
+            Self::Builds => "builds",
+            Self::Environments => "environments",
+            Self::Python => "python",
+            Self::Binaries => "binaries",
+            Self::Bytecode => "bytecode",
         }
     }
 
@@ -1292,7 +1775,8 @@ impl CacheBucket {
             | Self::Builds
             | Self::Environments
             | Self::Python
-            | Self::Binaries => {
+            | Self::Binaries
+            | Self::Bytecode => {
                 // Nothing to do.
             }
         }
@@ -1312,6 +1796,7 @@ impl CacheBucket {
             Self::Builds,
             Self::Environments,
             Self::Binaries,
+            Self::Bytecode,
         ]
         .iter()
         .copied()
@@ -1419,11 +1904,25 @@ impl Refresh {
     }
 }
 
+/// Return the total size of the files under the given path, in bytes, without following symlinks.
+fn disk_usage(path: &Path) -> Result<u64, io::Error> {
+    let mut total = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::ArchiveId;
+    use uv_normalize::PackageName;
+
+    use crate::{ArchiveId, Cache, CacheBucket};
 
     use super::Link;
 
@@ -1444,4 +1943,326 @@ mod tests {
         assert!(Link::from_str("v1/foo").is_err());
         assert!(Link::from_str("archive-v0/").is_err());
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_evict() {
+        use std::time::{Duration, SystemTime};
+
+        /// Create an entry of the given size, last used `days` ago.
+        fn entry(path: &std::path::Path, size: usize, days: u64) {
+            fs_err::create_dir_all(path).unwrap();
+            fs_err::write(path.join("file"), vec![0; size]).unwrap();
+            let time = SystemTime::now() - Duration::from_secs(days * 60 * 60 * 24);
+            let times = std::fs::FileTimes::new()
+                .set_accessed(time)
+                .set_modified(time);
+            fs_err::File::open(path).unwrap().set_times(times).unwrap();
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(temp_dir.path());
+        let old = cache.bucket(CacheBucket::Archive).join("old");
+        let new = cache.bucket(CacheBucket::Archive).join("new");
+        let python = cache.bucket(CacheBucket::Python).join("cpython");
+        entry(&old, 100, 10);
+        entry(&new, 100, 0);
+        entry(&python, 1000, 1);
+
+        // Evict entries that haven't been used in a week.
+        let removal = cache
+            .evict(Some(Duration::from_secs(7 * 60 * 60 * 24)), None)
+            .unwrap();
+        assert_eq!(removal.total_bytes, 100);
+        assert!(!old.exists());
+        assert!(new.exists());
+        assert!(python.exists());
+
+        // Evict the least-recently-used entries until the cache fits.
+        let removal = cache.evict(None, Some(500)).unwrap();
+        assert_eq!(removal.total_bytes, 1000);
+        assert!(new.exists());
+        assert!(!python.exists());
+
+        // Periodic eviction is skipped, since the cache was just evicted.
+        assert!(cache.evict_periodically(None, Some(0)).unwrap().is_none());
+        assert!(new.exists());
+    }
+
+    #[test]
+    fn test_quarantine() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(temp_dir.path());
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(cache.archive(&id)).unwrap();
+        fs_err::write(cache.archive(&id).join("file"), "corrupt").unwrap();
+        assert_eq!(cache.archives().unwrap(), vec![id.clone()]);
+
+        // The archive is moved out of the archive bucket.
+        let quarantined = cache.quarantine(&id).unwrap();
+        assert!(!cache.archive(&id).exists());
+        assert!(quarantined.join("file").exists());
+        assert!(cache.archives().unwrap().is_empty());
+
+        // Pruning removes the quarantined archive.
+        cache.prune(false).unwrap();
+        assert!(!quarantined.exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_remove_built_wheels() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(temp_dir.path());
+
+        // A source distribution revision, with wheels built for `foo` and `bar`.
+        let revision = cache
+            .bucket(CacheBucket::SourceDistributions)
+            .join("pypi")
+            .join("foo")
+            .join("1.0.0")
+            .join("revision");
+        fs_err::create_dir_all(revision.join("src")).unwrap();
+        fs_err::write(revision.join("metadata.msgpack"), "").unwrap();
+        let mut archives = Vec::new();
+        for name in ["foo", "bar"] {
+            let id = ArchiveId::new();
+            fs_err::create_dir_all(cache.archive(&id)).unwrap();
+            let stem = format!("{name}-1.0.0-py3-none-any");
+            fs_err::write(revision.join(format!("{stem}.whl")), "").unwrap();
+            cache.create_link(&id, revision.join(stem)).unwrap();
+            archives.push(id);
+        }
+
+        // Only the wheel built for `foo` (and its unzipped archive) is removed.
+        let foo = PackageName::from_str("foo").unwrap();
+        cache.remove_built_wheels(Some(&foo)).unwrap();
+        assert!(!revision.join("foo-1.0.0-py3-none-any.whl").exists());
+        assert!(!revision.join("foo-1.0.0-py3-none-any").exists());
+        assert!(!cache.archive(&archives[0]).exists());
+        assert!(revision.join("bar-1.0.0-py3-none-any.whl").exists());
+        assert!(cache.archive(&archives[1]).exists());
+
+        // The source distribution and its metadata are retained.
+        cache.remove_built_wheels(None).unwrap();
+        assert!(!revision.join("bar-1.0.0-py3-none-any.whl").exists());
+        assert!(revision.join("src").exists());
+        assert!(revision.join("metadata.msgpack").exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_usage() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(temp_dir.path());
+
+        // A downloaded wheel for `anyio`, referencing an unzipped wheel in the archive bucket.
+        let anyio = cache.bucket(CacheBucket::Wheels).join("pypi").join("anyio");
+        fs_err::create_dir_all(&anyio).unwrap();
+        fs_err::write(anyio.join("anyio-4.0.0-py3-none-any.http"), vec![0; 10]).unwrap();
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(cache.archive(&id)).unwrap();
+        fs_err::write(cache.archive(&id).join("file"), vec![0; 1000]).unwrap();
+        cache
+            .create_link(&id, anyio.join("anyio-4.0.0-py3-none-any"))
+            .unwrap();
+
+        // Index metadata for `idna`, from an alternate index.
+        let idna = cache
+            .bucket(CacheBucket::Simple)
+            .join("index")
+            .join("0123456789abcdef");
+        fs_err::create_dir_all(&idna).unwrap();
+        fs_err::write(idna.join("idna.rkyv"), vec![0; 100]).unwrap();
+
+        let usage = cache.usage().unwrap();
+        let packages = usage
+            .packages
+            .iter()
+            .map(|(name, size)| (name.as_str(), *size))
+            .collect::<Vec<_>>();
+        assert_eq!(packages, vec![("anyio", 1010), ("idna", 100)]);
+
+        let bucket = |bucket| {
+            usage
+                .buckets
+                .iter()
+                .find(|(candidate, _)| *candidate == bucket)
+                .map(|(_, size)| *size)
+                .unwrap()
+        };
+        assert_eq!(bucket(CacheBucket::Archive), 1000);
+        assert_eq!(bucket(CacheBucket::Simple), 100);
+        assert_eq!(bucket(CacheBucket::Git), 0);
+        assert!(usage.total_bytes >= 1110);
+    }
+
+    #[test]
+    fn test_namespace_isolated() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let shared = Cache::from_path(temp_dir.path());
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(shared.archive(&id)).unwrap();
+        let entry = shared.entry(CacheBucket::Simple, "pypi", "anyio.rkyv");
+        fs_err::create_dir_all(entry.dir()).unwrap();
+        fs_err::write(entry.path(), "shared").unwrap();
+
+        // Entries in the shared cache aren't visible from an isolated namespace.
+        let namespace =
+            Cache::from_path(temp_dir.path()).with_namespace(Some("experiments"), false);
+        assert_eq!(
+            namespace.root(),
+            temp_dir.path().join("namespaces").join("experiments")
+        );
+        let entry = namespace.entry(CacheBucket::Simple, "pypi", "anyio.rkyv");
+        assert!(entry.path().starts_with(namespace.root()));
+        assert!(!entry.path().exists());
+        assert!(!namespace.archive(&id).exists());
+
+        // Writes to the namespace don't affect the shared cache.
+        fs_err::create_dir_all(entry.dir()).unwrap();
+        fs_err::write(entry.path(), "namespace").unwrap();
+        assert_eq!(
+            fs_err::read_to_string(
+                shared
+                    .entry(CacheBucket::Simple, "pypi", "anyio.rkyv")
+                    .path()
+            )
+            .unwrap(),
+            "shared"
+        );
+    }
+
+    #[test]
+    fn test_namespace_overlay() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let shared = Cache::from_path(temp_dir.path());
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(shared.archive(&id)).unwrap();
+        let entry = shared.entry(CacheBucket::Simple, "pypi", "anyio.rkyv");
+        fs_err::create_dir_all(entry.dir()).unwrap();
+        fs_err::write(entry.path(), "shared").unwrap();
+
+        // Entries in the shared cache are copied into the namespace when materialized, but not
+        // when the entry is constructed.
+        let namespace = Cache::from_path(temp_dir.path()).with_namespace(Some("experiments"), true);
+        let entry = namespace.entry(CacheBucket::Simple, "pypi", "anyio.rkyv");
+        assert!(entry.path().starts_with(namespace.root()));
+        assert!(!entry.path().exists());
+        entry.materialize_sync().unwrap();
+        assert_eq!(fs_err::read_to_string(entry.path()).unwrap(), "shared");
+
+        // Writes to the namespace don't affect the shared cache.
+        fs_err::write(entry.path(), "namespace").unwrap();
+        assert_eq!(
+            fs_err::read_to_string(
+                shared
+                    .entry(CacheBucket::Simple, "pypi", "anyio.rkyv")
+                    .path()
+            )
+            .unwrap(),
+            "shared"
+        );
+        assert_eq!(
+            fs_err::read_to_string(
+                namespace
+                    .entry(CacheBucket::Simple, "pypi", "anyio.rkyv")
+                    .path()
+            )
+            .unwrap(),
+            "namespace"
+        );
+
+        // Entries within shards are copied, too.
+        fs_err::write(
+            shared
+                .entry(CacheBucket::Simple, "pypi", "idna.rkyv")
+                .path(),
+            "shared",
+        )
+        .unwrap();
+        let entry = namespace
+            .shard(CacheBucket::Simple, "pypi")
+            .entry("idna.rkyv");
+        assert!(entry.path().starts_with(namespace.root()));
+        entry.materialize_sync().unwrap();
+        assert_eq!(fs_err::read_to_string(entry.path()).unwrap(), "shared");
+
+        // As are directories.
+        let src = shared.entry(CacheBucket::SourceDistributions, "pypi", "src");
+        fs_err::create_dir_all(src.path().join("anyio")).unwrap();
+        fs_err::write(src.path().join("anyio").join("__init__.py"), "shared").unwrap();
+        let src = namespace.entry(CacheBucket::SourceDistributions, "pypi", "src");
+        src.materialize_sync().unwrap();
+        assert!(src.path().starts_with(namespace.root()));
+        assert_eq!(
+            fs_err::read_to_string(src.path().join("anyio").join("__init__.py")).unwrap(),
+            "shared"
+        );
+
+        // Listing a shard includes the entries that only exist in the shared cache.
+        fs_err::write(
+            shared
+                .entry(CacheBucket::Simple, "pypi", "sniffio.rkyv")
+                .path(),
+            "shared",
+        )
+        .unwrap();
+        let mut names = namespace
+            .shard(CacheBucket::Simple, "pypi")
+            .list(uv_fs::files)
+            .unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["anyio.rkyv", "idna.rkyv", "sniffio.rkyv"]);
+
+        // Entries that are missing from both caches remain missing.
+        let missing = namespace.entry(CacheBucket::Simple, "pypi", "urllib3.rkyv");
+        missing.materialize_sync().unwrap();
+        assert!(!missing.path().exists());
+        assert!(
+            !shared
+                .entry(CacheBucket::Simple, "pypi", "urllib3.rkyv")
+                .path()
+                .exists()
+        );
+
+        // Archives are read from the shared cache in place, but new archives are written to the
+        // namespace.
+        assert_eq!(namespace.find_archive(&id), shared.archive(&id));
+        assert!(namespace.archive(&id).starts_with(namespace.root()));
+        let new = ArchiveId::new();
+        fs_err::create_dir_all(namespace.archive(&new)).unwrap();
+        assert!(namespace.find_archive(&new).starts_with(namespace.root()));
+        assert!(!shared.archive(&new).exists());
+
+        // Archives in the shared cache are never quarantined from the namespace.
+        assert!(namespace.quarantine(&id).is_err());
+        assert!(shared.archive(&id).exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_namespace_overlay_link() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let shared = Cache::from_path(temp_dir.path());
+        let id = ArchiveId::new();
+        fs_err::create_dir_all(shared.archive(&id)).unwrap();
+        fs_err::write(shared.archive(&id).join("file"), "contents").unwrap();
+        let entry = shared.entry(CacheBucket::Wheels, "pypi", "anyio-4.3.0-py3-none-any");
+        fs_err::create_dir_all(entry.dir()).unwrap();
+        shared.create_link(&id, entry.path()).unwrap();
+
+        // Links to archives in the shared cache resolve from the namespace.
+        let namespace = Cache::from_path(temp_dir.path()).with_namespace(Some("experiments"), true);
+        let entry = namespace.entry(CacheBucket::Wheels, "pypi", "anyio-4.3.0-py3-none-any");
+        entry.materialize_sync().unwrap();
+        let resolved = namespace.resolve_link(entry.path()).unwrap();
+        assert_eq!(
+            fs_err::read_to_string(resolved.join("file")).unwrap(),
+            "contents"
+        );
+    }
 }
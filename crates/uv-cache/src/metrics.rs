@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+
+/// The global cache metrics for the current process.
+static METRICS: CacheMetrics = CacheMetrics::new();
+
+/// Return the cache metrics for the current process.
+pub fn metrics() -> &'static CacheMetrics {
+    &METRICS
+}
+
+/// An event that affects the effectiveness of the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEvent {
+    /// A cached HTTP response was fresh, and used without a network request.
+    Hit,
+    /// A cached HTTP response was stale, but the server confirmed that it was still valid.
+    Revalidated,
+    /// An HTTP response was absent from the cache (or modified), and fetched from the network.
+    Miss,
+    /// A wheel built from a source distribution was found in the cache.
+    BuiltWheelHit,
+    /// A source distribution was built, since no compatible wheel was found in the cache.
+    BuiltWheelMiss,
+    /// An entry was evicted from the cache, freeing the given number of bytes.
+    Eviction { bytes: u64 },
+}
+
+/// Counters that track cache hits, misses, and evictions over the lifetime of the process.
+#[derive(Debug, Default)]
+pub struct CacheMetrics {
+    hits: AtomicU64,
+    revalidations: AtomicU64,
+    misses: AtomicU64,
+    built_wheel_hits: AtomicU64,
+    built_wheel_misses: AtomicU64,
+    evictions: AtomicU64,
+    evicted_bytes: AtomicU64,
+}
+
+impl CacheMetrics {
+    const fn new() -> Self {
+        Self {
+            hits: AtomicU64::new(0),
+            revalidations: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            built_wheel_hits: AtomicU64::new(0),
+            built_wheel_misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            evicted_bytes: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a [`CacheEvent`].
+    pub fn record(&self, event: CacheEvent) {
+        match event {
+            CacheEvent::Hit => self.hits.fetch_add(1, Ordering::Relaxed),
+            CacheEvent::Revalidated => self.revalidations.fetch_add(1, Ordering::Relaxed),
+            CacheEvent::Miss => self.misses.fetch_add(1, Ordering::Relaxed),
+            CacheEvent::BuiltWheelHit => self.built_wheel_hits.fetch_add(1, Ordering::Relaxed),
+            CacheEvent::BuiltWheelMiss => self.built_wheel_misses.fetch_add(1, Ordering::Relaxed),
+            CacheEvent::Eviction { bytes } => {
+                self.evicted_bytes.fetch_add(bytes, Ordering::Relaxed);
+                self.evictions.fetch_add(1, Ordering::Relaxed)
+            }
+        };
+    }
+
+    /// Return a point-in-time copy of the counters.
+    pub fn snapshot(&self) -> CacheMetricsSnapshot {
+        CacheMetricsSnapshot {
+            hits: self.hits.load(Ordering::Relaxed),
+            revalidations: self.revalidations.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            built_wheel_hits: self.built_wheel_hits.load(Ordering::Relaxed),
+            built_wheel_misses: self.built_wheel_misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            evicted_bytes: self.evicted_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A point-in-time copy of the [`CacheMetrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CacheMetricsSnapshot {
+    /// The number of HTTP responses served from the cache without a network request.
+    pub hits: u64,
+    /// The number of stale HTTP responses that were revalidated with the server.
+    pub revalidations: u64,
+    /// The number of HTTP responses that were fetched from the network.
+    pub misses: u64,
+    /// The number of built wheels that were found in the cache.
+    pub built_wheel_hits: u64,
+    /// The number of source distributions that were built.
+    pub built_wheel_misses: u64,
+    /// The number of entries evicted from the cache.
+    pub evictions: u64,
+    /// The number of bytes freed by evictions.
+    pub evicted_bytes: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheEvent, CacheMetrics, CacheMetricsSnapshot};
+
+    #[test]
+    fn record() {
+        let metrics = CacheMetrics::new();
+        metrics.record(CacheEvent::Hit);
+        metrics.record(CacheEvent::Hit);
+        metrics.record(CacheEvent::Miss);
+        metrics.record(CacheEvent::BuiltWheelMiss);
+        metrics.record(CacheEvent::Eviction { bytes: 100 });
+        metrics.record(CacheEvent::Eviction { bytes: 50 });
+        assert_eq!(
+            metrics.snapshot(),
+            CacheMetricsSnapshot {
+                hits: 2,
+                misses: 1,
+                built_wheel_misses: 1,
+                evictions: 2,
+                evicted_bytes: 150,
+                ..CacheMetricsSnapshot::default()
+            }
+        );
+    }
+}
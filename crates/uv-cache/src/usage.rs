@@ -0,0 +1,131 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashMap;
+
+use uv_fs::directories;
+use uv_normalize::PackageName;
+
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket, disk_usage};
+
+/// The disk usage of the cache, broken down by bucket and by package.
+#[derive(Debug)]
+pub struct CacheUsage {
+    /// The total size of the cache, in bytes.
+    pub total_bytes: u64,
+    /// The size of each cache bucket, in bytes.
+    pub buckets: Vec<(CacheBucket, u64)>,
+    /// The size attributed to each package, in bytes, from largest to smallest.
+    ///
+    /// Includes the package's wheels, built wheels, and index metadata, along with any unzipped
+    /// wheels that they reference.
+    pub packages: Vec<(PackageName, u64)>,
+}
+
+impl Cache {
+    /// Compute the disk usage of the cache.
+    pub fn usage(&self) -> Result<CacheUsage, io::Error> {
+        let total_bytes = if self.root.exists() {
+            disk_usage(&self.root)?
+        } else {
+            0
+        };
+
+        let mut buckets = Vec::new();
+        for bucket in CacheBucket::iter() {
+            let path = self.bucket(bucket);
+            let size = if path.exists() { disk_usage(&path)? } else { 0 };
+            buckets.push((bucket, size));
+        }
+
+        // Attribute each package-scoped entry to its package.
+        let entries = self.package_entries()?;
+        let mut packages = FxHashMap::<PackageName, u64>::default();
+        for (path, name) in &entries {
+            *packages.entry(name.clone()).or_default() += disk_usage(path)?;
+        }
+
+        // Attribute each unzipped wheel to the package that references it.
+        for (target, references) in self.find_archive_references()? {
+            let Some(name) = references.iter().find_map(|reference| {
+                reference
+                    .ancestors()
+                    .find_map(|ancestor| entries.get(ancestor))
+            }) else {
+                continue;
+            };
+            *packages.entry(name.clone()).or_default() += disk_usage(&target)?;
+        }
+
+        let mut packages = packages.into_iter().collect::<Vec<_>>();
+        packages.sort_by(|(a_name, a_size), (b_name, b_size)| {
+            b_size.cmp(a_size).then_with(|| a_name.cmp(b_name))
+        });
+
+        Ok(CacheUsage {
+            total_bytes,
+            buckets,
+            packages,
+        })
+    }
+
+    /// Return the package-scoped entries in the cache, mapped to their package names.
+    fn package_entries(&self) -> Result<FxHashMap<PathBuf, PackageName>, io::Error> {
+        let mut entries = FxHashMap::default();
+        for bucket in [
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Simple,
+        ] {
+            // For `pypi` entries, we expect an entry per package (indexed by name).
+            let root = self.bucket(bucket).join(WheelCacheKind::Pypi);
+            insert_packages(&mut entries, &root)?;
+
+            // For alternate indexes, we expect a directory for every index, followed by an entry
+            // per package (indexed by name).
+            let root = self.bucket(bucket).join(WheelCacheKind::Index);
+            for directory in directories(root)? {
+                insert_packages(&mut entries, &directory)?;
+            }
+
+            // For direct URL wheels, we expect a directory for every URL, followed by a directory
+            // per package (indexed by name).
+            if bucket == CacheBucket::Wheels {
+                let root = self.bucket(bucket).join(WheelCacheKind::Url);
+                for directory in directories(root)? {
+                    insert_packages(&mut entries, &directory)?;
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Insert the package entries (e.g., `anyio` or `anyio.rkyv`) in the given directory.
+fn insert_packages(
+    entries: &mut FxHashMap<PathBuf, PackageName>,
+    root: &Path,
+) -> Result<(), io::Error> {
+    let read_dir = match fs_err::read_dir(root) {
+        Ok(read_dir) => read_dir,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in read_dir {
+        let path = entry?.path();
+        let name = if path.is_dir() {
+            path.file_name()
+        } else {
+            path.file_stem()
+        };
+        if let Some(name) = name
+            .and_then(|name| name.to_str())
+            .and_then(|name| PackageName::from_str(name).ok())
+        {
+            entries.insert(path, name);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,263 @@
+//! Dynamic completion candidates for argument values.
+//!
+//! These are evaluated by the shell integration registered with `uv generate-shell-completion`,
+//! i.e., on every completion request, and so must be fast and must never fail: any error reading
+//! the project or the environment results in no candidates.
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use clap::CommandFactory;
+use clap_complete::env::{Bash, EnvCompleter, Fish, Powershell, Zsh};
+use clap_complete::{CompleteEnv, CompletionCandidate};
+use serde::Deserialize;
+
+use uv_normalize::{GroupName, PackageName};
+use uv_python::managed::ManagedPythonInstallations;
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
+
+use crate::Cli;
+
+/// Respond to a completion request from a shell registered with [`write_registration`].
+///
+/// Returns `true` if the arguments were a completion request and the candidates have been written
+/// to `stdout`, in which case the command should not be run.
+pub fn try_complete(args: &[OsString]) -> clap::error::Result<bool> {
+    CompleteEnv::with_factory(Cli::command)
+        .var(EnvVars::UV_COMPLETE)
+        .completer("uv")
+        .try_complete(args, env::current_dir().ok().as_deref())
+}
+
+/// Write the script that registers uv's dynamic completions for the given shell.
+///
+/// Returns `false` if the shell does not support dynamic completions, in which case the static
+/// completion script should be used instead.
+pub fn write_registration(
+    shell: clap_complete_command::Shell,
+    buf: &mut dyn io::Write,
+) -> io::Result<bool> {
+    let completer: &dyn EnvCompleter = match shell {
+        clap_complete_command::Shell::Bash => &Bash,
+        clap_complete_command::Shell::Fish => &Fish,
+        clap_complete_command::Shell::PowerShell => &Powershell,
+        clap_complete_command::Shell::Zsh => &Zsh,
+        _ => return Ok(false),
+    };
+    completer.write_registration(EnvVars::UV_COMPLETE, "uv", "uv", "uv", buf)?;
+    Ok(true)
+}
+
+/// Complete the names of the packages in the lockfile and the project environment.
+pub fn packages() -> Vec<CompletionCandidate> {
+    let mut names = BTreeSet::new();
+    if let Some(lock) = find_upwards("uv.lock") {
+        names.extend(locked_packages(&lock));
+    }
+    if let Some(environment) = environment() {
+        names.extend(installed_packages(&environment));
+    }
+    candidates(names)
+}
+
+/// Complete the names of the packages installed in the active or project environment.
+pub fn installed() -> Vec<CompletionCandidate> {
+    candidates(
+        environment()
+            .map(|environment| installed_packages(&environment))
+            .unwrap_or_default(),
+    )
+}
+
+/// Complete the names of the dependency groups defined in the nearest `pyproject.toml`.
+pub fn groups() -> Vec<CompletionCandidate> {
+    let Some(pyproject) = find_upwards("pyproject.toml").and_then(|path| read_toml(&path)) else {
+        return Vec::new();
+    };
+    let PyProjectToml {
+        dependency_groups,
+        tool,
+    } = pyproject;
+    let mut names = dependency_groups.into_keys().collect::<BTreeSet<_>>();
+    if tool.uv.dev_dependencies.is_some() {
+        names.insert(GroupName::from_str("dev").expect("`dev` is a valid group name"));
+    }
+    candidates(names)
+}
+
+/// Complete the names of the indexes defined in the nearest `pyproject.toml` or `uv.toml`.
+pub fn indexes() -> Vec<CompletionCandidate> {
+    let mut names = BTreeSet::new();
+    if let Some(pyproject) =
+        find_upwards("pyproject.toml").and_then(|path| read_toml::<PyProjectToml>(&path))
+    {
+        names.extend(
+            pyproject
+                .tool
+                .uv
+                .index
+                .into_iter()
+                .filter_map(|index| index.name),
+        );
+    }
+    if let Some(uv_toml) = find_upwards("uv.toml").and_then(|path| read_toml::<UvToml>(&path)) {
+        names.extend(uv_toml.index.into_iter().filter_map(|index| index.name));
+    }
+    candidates(names)
+}
+
+/// Complete the versions of the managed Python installations, e.g., `3.12` and `3.12.4`.
+pub fn python_versions() -> Vec<CompletionCandidate> {
+    let Ok(installations) = ManagedPythonInstallations::from_settings(None) else {
+        return Vec::new();
+    };
+    let Ok(installations) = installations.find_all() else {
+        return Vec::new();
+    };
+    let mut versions = BTreeSet::new();
+    for installation in installations {
+        let version = installation.version();
+        versions.insert(format!("{}.{}", version.major(), version.minor()));
+        versions.insert(version.to_string());
+    }
+    candidates(versions)
+}
+
+/// Complete the names of the installed tools.
+pub fn tools() -> Vec<CompletionCandidate> {
+    let root = if let Some(tool_dir) = env::var_os(EnvVars::UV_TOOL_DIR).filter(|s| !s.is_empty()) {
+        PathBuf::from(tool_dir)
+    } else {
+        let Ok(store) = StateStore::from_settings(None) else {
+            return Vec::new();
+        };
+        store.bucket(StateBucket::Tools)
+    };
+    let Ok(entries) = fs_err::read_dir(root) else {
+        return Vec::new();
+    };
+    candidates(
+        entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().join("uv-receipt.toml").is_file())
+            .filter_map(|entry| PackageName::from_str(entry.file_name().to_str()?).ok())
+            .collect::<BTreeSet<_>>(),
+    )
+}
+
+fn candidates(values: impl IntoIterator<Item = impl ToString>) -> Vec<CompletionCandidate> {
+    values
+        .into_iter()
+        .map(|value| CompletionCandidate::new(value.to_string()))
+        .collect()
+}
+
+/// Find the nearest file with the given name in the current directory or its ancestors.
+fn find_upwards(name: &str) -> Option<PathBuf> {
+    let current_dir = env::current_dir().ok()?;
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn read_toml<T: for<'de> Deserialize<'de>>(path: &Path) -> Option<T> {
+    let contents = fs_err::read_to_string(path).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// The active virtual environment, or the `.venv` next to the nearest project.
+fn environment() -> Option<PathBuf> {
+    if let Some(venv) = env::var_os(EnvVars::VIRTUAL_ENV).filter(|s| !s.is_empty()) {
+        return Some(PathBuf::from(venv));
+    }
+    let project = find_upwards("uv.lock").or_else(|| find_upwards("pyproject.toml"))?;
+    let venv = project.parent()?.join(".venv");
+    venv.is_dir().then_some(venv)
+}
+
+fn locked_packages(lock: &Path) -> impl Iterator<Item = PackageName> {
+    read_toml::<Lock>(lock)
+        .map(|lock| lock.package)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|package| package.name)
+}
+
+/// Read the names of the installed packages from the `.dist-info` directories in the environment.
+fn installed_packages(environment: &Path) -> BTreeSet<PackageName> {
+    let site_packages = if cfg!(windows) {
+        vec![environment.join("Lib").join("site-packages")]
+    } else {
+        fs_err::read_dir(environment.join("lib"))
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path().join("site-packages"))
+            .collect()
+    };
+    site_packages
+        .iter()
+        .filter_map(|dir| fs_err::read_dir(dir).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let stem = file_name.to_str()?.strip_suffix(".dist-info")?;
+            let (name, _version) = stem.split_once('-')?;
+            PackageName::from_str(name).ok()
+        })
+        .collect()
+}
+
+/// The subset of a `pyproject.toml` used for completions.
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+struct PyProjectToml {
+    #[serde(default)]
+    dependency_groups: BTreeMap<GroupName, toml::Value>,
+    #[serde(default)]
+    tool: Tool,
+}
+
+#[derive(Deserialize, Default)]
+struct Tool {
+    #[serde(default)]
+    uv: ToolUv,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+struct ToolUv {
+    dev_dependencies: Option<toml::Value>,
+    #[serde(default)]
+    index: Vec<NamedIndex>,
+}
+
+/// The subset of a `uv.toml` used for completions.
+#[derive(Deserialize)]
+struct UvToml {
+    #[serde(default)]
+    index: Vec<NamedIndex>,
+}
+
+#[derive(Deserialize)]
+struct NamedIndex {
+    name: Option<String>,
+}
+
+/// The subset of a `uv.lock` used for completions.
+#[derive(Deserialize)]
+struct Lock {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+#[derive(Deserialize)]
+struct LockedPackage {
+    name: PackageName,
+}
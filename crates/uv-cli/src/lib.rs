@@ -1,5 +1,6 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -10,16 +11,18 @@ use clap::builder::{PossibleValue, Styles, TypedValueParser, ValueParserFactory}
 use clap::error::ErrorKind;
 use clap::{Args, Parser, Subcommand};
 use clap::{ValueEnum, ValueHint};
+use clap_complete::ArgValueCandidates;
 
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    CacheMaxAge, CacheMaxSize, ExportFormat, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, PipCompileFormat, PipFreezeFormat, ProjectBuildBackend, TargetTriple,
+    TrustedHost, TrustedPublishing, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
-    PipFindLinks, PipIndex,
+    PipFindLinks, PipIndex, RequirementsHash,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
@@ -38,32 +41,61 @@ use uv_workspace::pyproject_mut::AddBoundsKind;
 
 pub mod comma;
 pub mod compat;
+pub mod complete;
 pub mod options;
 pub mod version;
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
-pub enum VersionFormat {
-    /// Display the version as plain text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Display the results in a human-readable format.
+    #[default]
+    Text,
+    /// Display the results in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum EnvInfoFormat {
+    /// Display the environment details in a human-readable format.
+    #[default]
     Text,
-    /// Display the version as JSON.
+    /// Display the environment details in JSON format.
     Json,
 }
 
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
-pub enum PythonListFormat {
-    /// Plain text (for humans).
+pub enum CacheInfoFormat {
+    /// Display the breakdown in a human-readable format.
     #[default]
     Text,
-    /// JSON (for computers).
+    /// Display the breakdown in JSON format.
     Json,
 }
 
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
-pub enum SyncFormat {
-    /// Display the result in a human-readable format.
+pub enum PipSizeFormat {
+    /// Display the disk usage in a human-readable format.
     #[default]
     Text,
-    /// Display the result in JSON format.
+    /// Display the disk usage in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProgressFormat {
+    /// Display progress bars and spinners.
+    #[default]
+    Human,
+    /// Report progress as JSON events.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// Display errors in a human-readable format.
+    #[default]
+    Human,
+    /// Display errors in JSON format.
     Json,
 }
 
@@ -143,6 +175,19 @@ pub struct TopLevelArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_CONFIG, value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Global options")]
     pub no_config: bool,
 
+    /// The name of a settings profile to apply on top of the discovered configuration.
+    ///
+    /// Profiles are defined in the `profiles` table of a `uv.toml` file, or the
+    /// `[tool.uv.profiles]` table of a `pyproject.toml` file, e.g., `[tool.uv.profiles.ci]`.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_PROFILE,
+        help_heading = "Global options",
+        conflicts_with = "no_config"
+    )]
+    pub profile: Option<String>,
+
     /// Display the concise help for this command.
     #[arg(global = true, short, long, action = clap::ArgAction::HelpShort, help_heading = "Global options")]
     help: Option<bool>,
@@ -280,6 +325,78 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+    ///
+    /// Defaults to 50. To limit the number of concurrent requests to each host, or the total
+    /// download bandwidth, see the `concurrent-downloads-per-host` and `bandwidth-limit` settings.
+    #[arg(global = true, long, value_name = "N")]
+    pub max_concurrent_downloads: Option<NonZeroUsize>,
+
+    /// Write a record of every request made to an index or other remote host to the given
+    /// directory.
+    ///
+    /// Each invocation creates a new JSON Lines file in the directory, with one record per request,
+    /// including the URL, the package, the outcome, the number of bytes received, and whether the
+    /// response was served from the cache.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_AUDIT_LOG,
+        value_hint = ValueHint::DirPath,
+        hide = true
+    )]
+    pub audit_log: Option<PathBuf>,
+
+    /// Redact credentials, tokens, and private index URLs from logs, warnings, and errors.
+    ///
+    /// Usernames, passwords, and query parameter values are masked in every URL, and URLs on hosts
+    /// other than well-known public indexes (like PyPI) are reduced to their final path segment.
+    /// The hosts of private indexes, `Authorization` header values, and well-known token formats
+    /// are masked wherever they appear. Applies to verbose output, warnings, errors, and the audit
+    /// log, but not to the output of build backends or other subprocesses.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_REDACT_LOGS,
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    pub redact_logs: bool,
+
+    /// Append a record of each invocation to the given file, for measuring usage across a fleet.
+    ///
+    /// Each record is a single line of JSON, including the command (without its arguments), the
+    /// duration, the exit code, and the cache hit and miss counters. The file is never sent
+    /// anywhere by uv.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_USAGE_LOG,
+        value_hint = ValueHint::FilePath,
+        hide = true
+    )]
+    pub usage_log: Option<PathBuf>,
+
+    /// The format in which to display the results of the command.
+    ///
+    /// With `json`, the results are written to stdout as JSON, while warnings and other
+    /// diagnostics are written to stderr. Supported by `uv sync`, `uv add`, `uv lock`, `uv tree`,
+    /// `uv version`, `uv python list`, and `uv cache info`.
+    #[arg(global = true, long, value_enum)]
+    pub output_format: Option<OutputFormat>,
+
+    /// The format in which to report errors.
+    ///
+    /// With `json`, errors are written to stderr as a single line of JSON, including the stable
+    /// error code, if any, the message, and its causes.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = EnvVars::UV_ERROR_FORMAT,
+        hide = true
+    )]
+    pub error_format: Option<ErrorFormat>,
+
     /// Whether to enable all experimental preview features [env: UV_PREVIEW=]
     ///
     /// Preview features may change without warning.
@@ -329,6 +446,14 @@ pub struct GlobalArgs {
     #[arg(global = true, long, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// The format in which to report progress.
+    ///
+    /// With `json`, progress bars are hidden, and progress events (e.g., resolution steps,
+    /// download bytes, and builds) are written to stderr as JSON, one event per line, for
+    /// rendering by other tools.
+    #[arg(global = true, long, value_enum, env = EnvVars::UV_PROGRESS, hide = true)]
+    pub progress: Option<ProgressFormat>,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and
     /// `direct_url.json`) to site-packages `.dist-info` directories [env: UV_NO_INSTALLER_METADATA=]
     #[arg(global = true, long, hide = true, value_parser = clap::builder::BoolishValueParser::new())]
@@ -523,6 +648,41 @@ pub enum Commands {
         hide = true
     )]
     Workspace(WorkspaceNamespace),
+    /// Generate configuration files for developing the project.
+    #[command(
+        after_help = "Use `uv help generate` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Generate(GenerateNamespace),
+    /// Inspect the project environment.
+    #[command(
+        after_help = "Use `uv help env` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Env(EnvNamespace),
+    /// Manage software bills of materials (SBOMs) for the project.
+    #[command(
+        after_help = "Use `uv help sbom` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Sbom(SbomNamespace),
+    /// Manage Jupyter kernels for the project environment.
+    #[command(
+        after_help = "Use `uv help kernel` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Kernel(KernelNamespace),
+    /// Read and write uv's configuration files.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Config(ConfigNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -602,10 +762,6 @@ pub struct VersionArgs {
     #[arg(long)]
     pub short: bool,
 
-    /// The format of the output
-    #[arg(long, value_enum, default_value = "text")]
-    pub output_format: VersionFormat,
-
     /// Avoid syncing the virtual environment after re-locking the project [env: UV_NO_SYNC=]
     #[arg(long)]
     pub no_sync: bool,
@@ -636,6 +792,28 @@ pub struct VersionArgs {
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Update the version of the project in the `uv.lock` without re-locking the project
+    ///
+    /// Only the version entry of the project in the lockfile is updated, the project is not
+    /// re-resolved and the environment is not synced.
+    #[arg(long, requires = "operation", conflicts_with_all = ["dry_run", "locked", "frozen"])]
+    pub sync_lock: bool,
+
+    /// Collect the changelog fragments in `changelog.d` into `CHANGELOG.md`
+    ///
+    /// Fragments are named `<id>.<type>.md`, where the type is one of `feature`, `bugfix`,
+    /// `doc`, `removal`, or `misc`. A section for the new version is added to `CHANGELOG.md` and
+    /// the collected fragments are removed.
+    #[arg(long, requires = "operation", conflicts_with = "dry_run")]
+    pub changelog: bool,
+
+    /// Commit the version change and create a Git tag for the new version
+    ///
+    /// The tag is named `v<version>`, or `<package>-v<version>` with `--package`. Only the files
+    /// modified by `uv version` are committed.
+    #[arg(long, requires = "operation", conflicts_with = "dry_run")]
+    pub tag: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -646,7 +824,7 @@ pub struct VersionArgs {
     pub refresh: RefreshArgs,
 
     /// Update the version of a specific package in the workspace.
-    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Option<PackageName>,
 
     /// The Python interpreter to use for resolving and syncing.
@@ -660,6 +838,7 @@ pub struct VersionArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 }
@@ -820,6 +999,65 @@ impl TypedValueParser for VersionBumpSpecValueParser {
     }
 }
 
+/// The format to which `uv.lock` should be exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportFormatArg {
+    /// A format built into uv.
+    Builtin(ExportFormat),
+    /// A format provided by an external `uv-export-<name>` executable, selected with `ext:<name>`.
+    External(String),
+}
+
+impl ValueParserFactory for ExportFormatArg {
+    type Parser = ExportFormatArgValueParser;
+
+    fn value_parser() -> Self::Parser {
+        ExportFormatArgValueParser
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExportFormatArgValueParser;
+
+impl TypedValueParser for ExportFormatArgValueParser {
+    type Value = ExportFormatArg;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let Some(name) = value.to_str().and_then(|value| value.strip_prefix("ext:")) else {
+            return clap::builder::EnumValueParser::<ExportFormat>::new()
+                .parse_ref(cmd, arg, value)
+                .map(ExportFormatArg::Builtin);
+        };
+
+        if name.is_empty() || name.starts_with('-') || name.contains(['/', '\\']) {
+            return Err(clap::Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "invalid external export format `ext:{name}`; expected `ext:<name>`, where `uv-export-<name>` is an executable on the `PATH`"
+                ),
+            ));
+        }
+
+        Ok(ExportFormatArg::External(name.to_string()))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            ExportFormat::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value)
+                .chain(std::iter::once(PossibleValue::new("ext:<name>").help(
+                    "Export with an external `uv-export-<name>` executable, which reads `uv.lock` from stdin and writes the export to stdout",
+                ))),
+        ))
+    }
+}
+
 #[derive(Args)]
 pub struct SelfNamespace {
     #[command(subcommand)]
@@ -835,8 +1073,6 @@ pub enum SelfCommand {
         /// Only print the version
         #[arg(long)]
         short: bool,
-        #[arg(long, value_enum, default_value = "text")]
-        output_format: VersionFormat,
     },
 }
 
@@ -888,12 +1124,30 @@ pub enum CacheCommand {
     /// wheels, source distributions, and other cached data. By default, outputs the size in raw
     /// bytes; use `--human` for human-readable output.
     Size(SizeArgs),
+    /// Show a breakdown of the cache size.
+    ///
+    /// Displays the size of each cache bucket (e.g., downloaded wheels, built wheels, and index
+    /// metadata), along with the packages that occupy the most space, to guide pruning and the
+    /// design of cache keys in continuous integration.
+    Info(CacheInfoArgs),
+    /// Verify the integrity of the cache.
+    ///
+    /// Re-hashes the files in each unzipped wheel against the digests recorded in the wheel's
+    /// `RECORD` file. Corrupt wheels are moved out of the cache, such that they're downloaded (or
+    /// built) again on next use.
+    Verify(CacheVerifyArgs),
+    /// Populate the cache with every distribution in the project's lockfile.
+    ///
+    /// Downloads (and, for source distributions, builds) every package referenced by the
+    /// `uv.lock`, across all extras and dependency groups, without installing them. A subsequent
+    /// `uv sync --offline` can then be performed without network access.
+    Warm(Box<CacheWarmArgs>),
 }
 
 #[derive(Args, Debug)]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
-    #[arg(value_hint = ValueHint::Other)]
+    #[arg(value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Vec<PackageName>,
 
     /// Force removal of the cache, ignoring in-use checks.
@@ -902,6 +1156,14 @@ pub struct CleanArgs {
     /// `--force` is used, `uv cache clean` will proceed without taking a lock.
     #[arg(long)]
     pub force: bool,
+
+    /// Only remove the wheels that were built from source distributions.
+    ///
+    /// The source distributions themselves, along with pre-built wheels and index metadata, are
+    /// retained, such that the wheels are rebuilt (but not re-downloaded) on next use. This is
+    /// useful after upgrading a build toolchain (e.g., a compiler).
+    #[arg(long)]
+    pub built_only: bool,
 }
 
 #[derive(Args, Debug)]
@@ -922,6 +1184,23 @@ pub struct PruneArgs {
     #[arg(long)]
     pub ci: bool,
 
+    /// Remove unzipped wheels and Python downloads that haven't been used within the given
+    /// duration (e.g., `30d`).
+    ///
+    /// Durations support the `s`, `m`, `h`, `d`, and `w` suffixes, for seconds, minutes, hours,
+    /// days, and weeks, respectively. An entry's last use is determined by its access time, as
+    /// reported by the filesystem.
+    #[arg(long, value_name = "DURATION")]
+    pub max_age: Option<CacheMaxAge>,
+
+    /// Remove the least-recently-used unzipped wheels and Python downloads until the cache fits
+    /// within the given size (e.g., `10GB`).
+    ///
+    /// Sizes support both decimal (e.g., `GB`) and binary (e.g., `GiB`) units. A bare number is
+    /// interpreted as a number of bytes.
+    #[arg(long, value_name = "SIZE")]
+    pub max_size: Option<CacheMaxSize>,
+
     /// Force removal of the cache, ignoring in-use checks.
     ///
     /// By default, `uv cache prune` will block until no process is reading the cache. When
@@ -937,6 +1216,89 @@ pub struct SizeArgs {
     pub human: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheInfoArgs {
+    /// The format in which to display the cache breakdown.
+    ///
+    /// Equivalent to the global `--output-format` option.
+    #[arg(long, value_enum, default_value_t = CacheInfoFormat::default())]
+    pub format: CacheInfoFormat,
+
+    /// The number of packages to display, ordered by size.
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheVerifyArgs {
+    /// Report corrupt entries without removing them from the cache.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Force verification of the cache, ignoring in-use checks.
+    ///
+    /// By default, `uv cache verify` will block until no process is reading the cache. When
+    /// `--force` is used, `uv cache verify` will proceed without taking a lock.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct CacheWarmArgs {
+    /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Warm the cache from the existing `uv.lock`, without checking whether it is up-to-date [env: UV_FROZEN=]
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    /// The platforms for which the cache should be warmed.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`. May be provided multiple times.
+    ///
+    /// Source distributions are only built for the current platform; for other platforms, only
+    /// pre-built wheels are downloaded.
+    ///
+    /// Defaults to the current platform.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use for the project.
+    ///
+    /// The interpreter determines the default platform, and is used to build source
+    /// distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct PipNamespace {
     #[command(subcommand)]
@@ -972,6 +1334,16 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Install(PipInstallArgs),
+    /// Download packages into a directory, without installing them.
+    ///
+    /// Resolves the given requirements and downloads the selected wheels and source distributions
+    /// into the destination directory (e.g., to populate a wheelhouse for offline installation
+    /// via `--find-links`).
+    #[command(
+        after_help = "Use `uv help pip download` for more details.",
+        after_long_help = ""
+    )]
+    Download(PipDownloadArgs),
     /// Uninstall packages from an environment.
     #[command(
         after_help = "Use `uv help pip uninstall` for more details.",
@@ -1009,6 +1381,16 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Show the disk usage of the installed packages.
+    ///
+    /// Reports the total size of the environment's installed packages, along with how much of
+    /// that size is shared with the cache (and, in turn, with other environments) via hard links,
+    /// and how much is unique to the environment (e.g., copied files and compiled bytecode).
+    #[command(
+        after_help = "Use `uv help pip size` for more details.",
+        after_long_help = ""
+    )]
+    Size(PipSizeArgs),
     /// Display debug information (unsupported)
     #[command(hide = true)]
     Debug(PipDebugArgs),
@@ -1357,6 +1739,19 @@ pub struct PipCompileArgs {
     #[arg(group = "sources", value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
     pub src_file: Vec<PathBuf>,
 
+    /// Require that a requirements file matches the given hash.
+    ///
+    /// Hashes must be provided in the `<file>=<algorithm>:<digest>` format (e.g.,
+    /// `requirements.txt=sha256:...`), where `<file>` is one of the provided requirements files,
+    /// including a URL or `-` for stdin (e.g., `--requirements-hash=-=sha256:...`). The option can
+    /// be repeated to verify multiple files; if multiple hashes are provided for the same file, its
+    /// contents must match at least one of them.
+    ///
+    /// Requirements files without a hash, and requirements files included from within other
+    /// requirements files (via `-r`), are not verified.
+    #[arg(long, value_name = "FILE=HASH")]
+    pub requirements_hash: Vec<RequirementsHash>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -1548,6 +1943,7 @@ pub struct PipCompileArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -1673,7 +2069,7 @@ pub struct PipCompileArgs {
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
-    #[arg(long, alias = "unsafe-package", value_hint = ValueHint::Other)]
+    #[arg(long, alias = "unsafe-package", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub no_emit_package: Option<Vec<PackageName>>,
 
     /// Include `--index-url` and `--extra-index-url` entries in the generated output file.
@@ -1872,6 +2268,7 @@ pub struct PipSyncArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -1936,6 +2333,14 @@ pub struct PipSyncArgs {
     #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
     pub prefix: Option<PathBuf>,
 
+    /// Don't install scripts, i.e., console and GUI entrypoints and any scripts included in the
+    /// wheel's `.data/scripts` directory.
+    ///
+    /// Useful in conjunction with `--target` when building bundles (e.g., for AWS Lambda) in which
+    /// the generated scripts would reference the installing interpreter.
+    #[arg(long)]
+    pub no_scripts: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -2079,6 +2484,19 @@ pub struct PipInstallArgs {
     )]
     pub requirements: Vec<PathBuf>,
 
+    /// Require that a requirements file matches the given hash.
+    ///
+    /// Hashes must be provided in the `<file>=<algorithm>:<digest>` format (e.g.,
+    /// `requirements.txt=sha256:...`), where `<file>` is one of the provided requirements files,
+    /// including a URL or `-` for stdin (e.g., `--requirements-hash=-=sha256:...`). The option can
+    /// be repeated to verify multiple files; if multiple hashes are provided for the same file, its
+    /// contents must match at least one of them.
+    ///
+    /// Requirements files without a hash, and requirements files included from within other
+    /// requirements files (via `-r`), are not verified.
+    #[arg(long, value_name = "FILE=HASH")]
+    pub requirements_hash: Vec<RequirementsHash>,
+
     /// Install the editable package based on the provided local file path.
     #[arg(long, short, group = "sources")]
     pub editable: Vec<String>,
@@ -2249,6 +2667,7 @@ pub struct PipInstallArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2313,6 +2732,14 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
     pub prefix: Option<PathBuf>,
 
+    /// Don't install scripts, i.e., console and GUI entrypoints and any scripts included in the
+    /// wheel's `.data/scripts` directory.
+    ///
+    /// Useful in conjunction with `--target` when building bundles (e.g., for AWS Lambda) in which
+    /// the generated scripts would reference the installing interpreter.
+    #[arg(long)]
+    pub no_scripts: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -2433,11 +2860,275 @@ pub struct PipInstallArgs {
     pub compat_args: compat::PipInstallCompatArgs,
 }
 
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipDownloadArgs {
+    /// Download all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
+    #[arg(group = "sources", value_hint = ValueHint::Other)]
+    pub package: Vec<String>,
+
+    /// Download the packages listed in the given files.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will download the
+    /// requirements for the relevant project, but not the project itself.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(
+        long,
+        short,
+        alias = "requirement",
+        group = "sources",
+        value_parser = parse_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub requirements: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's downloaded. However, including a package in a constraints file will
+    /// _not_ trigger the download of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(
+        long,
+        short,
+        alias = "constraint",
+        env = EnvVars::UV_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be downloaded, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(
+        long,
+        alias = "override",
+        env = EnvVars::UV_OVERRIDE,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    #[arg(
+        long,
+        short,
+        alias = "build-constraint",
+        env = EnvVars::UV_BUILD_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub build_constraints: Vec<Maybe<PathBuf>>,
+
+    /// Include optional dependencies from the specified extra name; may be provided more than once.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Download the specified dependency group from a `pyproject.toml`.
+    ///
+    /// If no path is provided, the `pyproject.toml` in the working directory is used.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, group = "sources")]
+    pub group: Vec<PipGroupName>,
+
+    /// The directory into which the distributions should be downloaded.
+    ///
+    /// Defaults to the current working directory. The directory will be created if it does not
+    /// already exist.
+    #[arg(long, short, value_hint = ValueHint::DirPath)]
+    pub dest: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Ignore package dependencies, instead only downloading those packages explicitly listed
+    /// on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash.
+    ///
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum Python version if
+    /// `--python-version` is not set.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Use the system Python to resolve the requirements.
+    ///
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--system`
+    /// option instructs uv to avoid using a virtual environment Python and restrict its search to
+    /// the system path.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Don't build source distributions.
+    ///
+    /// When enabled, resolving will not run arbitrary Python code. Source distributions will not
+    /// be downloaded; only wheels will be selected.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't download pre-built wheels.
+    ///
+    /// The source distributions for the given packages will be downloaded instead. The resolver
+    /// will still use pre-built wheels to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only download pre-built wheels; don't download source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// The Python version for which distributions should be downloaded (e.g., `3.8` or `3.8.17`).
+    ///
+    /// If a patch version is omitted, the minimum patch version is assumed. For example, `3.8` is
+    /// mapped to `3.8.0`.
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which distributions should be downloaded.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// When targeting macOS (Darwin), the default minimum version is `13.0`. Use
+    /// `MACOSX_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// When targeting iOS, the default minimum version is `13.0`. Use
+    /// `IPHONEOS_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// When targeting Android, the default minimum Android API level is `24`. Use
+    /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
+    ///
+    /// When specified, uv will select wheels that are compatible with the _target_ platform.
+    /// Combine with `--only-binary :all:` to ensure that every downloaded distribution is
+    /// installable on the target platform without a build step.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct PipUninstallArgs {
     /// Uninstall all listed packages.
-    #[arg(group = "sources", value_hint = ValueHint::Other)]
+    #[arg(group = "sources", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::installed))]
     pub package: Vec<String>,
 
     /// Uninstall the packages listed in the given files.
@@ -2462,6 +3153,7 @@ pub struct PipUninstallArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2536,6 +3228,35 @@ pub struct PipFreezeArgs {
     #[arg(long)]
     pub r#exclude: Vec<PackageName>,
 
+    /// The format in which to display the installed packages.
+    ///
+    /// By default, packages are displayed in `requirements.txt` format. The `CycloneDX` formats
+    /// export a software bill of materials (SBOM) of the installed packages, including their
+    /// licenses, direct URL sources, and dependencies, in the same form as `uv export`.
+    #[arg(long, value_enum, default_value_t = PipFreezeFormat::default())]
+    pub format: PipFreezeFormat,
+
+    /// Include the hashes of each installed distribution in the output.
+    ///
+    /// Hashes are emitted in `requirements.txt` format (e.g., `--hash=sha256:...`), such that
+    /// the output can be installed with `--require-hashes`.
+    ///
+    /// Hashes are read from the installation records, and are only available for distributions
+    /// whose archive hashes were known at installation time (e.g., when installing from a
+    /// lockfile or with `--require-hashes`).
+    #[arg(long)]
+    pub hashes: bool,
+
+    /// Record the environment's Python interpreter in the SBOM.
+    ///
+    /// The implementation and version of the interpreter are included in the SBOM's metadata as
+    /// the `uv:python:implementation` and `uv:python:version` properties. They're omitted by
+    /// default, such that the SBOM is reproducible with `SOURCE_DATE_EPOCH`.
+    ///
+    /// Only supported for the `CycloneDX` formats.
+    #[arg(long)]
+    pub include_interpreter: bool,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2558,6 +3279,7 @@ pub struct PipFreezeArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2646,6 +3368,7 @@ pub struct PipListArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2693,6 +3416,7 @@ pub struct PipCheckArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2743,7 +3467,7 @@ pub struct PipCheckArgs {
 #[derive(Args)]
 pub struct PipShowArgs {
     /// The package(s) to display.
-    #[arg(value_hint = ValueHint::Other)]
+    #[arg(value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::installed))]
     pub package: Vec<PackageName>,
 
     /// Validate the Python environment, to detect packages with missing dependencies and other
@@ -2772,6 +3496,7 @@ pub struct PipShowArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2803,6 +3528,47 @@ pub struct PipShowArgs {
     pub compat_args: compat::PipGlobalCompatArgs,
 }
 
+#[derive(Args)]
+pub struct PipSizeArgs {
+    /// The format in which to display the disk usage.
+    #[arg(long, value_enum, default_value_t = PipSizeFormat::default())]
+    pub format: PipSizeFormat,
+
+    /// The Python interpreter for which the disk usage should be reported.
+    ///
+    /// By default, uv reports on packages in a virtual environment but will report on packages in
+    /// a system Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Report on packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+}
+
 #[derive(Args)]
 pub struct PipTreeArgs {
     /// Show the version constraint(s) imposed on each package.
@@ -2837,6 +3603,7 @@ pub struct PipTreeArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -2890,7 +3657,7 @@ pub struct BuildArgs {
     /// directory if no source directory is provided.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
-    #[arg(long, conflicts_with("all_packages"), value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with("all_packages"), value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Option<PackageName>,
 
     /// Builds all packages in the workspace.
@@ -2948,6 +3715,35 @@ pub struct BuildArgs {
     #[arg(long)]
     pub clear: bool,
 
+    /// Check the built distributions for common packaging problems.
+    ///
+    /// Fails the build if a wheel or source distribution contains absolute paths or paths outside
+    /// the archive, a wheel's files don't match its `RECORD`, a distribution exceeds PyPI's default
+    /// upload limit of 100 MiB, or it contains files that should not be distributed, such as
+    /// `.git`, `__pycache__` or `.env`. If `SOURCE_DATE_EPOCH` is set, files must not have a
+    /// timestamp newer than it, to catch non-reproducible builds.
+    #[arg(long, conflicts_with = "list")]
+    pub check: bool,
+
+    /// Write a `CycloneDX` SBOM of the build environment next to each built distribution.
+    ///
+    /// The SBOM describes the build backend, the packages installed in the build environment and
+    /// the Python interpreter used for the build, and is written to
+    /// `<distribution>.build.cdx.json`. For wheels built from a source distribution, the SBOM also
+    /// includes the source distribution and its hash.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, conflicts_with = "list")]
+    pub sbom: bool,
+
+    /// Record the source distribution that a wheel was built from in the wheel's metadata.
+    ///
+    /// When a wheel is built from a source distribution, which is the default when building both,
+    /// the filename and SHA-256 hash of the source distribution are written to
+    /// `source_distribution.json` in the wheel's `.dist-info` directory.
+    #[arg(long, conflicts_with = "list")]
+    pub record_sdist: bool,
+
     #[arg(long, overrides_with("no_create_gitignore"), hide = true)]
     pub create_gitignore: bool,
 
@@ -3031,9 +3827,28 @@ pub struct BuildArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
+    /// The platform for which wheels should be built.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Wheels built with the uv build backend are platform-independent, and are unaffected. Other
+    /// build backends are instructed to target the given platform via `_PYTHON_HOST_PLATFORM`
+    /// (along with `ARCHFLAGS` and `MACOSX_DEPLOYMENT_TARGET` for macOS, and `VSCMD_ARG_TGT_ARCH`
+    /// for Windows), and uv fails if the resulting wheel isn't compatible with the target
+    /// platform. Compiling extension modules for another platform also requires a
+    /// cross-compiler, which can be provided via `extra-build-variables` (e.g., `CC`).
+    ///
+    /// When targeting macOS (Darwin), the default minimum version is `13.0`. Use
+    /// `MACOSX_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    #[arg(long, alias = "platform", help_heading = "Python options")]
+    pub python_platform: Option<TargetTriple>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3060,6 +3875,7 @@ pub struct VenvArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -3445,6 +4261,7 @@ pub struct InitArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 }
@@ -3507,7 +4324,7 @@ pub struct RunArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with_all = ["only_group", "only_dev"], value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"], value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::groups))]
     pub group: Vec<GroupName>,
 
     /// Disable the specified dependency group.
@@ -3516,7 +4333,7 @@ pub struct RunArgs {
     /// `--all-groups`, and `--group`.
     ///
     /// May be provided multiple times.
-    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ', value_hint = ValueHint::Other)]
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ', value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::groups))]
     pub no_group: Vec<GroupName>,
 
     /// Ignore the default dependency groups.
@@ -3531,7 +4348,7 @@ pub struct RunArgs {
     /// The project and its dependencies will be omitted.
     ///
     /// May be provided multiple times. Implies `--no-default-groups`.
-    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"], value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"], value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::groups))]
     pub only_group: Vec<GroupName>,
 
     /// Include dependencies from all dependency groups.
@@ -3578,7 +4395,7 @@ pub struct RunArgs {
     /// Load environment variables from a `.env` file.
     ///
     /// Can be provided multiple times, with subsequent files overriding values defined in previous
-    /// files.
+    /// files. Replaces any files set via the `env-file` setting.
     #[arg(long, env = EnvVars::UV_ENV_FILE, value_hint = ValueHint::FilePath)]
     pub env_file: Vec<String>,
 
@@ -3653,6 +4470,16 @@ pub struct RunArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_sync: bool,
 
+    /// Recreate the cached environment for a PEP 723 script before running it.
+    ///
+    /// By default, the environment for a script is cached and reused across invocations, and only
+    /// updated if the script's dependencies are no longer satisfied. With `--refresh-script`, the
+    /// cached environment is removed and rebuilt from scratch.
+    ///
+    /// Has no effect when the target is not a script with inline metadata.
+    #[arg(long, conflicts_with_all = ["active", "no_sync"])]
+    pub refresh_script: bool,
+
     /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -3704,7 +4531,7 @@ pub struct RunArgs {
     /// Run the command in a specific package in the workspace.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
-    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Option<PackageName>,
 
     /// Avoid discovering the project or workspace.
@@ -3731,6 +4558,7 @@ pub struct RunArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -3794,10 +4622,6 @@ pub struct SyncArgs {
     )]
     pub extra: Option<Vec<ExtraName>>,
 
-    /// Select the output format.
-    #[arg(long, value_enum, default_value_t = SyncFormat::default())]
-    pub output_format: SyncFormat,
-
     /// Include all optional dependencies.
     ///
     /// When two or more extras are declared as conflicting in `tool.uv.conflicts`, using this flag
@@ -3844,7 +4668,7 @@ pub struct SyncArgs {
     /// `tool.uv.conflicts`, uv will report an error.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with_all = ["only_group", "only_dev"], value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"], value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::groups))]
     pub group: Vec<GroupName>,
 
     /// Disable the specified dependency group.
@@ -3853,7 +4677,7 @@ pub struct SyncArgs {
     /// `--all-groups`, and `--group`.
     ///
     /// May be provided multiple times.
-    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ', value_hint = ValueHint::Other)]
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ', value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::groups))]
     pub no_group: Vec<GroupName>,
 
     /// Ignore the default dependency groups.
@@ -3868,7 +4692,7 @@ pub struct SyncArgs {
     /// The project and its dependencies will be omitted.
     ///
     /// May be provided multiple times. Implies `--no-default-groups`.
-    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"], value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"], value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::groups))]
     pub only_group: Vec<GroupName>,
 
     /// Include dependencies from all dependency groups.
@@ -3912,6 +4736,43 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Make the project virtual environment relocatable.
+    ///
+    /// A relocatable virtual environment can be moved around and redistributed without invalidating
+    /// its associated entrypoint and activation scripts, e.g., when copying the environment into a
+    /// different location in a later stage of a container build.
+    ///
+    /// If the existing project environment is not relocatable, it will be recreated.
+    ///
+    /// Note that this can only be guaranteed for standard `console_scripts` and `gui_scripts`.
+    /// Other scripts may be adjusted if they ship with a generic `#!python[w]` shebang, and
+    /// binaries are left as-is.
+    #[arg(long, conflicts_with = "script")]
+    pub relocatable: bool,
+
+    /// Install into the given root filesystem, rather than the project virtual environment.
+    ///
+    /// The directory is treated as the root of the filesystem (`/`) in which the selected Python
+    /// interpreter is installed, and packages are installed under the interpreter's prefix within
+    /// it (e.g., `<dir>/usr/local/lib/python3.12/site-packages` for an interpreter with the
+    /// `/usr/local` prefix). No virtual environment is created, and scripts are written with
+    /// shebangs that point to the interpreter, such that the directory can be added to a container
+    /// image with the same interpreter (e.g., as a layer of a distroless image).
+    ///
+    /// Packages that are already installed in the interpreter's own environment are ignored. A
+    /// virtual environment is never used, so the interpreter is always a system (or managed)
+    /// Python installation.
+    ///
+    /// When combined with `--relocatable`, scripts refer to the interpreter relative to their own
+    /// location instead, such that the directory remains valid if the interpreter's prefix is
+    /// moved within the image.
+    #[arg(
+        long,
+        conflicts_with_all = ["script", "active"],
+        value_hint = ValueHint::DirPath,
+    )]
+    pub target_rootfs: Option<PathBuf>,
+
     /// Do not install the current project.
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -3969,11 +4830,11 @@ pub struct SyncArgs {
     ///
     /// The inverse `--only-install-package` can be used to install _only_ the specified packages,
     /// excluding all others.
-    #[arg(long, conflicts_with = "only_install_package", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "only_install_package", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub no_install_package: Vec<PackageName>,
 
     /// Only install the given package(s).
-    #[arg(long, conflicts_with = "no_install_package", hide = true, value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "no_install_package", hide = true, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub only_install_package: Vec<PackageName>,
 
     /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
@@ -3999,6 +4860,14 @@ pub struct SyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Watch the project for changes and sync the environment again when they occur.
+    ///
+    /// uv watches the `pyproject.toml` files of the workspace members, the lockfile, and
+    /// `.python-version`, and keeps running until interrupted. Each sync only applies the changes
+    /// to the environment. Settings, e.g., from `uv.toml`, are read once when the watch starts.
+    #[arg(long, conflicts_with_all = ["dry_run", "check", "script"])]
+    pub watch: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -4023,7 +4892,7 @@ pub struct SyncArgs {
     /// declared by the specified workspace member packages.
     ///
     /// If any workspace member does not exist, uv will exit with an error.
-    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Vec<PackageName>,
 
     /// Sync the environment for a Python script, rather than the current project.
@@ -4071,6 +4940,7 @@ pub struct SyncArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -4145,6 +5015,23 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Offer to fix the project's requirements if resolution fails.
+    ///
+    /// If the project's requirements can't be resolved, uv will propose edits to the
+    /// `pyproject.toml` that may resolve the conflict (e.g., relaxing a version bound, adding an
+    /// override, or declaring extras or groups as conflicting), apply any that are accepted, and
+    /// retry.
+    ///
+    /// In a non-interactive terminal, the proposed edits are displayed but not applied.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked",
+        conflicts_with = "script"
+    )]
+    pub interactive: bool,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -4178,6 +5065,7 @@ pub struct LockArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 }
@@ -4254,6 +5142,7 @@ pub struct AddArgs {
         conflicts_with("optional"),
         conflicts_with("script"),
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::groups),
     )]
     pub group: Option<GroupName>,
 
@@ -4338,6 +5227,17 @@ pub struct AddArgs {
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Offer to fix the project's requirements if resolution fails.
+    ///
+    /// If the requirements can't be resolved after adding the dependencies, uv will propose edits
+    /// to the `pyproject.toml` that may resolve the conflict (e.g., relaxing a version bound,
+    /// adding an override, or declaring extras or groups as conflicting), apply any that are
+    /// accepted, and retry.
+    ///
+    /// In a non-interactive terminal, the proposed edits are displayed but not applied.
+    #[arg(long, conflicts_with_all = ["locked", "frozen", "script"])]
+    pub interactive: bool,
+
     /// Prefer the active virtual environment over the project's virtual environment.
     ///
     /// If the project virtual environment is active or no virtual environment is active, this has
@@ -4361,7 +5261,7 @@ pub struct AddArgs {
     pub refresh: RefreshArgs,
 
     /// Add the dependency to a specific package in the workspace.
-    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Option<PackageName>,
 
     /// Add the dependency to the specified Python script, rather than to a project.
@@ -4391,6 +5291,7 @@ pub struct AddArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -4506,6 +5407,7 @@ pub struct AddArgs {
         conflicts_with = "no_sync",
         conflicts_with = "only_install_package",
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::packages),
     )]
     pub no_install_package: Vec<PackageName>,
 
@@ -4517,6 +5419,7 @@ pub struct AddArgs {
         conflicts_with = "no_install_package",
         hide = true,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::packages),
     )]
     pub only_install_package: Vec<PackageName>,
 }
@@ -4524,7 +5427,7 @@ pub struct AddArgs {
 #[derive(Args)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true, value_hint = ValueHint::Other)]
+    #[arg(required = true, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub packages: Vec<Requirement<VerbatimParsedUrl>>,
 
     /// Remove the packages from the development dependency group [env: UV_DEV=]
@@ -4550,6 +5453,7 @@ pub struct RemoveArgs {
         conflicts_with("optional"),
         conflicts_with("script"),
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::groups),
     )]
     pub group: Option<GroupName>,
 
@@ -4593,7 +5497,7 @@ pub struct RemoveArgs {
     pub refresh: RefreshArgs,
 
     /// Remove the dependencies from a specific package in the workspace.
-    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "isolated", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Option<PackageName>,
 
     /// Remove the dependency from the specified Python script, rather than from a project.
@@ -4614,6 +5518,7 @@ pub struct RemoveArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 }
@@ -4659,7 +5564,7 @@ pub struct TreeArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"], add = ArgValueCandidates::new(complete::groups))]
     pub group: Vec<GroupName>,
 
     /// Disable the specified dependency group.
@@ -4668,7 +5573,7 @@ pub struct TreeArgs {
     /// `--all-groups`, and `--group`.
     ///
     /// May be provided multiple times.
-    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ', add = ArgValueCandidates::new(complete::groups))]
     pub no_group: Vec<GroupName>,
 
     /// Ignore the default dependency groups.
@@ -4683,7 +5588,7 @@ pub struct TreeArgs {
     /// The project and its dependencies will be omitted.
     ///
     /// May be provided multiple times. Implies `--no-default-groups`.
-    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"], add = ArgValueCandidates::new(complete::groups))]
     pub only_group: Vec<GroupName>,
 
     /// Include dependencies from all dependency groups.
@@ -4754,6 +5659,7 @@ pub struct TreeArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 }
@@ -4763,12 +5669,18 @@ pub struct ExportArgs {
     #[expect(clippy::doc_markdown)]
     /// The format to which `uv.lock` should be exported.
     ///
-    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751), CycloneDX v1.5 and v1.4 (JSON or
+    /// XML), and SPDX v2.3 JSON output formats.
     ///
-    /// uv will infer the output format from the file extension of the output file, if
-    /// provided. Otherwise, defaults to `requirements.txt`.
-    #[arg(long, value_enum)]
-    pub format: Option<ExportFormat>,
+    /// uv will infer the output format from the name of the output file, if provided, recognizing
+    /// `pylock.toml`, `.cdx.json`, `.cdx.xml`, and `.spdx.json` files. Otherwise, defaults to
+    /// `requirements.txt`.
+    ///
+    /// Additional formats can be provided by external executables: `ext:<name>` runs
+    /// `uv-export-<name>` from the `PATH` in the project root, with the contents of `uv.lock` on
+    /// stdin, and writes its stdout as the export.
+    #[arg(long)]
+    pub format: Option<ExportFormatArg>,
 
     /// Export the entire workspace.
     ///
@@ -4783,14 +5695,14 @@ pub struct ExportArgs {
     /// Export the dependencies for specific packages in the workspace.
     ///
     /// If any workspace member does not exist, uv will exit with an error.
-    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other)]
+    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Vec<PackageName>,
 
     /// Prune the given package from the dependency tree.
     ///
     /// Pruned packages will be excluded from the exported requirements file, as will any
     /// dependencies that are no longer required after the pruned package is removed.
-    #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE")]
+    #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE", add = ArgValueCandidates::new(complete::packages))]
     pub prune: Vec<PackageName>,
 
     /// Include optional dependencies from the specified extra name.
@@ -4836,7 +5748,7 @@ pub struct ExportArgs {
     /// Include dependencies from the specified dependency group.
     ///
     /// May be provided multiple times.
-    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"], add = ArgValueCandidates::new(complete::groups))]
     pub group: Vec<GroupName>,
 
     /// Disable the specified dependency group.
@@ -4845,7 +5757,7 @@ pub struct ExportArgs {
     /// `--all-groups`, and `--group`.
     ///
     /// May be provided multiple times.
-    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ', add = ArgValueCandidates::new(complete::groups))]
     pub no_group: Vec<GroupName>,
 
     /// Ignore the default dependency groups.
@@ -4860,7 +5772,7 @@ pub struct ExportArgs {
     /// The project and its dependencies will be omitted.
     ///
     /// May be provided multiple times. Implies `--no-default-groups`.
-    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"], add = ArgValueCandidates::new(complete::groups))]
     pub only_group: Vec<GroupName>,
 
     /// Include dependencies from all dependency groups.
@@ -4894,17 +5806,52 @@ pub struct ExportArgs {
     pub no_editable: bool,
 
     /// Include hashes for all dependencies.
-    #[arg(long, overrides_with("no_hashes"), hide = true)]
+    ///
+    /// Hashes are included by default, except in `CycloneDX` SBOMs. In `CycloneDX` SBOMs, each
+    /// distribution is also included as an external reference, alongside its hash.
+    #[arg(long, overrides_with("no_hashes"))]
     pub hashes: bool,
 
     /// Omit hashes in the generated output.
     #[arg(long, overrides_with("hashes"))]
     pub no_hashes: bool,
 
+    /// Include the build requirements of packages built from local source trees in the SBOM.
+    ///
+    /// The `build-system.requires` of each project, workspace member, and path dependency is
+    /// resolved for the current interpreter, and each package is recorded as a component with the
+    /// `excluded` scope, as it's required to build the project, but isn't installed.
+    ///
+    /// Only supported for `CycloneDX` formats. Incompatible with `--frozen`.
+    #[arg(long)]
+    pub include_build_dependencies: bool,
+
+    /// Record the Python interpreter used for the export in the SBOM.
+    ///
+    /// The implementation and version of the interpreter are included in the SBOM's metadata as
+    /// the `uv:python:implementation` and `uv:python:version` properties. They're omitted by
+    /// default, as they vary across machines, such that an SBOM exported with `SOURCE_DATE_EPOCH`
+    /// wouldn't be reproducible.
+    ///
+    /// Only supported for `CycloneDX` formats. Has no effect with `--frozen`, as no interpreter is
+    /// discovered.
+    #[arg(long)]
+    pub include_interpreter: bool,
+
     /// Write the exported requirements to the given file.
     #[arg(long, short, value_hint = ValueHint::FilePath)]
     pub output_file: Option<PathBuf>,
 
+    /// Sign the exported SBOM with the private key at the given path.
+    ///
+    /// A detached attestation is written alongside the output file, to `<output-file>.intoto.jsonl`:
+    /// an in-toto statement with the SBOM as its predicate, in a DSSE envelope signed by the key.
+    ///
+    /// The key must be an ECDSA (P-256 or P-384) or Ed25519 private key, encoded as PKCS#8 in PEM
+    /// format. Requires `--output-file`, and a JSON-encoded `CycloneDX` or SPDX format.
+    #[arg(long, value_hint = ValueHint::FilePath, env = EnvVars::UV_SIGNING_KEY, requires = "output_file")]
+    pub signing_key: Option<PathBuf>,
+
     /// Do not emit the current project.
     ///
     /// By default, the current project is included in the exported requirements file with all of
@@ -4985,6 +5932,7 @@ pub struct ExportArgs {
         alias = "no-install-package",
         conflicts_with = "only_emit_package",
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::packages),
     )]
     pub no_emit_package: Vec<PackageName>,
 
@@ -4995,6 +5943,7 @@ pub struct ExportArgs {
         conflicts_with = "no_emit_package",
         hide = true,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::packages),
     )]
     pub only_emit_package: Vec<PackageName>,
 
@@ -5049,6 +5998,7 @@ pub struct ExportArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 }
@@ -5346,6 +6296,7 @@ pub struct ToolRunArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -5545,6 +6496,7 @@ pub struct ToolInstallArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -5638,7 +6590,7 @@ pub struct ToolDirArgs {
 #[derive(Args)]
 pub struct ToolUninstallArgs {
     /// The name of the tool to uninstall.
-    #[arg(required = true, value_hint = ValueHint::Other)]
+    #[arg(required = true, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::tools))]
     pub name: Vec<PackageName>,
 
     /// Uninstall all tools.
@@ -5649,7 +6601,7 @@ pub struct ToolUninstallArgs {
 #[derive(Args)]
 pub struct ToolUpgradeArgs {
     /// The name of the tool to upgrade, along with an optional version specifier.
-    #[arg(required = true, value_hint = ValueHint::Other)]
+    #[arg(required = true, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::tools))]
     pub name: Vec<String>,
 
     /// Upgrade all tools.
@@ -5668,6 +6620,7 @@ pub struct ToolUpgradeArgs {
         help_heading = "Python options",
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::python_versions),
     )]
     pub python: Option<Maybe<String>>,
 
@@ -6060,6 +7013,7 @@ pub struct PythonListArgs {
     /// A Python request to filter by.
     ///
     /// See `uv help python` to view supported request formats.
+    #[arg(add = ArgValueCandidates::new(complete::python_versions))]
     pub request: Option<String>,
 
     /// List all Python versions, including old patch versions.
@@ -6098,10 +7052,6 @@ pub struct PythonListArgs {
     #[arg(long)]
     pub show_urls: bool,
 
-    /// Select the output format.
-    #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
-    pub output_format: PythonListFormat,
-
     /// URL pointing to JSON of custom Python installations.
     #[arg(long, value_hint = ValueHint::Other)]
     pub python_downloads_json_url: Option<String>,
@@ -6304,7 +7254,7 @@ pub struct PythonUpgradeArgs {
     /// The Python minor version(s) to upgrade.
     ///
     /// If no target version is provided, then uv will upgrade all managed CPython versions.
-    #[arg(env = EnvVars::UV_PYTHON)]
+    #[arg(env = EnvVars::UV_PYTHON, add = ArgValueCandidates::new(complete::python_versions))]
     pub targets: Vec<String>,
 
     /// Set the URL to use as the source for downloading Python installations.
@@ -6361,7 +7311,7 @@ pub struct PythonUninstallArgs {
     /// The Python version(s) to uninstall.
     ///
     /// See `uv help python` to view supported request formats.
-    #[arg(required = true)]
+    #[arg(required = true, add = ArgValueCandidates::new(complete::python_versions))]
     pub targets: Vec<String>,
 
     /// Uninstall all managed Python versions.
@@ -6374,6 +7324,7 @@ pub struct PythonFindArgs {
     /// The Python request.
     ///
     /// See `uv help python` to view supported request formats.
+    #[arg(add = ArgValueCandidates::new(complete::python_versions))]
     pub request: Option<String>,
 
     /// Avoid discovering a project or workspace.
@@ -6433,6 +7384,7 @@ pub struct PythonPinArgs {
     /// If no request is provided, the currently pinned version will be shown.
     ///
     /// See `uv help python` to view supported request formats.
+    #[arg(add = ArgValueCandidates::new(complete::python_versions))]
     pub request: Option<String>,
 
     /// Write the resolved Python interpreter path instead of the request.
@@ -6734,7 +7686,7 @@ pub struct RefreshArgs {
     pub no_refresh: bool,
 
     /// Refresh cached data for a specific package.
-    #[arg(long, help_heading = "Cache options", value_hint = ValueHint::Other)]
+    #[arg(long, help_heading = "Cache options", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub refresh_package: Vec<PackageName>,
 }
 
@@ -6769,6 +7721,7 @@ pub struct BuildOptionsArgs {
         env = EnvVars::UV_NO_BUILD_PACKAGE,
         value_delimiter = ' ',
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::packages),
     )]
     pub no_build_package: Vec<PackageName>,
 
@@ -6800,6 +7753,7 @@ pub struct BuildOptionsArgs {
         env = EnvVars::UV_NO_BINARY_PACKAGE,
         value_delimiter = ' ',
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::packages),
     )]
     pub no_binary_package: Vec<PackageName>,
 }
@@ -6830,7 +7784,7 @@ pub struct InstallerArgs {
 
     /// Reinstall a specific package, regardless of whether it's already installed. Implies
     /// `--refresh-package`.
-    #[arg(long, help_heading = "Installer options", value_hint = ValueHint::Other)]
+    #[arg(long, help_heading = "Installer options", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub reinstall_package: Vec<PackageName>,
 
     /// The strategy to use when resolving against multiple index URLs.
@@ -6986,7 +7940,7 @@ pub struct InstallerArgs {
     pub no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages.
-    #[arg(long, help_heading = "Resolver options", env = EnvVars::UV_NO_SOURCES_PACKAGE, value_delimiter = ' ')]
+    #[arg(long, help_heading = "Resolver options", env = EnvVars::UV_NO_SOURCES_PACKAGE, value_delimiter = ' ', add = ArgValueCandidates::new(complete::packages))]
     pub no_sources_package: Vec<PackageName>,
 }
 
@@ -7016,7 +7970,7 @@ pub struct ResolverArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output
     /// file. Implies `--refresh-package`.
-    #[arg(long, short = 'P', help_heading = "Resolver options")]
+    #[arg(long, short = 'P', help_heading = "Resolver options", add = ArgValueCandidates::new(complete::packages))]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
     /// The strategy to use when resolving against multiple index URLs.
@@ -7125,7 +8079,7 @@ pub struct ResolverArgs {
     /// Disable isolation when building source distributions for a specific package.
     ///
     /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
-    #[arg(long, help_heading = "Build options", value_hint = ValueHint::Other)]
+    #[arg(long, help_heading = "Build options", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub no_build_isolation_package: Vec<PackageName>,
 
     #[arg(
@@ -7197,7 +8151,7 @@ pub struct ResolverArgs {
     pub no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages.
-    #[arg(long, help_heading = "Resolver options", env = EnvVars::UV_NO_SOURCES_PACKAGE, value_delimiter = ' ')]
+    #[arg(long, help_heading = "Resolver options", env = EnvVars::UV_NO_SOURCES_PACKAGE, value_delimiter = ' ', add = ArgValueCandidates::new(complete::packages))]
     pub no_sources_package: Vec<PackageName>,
 }
 
@@ -7227,7 +8181,7 @@ pub struct ResolverInstallerArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output file.
     /// Implies `--refresh-package`.
-    #[arg(long, short = 'P', help_heading = "Resolver options", value_hint = ValueHint::Other)]
+    #[arg(long, short = 'P', help_heading = "Resolver options", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
     /// Reinstall all packages, regardless of whether they're already installed. Implies
@@ -7250,7 +8204,7 @@ pub struct ResolverInstallerArgs {
 
     /// Reinstall a specific package, regardless of whether it's already installed. Implies
     /// `--refresh-package`.
-    #[arg(long, help_heading = "Installer options", value_hint = ValueHint::Other)]
+    #[arg(long, help_heading = "Installer options", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub reinstall_package: Vec<PackageName>,
 
     /// The strategy to use when resolving against multiple index URLs.
@@ -7361,7 +8315,7 @@ pub struct ResolverInstallerArgs {
     /// Disable isolation when building source distributions for a specific package.
     ///
     /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
-    #[arg(long, help_heading = "Build options", value_hint = ValueHint::Other)]
+    #[arg(long, help_heading = "Build options", value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub no_build_isolation_package: Vec<PackageName>,
 
     #[arg(
@@ -7465,7 +8419,7 @@ pub struct ResolverInstallerArgs {
     pub no_sources: bool,
 
     /// Don't use sources from the `tool.uv.sources` table for the specified packages.
-    #[arg(long, help_heading = "Resolver options", env = EnvVars::UV_NO_SOURCES_PACKAGE, value_delimiter = ' ')]
+    #[arg(long, help_heading = "Resolver options", env = EnvVars::UV_NO_SOURCES_PACKAGE, value_delimiter = ' ', add = ArgValueCandidates::new(complete::packages))]
     pub no_sources_package: Vec<PackageName>,
 }
 
@@ -7524,11 +8478,11 @@ pub struct DisplayTreeArgs {
     pub depth: u8,
 
     /// Prune the given package from the display of the dependency tree.
-    #[arg(long, value_hint = ValueHint::Other)]
+    #[arg(long, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub prune: Vec<PackageName>,
 
     /// Display only the specified packages.
-    #[arg(long, value_hint = ValueHint::Other)]
+    #[arg(long, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Vec<PackageName>,
 
     /// Do not de-duplicate repeated dependencies. Usually, when a package has already displayed its
@@ -7563,6 +8517,12 @@ pub struct PublishArgs {
 
     /// The name of an index in the configuration to use for publishing.
     ///
+    /// Can be provided multiple times to publish the same files to multiple indexes. Each index
+    /// is published to with its own credentials, which are read from `UV_INDEX_<NAME>_USERNAME`
+    /// and `UV_INDEX_<NAME>_PASSWORD`, the keyring, or trusted publishing, and its own check for
+    /// existing files. If publishing to one index fails, uv continues with the remaining indexes
+    /// and reports the outcome for each index at the end.
+    ///
     /// The index must have a `publish-url` setting, for example:
     ///
     /// ```toml
@@ -7584,11 +8544,13 @@ pub struct PublishArgs {
         long,
         verbatim_doc_comment,
         env = EnvVars::UV_PUBLISH_INDEX,
+        value_delimiter = ',',
         conflicts_with = "publish_url",
         conflicts_with = "check_url",
         value_hint = ValueHint::Other,
+        add = ArgValueCandidates::new(complete::indexes),
     )]
-    pub index: Option<String>,
+    pub index: Vec<String>,
 
     /// The username for the upload.
     #[arg(
@@ -7630,7 +8592,10 @@ pub struct PublishArgs {
     /// By default, uv checks for trusted publishing when running in a supported environment, but
     /// ignores it if it isn't configured.
     ///
-    /// uv's supported environments for trusted publishing include GitHub Actions and GitLab CI/CD.
+    /// uv's supported environments for trusted publishing include GitHub Actions, GitLab CI/CD and
+    /// Buildkite. On other CI providers, pass an OIDC token minted for the index's audience through
+    /// `UV_PUBLISH_OIDC_TOKEN`. The token exchange endpoint and the audience can be configured with
+    /// `UV_PUBLISH_OIDC_EXCHANGE_URL` and `UV_PUBLISH_OIDC_AUDIENCE`.
     #[arg(long)]
     pub trusted_publishing: Option<TrustedPublishing>,
 
@@ -7674,8 +8639,13 @@ pub struct PublishArgs {
 
     /// Perform a dry run without uploading files.
     ///
-    /// When enabled, the command will check for existing files if `--check-url` is provided,
-    /// and will perform validation against the index if supported, but will not upload any files.
+    /// When enabled, the command will check the metadata of each file, similar to `twine check`,
+    /// i.e., that the description has a supported content type and renders, and that the
+    /// classifiers and the license expression are valid. It will check for existing files if
+    /// `--check-url` is provided, and will perform validation against the index if supported. It
+    /// then reports the files that would be uploaded to each index, but will not upload any files.
+    ///
+    /// In a dry run, uv does not prompt for credentials or request a trusted publishing token.
     #[arg(long)]
     pub dry_run: bool,
 
@@ -7726,7 +8696,7 @@ pub struct MetadataArgs;
 #[derive(Args, Debug)]
 pub struct WorkspaceDirArgs {
     /// Display the path to a specific package in the workspace.
-    #[arg(long, value_hint = ValueHint::Other)]
+    #[arg(long, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::packages))]
     pub package: Option<PackageName>,
 }
 
@@ -7737,6 +8707,290 @@ pub struct WorkspaceListArgs {
     pub paths: bool,
 }
 
+#[derive(Args)]
+pub struct GenerateNamespace {
+    #[command(subcommand)]
+    pub command: GenerateCommand,
+}
+
+#[derive(Subcommand)]
+pub enum GenerateCommand {
+    /// Generate a development container configuration for the project.
+    ///
+    /// Writes a `.devcontainer/devcontainer.json` to the workspace root, using a uv image for the
+    /// project's Python version, a named volume for the uv cache, and a `uv sync` command that
+    /// runs when the container is created. The project virtual environment is kept in a separate
+    /// volume, such that it does not conflict with an environment on the host.
+    ///
+    /// The Python version is read from the `--python` option, a `.python-version` file, or the
+    /// lower bound of the project's `requires-python`, in that order.
+    Devcontainer(GenerateDevcontainerArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct GenerateDevcontainerArgs {
+    /// The Python version to use in the container image, e.g., `3.12`.
+    #[arg(long, short, value_hint = ValueHint::Other, add = ArgValueCandidates::new(complete::python_versions))]
+    pub python: Option<String>,
+
+    /// Define the container in a Docker Compose file.
+    ///
+    /// Writes a `.devcontainer/compose.yaml` alongside the `devcontainer.json`, such that
+    /// additional services (e.g., a database) can be added to the development environment.
+    #[arg(long)]
+    pub compose: bool,
+
+    /// Overwrite existing configuration files.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct EnvNamespace {
+    #[command(subcommand)]
+    pub command: EnvCommand,
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommand {
+    /// Display the interpreter, paths, and lockfile digest of the project environment.
+    ///
+    /// Intended for build systems that consume a uv-managed environment, e.g., to locate the
+    /// interpreter and `site-packages` directories, and to invalidate their own caches when the
+    /// lockfile changes.
+    ///
+    /// The environment is not created or synced; if it does not exist, uv will exit with an error.
+    Info(EnvInfoArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct EnvInfoArgs {
+    /// The format in which to display the environment details.
+    #[arg(long, value_enum, default_value_t = EnvInfoFormat::default())]
+    pub format: EnvInfoFormat,
+}
+
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Show the effective value of a setting.
+    ///
+    /// Settings are read from, in order of precedence: environment variables, the project
+    /// configuration (a `uv.toml` or `[tool.uv]` in the `pyproject.toml` at the workspace root), the
+    /// user configuration, and the system configuration. Settings that are locked by the system
+    /// configuration are only read from environment variables and the system configuration.
+    ///
+    /// Nested settings are referenced by their dotted name, e.g., `pip.index-url`.
+    ///
+    /// If the setting is not set, uv will exit with an error.
+    Get(ConfigGetArgs),
+    /// Write a setting to a configuration file.
+    ///
+    /// By default, the setting is written to the project configuration: the nearest `uv.toml` or
+    /// `pyproject.toml` with a `[tool.uv]` section, or else the `pyproject.toml` at the workspace
+    /// root. If the project has no `pyproject.toml`, a `uv.toml` is created in the current
+    /// directory.
+    ///
+    /// The value is parsed as TOML (e.g., `true`, `8`, or `["a", "b"]`), falling back to a string.
+    Set(ConfigSetArgs),
+    /// List the settings in each configuration file and environment variable.
+    ///
+    /// Settings are listed from lowest to highest precedence, i.e., a setting listed later
+    /// overrides the same setting listed earlier.
+    List(ConfigListArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigGetArgs {
+    /// The name of the setting, e.g., `index-url` or `pip.index-url`.
+    #[arg(value_hint = ValueHint::Other)]
+    pub key: String,
+
+    /// Show the file or environment variable that the value was read from.
+    #[arg(long)]
+    pub show_origin: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigSetArgs {
+    /// The name of the setting, e.g., `index-url` or `pip.index-url`.
+    #[arg(value_hint = ValueHint::Other)]
+    pub key: String,
+
+    /// The value of the setting.
+    #[arg(value_hint = ValueHint::Other)]
+    pub value: String,
+
+    /// Write the setting to the user configuration file, e.g., `~/.config/uv/uv.toml`.
+    #[arg(long, conflicts_with = "system")]
+    pub user: bool,
+
+    /// Write the setting to the system configuration file, e.g., `/etc/uv/uv.toml`.
+    ///
+    /// The system configuration file must already exist.
+    #[arg(long)]
+    pub system: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigListArgs {
+    /// Show the file or environment variable that each setting was read from.
+    #[arg(long)]
+    pub show_origin: bool,
+}
+
+#[derive(Args)]
+pub struct KernelNamespace {
+    #[command(subcommand)]
+    pub command: KernelCommand,
+}
+
+#[derive(Subcommand)]
+pub enum KernelCommand {
+    /// Register the project environment as a Jupyter kernel.
+    ///
+    /// Writes a kernelspec to the user-level Jupyter data directory (e.g., `~/.local/share/jupyter`
+    /// on Linux), which launches `ipykernel` with the interpreter of the project environment.
+    /// `ipykernel` must be installed in the environment, e.g., with `uv add --dev ipykernel`.
+    ///
+    /// The environment is not created or synced; if it does not exist, uv will exit with an error.
+    /// Subsequent runs of `uv sync` keep the kernelspec up-to-date with the environment.
+    Install(KernelInstallArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct KernelInstallArgs {
+    /// The name of the kernel.
+    ///
+    /// Defaults to the name of the project. Installing a kernel with the same name replaces it.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub name: Option<String>,
+
+    /// The name of the kernel shown in Jupyter.
+    ///
+    /// Defaults to the name of the kernel and the Python version of the environment, e.g.,
+    /// `project (Python 3.12)`.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub display_name: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SbomNamespace {
+    #[command(subcommand)]
+    pub command: SbomCommand,
+}
+
+#[derive(Subcommand)]
+pub enum SbomCommand {
+    /// Upload a `CycloneDX` SBOM of the project to Dependency-Track.
+    ///
+    /// The SBOM is generated from the lockfile, as with `uv export --format cyclonedx1.5`, and
+    /// accepts the same options for selecting packages, extras and groups.
+    ///
+    /// The SBOM is uploaded to the `/api/v1/bom` endpoint of the Dependency-Track server. If the
+    /// project does not exist in Dependency-Track, it is created.
+    Upload(SbomUploadArgs),
+
+    /// Audit the project's dependencies for known vulnerabilities.
+    ///
+    /// The locked packages from registries are checked against the OSV advisory database, and the
+    /// vulnerabilities affecting them are reported and recorded in the `vulnerabilities` section of
+    /// the `CycloneDX` SBOM, as with `uv export --format cyclonedx1.5`, which accepts the same
+    /// options for selecting packages, extras and groups.
+    ///
+    /// Exits with a non-zero status if any known vulnerabilities are found.
+    Audit(SbomAuditArgs),
+
+    /// Verify that the lockfile or the project environment matches an existing SBOM.
+    ///
+    /// The components of the `CycloneDX` SBOM are compared against the packages in the lockfile,
+    /// as exported with `uv export --format cyclonedx1.5`, which accepts the same options for
+    /// selecting packages, extras and groups. With `--environment`, the components are compared
+    /// against the packages installed in the project environment instead.
+    ///
+    /// Packages that are missing or extra, or whose versions or hashes don't match the SBOM, are
+    /// reported, and the command exits with a non-zero status if any are found.
+    Verify(SbomVerifyArgs),
+}
+
+#[derive(Args)]
+pub struct SbomUploadArgs {
+    /// The URL of the Dependency-Track server, e.g., `https://dtrack.example.com`.
+    #[arg(long, value_name = "URL", env = EnvVars::UV_DEPENDENCY_TRACK_URL)]
+    pub dependency_track: DisplaySafeUrl,
+
+    /// The API key to authenticate with Dependency-Track.
+    ///
+    /// The key requires the `BOM_UPLOAD` permission, and the `PROJECT_CREATION_UPLOAD`
+    /// permission if the project does not exist yet.
+    #[arg(
+        long,
+        env = EnvVars::UV_DEPENDENCY_TRACK_API_KEY,
+        hide_env_values = true,
+        value_hint = ValueHint::Other
+    )]
+    pub api_key: String,
+
+    /// The name of the project in Dependency-Track.
+    ///
+    /// Defaults to the name of the root component of the SBOM, i.e., the project name.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub project_name: Option<String>,
+
+    /// The version of the project in Dependency-Track.
+    ///
+    /// Defaults to the version of the root component of the SBOM, i.e., the project version.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub project_version: Option<String>,
+
+    /// A tag to apply to the project in Dependency-Track.
+    ///
+    /// May be provided multiple times. Tags are only applied when the project is created.
+    #[arg(long = "tag", value_name = "TAG", value_hint = ValueHint::Other)]
+    pub tags: Vec<String>,
+
+    /// Mark the uploaded version as the latest version of the project in Dependency-Track.
+    #[arg(long)]
+    pub latest: bool,
+
+    #[command(flatten)]
+    pub export: ExportArgs,
+}
+
+#[derive(Args)]
+pub struct SbomAuditArgs {
+    /// The URL of the OSV API to query for vulnerabilities.
+    ///
+    /// Defaults to `https://api.osv.dev`.
+    #[arg(long, value_name = "URL", env = EnvVars::UV_OSV_URL)]
+    pub osv_url: Option<DisplaySafeUrl>,
+
+    #[command(flatten)]
+    pub export: ExportArgs,
+}
+
+#[derive(Args)]
+pub struct SbomVerifyArgs {
+    /// The path to the `CycloneDX` SBOM to verify, in JSON or XML format.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub sbom: PathBuf,
+
+    /// Compare the SBOM against the packages installed in the project environment, rather than
+    /// the lockfile.
+    ///
+    /// The environment is not created or synced; if it doesn't exist, an error is raised.
+    #[arg(long)]
+    pub environment: bool,
+
+    #[command(flatten)]
+    pub export: ExportArgs,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
@@ -492,6 +492,7 @@ pub fn resolver_options(
             upgrade_package.into_iter().map(Requirement::from).collect(),
         ),
         index_strategy,
+        index_tracking_policy: None,
         keyring_provider,
         resolution,
         prerelease: if pre {
@@ -612,6 +613,7 @@ pub fn resolver_installer_options(
             reinstall_package,
         ),
         index_strategy,
+        index_tracking_policy: None,
         keyring_provider,
         resolution,
         prerelease: if pre {
@@ -0,0 +1,444 @@
+//! Verification of [PEP 740](https://peps.python.org/pep-0740/) attestations.
+//!
+//! An attestation binds a distribution (by filename and SHA-256 digest) to the Trusted Publisher
+//! that uploaded it: the in-toto statement naming the distribution is signed with a short-lived
+//! Sigstore certificate issued to the publisher's CI workflow.
+//!
+//! We check that the statement matches the distribution, that the signature over the statement
+//! was produced by the certificate's key, and that the certificate's identity matches the
+//! publisher declared by the index. We do _not_ verify the certificate chain against the Sigstore
+//! root of trust, nor the inclusion of the signature in the transparency log, so a passing check
+//! only establishes that the attestation is _signed_ consistently with the declared publisher, not
+//! that the certificate was issued by Sigstore.
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use ring::signature::{ECDSA_P256_SHA256_ASN1, ECDSA_P384_SHA384_ASN1, UnparsedPublicKey};
+use tokio::sync::Semaphore;
+use tracing::{debug, instrument};
+use x509_parser::extensions::GeneralName;
+
+use uv_distribution_types::{File, IndexCapabilities, IndexFormat, IndexMetadataRef, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{Attestation, HashAlgorithm, Provenance, Publisher, Statement};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::{Error, ErrorKind, MetadataFormat, RegistryClient, SimpleDetailMetadatum};
+
+/// The in-toto statement type for attestations.
+pub const IN_TOTO_STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+
+/// The DSSE payload type for in-toto statements.
+pub const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// The outcome of checking the attestations for a distribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttestationStatus {
+    /// An attestation for the distribution is signed by a certificate issued to the publisher.
+    ///
+    /// The certificate itself is not verified against the Sigstore root of trust.
+    Signed(Publisher),
+    /// The index does not publish attestations for the distribution.
+    Missing,
+    /// The index publishes attestations for the distribution, but none could be verified.
+    Invalid(String),
+}
+
+impl AttestationStatus {
+    /// Return the label used to describe the status, e.g., in an SBOM.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Signed(_) => "signed",
+            Self::Missing => "missing",
+            Self::Invalid(_) => "invalid",
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AttestationError {
+    #[error("The provenance does not contain any attestations")]
+    NoAttestations,
+    #[error("The index did not provide a SHA-256 digest for `{0}`")]
+    MissingDigest(String),
+    #[error("Failed to decode attestation")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Failed to parse attestation statement")]
+    Statement(#[from] serde_json::Error),
+    #[error("Unsupported attestation statement type: `{0}`")]
+    StatementType(String),
+    #[error("The attestation statement does not match `{0}` and its SHA-256 digest")]
+    Subject(String),
+    #[error("Failed to parse attestation certificate: {0}")]
+    Certificate(String),
+    #[error("Unsupported attestation certificate key")]
+    UnsupportedKey,
+    #[error("The attestation signature is invalid")]
+    Signature,
+    #[error("The attestation certificate was not issued to {0}")]
+    Identity(Publisher),
+    #[error("Unsupported attestation publisher: {0}")]
+    UnsupportedPublisher(Publisher),
+}
+
+/// Check a [`Provenance`] for the distribution with the given filename and SHA-256 digest.
+///
+/// Returns the [`Publisher`] of the first attestation that passes.
+pub fn verify_provenance(
+    provenance: &Provenance,
+    filename: &str,
+    sha256: &str,
+) -> Result<Publisher, AttestationError> {
+    let mut error = AttestationError::NoAttestations;
+    for bundle in &provenance.attestation_bundles {
+        for attestation in &bundle.attestations {
+            match verify_attestation(attestation, &bundle.publisher, filename, sha256) {
+                Ok(()) => return Ok(bundle.publisher.clone()),
+                Err(err) => {
+                    debug!("Failed to verify attestation for `{filename}`: {err}");
+                    error = err;
+                }
+            }
+        }
+    }
+    Err(error)
+}
+
+/// Verify a single [`Attestation`] produced by the given [`Publisher`].
+fn verify_attestation(
+    attestation: &Attestation,
+    publisher: &Publisher,
+    filename: &str,
+    sha256: &str,
+) -> Result<(), AttestationError> {
+    // Ensure that the statement refers to the distribution.
+    let payload = BASE64_STANDARD.decode(&attestation.envelope.statement)?;
+    let statement: Statement = serde_json::from_slice(&payload)?;
+    if statement.type_ != IN_TOTO_STATEMENT_TYPE {
+        return Err(AttestationError::StatementType(statement.type_));
+    }
+    if !statement.subject.iter().any(|subject| {
+        subject.name == filename
+            && subject
+                .digest
+                .get("sha256")
+                .is_some_and(|digest| digest.eq_ignore_ascii_case(sha256))
+    }) {
+        return Err(AttestationError::Subject(filename.to_string()));
+    }
+
+    // Verify the signature over the DSSE pre-authentication encoding of the statement.
+    let der = BASE64_STANDARD.decode(&attestation.verification_material.certificate)?;
+    let (_, certificate) = x509_parser::parse_x509_certificate(&der)
+        .map_err(|err| AttestationError::Certificate(err.to_string()))?;
+    let key = &certificate.public_key().subject_public_key.data;
+    let algorithm = match key.len() {
+        65 => &ECDSA_P256_SHA256_ASN1,
+        97 => &ECDSA_P384_SHA384_ASN1,
+        _ => return Err(AttestationError::UnsupportedKey),
+    };
+    let signature = BASE64_STANDARD.decode(&attestation.envelope.signature)?;
+    UnparsedPublicKey::new(algorithm, key.as_ref())
+        .verify(&dsse_pae(&payload), &signature)
+        .map_err(|_| AttestationError::Signature)?;
+
+    // Ensure that the certificate was issued to the publisher. Publishers whose identity we can't
+    // check are rejected, rather than accepted on the strength of the signature alone.
+    let identity = expected_identity(publisher)
+        .ok_or_else(|| AttestationError::UnsupportedPublisher(publisher.clone()))?;
+    let san = certificate
+        .subject_alternative_name()
+        .map_err(|err| AttestationError::Certificate(err.to_string()))?;
+    let matches = san.is_some_and(|san| {
+        san.value.general_names.iter().any(|name| {
+            matches!(name, GeneralName::URI(uri) if uri.to_ascii_lowercase().starts_with(&identity))
+        })
+    });
+    if !matches {
+        return Err(AttestationError::Identity(publisher.clone()));
+    }
+
+    Ok(())
+}
+
+/// Return the DSSE pre-authentication encoding of an in-toto statement.
+///
+/// See: <https://github.com/secure-systems-lab/dsse/blob/master/protocol.md>
+pub fn dsse_pae(payload: &[u8]) -> Vec<u8> {
+    let mut encoded = format!(
+        "DSSEv1 {} {IN_TOTO_PAYLOAD_TYPE} {} ",
+        IN_TOTO_PAYLOAD_TYPE.len(),
+        payload.len()
+    )
+    .into_bytes();
+    encoded.extend_from_slice(payload);
+    encoded
+}
+
+/// Return the (lowercase) prefix of the certificate identity expected for a [`Publisher`], if the
+/// publisher kind is known and the publisher declares its repository and workflow.
+fn expected_identity(publisher: &Publisher) -> Option<String> {
+    let repository = publisher.repository.as_deref()?;
+    let workflow = publisher.workflow.as_deref()?;
+    let identity = match publisher.kind.as_ref() {
+        "GitHub" => format!("https://github.com/{repository}/.github/workflows/{workflow}@"),
+        "GitLab" => format!("https://gitlab.com/{repository}//{workflow}@"),
+        _ => return None,
+    };
+    Some(identity.to_ascii_lowercase())
+}
+
+impl RegistryClient {
+    /// Check the PEP 740 attestations for a distribution from a registry.
+    ///
+    /// If the [`File`] doesn't include a provenance URL (e.g., because it was read from a
+    /// lockfile), the index is queried for the file's provenance.
+    #[instrument(skip_all, fields(file = %file.filename))]
+    pub async fn verify_attestations(
+        &self,
+        name: &PackageName,
+        version: &Version,
+        file: &File,
+        index: &IndexUrl,
+        capabilities: &IndexCapabilities,
+        download_concurrency: &Semaphore,
+    ) -> Result<AttestationStatus, Error> {
+        let Some(sha256) = file
+            .hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        else {
+            return Ok(AttestationStatus::Invalid(
+                AttestationError::MissingDigest(file.filename.to_string()).to_string(),
+            ));
+        };
+
+        let provenance = match &file.provenance {
+            Some(provenance) => Some(provenance.clone()),
+            None => {
+                self.provenance_url(
+                    name,
+                    version,
+                    file,
+                    index,
+                    capabilities,
+                    download_concurrency,
+                )
+                .await?
+            }
+        };
+        let Some(provenance) = provenance else {
+            return Ok(AttestationStatus::Missing);
+        };
+        let url = provenance.to_url().map_err(ErrorKind::InvalidUrl)?;
+        let Some(provenance) = self.fetch_provenance(&url).await? else {
+            return Ok(AttestationStatus::Missing);
+        };
+
+        match verify_provenance(&provenance, &file.filename, &sha256.digest) {
+            Ok(publisher) => Ok(AttestationStatus::Signed(publisher)),
+            Err(err) => Ok(AttestationStatus::Invalid(err.to_string())),
+        }
+    }
+
+    /// Look up the provenance URL for a [`File`] on the given index.
+    async fn provenance_url(
+        &self,
+        name: &PackageName,
+        version: &Version,
+        file: &File,
+        index: &IndexUrl,
+        capabilities: &IndexCapabilities,
+        download_concurrency: &Semaphore,
+    ) -> Result<Option<uv_distribution_types::FileLocation>, Error> {
+        let index = IndexMetadataRef {
+            url: index,
+            format: IndexFormat::Simple,
+        };
+        for (_, metadata) in self
+            .simple_detail(name, Some(index), capabilities, download_concurrency)
+            .await?
+        {
+            let MetadataFormat::Simple(metadata) = metadata else {
+                continue;
+            };
+            for datum in metadata.iter() {
+                let datum = rkyv::deserialize::<SimpleDetailMetadatum, rkyv::rancor::Error>(datum)
+                    .expect("archived version always deserializes");
+                if &datum.version != version {
+                    continue;
+                }
+                if let Some((_, candidate)) = datum
+                    .files
+                    .all()
+                    .find(|(_, candidate)| candidate.filename == file.filename)
+                {
+                    return Ok(candidate.provenance);
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Fetch the [`Provenance`] at the given URL.
+    ///
+    /// Returns `None` if the index does not serve a provenance object at the URL.
+    async fn fetch_provenance(&self, url: &DisplaySafeUrl) -> Result<Option<Provenance>, Error> {
+        let bytes = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+            match fs_err::tokio::read(&path).await {
+                Ok(bytes) => bytes,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(ErrorKind::Io(err).into()),
+            }
+        } else {
+            let response = self
+                .uncached_client(url)
+                .get(url::Url::from(url.clone()))
+                .header(
+                    "Accept",
+                    "application/vnd.pypi.integrity.v1+json, application/json",
+                )
+                .send()
+                .await
+                .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            response
+                .error_for_status()
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?
+                .to_vec()
+        };
+        let provenance =
+            serde_json::from_slice(&bytes).map_err(|err| Error::from_json_err(err, url.clone()))?;
+        Ok(Some(provenance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_STANDARD;
+    use rcgen::{CertificateParams, KeyPair, SanType};
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair};
+
+    use uv_pypi_types::Provenance;
+
+    use super::{AttestationError, dsse_pae, verify_provenance};
+
+    const FILENAME: &str = "example-1.0.0-py3-none-any.whl";
+    const SHA256: &str = "2d0a7f2aeb6e2e3a7b0a6c5d48f1e7b3d1c8e4f3a2b1c0d9e8f7a6b5c4d3e2f1";
+
+    /// Create a provenance object for a statement, signed by a certificate issued to the given
+    /// identity, for a publisher of the given kind.
+    fn provenance_for(statement: &str, identity: &str, kind: &str) -> Provenance {
+        let key_pair = KeyPair::generate_for(&rcgen::PKCS_ECDSA_P256_SHA256).unwrap();
+        let mut params = CertificateParams::default();
+        params.subject_alt_names = vec![SanType::URI(identity.try_into().unwrap())];
+        let certificate = params.self_signed(&key_pair).unwrap();
+
+        let signer = EcdsaKeyPair::from_pkcs8(
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &key_pair.serialize_der(),
+            &SystemRandom::new(),
+        )
+        .unwrap();
+        let signature = signer
+            .sign(&SystemRandom::new(), &dsse_pae(statement.as_bytes()))
+            .unwrap();
+
+        serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "attestation_bundles": [{
+                "publisher": {
+                    "kind": kind,
+                    "repository": "owner/example",
+                    "workflow": "release.yml",
+                },
+                "attestations": [{
+                    "version": 1,
+                    "verification_material": {
+                        "certificate": BASE64_STANDARD.encode(certificate.der()),
+                    },
+                    "envelope": {
+                        "statement": BASE64_STANDARD.encode(statement),
+                        "signature": BASE64_STANDARD.encode(signature.as_ref()),
+                    },
+                }],
+            }],
+        }))
+        .unwrap()
+    }
+
+    fn provenance(statement: &str, identity: &str) -> Provenance {
+        provenance_for(statement, identity, "GitHub")
+    }
+
+    fn statement(name: &str, sha256: &str) -> String {
+        serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": name, "digest": {"sha256": sha256}}],
+            "predicateType": "https://docs.pypi.org/attestations/publish/v1",
+            "predicate": null,
+        })
+        .to_string()
+    }
+
+    const IDENTITY: &str =
+        "https://github.com/owner/example/.github/workflows/release.yml@refs/tags/v1.0.0";
+
+    #[test]
+    fn verify_valid() {
+        let provenance = provenance(&statement(FILENAME, SHA256), IDENTITY);
+        let publisher = verify_provenance(&provenance, FILENAME, SHA256).unwrap();
+        assert_eq!(publisher.repository.as_deref(), Some("owner/example"));
+    }
+
+    #[test]
+    fn verify_digest_mismatch() {
+        let provenance = provenance(&statement(FILENAME, SHA256), IDENTITY);
+        let other = "0".repeat(64);
+        let err = verify_provenance(&provenance, FILENAME, &other).unwrap_err();
+        assert!(matches!(err, AttestationError::Subject(_)), "{err}");
+    }
+
+    #[test]
+    fn verify_identity_mismatch() {
+        let identity =
+            "https://github.com/attacker/example/.github/workflows/release.yml@refs/tags/v1.0.0";
+        let provenance = provenance(&statement(FILENAME, SHA256), identity);
+        let err = verify_provenance(&provenance, FILENAME, SHA256).unwrap_err();
+        assert!(matches!(err, AttestationError::Identity(_)), "{err}");
+    }
+
+    #[test]
+    fn verify_tampered_statement() {
+        let mut provenance = provenance(&statement(FILENAME, SHA256), IDENTITY);
+        // Replace the statement with one for a different file, keeping the original signature.
+        let tampered = statement("other-1.0.0-py3-none-any.whl", SHA256);
+        provenance.attestation_bundles[0].attestations[0]
+            .envelope
+            .statement = BASE64_STANDARD.encode(tampered);
+        let err =
+            verify_provenance(&provenance, "other-1.0.0-py3-none-any.whl", SHA256).unwrap_err();
+        assert!(matches!(err, AttestationError::Signature), "{err}");
+    }
+
+    #[test]
+    fn verify_unsupported_publisher() {
+        let provenance = provenance_for(&statement(FILENAME, SHA256), IDENTITY, "Unknown");
+        let err = verify_provenance(&provenance, FILENAME, SHA256).unwrap_err();
+        assert!(
+            matches!(err, AttestationError::UnsupportedPublisher(_)),
+            "{err}"
+        );
+    }
+}
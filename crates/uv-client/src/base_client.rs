@@ -1,13 +1,15 @@
 use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Write;
-use std::num::ParseIntError;
-use std::path::Path;
+use std::num::{NonZeroUsize, ParseIntError};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use std::{env, io, iter};
 
 use anyhow::anyhow;
+use bytes::Bytes;
+use futures::stream::BoxStream;
 use http::{
     HeaderMap, HeaderName, HeaderValue, Method, StatusCode,
     header::{
@@ -16,21 +18,25 @@ use http::{
     },
 };
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, IntoUrl, NoProxy, Proxy, Request, Response, multipart};
+use reqwest::{
+    Certificate, Client, ClientBuilder, Identity, IntoUrl, NoProxy, Proxy, Request, Response,
+    multipart,
+};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
-    RetryPolicy, RetryTransientMiddleware, Retryable, RetryableStrategy, default_on_request_error,
-    default_on_request_success,
+    Jitter, RetryPolicy, RetryTransientMiddleware, Retryable, RetryableStrategy,
+    default_on_request_error, default_on_request_success,
 };
 use thiserror::Error;
 use tracing::{debug, trace};
 use url::ParseError;
 use url::Url;
 
-use uv_auth::{AuthMiddleware, Credentials, CredentialsCache, Indexes, PyxTokenStore};
+use uv_auth::{AuthMiddleware, Credentials, CredentialsCache, Indexes, PyxTokenStore, RealmRef};
 use uv_configuration::ProxyUrlKind;
-use uv_configuration::{KeyringProviderType, ProxyUrl, TrustedHost};
+use uv_configuration::{BandwidthLimit, KeyringProviderType, ProxyUrl, TrustedHost};
+use uv_distribution_types::{RetryBackoff, RetrySettings, RetryableError};
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -41,9 +47,14 @@ use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::audit::{AuditMiddleware, audit_log};
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{InvalidCertificateMiddleware, OfflineMiddleware};
+use crate::oci::{OciAuthMiddleware, OciReference};
+use crate::policy::is_nested_under;
+use crate::resumable;
+use crate::throttle::{Throttle, ThrottleMiddleware};
+use crate::tls::{IndexCertificateError, read_certificates, read_identity};
 use crate::{Connectivity, WrappedReqwestError};
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -75,6 +86,8 @@ pub struct BaseClientBuilder<'a> {
     native_tls: bool,
     built_in_root_certs: bool,
     retries: u32,
+    /// The policy for retrying failed requests, aside from the number of retries.
+    retry: RetrySettings,
     pub connectivity: Connectivity,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
@@ -82,12 +95,22 @@ pub struct BaseClientBuilder<'a> {
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     credentials_cache: Arc<CredentialsCache>,
     indexes: Indexes,
+    /// Per-index client configuration, e.g., client certificates for mutual TLS.
+    index_configs: Vec<IndexClientConfig>,
+    /// The artifacts of any `oci://` indexes, to which registry tokens are sent.
+    oci_references: Vec<OciReference>,
     timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
     http_proxy: Option<ProxyUrl>,
     https_proxy: Option<ProxyUrl>,
     no_proxy: Option<Vec<String>>,
+    /// The maximum number of in-flight requests to each host.
+    concurrent_downloads_per_host: Option<NonZeroUsize>,
+    /// The maximum download bandwidth, shared across all requests.
+    bandwidth_limit: Option<BandwidthLimit>,
+    /// The per-host and bandwidth limits, shared by all clients built from this builder.
+    throttle: Arc<Throttle>,
     redirect_policy: RedirectPolicy,
     /// Whether credentials should be propagated during cross-origin redirects.
     ///
@@ -122,6 +145,24 @@ impl RedirectPolicy {
     }
 }
 
+/// The TLS and proxy configuration for requests to an index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexClientConfig {
+    /// The URL of the index to which the configuration applies.
+    ///
+    /// Requests to URLs under this prefix use the configuration. Other requests to the same
+    /// origin (e.g., file downloads) use it too, unless a more specific configuration applies.
+    pub url: DisplaySafeUrl,
+    /// The path to a PEM-encoded client certificate and private key.
+    pub client_cert: Option<PathBuf>,
+    /// The path to a PEM-encoded bundle of additional CA certificates to trust.
+    pub ca_cert: Option<PathBuf>,
+    /// The proxy to use for all requests, in place of the global proxy configuration.
+    pub proxy: Option<ProxyUrl>,
+    /// The policy for retrying failed requests, in place of the global retry policy.
+    pub retry: Option<RetrySettings>,
+}
+
 /// A list of user-defined middlewares to be applied to the client.
 #[derive(Clone)]
 pub struct ExtraMiddleware(pub Vec<Arc<dyn Middleware>>);
@@ -144,17 +185,23 @@ impl Default for BaseClientBuilder<'_> {
             built_in_root_certs: false,
             connectivity: Connectivity::Online,
             retries: DEFAULT_RETRIES,
+            retry: RetrySettings::default(),
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
             credentials_cache: Arc::new(CredentialsCache::default()),
             indexes: Indexes::new(),
+            index_configs: vec![],
+            oci_references: vec![],
             timeout: Duration::from_secs(30),
             extra_middleware: None,
             proxies: vec![],
             http_proxy: None,
             https_proxy: None,
             no_proxy: None,
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
+            throttle: Arc::new(Throttle::new(None, None)),
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
             custom_client: None,
@@ -218,6 +265,15 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the policy for retrying failed requests.
+    ///
+    /// The number of retries is set separately, via [`BaseClientBuilder::retries`].
+    #[must_use]
+    pub fn retry(mut self, retry: RetrySettings) -> Self {
+        self.retry = retry;
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -254,6 +310,18 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn index_configs(mut self, index_configs: Vec<IndexClientConfig>) -> Self {
+        self.index_configs = index_configs;
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn oci_references(mut self, oci_references: Vec<OciReference>) -> Self {
+        self.oci_references = oci_references;
+        self
+    }
+
     #[must_use]
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
@@ -290,6 +358,28 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Limit the number of in-flight requests to each host.
+    #[must_use]
+    pub fn concurrent_downloads_per_host(mut self, limit: Option<NonZeroUsize>) -> Self {
+        self.concurrent_downloads_per_host = limit;
+        self.throttle = Arc::new(Throttle::new(
+            self.concurrent_downloads_per_host,
+            self.bandwidth_limit.map(BandwidthLimit::bytes_per_second),
+        ));
+        self
+    }
+
+    /// Limit the download bandwidth, shared across all requests.
+    #[must_use]
+    pub fn bandwidth_limit(mut self, limit: Option<BandwidthLimit>) -> Self {
+        self.bandwidth_limit = limit;
+        self.throttle = Arc::new(Throttle::new(
+            self.concurrent_downloads_per_host,
+            self.bandwidth_limit.map(BandwidthLimit::bytes_per_second),
+        ));
+        self
+    }
+
     #[must_use]
     pub fn redirect(mut self, policy: RedirectPolicy) -> Self {
         self.redirect_policy = policy;
@@ -336,13 +426,21 @@ impl<'a> BaseClientBuilder<'a> {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
-    /// Create a [`RetryPolicy`] for the client.
-    pub fn retry_policy(&self) -> ExponentialBackoff {
-        let mut builder = ExponentialBackoff::builder();
-        if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
-            builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
+    /// Create a [`UvRetryPolicy`] for the client.
+    pub fn retry_policy(&self) -> UvRetryPolicy {
+        UvRetryPolicy::new(self.retries, &self.retry)
+    }
+
+    /// Create a [`UvRetryPolicy`] for an index, falling back to the client's policy for any
+    /// settings that the index doesn't override.
+    fn index_retry_policy(&self, index: &IndexClientConfig) -> UvRetryPolicy {
+        match &index.retry {
+            Some(retry) => UvRetryPolicy::new(
+                retry.retries.unwrap_or(self.retries),
+                &retry.clone().combine(self.retry.clone()),
+            ),
+            None => self.retry_policy(),
         }
-        builder.build_with_max_retries(self.retries)
     }
 
     pub fn build(&self) -> BaseClient {
@@ -352,17 +450,52 @@ impl<'a> BaseClientBuilder<'a> {
         // Use the custom client if provided, otherwise create a new one
         let (raw_client, raw_dangerous_client) = match &self.custom_client {
             Some(client) => (client.clone(), client.clone()),
-            None => self.create_secure_and_insecure_clients(timeout),
+            None => self.create_secure_and_insecure_clients(timeout, None),
+        };
+
+        // Create dedicated clients for any indexes with a custom TLS or proxy configuration.
+        let index_clients = if self.custom_client.is_some() {
+            vec![]
+        } else {
+            self.index_configs
+                .iter()
+                .map(|index| match IndexCertificates::read(index) {
+                    Ok(certificates) => {
+                        let (raw_client, raw_dangerous_client) = self
+                            .create_secure_and_insecure_clients(
+                                timeout,
+                                Some((index, &certificates)),
+                            );
+                        self.index_client(
+                            index.url.clone(),
+                            self.index_retry_policy(index),
+                            raw_client,
+                            raw_dangerous_client,
+                            None,
+                        )
+                    }
+                    // Rather than falling back to the default TLS configuration, reject every
+                    // request to the index.
+                    Err(err) => self.index_client(
+                        index.url.clone(),
+                        self.index_retry_policy(index),
+                        raw_client.clone(),
+                        raw_dangerous_client.clone(),
+                        Some(err),
+                    ),
+                })
+                .collect()
         };
 
         // Wrap in any relevant middleware and handle connectivity.
+        let retry_policy = self.retry_policy();
         let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_client.clone()),
+            client: self.apply_middleware(raw_client.clone(), &retry_policy),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
         let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_dangerous_client.clone()),
+            client: self.apply_middleware(raw_dangerous_client.clone(), &retry_policy),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
@@ -370,11 +503,12 @@ impl<'a> BaseClientBuilder<'a> {
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            retry_policy,
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients,
             timeout,
             credentials_cache: self.credentials_cache.clone(),
         }
@@ -383,13 +517,14 @@ impl<'a> BaseClientBuilder<'a> {
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
         // Wrap in any relevant middleware and handle connectivity.
+        let retry_policy = self.retry_policy();
         let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_client.clone()),
+            client: self.apply_middleware(existing.raw_client.clone(), &retry_policy),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
         let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_dangerous_client.clone()),
+            client: self.apply_middleware(existing.raw_dangerous_client.clone(), &retry_policy),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
@@ -397,17 +532,71 @@ impl<'a> BaseClientBuilder<'a> {
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            retry_policy,
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients: existing
+                .index_clients
+                .iter()
+                .map(|index| {
+                    self.index_client(
+                        index.url.clone(),
+                        index.retry_policy.clone(),
+                        index.raw_client.clone(),
+                        index.raw_dangerous_client.clone(),
+                        index.certificate_error.clone(),
+                    )
+                })
+                .collect(),
             timeout: existing.timeout,
             credentials_cache: existing.credentials_cache.clone(),
         }
     }
 
-    fn create_secure_and_insecure_clients(&self, timeout: Duration) -> (Client, Client) {
+    /// Wrap the raw clients for an index in the relevant middleware.
+    ///
+    /// If the index's certificates couldn't be read, every request to the index fails with the
+    /// given error.
+    fn index_client(
+        &self,
+        url: DisplaySafeUrl,
+        retry_policy: UvRetryPolicy,
+        raw_client: Client,
+        raw_dangerous_client: Client,
+        certificate_error: Option<IndexCertificateError>,
+    ) -> IndexClient {
+        let wrap = |client: Client| match &certificate_error {
+            Some(err) => reqwest_middleware::ClientBuilder::new(client)
+                .with(InvalidCertificateMiddleware(err.clone()))
+                .build(),
+            None => self.apply_middleware(client, &retry_policy),
+        };
+        IndexClient {
+            url,
+            client: RedirectClientWithMiddleware {
+                client: wrap(raw_client.clone()),
+                redirect_policy: self.redirect_policy,
+                cross_origin_credentials_policy: self.cross_origin_credential_policy,
+            },
+            dangerous_client: RedirectClientWithMiddleware {
+                client: wrap(raw_dangerous_client.clone()),
+                redirect_policy: self.redirect_policy,
+                cross_origin_credentials_policy: self.cross_origin_credential_policy,
+            },
+            retry_policy,
+            raw_client,
+            raw_dangerous_client,
+            certificate_error,
+        }
+    }
+
+    fn create_secure_and_insecure_clients(
+        &self,
+        timeout: Duration,
+        index: Option<(&IndexClientConfig, &IndexCertificates)>,
+    ) -> (Client, Client) {
         // Create user agent.
         let mut user_agent_string = format!("uv/{}", version());
 
@@ -505,6 +694,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_dir_exists,
             Security::Secure,
             self.redirect_policy,
+            index,
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -515,6 +705,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_dir_exists,
             Security::Insecure,
             self.redirect_policy,
+            index,
         );
 
         (raw_client, raw_dangerous_client)
@@ -528,6 +719,7 @@ impl<'a> BaseClientBuilder<'a> {
         ssl_cert_dir_exists: bool,
         security: Security,
         redirect_policy: RedirectPolicy,
+        index: Option<(&IndexClientConfig, &IndexCertificates)>,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -550,8 +742,23 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+        // Trust any additional CA certificates for the index.
+        let client_builder = if let Some((_, certificates)) = index {
+            certificates
+                .ca_certs
+                .iter()
+                .cloned()
+                .fold(client_builder, ClientBuilder::add_root_certificate)
+        } else {
+            client_builder
+        };
+
+        // Configure mTLS, preferring the index's client certificate over `SSL_CLIENT_CERT`.
+        let client_builder = if let Some((_, certificates)) = index
+            && let Some(identity) = &certificates.identity
+        {
+            client_builder.identity(identity.clone())
+        } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
                 Ok(identity) => client_builder.identity(identity),
                 Err(err) => {
@@ -563,6 +770,16 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // If the index has a dedicated proxy, route all requests through it.
+        if let Some((index, _)) = index
+            && let Some(proxy) = &index.proxy
+        {
+            return client_builder
+                .proxy(proxy.as_proxy(ProxyUrlKind::All))
+                .build()
+                .expect("Failed to build HTTP client.");
+        }
+
         // apply proxies
         let mut client_builder = client_builder;
         for p in &self.proxies {
@@ -593,7 +810,11 @@ impl<'a> BaseClientBuilder<'a> {
             .expect("Failed to build HTTP client.")
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    fn apply_middleware(
+        &self,
+        client: Client,
+        retry_policy: &UvRetryPolicy,
+    ) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
                 // Create a base client to using in the authentication middleware.
@@ -601,11 +822,11 @@ impl<'a> BaseClientBuilder<'a> {
                     let mut client = reqwest_middleware::ClientBuilder::new(client.clone());
 
                     // Avoid uncloneable errors with a streaming body during publish.
-                    if self.retries > 0 {
+                    if retry_policy.max_retries() > 0 {
                         // Initialize the retry strategy.
                         let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                            self.retry_policy(),
-                            UvRetryableStrategy,
+                            retry_policy.backoff,
+                            retry_policy.strategy.clone(),
                         );
                         client = client.with(retry_strategy);
                     }
@@ -617,17 +838,21 @@ impl<'a> BaseClientBuilder<'a> {
                         }
                     }
 
+                    if let Some(audit_log) = audit_log() {
+                        client = client.with(AuditMiddleware(audit_log));
+                    }
+
                     client.build()
                 };
 
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
-                if self.retries > 0 {
+                if retry_policy.max_retries() > 0 {
                     // Initialize the retry strategy.
                     let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
-                        UvRetryableStrategy,
+                        retry_policy.backoff,
+                        retry_policy.strategy.clone(),
                     );
                     client = client.with(retry_strategy);
                 }
@@ -639,6 +864,13 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Initialize the OCI registry token middleware for any `oci://` indexes. It's
+                // added after the authentication middleware so that any Basic credentials
+                // discovered for the registry are exchanged for a token.
+                let oci_middleware = (!self.oci_references.is_empty()).then(|| {
+                    OciAuthMiddleware::new(base_client.clone(), self.oci_references.clone())
+                });
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
@@ -671,6 +903,22 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                if let Some(oci_middleware) = oci_middleware {
+                    client = client.with(oci_middleware);
+                }
+
+                // Record each request that reaches the network, including retries and
+                // authentication attempts.
+                if let Some(audit_log) = audit_log() {
+                    client = client.with(AuditMiddleware(audit_log));
+                }
+
+                // Enforce the per-host and bandwidth limits closest to the network, such that
+                // each retry and authentication attempt is counted individually.
+                if !self.throttle.is_empty() {
+                    client = client.with(ThrottleMiddleware(self.throttle.clone()));
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -691,18 +939,75 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for indexes with a custom TLS or proxy configuration.
+    index_clients: Vec<IndexClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
-    /// The number of retries to attempt on transient errors.
-    retries: u32,
+    /// The policy for retrying transient errors.
+    retry_policy: UvRetryPolicy,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     credentials_cache: Arc<CredentialsCache>,
 }
 
+/// The HTTP clients for an index with a custom TLS or proxy configuration.
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The URL of the index.
+    url: DisplaySafeUrl,
+    /// The HTTP client that enforces valid certificates.
+    client: RedirectClientWithMiddleware,
+    /// The HTTP client that accepts invalid certificates.
+    dangerous_client: RedirectClientWithMiddleware,
+    /// The policy for retrying transient errors in requests to the index.
+    retry_policy: UvRetryPolicy,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+    /// The HTTP client that accepts invalid certificates without middleware.
+    raw_dangerous_client: Client,
+    /// The error reading the index's certificates, if any, with which all requests fail.
+    certificate_error: Option<IndexCertificateError>,
+}
+
+/// The certificates configured for an index.
+#[derive(Debug, Default)]
+struct IndexCertificates {
+    /// The additional CA certificates to trust.
+    ca_certs: Vec<Certificate>,
+    /// The client certificate and private key.
+    identity: Option<Identity>,
+}
+
+impl IndexCertificates {
+    /// Read the certificates configured for an index.
+    fn read(index: &IndexClientConfig) -> Result<Self, IndexCertificateError> {
+        let ca_certs = match &index.ca_cert {
+            Some(ca_cert) => read_certificates(ca_cert.as_os_str()).map_err(|err| {
+                IndexCertificateError::CaCert {
+                    path: ca_cert.clone(),
+                    url: index.url.clone(),
+                    err: Arc::new(err),
+                }
+            })?,
+            None => Vec::new(),
+        };
+        let identity = match &index.client_cert {
+            Some(client_cert) => Some(read_identity(client_cert.as_os_str()).map_err(|err| {
+                IndexCertificateError::ClientCert {
+                    path: client_cert.clone(),
+                    url: index.url.clone(),
+                    err: Arc::new(err),
+                }
+            })?),
+            None => None,
+        };
+        Ok(Self { ca_certs, identity })
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Security {
     /// The client should use secure settings, i.e., valid certificates.
@@ -714,19 +1019,78 @@ enum Security {
 impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
-        if self.disable_ssl(url) {
+        if let Some(index) = self.index_client(url) {
+            if self.disable_ssl(url) {
+                &index.dangerous_client
+            } else {
+                &index.client
+            }
+        } else if self.disable_ssl(url) {
             &self.dangerous_client
         } else {
             &self.client
         }
     }
 
+    /// Selects the appropriate client without middleware based on the host's trustworthiness.
+    ///
+    /// Requests with a streaming body can't be retried by the middleware, so callers are
+    /// responsible for authenticating (and, if necessary, retrying) the request.
+    pub fn raw_client_for_host(&self, url: &DisplaySafeUrl) -> &Client {
+        if let Some(index) = self.index_client(url) {
+            if self.disable_ssl(url) {
+                &index.raw_dangerous_client
+            } else {
+                &index.raw_client
+            }
+        } else if self.disable_ssl(url) {
+            &self.raw_dangerous_client
+        } else {
+            &self.raw_client
+        }
+    }
+
+    /// Returns the client for the index with a custom TLS or proxy configuration that applies to
+    /// the URL, if any.
+    ///
+    /// Prefers an index under whose URL the given URL is nested, then any index on the same origin.
+    fn index_client(&self, url: &DisplaySafeUrl) -> Option<&IndexClient> {
+        if self.index_clients.is_empty() {
+            return None;
+        }
+        self.index_clients
+            .iter()
+            .find(|index| is_nested_under(url, &index.url))
+            .or_else(|| {
+                let realm = RealmRef::from(&**url);
+                self.index_clients
+                    .iter()
+                    .find(|index| RealmRef::from(&*index.url) == realm)
+            })
+    }
+
     /// Executes a request, applying redirect policy.
     pub async fn execute(&self, req: Request) -> reqwest_middleware::Result<Response> {
         let client = self.for_host(&DisplaySafeUrl::from_url(req.url().clone()));
         client.execute(req).await
     }
 
+    /// Streams the body of a response, resuming the download with HTTP range requests if the
+    /// transfer is interrupted.
+    ///
+    /// Resumption requires the server to advertise range support and a strong validator, which
+    /// ensures the remainder is taken from the same file; otherwise, this is equivalent to
+    /// [`Response::bytes_stream`].
+    pub fn resumable_stream(
+        &self,
+        response: Response,
+    ) -> BoxStream<'static, reqwest::Result<Bytes>> {
+        let url = DisplaySafeUrl::from_url(response.url().clone());
+        let client = self.for_host(&url).clone();
+        let retries = self.retry_policy_for(&url).max_retries();
+        resumable::resumable_stream(client, retries, response)
+    }
+
     /// Returns `true` if the host is trusted to use the insecure client.
     pub fn disable_ssl(&self, url: &DisplaySafeUrl) -> bool {
         self.allow_insecure_host
@@ -744,13 +1108,18 @@ impl BaseClient {
         self.connectivity
     }
 
-    /// The [`RetryPolicy`] for the client.
-    pub fn retry_policy(&self) -> ExponentialBackoff {
-        let mut builder = ExponentialBackoff::builder();
-        if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
-            builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
+    /// The [`UvRetryPolicy`] for the client.
+    pub fn retry_policy(&self) -> UvRetryPolicy {
+        self.retry_policy.clone()
+    }
+
+    /// The [`UvRetryPolicy`] for requests to the given URL, taking any per-index policy into
+    /// account.
+    pub fn retry_policy_for(&self, url: &DisplaySafeUrl) -> UvRetryPolicy {
+        match self.index_client(url) {
+            Some(index) => index.retry_policy.clone(),
+            None => self.retry_policy.clone(),
         }
-        builder.build_with_max_retries(self.retries)
     }
 
     pub fn credentials_cache(&self) -> &CredentialsCache {
@@ -782,6 +1151,11 @@ impl RedirectClientWithMiddleware {
         RequestBuilder::new(self.client.post(url), self)
     }
 
+    /// Convenience method to make a `PUT` request to a URL.
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
+        RequestBuilder::new(self.client.put(url), self)
+    }
+
     /// Convenience method to make a `HEAD` request to a URL.
     pub fn head<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
         RequestBuilder::new(self.client.head(url), self)
@@ -1063,6 +1437,12 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Set the request body.
+    pub fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.builder = self.builder.body(body);
+        self
+    }
+
     /// Build a `Request`.
     pub fn build(self) -> reqwest::Result<Request> {
         self.builder.build()
@@ -1079,15 +1459,105 @@ impl<'a> RequestBuilder<'a> {
     }
 }
 
+/// The policy for retrying failed requests: how many times, how long to wait in between, and
+/// which failures to retry.
+#[derive(Debug, Clone)]
+pub struct UvRetryPolicy {
+    backoff: ExponentialBackoff,
+    strategy: UvRetryableStrategy,
+}
+
+impl UvRetryPolicy {
+    /// Create a [`UvRetryPolicy`] that retries up to `retries` times, per the given settings.
+    pub fn new(retries: u32, settings: &RetrySettings) -> Self {
+        let min_delay = Duration::from_secs(settings.min_delay.unwrap_or(1));
+        let max_delay = Duration::from_secs(settings.max_delay.unwrap_or(30 * 60)).max(min_delay);
+
+        let mut builder = ExponentialBackoff::builder();
+        builder = match settings.backoff.unwrap_or_default() {
+            RetryBackoff::Exponential => builder.retry_bounds(min_delay, max_delay),
+            RetryBackoff::Constant => builder
+                .retry_bounds(min_delay, min_delay)
+                .base(1)
+                .jitter(Jitter::None),
+        };
+        if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
+            builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
+        }
+
+        Self {
+            backoff: builder.build_with_max_retries(retries),
+            strategy: UvRetryableStrategy {
+                statuses: settings
+                    .retry_on_status
+                    .as_ref()
+                    .map(|statuses| statuses.iter().map(|status| **status).collect()),
+                errors: settings.retry_on_error.clone(),
+            },
+        }
+    }
+
+    /// The maximum number of retries.
+    pub fn max_retries(&self) -> u32 {
+        self.backoff.max_n_retries.unwrap_or_default()
+    }
+}
+
+impl Default for UvRetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_RETRIES, &RetrySettings::default())
+    }
+}
+
 /// An extension over [`DefaultRetryableStrategy`] that logs transient request failures and
 /// adds additional retry cases.
-pub struct UvRetryableStrategy;
+///
+/// By default, server errors, timeouts, rate limits, and network errors are retried. The retried
+/// status codes and network errors can be restricted through [`RetrySettings`].
+#[derive(Debug, Clone, Default)]
+pub struct UvRetryableStrategy {
+    /// The status codes to retry, if not the default set.
+    statuses: Option<Vec<StatusCode>>,
+    /// The classes of network errors to retry, if not all of them.
+    errors: Option<Vec<RetryableError>>,
+}
+
+impl UvRetryableStrategy {
+    /// Whether a response with the given status should be retried.
+    fn is_retryable_status(&self, status: StatusCode) -> bool {
+        match &self.statuses {
+            Some(statuses) => statuses.contains(&status),
+            None => {
+                status.is_server_error()
+                    || status == StatusCode::REQUEST_TIMEOUT
+                    || status == StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+
+    /// Whether the given class of network error should be retried.
+    fn is_retryable_error(&self, error: RetryableError) -> bool {
+        self.errors
+            .as_ref()
+            .is_none_or(|errors| errors.contains(&error))
+    }
+}
 
 impl RetryableStrategy for UvRetryableStrategy {
     fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
         let retryable = match res {
-            Ok(success) => default_on_request_success(success),
-            Err(err) => retryable_on_request_failure(err),
+            Ok(success) if self.statuses.is_none() => default_on_request_success(success),
+            Ok(success) => {
+                let status = success.status();
+                if self.is_retryable_status(status) {
+                    Some(Retryable::Transient)
+                } else if status.is_success() {
+                    None
+                } else {
+                    Some(Retryable::Fatal)
+                }
+            }
+            Err(err) => retryable_on_request_failure(err, self),
         };
 
         // Log on transient errors
@@ -1119,7 +1589,12 @@ impl RetryableStrategy for UvRetryableStrategy {
 /// * When streaming a response, a reqwest error may be hidden several layers behind errors
 ///   of different crates processing the stream, including `io::Error` layers
 /// * Any `h2` error
-fn retryable_on_request_failure(err: &(dyn Error + 'static)) -> Option<Retryable> {
+///
+/// Status codes and classes of network errors that the strategy doesn't allow are never retried.
+fn retryable_on_request_failure(
+    err: &(dyn Error + 'static),
+    strategy: &UvRetryableStrategy,
+) -> Option<Retryable> {
     // First, try to show a nice trace log
     if let Some((Some(status), Some(url))) = find_source::<WrappedReqwestError>(&err)
         .map(|request_err| (request_err.status(), request_err.url()))
@@ -1157,16 +1632,34 @@ fn retryable_on_request_failure(err: &(dyn Error + 'static)) -> Option<Retryable
             has_known_error = true;
             // Ignore the default retry strategy returning fatal.
             if default_on_request_error(reqwest_err) == Some(Retryable::Transient) {
+                let error = if reqwest_err.is_timeout() {
+                    RetryableError::Timeout
+                } else if reqwest_err.is_connect() {
+                    RetryableError::Connect
+                } else {
+                    RetryableError::Interrupted
+                };
+                if !strategy.is_retryable_error(error) {
+                    trace!("Cannot retry nested reqwest error, retries are disabled for {error:?}");
+                    return None;
+                }
                 trace!("Retrying nested reqwest error");
                 return Some(Retryable::Transient);
             }
-            if is_retryable_status_error(reqwest_err) {
+            if reqwest_err
+                .status()
+                .is_some_and(|status| strategy.is_retryable_status(status))
+            {
                 trace!("Retrying nested reqwest status code error");
                 return Some(Retryable::Transient);
             }
 
             trace!("Cannot retry nested reqwest error");
         } else if source.downcast_ref::<h2::Error>().is_some() {
+            if !strategy.is_retryable_error(RetryableError::Interrupted) {
+                trace!("Cannot retry nested h2 error, retries are disabled for interruptions");
+                return None;
+            }
             // All h2 errors look like errors that should be retried
             // https://github.com/astral-sh/uv/issues/15916
             trace!("Retrying nested h2 error");
@@ -1186,6 +1679,13 @@ fn retryable_on_request_failure(err: &(dyn Error + 'static)) -> Option<Retryable
                 io::ErrorKind::UnexpectedEof,
             ];
             if retryable_io_err_kinds.contains(&io_err.kind()) {
+                if !strategy.is_retryable_error(RetryableError::Interrupted) {
+                    trace!(
+                        "Cannot retry error `{}`, retries are disabled for interruptions",
+                        io_err.kind()
+                    );
+                    return None;
+                }
                 trace!("Retrying error: `{}`", io_err.kind());
                 return Some(Retryable::Transient);
             }
@@ -1206,9 +1706,15 @@ fn retryable_on_request_failure(err: &(dyn Error + 'static)) -> Option<Retryable
     None
 }
 
+/// Returns `true` if the error is a transient network failure (e.g., a connection failure, a
+/// timeout, or a server error), as opposed to a failure that would recur on every request.
+pub(crate) fn is_transient_network_error(err: &(dyn Error + 'static)) -> bool {
+    retryable_on_request_failure(err, &UvRetryableStrategy::default()) == Some(Retryable::Transient)
+}
+
 /// Per-request retry state and policy.
 pub struct RetryState {
-    retry_policy: ExponentialBackoff,
+    retry_policy: UvRetryPolicy,
     start_time: SystemTime,
     total_retries: u32,
     url: DisplaySafeUrl,
@@ -1216,7 +1722,7 @@ pub struct RetryState {
 
 impl RetryState {
     /// Initialize the [`RetryState`] and start the backoff timer.
-    pub fn start(retry_policy: ExponentialBackoff, url: impl Into<DisplaySafeUrl>) -> Self {
+    pub fn start(retry_policy: UvRetryPolicy, url: impl Into<DisplaySafeUrl>) -> Self {
         Self {
             retry_policy,
             start_time: SystemTime::now(),
@@ -1246,10 +1752,11 @@ impl RetryState {
     ) -> Option<Duration> {
         // If the middleware performed any retries, consider them in our budget.
         self.total_retries += error_retries;
-        match retryable_on_request_failure(err) {
+        match retryable_on_request_failure(err, &self.retry_policy.strategy) {
             Some(Retryable::Transient) => {
                 let retry_decision = self
                     .retry_policy
+                    .backoff
                     .should_retry(self.start_time, self.total_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
                     let duration = execute_after
@@ -1279,18 +1786,6 @@ impl RetryState {
     }
 }
 
-/// Whether the error is a status code error that is retryable.
-///
-/// Port of `reqwest_retry::default_on_request_success`.
-fn is_retryable_status_error(reqwest_err: &reqwest::Error) -> bool {
-    let Some(status) = reqwest_err.status() else {
-        return false;
-    };
-    status.is_server_error()
-        || status == StatusCode::REQUEST_TIMEOUT
-        || status == StatusCode::TOO_MANY_REQUESTS
-}
-
 /// Find the first source error of a specific type.
 ///
 /// See <https://github.com/seanmonstar/reqwest/issues/1602#issuecomment-1220996681>
@@ -1537,7 +2032,7 @@ mod tests {
                 .await;
 
             let middleware_retry =
-                UvRetryableStrategy.handle(&response) == Some(Retryable::Transient);
+                UvRetryableStrategy::default().handle(&response) == Some(Retryable::Transient);
 
             let response = client
                 .get(format!("{}/{}", server.uri(), status))
@@ -1546,7 +2041,10 @@ mod tests {
 
             let uv_retry = match response.error_for_status() {
                 Ok(_) => false,
-                Err(err) => retryable_on_request_failure(&err) == Some(Retryable::Transient),
+                Err(err) => {
+                    retryable_on_request_failure(&err, &UvRetryableStrategy::default())
+                        == Some(Retryable::Transient)
+                }
             };
 
             // Ensure we're retrying the same status code as the reqwest_retry crate. We may choose
@@ -1580,4 +2078,129 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn retry_on_status() -> Result<()> {
+        let server = MockServer::start().await;
+        for status in [200, 404, 502, 503] {
+            Mock::given(path(format!("/{status}")))
+                .respond_with(ResponseTemplate::new(status))
+                .mount(&server)
+                .await;
+        }
+
+        let policy = UvRetryPolicy::new(
+            3,
+            &RetrySettings {
+                retry_on_status: Some(vec![serde_json::from_str("502")?]),
+                ..RetrySettings::default()
+            },
+        );
+        let middleware_client = ClientWithMiddleware::default();
+        let mut decisions = Vec::new();
+        for status in [200, 404, 502, 503] {
+            let response = middleware_client
+                .get(format!("{}/{}", server.uri(), status))
+                .send()
+                .await;
+            let decision = match policy.strategy.handle(&response) {
+                Some(Retryable::Transient) => "transient",
+                Some(Retryable::Fatal) => "fatal",
+                None => "none",
+            };
+            decisions.push((status, decision));
+        }
+
+        assert_debug_snapshot!(decisions, @r#"
+        [
+            (
+                200,
+                "none",
+            ),
+            (
+                404,
+                "fatal",
+            ),
+            (
+                502,
+                "transient",
+            ),
+            (
+                503,
+                "fatal",
+            ),
+        ]
+        "#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_on_error() -> Result<()> {
+        // Bind and immediately drop a listener, such that connections to the port are refused.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let err = Client::default()
+            .get(format!("http://{addr}/"))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(
+            retryable_on_request_failure(&err, &UvRetryableStrategy::default())
+                == Some(Retryable::Transient)
+        );
+
+        let connect = UvRetryPolicy::new(
+            3,
+            &RetrySettings {
+                retry_on_error: Some(vec![RetryableError::Connect]),
+                ..RetrySettings::default()
+            },
+        );
+        assert!(
+            retryable_on_request_failure(&err, &connect.strategy) == Some(Retryable::Transient)
+        );
+
+        let timeout = UvRetryPolicy::new(
+            3,
+            &RetrySettings {
+                retry_on_error: Some(vec![RetryableError::Timeout]),
+                ..RetrySettings::default()
+            },
+        );
+        assert!(retryable_on_request_failure(&err, &timeout.strategy).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn constant_backoff() {
+        let policy = UvRetryPolicy::new(
+            5,
+            &RetrySettings {
+                backoff: Some(RetryBackoff::Constant),
+                min_delay: Some(2),
+                ..RetrySettings::default()
+            },
+        );
+        assert_eq!(policy.max_retries(), 5);
+
+        let start = SystemTime::now();
+        for n_past_retries in 0..5 {
+            let reqwest_retry::RetryDecision::Retry { execute_after } =
+                policy.backoff.should_retry(start, n_past_retries)
+            else {
+                panic!("Expected a retry after {n_past_retries} retries");
+            };
+            let delay = execute_after.duration_since(start).unwrap();
+            assert!(
+                (Duration::from_millis(1900)..=Duration::from_millis(2100)).contains(&delay),
+                "Unexpected delay after {n_past_retries} retries: {delay:?}"
+            );
+        }
+        assert!(matches!(
+            policy.backoff.should_retry(start, 5),
+            reqwest_retry::RetryDecision::DoNotRetry
+        ));
+    }
 }
@@ -1,4 +1,5 @@
-use std::{borrow::Cow, path::Path};
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 
 use futures::FutureExt;
 use reqwest::{Request, Response};
@@ -7,16 +8,20 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 
-use uv_cache::{CacheEntry, Freshness};
+use uv_cache::{CacheEntry, CacheEvent, Freshness, metrics};
 use uv_fs::write_atomic;
 use uv_redacted::DisplaySafeUrl;
 
 use crate::BaseClient;
+use crate::audit::audit_log;
 use crate::base_client::RetryState;
 use crate::error::ProblemDetails;
 use crate::{
     Error, ErrorKind,
-    httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
+    httpcache::{
+        AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder,
+        stale_while_revalidate_seconds,
+    },
     rkyvutil::OwnedArchive,
 };
 
@@ -281,7 +286,7 @@ impl CachedClient {
     ) -> Result<Payload::Target, CachedClientError<CallBackError>> {
         let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
         let cached_response = if let Some(cached) = Self::read_cache(cache_entry).await {
-            self.send_cached(req, cache_control, cached)
+            self.send_cached(req, cache_entry, cache_control, cached)
                 .boxed_local()
                 .await?
         } else {
@@ -292,6 +297,20 @@ impl CachedClient {
                 cache_policy,
             }
         };
+        metrics().record(match &cached_response {
+            CachedResponse::FreshCache(_) => CacheEvent::Hit,
+            CachedResponse::NotModified { .. } => CacheEvent::Revalidated,
+            CachedResponse::ModifiedOrNew { .. } => CacheEvent::Miss,
+        });
+        if let (Some(audit_log), CachedResponse::FreshCache(cached)) =
+            (audit_log(), &cached_response)
+        {
+            audit_log.record_cache_hit(
+                fresh_req.method().as_str(),
+                fresh_req.url(),
+                cached.data.len() as u64,
+            );
+        }
         match cached_response {
             CachedResponse::FreshCache(cached) => match Payload::from_aligned_bytes(cached.data) {
                 Ok(payload) => Ok(payload),
@@ -379,6 +398,7 @@ impl CachedClient {
         cache_control: CacheControl<'_>,
         response_callback: Callback,
     ) -> Result<Payload, CachedClientError<CallBackError>> {
+        metrics().record(CacheEvent::Miss);
         let (response, cache_policy) = self.fresh_request(req, cache_control).await?;
 
         let payload = self
@@ -451,6 +471,12 @@ impl CachedClient {
     #[instrument(name = "read_and_parse_cache", skip_all, fields(file = %cache_entry.path().display()
     ))]
     async fn read_cache(cache_entry: &CacheEntry) -> Option<DataWithCachePolicy> {
+        if let Err(err) = cache_entry.materialize().await {
+            warn!(
+                "Failed to copy cache entry from the shared cache to {}: {err}",
+                cache_entry.path().display()
+            );
+        }
         match DataWithCachePolicy::from_path_async(cache_entry.path()).await {
             Ok(data) => Some(data),
             Err(err) => {
@@ -477,6 +503,7 @@ impl CachedClient {
     async fn send_cached(
         &self,
         mut req: Request,
+        cache_entry: &CacheEntry,
         cache_control: CacheControl<'_>,
         cached: DataWithCachePolicy,
     ) -> Result<CachedResponse, Error> {
@@ -496,6 +523,25 @@ impl CachedClient {
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
+                CacheControl::Override(header)
+                    if stale_while_revalidate_seconds(header).is_some_and(|seconds| {
+                        cached
+                            .cache_policy
+                            .allows_stale_while_revalidate(&req, seconds)
+                    }) =>
+                {
+                    debug!(
+                        "Found stale (but within `stale-while-revalidate`) response for: {}",
+                        req.url()
+                    );
+                    self.revalidate_in_background(
+                        req,
+                        header,
+                        new_cache_policy_builder,
+                        cache_entry.path().to_path_buf(),
+                    );
+                    CachedResponse::FreshCache(cached)
+                }
                 CacheControl::None | CacheControl::MustRevalidate | CacheControl::Override(_) => {
                     debug!("Found stale response for: {}", req.url());
                     self.send_cached_handle_stale(
@@ -526,6 +572,65 @@ impl CachedClient {
         })
     }
 
+    /// Revalidate a stale cached response in the background, as permitted by a
+    /// `stale-while-revalidate` directive.
+    ///
+    /// If the response wasn't modified, the cache policy is refreshed. Otherwise, the cache entry
+    /// is removed, such that the next request fetches the new response.
+    fn revalidate_in_background(
+        &self,
+        req: Request,
+        header: &str,
+        new_cache_policy_builder: CachePolicyBuilder,
+        path: PathBuf,
+    ) {
+        let client = self.0.clone();
+        let header =
+            http::HeaderValue::from_str(header).expect("Cache-Control header must be valid UTF-8");
+        tokio::spawn(async move {
+            let url = DisplaySafeUrl::from_url(req.url().clone());
+            debug!("Sending background revalidation request for: {url}");
+            let result: Result<(), Error> = async {
+                let mut response = client
+                    .execute(req)
+                    .await
+                    .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
+                if let Err(status_error) = response.error_for_status_ref() {
+                    return Err(ErrorKind::from_reqwest(url.clone(), status_error).into());
+                }
+                response
+                    .headers_mut()
+                    .insert(http::header::CACHE_CONTROL, header);
+
+                let cached = DataWithCachePolicy::from_path_async(&path).await?;
+                match cached
+                    .cache_policy
+                    .after_response(new_cache_policy_builder, &response)
+                {
+                    AfterResponse::NotModified(new_policy) => {
+                        debug!("Found not-modified response in background for: {url}");
+                        let data_with_cache_policy_bytes =
+                            DataWithCachePolicy::serialize(&new_policy, &cached.data)?;
+                        write_atomic(&path, data_with_cache_policy_bytes)
+                            .await
+                            .map_err(ErrorKind::CacheWrite)?;
+                    }
+                    AfterResponse::Modified(..) => {
+                        debug!("Found modified response in background for: {url}");
+                        fs_err::tokio::remove_file(&path)
+                            .await
+                            .map_err(ErrorKind::CacheWrite)?;
+                    }
+                }
+                Ok(())
+            }
+            .await;
+            if let Err(err) = result {
+                debug!("Failed to revalidate in background for {url}: {err}");
+            }
+        });
+    }
+
     async fn send_cached_handle_stale(
         &self,
         req: Request,
@@ -700,7 +805,11 @@ impl CachedClient {
         cache_control: CacheControl<'_>,
         response_callback: Callback,
     ) -> Result<Payload::Target, CachedClientError<CallBackError>> {
-        let mut retry_state = RetryState::start(self.uncached().retry_policy(), req.url().clone());
+        let mut retry_state = RetryState::start(
+            self.uncached()
+                .retry_policy_for(&DisplaySafeUrl::from_url(req.url().clone())),
+            req.url().clone(),
+        );
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
@@ -734,7 +843,11 @@ impl CachedClient {
         cache_control: CacheControl<'_>,
         response_callback: Callback,
     ) -> Result<Payload, CachedClientError<CallBackError>> {
-        let mut retry_state = RetryState::start(self.uncached().retry_policy(), req.url().clone());
+        let mut retry_state = RetryState::start(
+            self.uncached()
+                .retry_policy_for(&DisplaySafeUrl::from_url(req.url().clone())),
+            req.url().clone(),
+        );
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
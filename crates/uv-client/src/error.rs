@@ -201,7 +201,8 @@ impl Error {
             ErrorKind::AsyncHttpRangeReader(
                 _,
                 AsyncHttpRangeReaderError::ContentLengthMissing
-                | AsyncHttpRangeReaderError::ContentRangeMissing,
+                | AsyncHttpRangeReaderError::ContentRangeMissing
+                | AsyncHttpRangeReaderError::ContentRangeParser(_),
             ) => {
                 return true;
             }
@@ -234,6 +235,11 @@ impl Error {
                     if status == reqwest::StatusCode::BAD_REQUEST {
                         return true;
                     }
+
+                    // The server rejected the range of a request for the end of the file.
+                    if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+                        return true;
+                    }
                 }
             }
 
@@ -305,6 +311,21 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the registry")]
     RemotePackageNotFound(PackageName),
 
+    /// The package was found on multiple indexes that don't declare each other as mirrors or
+    /// alternate locations via PEP 708 repository tracking metadata.
+    #[error(
+        "Package `{0}` was found on multiple indexes that don't declare each other as mirrors or alternate locations: {1}"
+    )]
+    UntrackedIndexes(PackageName, String),
+
+    /// The index is not allowed by the organization policy.
+    #[error("The index `{0}` is not allowed by the organization policy")]
+    IndexNotAllowed(DisplaySafeUrl),
+
+    /// The direct URL or Git repository is not allowed by the organization policy.
+    #[error("The URL `{0}` is not allowed by the organization policy")]
+    UrlNotAllowed(DisplaySafeUrl),
+
     /// The package was not found in the local (file-based) index.
     #[error("Package `{0}` was not found in the local index")]
     LocalPackageNotFound(PackageName),
@@ -337,6 +358,11 @@ pub enum ErrorKind {
         url: DisplaySafeUrl,
     },
 
+    #[error(
+        "Expected an OCI image manifest, but received an image index from {0}; reference a single-platform artifact instead"
+    )]
+    OciImageIndex(DisplaySafeUrl),
+
     #[error("Received some unexpected MessagePack from {}", url)]
     BadMessagePack {
         source: rmp_serde::decode::Error,
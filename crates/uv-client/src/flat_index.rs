@@ -1,21 +1,26 @@
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use futures::{FutureExt, StreamExt};
 use reqwest::Response;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tracing::{Instrument, debug, info_span, warn};
 use url::Url;
 
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::{Cache, CacheBucket, CacheEntry};
 use uv_cache_key::cache_digest;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{File, FileLocation, IndexUrl, UrlString};
-use uv_pypi_types::HashDigests;
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleDetailHTML;
-use crate::{CachedClient, Connectivity, Error, ErrorKind, OwnedArchive};
+use crate::oci::{MANIFEST_ACCEPT, OciManifest, OciReference};
+use crate::{CachedClient, Connectivity, Error, ErrorKind, OwnedArchive, check_index_allowed};
 
 #[derive(Debug, thiserror::Error)]
 pub enum FlatIndexError {
@@ -27,6 +32,13 @@ pub enum FlatIndexError {
 
     #[error("Failed to read `--find-links` URL: {0}")]
     FindLinksUrl(DisplaySafeUrl, #[source] Error),
+
+    #[error(
+        "Invalid OCI reference: `{0}` (expected, e.g., `oci://ghcr.io/org/repo:tag` or `oci://ghcr.io/org/repo@sha256:...`)"
+    )]
+    InvalidOciReference(DisplaySafeUrl),
+    #[error(transparent)]
+    NotAllowed(Error),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -141,14 +153,22 @@ impl<'a> FlatIndexClient<'a> {
 
     /// Fetch a flat remote index from a `--find-links` URL.
     pub async fn fetch_index(&self, index: &IndexUrl) -> Result<FlatIndexEntries, FlatIndexError> {
+        check_index_allowed(index).map_err(FlatIndexError::NotAllowed)?;
         match index {
             IndexUrl::Path(url) => {
                 let path = url
                     .to_file_path()
                     .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
-                Self::read_from_directory(&path, index)
+                Self::read_from_directory(&path, index, self.cache)
                     .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))
             }
+            IndexUrl::Url(url) if url.scheme() == "oci" => {
+                let reference = OciReference::from_url(url)
+                    .ok_or_else(|| FlatIndexError::InvalidOciReference(url.to_url()))?;
+                self.read_from_oci(&reference, index)
+                    .await
+                    .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))
+            }
             IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
                 .read_from_url(url, index)
                 .await
@@ -156,6 +176,71 @@ impl<'a> FlatIndexClient<'a> {
         }
     }
 
+    /// Read a flat index from the manifest of an `oci://` artifact.
+    async fn read_from_oci(
+        &self,
+        reference: &OciReference,
+        flat_index: &IndexUrl,
+    ) -> Result<FlatIndexEntries, Error> {
+        let url = reference.manifest_url();
+        let cache_entry = self.cache.entry(
+            CacheBucket::FlatIndex,
+            "oci",
+            format!("{}.msgpack", cache_digest(&url.to_string())),
+        );
+        let cache_control = match self.connectivity {
+            Connectivity::Online => CacheControl::from(
+                self.cache
+                    .freshness(&cache_entry, None, None)
+                    .map_err(ErrorKind::Io)?,
+            ),
+            Connectivity::Offline => CacheControl::AllowStale,
+        };
+
+        let manifest_request = self
+            .client
+            .uncached()
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", MANIFEST_ACCEPT)
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let parse_manifest_response = |response: Response| {
+            async {
+                let url = DisplaySafeUrl::from_url(response.url().clone());
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+                let manifest: OciManifest = serde_json::from_slice(&bytes)
+                    .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                if manifest.is_index() {
+                    return Err(ErrorKind::OciImageIndex(url).into());
+                }
+                let unarchived = manifest.into_files(reference);
+                OwnedArchive::from_unarchived(&unarchived)
+            }
+            .boxed_local()
+            .instrument(info_span!("parse_oci_manifest", url = % url))
+        };
+        let response = self
+            .client
+            .get_cacheable_with_retry(
+                manifest_request,
+                &cache_entry,
+                cache_control,
+                parse_manifest_response,
+            )
+            .await;
+        match response {
+            Ok(files) => Ok(Self::entries_from_archive(&files, flat_index)),
+            Err(CachedClientError::Client(err)) if err.is_offline() => {
+                Ok(FlatIndexEntries::offline())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Read a flat remote index from a `--find-links` URL.
     async fn read_from_url(
         &self,
@@ -197,6 +282,7 @@ impl<'a> FlatIndexClient<'a> {
                     .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
                 let SimpleDetailHTML {
                     project_status: _,
+                    tracking: _,
                     base,
                     files,
                 } = SimpleDetailHTML::parse(&text, &url)
@@ -233,23 +319,7 @@ impl<'a> FlatIndexClient<'a> {
             )
             .await;
         match response {
-            Ok(files) => {
-                let files = files
-                    .iter()
-                    .map(|file| {
-                        rkyv::deserialize::<File, rkyv::rancor::Error>(file)
-                            .expect("archived version always deserializes")
-                    })
-                    .filter_map(|file| {
-                        Some(FlatIndexEntry {
-                            filename: DistFilename::try_from_normalized_filename(&file.filename)?,
-                            file,
-                            index: flat_index.clone(),
-                        })
-                    })
-                    .collect();
-                Ok(FlatIndexEntries::from_entries(files))
-            }
+            Ok(files) => Ok(Self::entries_from_archive(&files, flat_index)),
             Err(CachedClientError::Client(err)) if err.is_offline() => {
                 Ok(FlatIndexEntries::offline())
             }
@@ -257,15 +327,50 @@ impl<'a> FlatIndexClient<'a> {
         }
     }
 
+    /// Convert a cached list of files into `--find-links` entries.
+    fn entries_from_archive(
+        files: &OwnedArchive<Vec<File>>,
+        flat_index: &IndexUrl,
+    ) -> FlatIndexEntries {
+        let files = files
+            .iter()
+            .map(|file| {
+                rkyv::deserialize::<File, rkyv::rancor::Error>(file)
+                    .expect("archived version always deserializes")
+            })
+            .filter_map(|file| {
+                Some(FlatIndexEntry {
+                    filename: DistFilename::try_from_normalized_filename(&file.filename)?,
+                    file,
+                    index: flat_index.clone(),
+                })
+            })
+            .collect();
+        FlatIndexEntries::from_entries(files)
+    }
+
     /// Read a flat remote index from a `--find-links` directory.
+    ///
+    /// The SHA-256 digest of each distribution is computed, such that distributions from the
+    /// directory can be locked and verified like those from a registry. Digests are cached by
+    /// file size and modification time, to avoid re-reading unchanged distributions.
     fn read_from_directory(
         path: &Path,
         flat_index: &IndexUrl,
+        cache: &Cache,
     ) -> Result<FlatIndexEntries, FindLinksDirectoryError> {
         // The path context is provided by the caller.
         #[expect(clippy::disallowed_methods)]
         let entries = std::fs::read_dir(path)?;
 
+        let cache_entry = cache.entry(
+            CacheBucket::FlatIndex,
+            "local",
+            format!("{}.msgpack", cache_digest(&path.to_string_lossy())),
+        );
+        let cached = LocalDigests::read(&cache_entry);
+        let mut digests = LocalDigests::default();
+
         let mut dists = Vec::new();
         for entry in entries {
             let entry = entry?;
@@ -297,30 +402,57 @@ impl<'a> FlatIndexClient<'a> {
                 continue;
             };
 
+            let Some(dist_filename) = DistFilename::try_from_normalized_filename(filename) else {
+                debug!(
+                    "Ignoring `--find-links` entry (expected a wheel or source distribution filename): {}",
+                    entry.path().display()
+                );
+                continue;
+            };
+
             // SAFETY: The index path is itself constructed from a URL.
             let url = DisplaySafeUrl::from_file_path(entry.path()).unwrap();
 
+            // Compute the digest of the distribution, reusing the cached digest if the file is
+            // unchanged.
+            let digest = match LocalDigest::from_path(&entry.path(), cached.get(filename)) {
+                Ok(digest) => Some(digest),
+                Err(err) => {
+                    warn!(
+                        "Failed to hash `--find-links` entry: {} ({err})",
+                        entry.path().display()
+                    );
+                    None
+                }
+            };
+
             let file = File {
                 dist_info_metadata: false,
                 filename: filename.into(),
-                hashes: HashDigests::empty(),
+                hashes: digest
+                    .as_ref()
+                    .map(|digest| {
+                        HashDigests::from(HashDigest {
+                            algorithm: HashAlgorithm::Sha256,
+                            digest: SmallString::from(digest.sha256.as_str()),
+                        })
+                    })
+                    .unwrap_or_else(HashDigests::empty),
+                provenance: None,
                 requires_python: None,
-                size: None,
+                size: digest.as_ref().map(|digest| digest.size),
                 upload_time_utc_ms: None,
                 url: FileLocation::AbsoluteUrl(UrlString::from(url)),
                 yanked: None,
                 zstd: None,
             };
 
-            let Some(filename) = DistFilename::try_from_normalized_filename(filename) else {
-                debug!(
-                    "Ignoring `--find-links` entry (expected a wheel or source distribution filename): {}",
-                    entry.path().display()
-                );
-                continue;
-            };
+            if let Some(digest) = digest {
+                digests.insert(filename.to_string(), digest);
+            }
+
             dists.push(FlatIndexEntry {
-                filename,
+                filename: dist_filename,
                 file,
                 index: flat_index.clone(),
             });
@@ -332,10 +464,86 @@ impl<'a> FlatIndexClient<'a> {
                 .then_with(|| a.index.cmp(&b.index))
         });
 
+        if digests != cached {
+            if let Err(err) = digests.write(&cache_entry) {
+                debug!(
+                    "Failed to cache digests for `--find-links` directory: {} ({err})",
+                    path.display()
+                );
+            }
+        }
+
         Ok(FlatIndexEntries::from_entries(dists))
     }
 }
 
+/// The cached SHA-256 digests of the distributions in a `--find-links` directory, keyed by
+/// filename.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct LocalDigests(FxHashMap<String, LocalDigest>);
+
+impl LocalDigests {
+    /// Read the cached digests, returning an empty set if the cache entry is missing or invalid.
+    fn read(cache_entry: &CacheEntry) -> Self {
+        cache_entry
+            .materialize_sync()
+            .and_then(|()| fs_err::read(cache_entry.path()))
+            .ok()
+            .and_then(|bytes| rmp_serde::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the digests to the cache.
+    fn write(&self, cache_entry: &CacheEntry) -> std::io::Result<()> {
+        let bytes = rmp_serde::to_vec(self).map_err(std::io::Error::other)?;
+        fs_err::create_dir_all(cache_entry.dir())?;
+        uv_fs::write_atomic_sync(cache_entry.path(), bytes)
+    }
+
+    fn get(&self, filename: &str) -> Option<&LocalDigest> {
+        self.0.get(filename)
+    }
+
+    fn insert(&mut self, filename: String, digest: LocalDigest) {
+        self.0.insert(filename, digest);
+    }
+}
+
+/// The SHA-256 digest of a distribution in a `--find-links` directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LocalDigest {
+    /// The size of the file, in bytes.
+    size: u64,
+    /// The modification time of the file.
+    modified: SystemTime,
+    /// The hex-encoded SHA-256 digest of the file.
+    sha256: String,
+}
+
+impl LocalDigest {
+    /// Compute the digest of the file at the given path, unless the cached digest refers to a file
+    /// of the same size and modification time.
+    fn from_path(path: &Path, cached: Option<&Self>) -> std::io::Result<Self> {
+        let metadata = fs_err::metadata(path)?;
+        let size = metadata.len();
+        let modified = metadata.modified()?;
+        if let Some(cached) = cached
+            && cached.size == size
+            && cached.modified == modified
+        {
+            return Ok(cached.clone());
+        }
+
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut fs_err::File::open(path)?, &mut hasher)?;
+        Ok(Self {
+            size,
+            modified,
+            sha256: format!("{:x}", hasher.finalize()),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -361,6 +569,7 @@ mod tests {
         let entries = FlatIndexClient::read_from_directory(
             dir.path(),
             &IndexUrl::parse(&dir.path().to_string_lossy(), None).unwrap(),
+            &Cache::temp().unwrap(),
         )
         .unwrap();
 
@@ -384,4 +593,38 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+    #[test]
+    fn read_from_directory_hashes_distributions() {
+        let dir = tempdir().unwrap();
+        let cache = Cache::temp().unwrap();
+        let index = IndexUrl::parse(&dir.path().to_string_lossy(), None).unwrap();
+
+        let path = dir.path().join("alpha-1.0.0-py3-none-any.whl");
+        File::create(&path).unwrap().write_all(b"alpha").unwrap();
+
+        let entries = FlatIndexClient::read_from_directory(dir.path(), &index, &cache).unwrap();
+        let file = &entries.entries[0].file;
+        assert_eq!(file.size, Some(5));
+        assert_eq!(
+            file.hashes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["sha256:8ed3f6ad685b959ead7022518e1af76cd816f8e8ec7ccdda1ed4018e8f2223f8"]
+        );
+
+        // If the file changes, the digest should be recomputed.
+        File::create(&path).unwrap().write_all(b"alpha-2").unwrap();
+
+        let entries = FlatIndexClient::read_from_directory(dir.path(), &index, &cache).unwrap();
+        let file = &entries.entries[0].file;
+        assert_eq!(file.size, Some(7));
+        assert_ne!(
+            file.hashes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["sha256:8ed3f6ad685b959ead7022518e1af76cd816f8e8ec7ccdda1ed4018e8f2223f8"]
+        );
+    }
 }
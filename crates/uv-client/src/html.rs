@@ -6,7 +6,9 @@ use tracing::{debug, instrument, warn};
 
 use uv_normalize::PackageName;
 use uv_pep440::VersionSpecifiers;
-use uv_pypi_types::{BaseUrl, CoreMetadata, Hashes, ProjectStatus, PypiFile, Status, Yanked};
+use uv_pypi_types::{
+    BaseUrl, CoreMetadata, Hashes, ProjectStatus, PypiFile, RepositoryTracking, Status, Yanked,
+};
 use uv_pypi_types::{HashError, LenientVersionSpecifiers};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_small_str::SmallString;
@@ -17,6 +19,8 @@ pub(crate) struct SimpleDetailHTML {
     /// The PEP 792 project status information.
     #[allow(dead_code)]
     pub(crate) project_status: ProjectStatus,
+    /// The PEP 708 repository tracking metadata.
+    pub(crate) tracking: RepositoryTracking,
     /// The [`BaseUrl`] to which all relative URLs should be resolved.
     pub(crate) base: BaseUrl,
     /// The list of [`PypiFile`]s available for download sorted by filename.
@@ -33,18 +37,24 @@ impl SimpleDetailHTML {
         // Specifically, it appears as `name="pypi:project-status"`
         // and `name="pypi:project-status-reason"` with corresponding
         // `content` attributes.
-        let project_status = dom
-            .nodes()
-            .iter()
-            .find(|node| {
-                node.as_tag()
-                    .is_some_and(|tag| tag.name().as_bytes() == b"head")
-            })
+        let head = dom.nodes().iter().find(|node| {
+            node.as_tag()
+                .is_some_and(|tag| tag.name().as_bytes() == b"head")
+        });
+        let project_status = head
             .map(|head| Self::parse_project_status(dom.parser(), head))
             .transpose()?
             .flatten()
             .unwrap_or_default();
 
+        // Repository tracking information also appears in the `<meta>` tags, as
+        // `name="pypi:tracks"` and `name="pypi:alternate-locations"`, each of which may be
+        // repeated.
+        let tracking = head
+            .map(|head| Self::parse_tracking(dom.parser(), head))
+            .transpose()?
+            .unwrap_or_default();
+
         // Parse the first `<base>` tag, if any, to determine the base URL to which all
         // relative URLs should be resolved. The HTML spec requires that the `<base>` tag
         // appear before other tags with attribute values of URLs.
@@ -85,6 +95,7 @@ impl SimpleDetailHTML {
 
         Ok(Self {
             project_status,
+            tracking,
             base,
             files,
         })
@@ -94,20 +105,6 @@ impl SimpleDetailHTML {
     ///
     /// Precondition: `head` is a `<head>` tag.
     fn parse_project_status(parser: &Parser, head: &Node) -> Result<Option<ProjectStatus>, Error> {
-        /// Extract the value of the `content` attribute from a tag.
-        fn content<'a>(tag: &'a HTMLTag<'a>) -> Result<Option<&'a str>, Error> {
-            let Some(content) = tag
-                .attributes()
-                .get("content")
-                .and_then(|bytes| bytes)
-                .map(|bytes| std::str::from_utf8(bytes.as_bytes()))
-                .transpose()?
-            else {
-                return Ok(None);
-            };
-            Ok(Some(content))
-        }
-
         let Some(children) = head.children() else {
             return Ok(None);
         };
@@ -130,7 +127,7 @@ impl SimpleDetailHTML {
             match name {
                 "pypi:project-status" => {
                     status = {
-                        let Some(status) = content(tag)?.and_then(Status::new) else {
+                        let Some(status) = Self::parse_content(tag)?.and_then(Status::new) else {
                             return Ok(None);
                         };
                         Some(status)
@@ -138,7 +135,7 @@ impl SimpleDetailHTML {
                 }
                 "pypi:project-status-reason" => {
                     reason = {
-                        let Some(content) = content(tag)?.map(SmallString::from) else {
+                        let Some(content) = Self::parse_content(tag)?.map(SmallString::from) else {
                             // TODO: Make this a hard error instead?
                             warn!("Invalid project status reason (missing)");
                             return Ok(None);
@@ -158,6 +155,58 @@ impl SimpleDetailHTML {
         }
     }
 
+    /// Parse the [`RepositoryTracking`] from the `<meta>` tags in the given `<head>`.
+    ///
+    /// Precondition: `head` is a `<head>` tag.
+    fn parse_tracking(parser: &Parser, head: &Node) -> Result<RepositoryTracking, Error> {
+        let mut tracking = RepositoryTracking::default();
+
+        let Some(children) = head.children() else {
+            return Ok(tracking);
+        };
+
+        for node in children.all(parser) {
+            let tag = match node.as_tag() {
+                Some(tag) if tag.name().as_bytes() == b"meta" => tag,
+                _ => continue,
+            };
+
+            let name = match tag.attributes().get("name").and_then(|bytes| bytes) {
+                Some(name) => std::str::from_utf8(name.as_bytes())?,
+                None => continue,
+            };
+
+            let urls = match name {
+                "pypi:tracks" => &mut tracking.tracks,
+                "pypi:alternate-locations" => &mut tracking.alternate_locations,
+                _ => continue,
+            };
+            if let Some(content) = Self::parse_content(tag)? {
+                urls.push(SmallString::from(html_escape::decode_html_entities(
+                    content,
+                )));
+            } else {
+                warn!("Invalid `{name}` metadata (missing content)");
+            }
+        }
+
+        Ok(tracking)
+    }
+
+    /// Extract the value of the `content` attribute from a tag.
+    fn parse_content<'a>(tag: &'a HTMLTag<'a>) -> Result<Option<&'a str>, Error> {
+        let Some(content) = tag
+            .attributes()
+            .get("content")
+            .and_then(|bytes| bytes)
+            .map(|bytes| std::str::from_utf8(bytes.as_bytes()))
+            .transpose()?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(content))
+    }
+
     /// Parse the `href` from a `<base>` tag.
     fn parse_base(base: &HTMLTag) -> Result<Option<DisplaySafeUrl>, Error> {
         let Some(Some(href)) = base.attributes().get("href") else {
@@ -283,6 +332,16 @@ impl SimpleDetailHTML {
             None
         };
 
+        // Extract the `provenance` field, which should be set on the `data-provenance` attribute,
+        // per PEP 740.
+        let provenance = link
+            .attributes()
+            .get("data-provenance")
+            .flatten()
+            .map(|provenance| std::str::from_utf8(provenance.as_bytes()))
+            .transpose()?
+            .map(|provenance| SmallString::from(html_escape::decode_html_entities(provenance)));
+
         // Extract the `size` field, which should be set on the `data-size` attribute. This isn't
         // included in PEP 700, which omits the HTML API, but we respect it anyway. Since this
         // field isn't standardized, we discard errors.
@@ -310,6 +369,7 @@ impl SimpleDetailHTML {
             yanked,
             requires_python,
             hashes,
+            provenance,
             filename: filename.into(),
             url: path.into(),
             size,
@@ -425,6 +485,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -455,6 +519,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -486,6 +551,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -516,6 +585,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -550,6 +620,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -580,6 +654,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -611,6 +686,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -641,6 +720,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -672,6 +752,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -702,6 +786,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -733,6 +818,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -761,6 +850,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -792,6 +882,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -820,6 +914,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -851,6 +946,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -893,6 +992,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -935,6 +1038,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -963,6 +1070,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -994,6 +1102,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1022,6 +1134,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1054,6 +1167,10 @@ mod tests {
                     status: Active,
                     reason: None,
                 },
+                tracking: RepositoryTracking {
+                    tracks: [],
+                    alternate_locations: [],
+                },
                 base: BaseUrl(
                     DisplaySafeUrl {
                         scheme: "https",
@@ -1082,6 +1199,7 @@ mod tests {
                             sha512: None,
                             blake2b: None,
                         },
+                        provenance: None,
                         requires_python: None,
                         size: None,
                         upload_time: None,
@@ -1115,6 +1233,10 @@ mod tests {
                     status: Active,
                     reason: None,
                 },
+                tracking: RepositoryTracking {
+                    tracks: [],
+                    alternate_locations: [],
+                },
                 base: BaseUrl(
                     DisplaySafeUrl {
                         scheme: "https",
@@ -1143,6 +1265,7 @@ mod tests {
                             sha512: None,
                             blake2b: None,
                         },
+                        provenance: None,
                         requires_python: None,
                         size: None,
                         upload_time: None,
@@ -1195,6 +1318,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1223,6 +1350,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1239,6 +1367,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1281,6 +1410,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1311,6 +1444,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1329,6 +1463,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1347,6 +1482,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1388,6 +1524,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1418,6 +1558,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1465,6 +1606,10 @@ mod tests {
                 status: Active,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             base: BaseUrl(
                 DisplaySafeUrl {
                     scheme: "https",
@@ -1497,6 +1642,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1517,6 +1663,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1537,6 +1684,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1557,6 +1705,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1577,6 +1726,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1657,6 +1807,45 @@ mod tests {
         "#);
     }
 
+    /// Test parsing PEP 708 repository tracking metadata.
+    #[test]
+    fn parse_simple_detail_with_tracking() {
+        let text = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta name="pypi:repository-version" content="1.1">
+    <meta name="pypi:tracks" content="https://pypi.org/simple/fakeproject/">
+    <meta name="pypi:alternate-locations" content="https://example.com/simple/fakeproject/">
+    <meta name="pypi:alternate-locations" content="https://example.org/simple/fakeproject/">
+    <title>Links for fakeproject</title>
+</head>
+<body>
+    <h1>Links for fakeproject</h1>
+    <a href="https://example.com/fakeproject">fakeproject-1.2.3.tar.gz</a>
+    <br/>
+</body>
+</html>
+        "#;
+
+        let result = SimpleDetailHTML::parse(
+            text,
+            &DisplaySafeUrl::parse("https://example.com/simple/fakeproject/").unwrap(),
+        )
+        .unwrap();
+        insta::assert_debug_snapshot!(result.tracking, @r#"
+        RepositoryTracking {
+            tracks: [
+                "https://pypi.org/simple/fakeproject/",
+            ],
+            alternate_locations: [
+                "https://example.com/simple/fakeproject/",
+                "https://example.org/simple/fakeproject/",
+            ],
+        }
+        "#);
+    }
+
     // Test parsing project status metadata with emojis in the reason.
     #[test]
     fn parse_simple_detail_with_project_status_and_emoji_reason() {
@@ -1899,4 +2088,32 @@ mod tests {
         }
         "#);
     }
+    /// Respect PEP 740 (see: <https://peps.python.org/pep-0740/>).
+    #[test]
+    fn parse_provenance() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<body>
+<h1>Links for jinja2</h1>
+<a href="/whl/Jinja2-3.1.2-py3-none-any.whl" data-provenance="/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+<a href="/whl/Jinja2-3.1.3-py3-none-any.whl">Jinja2-3.1.3-py3-none-any.whl</a><br/>
+</body>
+</html>
+    "#;
+        let base = DisplaySafeUrl::parse("https://pypi.org/simple/jinja2/").unwrap();
+        let result = SimpleDetailHTML::parse(text, &base).unwrap();
+        let provenance = result
+            .files
+            .iter()
+            .map(|file| file.provenance.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            provenance,
+            vec![
+                Some("/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance"),
+                None
+            ]
+        );
+    }
 }
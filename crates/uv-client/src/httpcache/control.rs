@@ -140,6 +140,19 @@ impl FromIterator<CacheControlDirective> for CacheControl {
     }
 }
 
+/// Returns the value of the `stale-while-revalidate` directive in the given
+/// `Cache-Control` header value, if any.
+///
+/// The directive is defined in [RFC 5861 S3]. It isn't stored as part of
+/// [`CacheControl`], since it's only used for user-provided headers.
+///
+/// [RFC 5861 S3]: https://www.rfc-editor.org/rfc/rfc5861#section-3
+pub(crate) fn stale_while_revalidate_seconds(header: &str) -> Option<u64> {
+    CacheControlParser::new([header])
+        .find(|directive| directive.name == "stale-while-revalidate")
+        .and_then(|directive| std::str::from_utf8(&directive.value).ok()?.parse().ok())
+}
+
 /// A parser for the HTTP `Cache-Control` header.
 ///
 /// The parser is mostly defined across multiple parts of multiple RFCs.
@@ -491,6 +504,23 @@ mod tests {
         assert!(!cc.must_revalidate);
     }
 
+    #[test]
+    fn cache_control_stale_while_revalidate() {
+        assert_eq!(
+            stale_while_revalidate_seconds("max-age=60, stale-while-revalidate=600"),
+            Some(600)
+        );
+        assert_eq!(
+            stale_while_revalidate_seconds("stale-while-revalidate=\"30\""),
+            Some(30)
+        );
+        assert_eq!(stale_while_revalidate_seconds("max-age=60"), None);
+        assert_eq!(
+            stale_while_revalidate_seconds("stale-while-revalidate=soon"),
+            None
+        );
+    }
+
     #[test]
     fn cache_control_unrecognized() {
         let cc: CacheControl = CacheControlParser::new(["lion,max-age=60,zebra"]).collect();
@@ -142,6 +142,7 @@ use http::header::HeaderValue;
 use crate::rkyvutil::OwnedArchive;
 
 use self::control::CacheControl;
+pub(crate) use self::control::stale_while_revalidate_seconds;
 
 mod control;
 
@@ -400,10 +401,54 @@ impl ArchivedCachePolicy {
             AfterResponse::Modified(new_policy)
         } else {
             new_policy.response.status = self.response.status.into();
+            // As per [RFC 9111 S4.3.4], the stored response is updated with the header fields
+            // provided in the 304 response. Any header fields absent from the 304 response (e.g.,
+            // validators or freshness information) are retained from the stored response, such
+            // that subsequent revalidation requests can continue to use them.
+            //
+            // [RFC 9111 S4.3.4]: https://www.rfc-editor.org/rfc/rfc9111.html#section-4.3.4
+            self.response
+                .headers
+                .retain_absent(&mut new_policy.response.headers, response.headers());
             AfterResponse::NotModified(new_policy)
         }
     }
 
+    /// Returns true if the stale cached response may be served while it's
+    /// revalidated in the background, as per the `stale-while-revalidate`
+    /// directive in [RFC 5861 S3].
+    ///
+    /// The window is provided by the caller (rather than read from the
+    /// cached response), since it's configured by the user on a per-index
+    /// basis.
+    ///
+    /// [RFC 5861 S3]: https://www.rfc-editor.org/rfc/rfc5861#section-3
+    pub fn allows_stale_while_revalidate(
+        &self,
+        request: &reqwest::Request,
+        stale_while_revalidate: u64,
+    ) -> bool {
+        let cc = &self.response.headers.cc;
+        if cc.no_cache || cc.must_revalidate || !self.vary.matches(request.headers()) {
+            return false;
+        }
+        let stale_amount = self
+            .age(SystemTime::now())
+            .as_secs()
+            .saturating_sub(self.freshness_lifetime().as_secs());
+        if stale_amount > stale_while_revalidate {
+            tracing::trace!(
+                "Request to {} has a cached response that is {} seconds stale, \
+                 beyond the 'stale-while-revalidate' window of {} seconds",
+                request.url(),
+                stale_amount,
+                stale_while_revalidate,
+            );
+            return false;
+        }
+        true
+    }
+
     fn is_modified(&self, new_policy: &CachePolicy) -> bool {
         // From [RFC 9111 S4.3.3],
         //
@@ -462,10 +507,16 @@ impl ArchivedCachePolicy {
         // we can just rely on the HTTP 304 status code and reuse the cached
         // response.
         //
+        // We go a bit further: if the 304 response doesn't include any
+        // validators, then it can only refer to our stored response, since
+        // the validators in the revalidation request (if any) were taken from
+        // it. [RFC 9110 S15.4.5] requires servers to send the `ETag` in a 304
+        // response, but not all do, and treating such a response as modified
+        // would force a redundant (and uncached) request.
+        //
         // [RFC 9111 S4.3.4]: https://www.rfc-editor.org/rfc/rfc9111.html#section-4.3.4
-        if self.response.headers.etag.is_none()
-            && new_policy.response.headers.etag.is_none()
-            && self.response.headers.last_modified_unix_timestamp.is_none()
+        // [RFC 9110 S15.4.5]: https://www.rfc-editor.org/rfc/rfc9110#section-15.4.5
+        if new_policy.response.headers.etag.is_none()
             && new_policy
                 .response
                 .headers
@@ -473,8 +524,8 @@ impl ArchivedCachePolicy {
                 .is_none()
         {
             tracing::trace!(
-                "Resource is not modified because there are no etags or last modified \
-                 timestamps, so we assume the 304 status is correct",
+                "Resource is not modified because the 304 response has no etag or \
+                 last modified timestamp, so we assume it refers to the cached response",
             );
             return false;
         }
@@ -1213,6 +1264,30 @@ impl<'a> From<&'a http::HeaderMap> for ResponseHeaders {
     }
 }
 
+impl ArchivedResponseHeaders {
+    /// Copies the values of any header fields that are absent from the given
+    /// (304) response headers from this (stored) response into `new`.
+    fn retain_absent(&self, new: &mut ResponseHeaders, headers: &http::HeaderMap) {
+        if !headers.contains_key("cache-control") {
+            if let Ok(cc) = rkyv::deserialize::<CacheControl, rkyv::rancor::Error>(&self.cc) {
+                new.cc = cc;
+            }
+        }
+        if !headers.contains_key("expires") {
+            new.expires_unix_timestamp = self.expires_unix_timestamp.as_ref().map(u64::from);
+        }
+        if !headers.contains_key("last-modified") {
+            new.last_modified_unix_timestamp =
+                self.last_modified_unix_timestamp.as_ref().map(u64::from);
+        }
+        if !headers.contains_key("etag") {
+            if let Ok(etag) = rkyv::deserialize::<Option<ETag>, rkyv::rancor::Error>(&self.etag) {
+                new.etag = etag;
+            }
+        }
+    }
+}
+
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
 struct ETag {
@@ -1,18 +1,26 @@
+pub use attestation::{
+    AttestationError, AttestationStatus, IN_TOTO_PAYLOAD_TYPE, IN_TOTO_STATEMENT_TYPE, dsse_pae,
+    verify_provenance,
+};
+pub use audit::{init_audit_log, with_audit_package};
 pub use base_client::{
     AuthIntegration, BaseClient, BaseClientBuilder, DEFAULT_MAX_REDIRECTS, DEFAULT_RETRIES,
-    ExtraMiddleware, RedirectClientWithMiddleware, RedirectPolicy, RequestBuilder,
-    RetryParsingError, RetryState, UvRetryableStrategy,
+    ExtraMiddleware, IndexClientConfig, RedirectClientWithMiddleware, RedirectPolicy,
+    RequestBuilder, RetryParsingError, RetryState, UvRetryPolicy, UvRetryableStrategy,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use policy::{check_index_allowed, check_url_allowed, init_allowed_indexes};
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleDetailMetadata,
     SimpleDetailMetadatum, SimpleIndexMetadata, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
 
+mod attestation;
+mod audit;
 mod base_client;
 mod cached_client;
 mod error;
@@ -21,7 +29,11 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod oci;
+mod policy;
 mod registry_client;
 mod remote_metadata;
+mod resumable;
 mod rkyvutil;
+mod throttle;
 mod tls;
@@ -5,6 +5,8 @@ use uv_redacted::DisplaySafeUrl;
 use reqwest::{Request, Response};
 use reqwest_middleware::{Middleware, Next};
 
+use crate::tls::IndexCertificateError;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -49,3 +51,19 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// A middleware that always returns an error indicating that the certificates configured for an
+/// index are invalid.
+pub(crate) struct InvalidCertificateMiddleware(pub(crate) IndexCertificateError);
+
+#[async_trait::async_trait]
+impl Middleware for InvalidCertificateMiddleware {
+    async fn handle(
+        &self,
+        _req: Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        Err(reqwest_middleware::Error::Middleware(self.0.clone().into()))
+    }
+}
@@ -0,0 +1,632 @@
+//! Support for reading wheels published as OCI artifacts (e.g., `oci://ghcr.io/org/repo:tag`).
+//!
+//! An OCI artifact is treated as a flat index: each layer in the image manifest is a single
+//! distribution, named by its `org.opencontainers.image.title` annotation (as set by, e.g.,
+//! `oras push`), and downloaded from the registry's blob endpoint.
+
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+use http::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use http::{Extensions, HeaderValue, StatusCode};
+use reqwest::{Request, Response};
+use reqwest_middleware::{ClientWithMiddleware, Middleware, Next};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tracing::{debug, trace, warn};
+use url::Url;
+
+use uv_auth::DockerConfig;
+use uv_distribution_types::{File, FileLocation, UrlString};
+use uv_pypi_types::{HashDigest, HashDigests};
+use uv_redacted::DisplaySafeUrl;
+
+/// The media types to accept when fetching a manifest.
+pub(crate) const MANIFEST_ACCEPT: &str = "application/vnd.oci.image.manifest.v1+json, \
+     application/vnd.oci.image.index.v1+json, \
+     application/vnd.docker.distribution.manifest.v2+json";
+
+/// The annotation containing the filename of a layer.
+const TITLE_ANNOTATION: &str = "org.opencontainers.image.title";
+
+/// The Docker configuration, read lazily on the first authentication challenge.
+static DOCKER_CONFIG: LazyLock<Option<DockerConfig>> = LazyLock::new(DockerConfig::from_settings);
+
+/// A reference to an artifact in an OCI registry, like `oci://ghcr.io/org/repo:tag` or
+/// `oci://ghcr.io/org/repo@sha256:...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OciReference {
+    /// The base URL of the registry API (e.g., `https://ghcr.io`).
+    registry: DisplaySafeUrl,
+    /// The repository within the registry (e.g., `org/repo`).
+    repository: String,
+    /// The tag or digest of the artifact (e.g., `latest` or `sha256:...`).
+    reference: String,
+}
+
+impl OciReference {
+    /// Parse an [`OciReference`] from an `oci://` URL.
+    ///
+    /// Registries on loopback addresses are accessed over plain HTTP, as in Docker; all others
+    /// are accessed over HTTPS.
+    pub(crate) fn from_url(url: &Url) -> Option<Self> {
+        if url.scheme() != "oci" {
+            return None;
+        }
+
+        // As `oci` isn't a special scheme, IP addresses are parsed as opaque hosts.
+        let host = match url.host_str()? {
+            // Docker Hub serves its API from a different domain.
+            "docker.io" => "registry-1.docker.io",
+            host => host,
+        };
+        let is_loopback = host == "localhost"
+            || host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback());
+        let scheme = if is_loopback { "http" } else { "https" };
+        let registry = match url.port() {
+            Some(port) => format!("{scheme}://{host}:{port}"),
+            None => format!("{scheme}://{host}"),
+        };
+        let registry = DisplaySafeUrl::parse(&registry).ok()?;
+
+        let path = url.path().trim_matches('/');
+        let (repository, reference) = if let Some((repository, digest)) = path.split_once('@') {
+            (repository, digest)
+        } else {
+            // The tag follows the first `:` in the final path segment.
+            let name = path.rfind('/').map_or(0, |index| index + 1);
+            match path[name..].find(':') {
+                Some(index) => (&path[..name + index], &path[name + index + 1..]),
+                None => (path, "latest"),
+            }
+        };
+        if repository.is_empty() || reference.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            registry,
+            repository: repository.to_string(),
+            reference: reference.to_string(),
+        })
+    }
+
+    /// Return the URL of the artifact's manifest.
+    pub(crate) fn manifest_url(&self) -> DisplaySafeUrl {
+        let mut url = self.registry.clone();
+        url.set_path(&format!(
+            "/v2/{}/manifests/{}",
+            self.repository, self.reference
+        ));
+        url
+    }
+
+    /// Returns `true` if the URL is an API endpoint for the artifact's repository (e.g., its
+    /// manifest or one of its blobs).
+    fn is_endpoint(&self, url: &Url) -> bool {
+        url.scheme() == self.registry.scheme()
+            && url.host_str() == self.registry.host_str()
+            && url.port_or_known_default() == self.registry.port_or_known_default()
+            && registry_key(url).is_some_and(|(_, repository)| repository == self.repository)
+    }
+
+    /// Return the URL of the blob with the given digest.
+    fn blob_url(&self, digest: &str) -> DisplaySafeUrl {
+        let mut url = self.registry.clone();
+        url.set_path(&format!("/v2/{}/blobs/{digest}", self.repository));
+        url
+    }
+}
+
+/// An OCI image manifest or image index.
+///
+/// See: <https://github.com/opencontainers/image-spec/blob/main/manifest.md>
+#[derive(Debug, Deserialize)]
+pub(crate) struct OciManifest {
+    #[serde(default)]
+    layers: Vec<OciDescriptor>,
+    /// The manifests referenced by an image index, which are unsupported.
+    #[serde(default)]
+    manifests: Vec<OciDescriptor>,
+}
+
+/// An OCI content descriptor.
+#[derive(Debug, Deserialize)]
+struct OciDescriptor {
+    digest: String,
+    size: u64,
+    #[serde(default)]
+    annotations: FxHashMap<String, String>,
+}
+
+impl OciManifest {
+    /// Returns `true` if the manifest is an image index, rather than an image manifest.
+    pub(crate) fn is_index(&self) -> bool {
+        !self.manifests.is_empty()
+    }
+
+    /// Convert the layers of the manifest into [`File`] entries.
+    pub(crate) fn into_files(self, reference: &OciReference) -> Vec<File> {
+        self.layers
+            .into_iter()
+            .filter_map(|layer| {
+                let Some(filename) = layer.annotations.get(TITLE_ANNOTATION) else {
+                    debug!(
+                        "Skipping layer without a `{TITLE_ANNOTATION}` annotation: {}",
+                        layer.digest
+                    );
+                    return None;
+                };
+                let hashes = HashDigest::from_str(&layer.digest)
+                    .map(HashDigests::from)
+                    .unwrap_or_else(|_| HashDigests::empty());
+                Some(File {
+                    dist_info_metadata: false,
+                    filename: filename.as_str().into(),
+                    hashes,
+                    provenance: None,
+                    requires_python: None,
+                    size: Some(layer.size),
+                    upload_time_utc_ms: None,
+                    url: FileLocation::AbsoluteUrl(UrlString::from(
+                        reference.blob_url(&layer.digest),
+                    )),
+                    yanked: None,
+                    zstd: None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A `Bearer` authentication challenge from a registry.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct BearerChallenge {
+    realm: String,
+    service: Option<String>,
+    scope: Option<String>,
+}
+
+impl BearerChallenge {
+    /// Parse a `WWW-Authenticate` header value, like
+    /// `Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:org/repo:pull"`.
+    fn parse(header: &str) -> Option<Self> {
+        let (scheme, params) = header.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+
+        let mut challenge = Self::default();
+        let mut rest = params.trim();
+        while !rest.is_empty() {
+            let (key, value) = rest.split_once('=')?;
+            let key = key.trim().trim_start_matches(',').trim();
+            let value = value.trim_start();
+            let (value, remainder) = if let Some(quoted) = value.strip_prefix('"') {
+                quoted.split_once('"')?
+            } else {
+                value.split_once(',').unwrap_or((value, ""))
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "realm" => challenge.realm = value.to_string(),
+                "service" => challenge.service = Some(value.to_string()),
+                "scope" => challenge.scope = Some(value.to_string()),
+                _ => {}
+            }
+            rest = remainder.trim_start().trim_start_matches(',').trim_start();
+        }
+
+        if challenge.realm.is_empty() {
+            return None;
+        }
+        Some(challenge)
+    }
+}
+
+/// The response from a registry token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// Returns `true` if credentials may be sent to the token realm advertised by a registry.
+///
+/// The realm must be served over HTTPS from the registry's own host, or from `auth.docker.io` for
+/// Docker Hub. Registries on loopback addresses, which are accessed over plain HTTP, may also
+/// advertise a plain HTTP realm on the same host.
+fn is_trusted_realm(realm: &Url, registry: &Url) -> bool {
+    let scheme =
+        realm.scheme() == "https" || (realm.scheme() == "http" && registry.scheme() == "http");
+    let host = realm.host_str() == registry.host_str()
+        || (registry.host_str() == Some("registry-1.docker.io")
+            && realm.host_str() == Some("auth.docker.io"));
+    scheme && host
+}
+
+/// Return the registry authority (e.g., `ghcr.io` or `localhost:5000`) and repository for a
+/// request to an OCI distribution API endpoint.
+fn registry_key(url: &Url) -> Option<(String, String)> {
+    let path = url.path().strip_prefix("/v2/")?;
+    let (repository, _) = path
+        .rsplit_once("/manifests/")
+        .or_else(|| path.rsplit_once("/blobs/"))?;
+    let authority = match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str()?),
+        None => url.host_str()?.to_string(),
+    };
+    Some((authority, repository.to_string()))
+}
+
+/// A middleware that authenticates requests to OCI registries via the registry token flow.
+///
+/// Only requests to the repositories of the configured `oci://` indexes are authenticated. When
+/// the registry responds with a `Bearer` challenge, a token is requested from the advertised
+/// realm, using any Basic credentials attached to the request (e.g., from the netrc file or
+/// keyring) or, failing that, the credentials from the Docker configuration. Tokens are cached
+/// per repository for the lifetime of the client.
+///
+/// See: <https://distribution.github.io/distribution/spec/auth/token/>
+pub(crate) struct OciAuthMiddleware {
+    /// The client used to request tokens.
+    client: ClientWithMiddleware,
+    /// The artifacts of the configured `oci://` indexes.
+    references: Vec<OciReference>,
+    /// The cached tokens, keyed by registry authority and repository.
+    tokens: RwLock<FxHashMap<(String, String), HeaderValue>>,
+}
+
+impl OciAuthMiddleware {
+    pub(crate) fn new(client: ClientWithMiddleware, references: Vec<OciReference>) -> Self {
+        Self {
+            client,
+            references,
+            tokens: RwLock::new(FxHashMap::default()),
+        }
+    }
+
+    /// Request a token for the given challenge.
+    async fn fetch_token(
+        &self,
+        challenge: &BearerChallenge,
+        authorization: Option<HeaderValue>,
+        registry: &Url,
+        authority: &str,
+        repository: &str,
+    ) -> Option<HeaderValue> {
+        let mut url = Url::parse(&challenge.realm)
+            .inspect_err(|err| warn!("Invalid registry token realm `{}`: {err}", challenge.realm))
+            .ok()?;
+        if !is_trusted_realm(&url, registry) {
+            warn!(
+                "Ignoring registry token realm `{}` for `{registry}`: the realm must use HTTPS on the registry's host",
+                challenge.realm
+            );
+            return None;
+        }
+        {
+            let mut query = url.query_pairs_mut();
+            if let Some(service) = &challenge.service {
+                query.append_pair("service", service);
+            }
+            // Only ever request read access to the repository being fetched, regardless of the
+            // scope advertised by the registry.
+            let scope = format!("repository:{repository}:pull");
+            if let Some(requested) = challenge
+                .scope
+                .as_deref()
+                .filter(|&requested| requested != scope)
+            {
+                debug!(
+                    "Requesting scope `{scope}` instead of registry-advertised scope `{requested}`"
+                );
+            }
+            query.append_pair("scope", &scope);
+        }
+
+        let authorization = match authorization {
+            Some(authorization) => Some(authorization),
+            None => match DOCKER_CONFIG.as_ref() {
+                Some(config) => config
+                    .credentials_for(authority)
+                    .await
+                    .map(|credentials| credentials.to_header_value()),
+                None => None,
+            },
+        };
+
+        trace!("Requesting registry token from: {url}");
+        let mut request = self.client.get(url.clone());
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status().map_err(Into::into))
+            .inspect_err(|err| warn!("Failed to request registry token from `{url}`: {err}"))
+            .ok()?;
+        let TokenResponse {
+            token,
+            access_token,
+        } = response
+            .json()
+            .await
+            .inspect_err(|err| warn!("Failed to parse registry token from `{url}`: {err}"))
+            .ok()?;
+
+        let token = token.or(access_token).filter(|token| !token.is_empty())?;
+        let mut header = HeaderValue::from_str(&format!("Bearer {token}")).ok()?;
+        header.set_sensitive(true);
+        Some(header)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for OciAuthMiddleware {
+    async fn handle(
+        &self,
+        mut request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(reference) = self
+            .references
+            .iter()
+            .find(|reference| reference.is_endpoint(request.url()))
+        else {
+            return next.run(request, extensions).await;
+        };
+        let Some(key) = registry_key(request.url()) else {
+            return next.run(request, extensions).await;
+        };
+
+        // Reuse a token from a previous challenge.
+        if !request.headers().contains_key(AUTHORIZATION) {
+            if let Some(token) = self
+                .tokens
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&key)
+            {
+                request.headers_mut().insert(AUTHORIZATION, token.clone());
+            }
+        }
+
+        let Some(mut retry_request) = request.try_clone() else {
+            return next.run(request, extensions).await;
+        };
+        let response = next.clone().run(request, extensions).await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        let Some(challenge) = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|header| header.to_str().ok())
+            .and_then(BearerChallenge::parse)
+        else {
+            return Ok(response);
+        };
+
+        // Forward Basic credentials to the token endpoint, but never a (stale) Bearer token.
+        let authorization = retry_request
+            .headers()
+            .get(AUTHORIZATION)
+            .filter(|header| header.as_bytes().starts_with(b"Basic "))
+            .cloned();
+        let Some(token) = self
+            .fetch_token(
+                &challenge,
+                authorization,
+                &reference.registry,
+                &key.0,
+                &key.1,
+            )
+            .await
+        else {
+            return Ok(response);
+        };
+
+        debug!(
+            "Retrying request for {} with registry token",
+            retry_request.url()
+        );
+        retry_request
+            .headers_mut()
+            .insert(AUTHORIZATION, token.clone());
+        self.tokens
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, token);
+        next.run(retry_request, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reference() {
+        let reference =
+            OciReference::from_url(&Url::parse("oci://ghcr.io/org/wheels:v1").unwrap()).unwrap();
+        assert_eq!(
+            reference.manifest_url().as_str(),
+            "https://ghcr.io/v2/org/wheels/manifests/v1"
+        );
+
+        let reference =
+            OciReference::from_url(&Url::parse("oci://ghcr.io/org/wheels").unwrap()).unwrap();
+        assert_eq!(
+            reference.manifest_url().as_str(),
+            "https://ghcr.io/v2/org/wheels/manifests/latest"
+        );
+
+        let reference = OciReference::from_url(
+            &Url::parse("oci://localhost:5000/wheels@sha256:abc123").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            reference.manifest_url().as_str(),
+            "http://localhost:5000/v2/wheels/manifests/sha256:abc123"
+        );
+        assert_eq!(
+            reference.blob_url("sha256:def456").as_str(),
+            "http://localhost:5000/v2/wheels/blobs/sha256:def456"
+        );
+
+        let reference =
+            OciReference::from_url(&Url::parse("oci://docker.io/library/wheels:1.0").unwrap())
+                .unwrap();
+        assert_eq!(
+            reference.manifest_url().as_str(),
+            "https://registry-1.docker.io/v2/library/wheels/manifests/1.0"
+        );
+
+        assert!(OciReference::from_url(&Url::parse("oci://ghcr.io").unwrap()).is_none());
+        assert!(
+            OciReference::from_url(&Url::parse("https://ghcr.io/org/wheels").unwrap()).is_none()
+        );
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let reference =
+            OciReference::from_url(&Url::parse("oci://ghcr.io/org/wheels:v1").unwrap()).unwrap();
+        let manifest: OciManifest = serde_json::from_str(
+            r#"{
+                "schemaVersion": 2,
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "config": {
+                    "mediaType": "application/vnd.oci.empty.v1+json",
+                    "digest": "sha256:44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a",
+                    "size": 2
+                },
+                "layers": [
+                    {
+                        "mediaType": "application/vnd.oci.image.layer.v1.tar",
+                        "digest": "sha256:5b3a9f8e0d9fa2d4e6a2e4b7a3c1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3",
+                        "size": 1024,
+                        "annotations": {
+                            "org.opencontainers.image.title": "example-1.0.0-py3-none-any.whl"
+                        }
+                    },
+                    {
+                        "mediaType": "application/vnd.oci.image.layer.v1.tar",
+                        "digest": "sha256:0000000000000000000000000000000000000000000000000000000000000000",
+                        "size": 8
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(!manifest.is_index());
+
+        let files = manifest.into_files(&reference);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].filename.as_ref(), "example-1.0.0-py3-none-any.whl");
+        assert_eq!(files[0].size, Some(1024));
+        assert_eq!(
+            files[0].hashes.first().map(ToString::to_string).as_deref(),
+            Some("sha256:5b3a9f8e0d9fa2d4e6a2e4b7a3c1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3")
+        );
+        assert_eq!(
+            files[0].url,
+            FileLocation::AbsoluteUrl(UrlString::from(DisplaySafeUrl::parse(
+                "https://ghcr.io/v2/org/wheels/blobs/sha256:5b3a9f8e0d9fa2d4e6a2e4b7a3c1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3"
+            ).unwrap()))
+        );
+    }
+
+    #[test]
+    fn parse_challenge() {
+        assert_eq!(
+            BearerChallenge::parse(
+                r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:org/wheels:pull,push""#
+            ),
+            Some(BearerChallenge {
+                realm: "https://ghcr.io/token".to_string(),
+                service: Some("ghcr.io".to_string()),
+                scope: Some("repository:org/wheels:pull,push".to_string()),
+            })
+        );
+        assert_eq!(
+            BearerChallenge::parse(
+                r#"bearer realm="https://auth.docker.io/token", service=registry.docker.io"#
+            ),
+            Some(BearerChallenge {
+                realm: "https://auth.docker.io/token".to_string(),
+                service: Some("registry.docker.io".to_string()),
+                scope: None,
+            })
+        );
+        assert_eq!(BearerChallenge::parse(r#"Basic realm="registry""#), None);
+        assert_eq!(BearerChallenge::parse("Bearer service=\"ghcr.io\""), None);
+    }
+
+    #[test]
+    fn endpoint() {
+        let reference =
+            OciReference::from_url(&Url::parse("oci://ghcr.io/org/wheels:v1").unwrap()).unwrap();
+        for (url, expected) in [
+            ("https://ghcr.io/v2/org/wheels/manifests/v1", true),
+            ("https://ghcr.io/v2/org/wheels/blobs/sha256:abc", true),
+            ("https://ghcr.io/v2/org/other/blobs/sha256:abc", false),
+            ("http://ghcr.io/v2/org/wheels/blobs/sha256:abc", false),
+            ("https://ghcr.io:8443/v2/org/wheels/blobs/sha256:abc", false),
+            ("https://example.com/v2/org/wheels/blobs/sha256:abc", false),
+            ("https://ghcr.io/simple/wheels/", false),
+        ] {
+            assert_eq!(
+                reference.is_endpoint(&Url::parse(url).unwrap()),
+                expected,
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn trusted_realm() {
+        let ghcr = Url::parse("https://ghcr.io").unwrap();
+        let docker = Url::parse("https://registry-1.docker.io").unwrap();
+        let local = Url::parse("http://localhost:5000").unwrap();
+        for (realm, registry, expected) in [
+            ("https://ghcr.io/token", &ghcr, true),
+            ("http://ghcr.io/token", &ghcr, false),
+            ("https://attacker.example.com/token", &ghcr, false),
+            ("https://ghcr.io.example.com/token", &ghcr, false),
+            ("https://auth.docker.io/token", &docker, true),
+            ("https://auth.docker.io/token", &ghcr, false),
+            ("http://localhost:5001/token", &local, true),
+            ("http://127.0.0.1:5001/token", &local, false),
+        ] {
+            assert_eq!(
+                is_trusted_realm(&Url::parse(realm).unwrap(), registry),
+                expected,
+                "{realm}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_registry_key() {
+        assert_eq!(
+            registry_key(&Url::parse("https://ghcr.io/v2/org/wheels/manifests/v1").unwrap()),
+            Some(("ghcr.io".to_string(), "org/wheels".to_string()))
+        );
+        assert_eq!(
+            registry_key(&Url::parse("http://localhost:5000/v2/wheels/blobs/sha256:abc").unwrap()),
+            Some(("localhost:5000".to_string(), "wheels".to_string()))
+        );
+        assert_eq!(
+            registry_key(&Url::parse("https://pypi.org/simple/wheels/").unwrap()),
+            None
+        );
+    }
+}
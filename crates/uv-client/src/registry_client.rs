@@ -19,33 +19,41 @@ use uv_auth::{CredentialsCache, Indexes, PyxTokenStore};
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_configuration::IndexStrategy;
 use uv_configuration::KeyringProviderType;
+use uv_configuration::ProxyUrl;
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, File, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
-    IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, Name,
+    BuiltDist, File, IndexApi, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
+    IndexRole, IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexTrackingPolicy, IndexUrl,
+    IndexUrls, Name,
 };
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_pypi_types::ProjectStatus;
+use uv_pypi_types::{ArchivedRepositoryTracking, ProjectStatus, RepositoryTracking};
 use uv_pypi_types::{
     PypiSimpleDetail, PypiSimpleIndex, PyxSimpleDetail, PyxSimpleIndex, ResolutionMetadata,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
+use uv_warnings::warn_user_once;
 
-use crate::base_client::{BaseClientBuilder, ExtraMiddleware, RedirectPolicy};
+use crate::audit::with_audit_package;
+use crate::base_client::{
+    BaseClientBuilder, ExtraMiddleware, IndexClientConfig, RedirectPolicy,
+    is_transient_network_error,
+};
 use crate::cached_client::CacheControl;
 use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleDetailHTML;
-use crate::remote_metadata::wheel_metadata_from_remote_zip;
+use crate::oci::OciReference;
+use crate::remote_metadata::{CENTRAL_DIRECTORY_SIZE, wheel_metadata_from_remote_zip};
 use crate::rkyvutil::OwnedArchive;
 use crate::{
     BaseClient, CachedClient, Error, ErrorKind, FlatIndexClient, FlatIndexEntries,
-    RedirectClientWithMiddleware,
+    RedirectClientWithMiddleware, check_index_allowed,
 };
 
 /// A builder for an [`RegistryClient`].
@@ -171,14 +179,75 @@ impl<'a> RegistryClientBuilder<'a> {
         }
     }
 
+    /// Register the hosts of the known indexes, such that they're redacted wherever they appear
+    /// in redacted output (e.g., as a bare hostname in a log message).
+    fn register_private_hosts(&self) {
+        if !uv_redacted::is_redaction_enabled() {
+            return;
+        }
+        for index in self.index_locations.known_indexes() {
+            if let Some(host) = index.raw_url().host_str() {
+                uv_redacted::register_private_host(host);
+            }
+        }
+    }
+
+    /// Collect the client configuration for any indexes with client or CA certificates, a
+    /// dedicated proxy, or a dedicated retry policy.
+    fn index_configs(&self) -> Vec<IndexClientConfig> {
+        self.index_locations
+            .known_indexes()
+            .filter(|index| {
+                index.client_cert.is_some()
+                    || index.ca_cert.is_some()
+                    || index.proxy.is_some()
+                    || index.retry.is_some()
+            })
+            .map(|index| IndexClientConfig {
+                url: index.root_url().unwrap_or_else(|| index.raw_url().clone()),
+                client_cert: index.client_cert.clone(),
+                ca_cert: index.ca_cert.clone(),
+                proxy: index.proxy.as_ref().and_then(|proxy| {
+                    match ProxyUrl::try_from(Url::from(proxy.clone())) {
+                        Ok(proxy) => Some(proxy),
+                        Err(err) => {
+                            warn_user_once!(
+                                "Ignoring invalid proxy for `{}`: {err}",
+                                index.raw_url()
+                            );
+                            None
+                        }
+                    }
+                }),
+                retry: index.retry.clone(),
+            })
+            .collect()
+    }
+
+    /// Collect the artifacts of any `oci://` flat indexes.
+    fn oci_references(&self) -> Vec<OciReference> {
+        self.index_locations
+            .flat_indexes()
+            .filter_map(|index| match &index.url {
+                IndexUrl::Url(url) if url.scheme() == "oci" => OciReference::from_url(url),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn build(mut self) -> RegistryClient {
         self.cache_index_credentials();
+        self.register_private_hosts();
         let index_urls = self.index_locations.index_urls();
+        let index_configs = self.index_configs();
+        let oci_references = self.oci_references();
 
         // Build a base client
         let builder = self
             .base_client_builder
             .indexes(Indexes::from(&self.index_locations))
+            .index_configs(index_configs)
+            .oci_references(oci_references)
             .redirect(RedirectPolicy::RetriggerMiddleware);
 
         let client = builder.build();
@@ -205,12 +274,15 @@ impl<'a> RegistryClientBuilder<'a> {
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(mut self, existing: &BaseClient) -> RegistryClient {
         self.cache_index_credentials();
+        self.register_private_hosts();
         let index_urls = self.index_locations.index_urls();
+        let oci_references = self.oci_references();
 
         // Wrap in any relevant middleware and handle connectivity.
         let client = self
             .base_client_builder
             .indexes(Indexes::from(&self.index_locations))
+            .oci_references(oci_references)
             .wrap_existing(existing);
 
         let timeout = client.timeout();
@@ -301,16 +373,31 @@ impl RegistryClient {
         &self,
         package_name: &PackageName,
     ) -> impl Iterator<Item = IndexMetadataRef<'_>> {
-        self.torch_backend
-            .as_ref()
-            .and_then(|torch_backend| {
-                torch_backend
-                    .applies_to(package_name)
-                    .then(|| torch_backend.index_urls())
-                    .map(|indexes| indexes.map(IndexMetadataRef::from))
-            })
-            .map(Either::Left)
-            .unwrap_or_else(|| Either::Right(self.index_urls.indexes().map(IndexMetadataRef::from)))
+        // If the package is pinned to an index, only consult that index.
+        if let Some(index) = self.index_urls.pinned_index(package_name) {
+            return Either::Left(std::iter::once(IndexMetadataRef::from(index)));
+        }
+        Either::Right(
+            self.torch_backend
+                .as_ref()
+                .and_then(|torch_backend| {
+                    torch_backend
+                        .applies_to(package_name)
+                        .then(|| torch_backend.index_urls())
+                        .map(|indexes| indexes.map(IndexMetadataRef::from))
+                })
+                .map(Either::Left)
+                .unwrap_or_else(|| {
+                    Either::Right(self.index_urls.indexes().map(IndexMetadataRef::from))
+                }),
+        )
+    }
+
+    /// Returns `true` if the given [`PackageName`] is pinned to an index via a `packages` pattern.
+    ///
+    /// Pinned packages are never resolved from other indexes, including `--find-links` locations.
+    pub fn is_pinned(&self, package_name: &PackageName) -> bool {
+        self.index_urls.pinned_index(package_name).is_some()
     }
 
     /// Return the appropriate [`IndexStrategy`] for the given [`PackageName`].
@@ -346,6 +433,9 @@ impl RegistryClient {
             return Err(ErrorKind::NoIndex(package_name.to_string()).into());
         }
 
+        // Packages that are pinned to an index can't fail over to other indexes.
+        let pinned = index.is_some() || self.is_pinned(package_name);
+
         let indexes = if let Some(index) = index {
             Either::Left(std::iter::once(index))
         } else {
@@ -358,20 +448,30 @@ impl RegistryClient {
             // If we're searching for the first index that contains the package, fetch serially.
             IndexStrategy::FirstIndex => {
                 for index in indexes {
+                    if self.is_unhealthy(index.url, pinned, capabilities) {
+                        continue;
+                    }
                     let _permit = download_concurrency.acquire().await;
                     match index.format {
                         IndexFormat::Simple => {
                             let status_code_strategy =
                                 self.index_urls.status_code_strategy_for(index.url);
-                            match self
+                            let outcome = match self
                                 .simple_detail_single_index(
                                     package_name,
                                     index.url,
                                     capabilities,
                                     &status_code_strategy,
                                 )
-                                .await?
+                                .await
                             {
+                                Ok(outcome) => outcome,
+                                Err(err) => {
+                                    self.fail_over(index.url, pinned, err, capabilities)?;
+                                    continue;
+                                }
+                            };
+                            match outcome {
                                 SimpleMetadataSearchOutcome::Found(metadata) => {
                                     results.push((index.url, MetadataFormat::Simple(metadata)));
                                     break;
@@ -389,7 +489,14 @@ impl RegistryClient {
                             }
                         }
                         IndexFormat::Flat => {
-                            let entries = self.flat_single_index(package_name, index.url).await?;
+                            let entries =
+                                match self.flat_single_index(package_name, index.url).await {
+                                    Ok(entries) => entries,
+                                    Err(err) => {
+                                        self.fail_over(index.url, pinned, err, capabilities)?;
+                                        continue;
+                                    }
+                                };
                             if !entries.is_empty() {
                                 results.push((index.url, MetadataFormat::Flat(entries)));
                                 break;
@@ -399,48 +506,39 @@ impl RegistryClient {
                 }
             }
 
-            // Otherwise, fetch concurrently.
+            // Otherwise, fetch concurrently. Fallback indexes are only queried if the package
+            // isn't available on any primary index.
             IndexStrategy::UnsafeBestMatch | IndexStrategy::UnsafeFirstMatch => {
-                results = futures::stream::iter(indexes)
-                    .map(async |index| {
-                        let _permit = download_concurrency.acquire().await;
-                        match index.format {
-                            IndexFormat::Simple => {
-                                // For unsafe matches, ignore authentication failures.
-                                let status_code_strategy =
-                                    IndexStatusCodeStrategy::ignore_authentication_error_codes();
-                                let metadata = match self
-                                    .simple_detail_single_index(
-                                        package_name,
-                                        index.url,
-                                        capabilities,
-                                        &status_code_strategy,
-                                    )
-                                    .await?
-                                {
-                                    SimpleMetadataSearchOutcome::Found(metadata) => Some(metadata),
-                                    _ => None,
-                                };
-                                Ok((index.url, metadata.map(MetadataFormat::Simple)))
-                            }
-                            IndexFormat::Flat => {
-                                let entries =
-                                    self.flat_single_index(package_name, index.url).await?;
-                                Ok((index.url, Some(MetadataFormat::Flat(entries))))
-                            }
-                        }
-                    })
-                    .buffered(8)
-                    .filter_map(async |result: Result<_, Error>| match result {
-                        Ok((index, Some(metadata))) => Some(Ok((index, metadata))),
-                        Ok((_, None)) => None,
-                        Err(err) => Some(Err(err)),
-                    })
-                    .try_collect::<Vec<_>>()
-                    .await?;
+                let (primary, fallback): (Vec<_>, Vec<_>) = indexes
+                    .filter(|index| !self.is_unhealthy(index.url, pinned, capabilities))
+                    .partition(|index| self.index_urls.role_for(index.url) == IndexRole::Primary);
+                for indexes in [primary, fallback] {
+                    results = self
+                        .simple_detail_concurrent(
+                            package_name,
+                            indexes,
+                            pinned,
+                            capabilities,
+                            download_concurrency,
+                        )
+                        .await?;
+                    if !results.is_empty() {
+                        break;
+                    }
+                }
             }
         }
 
+        // Packages that are managed by the PyTorch backend are expected to span its indexes.
+        if results.len() > 1
+            && !self
+                .torch_backend
+                .as_ref()
+                .is_some_and(|torch_backend| torch_backend.applies_to(package_name))
+        {
+            self.verify_tracking(package_name, &results)?;
+        }
+
         if results.is_empty() {
             return match self.connectivity {
                 Connectivity::Online => {
@@ -453,6 +551,183 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Enforce the [`IndexTrackingPolicy`] for a package that was found on multiple indexes.
+    ///
+    /// Per PEP 708, files should only be merged across indexes if each pair of indexes is linked,
+    /// i.e., one index tracks the other (as a mirror), or both indexes list each other as
+    /// alternate locations. Flat indexes don't provide tracking metadata, and are exempt.
+    fn verify_tracking(
+        &self,
+        package_name: &PackageName,
+        results: &[(&IndexUrl, MetadataFormat)],
+    ) -> Result<(), Error> {
+        let policy = self.index_urls.tracking_policy();
+        if policy == IndexTrackingPolicy::Ignore {
+            return Ok(());
+        }
+
+        let projects = results
+            .iter()
+            .filter_map(|(index, metadata)| match metadata {
+                MetadataFormat::Simple(metadata) => {
+                    TrackedProject::new(index, package_name, &metadata.tracking)
+                }
+                MetadataFormat::Flat(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut untracked: Vec<&IndexUrl> = Vec::new();
+        for (i, a) in projects.iter().enumerate() {
+            for b in &projects[i + 1..] {
+                if a.is_linked(b) {
+                    continue;
+                }
+                for index in [a.index, b.index] {
+                    if !untracked.contains(&index) {
+                        untracked.push(index);
+                    }
+                }
+            }
+        }
+        if untracked.is_empty() {
+            return Ok(());
+        }
+
+        let indexes = untracked
+            .iter()
+            .map(|index| format!("`{}`", index.without_credentials()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match policy {
+            IndexTrackingPolicy::Warn => {
+                warn_user_once!(
+                    "`{package_name}` was found on multiple indexes that don't declare each other as mirrors or alternate locations ({indexes}); set `index-tracking-policy = \"deny\"` to reject it"
+                );
+                Ok(())
+            }
+            IndexTrackingPolicy::Deny => {
+                Err(ErrorKind::UntrackedIndexes(package_name.clone(), indexes).into())
+            }
+            IndexTrackingPolicy::Ignore => Ok(()),
+        }
+    }
+
+    /// Fetch package metadata from the given indexes concurrently, returning the metadata from
+    /// every index that contains the package.
+    async fn simple_detail_concurrent<'index>(
+        &'index self,
+        package_name: &PackageName,
+        indexes: Vec<IndexMetadataRef<'index>>,
+        pinned: bool,
+        capabilities: &IndexCapabilities,
+        download_concurrency: &Semaphore,
+    ) -> Result<Vec<(&'index IndexUrl, MetadataFormat)>, Error> {
+        futures::stream::iter(indexes)
+            .map(async |index| {
+                let _permit = download_concurrency.acquire().await;
+                let result = match index.format {
+                    IndexFormat::Simple => {
+                        // For unsafe matches, ignore authentication failures.
+                        let status_code_strategy =
+                            IndexStatusCodeStrategy::ignore_authentication_error_codes();
+                        self.simple_detail_single_index(
+                            package_name,
+                            index.url,
+                            capabilities,
+                            &status_code_strategy,
+                        )
+                        .await
+                        .map(|outcome| match outcome {
+                            SimpleMetadataSearchOutcome::Found(metadata) => {
+                                Some(MetadataFormat::Simple(metadata))
+                            }
+                            _ => None,
+                        })
+                    }
+                    IndexFormat::Flat => self
+                        .flat_single_index(package_name, index.url)
+                        .await
+                        .map(|entries| Some(MetadataFormat::Flat(entries))),
+                };
+                match result {
+                    Ok(metadata) => Ok((index.url, metadata)),
+                    Err(err) => {
+                        self.fail_over(index.url, pinned, err, capabilities)?;
+                        Ok((index.url, None))
+                    }
+                }
+            })
+            .buffered(8)
+            .filter_map(async |result: Result<_, Error>| match result {
+                Ok((index, Some(metadata))) => Some(Ok((index, metadata))),
+                Ok((_, None)) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    }
+
+    /// Returns `true` if the index has exhausted its retry budget, and should be skipped in favor
+    /// of a fallback index.
+    fn is_unhealthy(
+        &self,
+        index: &IndexUrl,
+        pinned: bool,
+        capabilities: &IndexCapabilities,
+    ) -> bool {
+        if pinned {
+            return false;
+        }
+        // Only look up the retry budget once the index has failed.
+        let failures = capabilities.failures(index);
+        failures > 0
+            && self
+                .index_urls
+                .retry_budget_for(index)
+                .is_some_and(|retry_budget| failures >= retry_budget)
+    }
+
+    /// Handle a failed request to an index.
+    ///
+    /// If the failure is transient (e.g., a connection failure or server error), the index opted in
+    /// to failing over by setting a retry budget, and a fallback index is available, the failure is counted against the index's retry budget, and the search
+    /// should continue with the next index. Otherwise (or if the package is pinned to the index),
+    /// the error is returned.
+    fn fail_over(
+        &self,
+        index: &IndexUrl,
+        pinned: bool,
+        err: Error,
+        capabilities: &IndexCapabilities,
+    ) -> Result<(), Error> {
+        if pinned {
+            return Err(err);
+        }
+        let Some(retry_budget) = self.index_urls.retry_budget_for(index) else {
+            return Err(err);
+        };
+        if !is_transient_network_error(&err) {
+            return Err(err);
+        }
+        let failures = capabilities.record_failure(index.clone());
+        warn_user_once!(
+            "Failed to query index `{}`; falling back to other indexes",
+            index.without_credentials()
+        );
+        if failures >= retry_budget {
+            debug!(
+                "Index `{}` exhausted its retry budget after {failures} failed requests; skipping for the remainder of the invocation",
+                index.without_credentials()
+            );
+        } else {
+            debug!(
+                "Request to index `{}` failed; trying the next index: {err}",
+                index.without_credentials()
+            );
+        }
+        Ok(())
+    }
+
     /// Fetch the [`FlatIndexEntry`] entries for a given package from a single `--find-links` index.
     async fn flat_single_index(
         &self,
@@ -504,6 +779,8 @@ impl RegistryClient {
         capabilities: &IndexCapabilities,
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
+        check_index_allowed(index)?;
+
         // Format the URL for PyPI.
         let mut url = index.url().clone();
         url.path_segments_mut()
@@ -546,8 +823,15 @@ impl RegistryClient {
         let result = if matches!(index, IndexUrl::Path(_)) {
             self.fetch_local_simple_detail(package_name, &url).await
         } else {
-            self.fetch_remote_simple_detail(package_name, &url, index, &cache_entry, cache_control)
-                .await
+            self.fetch_remote_simple_detail(
+                package_name,
+                &url,
+                index,
+                &cache_entry,
+                cache_control,
+                capabilities,
+            )
+            .await
         };
 
         match result {
@@ -582,6 +866,40 @@ impl RegistryClient {
         }
     }
 
+    /// Return the `Accept` header to send in Simple API requests to the given index.
+    ///
+    /// If the [`IndexCapabilities`] indicate that the index only serves HTML, HTML is requested
+    /// directly, rather than negotiating the representation.
+    fn simple_api_accept(
+        &self,
+        index: &IndexUrl,
+        api: IndexApi,
+        capabilities: Option<&IndexCapabilities>,
+    ) -> &'static str {
+        match api {
+            IndexApi::Json => MediaType::json(),
+            IndexApi::Html => MediaType::html(),
+            IndexApi::Auto => {
+                // In theory, we should be able to pass `MediaType::all()` to all registries, and as
+                // unsupported media types should be ignored by the server. For now, we implement
+                // this defensively to avoid issues with misconfigured servers.
+                if self
+                    .pyx_token_store
+                    .as_ref()
+                    .is_some_and(|token_store| token_store.is_known_url(index.url()))
+                {
+                    MediaType::all()
+                } else if capabilities
+                    .is_some_and(|capabilities| !capabilities.supports_json(index))
+                {
+                    MediaType::html()
+                } else {
+                    MediaType::pypi()
+                }
+            }
+        }
+    }
+
     /// Fetch the [`SimpleDetailMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
     async fn fetch_remote_simple_detail(
         &self,
@@ -590,19 +908,10 @@ impl RegistryClient {
         index: &IndexUrl,
         cache_entry: &CacheEntry,
         cache_control: CacheControl<'_>,
+        capabilities: &IndexCapabilities,
     ) -> Result<OwnedArchive<SimpleDetailMetadata>, Error> {
-        // In theory, we should be able to pass `MediaType::all()` to all registries, and as
-        // unsupported media types should be ignored by the server. For now, we implement this
-        // defensively to avoid issues with misconfigured servers.
-        let accept = if self
-            .pyx_token_store
-            .as_ref()
-            .is_some_and(|token_store| token_store.is_known_url(index.url()))
-        {
-            MediaType::all()
-        } else {
-            MediaType::pypi()
-        };
+        let api = self.index_urls.api_for(index);
+        let accept = self.simple_api_accept(index, api, Some(capabilities));
         let simple_request = self
             .uncached_client(url)
             .get(Url::from(url.clone()))
@@ -677,10 +986,20 @@ impl RegistryClient {
                             data.files,
                             package_name,
                             data.project_status,
+                            RepositoryTracking {
+                                tracks: data.meta.tracks,
+                                alternate_locations: data.alternate_locations,
+                            },
                             &url,
                         )
                     }
                     MediaType::PypiV1Html | MediaType::TextHtml => {
+                        // If we asked for JSON, but the index responded with HTML, avoid asking for
+                        // JSON in subsequent requests.
+                        if api == IndexApi::Auto && capabilities.supports_json(index) {
+                            debug!("Index `{index}` doesn't serve the JSON API; requesting HTML");
+                            capabilities.set_no_json(index.clone());
+                        }
                         let text = response
                             .text()
                             .await
@@ -688,20 +1007,25 @@ impl RegistryClient {
                         SimpleDetailMetadata::from_html(&text, package_name, &url)?
                     }
                 };
+                if !unarchived.has_hashes() && capabilities.offers_hashes(index) {
+                    debug!("Index `{index}` serves files without hashes");
+                    capabilities.set_no_hashes(index.clone());
+                }
                 OwnedArchive::from_unarchived(&unarchived)
             }
             .boxed_local()
             .instrument(info_span!("parse_simple_api", package = %package_name))
         };
-        let simple = self
-            .cached_client()
-            .get_cacheable_with_retry(
+        let simple = with_audit_package(
+            package_name,
+            self.cached_client().get_cacheable_with_retry(
                 simple_request,
                 cache_entry,
                 cache_control,
                 parse_simple_response,
-            )
-            .await?;
+            ),
+        )
+        .await?;
         Ok(simple)
     }
 
@@ -739,6 +1063,8 @@ impl RegistryClient {
         &self,
         index_url: &IndexUrl,
     ) -> Result<SimpleIndexMetadata, Error> {
+        check_index_allowed(index_url)?;
+
         // Format the URL for PyPI.
         let mut url = index_url.url().clone();
         url.path_segments_mut()
@@ -763,18 +1089,7 @@ impl RegistryClient {
         url: &DisplaySafeUrl,
         index: &IndexUrl,
     ) -> Result<OwnedArchive<SimpleIndexMetadata>, Error> {
-        // In theory, we should be able to pass `MediaType::all()` to all registries, and as
-        // unsupported media types should be ignored by the server. For now, we implement this
-        // defensively to avoid issues with misconfigured servers.
-        let accept = if self
-            .pyx_token_store
-            .as_ref()
-            .is_some_and(|token_store| token_store.is_known_url(index.url()))
-        {
-            MediaType::all()
-        } else {
-            MediaType::pypi()
-        };
+        let accept = self.simple_api_accept(index, self.index_urls.api_for(index), None);
 
         let cache_entry = self.cache.entry(
             CacheBucket::Simple,
@@ -955,18 +1270,31 @@ impl RegistryClient {
                         })?
                     }
                     WheelLocation::Url(url) => {
-                        self.wheel_metadata_registry(&wheel.index, &wheel.file, &url, capabilities)
-                            .await?
+                        with_audit_package(
+                            built_dist.name(),
+                            self.wheel_metadata_registry(
+                                &wheel.index,
+                                &wheel.file,
+                                &url,
+                                capabilities,
+                            )
+                            .boxed_local(),
+                        )
+                        .await?
                     }
                 }
             }
             BuiltDist::DirectUrl(wheel) => {
-                self.wheel_metadata_no_pep658(
-                    &wheel.filename,
-                    &wheel.url,
-                    None,
-                    WheelCache::Url(&wheel.url),
-                    capabilities,
+                with_audit_package(
+                    built_dist.name(),
+                    self.wheel_metadata_no_pep658(
+                        &wheel.filename,
+                        &wheel.url,
+                        None,
+                        WheelCache::Url(&wheel.url),
+                        capabilities,
+                    )
+                    .boxed_local(),
                 )
                 .await?
             }
@@ -1128,61 +1456,39 @@ impl RegistryClient {
 
         // Attempt to fetch via a range request.
         if index.is_none_or(|index| capabilities.supports_range_requests(index)) {
-            let req = self
-                .uncached_client(url)
-                .head(Url::from(url.clone()))
-                .header(
-                    "accept-encoding",
-                    http::HeaderValue::from_static("identity"),
-                )
-                .build()
-                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-
-            // Copy authorization headers from the HEAD request to subsequent requests
-            let mut headers = HeaderMap::default();
-            if let Some(authorization) = req.headers().get("authorization") {
-                headers.append("authorization", authorization.clone());
-            }
-
-            // This response callback is special, we actually make a number of subsequent requests to
-            // fetch the file from the remote zip.
-            let read_metadata_range_request = |response: Response| {
-                async {
-                    let mut reader = AsyncHttpRangeReader::from_head_response(
-                        self.uncached_client(url).clone(),
-                        response,
-                        Url::from(url.clone()),
-                        headers.clone(),
-                    )
+            // Check for range request support with a `HEAD` request, then fetch the central
+            // directory and the `METADATA` file.
+            let head_supported =
+                index.is_none_or(|index| capabilities.supports_head_requests(index));
+            if head_supported {
+                match self
+                    .wheel_metadata_head_range_request(filename, url, &cache_entry, cache_control)
                     .await
-                    .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
-                    trace!("Getting metadata for {filename} by range request");
-                    let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
-                    ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
-                        Error::from(ErrorKind::MetadataParseError(
-                            filename.clone(),
-                            url.to_string(),
-                            Box::new(err),
-                        ))
-                    })
+                {
+                    Ok(metadata) => return Ok(metadata),
+                    Err(err) if err.is_http_range_requests_unsupported() => {
+                        debug!(
+                            "Failed to check for range requests for {filename}; requesting the end of the wheel"
+                        );
+                    }
+                    Err(err) => return Err(err),
                 }
-                .boxed_local()
-                .instrument(info_span!("read_metadata_range_request", wheel = %filename))
-            };
+            }
 
-            let result = self
-                .cached_client()
-                .get_serde_with_retry(
-                    req,
-                    &cache_entry,
-                    cache_control,
-                    read_metadata_range_request,
-                )
+            // Some servers, like object storage behind pre-signed URLs, reject `HEAD` requests
+            // but serve ranges. Request the end of the wheel with the central directory directly,
+            // which also tells us the size of the wheel.
+            match self
+                .wheel_metadata_tail_range_request(filename, url, &cache_entry, cache_control)
                 .await
-                .map_err(crate::Error::from);
-
-            match result {
-                Ok(metadata) => return Ok(metadata),
+            {
+                Ok(metadata) => {
+                    // Skip the `HEAD` request for the other files on this index.
+                    if let Some(index) = index.filter(|_| head_supported) {
+                        capabilities.set_no_head_requests(index.clone());
+                    }
+                    return Ok(metadata);
+                }
                 Err(err) => {
                     if err.is_http_range_requests_unsupported() {
                         // The range request version failed. Fall back to streaming the file to search
@@ -1235,6 +1541,126 @@ impl RegistryClient {
             .map_err(crate::Error::from)
     }
 
+    /// Read the wheel metadata with range requests, after checking for range request support and
+    /// the size of the wheel with a `HEAD` request.
+    async fn wheel_metadata_head_range_request(
+        &self,
+        filename: &WheelFilename,
+        url: &DisplaySafeUrl,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<ResolutionMetadata, Error> {
+        let req = self
+            .uncached_client(url)
+            .head(Url::from(url.clone()))
+            .header(
+                "accept-encoding",
+                http::HeaderValue::from_static("identity"),
+            )
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+
+        // Copy authorization headers from the HEAD request to subsequent requests
+        let mut headers = HeaderMap::default();
+        if let Some(authorization) = req.headers().get("authorization") {
+            headers.append("authorization", authorization.clone());
+        }
+
+        // This response callback is special, we actually make a number of subsequent requests to
+        // fetch the file from the remote zip.
+        let read_metadata_range_request = |response: Response| {
+            async {
+                let mut reader = AsyncHttpRangeReader::from_head_response(
+                    self.uncached_client(url).clone(),
+                    response,
+                    Url::from(url.clone()),
+                    headers.clone(),
+                )
+                .await
+                .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
+                trace!("Getting metadata for {filename} by range request");
+                let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
+                ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
+                    Error::from(ErrorKind::MetadataParseError(
+                        filename.clone(),
+                        url.to_string(),
+                        Box::new(err),
+                    ))
+                })
+            }
+            .boxed_local()
+            .instrument(info_span!("read_metadata_range_request", wheel = %filename))
+        };
+
+        self.cached_client()
+            .get_serde_with_retry(req, cache_entry, cache_control, read_metadata_range_request)
+            .await
+            .map_err(crate::Error::from)
+    }
+
+    /// Read the wheel metadata with range requests, starting with a request for the end of the
+    /// wheel, which contains the central directory of the zip.
+    ///
+    /// Unlike [`Self::wheel_metadata_head_range_request`], this works with servers that don't
+    /// answer `HEAD` requests. As partial responses aren't cacheable, the metadata is not cached
+    /// over HTTP; the lockfile usually avoids refetching it.
+    async fn wheel_metadata_tail_range_request(
+        &self,
+        filename: &WheelFilename,
+        url: &DisplaySafeUrl,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<ResolutionMetadata, Error> {
+        let req = self
+            .uncached_client(url)
+            .get(Url::from(url.clone()))
+            .header(
+                "accept-encoding",
+                http::HeaderValue::from_static("identity"),
+            )
+            .header(
+                http::header::RANGE,
+                format!("bytes=-{CENTRAL_DIRECTORY_SIZE}"),
+            )
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+
+        // Copy authorization headers from the initial request to subsequent requests
+        let mut headers = HeaderMap::default();
+        if let Some(authorization) = req.headers().get("authorization") {
+            headers.append("authorization", authorization.clone());
+        }
+
+        let read_metadata_tail_request = |response: Response| {
+            async {
+                let mut reader = AsyncHttpRangeReader::from_tail_response(
+                    self.uncached_client(url).clone(),
+                    response,
+                    Url::from(url.clone()),
+                    headers.clone(),
+                )
+                .await
+                .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
+                trace!("Getting metadata for {filename} by range request for the end of the wheel");
+                let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
+                ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
+                    Error::from(ErrorKind::MetadataParseError(
+                        filename.clone(),
+                        url.to_string(),
+                        Box::new(err),
+                    ))
+                })
+            }
+            .boxed_local()
+            .instrument(info_span!("read_metadata_tail_request", wheel = %filename))
+        };
+
+        self.cached_client()
+            .get_serde_with_retry(req, cache_entry, cache_control, read_metadata_tail_request)
+            .await
+            .map_err(crate::Error::from)
+    }
+
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
     fn handle_response_errors(&self, err: reqwest::Error) -> std::io::Error {
         if err.is_timeout() {
@@ -1377,6 +1803,63 @@ impl SimpleIndexMetadata {
     }
 }
 
+/// A project page on an index, along with its PEP 708 repository tracking metadata.
+struct TrackedProject<'a> {
+    index: &'a IndexUrl,
+    /// The normalized URL of the project page.
+    url: String,
+    /// The normalized URLs of the project pages that this project tracks.
+    tracks: Vec<String>,
+    /// The normalized URLs of the project pages in the same namespace as this project.
+    alternate_locations: Vec<String>,
+}
+
+impl<'a> TrackedProject<'a> {
+    fn new(
+        index: &'a IndexUrl,
+        package_name: &PackageName,
+        tracking: &ArchivedRepositoryTracking,
+    ) -> Option<Self> {
+        let mut url = Url::from(index.url().clone());
+        url.path_segments_mut()
+            .ok()?
+            .pop_if_empty()
+            .push(package_name.as_ref())
+            .push("");
+        let normalize = |urls: &[rkyv::string::ArchivedString]| {
+            urls.iter()
+                .filter_map(|url| Url::parse(url.as_str()).ok())
+                .map(Self::normalize)
+                .collect::<Vec<_>>()
+        };
+        Some(Self {
+            index,
+            url: Self::normalize(url),
+            tracks: normalize(&tracking.tracks),
+            alternate_locations: normalize(&tracking.alternate_locations),
+        })
+    }
+
+    /// Normalize a project URL for comparison, ignoring credentials, queries, fragments, and
+    /// trailing slashes.
+    fn normalize(mut url: Url) -> String {
+        let _ = url.set_username("");
+        let _ = url.set_password(None);
+        url.set_query(None);
+        url.set_fragment(None);
+        url.as_str().trim_end_matches('/').to_string()
+    }
+
+    /// Returns `true` if the projects may be merged, i.e., one tracks the other, or both list
+    /// each other as alternate locations.
+    fn is_linked(&self, other: &Self) -> bool {
+        self.tracks.contains(&other.url)
+            || other.tracks.contains(&self.url)
+            || (self.alternate_locations.contains(&other.url)
+                && other.alternate_locations.contains(&self.url))
+    }
+}
+
 /// Detail response for a Python package from a Simple API index.
 ///
 /// Abstracts over both HTML and JSON index formats.
@@ -1384,6 +1867,7 @@ impl SimpleIndexMetadata {
 #[rkyv(derive(Debug))]
 pub struct SimpleDetailMetadata {
     project_status: ProjectStatus,
+    tracking: RepositoryTracking,
     versions: Vec<SimpleDetailMetadatum>,
 }
 
@@ -1400,10 +1884,24 @@ impl SimpleDetailMetadata {
         self.versions.iter()
     }
 
+    /// Returns `true` if every file includes at least one hash.
+    fn has_hashes(&self) -> bool {
+        self.versions.iter().all(|metadatum| {
+            metadatum
+                .files
+                .wheels
+                .iter()
+                .map(|wheel| &wheel.file)
+                .chain(metadatum.files.source_dists.iter().map(|sdist| &sdist.file))
+                .all(|file| !file.hashes.is_empty())
+        })
+    }
+
     fn from_pypi_files(
         files: Vec<uv_pypi_types::PypiFile>,
         package_name: &PackageName,
         project_status: ProjectStatus,
+        tracking: RepositoryTracking,
         base: &Url,
     ) -> Self {
         let mut version_map: BTreeMap<Version, VersionFiles> = BTreeMap::default();
@@ -1448,6 +1946,7 @@ impl SimpleDetailMetadata {
                 })
                 .collect(),
             project_status,
+            tracking,
         }
     }
 
@@ -1504,6 +2003,8 @@ impl SimpleDetailMetadata {
                                 requires_python: metadata.requires_python,
                                 provides_extra: metadata.provides_extra,
                                 dynamic: false,
+                                license: None,
+                                project_links: None,
                             });
                     SimpleDetailMetadatum {
                         version,
@@ -1513,6 +2014,7 @@ impl SimpleDetailMetadata {
                 })
                 .collect(),
             project_status,
+            tracking: RepositoryTracking::default(),
         }
     }
 
@@ -1524,6 +2026,7 @@ impl SimpleDetailMetadata {
     ) -> Result<Self, Error> {
         let SimpleDetailHTML {
             project_status,
+            tracking,
             base,
             files,
         } = SimpleDetailHTML::parse(text, url)
@@ -1533,6 +2036,7 @@ impl SimpleDetailMetadata {
             files,
             package_name,
             project_status,
+            tracking,
             base.as_url(),
         ))
     }
@@ -1586,6 +2090,18 @@ impl MediaType {
         "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01"
     }
 
+    /// Return the `Accept` header value for the JSON PyPI media type.
+    #[inline]
+    const fn json() -> &'static str {
+        "application/vnd.pypi.simple.v1+json"
+    }
+
+    /// Return the `Accept` header value for the HTML PyPI media types.
+    #[inline]
+    const fn html() -> &'static str {
+        "application/vnd.pypi.simple.v1+html, text/html;q=0.01"
+    }
+
     /// Return the `Accept` header value for all supported media types.
     #[inline]
     const fn all() -> &'static str {
@@ -1632,7 +2148,7 @@ mod tests {
 
     use url::Url;
     use uv_normalize::PackageName;
-    use uv_pypi_types::PypiSimpleDetail;
+    use uv_pypi_types::{PypiSimpleDetail, RepositoryTracking};
     use uv_redacted::DisplaySafeUrl;
 
     use crate::{
@@ -1640,11 +2156,15 @@ mod tests {
     };
 
     use crate::RegistryClientBuilder;
+    use crate::registry_client::MediaType;
+    use tokio::sync::Semaphore;
     use uv_cache::Cache;
-    use uv_distribution_types::{FileLocation, ToUrlError};
+    use uv_distribution_types::{
+        FileLocation, Index, IndexApi, IndexCapabilities, IndexLocations, IndexUrl, ToUrlError,
+    };
     use uv_small_str::SmallString;
     use wiremock::matchers::{basic_auth, method, path_regex};
-    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
     type Error = Box<dyn std::error::Error>;
 
@@ -1818,6 +2338,108 @@ mod tests {
         Ok(())
     }
 
+    /// Match requests with the given `Accept` header.
+    fn accept(value: &'static str) -> impl Fn(&Request) -> bool {
+        move |request: &Request| {
+            request
+                .headers
+                .get("accept")
+                .is_some_and(|header| header == value)
+        }
+    }
+
+    /// After an index responds with HTML, subsequent requests ask for HTML directly.
+    #[tokio::test]
+    async fn probe_html_only_index() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        let html =
+            r#"<html><body><a href="/files/foo-1.0.tar.gz">foo-1.0.tar.gz</a></body></html>"#;
+        Mock::given(method("GET"))
+            .and(accept(MediaType::html()))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(html, "text/html"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(accept(MediaType::pypi()))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(html, "text/html"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let index = IndexUrl::from_str(&format!("{}/simple", server.uri()))?;
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), Cache::temp()?)
+                .index_locations(IndexLocations::new(
+                    vec![Index::from(index.clone())],
+                    vec![],
+                    false,
+                ))
+                .build();
+        let capabilities = IndexCapabilities::default();
+        let concurrency = Semaphore::new(1);
+
+        for package in ["foo", "bar"] {
+            registry_client
+                .simple_detail(
+                    &PackageName::from_str(package)?,
+                    None,
+                    &capabilities,
+                    &concurrency,
+                )
+                .await?;
+        }
+
+        assert!(!capabilities.supports_json(&index));
+        assert!(!capabilities.offers_hashes(&index));
+
+        Ok(())
+    }
+
+    /// An index with `api = "json"` is only asked for JSON.
+    #[tokio::test]
+    async fn json_api_override() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(accept(MediaType::json()))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"meta": {"api-version": "1.0"}, "files": []}"#,
+                "application/vnd.pypi.simple.v1+json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let index = IndexUrl::from_str(&format!("{}/simple", server.uri()))?;
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), Cache::temp()?)
+                .index_locations(IndexLocations::new(
+                    vec![Index {
+                        api: IndexApi::Json,
+                        ..Index::from(index.clone())
+                    }],
+                    vec![],
+                    false,
+                ))
+                .build();
+        let capabilities = IndexCapabilities::default();
+
+        registry_client
+            .simple_detail(
+                &PackageName::from_str("foo")?,
+                None,
+                &capabilities,
+                &Semaphore::new(1),
+            )
+            .await?;
+
+        assert!(capabilities.supports_json(&index));
+
+        Ok(())
+    }
+
     #[test]
     fn ignore_failing_files() {
         // 1.7.7 has an invalid requires-python field (double comma), 1.7.8 is valid
@@ -1859,6 +2481,7 @@ mod tests {
             data.files,
             &PackageName::from_str("pyflyby").unwrap(),
             data.project_status,
+            RepositoryTracking::default(),
             &base,
         );
         let versions: Vec<String> = simple_metadata
@@ -1911,6 +2534,7 @@ mod tests {
             data.files,
             &PackageName::from_str("pepy").unwrap(),
             data.project_status,
+            RepositoryTracking::default(),
             &base,
         );
 
@@ -1920,6 +2544,10 @@ mod tests {
                 status: Archived,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             versions: [
                 SimpleDetailMetadatum {
                     version: "2.1.1",
@@ -1945,6 +2573,7 @@ mod tests {
                                             },
                                         ],
                                     ),
+                                    provenance: None,
                                     requires_python: Some(
                                         VersionSpecifiers(
                                             [
@@ -2012,6 +2641,10 @@ mod tests {
                 status: Archived,
                 reason: None,
             },
+            tracking: RepositoryTracking {
+                tracks: [],
+                alternate_locations: [],
+            },
             versions: [
                 SimpleDetailMetadatum {
                     version: "2.1.1",
@@ -2037,6 +2670,7 @@ mod tests {
                                             },
                                         ],
                                     ),
+                                    provenance: None,
                                     requires_python: Some(
                                         VersionSpecifiers(
                                             [
@@ -2095,6 +2729,7 @@ mod tests {
             .unwrap();
         let SimpleDetailHTML {
             project_status: _,
+            tracking: _,
             base,
             files,
         } = SimpleDetailHTML::parse(text, &base).unwrap();
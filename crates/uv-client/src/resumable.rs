@@ -0,0 +1,217 @@
+//! Resumption of interrupted downloads via HTTP range requests.
+
+use bytes::Bytes;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use http::header::{
+    ACCEPT_ENCODING, ACCEPT_RANGES, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE, ETAG,
+    IF_RANGE, LAST_MODIFIED, RANGE,
+};
+use http::{HeaderValue, Method, StatusCode};
+use reqwest::{Request, Response};
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::RedirectClientWithMiddleware;
+
+/// The state of a download that can be resumed from its current offset.
+struct Resumable {
+    client: RedirectClientWithMiddleware,
+    url: Url,
+    /// The strong validator (`ETag` or `Last-Modified`) of the original response, sent as
+    /// `If-Range` to ensure that the remainder belongs to the same representation.
+    validator: HeaderValue,
+    /// The total length of the body, in bytes.
+    length: u64,
+    /// The number of bytes received so far.
+    offset: u64,
+    /// The number of consecutive attempts to resume the download that failed without progress.
+    failures: u32,
+    /// The maximum number of consecutive attempts to resume the download.
+    max_failures: u32,
+    stream: BoxStream<'static, reqwest::Result<Bytes>>,
+}
+
+impl Resumable {
+    /// Returns the length and strong validator of the response, if the server supports
+    /// resumption.
+    ///
+    /// Requires a `200 OK` response with `Accept-Ranges: bytes`, a `Content-Length` (which is
+    /// absent if the body was decompressed, in which case offsets wouldn't match), and a strong
+    /// validator.
+    fn validator(response: &Response) -> Option<(u64, HeaderValue)> {
+        if response.status() != StatusCode::OK {
+            return None;
+        }
+        let headers = response.headers();
+        if !headers
+            .get(ACCEPT_RANGES)
+            .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"bytes"))
+        {
+            return None;
+        }
+        if headers.contains_key(CONTENT_ENCODING) {
+            return None;
+        }
+        let length = headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())?;
+        let validator = headers
+            .get(ETAG)
+            .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+            .or_else(|| headers.get(LAST_MODIFIED))
+            .cloned()?;
+        Some((length, validator))
+    }
+
+    /// Request the remainder of the body, starting at the current offset.
+    async fn resume(&mut self) -> Option<BoxStream<'static, reqwest::Result<Bytes>>> {
+        let mut request = Request::new(Method::GET, self.url.clone());
+        let headers = request.headers_mut();
+        headers.insert(
+            RANGE,
+            HeaderValue::from_str(&format!("bytes={}-", self.offset)).ok()?,
+        );
+        headers.insert(IF_RANGE, self.validator.clone());
+        // Ranges apply to the encoded body, so avoid any content encoding.
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("identity"));
+
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .inspect_err(|err| debug!("Failed to resume download of {}: {err}", self.url))
+            .ok()?;
+
+        // If the representation changed, the server responds with the full body instead.
+        if response.status() != StatusCode::PARTIAL_CONTENT {
+            debug!(
+                "Unable to resume download of {}: received status {}",
+                self.url,
+                response.status()
+            );
+            return None;
+        }
+        let content_range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(ContentRange::parse);
+        if content_range
+            != Some(ContentRange {
+                start: self.offset,
+                length: self.length,
+            })
+        {
+            debug!(
+                "Unable to resume download of {}: unexpected `Content-Range`",
+                self.url
+            );
+            return None;
+        }
+
+        Some(response.bytes_stream().boxed())
+    }
+}
+
+/// A parsed `Content-Range` header, like `bytes 100-199/200`.
+#[derive(Debug, PartialEq, Eq)]
+struct ContentRange {
+    start: u64,
+    length: u64,
+}
+
+impl ContentRange {
+    fn parse(header: &str) -> Option<Self> {
+        let range = header.trim().strip_prefix("bytes ")?;
+        let (range, length) = range.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+        let start = start.trim().parse::<u64>().ok()?;
+        let end = end.trim().parse::<u64>().ok()?;
+        let length = length.trim().parse::<u64>().ok()?;
+        // The range must extend to the end of the body.
+        if end.checked_add(1)? != length {
+            return None;
+        }
+        Some(Self { start, length })
+    }
+}
+
+/// Stream the body of a response, resuming the transfer with HTTP range requests if it's
+/// interrupted.
+///
+/// Resumption is attempted up to `max_failures` consecutive times without progress, and only if
+/// the server advertises support for range requests; otherwise, the original error is returned.
+pub(crate) fn resumable_stream(
+    client: RedirectClientWithMiddleware,
+    max_failures: u32,
+    response: Response,
+) -> BoxStream<'static, reqwest::Result<Bytes>> {
+    if max_failures == 0 {
+        return response.bytes_stream().boxed();
+    }
+    let Some((length, validator)) = Resumable::validator(&response) else {
+        return response.bytes_stream().boxed();
+    };
+    let resumable = Resumable {
+        client,
+        url: response.url().clone(),
+        validator,
+        length,
+        offset: 0,
+        failures: 0,
+        max_failures,
+        stream: response.bytes_stream().boxed(),
+    };
+
+    futures::stream::unfold(Some(resumable), async |state| {
+        let mut state = state?;
+        loop {
+            match state.stream.next().await {
+                Some(Ok(chunk)) => {
+                    state.offset += chunk.len() as u64;
+                    if !chunk.is_empty() {
+                        state.failures = 0;
+                    }
+                    return Some((Ok(chunk), Some(state)));
+                }
+                Some(Err(err)) => {
+                    if state.failures >= state.max_failures || state.offset >= state.length {
+                        return Some((Err(err), None));
+                    }
+                    state.failures += 1;
+                    warn!(
+                        "Download of {} interrupted after {} of {} bytes; resuming ({err})",
+                        state.url, state.offset, state.length
+                    );
+                    let Some(stream) = state.resume().await else {
+                        return Some((Err(err), None));
+                    };
+                    state.stream = stream;
+                }
+                None => return None,
+            }
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_content_range() {
+        assert_eq!(
+            ContentRange::parse("bytes 100-199/200"),
+            Some(ContentRange {
+                start: 100,
+                length: 200
+            })
+        );
+        assert_eq!(ContentRange::parse("bytes 100-149/200"), None);
+        assert_eq!(ContentRange::parse("bytes 100-199/*"), None);
+        assert_eq!(ContentRange::parse("bytes */200"), None);
+    }
+}
@@ -0,0 +1,255 @@
+//! Per-host request limits and bandwidth limits for downloads.
+
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::{Extensions, Method};
+use http_body::{Frame, SizeHint};
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::{Instant, Sleep};
+
+/// Limits shared by all clients created from the same builder.
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    /// The per-host request semaphores, if the number of requests per host is limited.
+    hosts: Option<HostLimits>,
+    /// The bandwidth limiter, if the download bandwidth is limited.
+    bandwidth: Option<BandwidthLimiter>,
+}
+
+impl Throttle {
+    pub(crate) fn new(
+        concurrent_downloads_per_host: Option<NonZeroUsize>,
+        bandwidth_limit: Option<NonZeroU64>,
+    ) -> Self {
+        Self {
+            hosts: concurrent_downloads_per_host.map(HostLimits::new),
+            bandwidth: bandwidth_limit.map(BandwidthLimiter::new),
+        }
+    }
+
+    /// Returns `true` if no limits are configured.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.hosts.is_none() && self.bandwidth.is_none()
+    }
+}
+
+#[derive(Debug)]
+struct HostLimits {
+    limit: NonZeroUsize,
+    semaphores: Mutex<FxHashMap<String, Arc<Semaphore>>>,
+}
+
+impl HostLimits {
+    fn new(limit: NonZeroUsize) -> Self {
+        Self {
+            limit,
+            semaphores: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Return the semaphore for the host (and port) of the given request.
+    fn semaphore(&self, request: &Request) -> Option<Arc<Semaphore>> {
+        let url = request.url();
+        let key = match url.port_or_known_default() {
+            Some(port) => format!("{}:{port}", url.host_str()?),
+            None => url.host_str()?.to_string(),
+        };
+        let mut semaphores = self
+            .semaphores
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        Some(
+            semaphores
+                .entry(key)
+                .or_insert_with(|| Arc::new(Semaphore::new(self.limit.get())))
+                .clone(),
+        )
+    }
+}
+
+/// A token bucket shared by all response bodies, which delays each chunk until the configured
+/// number of bytes per second allows for it.
+#[derive(Debug)]
+struct BandwidthLimiter {
+    bytes_per_second: NonZeroU64,
+    /// The instant at which the next chunk may be delivered.
+    next: Mutex<Instant>,
+}
+
+impl BandwidthLimiter {
+    fn new(bytes_per_second: NonZeroU64) -> Self {
+        Self {
+            bytes_per_second,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Reserve the transfer of a chunk of the given size, returning the instant at which it may
+    /// be delivered.
+    fn reserve(&self, bytes: usize, now: Instant) -> Instant {
+        let cost = Duration::from_nanos(
+            u64::try_from(
+                u128::try_from(bytes)
+                    .unwrap_or(u128::MAX)
+                    .saturating_mul(1_000_000_000)
+                    / u128::from(self.bytes_per_second.get()),
+            )
+            .unwrap_or(u64::MAX),
+        );
+        let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+        let start = (*next).max(now);
+        *next = start + cost;
+        start
+    }
+}
+
+/// A middleware that enforces the per-host request limit and the bandwidth limit.
+///
+/// Successful responses hold their host's permit until the body is consumed or dropped, while
+/// other responses (e.g., redirects and authentication challenges) release it immediately, such
+/// that middleware which retries a request while holding the previous response can't deadlock.
+pub(crate) struct ThrottleMiddleware(pub(crate) Arc<Throttle>);
+
+#[async_trait::async_trait]
+impl Middleware for ThrottleMiddleware {
+    async fn handle(
+        &self,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let semaphore = self
+            .0
+            .hosts
+            .as_ref()
+            .and_then(|hosts| hosts.semaphore(&request));
+        let permit = match semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire_owned()
+                    .await
+                    .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?,
+            ),
+            None => None,
+        };
+        let is_head = request.method() == Method::HEAD;
+
+        let mut response = next.run(request, extensions).await?;
+        if is_head || !response.status().is_success() {
+            return Ok(response);
+        }
+        if permit.is_none() && self.0.bandwidth.is_none() {
+            return Ok(response);
+        }
+
+        // Rebuild the response around a body that holds the permit and limits the bandwidth.
+        let mut builder = http::Response::builder()
+            .status(response.status())
+            .version(response.version())
+            .url(response.url().clone());
+        if let Some(headers) = builder.headers_mut() {
+            *headers = std::mem::take(response.headers_mut());
+        }
+        if let Some(builder_extensions) = builder.extensions_mut() {
+            builder_extensions.extend(std::mem::take(response.extensions_mut()));
+        }
+        let body = ThrottledBody {
+            inner: http::Response::<reqwest::Body>::from(response).into_body(),
+            throttle: self.0.clone(),
+            pending: None,
+            permit,
+        };
+        let response = builder
+            .body(reqwest::Body::wrap(body))
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        Ok(Response::from(response))
+    }
+}
+
+/// A response body that holds a per-host permit and delays chunks to enforce the bandwidth
+/// limit.
+struct ThrottledBody {
+    inner: reqwest::Body,
+    throttle: Arc<Throttle>,
+    /// A chunk that was received, but is waiting for bandwidth to be delivered.
+    pending: Option<(Pin<Box<Sleep>>, Frame<Bytes>)>,
+    /// The per-host permit, released once the body is exhausted or dropped.
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl http_body::Body for ThrottledBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        loop {
+            if let Some((delay, _)) = &mut this.pending {
+                ready!(delay.as_mut().poll(cx));
+                if let Some((_, frame)) = this.pending.take() {
+                    return Poll::Ready(Some(Ok(frame)));
+                }
+            }
+
+            let frame = match ready!(Pin::new(&mut this.inner).poll_frame(cx)) {
+                Some(Ok(frame)) => frame,
+                None => {
+                    this.permit = None;
+                    return Poll::Ready(None);
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+            };
+            if let Some(bandwidth) = &this.throttle.bandwidth
+                && let Some(data) = frame.data_ref()
+            {
+                let now = Instant::now();
+                let start = bandwidth.reserve(data.len(), now);
+                if start > now {
+                    this.pending = Some((Box::pin(tokio::time::sleep_until(start)), frame));
+                    continue;
+                }
+            }
+            return Poll::Ready(Some(Ok(frame)));
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.pending.is_none() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bandwidth_limiter() {
+        let limiter = BandwidthLimiter::new(NonZeroU64::new(1000).unwrap());
+        let now = Instant::now();
+
+        // The first chunk is delivered immediately, and each subsequent chunk is delayed by the
+        // time it takes to transfer the previous chunks.
+        assert_eq!(limiter.reserve(500, now), now);
+        assert_eq!(limiter.reserve(2000, now), now + Duration::from_millis(500));
+        assert_eq!(limiter.reserve(10, now), now + Duration::from_millis(2500));
+
+        // Once the link has been idle, the next chunk is delivered immediately.
+        let later = now + Duration::from_secs(10);
+        assert_eq!(limiter.reserve(10, later), later);
+    }
+}
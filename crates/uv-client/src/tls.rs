@@ -1,6 +1,11 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use uv_fs::Simplified;
+use uv_redacted::DisplaySafeUrl;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -8,6 +13,27 @@ pub(crate) enum CertificateError {
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Reqwest(reqwest::Error),
+    #[error("No PEM-encoded certificates found")]
+    Empty,
+}
+
+/// An error reading the certificates configured for an index.
+#[derive(thiserror::Error, Debug, Clone)]
+pub(crate) enum IndexCertificateError {
+    #[error("Failed to read CA certificate `{}` for `{url}`", path.user_display())]
+    CaCert {
+        path: PathBuf,
+        url: DisplaySafeUrl,
+        #[source]
+        err: Arc<CertificateError>,
+    },
+    #[error("Failed to read client certificate `{}` for `{url}`", path.user_display())]
+    ClientCert {
+        path: PathBuf,
+        url: DisplaySafeUrl,
+        #[source]
+        err: Arc<CertificateError>,
+    },
 }
 
 /// Return the `Identity` from the provided file.
@@ -19,3 +45,14 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the `Certificate`s from the provided PEM bundle.
+pub(crate) fn read_certificates(ca_cert: &OsStr) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ca_cert)?.read_to_end(&mut buf)?;
+    let certificates = Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)?;
+    if certificates.is_empty() {
+        return Err(CertificateError::Empty);
+    }
+    Ok(certificates)
+}
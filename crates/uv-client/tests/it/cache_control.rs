@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use reqwest::Response;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClientBuilder, CacheControl, RegistryClientBuilder};
+use uv_redacted::DisplaySafeUrl;
+
+/// After a `304 Not Modified` response that omits the `ETag`, the validator from the stored
+/// response should be retained and sent in subsequent revalidation requests.
+#[tokio::test]
+async fn revalidation_retains_etag() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/example/"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/simple/example/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .insert_header("Cache-Control", "no-cache")
+                .set_body_string("example"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone()).build();
+    let cache_entry = cache.entry(CacheBucket::Simple, "test", "example.msgpack");
+    let url = DisplaySafeUrl::parse(&format!("{}/simple/example/", server.uri()))?;
+
+    // The first request populates the cache; the others revalidate it.
+    for _ in 0..3 {
+        let req = client
+            .cached_client()
+            .uncached()
+            .for_host(&url)
+            .get(url.as_str())
+            .build()?;
+        let body = client
+            .cached_client()
+            .get_serde(
+                req,
+                &cache_entry,
+                CacheControl::None,
+                async |response: Response| response.text().await,
+            )
+            .await
+            .map_err(|err| anyhow::anyhow!("{err:?}"))?;
+        assert_eq!(body, "example");
+    }
+
+    Ok(())
+}
+
+/// Within the `stale-while-revalidate` window, a stale response should be served from the cache,
+/// and revalidated in the background.
+#[tokio::test]
+async fn stale_while_revalidate() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/example/"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/simple/example/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_string("example"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone()).build();
+    let cache_entry = cache.entry(CacheBucket::Simple, "test", "example.msgpack");
+    let url = DisplaySafeUrl::parse(&format!("{}/simple/example/", server.uri()))?;
+    let cache_control = CacheControl::Override("max-age=0, stale-while-revalidate=600");
+
+    for i in 0..2 {
+        // Wait for the cached response to become stale.
+        if i > 0 {
+            tokio::time::sleep(Duration::from_millis(1100)).await;
+        }
+        let req = client
+            .cached_client()
+            .uncached()
+            .for_host(&url)
+            .get(url.as_str())
+            .build()?;
+        let body = client
+            .cached_client()
+            .get_serde(
+                req,
+                &cache_entry,
+                cache_control,
+                async |response: Response| response.text().await,
+            )
+            .await
+            .map_err(|err| anyhow::anyhow!("{err:?}"))?;
+        assert_eq!(body, "example");
+    }
+
+    // Wait for the background revalidation request.
+    for _ in 0..100 {
+        if server.received_requests().await.unwrap_or_default().len() >= 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let requests = server.received_requests().await.unwrap_or_default();
+    assert_eq!(requests.len(), 2);
+
+    Ok(())
+}
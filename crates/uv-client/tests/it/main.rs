@@ -1,5 +1,9 @@
+mod cache_control;
 mod http_util;
+mod oci;
 mod proxy;
 mod remote_metadata;
+mod resumable;
 mod ssl_certs;
+mod throttle;
 mod user_agent_version;
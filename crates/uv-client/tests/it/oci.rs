@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_distribution_types::{FileLocation, Index, IndexLocations, IndexUrl, UrlString};
+use uv_redacted::DisplaySafeUrl;
+
+/// Resolve an `oci://` artifact as a flat index, authenticating with a registry token.
+///
+/// The token is only ever requested with pull access to the configured repository, even if the
+/// registry advertises a broader scope.
+#[tokio::test]
+async fn oci_flat_index_with_token() -> Result<()> {
+    let server = MockServer::start().await;
+    let address = server.address();
+
+    // Reject anonymous requests with a `Bearer` challenge.
+    Mock::given(method("GET"))
+        .and(path("/v2/org/wheels/manifests/v1"))
+        .and(header("Authorization", "Bearer secret-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{
+                "schemaVersion": 2,
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "layers": [
+                    {
+                        "mediaType": "application/vnd.oci.image.layer.v1.tar",
+                        "digest": "sha256:5b3a9f8e0d9fa2d4e6a2e4b7a3c1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3",
+                        "size": 1024,
+                        "annotations": {
+                            "org.opencontainers.image.title": "example-1.0.0-py3-none-any.whl"
+                        }
+                    }
+                ]
+            }"#,
+            "application/vnd.oci.image.manifest.v1+json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/org/wheels/manifests/v1"))
+        .respond_with(ResponseTemplate::new(401).insert_header(
+            "WWW-Authenticate",
+            format!(
+                r#"Bearer realm="http://{address}/token",service="registry",scope="repository:org/wheels:pull,push repository:org/other:pull""#
+            ),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/token"))
+        .and(query_param("service", "registry"))
+        .and(query_param("scope", "repository:org/wheels:pull"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(r#"{"token": "secret-token"}"#, "application/json"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let index = IndexUrl::from_str(&format!("oci://{address}/org/wheels:v1"))?;
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone())
+        .index_locations(IndexLocations::new(
+            vec![],
+            vec![Index::from_find_links(index.clone())],
+            false,
+        ))
+        .build();
+    let flat_index = FlatIndexClient::new(client.cached_client(), Connectivity::Online, &cache);
+
+    let entries = flat_index.fetch_index(&index).await?;
+
+    assert_eq!(entries.entries.len(), 1);
+    let entry = &entries.entries[0];
+    assert_eq!(entry.filename.to_string(), "example-1.0.0-py3-none-any.whl");
+    assert_eq!(
+        entry.file.url,
+        FileLocation::AbsoluteUrl(UrlString::from(DisplaySafeUrl::parse(&format!(
+            "http://{address}/v2/org/wheels/blobs/sha256:5b3a9f8e0d9fa2d4e6a2e4b7a3c1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3"
+        ))?))
+    );
+
+    Ok(())
+}
+
+/// Don't request a token from a realm on a different host than the registry, or for a registry
+/// that isn't configured as an index.
+#[tokio::test]
+async fn oci_flat_index_untrusted_realm() -> Result<()> {
+    let server = MockServer::start().await;
+    let address = server.address();
+    let port = address.port();
+
+    Mock::given(method("GET"))
+        .and(path("/v2/org/wheels/manifests/v1"))
+        .respond_with(ResponseTemplate::new(401).insert_header(
+            "WWW-Authenticate",
+            format!(r#"Bearer realm="http://localhost:{port}/token",service="registry""#),
+        ))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(r#"{"token": "secret-token"}"#, "application/json"),
+        )
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let index = IndexUrl::from_str(&format!("oci://{address}/org/wheels:v1"))?;
+
+    // The realm is on `localhost`, rather than the registry's host.
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone())
+        .index_locations(IndexLocations::new(
+            vec![],
+            vec![Index::from_find_links(index.clone())],
+            false,
+        ))
+        .build();
+    let flat_index = FlatIndexClient::new(client.cached_client(), Connectivity::Online, &cache);
+    assert!(flat_index.fetch_index(&index).await.is_err());
+
+    // The registry isn't configured as an index.
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone()).build();
+    let flat_index = FlatIndexClient::new(client.cached_client(), Connectivity::Online, &cache);
+    assert!(flat_index.fetch_index(&index).await.is_err());
+
+    Ok(())
+}
@@ -1,11 +1,16 @@
 //! An integration test for proxy support in `uv-client`.
 
+use std::str::FromStr;
+
 use anyhow::Result;
 use wiremock::matchers::{any, method};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
-use uv_client::BaseClientBuilder;
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::ProxyUrl;
+use uv_distribution_types::{Index, IndexLocations, IndexUrl};
+use uv_redacted::DisplaySafeUrl;
 
 #[tokio::test]
 async fn http_proxy() -> Result<()> {
@@ -98,3 +103,82 @@ async fn no_proxy() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn index_proxy() -> Result<()> {
+    // Start a mock server to act as the index.
+    let index_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&index_server)
+        .await;
+
+    // Start a mock server to act as any other host.
+    let other_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&other_server)
+        .await;
+
+    // Start a mock server to act as the global proxy.
+    let global_proxy = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&global_proxy)
+        .await;
+
+    // Start a mock server to act as the index's proxy.
+    let index_proxy = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&index_proxy)
+        .await;
+
+    // Create a client with a global proxy, and a dedicated proxy for the index.
+    let mut index = Index::from_index_url(IndexUrl::from_str(&format!(
+        "{}/simple",
+        index_server.uri()
+    ))?);
+    index.proxy = Some(DisplaySafeUrl::parse(&index_proxy.uri())?);
+    let base_client_builder = BaseClientBuilder::new(
+        uv_client::Connectivity::Online,
+        false,
+        vec![],
+        uv_preview::Preview::default(),
+        std::time::Duration::from_secs(30),
+        3,
+    )
+    .http_proxy(Some(global_proxy.uri().parse::<ProxyUrl>()?));
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(base_client_builder, cache)
+        .index_locations(IndexLocations::new(vec![index], vec![], false))
+        .build();
+
+    // Make a request to a file on the index's host, and to another host.
+    for url in [
+        format!("{}/files/example.whl", index_server.uri()),
+        other_server.uri(),
+    ] {
+        let response = client
+            .cached_client()
+            .uncached()
+            .for_host(&DisplaySafeUrl::parse(&url)?)
+            .get(url)
+            .send()
+            .await?;
+        assert_eq!(response.status(), 200);
+    }
+
+    // Assert that each request used the appropriate proxy.
+    let received_requests = index_proxy.received_requests().await.unwrap();
+    assert_eq!(received_requests.len(), 1);
+    assert_eq!(received_requests[0].url.path(), "/files/example.whl");
+    let received_requests = global_proxy.received_requests().await.unwrap();
+    assert_eq!(received_requests.len(), 1);
+
+    // Assert that neither request reached its target directly.
+    assert!(index_server.received_requests().await.unwrap().is_empty());
+    assert!(other_server.received_requests().await.unwrap().is_empty());
+
+    Ok(())
+}
@@ -1,6 +1,11 @@
+use std::path::Path;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::Result;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Respond, ResponseTemplate};
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
@@ -31,3 +36,80 @@ async fn remote_metadata_with_and_without_cache() -> Result<()> {
 
     Ok(())
 }
+
+/// Serve a file with range requests, counting the requests for the full file.
+struct RangeResponder {
+    body: Vec<u8>,
+    full_requests: Arc<AtomicUsize>,
+}
+
+impl Respond for RangeResponder {
+    fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+        let len = self.body.len();
+        let Some(range) = request
+            .headers
+            .get("range")
+            .and_then(|range| range.to_str().ok())
+            .and_then(|range| range.strip_prefix("bytes="))
+        else {
+            self.full_requests.fetch_add(1, Ordering::SeqCst);
+            return ResponseTemplate::new(200).set_body_bytes(self.body.clone());
+        };
+        let (start, end) = if let Some(suffix) = range.strip_prefix('-') {
+            (len.saturating_sub(suffix.parse().unwrap()), len - 1)
+        } else {
+            let (start, end) = range.split_once('-').unwrap();
+            (
+                start.parse().unwrap(),
+                end.parse::<usize>().map_or(len - 1, |end| end.min(len - 1)),
+            )
+        };
+        ResponseTemplate::new(206)
+            .insert_header("Content-Range", format!("bytes {start}-{end}/{len}"))
+            .set_body_bytes(self.body[start..=end].to_vec())
+    }
+}
+
+/// Read the metadata with range requests from a server that rejects `HEAD` requests, like object
+/// storage behind pre-signed URLs, without downloading the whole wheel.
+#[tokio::test]
+async fn remote_metadata_without_head_requests() -> Result<()> {
+    let wheel = "tqdm-1000.0.0-py3-none-any.whl";
+    let full_requests = Arc::new(AtomicUsize::new(0));
+
+    let server = MockServer::start().await;
+    Mock::given(method("HEAD"))
+        .and(path(format!("/{wheel}")))
+        .respond_with(ResponseTemplate::new(405))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/{wheel}")))
+        .respond_with(RangeResponder {
+            body: fs_err::read(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("../../test/links")
+                    .join(wheel),
+            )?,
+            full_requests: full_requests.clone(),
+        })
+        .mount(&server)
+        .await;
+
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache).build();
+    let url = format!("{}/{wheel}", server.uri());
+    let dist = BuiltDist::DirectUrl(DirectUrlBuiltDist {
+        filename: WheelFilename::from_str(wheel)?,
+        location: Box::new(DisplaySafeUrl::parse(&url)?),
+        url: VerbatimUrl::from_str(&url)?,
+    });
+    let metadata = client
+        .wheel_metadata(&dist, &IndexCapabilities::default())
+        .await?;
+    assert_eq!(metadata.version.to_string(), "1000.0.0");
+    assert_eq!(full_requests.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use futures::TryStreamExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use uv_client::BaseClientBuilder;
+use uv_redacted::DisplaySafeUrl;
+
+const LENGTH: usize = 10_000;
+const INTERRUPTED_AT: usize = 4_000;
+
+fn body() -> Vec<u8> {
+    (0..=u8::MAX).cycle().take(LENGTH).collect()
+}
+
+/// Read the request headers from the stream.
+async fn read_request(stream: &mut tokio::net::TcpStream) -> Result<String> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.ends_with(b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+    Ok(String::from_utf8(request)?.to_ascii_lowercase())
+}
+
+/// Start a server that drops the first connection partway through the body, and serves the
+/// remainder of the body on the second connection, provided that the validator matches `etag`.
+async fn start_flaky_server(etag: &'static str) -> Result<(JoinHandle<Result<()>>, SocketAddr)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let body = body();
+
+    let task = tokio::spawn(async move {
+        // Send the headers and the start of the body, then close the connection.
+        let (mut stream, _) = listener.accept().await?;
+        read_request(&mut stream).await?;
+        stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {LENGTH}\r\nAccept-Ranges: bytes\r\nETag: \"v1\"\r\n\r\n"
+                )
+                .as_bytes(),
+            )
+            .await?;
+        stream.write_all(&body[..INTERRUPTED_AT]).await?;
+        stream.shutdown().await?;
+        drop(stream);
+
+        // Serve the remainder of the body, if requested with the expected range and validator.
+        let (mut stream, _) = listener.accept().await?;
+        let request = read_request(&mut stream).await?;
+        if request.contains(&format!("range: bytes={INTERRUPTED_AT}-\r\n"))
+            && request.contains(&format!("if-range: {etag}\r\n"))
+        {
+            stream
+                .write_all(
+                    format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {INTERRUPTED_AT}-{}/{LENGTH}\r\nETag: {etag}\r\n\r\n",
+                        LENGTH - INTERRUPTED_AT,
+                        LENGTH - 1,
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+            stream.write_all(&body[INTERRUPTED_AT..]).await?;
+        } else {
+            // The validator doesn't match, so send the full body.
+            stream
+                .write_all(
+                    format!("HTTP/1.1 200 OK\r\nContent-Length: {LENGTH}\r\nETag: {etag}\r\n\r\n")
+                        .as_bytes(),
+                )
+                .await?;
+            stream.write_all(&body).await?;
+        }
+        stream.shutdown().await?;
+        Ok(())
+    });
+
+    Ok((task, addr))
+}
+
+/// An interrupted download is resumed from where it left off.
+#[tokio::test]
+async fn resume_interrupted_download() -> Result<()> {
+    let (server_task, addr) = start_flaky_server("\"v1\"").await?;
+
+    let client = BaseClientBuilder::default().build();
+    let url = DisplaySafeUrl::parse(&format!("http://{addr}/files/example.whl"))?;
+    let response = client.for_host(&url).get(url.as_str()).send().await?;
+    let chunks: Vec<_> = client.resumable_stream(response).try_collect().await?;
+
+    assert_eq!(chunks.concat(), body());
+    server_task.await??;
+
+    Ok(())
+}
+
+/// If the file changed on the server, the download fails rather than mixing the two versions.
+#[tokio::test]
+async fn resume_changed_download() -> Result<()> {
+    let (server_task, addr) = start_flaky_server("\"v2\"").await?;
+
+    let client = BaseClientBuilder::default().build();
+    let url = DisplaySafeUrl::parse(&format!("http://{addr}/files/example.whl"))?;
+    let response = client.for_host(&url).get(url.as_str()).send().await?;
+    let result: Result<Vec<_>, _> = client.resumable_stream(response).try_collect().await;
+
+    assert!(result.is_err());
+    server_task.await??;
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ use url::Url;
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_client::RegistryClientBuilder;
+use uv_distribution_types::{Index, IndexLocations, IndexUrl, RetrySettings};
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
@@ -331,3 +332,135 @@ async fn ssl_env_vars() -> Result<()> {
     // Fin.
     Ok(())
 }
+
+/// Configure a client certificate and CA bundle for a single index, without any environment
+/// variables.
+#[tokio::test]
+async fn index_tls_config() -> Result<()> {
+    // Create temporary cert dirs
+    let cert_dir = test_cert_dir();
+    fs_err::create_dir_all(&cert_dir).expect("Failed to create test cert bucket");
+    let cert_dir =
+        tempfile::TempDir::new_in(cert_dir).expect("Failed to create test cert directory");
+
+    // Generate self-signed CA, server, and client certs
+    let (ca_cert, server_cert, client_cert) = generate_self_signed_certs_with_ca()?;
+    let ca_public_pem_path = cert_dir.path().join("ca_public.pem");
+    let client_combined_pem_path = cert_dir.path().join("client_combined.pem");
+    fs_err::write(ca_public_pem_path.as_path(), ca_cert.public.pem())?;
+    fs_err::write(
+        client_combined_pem_path.as_path(),
+        format!(
+            "{}\n{}",
+            client_cert.public.pem(),
+            client_cert.private.serialize_pem()
+        ),
+    )?;
+
+    // ** Configure the index with our CA and client cert
+    // ** Then verify requests to the index and its files successfully establish a connection
+
+    let (server_task, addr) = start_https_mtls_user_agent_server(&ca_cert, &server_cert).await?;
+    let mut index = Index::from_index_url(IndexUrl::from_str(&format!("https://{addr}/simple"))?);
+    index.ca_cert = Some(ca_public_pem_path.clone());
+    index.client_cert = Some(client_combined_pem_path.clone());
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache)
+        .index_locations(IndexLocations::new(vec![index], vec![], false))
+        .build();
+    let url = DisplaySafeUrl::from_str(&format!("https://{addr}/files/example.whl"))?;
+    let res = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(Url::from(url))
+        .send()
+        .await;
+    assert!(res.is_ok());
+    let _ = server_task.await?; // wait for server shutdown
+
+    // ** Configure the index with our CA, but not the client cert
+    // ** Then verify our request fails to establish a connection
+
+    let (server_task, addr) = start_https_mtls_user_agent_server(&ca_cert, &server_cert).await?;
+    let mut index = Index::from_index_url(IndexUrl::from_str(&format!("https://{addr}/simple"))?);
+    index.ca_cert = Some(ca_public_pem_path);
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache)
+        .index_locations(IndexLocations::new(vec![index], vec![], false))
+        .build();
+    let url = DisplaySafeUrl::from_str(&format!("https://{addr}/simple"))?;
+    let res = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(Url::from(url))
+        .send()
+        .await;
+    assert!(res.is_err());
+
+    // Validate the server error
+    let server_res = server_task.await?;
+    let expected_err = if let Err(anyhow_err) = server_res
+        && let Some(io_err) = anyhow_err.downcast_ref::<std::io::Error>()
+        && let Some(wrapped_err) = io_err.get_ref()
+        && let Some(tls_err) = wrapped_err.downcast_ref::<rustls::Error>()
+        && matches!(tls_err, rustls::Error::NoCertificatesPresented)
+    {
+        true
+    } else {
+        false
+    };
+    assert!(expected_err);
+
+    // Fin.
+    Ok(())
+}
+
+/// An invalid CA bundle for an index fails requests to the index, rather than falling back to the
+/// default TLS configuration. Requests to a sibling path on the same host use the matching index.
+#[tokio::test]
+async fn index_tls_config_invalid() -> Result<()> {
+    let cert_dir = tempfile::TempDir::new()?;
+    let ca_cert_path = cert_dir.path().join("missing.pem");
+
+    let mut index = Index::from_index_url(IndexUrl::from_str("https://127.0.0.1:1/simple")?);
+    index.ca_cert = Some(ca_cert_path);
+    let mut other = Index::from_index_url(IndexUrl::from_str("https://127.0.0.1:1/simple-other")?);
+    other.retry = Some(RetrySettings {
+        retries: Some(0),
+        ..RetrySettings::default()
+    });
+    let cache = Cache::temp()?.init().await?;
+    let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache)
+        .index_locations(IndexLocations::new(vec![index, other], vec![], false))
+        .build();
+
+    let url = DisplaySafeUrl::from_str("https://127.0.0.1:1/simple/example/")?;
+    let err = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(Url::from(url))
+        .send()
+        .await
+        .expect_err("request to an index with an invalid CA bundle should fail");
+    assert!(
+        err.to_string()
+            .starts_with("Failed to read CA certificate `"),
+        "{err}"
+    );
+
+    let url = DisplaySafeUrl::from_str("https://127.0.0.1:1/simple-other/example/")?;
+    let err = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(Url::from(url))
+        .send()
+        .await
+        .expect_err("nothing is listening on port 1");
+    assert!(!err.to_string().contains("CA certificate"), "{err}");
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use futures::future::try_join_all;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_client::BaseClientBuilder;
+use uv_configuration::BandwidthLimit;
+use uv_redacted::DisplaySafeUrl;
+
+/// With a per-host limit of one, concurrent requests to the same host are serialized.
+#[tokio::test]
+async fn concurrent_downloads_per_host() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files/example.whl"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("example")
+                .set_delay(Duration::from_millis(300)),
+        )
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = BaseClientBuilder::default()
+        .concurrent_downloads_per_host(NonZeroUsize::new(1))
+        .build();
+    let url = DisplaySafeUrl::parse(&format!("{}/files/example.whl", server.uri()))?;
+
+    let start = Instant::now();
+    let responses = try_join_all((0..3).map(|_| async {
+        let response = client.for_host(&url).get(url.as_str()).send().await?;
+        // The response should retain its URL and length after being wrapped.
+        assert_eq!(response.url().as_str(), url.as_str());
+        assert_eq!(response.content_length(), Some(7));
+        anyhow::Ok(response.text().await?)
+    }))
+    .await?;
+    assert!(start.elapsed() >= Duration::from_millis(900));
+    assert!(responses.iter().all(|body| body == "example"));
+
+    Ok(())
+}
+
+/// The bandwidth limit is shared across requests.
+#[tokio::test]
+async fn bandwidth_limit() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files/example.whl"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0u8; 10_000]))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = BaseClientBuilder::default()
+        .bandwidth_limit(Some(BandwidthLimit::from_str("20KB/s")?))
+        .build();
+    let url = DisplaySafeUrl::parse(&format!("{}/files/example.whl", server.uri()))?;
+
+    // At 20 KB/s, transferring the first 20 KB takes at least one second, so the final chunk
+    // can't arrive any sooner.
+    let start = Instant::now();
+    for _ in 0..3 {
+        let response = client.for_host(&url).get(url.as_str()).send().await?;
+        assert_eq!(response.bytes().await?.len(), 10_000);
+    }
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
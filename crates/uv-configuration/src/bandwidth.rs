@@ -0,0 +1,101 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+use crate::cache_limits::parse_bytes;
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Expected a non-zero bandwidth in bytes per second (e.g., `10MB`, `500KiB`, or `1024`), but found: `{0}`"
+)]
+pub struct BandwidthLimitParseError(String);
+
+/// The maximum download bandwidth, in bytes per second (e.g., `10MB`).
+///
+/// Supports both decimal (`KB`, `MB`, `GB`) and binary (`KiB`, `MiB`, `GiB`) units, optionally
+/// followed by `/s`. A bare number is interpreted as a number of bytes per second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BandwidthLimit(NonZeroU64);
+
+impl BandwidthLimit {
+    /// Return the limit in bytes per second.
+    pub fn bytes_per_second(self) -> NonZeroU64 {
+        self.0
+    }
+}
+
+impl FromStr for BandwidthLimit {
+    type Err = BandwidthLimitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let trimmed = trimmed.strip_suffix("/s").unwrap_or(trimmed);
+        parse_bytes(trimmed)
+            .and_then(NonZeroU64::new)
+            .map(Self)
+            .ok_or_else(|| BandwidthLimitParseError(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for BandwidthLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B/s", self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BandwidthLimit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for BandwidthLimit {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("BandwidthLimit")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^\d+\s*([kKmMgGtT]([iI]?[bB])?|[bB])?(/s)?$",
+            "description": "A bandwidth in bytes per second, e.g. `10MB`, `500KiB/s`, or `1024`."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bandwidth_limit() {
+        assert_eq!(
+            BandwidthLimit::from_str("10MB")
+                .unwrap()
+                .bytes_per_second()
+                .get(),
+            10_000_000
+        );
+        assert_eq!(
+            BandwidthLimit::from_str("500KiB/s")
+                .unwrap()
+                .bytes_per_second()
+                .get(),
+            500 * 1024
+        );
+        assert_eq!(
+            BandwidthLimit::from_str("1024")
+                .unwrap()
+                .bytes_per_second()
+                .get(),
+            1024
+        );
+        assert!(BandwidthLimit::from_str("0").is_err());
+        assert!(BandwidthLimit::from_str("MB/s").is_err());
+        assert!(BandwidthLimit::from_str("10Mbps").is_err());
+    }
+}
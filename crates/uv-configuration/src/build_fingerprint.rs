@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// The inputs to a fingerprint of the build environment, used to scope cached wheels that were
+/// built from source distributions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BuildFingerprint {
+    /// Environment variables whose values affect the build (e.g., `CC` or `CFLAGS`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+    /// Shell commands whose output identifies the build toolchain (e.g., `cc --version`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<String>,
+}
+
+impl BuildFingerprint {
+    /// Returns `true` if the fingerprint has no inputs.
+    pub fn is_empty(&self) -> bool {
+        self.env.is_empty() && self.commands.is_empty()
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheLimitParseError {
+    #[error("Expected a duration (e.g., `30d`, `12h`, or `2w`), but found: `{0}`")]
+    InvalidAge(String),
+    #[error("Expected a size (e.g., `10GB`, `500MiB`, or `1024`), but found: `{0}`")]
+    InvalidSize(String),
+}
+
+/// The maximum age of a cache entry, measured from the last time it was used (e.g., `30d`).
+///
+/// Supports the `s`, `m`, `h`, `d`, and `w` suffixes for seconds, minutes, hours, days, and
+/// weeks, respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheMaxAge(Duration);
+
+impl CacheMaxAge {
+    /// Return the maximum age as a [`Duration`].
+    pub fn as_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for CacheMaxAge {
+    type Err = CacheLimitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || CacheLimitParseError::InvalidAge(s.to_string());
+
+        let trimmed = s.trim();
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        let (value, unit) = trimmed.split_at(split);
+        let value = value.parse::<u64>().map_err(|_| err())?;
+        let seconds = match unit.trim() {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" | "" => 60 * 60 * 24,
+            "w" => 60 * 60 * 24 * 7,
+            _ => return Err(err()),
+        };
+        let seconds = value.checked_mul(seconds).ok_or_else(err)?;
+        Ok(Self(Duration::from_secs(seconds)))
+    }
+}
+
+impl std::fmt::Display for CacheMaxAge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.0.as_secs();
+        for (unit, size) in [
+            ("w", 60 * 60 * 24 * 7),
+            ("d", 60 * 60 * 24),
+            ("h", 60 * 60),
+            ("m", 60),
+        ] {
+            if seconds > 0 && seconds.is_multiple_of(size) {
+                return write!(f, "{}{unit}", seconds / size);
+            }
+        }
+        write!(f, "{seconds}s")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheMaxAge {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheMaxAge {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CacheMaxAge")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^\d+\s*[smhdw]?$",
+            "description": "A duration, e.g. `30d`, `12h`, or `2w`."
+        })
+    }
+}
+
+/// The maximum size of the cache, in bytes (e.g., `10GB`).
+///
+/// Supports both decimal (`KB`, `MB`, `GB`, `TB`) and binary (`KiB`, `MiB`, `GiB`, `TiB`) units.
+/// A bare number is interpreted as a number of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheMaxSize(u64);
+
+impl CacheMaxSize {
+    /// Return the maximum size in bytes.
+    pub fn as_bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for CacheMaxSize {
+    type Err = CacheLimitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_bytes(s)
+            .map(Self)
+            .ok_or_else(|| CacheLimitParseError::InvalidSize(s.to_string()))
+    }
+}
+
+/// Parse a number of bytes with an optional decimal or binary unit (e.g., `10GB` or `500MiB`).
+pub(crate) fn parse_bytes(s: &str) -> Option<u64> {
+    let trimmed = s.trim();
+    let split = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    let (value, unit) = trimmed.split_at(split);
+    let value = value.parse::<u64>().ok()?;
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1_000,
+        "m" | "mb" => 1_000_000,
+        "g" | "gb" => 1_000_000_000,
+        "t" | "tb" => 1_000_000_000_000,
+        "kib" => 1 << 10,
+        "mib" => 1 << 20,
+        "gib" => 1 << 30,
+        "tib" => 1 << 40,
+        _ => return None,
+    };
+    value.checked_mul(multiplier)
+}
+
+impl std::fmt::Display for CacheMaxSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheMaxSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheMaxSize {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CacheMaxSize")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^\d+\s*([kKmMgGtT]([iI]?[bB])?|[bB])?$",
+            "description": "A size in bytes, e.g. `10GB`, `500MiB`, or `1024`."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_max_age() {
+        assert_eq!(
+            CacheMaxAge::from_str("30d").unwrap().as_duration(),
+            Duration::from_secs(30 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            CacheMaxAge::from_str("12h").unwrap().as_duration(),
+            Duration::from_secs(12 * 60 * 60)
+        );
+        assert_eq!(
+            CacheMaxAge::from_str("2w").unwrap().as_duration(),
+            Duration::from_secs(14 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            CacheMaxAge::from_str("7").unwrap().as_duration(),
+            Duration::from_secs(7 * 24 * 60 * 60)
+        );
+        assert!(CacheMaxAge::from_str("d").is_err());
+        assert!(CacheMaxAge::from_str("30y").is_err());
+        assert!(CacheMaxAge::from_str("-1d").is_err());
+
+        assert_eq!(CacheMaxAge::from_str("14d").unwrap().to_string(), "2w");
+        assert_eq!(CacheMaxAge::from_str("90s").unwrap().to_string(), "90s");
+    }
+
+    #[test]
+    fn parse_max_size() {
+        assert_eq!(
+            CacheMaxSize::from_str("10GB").unwrap().as_bytes(),
+            10_000_000_000
+        );
+        assert_eq!(
+            CacheMaxSize::from_str("500MiB").unwrap().as_bytes(),
+            500 * 1024 * 1024
+        );
+        assert_eq!(CacheMaxSize::from_str("1024").unwrap().as_bytes(), 1024);
+        assert_eq!(CacheMaxSize::from_str("2 gib").unwrap().as_bytes(), 2 << 30);
+        assert!(CacheMaxSize::from_str("GB").is_err());
+        assert!(CacheMaxSize::from_str("10XB").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Expected a cache namespace consisting of ASCII letters, digits, `-`, `_`, and `.` (not starting with `.`), but found: `{0}`"
+)]
+pub struct CacheNamespaceParseError(String);
+
+/// The name of an isolated namespace within the cache (e.g., `experiments`).
+///
+/// Namespaces may contain ASCII letters, digits, `-`, `_`, and `.`, but may not start with `.`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheNamespace(String);
+
+impl CacheNamespace {
+    /// Return the namespace as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// How a cache namespace relates to the shared cache.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CacheNamespaceMode {
+    /// Neither read from nor write to the shared cache.
+    #[default]
+    Isolated,
+    /// Read entries from the shared cache, but write new entries to the namespace.
+    Overlay,
+}
+
+impl FromStr for CacheNamespace {
+    type Err = CacheNamespaceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty()
+            || s.starts_with('.')
+            || !s
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(CacheNamespaceParseError(s.to_string()));
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for CacheNamespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheNamespace {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheNamespace {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CacheNamespace")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^[a-zA-Z0-9_\-][a-zA-Z0-9_\-.]*$",
+            "description": "The name of a cache namespace, e.g. `experiments`."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_namespace() {
+        assert_eq!(
+            CacheNamespace::from_str("experiments").unwrap().as_str(),
+            "experiments"
+        );
+        assert_eq!(
+            CacheNamespace::from_str("torch-nightly_2.6")
+                .unwrap()
+                .as_str(),
+            "torch-nightly_2.6"
+        );
+        assert!(CacheNamespace::from_str("").is_err());
+        assert!(CacheNamespace::from_str(".hidden").is_err());
+        assert!(CacheNamespace::from_str("..").is_err());
+        assert!(CacheNamespace::from_str("a/b").is_err());
+        assert!(CacheNamespace::from_str("a\\b").is_err());
+    }
+}
@@ -124,6 +124,14 @@ impl DependencyGroups {
         })
     }
 
+    /// Helper to make a spec from just --all-groups
+    pub fn from_all_groups() -> Self {
+        Self::from_history(DependencyGroupsHistory {
+            all_groups: true,
+            ..Default::default()
+        })
+    }
+
     /// Helper to make a spec from just a --dev flag
     pub fn from_dev_mode(dev_mode: DevMode) -> Self {
         Self::from_history(DependencyGroupsHistory {
@@ -1,7 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// A collection of `.env` file paths.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
 pub struct EnvFile(Vec<PathBuf>);
 
 impl EnvFile {
@@ -44,6 +46,17 @@ impl EnvFile {
         Self(paths)
     }
 
+    /// Resolve the env file paths relative to the given root directory.
+    #[must_use]
+    pub fn relative_to(self, root_dir: &Path) -> Self {
+        Self(self.0.into_iter().map(|path| root_dir.join(path)).collect())
+    }
+
+    /// Returns `true` if no env files are specified.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Iterate over the paths in the env file.
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &PathBuf> {
         self.0.iter()
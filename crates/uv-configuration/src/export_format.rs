@@ -22,6 +22,65 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export in `CycloneDX` v1.5 XML format.
+    #[serde(rename = "cyclonedx1.5+xml")]
+    #[cfg_attr(feature = "clap", clap(name = "cyclonedx1.5+xml"))]
+    CycloneDX1_5Xml,
+    /// Export in `CycloneDX` v1.4 JSON format.
+    #[serde(rename = "cyclonedx1.4")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "cyclonedx1.4", alias = "cyclonedx1.4+json")
+    )]
+    CycloneDX1_4,
+    /// Export in `CycloneDX` v1.4 XML format.
+    #[serde(rename = "cyclonedx1.4+xml")]
+    #[cfg_attr(feature = "clap", clap(name = "cyclonedx1.4+xml"))]
+    CycloneDX1_4Xml,
+    /// Export in `SPDX` v2.3 JSON format.
+    #[serde(rename = "spdx2.3+json", alias = "spdx2.3")]
+    #[cfg_attr(feature = "clap", clap(name = "spdx2.3+json", alias = "spdx2.3"))]
+    SpdxJson,
+    /// Export as a GitHub dependency submission snapshot.
+    #[serde(rename = "github-dependency-snapshot")]
+    #[cfg_attr(feature = "clap", clap(name = "github-dependency-snapshot"))]
+    GitHubDependencySnapshot,
+    /// Export a digest of the selected dependencies, for use as a CI cache key.
+    #[serde(rename = "cache-key")]
+    #[cfg_attr(feature = "clap", clap(name = "cache-key"))]
+    CacheKey,
+}
+
+impl ExportFormat {
+    /// Returns the specification version and encoding of a `CycloneDX` format, or `None` for
+    /// other formats.
+    pub fn cyclonedx(self) -> Option<(CycloneDxVersion, CycloneDxEncoding)> {
+        match self {
+            Self::CycloneDX1_5 => Some((CycloneDxVersion::V1_5, CycloneDxEncoding::Json)),
+            Self::CycloneDX1_5Xml => Some((CycloneDxVersion::V1_5, CycloneDxEncoding::Xml)),
+            Self::CycloneDX1_4 => Some((CycloneDxVersion::V1_4, CycloneDxEncoding::Json)),
+            Self::CycloneDX1_4Xml => Some((CycloneDxVersion::V1_4, CycloneDxEncoding::Xml)),
+            Self::RequirementsTxt
+            | Self::PylockToml
+            | Self::SpdxJson
+            | Self::GitHubDependencySnapshot
+            | Self::CacheKey => None,
+        }
+    }
+}
+
+/// A version of the `CycloneDX` specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycloneDxVersion {
+    V1_4,
+    V1_5,
+}
+
+/// The encoding of a `CycloneDX` document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycloneDxEncoding {
+    Json,
+    Xml,
 }
 
 /// The output format to use in `uv pip compile`.
@@ -42,3 +101,66 @@ pub enum PipCompileFormat {
     #[cfg_attr(feature = "clap", clap(name = "pylock.toml", alias = "pylock-toml"))]
     PylockToml,
 }
+
+/// The output format to use in `uv pip freeze`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum PipFreezeFormat {
+    /// Display the installed packages in `requirements.txt` format.
+    #[default]
+    #[serde(rename = "requirements.txt", alias = "requirements-txt")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "requirements.txt", alias = "requirements-txt")
+    )]
+    RequirementsTxt,
+    /// Export the installed packages in `CycloneDX` v1.5 JSON format.
+    #[serde(rename = "cyclonedx1.5")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
+    )]
+    CycloneDX1_5,
+    /// Export the installed packages in `CycloneDX` v1.5 XML format.
+    #[serde(rename = "cyclonedx1.5+xml")]
+    #[cfg_attr(feature = "clap", clap(name = "cyclonedx1.5+xml"))]
+    CycloneDX1_5Xml,
+    /// Export the installed packages in `CycloneDX` v1.4 JSON format.
+    #[serde(rename = "cyclonedx1.4")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "cyclonedx1.4", alias = "cyclonedx1.4+json")
+    )]
+    CycloneDX1_4,
+    /// Export the installed packages in `CycloneDX` v1.4 XML format.
+    #[serde(rename = "cyclonedx1.4+xml")]
+    #[cfg_attr(feature = "clap", clap(name = "cyclonedx1.4+xml"))]
+    CycloneDX1_4Xml,
+}
+
+impl PipFreezeFormat {
+    /// Returns the specification version and encoding of a `CycloneDX` format, or `None` for
+    /// `requirements.txt`.
+    pub fn cyclonedx(self) -> Option<(CycloneDxVersion, CycloneDxEncoding)> {
+        match self {
+            Self::CycloneDX1_5 => Some((CycloneDxVersion::V1_5, CycloneDxEncoding::Json)),
+            Self::CycloneDX1_5Xml => Some((CycloneDxVersion::V1_5, CycloneDxEncoding::Xml)),
+            Self::CycloneDX1_4 => Some((CycloneDxVersion::V1_4, CycloneDxEncoding::Json)),
+            Self::CycloneDX1_4Xml => Some((CycloneDxVersion::V1_4, CycloneDxEncoding::Xml)),
+            Self::RequirementsTxt => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PipFreezeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RequirementsTxt => write!(f, "requirements.txt"),
+            Self::CycloneDX1_5 => write!(f, "cyclonedx1.5"),
+            Self::CycloneDX1_5Xml => write!(f, "cyclonedx1.5+xml"),
+            Self::CycloneDX1_4 => write!(f, "cyclonedx1.4"),
+            Self::CycloneDX1_4Xml => write!(f, "cyclonedx1.4+xml"),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// The strategy to use when fetching Git repositories.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum GitFetchStrategy {
+    /// Fetch the full history of the requested references.
+    #[default]
+    Full,
+    /// Fetch only the requested commit, without its history.
+    ///
+    /// When a reference can't be resolved without the full set of branches and tags (e.g., an
+    /// abbreviated commit hash), the full history is fetched instead.
+    Shallow,
+}
@@ -1,5 +1,9 @@
 pub use authentication::*;
+pub use bandwidth::*;
+pub use build_fingerprint::*;
 pub use build_options::*;
+pub use cache_limits::*;
+pub use cache_namespace::*;
 pub use concurrency::*;
 pub use constraints::*;
 pub use dependency_groups::*;
@@ -9,6 +13,7 @@ pub use env_file::*;
 pub use excludes::*;
 pub use export_format::*;
 pub use extras::*;
+pub use git_fetch::*;
 pub use hash::*;
 pub use install_options::*;
 pub use name_specifiers::*;
@@ -17,6 +22,7 @@ pub use package_options::*;
 pub use project_build_backend::*;
 pub use proxy_url::*;
 pub use required_version::*;
+pub use sbom::*;
 pub use sources::*;
 pub use target_triple::*;
 pub use threading::*;
@@ -25,7 +31,11 @@ pub use trusted_publishing::*;
 pub use vcs::*;
 
 mod authentication;
+mod bandwidth;
+mod build_fingerprint;
 mod build_options;
+mod cache_limits;
+mod cache_namespace;
 mod concurrency;
 mod constraints;
 mod dependency_groups;
@@ -35,6 +45,7 @@ mod env_file;
 mod excludes;
 mod export_format;
 mod extras;
+mod git_fetch;
 mod hash;
 mod install_options;
 mod name_specifiers;
@@ -43,6 +54,7 @@ mod package_options;
 mod project_build_backend;
 mod proxy_url;
 mod required_version;
+mod sbom;
 mod sources;
 mod target_triple;
 mod threading;
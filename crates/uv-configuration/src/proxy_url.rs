@@ -18,6 +18,7 @@ pub struct ProxyUrl(Url);
 pub enum ProxyUrlKind {
     Http,
     Https,
+    All,
 }
 
 impl ProxyUrl {
@@ -34,6 +35,8 @@ impl ProxyUrl {
                 .expect("Constructing a proxy from a url should never fail"),
             ProxyUrlKind::Https => Proxy::https(self.0.as_str())
                 .expect("Constructing a proxy from a url should never fail"),
+            ProxyUrlKind::All => Proxy::all(self.0.as_str())
+                .expect("Constructing a proxy from a url should never fail"),
         }
     }
 }
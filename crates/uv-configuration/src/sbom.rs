@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Metadata to record in the SBOMs exported for a project, as configured in `[tool.uv.sbom]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SbomMetadata {
+    /// The organization that supplies the project.
+    pub supplier: Option<SbomOrganization>,
+    /// The organization that manufactures the project.
+    pub manufacturer: Option<SbomOrganization>,
+    /// The people who authored the SBOM.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<SbomContact>,
+    /// The type of the root component, e.g., `application`, overriding the default of `library`.
+    pub component_type: Option<SbomComponentType>,
+    /// Tools to record alongside uv as having generated the SBOM.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<SbomTool>,
+    /// Custom properties to record in the metadata of the SBOM, e.g., `{ "acme:team" = "core" }`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, String>,
+}
+
+/// An organization, e.g., the supplier or manufacturer of a project.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SbomOrganization {
+    /// The name of the organization.
+    pub name: String,
+    /// The URLs of the organization.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub url: Vec<String>,
+    /// The contacts at the organization.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contact: Vec<SbomContact>,
+}
+
+/// A person, e.g., an author of an SBOM or a contact at an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SbomContact {
+    /// The name of the person.
+    pub name: Option<String>,
+    /// The email address of the person.
+    pub email: Option<String>,
+    /// The phone number of the person.
+    pub phone: Option<String>,
+}
+
+/// A tool that contributed to an SBOM.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SbomTool {
+    /// The vendor of the tool.
+    pub vendor: Option<String>,
+    /// The name of the tool.
+    pub name: String,
+    /// The version of the tool.
+    pub version: Option<String>,
+}
+
+/// The type of a component in an SBOM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SbomComponentType {
+    Application,
+    Framework,
+    Library,
+    Container,
+    Platform,
+    OperatingSystem,
+    Device,
+    DeviceDriver,
+    Firmware,
+    File,
+    MachineLearningModel,
+    Data,
+}
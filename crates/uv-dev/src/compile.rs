@@ -5,6 +5,7 @@ use tracing::info;
 
 use uv_cache::{Cache, CacheArgs};
 use uv_configuration::Concurrency;
+use uv_install_wheel::LinkMode;
 use uv_preview::Preview;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
 
@@ -38,7 +39,8 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         &fs_err::canonicalize(args.root)?,
         &interpreter,
         &Concurrency::default(),
-        cache.root(),
+        &cache,
+        LinkMode::default(),
     )
     .await?;
     info!("Compiled {files} files");
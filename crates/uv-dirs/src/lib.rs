@@ -83,6 +83,33 @@ pub fn legacy_user_state_dir() -> Option<PathBuf> {
         .map(|dir| if cfg!(windows) { dir.join("data") } else { dir })
 }
 
+/// Returns the user-level Jupyter data directory, in which Jupyter discovers kernels.
+///
+/// This follows Jupyter's own lookup:
+///
+/// - `$JUPYTER_DATA_DIR`
+/// - `%APPDATA%\jupyter` on Windows
+/// - `~/Library/Jupyter` on macOS
+/// - `$XDG_DATA_HOME/jupyter` or `~/.local/share/jupyter` on other platforms
+pub fn user_jupyter_data_dir() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(EnvVars::JUPYTER_DATA_DIR).and_then(parse_path) {
+        return Some(path);
+    }
+    if cfg!(windows) {
+        env::var_os(EnvVars::APPDATA)
+            .and_then(parse_path)
+            .map(|path| path.join("jupyter"))
+    } else if cfg!(target_os = "macos") {
+        etcetera::home_dir()
+            .ok()
+            .map(|home| home.join("Library").join("Jupyter"))
+    } else {
+        etcetera::choose_base_strategy()
+            .ok()
+            .map(|dirs| dirs.data_dir().join("jupyter"))
+    }
+}
+
 /// Return a [`PathBuf`] from the given [`OsString`], if non-empty.
 ///
 /// Unlike [`parse_xdg_path`], this function accepts both relative and absolute paths,
@@ -190,6 +217,35 @@ pub fn system_config_file() -> Option<PathBuf> {
     }
 }
 
+/// Returns the path to the system policy file, if it exists.
+///
+/// Unlike the system configuration file, the policy file is only read from a fixed location that
+/// can't be changed through the environment: `/etc/uv/policy.toml` on Unix-like systems, and
+/// `C:\ProgramData\uv\policy.toml` on Windows.
+pub fn system_policy_file() -> Option<PathBuf> {
+    // In debug builds, allow the test suite to provide a policy file.
+    if cfg!(debug_assertions)
+        && let Some(path) = env::var_os(EnvVars::UV_INTERNAL__TEST_POLICY_FILE)
+    {
+        let path = PathBuf::from(path);
+        return path.is_file().then_some(path);
+    }
+
+    #[cfg(windows)]
+    let candidate = Path::new(r"C:\ProgramData\uv\policy.toml");
+    #[cfg(not(windows))]
+    let candidate = Path::new("/etc/uv/policy.toml");
+
+    match candidate.try_exists() {
+        Ok(true) => Some(candidate.to_path_buf()),
+        Ok(false) => None,
+        Err(err) => {
+            tracing::warn!("Failed to query system policy file: {err}");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(windows)]
@@ -12,6 +12,8 @@ pub struct BuildInfo {
     extra_build_requires: Vec<ExtraBuildRequirement>,
     #[serde(default, skip_serializing_if = "BuildVariables::is_empty")]
     extra_build_variables: BuildVariables,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    build_fingerprint: Option<String>,
 }
 
 impl CacheKey for BuildInfo {
@@ -19,6 +21,7 @@ impl CacheKey for BuildInfo {
         self.config_settings.cache_key(state);
         self.extra_build_requires.cache_key(state);
         self.extra_build_variables.cache_key(state);
+        self.build_fingerprint.cache_key(state);
     }
 }
 
@@ -34,14 +37,27 @@ impl BuildInfo {
             config_settings: config_settings.clone(),
             extra_build_requires: extra_build_dependencies.to_vec(),
             extra_build_variables: extra_build_variables.cloned().unwrap_or_default(),
+            build_fingerprint: None,
+        }
+    }
+
+    /// Scope the [`BuildInfo`] to a fingerprint of the build environment (e.g., the compiler
+    /// version), if any.
+    #[must_use]
+    pub fn with_build_fingerprint(self, build_fingerprint: Option<&str>) -> Self {
+        Self {
+            build_fingerprint: build_fingerprint.map(ToString::to_string),
+            ..self
         }
     }
 
     /// Returns `true` if the [`BuildInfo`] is empty, meaning it has no configuration settings,
+    /// extra build dependencies, extra build variables, or build environment fingerprint.
     pub fn is_empty(&self) -> bool {
         self.config_settings.is_empty()
             && self.extra_build_requires.is_empty()
             && self.extra_build_variables.is_empty()
+            && self.build_fingerprint.is_none()
     }
 
     /// Return the cache shard for this [`BuildInfo`].
@@ -157,6 +157,14 @@ impl CachedDist {
         }
     }
 
+    /// Return the [`HashDigest`]s of the distribution's archive, if known.
+    pub fn hashes(&self) -> &[HashDigest] {
+        match self {
+            Self::Registry(dist) => dist.hashes.as_slice(),
+            Self::Url(dist) => dist.hashes.as_slice(),
+        }
+    }
+
     /// Return the [`ParsedUrl`] of the distribution, if it exists.
     pub fn parsed_url(&self) -> Option<&ParsedUrl> {
         match self {
@@ -31,6 +31,8 @@ pub struct File {
     pub dist_info_metadata: bool,
     pub filename: SmallString,
     pub hashes: HashDigests,
+    /// The URL of the PEP 740 provenance object for this file, if any.
+    pub provenance: Option<FileLocation>,
     pub requires_python: Option<VersionSpecifiers>,
     pub size: Option<u64>,
     // N.B. We don't use a Jiff timestamp here because it's a little
@@ -56,6 +58,9 @@ impl File {
                 .is_some_and(CoreMetadata::is_available),
             filename: file.filename,
             hashes: HashDigests::from(file.hashes),
+            provenance: file
+                .provenance
+                .map(|provenance| FileLocation::new(provenance, base)),
             requires_python: file
                 .requires_python
                 .transpose()
@@ -102,6 +107,7 @@ impl File {
                 .as_ref()
                 .is_some_and(CoreMetadata::is_available),
             hashes: HashDigests::from(file.hashes),
+            provenance: None,
             requires_python: file
                 .requires_python
                 .transpose()
@@ -1,17 +1,20 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use url::Url;
 
-use uv_auth::{AuthPolicy, Credentials};
+use uv_auth::{AuthPolicy, CredentialProvider, Credentials};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
-use crate::{IndexStatusCodeStrategy, IndexUrl, IndexUrlError, SerializableStatusCode};
+use crate::package_pattern::PackagePattern;
+use crate::{
+    IndexStatusCodeStrategy, IndexUrl, IndexUrlError, RetrySettings, SerializableStatusCode,
+};
 
 /// Cache control configuration for an index.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Default)]
@@ -110,6 +113,43 @@ pub struct Index {
     /// indexes can point to either local or remote resources.
     #[serde(default)]
     pub format: IndexFormat,
+    /// The Simple API representation to request from the index.
+    ///
+    /// By default (`auto`), uv negotiates the representation with the index, preferring the JSON
+    /// API ([PEP 691](https://peps.python.org/pep-0691/)), and remembers when an index only serves
+    /// HTML, such that subsequent requests ask for HTML directly. Set to `json` or `html` to skip
+    /// negotiation and request a single representation, e.g., for indexes that advertise a JSON API
+    /// but serve incomplete or malformed responses.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// api = "html"
+    /// ```
+    #[serde(default)]
+    pub api: IndexApi,
+    /// Whether to verify [PEP 740](https://peps.python.org/pep-0740/) attestations for
+    /// distributions downloaded from the index.
+    ///
+    /// When set to `warn`, uv fetches the provenance the index publishes for each distribution
+    /// and warns if it is missing or fails to verify. When set to `require`, uv refuses to
+    /// install distributions without a valid attestation. Defaults to `off`.
+    ///
+    /// Attestations are checked against the distribution's digest, the signing certificate's key,
+    /// and the publisher named by the certificate; the certificate chain and transparency log
+    /// inclusion are not verified, so a self-issued certificate is accepted. As such, `require`
+    /// rejects distributions with missing or inconsistent provenance, but doesn't protect against
+    /// an index that serves attestations signed with a certificate of its own.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// verify = "require"
+    /// ```
+    #[serde(default)]
+    pub verify: IndexVerifyPolicy,
     /// The URL of the upload endpoint.
     ///
     /// When using `uv publish --index <name>`, this URL is used for publishing.
@@ -133,6 +173,87 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub authenticate: AuthPolicy,
+    /// The provider to use to fetch credentials for the index.
+    ///
+    /// Credential providers request short-lived tokens on demand, and refresh them as they expire,
+    /// such that tokens don't need to be embedded in the index URL. Supported providers are:
+    ///
+    /// - `keyring`: Read credentials from the system keyring, as stored by `uv auth login`.
+    /// - `aws-codeartifact`: Request an authorization token for AWS CodeArtifact, using the default
+    ///   AWS credential chain.
+    /// - `gcp-artifact-registry`: Request an access token for Google Artifact Registry, using
+    ///   Application Default Credentials.
+    /// - `azure-artifacts`: Request an access token for Azure Artifacts, using the Azure CLI.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "codeartifact"
+    /// url = "https://<domain>-<owner>.d.codeartifact.<region>.amazonaws.com/pypi/<repository>/simple/"
+    /// credential-provider = "aws-codeartifact"
+    /// ```
+    #[serde(default)]
+    pub credential_provider: Option<CredentialProvider>,
+    /// The path to a PEM-encoded client certificate to present to the index for mutual TLS
+    /// authentication.
+    ///
+    /// The file must contain both the certificate and its private key. For requests to the index,
+    /// the certificate takes precedence over the `SSL_CLIENT_CERT` environment variable. Relative
+    /// paths are resolved relative to the directory containing the configuration file.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://<omitted>/simple"
+    /// client-cert = "certs/client.pem"
+    /// ```
+    #[serde(default)]
+    pub client_cert: Option<PathBuf>,
+    /// The path to a PEM-encoded bundle of CA certificates to trust for the index.
+    ///
+    /// The certificates are trusted in addition to the system or bundled root certificates, such
+    /// that an index signed by a private certificate authority can be used without setting
+    /// `SSL_CERT_FILE` globally. Relative paths are resolved relative to the directory containing
+    /// the configuration file.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://<omitted>/simple"
+    /// ca-cert = "certs/internal-ca.pem"
+    /// ```
+    #[serde(default)]
+    pub ca_cert: Option<PathBuf>,
+    /// The URL of the proxy to use for requests to the index.
+    ///
+    /// Requests to the index, and to files served from the same host, are sent through the given
+    /// proxy regardless of scheme, in place of the `http-proxy`, `https-proxy`, and `no-proxy`
+    /// settings and any proxy detected from the environment or system configuration. Supports
+    /// `http`, `https`, `socks5`, and `socks5h` proxies.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://<omitted>/simple"
+    /// proxy = "http://proxy.internal.example.com:3128"
+    /// ```
+    #[serde(default)]
+    pub proxy: Option<DisplaySafeUrl>,
+    /// The policy for retrying failed requests to the index.
+    ///
+    /// Overrides the global `retry` setting for requests to the index, and to files served from the
+    /// same host. Any field that's omitted falls back to the global setting.
+    ///
+    /// For example, to fail fast on authentication errors, while retrying a flaky mirror more
+    /// persistently:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "mirror"
+    /// url = "https://<omitted>/simple"
+    /// retry = { retries = 10, backoff = "constant", min-delay = 2, retry-on-status = [502, 503] }
+    /// ```
+    #[serde(default)]
+    pub retry: Option<RetrySettings>,
     /// Status codes that uv should ignore when deciding whether
     /// to continue searching in the next index after a failure.
     ///
@@ -157,6 +278,77 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub cache_control: Option<IndexCacheControl>,
+    /// The priority of the index, relative to other indexes of the same role.
+    ///
+    /// Indexes are queried in ascending order of priority, such that an index with priority `-1` is
+    /// queried before an index with priority `1`. Indexes without a priority default to `0`, and
+    /// ties are broken by the order in which the indexes are defined.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "mirror"
+    /// url = "https://<omitted>/simple"
+    /// priority = -1
+    /// ```
+    #[serde(default)]
+    pub priority: Option<i32>,
+    /// The role of the index.
+    ///
+    /// A `primary` index is queried as usual. A `fallback` index is queried after every `primary`
+    /// index, and serves as a failover target: when a `primary` index with a `retry-budget` is
+    /// unreachable (e.g., due to a connection failure, timeout, or server error), uv will skip it
+    /// with a warning and continue to the next index, rather than failing resolution.
+    ///
+    /// For example, to front PyPI with an internal mirror:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "mirror"
+    /// url = "https://<omitted>/simple"
+    /// retry-budget = 3
+    ///
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// default = true
+    /// role = "fallback"
+    /// ```
+    #[serde(default)]
+    pub role: IndexRole,
+    /// The number of failed requests after which the index is considered unhealthy.
+    ///
+    /// Setting a retry budget opts the index in to failing over to the `fallback` indexes: failed
+    /// requests are skipped with a warning, rather than failing resolution. Each request is retried
+    /// before it's counted as a failure. Once an index exhausts its retry budget, uv will stop
+    /// querying it for the remainder of the invocation. Only applies if at least one other
+    /// `fallback` index is defined. By default, failures are never skipped.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "mirror"
+    /// url = "https://<omitted>/simple"
+    /// retry-budget = 1
+    /// ```
+    #[serde(default)]
+    pub retry_budget: Option<u32>,
+    /// Package name patterns to pin to the index.
+    ///
+    /// Packages whose names match any of the given glob patterns will _only_ be resolved from this
+    /// index; other indexes (including `--find-links` locations) will not be consulted for them,
+    /// preventing "dependency confusion" attacks in which an attacker publishes a package with the
+    /// same name to a public index.
+    ///
+    /// Patterns are matched against normalized package names. If a package matches the patterns of
+    /// multiple indexes, the first-defined index is used.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://<omitted>/simple"
+    /// packages = ["internal-*", "acme-sdk"]
+    /// ```
+    #[serde(default)]
+    pub packages: Vec<PackagePattern>,
 }
 
 impl PartialEq for Index {
@@ -168,20 +360,42 @@ impl PartialEq for Index {
             default,
             origin: _,
             format,
+            api,
+            verify,
             publish_url,
             authenticate,
+            credential_provider,
+            client_cert,
+            ca_cert,
+            proxy,
+            retry,
             ignore_error_codes,
             cache_control,
+            priority,
+            role,
+            retry_budget,
+            packages,
         } = self;
         *url == other.url
             && *name == other.name
             && *explicit == other.explicit
             && *default == other.default
             && *format == other.format
+            && *api == other.api
+            && *verify == other.verify
             && *publish_url == other.publish_url
             && *authenticate == other.authenticate
+            && *credential_provider == other.credential_provider
+            && *client_cert == other.client_cert
+            && *ca_cert == other.ca_cert
+            && *proxy == other.proxy
+            && *retry == other.retry
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
+            && *priority == other.priority
+            && *role == other.role
+            && *retry_budget == other.retry_budget
+            && *packages == other.packages
     }
 }
 
@@ -202,20 +416,42 @@ impl Ord for Index {
             default,
             origin: _,
             format,
+            api,
+            verify,
             publish_url,
             authenticate,
+            credential_provider,
+            client_cert,
+            ca_cert,
+            proxy,
+            retry,
             ignore_error_codes,
             cache_control,
+            priority,
+            role,
+            retry_budget,
+            packages,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
             .then_with(|| explicit.cmp(&other.explicit))
             .then_with(|| default.cmp(&other.default))
             .then_with(|| format.cmp(&other.format))
+            .then_with(|| api.cmp(&other.api))
+            .then_with(|| verify.cmp(&other.verify))
             .then_with(|| publish_url.cmp(&other.publish_url))
             .then_with(|| authenticate.cmp(&other.authenticate))
+            .then_with(|| credential_provider.cmp(&other.credential_provider))
+            .then_with(|| client_cert.cmp(&other.client_cert))
+            .then_with(|| ca_cert.cmp(&other.ca_cert))
+            .then_with(|| proxy.cmp(&other.proxy))
+            .then_with(|| retry.cmp(&other.retry))
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
+            .then_with(|| priority.cmp(&other.priority))
+            .then_with(|| role.cmp(&other.role))
+            .then_with(|| retry_budget.cmp(&other.retry_budget))
+            .then_with(|| packages.cmp(&other.packages))
     }
 }
 
@@ -228,20 +464,42 @@ impl std::hash::Hash for Index {
             default,
             origin: _,
             format,
+            api,
+            verify,
             publish_url,
             authenticate,
+            credential_provider,
+            client_cert,
+            ca_cert,
+            proxy,
+            retry,
             ignore_error_codes,
             cache_control,
+            priority,
+            role,
+            retry_budget,
+            packages,
         } = self;
         url.hash(state);
         name.hash(state);
         explicit.hash(state);
         default.hash(state);
         format.hash(state);
+        api.hash(state);
+        verify.hash(state);
         publish_url.hash(state);
         authenticate.hash(state);
+        credential_provider.hash(state);
+        client_cert.hash(state);
+        ca_cert.hash(state);
+        proxy.hash(state);
+        retry.hash(state);
         ignore_error_codes.hash(state);
         cache_control.hash(state);
+        priority.hash(state);
+        role.hash(state);
+        retry_budget.hash(state);
+        packages.hash(state);
     }
 }
 
@@ -268,6 +526,89 @@ pub enum IndexFormat {
     Flat,
 }
 
+#[derive(
+    Default,
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexRole {
+    /// An index that is queried in order of priority.
+    #[default]
+    Primary,
+    /// An index that is queried after all primary indexes, and used as a failover target when a
+    /// primary index is unavailable.
+    Fallback,
+}
+
+#[derive(
+    Default,
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexApi {
+    /// Negotiate the representation with the index, preferring JSON.
+    #[default]
+    Auto,
+    /// Only request the JSON representation of the Simple API (PEP 691).
+    Json,
+    /// Only request the HTML representation of the Simple API (PEP 503).
+    Html,
+}
+
+/// The policy to apply to [PEP 740](https://peps.python.org/pep-0740/) attestations for
+/// distributions downloaded from an index.
+#[derive(
+    Default,
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexVerifyPolicy {
+    /// Do not verify attestations.
+    #[default]
+    Off,
+    /// Verify attestations, warning if they are missing or invalid.
+    Warn,
+    /// Verify attestations, failing if they are missing or invalid.
+    Require,
+}
+
+impl IndexVerifyPolicy {
+    /// Returns `true` if attestations should be verified.
+    pub fn is_enabled(self) -> bool {
+        !matches!(self, Self::Off)
+    }
+}
+
 impl Index {
     /// Initialize an [`Index`] from a pip-style `--index-url`.
     pub fn from_index_url(url: IndexUrl) -> Self {
@@ -278,10 +619,21 @@ impl Index {
             default: true,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
             cache_control: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }
     }
 
@@ -294,10 +646,21 @@ impl Index {
             default: false,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
             cache_control: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }
     }
 
@@ -310,10 +673,21 @@ impl Index {
             default: false,
             origin: None,
             format: IndexFormat::Flat,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
             cache_control: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }
     }
 
@@ -367,6 +741,12 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        if let Some(client_cert) = self.client_cert.take() {
+            self.client_cert = Some(root_dir.join(client_cert));
+        }
+        if let Some(ca_cert) = self.ca_cert.take() {
+            self.ca_cert = Some(root_dir.join(ca_cert));
+        }
         Ok(self)
     }
 
@@ -404,6 +784,12 @@ impl Index {
             IndexCacheControl::simple_api_cache_control(self.url.url())
         }
     }
+
+    /// Return the number of failed requests after which the index is considered unhealthy, if
+    /// the index opted in to failing over.
+    pub fn retry_budget(&self) -> Option<u32> {
+        self.retry_budget.map(|retry_budget| retry_budget.max(1))
+    }
 }
 
 impl From<IndexUrl> for Index {
@@ -415,10 +801,21 @@ impl From<IndexUrl> for Index {
             default: false,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
             cache_control: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }
     }
 }
@@ -439,10 +836,21 @@ impl FromStr for Index {
                     default: false,
                     origin: None,
                     format: IndexFormat::Simple,
+                    api: IndexApi::default(),
+                    verify: IndexVerifyPolicy::default(),
                     publish_url: None,
                     authenticate: AuthPolicy::default(),
+                    credential_provider: None,
+                    client_cert: None,
+                    ca_cert: None,
+                    proxy: None,
+                    retry: None,
                     ignore_error_codes: None,
                     cache_control: None,
+                    priority: None,
+                    role: IndexRole::default(),
+                    retry_budget: None,
+                    packages: Vec::new(),
                 });
             }
         }
@@ -456,10 +864,21 @@ impl FromStr for Index {
             default: false,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
             cache_control: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         })
     }
 }
@@ -11,11 +11,12 @@ use thiserror::Error;
 use url::{ParseError, Url};
 use uv_auth::RealmRef;
 use uv_cache_key::CanonicalUrl;
+use uv_normalize::PackageName;
 use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
-use crate::{Index, IndexStatusCodeStrategy, Verbatim};
+use crate::{Index, IndexApi, IndexRole, IndexStatusCodeStrategy, IndexVerifyPolicy, Verbatim};
 
 static PYPI_URL: LazyLock<DisplaySafeUrl> =
     LazyLock::new(|| DisplaySafeUrl::parse("https://pypi.org/simple").unwrap());
@@ -248,6 +249,25 @@ impl Deref for IndexUrl {
     }
 }
 
+/// The policy to apply when a package is available on multiple indexes that don't declare each
+/// other via PEP 708 repository tracking metadata.
+///
+/// See: <https://peps.python.org/pep-0708/>
+#[derive(
+    Debug, Default, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum IndexTrackingPolicy {
+    /// Warn when a package is found on multiple indexes that don't track each other.
+    Warn,
+    /// Fail when a package is found on multiple indexes that don't track each other.
+    Deny,
+    /// Merge packages across indexes regardless of their tracking metadata.
+    #[default]
+    Ignore,
+}
+
 /// The index locations to use for fetching packages. By default, uses the PyPI index.
 ///
 /// This type merges the legacy `--index-url`, `--extra-index-url`, and `--find-links` options,
@@ -258,6 +278,8 @@ pub struct IndexLocations {
     indexes: Vec<Index>,
     flat_index: Vec<Index>,
     no_index: bool,
+    #[serde(default)]
+    tracking_policy: IndexTrackingPolicy,
 }
 
 impl IndexLocations {
@@ -267,9 +289,17 @@ impl IndexLocations {
             indexes,
             flat_index,
             no_index,
+            tracking_policy: IndexTrackingPolicy::default(),
         }
     }
 
+    /// Set the [`IndexTrackingPolicy`] for packages that are available on multiple indexes.
+    #[must_use]
+    pub fn with_tracking_policy(mut self, tracking_policy: IndexTrackingPolicy) -> Self {
+        self.tracking_policy = tracking_policy;
+        self
+    }
+
     /// Combine a set of index locations.
     ///
     /// If either the current or the other index locations have `no_index` set, the result will
@@ -282,6 +312,7 @@ impl IndexLocations {
             indexes: self.indexes.into_iter().chain(indexes).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
             no_index: self.no_index || no_index,
+            tracking_policy: self.tracking_policy,
         }
     }
 
@@ -292,6 +323,16 @@ impl IndexLocations {
     }
 }
 
+/// Order the given [`Index`] entries by role and priority.
+///
+/// Primary indexes precede fallback indexes; within each role, indexes are ordered by ascending
+/// priority. The sort is stable, so indexes with the same role and priority retain their order.
+fn prioritize<'a>(indexes: impl Iterator<Item = &'a Index>) -> std::vec::IntoIter<&'a Index> {
+    let mut indexes = indexes.collect::<Vec<_>>();
+    indexes.sort_by_key(|index| (index.role, index.priority.unwrap_or_default()));
+    indexes.into_iter()
+}
+
 /// Returns `true` if two [`IndexUrl`]s refer to the same index.
 fn is_same_index(a: &IndexUrl, b: &IndexUrl) -> bool {
     RealmRef::from(&**b.url()) == RealmRef::from(&**a.url())
@@ -343,9 +384,11 @@ impl<'a> IndexLocations {
     /// If `no_index` was enabled, then this always returns an empty
     /// iterator.
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
-        self.implicit_indexes()
-            .chain(self.default_index())
-            .filter(|index| !index.explicit)
+        prioritize(
+            self.implicit_indexes()
+                .chain(self.default_index())
+                .filter(|index| !index.explicit),
+        )
     }
 
     /// Return an iterator over all simple [`Index`] entries in order.
@@ -374,11 +417,17 @@ impl<'a> IndexLocations {
         self.no_index
     }
 
+    /// Return the [`IndexTrackingPolicy`].
+    pub fn tracking_policy(&self) -> IndexTrackingPolicy {
+        self.tracking_policy
+    }
+
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
         IndexUrls {
             indexes: self.indexes.clone(),
             no_index: self.no_index,
+            tracking_policy: self.tracking_policy,
         }
     }
 
@@ -458,6 +507,21 @@ impl<'a> IndexLocations {
         }
         None
     }
+
+    /// Return the [`IndexVerifyPolicy`] for an [`IndexUrl`].
+    pub fn verify_for(&self, url: &IndexUrl) -> IndexVerifyPolicy {
+        for index in &self.indexes {
+            if is_same_index(index.url(), url) {
+                return index.verify;
+            }
+        }
+        IndexVerifyPolicy::default()
+    }
+
+    /// Returns `true` if any index requests attestation verification.
+    pub fn verifies_attestations(&self) -> bool {
+        self.indexes.iter().any(|index| index.verify.is_enabled())
+    }
 }
 
 impl From<&IndexLocations> for uv_auth::Indexes {
@@ -473,6 +537,7 @@ impl From<&IndexLocations> for uv_auth::Indexes {
                 url,
                 root_url,
                 auth_policy: index.authenticate,
+                credential_provider: index.credential_provider,
             }
         }))
     }
@@ -486,6 +551,7 @@ impl From<&IndexLocations> for uv_auth::Indexes {
 pub struct IndexUrls {
     indexes: Vec<Index>,
     no_index: bool,
+    tracking_policy: IndexTrackingPolicy,
 }
 
 impl<'a> IndexUrls {
@@ -493,6 +559,7 @@ impl<'a> IndexUrls {
         Self {
             indexes,
             no_index: false,
+            tracking_policy: IndexTrackingPolicy::default(),
         }
     }
 
@@ -540,10 +607,12 @@ impl<'a> IndexUrls {
     /// iterator.
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         let mut seen = FxHashSet::default();
-        self.implicit_indexes()
-            .chain(self.default_index())
-            .filter(|index| !index.explicit)
-            .filter(move |index| seen.insert(index.raw_url())) // Filter out redundant raw URLs
+        prioritize(
+            self.implicit_indexes()
+                .chain(self.default_index())
+                .filter(|index| !index.explicit)
+                .filter(move |index| seen.insert(index.raw_url())), // Filter out redundant raw URLs
+        )
     }
 
     /// Return an iterator over all user-defined [`Index`] entries in order.
@@ -582,6 +651,11 @@ impl<'a> IndexUrls {
         self.no_index
     }
 
+    /// Return the [`IndexTrackingPolicy`].
+    pub fn tracking_policy(&self) -> IndexTrackingPolicy {
+        self.tracking_policy
+    }
+
     /// Return the [`IndexStatusCodeStrategy`] for an [`IndexUrl`].
     pub fn status_code_strategy_for(&self, url: &IndexUrl) -> IndexStatusCodeStrategy {
         for index in &self.indexes {
@@ -592,6 +666,77 @@ impl<'a> IndexUrls {
         IndexStatusCodeStrategy::Default
     }
 
+    /// Return the [`Index`] to which the given package is pinned, if any.
+    ///
+    /// A package is pinned to the first-defined index whose `packages` patterns match its name. If
+    /// `no_index` was enabled, then this always returns `None`.
+    pub fn pinned_index(&'a self, package_name: &PackageName) -> Option<&'a Index> {
+        if self.no_index {
+            return None;
+        }
+        let mut seen = FxHashSet::default();
+        self.indexes
+            .iter()
+            .filter(move |index| index.name.as_ref().is_none_or(|name| seen.insert(name)))
+            .find(|index| {
+                index
+                    .packages
+                    .iter()
+                    .any(|pattern| pattern.matches(package_name))
+            })
+    }
+
+    /// Return the [`IndexRole`] for an [`IndexUrl`].
+    pub fn role_for(&self, url: &IndexUrl) -> IndexRole {
+        for index in &self.indexes {
+            if is_same_index(index.url(), url) {
+                return index.role;
+            }
+        }
+        IndexRole::default()
+    }
+
+    /// Return the [`IndexApi`] for an [`IndexUrl`].
+    pub fn api_for(&self, url: &IndexUrl) -> IndexApi {
+        for index in &self.indexes {
+            if is_same_index(index.url(), url) {
+                return index.api;
+            }
+        }
+        IndexApi::default()
+    }
+
+    /// Return the [`IndexVerifyPolicy`] for an [`IndexUrl`].
+    pub fn verify_for(&self, url: &IndexUrl) -> IndexVerifyPolicy {
+        for index in &self.indexes {
+            if is_same_index(index.url(), url) {
+                return index.verify;
+            }
+        }
+        IndexVerifyPolicy::default()
+    }
+
+    /// Return the retry budget for an [`IndexUrl`], if it can fail over to a fallback index.
+    ///
+    /// Returns `None` if the index didn't opt in to failing over (by setting a retry budget), or
+    /// if no other `fallback` index is available, in which case failures should be surfaced to the
+    /// user rather than skipped.
+    pub fn retry_budget_for(&self, url: &IndexUrl) -> Option<u32> {
+        let retry_budget = self
+            .indexes
+            .iter()
+            .find(|index| is_same_index(index.url(), url))?
+            .retry_budget()?;
+        // Avoid `Self::indexes`, which sorts the indexes by priority.
+        let fallback = !self.no_index
+            && self.indexes.iter().any(|index| {
+                !index.explicit
+                    && index.role == IndexRole::Fallback
+                    && !is_same_index(index.url(), url)
+            });
+        fallback.then_some(retry_budget)
+    }
+
     /// Return the Simple API cache control header for an [`IndexUrl`], if configured.
     pub fn simple_api_cache_control_for(&self, url: &IndexUrl) -> Option<&str> {
         for index in &self.indexes {
@@ -622,16 +767,40 @@ bitflags::bitflags! {
         const UNAUTHORIZED      = 1 << 2;
         /// Whether the index returned a `403 Forbidden` status code.
         const FORBIDDEN         = 1 << 1;
+        /// Whether the index only serves the HTML representation of the Simple API.
+        const NO_JSON           = 1 << 3;
+        /// Whether the index serves files without hashes.
+        const NO_HASHES         = 1 << 4;
+        /// Whether the index doesn't answer `HEAD` requests for files.
+        const NO_HEAD_REQUESTS  = 1 << 5;
     }
 }
 
 /// A map of [`IndexUrl`]s to their capabilities.
 ///
-/// We only store indexes that lack capabilities (i.e., don't support range requests, aren't
-/// authorized). The benefit is that the map is almost always empty, so validating capabilities is
+/// We only store indexes that lack capabilities (i.e., don't support range requests, don't serve
+/// the JSON API, aren't authorized). The benefit is that the map is almost always empty, so validating capabilities is
 /// extremely cheap.
 #[derive(Debug, Default, Clone)]
-pub struct IndexCapabilities(Arc<RwLock<FxHashMap<IndexUrl, Flags>>>);
+pub struct IndexCapabilities(Arc<RwLock<FxHashMap<IndexUrl, Capabilities>>>);
+
+/// The capabilities of a single index.
+#[derive(Debug, Copy, Clone)]
+struct Capabilities {
+    /// The capabilities that the index lacks.
+    flags: Flags,
+    /// The number of failed requests to the index.
+    failures: u32,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            flags: Flags::empty(),
+            failures: 0,
+        }
+    }
+}
 
 impl IndexCapabilities {
     /// Returns `true` if the given [`IndexUrl`] supports range requests.
@@ -641,7 +810,7 @@ impl IndexCapabilities {
             .read()
             .unwrap()
             .get(index_url)
-            .is_some_and(|flags| flags.intersects(Flags::NO_RANGE_REQUESTS))
+            .is_some_and(|capabilities| capabilities.flags.intersects(Flags::NO_RANGE_REQUESTS))
     }
 
     /// Mark an [`IndexUrl`] as not supporting range requests.
@@ -650,17 +819,40 @@ impl IndexCapabilities {
             .write()
             .unwrap()
             .entry(index_url)
-            .or_insert(Flags::empty())
+            .or_default()
+            .flags
             .insert(Flags::NO_RANGE_REQUESTS);
     }
 
+    /// Returns `true` if the given [`IndexUrl`] answers `HEAD` requests for files, which are used
+    /// to check for range request support.
+    pub fn supports_head_requests(&self, index_url: &IndexUrl) -> bool {
+        !self
+            .0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|capabilities| capabilities.flags.intersects(Flags::NO_HEAD_REQUESTS))
+    }
+
+    /// Mark an [`IndexUrl`] as not answering `HEAD` requests for files.
+    pub fn set_no_head_requests(&self, index_url: IndexUrl) {
+        self.0
+            .write()
+            .unwrap()
+            .entry(index_url)
+            .or_default()
+            .flags
+            .insert(Flags::NO_HEAD_REQUESTS);
+    }
+
     /// Returns `true` if the given [`IndexUrl`] returns a `401 Unauthorized` status code.
     pub fn unauthorized(&self, index_url: &IndexUrl) -> bool {
         self.0
             .read()
             .unwrap()
             .get(index_url)
-            .is_some_and(|flags| flags.intersects(Flags::UNAUTHORIZED))
+            .is_some_and(|capabilities| capabilities.flags.intersects(Flags::UNAUTHORIZED))
     }
 
     /// Mark an [`IndexUrl`] as returning a `401 Unauthorized` status code.
@@ -669,7 +861,8 @@ impl IndexCapabilities {
             .write()
             .unwrap()
             .entry(index_url)
-            .or_insert(Flags::empty())
+            .or_default()
+            .flags
             .insert(Flags::UNAUTHORIZED);
     }
 
@@ -679,7 +872,7 @@ impl IndexCapabilities {
             .read()
             .unwrap()
             .get(index_url)
-            .is_some_and(|flags| flags.intersects(Flags::FORBIDDEN))
+            .is_some_and(|capabilities| capabilities.flags.intersects(Flags::FORBIDDEN))
     }
 
     /// Mark an [`IndexUrl`] as returning a `403 Forbidden` status code.
@@ -688,15 +881,75 @@ impl IndexCapabilities {
             .write()
             .unwrap()
             .entry(index_url)
-            .or_insert(Flags::empty())
+            .or_default()
+            .flags
             .insert(Flags::FORBIDDEN);
     }
+
+    /// Returns `true` if the given [`IndexUrl`] serves the JSON representation of the Simple API.
+    pub fn supports_json(&self, index_url: &IndexUrl) -> bool {
+        !self
+            .0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|capabilities| capabilities.flags.intersects(Flags::NO_JSON))
+    }
+
+    /// Mark an [`IndexUrl`] as only serving the HTML representation of the Simple API.
+    pub fn set_no_json(&self, index_url: IndexUrl) {
+        self.0
+            .write()
+            .unwrap()
+            .entry(index_url)
+            .or_default()
+            .flags
+            .insert(Flags::NO_JSON);
+    }
+
+    /// Returns `true` if the given [`IndexUrl`] serves hashes for its files.
+    pub fn offers_hashes(&self, index_url: &IndexUrl) -> bool {
+        !self
+            .0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|capabilities| capabilities.flags.intersects(Flags::NO_HASHES))
+    }
+
+    /// Mark an [`IndexUrl`] as serving files without hashes.
+    pub fn set_no_hashes(&self, index_url: IndexUrl) {
+        self.0
+            .write()
+            .unwrap()
+            .entry(index_url)
+            .or_default()
+            .flags
+            .insert(Flags::NO_HASHES);
+    }
+
+    /// Returns the number of failed requests to the given [`IndexUrl`].
+    pub fn failures(&self, index_url: &IndexUrl) -> u32 {
+        self.0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .map_or(0, |capabilities| capabilities.failures)
+    }
+
+    /// Record a failed request to the given [`IndexUrl`], returning the total number of failures.
+    pub fn record_failure(&self, index_url: IndexUrl) -> u32 {
+        let mut map = self.0.write().unwrap();
+        let capabilities = map.entry(index_url).or_default();
+        capabilities.failures += 1;
+        capabilities.failures
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{IndexCacheControl, IndexFormat, IndexName};
+    use crate::{IndexCacheControl, IndexFormat, IndexName, PackagePattern};
     use uv_small_str::SmallString;
 
     #[test]
@@ -753,9 +1006,20 @@ mod tests {
                 default: false,
                 origin: None,
                 format: IndexFormat::Simple,
+                api: IndexApi::default(),
+                verify: IndexVerifyPolicy::default(),
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                credential_provider: None,
+                client_cert: None,
+                ca_cert: None,
+                proxy: None,
+                retry: None,
                 ignore_error_codes: None,
+                priority: None,
+                role: IndexRole::default(),
+                retry_budget: None,
+                packages: Vec::new(),
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
@@ -765,9 +1029,20 @@ mod tests {
                 default: false,
                 origin: None,
                 format: IndexFormat::Simple,
+                api: IndexApi::default(),
+                verify: IndexVerifyPolicy::default(),
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                credential_provider: None,
+                client_cert: None,
+                ca_cert: None,
+                proxy: None,
+                retry: None,
                 ignore_error_codes: None,
+                priority: None,
+                role: IndexRole::default(),
+                retry_budget: None,
+                packages: Vec::new(),
             },
         ];
 
@@ -803,9 +1078,20 @@ mod tests {
             default: false,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }];
 
         let index_urls = IndexUrls::from_indexes(indexes.clone());
@@ -845,9 +1131,20 @@ mod tests {
             default: false,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }];
 
         let index_urls = IndexUrls::from_indexes(indexes.clone());
@@ -887,9 +1184,20 @@ mod tests {
             default: false,
             origin: None,
             format: IndexFormat::Simple,
+            api: IndexApi::default(),
+            verify: IndexVerifyPolicy::default(),
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
+            credential_provider: None,
+            client_cert: None,
+            ca_cert: None,
+            proxy: None,
+            retry: None,
             ignore_error_codes: None,
+            priority: None,
+            role: IndexRole::default(),
+            retry_budget: None,
+            packages: Vec::new(),
         }];
 
         let index_urls = IndexUrls::from_indexes(indexes.clone());
@@ -914,4 +1222,116 @@ mod tests {
             Some("max-age=365000000, immutable, public")
         );
     }
+
+    #[test]
+    fn test_index_priority() {
+        let index = |name: &str, priority: Option<i32>, role: IndexRole| Index {
+            name: Some(IndexName::from_str(name).unwrap()),
+            priority,
+            role,
+            ..Index::from(
+                IndexUrl::from_str(&format!("https://{name}.example.com/simple")).unwrap(),
+            )
+        };
+
+        // Primary indexes (including the default, PyPI) are ordered by priority, followed by the
+        // fallback indexes.
+        let index_urls = IndexUrls::from_indexes(vec![
+            index("first", None, IndexRole::Primary),
+            index("fallback", Some(-10), IndexRole::Fallback),
+            index("second", Some(-1), IndexRole::Primary),
+            index("third", Some(1), IndexRole::Primary),
+        ]);
+        let names = index_urls
+            .indexes()
+            .map(|index| index.url().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "https://second.example.com/simple",
+                "https://first.example.com/simple",
+                "https://pypi.org/simple",
+                "https://third.example.com/simple",
+                "https://fallback.example.com/simple",
+            ]
+        );
+
+        // Indexes only fail over if they set a retry budget.
+        let first = IndexUrl::from_str("https://first.example.com/simple").unwrap();
+        let second = IndexUrl::from_str("https://second.example.com/simple").unwrap();
+        let fallback = IndexUrl::from_str("https://fallback.example.com/simple").unwrap();
+        assert_eq!(index_urls.role_for(&fallback), IndexRole::Fallback);
+        assert_eq!(index_urls.retry_budget_for(&first), None);
+        assert_eq!(index_urls.retry_budget_for(&second), None);
+
+        let index_urls = IndexUrls::from_indexes(vec![
+            Index {
+                retry_budget: Some(3),
+                ..index("first", None, IndexRole::Primary)
+            },
+            index("second", None, IndexRole::Primary),
+            Index {
+                retry_budget: Some(3),
+                ..index("fallback", None, IndexRole::Fallback)
+            },
+        ]);
+        assert_eq!(index_urls.retry_budget_for(&first), Some(3));
+        assert_eq!(index_urls.retry_budget_for(&second), None);
+
+        // The only fallback index can't fail over.
+        assert_eq!(index_urls.retry_budget_for(&fallback), None);
+
+        // Without a fallback index, failures are never skipped.
+        let index_urls = IndexUrls::from_indexes(vec![Index {
+            retry_budget: Some(3),
+            ..index("first", None, IndexRole::Primary)
+        }]);
+        assert_eq!(index_urls.retry_budget_for(&first), None);
+
+        // Failures are counted per index.
+        let capabilities = IndexCapabilities::default();
+        assert_eq!(capabilities.record_failure(first.clone()), 1);
+        assert_eq!(capabilities.record_failure(first.clone()), 2);
+        assert_eq!(capabilities.failures(&first), 2);
+        assert_eq!(capabilities.failures(&fallback), 0);
+    }
+
+    #[test]
+    fn test_pinned_index() {
+        let index = |name: &str, packages: &[&str], explicit: bool| Index {
+            name: Some(IndexName::from_str(name).unwrap()),
+            explicit,
+            packages: packages
+                .iter()
+                .map(|pattern| PackagePattern::from_str(pattern).unwrap())
+                .collect(),
+            ..Index::from(
+                IndexUrl::from_str(&format!("https://{name}.example.com/simple")).unwrap(),
+            )
+        };
+        let index_urls = IndexUrls::from_indexes(vec![
+            index("internal", &["internal-*"], true),
+            index("catch-all", &["*"], false),
+            index("unused", &["internal-*"], false),
+        ]);
+
+        // Packages are pinned to the first matching index, even if it's explicit.
+        let pinned = |name: &str| {
+            index_urls
+                .pinned_index(&PackageName::from_str(name).unwrap())
+                .and_then(|index| index.name.as_ref())
+                .map(ToString::to_string)
+        };
+        assert_eq!(pinned("internal-utils").as_deref(), Some("internal"));
+        assert_eq!(pinned("anyio").as_deref(), Some("catch-all"));
+
+        // Without any patterns, packages aren't pinned.
+        let index_urls = IndexUrls::from_indexes(vec![index("internal", &[], false)]);
+        assert!(
+            index_urls
+                .pinned_index(&PackageName::from_str("internal-utils").unwrap())
+                .is_none()
+        );
+    }
 }
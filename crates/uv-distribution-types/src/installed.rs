@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -15,7 +16,7 @@ use uv_fs::Simplified;
 use uv_install_wheel::WheelFile;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{DirectUrl, MetadataError};
+use uv_pypi_types::{DirectUrl, HashAlgorithm, HashDigest, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::{
@@ -33,6 +34,9 @@ pub enum InstalledDistError {
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 
+    #[error(transparent)]
+    Hash(#[from] uv_pypi_types::HashError),
+
     #[error(transparent)]
     EggInfoParse(#[from] uv_distribution_filename::EggInfoFilenameError),
 
@@ -429,6 +433,31 @@ impl InstalledDist {
         Ok(Some(build_info))
     }
 
+    /// Read the `uv_hashes.json` file from a `.dist-info` directory.
+    ///
+    /// Returns the hashes of the archive from which the distribution was installed, if they were
+    /// known at installation time.
+    pub fn read_hashes(path: &Path) -> Result<Vec<HashDigest>, InstalledDistError> {
+        let path = path.join("uv_hashes.json");
+        let file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let hashes = serde_json::from_reader::<BufReader<fs_err::File>, BTreeMap<String, String>>(
+            BufReader::new(file),
+        )?;
+        hashes
+            .into_iter()
+            .map(|(algorithm, digest)| {
+                Ok(HashDigest {
+                    algorithm: HashAlgorithm::from_str(&algorithm)?,
+                    digest: digest.into(),
+                })
+            })
+            .collect()
+    }
+
     /// Read the `METADATA` file from a `.dist-info` directory.
     pub fn read_metadata(&self) -> Result<&uv_pypi_types::ResolutionMetadata, InstalledDistError> {
         if let Some(metadata) = self.metadata_cache.get() {
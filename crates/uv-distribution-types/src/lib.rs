@@ -72,13 +72,16 @@ pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::known_platform::*;
 pub use crate::origin::*;
+pub use crate::package_pattern::*;
 pub use crate::pip_index::*;
 pub use crate::prioritized_distribution::*;
 pub use crate::requested::*;
 pub use crate::requirement::*;
+pub use crate::requirements_hash::*;
 pub use crate::requires_python::*;
 pub use crate::resolution::*;
 pub use crate::resolved::*;
+pub use crate::retry::*;
 pub use crate::specified_requirement::*;
 pub use crate::status_code_strategy::*;
 pub use crate::traits::*;
@@ -103,13 +106,16 @@ mod index_url;
 mod installed;
 mod known_platform;
 mod origin;
+mod package_pattern;
 mod pip_index;
 mod prioritized_distribution;
 mod requested;
 mod requirement;
+mod requirements_hash;
 mod requires_python;
 mod resolution;
 mod resolved;
+mod retry;
 mod specified_requirement;
 mod status_code_strategy;
 mod traits;
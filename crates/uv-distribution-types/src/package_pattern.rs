@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use glob::Pattern;
+use thiserror::Error;
+
+use uv_normalize::PackageName;
+
+/// A glob pattern that matches package names, like `internal-*`.
+///
+/// Patterns are matched against normalized package names, so the pattern itself is normalized
+/// prior to matching (e.g., `Internal_*` is equivalent to `internal-*`).
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct PackagePattern(Pattern);
+
+impl PackagePattern {
+    /// Parse a [`PackagePattern`] from a glob.
+    pub fn new(pattern: &str) -> Result<Self, PackagePatternError> {
+        let normalized = pattern
+            .chars()
+            .map(|c| match c {
+                '_' | '.' => '-',
+                c => c.to_ascii_lowercase(),
+            })
+            .collect::<String>();
+        Pattern::new(&normalized)
+            .map(Self)
+            .map_err(|err| PackagePatternError(pattern.to_string(), err))
+    }
+
+    /// Returns `true` if the pattern matches the given package name.
+    pub fn matches(&self, name: &PackageName) -> bool {
+        self.0.matches(name.as_str())
+    }
+}
+
+impl FromStr for PackagePattern {
+    type Err = PackagePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl std::fmt::Display for PackagePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for PackagePattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for PackagePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = Cow::<'_, str>::deserialize(deserializer)?;
+        Self::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PackagePattern {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("PackagePattern")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A glob pattern that matches package names, like `internal-*`."
+        })
+    }
+}
+
+/// An error that can occur when parsing a [`PackagePattern`].
+#[derive(Error, Debug)]
+#[error("Invalid package name pattern `{0}`")]
+pub struct PackagePatternError(String, #[source] glob::PatternError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches() {
+        let pattern = PackagePattern::new("Internal_*").unwrap();
+        assert!(pattern.matches(&PackageName::from_str("internal-utils").unwrap()));
+        assert!(pattern.matches(&PackageName::from_str("Internal.Core").unwrap()));
+        assert!(!pattern.matches(&PackageName::from_str("internal").unwrap()));
+        assert!(!pattern.matches(&PackageName::from_str("not-internal-utils").unwrap()));
+
+        let pattern = PackagePattern::new("torch").unwrap();
+        assert!(pattern.matches(&PackageName::from_str("torch").unwrap()));
+        assert!(!pattern.matches(&PackageName::from_str("torchvision").unwrap()));
+
+        assert!(PackagePattern::new("internal-[").is_err());
+    }
+}
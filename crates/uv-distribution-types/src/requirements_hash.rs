@@ -0,0 +1,64 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use uv_pypi_types::HashDigest;
+
+/// An expected hash for a requirements file, as in `requirements.txt=sha256:...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementsHash {
+    /// The path (or URL) of the requirements file, as provided via `-r`.
+    path: PathBuf,
+    /// The expected hash of the file contents.
+    hash: HashDigest,
+}
+
+impl RequirementsHash {
+    /// Return the path of the requirements file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the expected hash of the requirements file.
+    pub fn hash(&self) -> &HashDigest {
+        &self.hash
+    }
+}
+
+impl FromStr for RequirementsHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The digest can't contain `=`, but the path can, so split on the last `=`.
+        let Some((path, hash)) = s.rsplit_once('=').filter(|(path, _)| !path.is_empty()) else {
+            return Err(format!(
+                "Invalid requirements hash: {s} (expected `FILE=ALGORITHM:DIGEST`)"
+            ));
+        };
+        let hash = HashDigest::from_str(hash)
+            .map_err(|err| format!("Invalid requirements hash: {s} ({err})"))?;
+        Ok(Self {
+            path: PathBuf::from(path),
+            hash,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let entry = RequirementsHash::from_str("requirements.txt=sha256:abc").unwrap();
+        assert_eq!(entry.path(), Path::new("requirements.txt"));
+        assert_eq!(entry.hash().to_string(), "sha256:abc");
+
+        // Paths may contain `=`.
+        let entry = RequirementsHash::from_str("a=b.txt=sha256:abc").unwrap();
+        assert_eq!(entry.path(), Path::new("a=b.txt"));
+
+        assert!(RequirementsHash::from_str("sha256:abc").is_err());
+        assert!(RequirementsHash::from_str("=sha256:abc").is_err());
+        assert!(RequirementsHash::from_str("requirements.txt=abc").is_err());
+    }
+}
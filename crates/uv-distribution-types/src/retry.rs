@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::SerializableStatusCode;
+
+/// The policy for retrying failed requests.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RetrySettings {
+    /// The maximum number of times to retry a failed request.
+    ///
+    /// Defaults to `3`, or the value of `UV_HTTP_RETRIES`.
+    pub retries: Option<u32>,
+    /// The strategy for spacing out retries.
+    ///
+    /// With `exponential` backoff (the default), the delay doubles after each retry, starting at
+    /// `min-delay` and capped at `max-delay`, with random jitter. With `constant` backoff, each
+    /// retry waits `min-delay`.
+    pub backoff: Option<RetryBackoff>,
+    /// The delay before the first retry, in seconds.
+    ///
+    /// Defaults to `1`.
+    pub min_delay: Option<u64>,
+    /// The maximum delay between retries, in seconds.
+    ///
+    /// Defaults to `1800`.
+    pub max_delay: Option<u64>,
+    /// The HTTP status codes that should be retried.
+    ///
+    /// Defaults to server errors (`5xx`), `408 Request Timeout`, and `429 Too Many Requests`.
+    /// Responses with other status codes fail immediately.
+    pub retry_on_status: Option<Vec<SerializableStatusCode>>,
+    /// The network errors that should be retried.
+    ///
+    /// Defaults to all of `connect` (failures to establish a connection), `timeout` (requests that
+    /// timed out), and `interrupted` (connections that were reset or closed mid-response).
+    pub retry_on_error: Option<Vec<RetryableError>>,
+}
+
+impl RetrySettings {
+    /// Combine two sets of retry settings, preferring the values in `self`.
+    #[must_use]
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            retries: self.retries.or(other.retries),
+            backoff: self.backoff.or(other.backoff),
+            min_delay: self.min_delay.or(other.min_delay),
+            max_delay: self.max_delay.or(other.max_delay),
+            retry_on_status: self.retry_on_status.or(other.retry_on_status),
+            retry_on_error: self.retry_on_error.or(other.retry_on_error),
+        }
+    }
+}
+
+/// The strategy for spacing out retries.
+#[derive(
+    Debug, Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum RetryBackoff {
+    /// Double the delay after each retry, with random jitter.
+    #[default]
+    Exponential,
+    /// Wait the same delay before each retry.
+    Constant,
+}
+
+/// A class of network error that can be retried.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum RetryableError {
+    /// A connection to the server couldn't be established.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// The connection was reset or closed before the response was complete.
+    Interrupted,
+}
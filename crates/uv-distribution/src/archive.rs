@@ -29,7 +29,7 @@ impl Archive {
 
     /// Returns `true` if the archive exists in the cache.
     pub(crate) fn exists(&self, cache: &Cache) -> bool {
-        self.version == ARCHIVE_VERSION && cache.archive(&self.id).exists()
+        self.version == ARCHIVE_VERSION && cache.find_archive(&self.id).exists()
     }
 }
 
@@ -7,31 +7,33 @@ use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
 use tempfile::TempDir;
-use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
+use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWriteExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    check_index_allowed, check_url_allowed, with_audit_package,
 };
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuildInfo, BuildableSource, BuiltDist, Dist, File, HashPolicy, Hashed, IndexUrl, InstalledDist,
-    Name, SourceDist, ToUrlError,
+    Name, SourceDist, SourceUrl, ToUrlError,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
 
 use crate::archive::Archive;
 use crate::metadata::{ArchiveMetadata, Metadata};
+use crate::remote::RemoteArtifacts;
 use crate::source::SourceDistributionBuilder;
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
@@ -115,10 +117,18 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<LocalWheel, Error> {
-        match dist {
-            Dist::Built(built) => self.get_wheel(built, hashes).await,
-            Dist::Source(source) => self.build_wheel(source, tags, hashes).await,
-        }
+        check_dist_allowed(dist)?;
+        with_audit_package(
+            dist.name(),
+            async {
+                match dist {
+                    Dist::Built(built) => self.get_wheel(built, hashes).await,
+                    Dist::Source(source) => self.build_wheel(source, tags, hashes).await,
+                }
+            }
+            .boxed_local(),
+        )
+        .await
     }
 
     /// Either fetch the only wheel metadata (directly from the index or with range requests) or
@@ -158,13 +168,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &Dist,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        match dist {
-            Dist::Built(built) => self.get_wheel_metadata(built, hashes).await,
-            Dist::Source(source) => {
-                self.build_wheel_metadata(&BuildableSource::Dist(source), hashes)
-                    .await
+        check_dist_allowed(dist)?;
+        with_audit_package(
+            dist.name(),
+            async {
+                match dist {
+                    Dist::Built(built) => self.get_wheel_metadata(built, hashes).await,
+                    Dist::Source(source) => {
+                        self.build_wheel_metadata(&BuildableSource::Dist(source), hashes)
+                            .await
+                    }
+                }
             }
-        }
+            .boxed_local(),
+        )
+        .await
     }
 
     /// Fetch a wheel from the cache or download it from the index.
@@ -209,6 +227,42 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
+                // If a remote cache is configured, fetch the wheel from the remote cache, or
+                // download it to disk, such that it can be uploaded to the remote cache.
+                if extension == WheelExtension::Whl
+                    && let Some(remote) = RemoteArtifacts::new(
+                        self.build_context.cache().remote(),
+                        &wheel.file.filename,
+                        wheel.file.hashes.as_slice(),
+                        self.client.unmanaged,
+                    )
+                {
+                    let archive = self
+                        .remote_wheel(
+                            &remote,
+                            url,
+                            dist.index(),
+                            &wheel.filename,
+                            size,
+                            &wheel_entry,
+                            dist,
+                            hashes,
+                        )
+                        .await?;
+                    return Ok(LocalWheel {
+                        dist: Dist::Built(dist.clone()),
+                        archive: self
+                            .build_context
+                            .cache()
+                            .find_archive(&archive.id)
+                            .into_boxed_path(),
+                        hashes: archive.hashes,
+                        filename: wheel.filename.clone(),
+                        cache: CacheInfo::default(),
+                        build: None,
+                    });
+                }
+
                 // Download and unzip.
                 match self
                     .stream_wheel(
@@ -228,7 +282,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         archive: self
                             .build_context
                             .cache()
-                            .archive(&archive.id)
+                            .find_archive(&archive.id)
                             .into_boxed_path(),
                         hashes: archive.hashes,
                         filename: wheel.filename.clone(),
@@ -258,6 +312,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                None,
                             )
                             .await?;
 
@@ -266,7 +321,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                             archive: self
                                 .build_context
                                 .cache()
-                                .archive(&archive.id)
+                                .find_archive(&archive.id)
                                 .into_boxed_path(),
                             hashes: archive.hashes,
                             filename: wheel.filename.clone(),
@@ -305,7 +360,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         archive: self
                             .build_context
                             .cache()
-                            .archive(&archive.id)
+                            .find_archive(&archive.id)
                             .into_boxed_path(),
                         hashes: archive.hashes,
                         filename: wheel.filename.clone(),
@@ -329,6 +384,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                None,
                             )
                             .await?;
                         Ok(LocalWheel {
@@ -336,7 +392,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                             archive: self
                                 .build_context
                                 .cache()
-                                .archive(&archive.id)
+                                .find_archive(&archive.id)
                                 .into_boxed_path(),
                             hashes: archive.hashes,
                             filename: wheel.filename.clone(),
@@ -544,6 +600,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         source: &BuildableSource<'_>,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
+        check_source_allowed(source)?;
+
         // If the metadata was provided by the user directly, prefer it.
         if let Some(dist) = source.as_dist() {
             if let Some(metadata) = self
@@ -614,8 +672,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                let reader = self
+                    .client
+                    .unmanaged
+                    .cached_client()
+                    .uncached()
+                    .resumable_stream(response)
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -754,7 +816,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(archive)
     }
 
-    /// Download a wheel from a URL, then unzip it into the cache.
+    /// Download a wheel from a URL, hashing it as it is written to disk, then unzip it into the
+    /// cache.
     async fn download_wheel(
         &self,
         url: DisplaySafeUrl,
@@ -765,6 +828,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         wheel_entry: &CacheEntry,
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
+        remote: Option<&RemoteArtifacts<'_>>,
     ) -> Result<Archive, Error> {
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
@@ -785,8 +849,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                let reader = self
+                    .client
+                    .unmanaged
+                    .cached_client()
+                    .uncached()
+                    .resumable_stream(response)
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -798,87 +866,57 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     fs_err::File::from_parts(temp_file, self.build_context.cache().root()),
                 ));
 
+                // Hash the wheel as it's written to disk, so that the extraction below doesn't
+                // need a second pass over the file.
+                let mut algorithms = hashes.algorithms();
+                if remote.is_some() && !algorithms.contains(&HashAlgorithm::Sha256) {
+                    algorithms.push(HashAlgorithm::Sha256);
+                }
+                let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+
                 match progress {
                     Some((reporter, progress)) => {
                         // Wrap the reader in a progress reporter. This will report 100% progress
-                        // after the download is complete, even if we still have to unzip and hash
-                        // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
+                        // after the download is complete, even if we still have to unzip part of
+                        // the file.
+                        let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
 
                         tokio::io::copy(&mut reader, &mut writer)
                             .await
                             .map_err(Error::CacheWrite)?;
                     }
                     None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
+                        tokio::io::copy(&mut hasher, &mut writer)
                             .await
                             .map_err(Error::CacheWrite)?;
                     }
                 }
 
-                // Unzip the wheel to a temporary directory.
-                let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut file = writer.into_inner();
-                file.seek(io::SeekFrom::Start(0))
-                    .await
-                    .map_err(Error::CacheWrite)?;
+                let hashes = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
 
-                // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
-                    let file = file.into_std().await;
-                    tokio::task::spawn_blocking({
-                        let target = temp_dir.path().to_owned();
-                        move || -> Result<(), uv_extract::Error> {
-                            // Unzip the wheel into a temporary directory.
-                            match extension {
-                                WheelExtension::Whl => {
-                                    uv_extract::unzip(file, &target)?;
-                                }
-                                WheelExtension::WhlZst => {
-                                    uv_extract::stream::untar_zst_file(file, &target)?;
-                                }
-                            }
-                            Ok(())
-                        }
-                    })
-                    .await?
-                    .map_err(|err| Error::Extract(filename.to_string(), err))?;
-
-                    HashDigests::empty()
-                } else {
-                    // Create a hasher for each hash algorithm.
-                    let algorithms = hashes.algorithms();
-                    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
+                let mut file = writer.into_inner();
+                file.flush().await.map_err(Error::CacheWrite)?;
 
-                    match extension {
-                        WheelExtension::Whl => {
-                            uv_extract::stream::unzip(&mut hasher, temp_dir.path())
-                                .await
-                                .map_err(|err| Error::Extract(filename.to_string(), err))?;
-                        }
-                        WheelExtension::WhlZst => {
-                            uv_extract::stream::untar_zst(&mut hasher, temp_dir.path())
-                                .await
-                                .map_err(|err| Error::Extract(filename.to_string(), err))?;
-                        }
+                // Share the wheel via the remote cache, if it matches the digest reported by the
+                // index.
+                if let Some(remote) = remote
+                    && hashes.as_slice().contains(remote.sha256())
+                {
+                    match file.try_clone().await {
+                        Ok(file) => remote.put(file).await,
+                        Err(err) => warn!("Failed to read wheel for remote cache: {err}"),
                     }
+                }
 
-                    // If necessary, exhaust the reader to compute the hash.
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
-
-                    hashers.into_iter().map(HashDigest::from).collect()
-                };
-
-                // Persist the temporary directory to the directory store.
+                // The hashes were computed during the download, so the archive can always be
+                // extracted in parallel.
                 let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
-                    .await
-                    .map_err(Error::CacheRead)?;
+                    .extract_wheel_file(file, filename, extension, wheel_entry)
+                    .await?;
 
                 if let Some((reporter, progress)) = progress {
                     reporter.on_download_complete(dist.name(), progress);
@@ -959,6 +997,134 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(archive)
     }
 
+    /// Fetch a registry wheel from the remote cache, or download it to disk and upload it to the
+    /// remote cache, then unzip it into the cache.
+    async fn remote_wheel(
+        &self,
+        remote: &RemoteArtifacts<'_>,
+        url: DisplaySafeUrl,
+        index: Option<&IndexUrl>,
+        filename: &WheelFilename,
+        size: Option<u64>,
+        wheel_entry: &CacheEntry,
+        dist: &BuiltDist,
+        hashes: HashPolicy<'_>,
+    ) -> Result<Archive, Error> {
+        // If the wheel was already fetched from the remote cache, return it.
+        let pointer_entry = wheel_entry.with_file(format!("{}.remote", filename.cache_key()));
+        pointer_entry
+            .materialize()
+            .await
+            .map_err(Error::CacheRead)?;
+        if let Some(archive) = RemoteArchivePointer::read_from(&pointer_entry)?
+            .map(RemoteArchivePointer::into_archive)
+            .filter(|archive| archive.hashes.as_slice().contains(remote.sha256()))
+            .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| archive.exists(self.build_context.cache()))
+        {
+            return Ok(archive);
+        }
+
+        // If the wheel was previously downloaded from the index, skip the remote cache.
+        let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
+        if !http_entry.path().is_file() {
+            let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
+                .map_err(Error::CacheWrite)?;
+            let mut file = fs_err::tokio::File::from_std(
+                // It's an unnamed file on Linux so that's the best approximation.
+                fs_err::File::from_parts(temp_file, self.build_context.cache().root()),
+            );
+            if remote.get(&mut file).await {
+                debug!("Using wheel from remote cache for: {dist}");
+
+                // Compute any other hashes required by the hash policy.
+                let mut algorithms = hashes.algorithms();
+                if !algorithms.contains(&HashAlgorithm::Sha256) {
+                    algorithms.push(HashAlgorithm::Sha256);
+                }
+                file.seek(io::SeekFrom::Start(0))
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                let mut hasher = uv_extract::hash::HashReader::new(&mut file, &mut hashers);
+                hasher.finish().await.map_err(Error::HashExhaustion)?;
+                let hashes = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+
+                let id = self
+                    .extract_wheel_file(file, filename, WheelExtension::Whl, wheel_entry)
+                    .await?;
+                let archive = Archive::new(id, hashes, filename.clone());
+
+                // Write the archive pointer to the cache.
+                RemoteArchivePointer {
+                    archive: archive.clone(),
+                }
+                .write_to(&pointer_entry)
+                .await?;
+
+                return Ok(archive);
+            }
+        }
+
+        self.download_wheel(
+            url,
+            index,
+            filename,
+            WheelExtension::Whl,
+            size,
+            wheel_entry,
+            dist,
+            hashes,
+            Some(remote),
+        )
+        .await
+    }
+
+    /// Unzip a wheel from a file into the cache, returning the ID of the unzipped directory.
+    async fn extract_wheel_file(
+        &self,
+        mut file: fs_err::tokio::File,
+        filename: &WheelFilename,
+        extension: WheelExtension,
+        wheel_entry: &CacheEntry,
+    ) -> Result<ArchiveId, Error> {
+        // Unzip the wheel to a temporary directory.
+        let temp_dir =
+            tempfile::tempdir_in(self.build_context.cache().root()).map_err(Error::CacheWrite)?;
+        file.seek(io::SeekFrom::Start(0))
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        let file = file.into_std().await;
+        tokio::task::spawn_blocking({
+            let target = temp_dir.path().to_owned();
+            move || -> Result<(), uv_extract::Error> {
+                // Unzip the wheel into a temporary directory.
+                match extension {
+                    WheelExtension::Whl => {
+                        uv_extract::unzip(file, &target)?;
+                    }
+                    WheelExtension::WhlZst => {
+                        uv_extract::stream::untar_zst_file(file, &target)?;
+                    }
+                }
+                Ok(())
+            }
+        })
+        .await?
+        .map_err(|err| Error::Extract(filename.to_string(), err))?;
+
+        // Persist the temporary directory to the directory store.
+        self.build_context
+            .cache()
+            .persist(temp_dir.keep(), wheel_entry.path())
+            .await
+            .map_err(Error::CacheRead)
+    }
+
     /// Load a wheel from a local path.
     async fn load_wheel(
         &self,
@@ -980,13 +1146,18 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         // Attempt to read the archive pointer from the cache.
         let pointer_entry = wheel_entry.with_file(format!("{}.rev", filename.cache_key()));
+        pointer_entry
+            .materialize()
+            .await
+            .map_err(Error::CacheRead)?;
         let pointer = LocalArchivePointer::read_from(&pointer_entry)?;
 
         // Extract the archive from the pointer.
         let archive = pointer
             .filter(|pointer| pointer.is_up_to_date(modified))
             .map(LocalArchivePointer::into_archive)
-            .filter(|archive| archive.has_digests(hashes));
+            .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| archive.exists(self.build_context.cache()));
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
@@ -995,7 +1166,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 archive: self
                     .build_context
                     .cache()
-                    .archive(&archive.id)
+                    .find_archive(&archive.id)
                     .into_boxed_path(),
                 hashes: archive.hashes,
                 filename: filename.clone(),
@@ -1022,7 +1193,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 archive: self
                     .build_context
                     .cache()
-                    .archive(&archive.id)
+                    .find_archive(&archive.id)
                     .into_boxed_path(),
                 hashes: archive.hashes,
                 filename: filename.clone(),
@@ -1084,7 +1255,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 archive: self
                     .build_context
                     .cache()
-                    .archive(&archive.id)
+                    .find_archive(&archive.id)
                     .into_boxed_path(),
                 hashes: archive.hashes,
                 filename: filename.clone(),
@@ -1186,6 +1357,31 @@ impl<'a> ManagedClient<'a> {
 }
 
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
+/// Return an error if the distribution's index or URL is not allowed by the organization policy.
+fn check_dist_allowed(dist: &Dist) -> Result<(), uv_client::Error> {
+    match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => check_index_allowed(&wheels.best_wheel().index),
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => check_url_allowed(&wheel.location),
+        Dist::Built(BuiltDist::Path(_)) => Ok(()),
+        Dist::Source(source) => check_source_allowed(&BuildableSource::Dist(source)),
+    }
+}
+
+/// Return an error if the source's index or URL is not allowed by the organization policy.
+fn check_source_allowed(source: &BuildableSource<'_>) -> Result<(), uv_client::Error> {
+    match source {
+        BuildableSource::Dist(SourceDist::Registry(sdist)) => check_index_allowed(&sdist.index),
+        BuildableSource::Dist(SourceDist::DirectUrl(sdist)) => check_url_allowed(&sdist.location),
+        BuildableSource::Dist(SourceDist::Git(sdist)) => check_url_allowed(sdist.git.repository()),
+        BuildableSource::Url(SourceUrl::Direct(resource)) => check_url_allowed(resource.url),
+        BuildableSource::Url(SourceUrl::Git(resource)) => {
+            check_url_allowed(resource.git.repository())
+        }
+        BuildableSource::Dist(SourceDist::Path(_) | SourceDist::Directory(_))
+        | BuildableSource::Url(SourceUrl::Path(_) | SourceUrl::Directory(_)) => Ok(()),
+    }
+}
+
 fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
         .headers()
@@ -1315,6 +1511,37 @@ impl LocalArchivePointer {
     }
 }
 
+/// A pointer to an archive in the cache, fetched from the remote cache.
+///
+/// Encoded with `MsgPack`, and represented on disk by a `.remote` file.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct RemoteArchivePointer {
+    archive: Archive,
+}
+
+impl RemoteArchivePointer {
+    /// Read a [`RemoteArchivePointer`] from the cache.
+    fn read_from(entry: &CacheEntry) -> Result<Option<Self>, Error> {
+        match fs_err::read(entry.path()) {
+            Ok(cached) => Ok(Some(rmp_serde::from_slice::<Self>(&cached)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(Error::CacheRead(err)),
+        }
+    }
+
+    /// Write a [`RemoteArchivePointer`] to the cache.
+    async fn write_to(&self, entry: &CacheEntry) -> Result<(), Error> {
+        write_atomic(entry.path(), rmp_serde::to_vec(&self)?)
+            .await
+            .map_err(Error::CacheWrite)
+    }
+
+    /// Return the [`Archive`] from the pointer.
+    fn into_archive(self) -> Archive {
+        self.archive
+    }
+}
+
 #[derive(Debug, Clone)]
 struct WheelTarget {
     /// The URL from which the wheel can be downloaded.
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::process::Command;
+use std::sync::OnceLock;
+
+use tracing::debug;
+
+use uv_cache_key::cache_digest;
+use uv_configuration::BuildFingerprint;
+
+/// The process-wide inputs to the build environment fingerprint.
+static FINGERPRINT: OnceLock<BuildFingerprint> = OnceLock::new();
+
+/// The digest of the build environment fingerprint, computed on first use.
+static DIGEST: OnceLock<Option<String>> = OnceLock::new();
+
+/// Initialize the inputs to the build environment fingerprint.
+///
+/// Returns the inputs back if they were already initialized.
+pub fn init_build_fingerprint(fingerprint: BuildFingerprint) -> Result<(), BuildFingerprint> {
+    FINGERPRINT.set(fingerprint)
+}
+
+/// Return the digest of the build environment fingerprint, if any inputs were configured.
+///
+/// The digest is computed once per process, since evaluating the configured commands may be
+/// expensive.
+pub(crate) fn build_fingerprint() -> Option<&'static str> {
+    DIGEST
+        .get_or_init(|| {
+            let fingerprint = FINGERPRINT
+                .get()
+                .filter(|fingerprint| !fingerprint.is_empty())?;
+            Some(digest(fingerprint))
+        })
+        .as_deref()
+}
+
+/// Compute the digest of the given [`BuildFingerprint`].
+fn digest(fingerprint: &BuildFingerprint) -> String {
+    let mut inputs = BTreeMap::new();
+    for var in &fingerprint.env {
+        inputs.insert(format!("env:{var}"), std::env::var(var).ok());
+    }
+    for command in &fingerprint.commands {
+        inputs.insert(format!("command:{command}"), run(command));
+    }
+    let digest = cache_digest(&inputs);
+    debug!("Using build environment fingerprint: {digest}");
+    digest
+}
+
+/// Run a shell command, returning its output, or `None` if the command failed.
+fn run(command: &str) -> Option<String> {
+    let output = if cfg!(windows) {
+        Command::new("cmd").arg("/C").arg(command).output()
+    } else {
+        Command::new("sh").arg("-c").arg(command).output()
+    };
+    match output {
+        Ok(output) if output.status.success() => {
+            let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            Some(text)
+        }
+        Ok(output) => {
+            debug!(
+                "Build fingerprint command `{command}` failed ({})",
+                output.status
+            );
+            None
+        }
+        Err(err) => {
+            debug!("Failed to run build fingerprint command `{command}`: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_configuration::BuildFingerprint;
+
+    use super::digest;
+
+    #[test]
+    fn digest_commands() {
+        let fingerprint = |command: &str| BuildFingerprint {
+            env: vec![],
+            commands: vec![command.to_string()],
+        };
+        assert_eq!(
+            digest(&fingerprint("echo 1.0")),
+            digest(&fingerprint("echo 1.0"))
+        );
+        assert_ne!(
+            digest(&fingerprint("echo 1.0")),
+            digest(&fingerprint("echo 2.0"))
+        );
+    }
+}
@@ -13,6 +13,7 @@ use uv_pypi_types::HashDigests;
 use uv_types::HashStrategy;
 
 use crate::Error;
+use crate::fingerprint::build_fingerprint;
 use crate::index::cached_wheel::{CachedWheel, ResolvedWheel};
 use crate::source::{HTTP_REVISION, HttpRevisionPointer, LOCAL_REVISION, LocalRevisionPointer};
 
@@ -62,8 +63,11 @@ impl<'a> BuiltWheelIndex<'a> {
         );
 
         // Read the revision from the cache.
-        let Some(pointer) = HttpRevisionPointer::read_from(cache_shard.entry(HTTP_REVISION))?
-        else {
+        let revision_entry = cache_shard.entry(HTTP_REVISION);
+        revision_entry
+            .materialize_sync()
+            .map_err(Error::CacheRead)?;
+        let Some(pointer) = HttpRevisionPointer::read_from(&revision_entry)? else {
             return Ok(None);
         };
 
@@ -80,7 +84,8 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -104,8 +109,11 @@ impl<'a> BuiltWheelIndex<'a> {
         );
 
         // Read the revision from the cache.
-        let Some(pointer) = LocalRevisionPointer::read_from(cache_shard.entry(LOCAL_REVISION))?
-        else {
+        let revision_entry = cache_shard.entry(LOCAL_REVISION);
+        revision_entry
+            .materialize_sync()
+            .map_err(Error::CacheRead)?;
+        let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? else {
             return Ok(None);
         };
 
@@ -129,7 +137,8 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -156,8 +165,11 @@ impl<'a> BuiltWheelIndex<'a> {
         );
 
         // Read the revision from the cache.
-        let Some(pointer) = LocalRevisionPointer::read_from(cache_shard.entry(LOCAL_REVISION))?
-        else {
+        let revision_entry = cache_shard.entry(LOCAL_REVISION);
+        revision_entry
+            .materialize_sync()
+            .map_err(Error::CacheRead)?;
+        let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? else {
             return Ok(None);
         };
 
@@ -180,7 +192,8 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -210,7 +223,8 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -246,7 +260,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let mut candidate: Option<ResolvedWheel> = None;
 
         // Unzipped wheels are stored as symlinks into the archive directory.
-        for wheel_dir in uv_fs::entries(shard).ok().into_iter().flatten() {
+        for wheel_dir in shard.list(uv_fs::entries).ok().into_iter().flatten() {
             // Ignore any `.lock` files.
             if wheel_dir
                 .extension()
@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use uv_cache::{Cache, CacheBucket, CacheEntry};
+use uv_cache::{Cache, CacheEntry};
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
@@ -83,7 +83,7 @@ impl CachedWheel {
         }
 
         let Archive { id, hashes, .. } = archive;
-        let entry = cache.entry(CacheBucket::Archive, "", id);
+        let entry = CacheEntry::from_path(cache.find_archive(&id));
 
         // Convert to a cached wheel.
         Some(Self {
@@ -111,7 +111,7 @@ impl CachedWheel {
         }
 
         let Archive { id, hashes, .. } = archive;
-        let entry = cache.entry(CacheBucket::Archive, "", id);
+        let entry = CacheEntry::from_path(cache.find_archive(&id));
 
         // Convert to a cached wheel.
         Some(Self {
@@ -121,7 +121,7 @@ impl<'a> RegistryWheelIndex<'a> {
 
             // For registry wheels, the cache structure is: `<index>/<package-name>/<wheel>.http`
             // or `<index>/<package-name>/<version>/<wheel>.rev`.
-            for file in files(&wheel_dir).ok().into_iter().flatten() {
+            for file in wheel_dir.list(files).ok().into_iter().flatten() {
                 match index.url() {
                     // Add files from remote registries.
                     IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
@@ -188,7 +188,7 @@ impl<'a> RegistryWheelIndex<'a> {
             );
 
             // For registry source distributions, the cache structure is: `<index>/<package-name>/<version>/`.
-            for shard in directories(&cache_shard).ok().into_iter().flatten() {
+            for shard in cache_shard.list(directories).ok().into_iter().flatten() {
                 let cache_shard = cache_shard.shard(shard);
 
                 // Read the revision from the cache.
@@ -236,7 +236,7 @@ impl<'a> RegistryWheelIndex<'a> {
                         .map(|digest| cache_shard.shard(digest))
                         .unwrap_or(cache_shard);
 
-                    for wheel_dir in uv_fs::entries(cache_shard).ok().into_iter().flatten() {
+                    for wheel_dir in cache_shard.list(uv_fs::entries).ok().into_iter().flatten() {
                         // Ignore any `.lock` files.
                         if wheel_dir
                             .extension()
@@ -1,6 +1,7 @@
 pub use distribution_database::{DistributionDatabase, HttpArchivePointer, LocalArchivePointer};
 pub use download::LocalWheel;
 pub use error::Error;
+pub use fingerprint::init_build_fingerprint;
 pub use index::{BuiltWheelIndex, RegistryWheelIndex};
 pub use metadata::{
     ArchiveMetadata, BuildRequires, FlatRequiresDist, LoweredExtraBuildDependencies,
@@ -14,7 +15,9 @@ mod archive;
 mod distribution_database;
 mod download;
 mod error;
+mod fingerprint;
 mod index;
 mod metadata;
+mod remote;
 mod reporter;
 mod source;
@@ -8,7 +8,7 @@ use uv_configuration::NoSources;
 use uv_distribution_types::{GitSourceUrl, IndexLocations, Requirement};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
-use uv_pypi_types::{HashDigests, ResolutionMetadata};
+use uv_pypi_types::{HashDigests, License, ProjectLinks, ResolutionMetadata};
 use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::{WorkspaceCache, WorkspaceError};
 
@@ -64,6 +64,8 @@ pub struct Metadata {
     pub provides_extra: Box<[ExtraName]>,
     pub dependency_groups: BTreeMap<GroupName, Box<[Requirement]>>,
     pub dynamic: bool,
+    pub license: Option<License>,
+    pub project_links: Option<ProjectLinks>,
 }
 
 impl Metadata {
@@ -80,6 +82,8 @@ impl Metadata {
             provides_extra: metadata.provides_extra,
             dependency_groups: BTreeMap::default(),
             dynamic: metadata.dynamic,
+            license: metadata.license,
+            project_links: metadata.project_links,
         }
     }
 
@@ -127,6 +131,8 @@ impl Metadata {
             provides_extra,
             dependency_groups,
             dynamic,
+            license: metadata.license,
+            project_links: metadata.project_links,
         })
     }
 }
@@ -0,0 +1,750 @@
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use futures::StreamExt;
+use reqwest::header::{AUTHORIZATION, CONTENT_LENGTH, HeaderValue};
+use reqwest::{Body, Request, Response, StatusCode};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+use tracing::{debug, warn};
+
+use uv_auth::{Credentials, S3Bucket};
+use uv_cache_key::cache_digest;
+use uv_client::{Connectivity, RegistryClient};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::BuildInfo;
+use uv_extract::hash::Hasher;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest, ResolutionMetadata};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::fingerprint::build_fingerprint;
+
+/// The version of the remote cache layout for wheels built from source distributions.
+const SDISTS_VERSION: &str = "sdists-v2";
+
+/// The version of the remote cache layout for distributions downloaded from indexes.
+const ARTIFACTS_VERSION: &str = "artifacts-v1";
+
+/// A remote cache for wheels built from source distributions, shared across machines.
+///
+/// Entries are stored under a key derived from the source distribution URL, its SHA-256 digest,
+/// its build settings, and the build environment fingerprint (as used for the local cache of
+/// built wheels):
+///
+/// - `{remote}/sdists-v2/{key}/metadata.msgpack`: the metadata for the source distribution.
+/// - `{remote}/sdists-v2/{key}/{tags}`: the filename and SHA-256 digest of a wheel built for the
+///   given tags, one per line.
+/// - `{remote}/sdists-v2/{key}/{filename}`: the built wheel itself.
+///
+/// All operations are best-effort: failures are logged and treated as cache misses.
+pub(crate) struct RemoteCache<'a> {
+    store: RemoteStore<'a>,
+}
+
+impl<'a> RemoteCache<'a> {
+    /// Initialize a [`RemoteCache`] for the given source distribution, if a remote cache is
+    /// configured and the client is online.
+    ///
+    /// The source distribution must have a SHA-256 digest, such that entries are never shared
+    /// between different archives served from the same URL.
+    pub(crate) fn new(
+        remote: Option<&DisplaySafeUrl>,
+        url: &DisplaySafeUrl,
+        hashes: &[HashDigest],
+        subdirectory: Option<&Path>,
+        build_info: &BuildInfo,
+        client: &'a RegistryClient,
+    ) -> Option<Self> {
+        let remote = remote?;
+        let Some(sha256) = hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        else {
+            debug!("Skipping remote cache for source distribution without a SHA-256 digest: {url}");
+            return None;
+        };
+
+        let key = cache_digest(&(
+            url.without_credentials().as_str(),
+            sha256.digest.as_ref(),
+            subdirectory,
+            build_info.cache_shard(),
+            build_fingerprint(),
+        ));
+        let store = RemoteStore::new(remote, &format!("{SDISTS_VERSION}/{key}/"), client)?;
+        Some(Self { store })
+    }
+
+    /// Fetch the metadata for the source distribution.
+    pub(crate) async fn metadata(&self) -> Option<ResolutionMetadata> {
+        let bytes = self.store.get("metadata.msgpack").await?;
+        match rmp_serde::from_slice::<ResolutionMetadata>(&bytes) {
+            Ok(metadata) => Some(metadata),
+            Err(err) => {
+                warn!("Failed to deserialize metadata from remote cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Upload the metadata for the source distribution.
+    pub(crate) async fn put_metadata(&self, metadata: &ResolutionMetadata) {
+        match rmp_serde::to_vec(metadata) {
+            Ok(bytes) => self.store.put("metadata.msgpack", bytes).await,
+            Err(err) => warn!("Failed to serialize metadata for remote cache: {err}"),
+        }
+    }
+
+    /// Download a wheel compatible with the given tags into the given directory, returning its
+    /// filename.
+    ///
+    /// The wheel must match the name and version of the source distribution, if known. It's
+    /// streamed to disk, and verified against the SHA-256 digest recorded when it was uploaded.
+    pub(crate) async fn wheel(
+        &self,
+        tags: &Tags,
+        name: Option<&PackageName>,
+        version: Option<&Version>,
+        dir: &Path,
+    ) -> Option<WheelFilename> {
+        let key = tags_key(tags)?;
+        let pointer = self.store.get(&key).await?;
+        let Some((filename, digest)) = std::str::from_utf8(&pointer)
+            .ok()
+            .and_then(|pointer| pointer.trim().split_once('\n'))
+            .and_then(|(filename, digest)| {
+                Some((
+                    WheelFilename::from_str(filename.trim()).ok()?,
+                    HashDigest::from_str(digest.trim()).ok()?,
+                ))
+            })
+            .filter(|(_, digest)| digest.algorithm == HashAlgorithm::Sha256)
+        else {
+            warn!("Found invalid wheel pointer in remote cache at: {key}");
+            return None;
+        };
+        if !filename.is_compatible(tags) {
+            debug!("Ignoring incompatible wheel in remote cache: {filename}");
+            return None;
+        }
+        if name.is_some_and(|name| filename.name != *name)
+            || version.is_some_and(|version| filename.version != *version)
+        {
+            warn!("Ignoring wheel with mismatched name or version in remote cache: {filename}");
+            return None;
+        }
+
+        let temp_file = match tempfile::NamedTempFile::new_in(dir) {
+            Ok(temp_file) => temp_file,
+            Err(err) => {
+                warn!("Failed to create temporary file for remote cache: {err}");
+                return None;
+            }
+        };
+        let mut file = match fs_err::tokio::File::create(temp_file.path()).await {
+            Ok(file) => file,
+            Err(err) => {
+                warn!("Failed to create temporary file for remote cache: {err}");
+                return None;
+            }
+        };
+        if !self
+            .store
+            .get_file(&filename.to_string(), &digest, &mut file)
+            .await
+        {
+            return None;
+        }
+        if let Err(err) = temp_file.persist(dir.join(filename.to_string())) {
+            warn!("Failed to write wheel from remote cache: {err}");
+            return None;
+        }
+        Some(filename)
+    }
+
+    /// Upload a wheel built for the given tags.
+    pub(crate) async fn put_wheel(&self, tags: &Tags, path: &Path, filename: &WheelFilename) {
+        let Some(key) = tags_key(tags) else {
+            return;
+        };
+        let mut file = match fs_err::tokio::File::open(path).await {
+            Ok(file) => file,
+            Err(err) => {
+                warn!("Failed to read wheel for remote cache: {err}");
+                return;
+            }
+        };
+        let digest = match sha256_file(&mut file).await {
+            Ok(digest) => digest,
+            Err(err) => {
+                warn!("Failed to read wheel for remote cache: {err}");
+                return;
+            }
+        };
+        // Upload the wheel before the pointer, such that readers never observe a dangling pointer.
+        if !self.store.put_file(&filename.to_string(), file).await {
+            return;
+        }
+        let pointer = format!("{filename}\n{digest}\n");
+        self.store.put(&key, pointer.into_bytes()).await;
+    }
+}
+
+/// A remote cache for distributions downloaded from indexes, shared across machines.
+///
+/// Entries are addressed by the SHA-256 digest reported by the index, as
+/// `{remote}/artifacts-v1/{sha256}/{filename}`, such that the same distribution is shared across
+/// mirrors of the same index.
+///
+/// All operations are best-effort: failures are logged and treated as cache misses.
+pub(crate) struct RemoteArtifacts<'a> {
+    store: RemoteStore<'a>,
+    filename: String,
+    sha256: HashDigest,
+}
+
+impl<'a> RemoteArtifacts<'a> {
+    /// Initialize a [`RemoteArtifacts`] for the given distribution, if a remote cache is configured,
+    /// the client is online, and the index reports a SHA-256 digest for the distribution.
+    pub(crate) fn new(
+        remote: Option<&DisplaySafeUrl>,
+        filename: &str,
+        hashes: &[HashDigest],
+        client: &'a RegistryClient,
+    ) -> Option<Self> {
+        let remote = remote?;
+        let sha256 = hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)?
+            .clone();
+        let store = RemoteStore::new(
+            remote,
+            &format!("{ARTIFACTS_VERSION}/{}/", sha256.digest),
+            client,
+        )?;
+        Some(Self {
+            store,
+            filename: filename.to_string(),
+            sha256,
+        })
+    }
+
+    /// The SHA-256 digest of the distribution.
+    pub(crate) fn sha256(&self) -> &HashDigest {
+        &self.sha256
+    }
+
+    /// Download the distribution into the given file, returning `true` if it was found and
+    /// matches the expected SHA-256 digest.
+    ///
+    /// On failure, the file may contain partial data.
+    pub(crate) async fn get(&self, file: &mut fs_err::tokio::File) -> bool {
+        self.store
+            .get_file(&self.filename, &self.sha256, file)
+            .await
+    }
+
+    /// Upload the distribution from the given file, which must match the expected SHA-256 digest.
+    pub(crate) async fn put(&self, file: fs_err::tokio::File) {
+        self.store.put_file(&self.filename, file).await;
+    }
+}
+
+/// The storage backend of a remote cache.
+enum Backend {
+    /// An HTTP server that supports `GET` and `PUT` requests, with the `Authorization` header for
+    /// the credentials embedded in the remote cache URL, if any.
+    Http(Option<HeaderValue>),
+    /// An S3 bucket, addressed via an `s3://{bucket}/{prefix}` URL.
+    S3(S3Bucket),
+}
+
+/// A prefix in a remote cache, with entries fetched and uploaded via `GET` and `PUT` requests.
+struct RemoteStore<'a> {
+    /// The root URL of the entries, without credentials.
+    root: DisplaySafeUrl,
+    backend: Backend,
+    client: &'a RegistryClient,
+}
+
+impl<'a> RemoteStore<'a> {
+    /// Initialize a [`RemoteStore`] for the given prefix of the remote cache, if the client is
+    /// online.
+    fn new(remote: &DisplaySafeUrl, prefix: &str, client: &'a RegistryClient) -> Option<Self> {
+        if client.connectivity() == Connectivity::Offline {
+            return None;
+        }
+        let (mut base, backend) = if let Some(bucket) = S3Bucket::from_url(remote) {
+            (bucket.url().clone(), Backend::S3(bucket))
+        } else {
+            let authorization = Credentials::from_url(remote).map(|credentials| {
+                let mut header = credentials.to_header_value();
+                header.set_sensitive(true);
+                header
+            });
+            (
+                DisplaySafeUrl::from_url(remote.without_credentials().into_owned()),
+                Backend::Http(authorization),
+            )
+        };
+        if !base.path().ends_with('/') {
+            let path = format!("{}/", base.path());
+            base.set_path(&path);
+        }
+        let root = base.join(prefix).ok()?;
+        Some(Self {
+            root,
+            backend,
+            client,
+        })
+    }
+
+    /// Apply the credentials for the remote cache to the given request.
+    async fn authorize(&self, mut request: Request) -> Option<Request> {
+        match &self.backend {
+            Backend::Http(authorization) => {
+                if let Some(authorization) = authorization {
+                    request
+                        .headers_mut()
+                        .insert(AUTHORIZATION, authorization.clone());
+                }
+                Some(request)
+            }
+            Backend::S3(bucket) => match bucket.sign(request).await {
+                Ok(request) => Some(request),
+                Err(err) => {
+                    warn!("Failed to sign request to remote cache: {err}");
+                    None
+                }
+            },
+        }
+    }
+
+    /// Fetch the entry at the given path, returning `None` if it doesn't exist.
+    async fn fetch(&self, path: &str) -> Option<Response> {
+        let url = self.root.join(path).ok()?;
+        let client = self.client.uncached_client(&url);
+        let request = match client.get(url.as_str()).build() {
+            Ok(request) => request,
+            Err(err) => {
+                warn!("Failed to fetch `{url}` from remote cache: {err}");
+                return None;
+            }
+        };
+        let request = self.authorize(request).await?;
+        let response = match client.execute(request).await {
+            Ok(response) => response,
+            Err(err) => {
+                warn!("Failed to fetch `{url}` from remote cache: {err}");
+                return None;
+            }
+        };
+        if response.status() == StatusCode::NOT_FOUND {
+            debug!("Remote cache miss for: {url}");
+            return None;
+        }
+        match response.error_for_status() {
+            Ok(response) => {
+                debug!("Remote cache hit for: {url}");
+                Some(response)
+            }
+            Err(err) => {
+                warn!("Failed to fetch `{url}` from remote cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Fetch a small entry (e.g., metadata or a pointer) into memory.
+    async fn get(&self, path: &str) -> Option<Vec<u8>> {
+        let response = self.fetch(path).await?;
+        match response.bytes().await {
+            Ok(bytes) => Some(bytes.to_vec()),
+            Err(err) => {
+                warn!("Failed to read `{path}` from remote cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Stream the entry at the given path into the given file, returning `true` if it was found
+    /// and matches the expected SHA-256 digest.
+    async fn get_file(
+        &self,
+        path: &str,
+        expected: &HashDigest,
+        file: &mut fs_err::tokio::File,
+    ) -> bool {
+        let Some(response) = self.fetch(path).await else {
+            return false;
+        };
+        let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    warn!("Failed to read `{path}` from remote cache: {err}");
+                    return false;
+                }
+            };
+            hasher.update(&chunk);
+            if let Err(err) = file.write_all(&chunk).await {
+                warn!("Failed to write `{path}` from remote cache: {err}");
+                return false;
+            }
+        }
+        if let Err(err) = file.flush().await {
+            warn!("Failed to write `{path}` from remote cache: {err}");
+            return false;
+        }
+        let actual = HashDigest::from(hasher);
+        if actual != *expected {
+            warn!(
+                "Ignoring `{path}` from remote cache with mismatched digest (expected `{expected}`, found `{actual}`)"
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Upload a small entry (e.g., metadata or a pointer).
+    async fn put(&self, path: &str, bytes: Vec<u8>) {
+        let len = bytes.len() as u64;
+        self.send(path, Body::from(bytes), len).await;
+    }
+
+    /// Stream the given file to the entry at the given path, from the start of the file,
+    /// returning `true` if the upload succeeded.
+    async fn put_file(&self, path: &str, mut file: fs_err::tokio::File) -> bool {
+        let len = match file.seek(io::SeekFrom::Start(0)).await {
+            Ok(_) => file.metadata().await.map(|metadata| metadata.len()),
+            Err(err) => Err(err),
+        };
+        let len = match len {
+            Ok(len) => len,
+            Err(err) => {
+                warn!("Failed to read `{path}` for remote cache: {err}");
+                return false;
+            }
+        };
+        self.send(path, Body::wrap_stream(ReaderStream::new(file)), len)
+            .await
+    }
+
+    /// Upload an entry to the given path, returning `true` if the upload succeeded.
+    ///
+    /// Streaming bodies can't be retried, so uploads bypass the client middleware.
+    async fn send(&self, path: &str, body: Body, len: u64) -> bool {
+        let Ok(url) = self.root.join(path) else {
+            return false;
+        };
+        let client = self
+            .client
+            .cached_client()
+            .uncached()
+            .raw_client_for_host(&url);
+        let request = match client
+            .put(url.as_str())
+            .header(CONTENT_LENGTH, len)
+            .body(body)
+            .build()
+        {
+            Ok(request) => request,
+            Err(err) => {
+                warn!("Failed to upload `{url}` to remote cache: {err}");
+                return false;
+            }
+        };
+        let Some(request) = self.authorize(request).await else {
+            return false;
+        };
+        match client
+            .execute(request)
+            .await
+            .and_then(Response::error_for_status)
+        {
+            Ok(_) => {
+                debug!("Uploaded `{url}` to remote cache");
+                true
+            }
+            Err(err) => {
+                warn!("Failed to upload `{url}` to remote cache: {err}");
+                false
+            }
+        }
+    }
+}
+
+/// Compute the SHA-256 digest of the given file, from its current position.
+async fn sha256_file(file: &mut fs_err::tokio::File) -> io::Result<HashDigest> {
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(HashDigest::from(hasher))
+}
+
+/// Return the key for the wheel pointer for the given tags (e.g., `cp312-cp312-manylinux_2_17_x86_64`).
+fn tags_key(tags: &Tags) -> Option<String> {
+    Some(format!(
+        "{}-{}-{}",
+        tags.python_tag()?,
+        tags.abi_tag()?,
+        tags.platform_tag()?
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use wiremock::matchers::{header, method, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use uv_cache::Cache;
+    use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+    use uv_distribution_filename::WheelFilename;
+    use uv_distribution_types::BuildInfo;
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+    use uv_platform_tags::{Arch, Os, Platform, Tags};
+    use uv_redacted::DisplaySafeUrl;
+
+    use uv_extract::hash::Hasher;
+    use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+    use super::{RemoteArtifacts, RemoteCache};
+
+    /// `user:pass`, encoded for Basic authentication.
+    const AUTHORIZATION: &str = "Basic dXNlcjpwYXNz";
+
+    /// Compute the SHA-256 digest of the given bytes.
+    fn sha256(bytes: &[u8]) -> HashDigest {
+        let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+        hasher.update(bytes);
+        HashDigest::from(hasher)
+    }
+
+    fn tags() -> Tags {
+        let platform = Platform::new(
+            Os::Manylinux {
+                major: 2,
+                minor: 28,
+            },
+            Arch::X86_64,
+        );
+        Tags::from_env(&platform, (3, 12), "cpython", (3, 12), true, false, false).unwrap()
+    }
+
+    #[tokio::test]
+    async fn wheel_round_trip() {
+        let server = MockServer::start().await;
+        let cache = Cache::temp().unwrap().init().await.unwrap();
+        let client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone()).build();
+        let remote =
+            DisplaySafeUrl::parse(&format!("http://user:pass@{}/cache", server.address())).unwrap();
+        let url = DisplaySafeUrl::parse("https://example.com/example-1.0.0.tar.gz").unwrap();
+        let build_info = BuildInfo::default();
+
+        // Without a SHA-256 digest for the source distribution, the remote cache is skipped.
+        assert!(RemoteCache::new(Some(&remote), &url, &[], None, &build_info, &client).is_none());
+
+        let digest = sha256(b"source");
+        let remote_cache = RemoteCache::new(
+            Some(&remote),
+            &url,
+            std::slice::from_ref(&digest),
+            None,
+            &build_info,
+            &client,
+        )
+        .unwrap();
+
+        // Upload a wheel, with the credentials from the remote cache URL.
+        Mock::given(method("PUT"))
+            .and(path_regex("^/cache/sdists-v2/[0-9a-f]+/"))
+            .and(header("authorization", AUTHORIZATION))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let filename = WheelFilename::from_str("example-1.0.0-py3-none-any.whl").unwrap();
+        let wheel = cache.root().join(filename.to_string());
+        fs_err::write(&wheel, b"wheel").unwrap();
+        remote_cache.put_wheel(&tags(), &wheel, &filename).await;
+
+        let requests = server.received_requests().await.unwrap();
+        let pointer = requests
+            .iter()
+            .find(|request| {
+                request
+                    .url
+                    .path()
+                    .ends_with("/cp312-cp312-manylinux_2_28_x86_64")
+            })
+            .unwrap();
+        let pointer = String::from_utf8(pointer.body.clone()).unwrap();
+        assert_eq!(pointer, format!("{filename}\n{}\n", sha256(b"wheel")));
+        server.reset().await;
+
+        // A wheel that doesn't match the recorded digest is rejected.
+        Mock::given(method("GET"))
+            .and(path_regex("/cp312-cp312-manylinux_2_28_x86_64$"))
+            .and(header("authorization", AUTHORIZATION))
+            .respond_with(ResponseTemplate::new(200).set_body_string(pointer.clone()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex("/example-1.0.0-py3-none-any.whl$"))
+            .and(header("authorization", AUTHORIZATION))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"tampered".to_vec()))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        assert!(
+            remote_cache
+                .wheel(&tags(), Some(&filename.name), None, dir.path())
+                .await
+                .is_none()
+        );
+
+        // A wheel that matches the recorded digest is accepted.
+        Mock::given(method("GET"))
+            .and(path_regex("/example-1.0.0-py3-none-any.whl$"))
+            .and(header("authorization", AUTHORIZATION))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"wheel".to_vec()))
+            .mount(&server)
+            .await;
+        assert_eq!(
+            remote_cache
+                .wheel(&tags(), Some(&filename.name), None, dir.path())
+                .await,
+            Some(filename.clone())
+        );
+        assert_eq!(
+            fs_err::read(dir.path().join(filename.to_string())).unwrap(),
+            b"wheel"
+        );
+
+        // A wheel for a different package or version than the source distribution is rejected.
+        let other_name = PackageName::from_str("other").unwrap();
+        assert!(
+            remote_cache
+                .wheel(&tags(), Some(&other_name), None, dir.path())
+                .await
+                .is_none()
+        );
+        let other_version = Version::from_str("2.0.0").unwrap();
+        assert!(
+            remote_cache
+                .wheel(&tags(), None, Some(&other_version), dir.path())
+                .await
+                .is_none()
+        );
+
+        // A different source distribution at the same URL uses a different key.
+        let other = RemoteCache::new(
+            Some(&remote),
+            &url,
+            &[sha256(b"other")],
+            None,
+            &build_info,
+            &client,
+        )
+        .unwrap();
+        assert_ne!(other.store.root, remote_cache.store.root);
+
+        // So does a different build environment fingerprint.
+        let other = RemoteCache::new(
+            Some(&remote),
+            &url,
+            std::slice::from_ref(&digest),
+            None,
+            &build_info.with_build_fingerprint(Some("gcc-13")),
+            &client,
+        )
+        .unwrap();
+        assert_ne!(other.store.root, remote_cache.store.root);
+    }
+
+    #[tokio::test]
+    async fn artifact_round_trip() {
+        let server = MockServer::start().await;
+        let cache = Cache::temp().unwrap().init().await.unwrap();
+        let client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), cache.clone()).build();
+        let remote = DisplaySafeUrl::parse(&format!("http://{}/cache/", server.address())).unwrap();
+        let filename = "example-1.0.0-py3-none-any.whl";
+        let digest = sha256(b"wheel");
+
+        // Without a SHA-256 digest from the index, the remote cache is skipped.
+        assert!(RemoteArtifacts::new(Some(&remote), filename, &[], &client).is_none());
+
+        let artifacts = RemoteArtifacts::new(
+            Some(&remote),
+            filename,
+            std::slice::from_ref(&digest),
+            &client,
+        )
+        .unwrap();
+
+        // Upload the wheel, streamed from disk.
+        let path = format!(
+            "/cache/artifacts-v1/{}/example-1.0.0-py3-none-any.whl",
+            digest.digest
+        );
+        Mock::given(method("PUT"))
+            .and(path_regex(format!("^{path}$")))
+            .and(header("content-length", "5"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let wheel = cache.root().join(filename);
+        fs_err::write(&wheel, b"wheel").unwrap();
+        artifacts
+            .put(fs_err::tokio::File::open(&wheel).await.unwrap())
+            .await;
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].body, b"wheel");
+        server.reset().await;
+
+        // A missing wheel is a cache miss.
+        let target = cache.root().join("target.whl");
+        let mut file = fs_err::tokio::File::create(&target).await.unwrap();
+        assert!(!artifacts.get(&mut file).await);
+
+        // A wheel that doesn't match the digest reported by the index is rejected.
+        Mock::given(method("GET"))
+            .and(path_regex(format!("^{path}$")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"tampered".to_vec()))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        let mut file = fs_err::tokio::File::create(&target).await.unwrap();
+        assert!(!artifacts.get(&mut file).await);
+
+        // A wheel that matches the digest reported by the index is streamed to disk.
+        Mock::given(method("GET"))
+            .and(path_regex(format!("^{path}$")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"wheel".to_vec()))
+            .mount(&server)
+            .await;
+        let mut file = fs_err::tokio::File::create(&target).await.unwrap();
+        assert!(artifacts.get(&mut file).await);
+        drop(file);
+        assert_eq!(fs_err::read(&target).unwrap(), b"wheel");
+    }
+}
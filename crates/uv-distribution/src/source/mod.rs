@@ -23,10 +23,13 @@ use url::Url;
 use zip::ZipArchive;
 
 use uv_auth::CredentialsCache;
-use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
+use uv_cache::{
+    Cache, CacheBucket, CacheEntry, CacheEvent, CacheShard, Removal, WheelCache, metrics,
+};
 use uv_cache_info::CacheInfo;
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    check_url_allowed,
 };
 use uv_configuration::{BuildKind, BuildOutput, NoSources};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
@@ -50,7 +53,9 @@ use uv_workspace::pyproject::ToolUvSources;
 
 use crate::distribution_database::ManagedClient;
 use crate::error::Error;
+use crate::fingerprint::build_fingerprint;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
+use crate::remote::RemoteCache;
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
@@ -487,7 +492,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -499,6 +505,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|file| file.matches(source.name(), source.version()))
         {
+            metrics().record(CacheEvent::BuiltWheelHit);
             return Ok(BuiltWheelMetadata::from_file(
                 file,
                 revision.into_hashes(),
@@ -507,7 +514,52 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // If the remote cache contains a compatible wheel, download it.
+        let remote = RemoteCache::new(
+            self.build_context.cache().remote(),
+            url,
+            revision.hashes(),
+            subdirectory,
+            &build_info,
+            client.unmanaged,
+        );
+        if let Some(remote) = remote.as_ref() {
+            fs::create_dir_all(&cache_shard)
+                .await
+                .map_err(Error::CacheWrite)?;
+            if let Some(wheel_filename) = remote
+                .wheel(tags, source.name(), source.version(), &cache_shard)
+                .await
+            {
+                let path = cache_shard.join(wheel_filename.to_string());
+                let metadata = read_wheel_metadata(&wheel_filename, &path)?;
+                validate_metadata(source, &metadata)?;
+                validate_filename(&wheel_filename, &metadata)?;
+
+                debug!("Using wheel from remote cache for: {source}");
+
+                // Store the metadata.
+                let metadata_entry = cache_shard.entry(METADATA);
+                write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                return Ok(BuiltWheelMetadata {
+                    path: path.into_boxed_path(),
+                    target: cache_shard.join(wheel_filename.stem()).into_boxed_path(),
+                    filename: wheel_filename,
+                    hashes: revision.into_hashes(),
+                    cache_info,
+                    build_info,
+                });
+            }
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
+        source_dist_entry
+            .materialize()
+            .await
+            .map_err(Error::CacheRead)?;
         let revision = if source_dist_entry.path().is_dir() {
             revision
         } else {
@@ -562,6 +614,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // Populate the remote cache.
+        if let Some(remote) = remote.as_ref() {
+            remote
+                .put_wheel(tags, &cache_shard.join(&disk_filename), &wheel_filename)
+                .await;
+            remote.put_metadata(&metadata).await;
+        }
+
         Ok(BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename).into_boxed_path(),
             target: cache_shard.join(wheel_filename.stem()).into_boxed_path(),
@@ -640,7 +700,48 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If the remote cache contains compatible metadata, return it.
+        let config_settings = self.config_settings_for(source.name());
+        let extra_build_deps = self.extra_build_dependencies_for(source.name());
+        let extra_build_variables = self.extra_build_variables_for(source.name());
+        let build_info =
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
+        let remote = RemoteCache::new(
+            self.build_context.cache().remote(),
+            url,
+            revision.hashes(),
+            subdirectory,
+            &build_info,
+            client.unmanaged,
+        );
+        if let Some(remote) = remote.as_ref()
+            && let Some(metadata) = remote.metadata().await
+        {
+            if validate_metadata(source, &metadata).is_ok() {
+                debug!("Using metadata from remote cache for: {source}");
+
+                // Store the metadata.
+                fs::create_dir_all(metadata_entry.dir())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                return Ok(ArchiveMetadata {
+                    metadata: Metadata::from_metadata23(metadata),
+                    hashes: revision.into_hashes(),
+                });
+            }
+            debug!("Remote metadata does not match expected name and version for: {source}");
+        }
+
         // Otherwise, we need a wheel.
+        source_dist_entry
+            .materialize()
+            .await
+            .map_err(Error::CacheRead)?;
         let revision = if source_dist_entry.path().is_dir() {
             revision
         } else {
@@ -697,6 +798,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await
                 .map_err(Error::CacheWrite)?;
 
+            // Populate the remote cache.
+            if let Some(remote) = remote.as_ref() {
+                remote.put_metadata(&metadata).await;
+            }
+
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
                 hashes: revision.into_hashes(),
@@ -704,11 +810,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings or extra build dependencies, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let extra_build_deps = self.extra_build_dependencies_for(source.name());
-        let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -751,6 +852,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // Populate the remote cache.
+        if let Some(remote) = remote.as_ref() {
+            remote.put_metadata(&metadata).await;
+        }
+
         Ok(ArchiveMetadata {
             metadata: Metadata::from_metadata23(metadata),
             hashes: revision.into_hashes(),
@@ -800,9 +906,22 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 // Download the source distribution.
                 debug!("Downloading source distribution: {source}");
                 let entry = cache_shard.shard(revision.id()).entry(SOURCE);
-                let algorithms = hashes.algorithms();
+                let mut algorithms = hashes.algorithms();
+                // The remote cache is keyed by the SHA-256 digest of the source distribution.
+                if self.build_context.cache().remote().is_some()
+                    && !algorithms.contains(&HashAlgorithm::Sha256)
+                {
+                    algorithms.push(HashAlgorithm::Sha256);
+                }
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        client.unmanaged,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                    )
                     .await?;
 
                 Ok(revision.with_hashes(HashDigests::from(hashes)))
@@ -888,7 +1007,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -900,6 +1020,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|file| file.matches(source.name(), source.version()))
         {
+            metrics().record(CacheEvent::BuiltWheelHit);
             return Ok(BuiltWheelMetadata::from_file(
                 file,
                 revision.into_hashes(),
@@ -909,6 +1030,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel, which requires a source distribution.
+        source_entry.materialize().await.map_err(Error::CacheRead)?;
         let revision = if source_entry.path().is_dir() {
             revision
         } else {
@@ -1017,6 +1139,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need a source distribution.
+        source_entry.materialize().await.map_err(Error::CacheRead)?;
         let revision = if source_entry.path().is_dir() {
             revision
         } else {
@@ -1059,7 +1182,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1129,6 +1253,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the revision already exists, return it. There's no need to check for freshness, since
         // we use an exact timestamp.
+        revision_entry
+            .materialize()
+            .await
+            .map_err(Error::CacheRead)?;
         if let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? {
             if *pointer.cache_info() == cache_info {
                 if pointer.revision().has_digests(hashes) {
@@ -1204,7 +1332,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1216,6 +1345,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|file| file.matches(source.name(), source.version()))
         {
+            metrics().record(CacheEvent::BuiltWheelHit);
             return Ok(BuiltWheelMetadata::from_file(
                 file,
                 revision.into_hashes(),
@@ -1405,7 +1535,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1488,6 +1619,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map_err(Error::CacheRead)?
             .is_fresh()
         {
+            entry.materialize().await.map_err(Error::CacheRead)?;
             match LocalRevisionPointer::read_from(&entry) {
                 Ok(Some(pointer)) => {
                     if *pointer.cache_info() == cache_info {
@@ -1567,6 +1699,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Err(Error::HashesNotSupportedGit(source.to_string()));
         }
 
+        // Check that the repository is allowed by the organization policy.
+        check_url_allowed(resource.git.repository())?;
+
         // Fetch the Git repository.
         let fetch = self
             .build_context
@@ -1629,7 +1764,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1641,6 +1777,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|file| file.matches(source.name(), source.version()))
         {
+            metrics().record(CacheEvent::BuiltWheelHit);
             return Ok(BuiltWheelMetadata::from_file(
                 file, hashes, cache_info, build_info,
             ));
@@ -1699,6 +1836,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Err(Error::HashesNotSupportedGit(source.to_string()));
         }
 
+        // Check that the repository is allowed by the organization policy.
+        check_url_allowed(resource.git.repository())?;
+
         // If the reference appears to be a commit, and we've already checked it out, avoid taking
         // the GitHub fast path.
         let cache_shard = resource
@@ -1952,7 +2092,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables)
+                .with_build_fingerprint(build_fingerprint());
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -2023,6 +2164,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         };
 
+        // Check that the repository is allowed by the organization policy.
+        check_url_allowed(git.repository())?;
+
         // If the URL is already precise, return it.
         if let Some(precise) = self.build_context.git().get_precise(git) {
             debug!("Precise commit already known: {source}");
@@ -2255,7 +2399,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 };
 
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        client.unmanaged,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                    )
                     .await?;
                 for existing in revision.hashes() {
                     if !hashes.contains(existing) {
@@ -2290,6 +2441,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     async fn download_archive(
         &self,
         response: Response,
+        client: &RegistryClient,
         source: &BuildableSource<'_>,
         ext: SourceDistExtension,
         target: &Path,
@@ -2301,8 +2453,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .bucket(CacheBucket::SourceDistributions),
         )
         .map_err(Error::CacheWrite)?;
-        let reader = response
-            .bytes_stream()
+        let reader = client
+            .cached_client()
+            .uncached()
+            .resumable_stream(response)
             .map_err(std::io::Error::other)
             .into_async_read();
 
@@ -2451,6 +2605,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        metrics().record(CacheEvent::BuiltWheelMiss);
+
         // Build into a temporary directory, to prevent partial builds.
         let temp_dir = self
             .build_context
@@ -3091,6 +3247,7 @@ struct CachedMetadata(ResolutionMetadata);
 impl CachedMetadata {
     /// Read an existing cached [`ResolutionMetadata`], if it exists.
     async fn read(cache_entry: &CacheEntry) -> Result<Option<Self>, Error> {
+        cache_entry.materialize().await.map_err(Error::CacheRead)?;
         match fs::read(&cache_entry.path()).await {
             Ok(cached) => Ok(Some(Self(rmp_serde::from_slice(&cached)?))),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
@@ -18,6 +18,8 @@ use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
+use crate::options::fetch_options;
+
 /// A file indicates that if present, `git reset` has been done and a repo
 /// checkout is ready to go. See [`GitCheckout::reset`] for why we need this.
 const CHECKOUT_READY_LOCK: &str = ".ok";
@@ -196,6 +198,28 @@ impl GitRepository {
         })
     }
 
+    /// Borrow objects from the configured reference repositories, such that objects that are
+    /// already available on disk aren't fetched again.
+    fn add_alternates(&self) -> Result<()> {
+        let mut alternates = String::new();
+        for object_dir in fetch_options().reference_object_dirs() {
+            let object_dir = std::path::absolute(object_dir)?;
+            let Some(object_dir) = object_dir.to_str() else {
+                continue;
+            };
+            debug!("Using Git reference repository objects at: {object_dir}");
+            alternates.push_str(object_dir);
+            alternates.push('\n');
+        }
+        if alternates.is_empty() {
+            return Ok(());
+        }
+        let info = self.path.join(".git").join("objects").join("info");
+        fs_err::create_dir_all(&info)?;
+        fs_err::write(info.join("alternates"), alternates)?;
+        Ok(())
+    }
+
     /// Parses the object ID of the given `refname`.
     fn rev_parse(&self, refname: &str) -> Result<GitOid> {
         let result = ProcessBuilder::new(GIT.as_ref()?)
@@ -313,6 +337,7 @@ impl GitRemote {
 
         fs_err::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
+        repo.add_alternates()?;
         fetch(&mut repo, &self.url, reference, disable_ssl, offline)
             .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
@@ -439,6 +464,23 @@ impl GitCheckout {
         }
 
         let repo = GitRepository::open(into)?;
+
+        // A shallow database can't be cloned with hardlinks, in which case only its branches and
+        // tags are cloned. Fetch the revision from the database directly.
+        if repo.rev_parse(&format!("{revision}^0")).is_err() {
+            debug!(
+                "Fetching {revision} from Git database into: {}",
+                into.display()
+            );
+            ProcessBuilder::new(GIT.as_ref()?)
+                .arg("fetch")
+                .arg("--depth=1")
+                .arg(database.repo.path.simplified_display().to_string())
+                .arg(revision.as_str())
+                .cwd(into)
+                .exec_with_output()?;
+        }
+
         let checkout = Self::new(revision, repo);
         let lfs_ready = checkout.reset(database.lfs_ready)?;
         Ok(checkout.with_lfs_ready(lfs_ready))
@@ -701,6 +743,11 @@ fn fetch_with_cli(
     if tags {
         cmd.arg("--tags");
     }
+    // Only fetch the requested commit when shallow fetches are enabled, unless we need every
+    // branch and tag to resolve the reference.
+    if fetch_options().shallow && !tags {
+        cmd.arg("--depth=1");
+    }
     if disable_ssl {
         debug!("Disabling SSL verification for Git fetch via `GIT_SSL_NO_VERIFY`");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
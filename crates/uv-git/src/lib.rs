@@ -1,5 +1,6 @@
 pub use crate::credentials::{GIT_STORE, store_credentials_from_url};
 pub use crate::git::{GIT, GIT_LFS, GitError};
+pub use crate::options::{GitFetchOptions, init_fetch_options};
 pub use crate::resolver::{
     GitResolver, GitResolverError, RepositoryReference, ResolvedRepositoryReference,
 };
@@ -7,6 +8,7 @@ pub use crate::source::{Fetch, GitSource, Reporter};
 
 mod credentials;
 mod git;
+mod options;
 mod rate_limit;
 mod resolver;
 mod source;
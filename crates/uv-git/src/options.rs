@@ -0,0 +1,57 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use tracing::debug;
+
+/// The process-wide options for fetching Git repositories.
+static OPTIONS: OnceLock<GitFetchOptions> = OnceLock::new();
+
+/// Options that control how Git repositories are fetched into the cache.
+#[derive(Debug, Clone, Default)]
+pub struct GitFetchOptions {
+    /// Whether to fetch only the requested commit, rather than its full history.
+    pub shallow: bool,
+    /// Local repositories from which to borrow objects, such that objects that are already
+    /// available on disk aren't fetched again.
+    pub reference_repositories: Vec<PathBuf>,
+    /// A directory in which to store the Git databases, to share them across caches.
+    pub shared_cache_dir: Option<PathBuf>,
+}
+
+/// Initialize the options for fetching Git repositories.
+///
+/// Returns the options back if they were already initialized.
+pub fn init_fetch_options(options: GitFetchOptions) -> Result<(), GitFetchOptions> {
+    OPTIONS.set(options)
+}
+
+/// Return the options for fetching Git repositories.
+pub(crate) fn fetch_options() -> &'static GitFetchOptions {
+    OPTIONS.get_or_init(GitFetchOptions::default)
+}
+
+impl GitFetchOptions {
+    /// Return the object directories of the reference repositories that exist on disk.
+    pub(crate) fn reference_object_dirs(&self) -> impl Iterator<Item = PathBuf> + '_ {
+        self.reference_repositories
+            .iter()
+            .filter_map(|repository| object_dir(repository))
+    }
+}
+
+/// Return the object directory for a bare or non-bare Git repository.
+fn object_dir(repository: &Path) -> Option<PathBuf> {
+    let bare = repository.join("objects");
+    if bare.is_dir() {
+        return Some(bare);
+    }
+    let non_bare = repository.join(".git").join("objects");
+    if non_bare.is_dir() {
+        return Some(non_bare);
+    }
+    debug!(
+        "Ignoring Git reference repository without an object directory: {}",
+        repository.display()
+    );
+    None
+}
@@ -15,6 +15,7 @@ use uv_git_types::{GitHubRepository, GitOid, GitReference, GitUrl};
 use uv_static::EnvVars;
 use uv_version::version;
 
+use crate::options::fetch_options;
 use crate::{
     Fetch, GitSource, Reporter,
     rate_limit::{GITHUB_RATE_LIMIT_STATUS, is_github_rate_limited},
@@ -165,8 +166,13 @@ impl GitResolver {
             }
         };
 
-        // Avoid races between different processes, too.
-        let lock_dir = cache.join("locks");
+        // Avoid races between different processes, too. If the Git databases are shared across
+        // caches, so are the locks.
+        let lock_dir = if let Some(shared_cache_dir) = &fetch_options().shared_cache_dir {
+            shared_cache_dir.join("locks")
+        } else {
+            cache.join("locks")
+        };
         fs::create_dir_all(&lock_dir).await?;
         let repository_url = RepositoryUrl::new(url.repository());
         let _lock = LockedFile::acquire(
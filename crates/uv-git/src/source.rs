@@ -15,6 +15,7 @@ use uv_redacted::DisplaySafeUrl;
 
 use crate::GIT_STORE;
 use crate::git::{GitDatabase, GitRemote};
+use crate::options::fetch_options;
 
 /// A remote Git source that can be checked out locally.
 pub struct GitSource {
@@ -70,7 +71,11 @@ impl GitSource {
 
         // The path to the repo, within the Git database.
         let ident = cache_digest(&canonical);
-        let db_path = self.cache.join("db").join(&ident);
+        let db_path = if let Some(shared_cache_dir) = &fetch_options().shared_cache_dir {
+            shared_cache_dir.join(&ident)
+        } else {
+            self.cache.join("db").join(&ident)
+        };
 
         // Authenticate the URL, if necessary.
         let remote = if let Some(credentials) = GIT_STORE.get(&canonical) {
@@ -9,7 +9,7 @@ use tracing::{instrument, trace};
 
 use uv_distribution_filename::WheelFilename;
 use uv_pep440::Version;
-use uv_pypi_types::{DirectUrl, Metadata10};
+use uv_pypi_types::{DirectUrl, HashDigest, Metadata10};
 
 use crate::linker::{LinkMode, Locks};
 use crate::wheel::{
@@ -29,9 +29,11 @@ use crate::{Error, Layout};
 pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     layout: &Layout,
     relocatable: bool,
+    scripts: bool,
     wheel: impl AsRef<Path>,
     filename: &WheelFilename,
     direct_url: Option<&DirectUrl>,
+    hashes: &[HashDigest],
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
     installer: Option<&str>,
@@ -88,7 +90,9 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     let (console_scripts, gui_scripts) =
         parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
 
-    if console_scripts.is_empty() && gui_scripts.is_empty() {
+    if !scripts {
+        trace!(?name, "Skipping entrypoints");
+    } else if console_scripts.is_empty() && gui_scripts.is_empty() {
         trace!(?name, "No entrypoints");
     } else {
         trace!(?name, "Writing entrypoints");
@@ -119,6 +123,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
         install_data(
             layout,
             relocatable,
+            scripts,
             site_packages,
             &data_dir,
             &name,
@@ -126,6 +131,12 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
             &gui_scripts,
             &mut record,
         )?;
+        if !scripts {
+            // Drop the `RECORD` entries for any scripts that were skipped, which are removed
+            // along with the `.data` directory.
+            let prefix = format!("{dist_info_prefix}.data/scripts/");
+            record.retain(|entry| !entry.path.starts_with(&prefix));
+        }
         // 2.c If applicable, update scripts starting with #!python to point to the correct interpreter.
         // Script are unsupported through data
         // 2.e Remove empty distribution-1.0.data directory.
@@ -141,6 +152,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
             &dist_info_prefix,
             true,
             direct_url,
+            hashes,
             cache_info,
             build_info,
             installer,
@@ -14,6 +14,7 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
+pub use verify::verify_wheel;
 pub use wheel::{LibKind, WheelFile, read_record_file};
 
 mod install;
@@ -21,6 +22,7 @@ mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
@@ -0,0 +1,90 @@
+use std::io;
+use std::path::{Component, Path};
+
+use fs_err::File;
+
+use crate::Error;
+use crate::wheel::{copy_and_hash, find_dist_info, read_record_file};
+
+/// Verify the files in an unzipped wheel against the hashes in its `RECORD` file.
+///
+/// Returns an error if the `RECORD` file is missing, or if any recorded file is missing or doesn't
+/// match its recorded hash. Entries without a hash (e.g., the `RECORD` file itself), or with a hash
+/// algorithm other than SHA-256, are ignored.
+pub fn verify_wheel(wheel: &Path) -> Result<(), Error> {
+    let dist_info_prefix = find_dist_info(wheel)?;
+    let record_path = wheel
+        .join(format!("{dist_info_prefix}.dist-info"))
+        .join("RECORD");
+    let mut record_file = match File::open(&record_path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::InvalidWheel("Missing RECORD file".to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let record = read_record_file(&mut record_file)?;
+
+    for entry in record {
+        let Some(expected) = entry
+            .hash
+            .as_deref()
+            .filter(|hash| hash.starts_with("sha256="))
+        else {
+            continue;
+        };
+
+        // Ignore any entries that point outside the wheel.
+        let relative = Path::new(&entry.path);
+        if relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)))
+        {
+            continue;
+        }
+
+        let mut file = match File::open(wheel.join(relative)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::RecordFile(format!("Missing file: {}", entry.path)));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if actual != expected {
+            return Err(Error::RecordFile(format!(
+                "Hash mismatch for {}: expected `{expected}`, found `{actual}`",
+                entry.path
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use assert_fs::prelude::*;
+
+    use super::verify_wheel;
+
+    #[test]
+    fn verify() -> anyhow::Result<()> {
+        let wheel = assert_fs::TempDir::new()?;
+        wheel.child("foo/__init__.py").write_str("")?;
+        wheel.child("foo-1.0.0.dist-info/RECORD").write_str(
+            "foo/__init__.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0\nfoo-1.0.0.dist-info/RECORD,,\n",
+        )?;
+        verify_wheel(wheel.path())?;
+
+        // A modified file doesn't match its recorded hash.
+        wheel.child("foo/__init__.py").write_str("corrupt")?;
+        assert!(verify_wheel(wheel.path()).is_err());
+
+        // A missing file is reported.
+        fs_err::remove_file(wheel.child("foo/__init__.py").path())?;
+        assert!(verify_wheel(wheel.path()).is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io;
 use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
@@ -14,7 +14,7 @@ use walkdir::WalkDir;
 
 use uv_fs::{Simplified, persist_with_retry_sync, relative_to};
 use uv_normalize::PackageName;
-use uv_pypi_types::DirectUrl;
+use uv_pypi_types::{DirectUrl, HashDigest};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_trampoline_builder::windows_script_launcher;
 use uv_warnings::warn_user_once;
@@ -78,7 +78,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -591,6 +594,7 @@ fn install_script(
 pub(crate) fn install_data(
     layout: &Layout,
     relocatable: bool,
+    scripts: bool,
     site_packages: &Path,
     data_dir: &Path,
     dist_name: &PackageName,
@@ -612,6 +616,9 @@ pub(crate) fn install_data(
                 // Move the content of the folder to the root of the venv
                 move_folder_recorded(&path, &layout.scheme.data, site_packages, record)?;
             }
+            Some("scripts") if !scripts => {
+                trace!(?dist_name, "Skipping data/scripts");
+            }
             Some("scripts") => {
                 trace!(
                     ?dist_name,
@@ -725,6 +732,7 @@ pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Se
     dist_info_prefix: &str,
     requested: bool,
     direct_url: Option<&DirectUrl>,
+    hashes: &[HashDigest],
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
     installer: Option<&str>,
@@ -742,6 +750,20 @@ pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Se
             record,
         )?;
     }
+    if !hashes.is_empty() {
+        // Record the archive hashes in the same format as `archive_info.hashes` in
+        // `direct_url.json` (e.g., `{"sha256": "..."}`).
+        let hashes = hashes
+            .iter()
+            .map(|hash| (hash.algorithm().to_string(), hash.digest.to_string()))
+            .collect::<BTreeMap<_, _>>();
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("uv_hashes.json"),
+            serde_json::to_string(&hashes)?.as_bytes(),
+            record,
+        )?;
+    }
     if let Some(cache_info) = cache_info {
         write_file_recorded(
             site_packages,
@@ -951,6 +973,8 @@ mod test {
     use assert_fs::prelude::*;
     use indoc::{formatdoc, indoc};
 
+    use uv_pypi_types::{HashAlgorithm, HashDigest};
+
     use super::{
         Error, RecordEntry, Script, WheelFile, format_shebang, get_script_executable,
         parse_email_message_file, read_record_file, write_installer_metadata,
@@ -1243,19 +1267,32 @@ mod test {
             .child("foo-0.1.0.dist-info")
             .create_dir_all()
             .unwrap();
+        let hashes = [HashDigest {
+            algorithm: HashAlgorithm::Sha256,
+            digest: "0123456789abcdef".into(),
+        }];
         write_installer_metadata::<(), ()>(
             site_packages,
             "foo-0.1.0",
             true,
             None,
+            &hashes,
             None,
             None,
             Some("uv"),
             &mut record,
         )
         .unwrap();
+        assert_eq!(
+            fs_err::read_to_string(site_packages.join("foo-0.1.0.dist-info/uv_hashes.json"))
+                .unwrap(),
+            r#"{"sha256":"0123456789abcdef"}"#
+        );
+        // The hashes are recorded like the other installer metadata, so that uninstalling the
+        // package removes them.
         let expected = [
             "foo-0.1.0.dist-info/REQUESTED",
+            "foo-0.1.0.dist-info/uv_hashes.json",
             "foo-0.1.0.dist-info/INSTALLER",
         ]
         .map(ToString::to_string)
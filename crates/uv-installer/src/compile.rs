@@ -13,8 +13,10 @@ use tokio::sync::oneshot;
 use tracing::{debug, instrument};
 use walkdir::WalkDir;
 
+use uv_cache::{Cache, CacheBucket};
 use uv_configuration::Concurrency;
 use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
@@ -34,6 +36,8 @@ pub enum CompileError {
     PythonSubcommand(#[source] io::Error),
     #[error("Failed to create temporary script file")]
     TempFile(#[source] io::Error),
+    #[error("Failed to create the bytecode cache")]
+    BytecodeCache(#[source] io::Error),
     #[error(r#"Bytecode compilation failed, expected "{0}", received: "{1}""#)]
     WrongPath(String, String),
     #[error("Failed to write to Python {device}")]
@@ -69,12 +73,18 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
+///
+/// The compiled bytecode is stored in the cache, keyed by the source and the interpreter's magic
+/// number, so that identical files in other environments are not compiled again. With
+/// [`LinkMode::Hardlink`] (or [`LinkMode::Symlink`]), the cached bytecode is hard linked into the
+/// environment rather than copied.
+#[instrument(skip(python_executable, cache))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     concurrency: &Concurrency,
-    cache: &Path,
+    cache: &Cache,
+    link_mode: LinkMode,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
@@ -87,9 +97,14 @@ pub async fn compile_tree(
     let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count * 10);
 
     // Running Python with an actual file will produce better error messages.
-    let tempdir = tempdir_in(cache).map_err(CompileError::TempFile)?;
+    let tempdir = tempdir_in(cache.root()).map_err(CompileError::TempFile)?;
     let pip_compileall_py = tempdir.path().join("pip_compileall.py");
 
+    let bytecode_cache = cache.bucket(CacheBucket::Bytecode);
+    fs_err::tokio::create_dir_all(&bytecode_cache)
+        .await
+        .map_err(CompileError::BytecodeCache)?;
+
     let timeout: Option<Duration> = match env::var(EnvVars::UV_COMPILE_BYTECODE_TIMEOUT) {
         Ok(value) => match value.as_str() {
             "0" => None,
@@ -123,6 +138,8 @@ pub async fn compile_tree(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
+            bytecode_cache.clone(),
+            link_mode,
             receiver.clone(),
             timeout,
         );
@@ -215,6 +232,8 @@ async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
+    bytecode_cache: PathBuf,
+    link_mode: LinkMode,
     receiver: Receiver<PathBuf>,
     timeout: Option<Duration>,
 ) -> Result<(), CompileError> {
@@ -229,8 +248,14 @@ async fn worker(
     let wait_until_ready = async {
         loop {
             // If the interpreter started successful, return it, else retry.
-            if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+            if let Some(child) = launch_bytecode_compiler(
+                &dir,
+                &interpreter,
+                &pip_compileall_py,
+                &bytecode_cache,
+                link_mode,
+            )
+            .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -299,6 +324,8 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    bytecode_cache: &Path,
+    link_mode: LinkMode,
 ) -> Result<
     Option<(
         Child,
@@ -311,6 +338,11 @@ async fn launch_bytecode_compiler(
     // We input the paths through stdin and get the successful paths returned through stdout.
     let mut bytecode_compiler = Command::new(interpreter)
         .arg(pip_compileall_py)
+        .arg(bytecode_cache)
+        .arg(match link_mode {
+            LinkMode::Hardlink | LinkMode::Symlink => "link",
+            LinkMode::Clone | LinkMode::Copy => "copy",
+        })
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -22,6 +22,8 @@ pub struct Installer<'a> {
     name: Option<String>,
     /// The metadata associated with the [`Installer`].
     metadata: bool,
+    /// Whether to install scripts (entrypoints and `.data/scripts`).
+    scripts: bool,
     /// Preview settings for the installer.
     preview: Preview,
 }
@@ -36,6 +38,7 @@ impl<'a> Installer<'a> {
             reporter: None,
             name: Some("uv".to_string()),
             metadata: true,
+            scripts: true,
             preview,
         }
     }
@@ -82,6 +85,13 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set whether to install scripts, i.e., console and GUI entrypoints and any scripts included
+    /// in the wheel's `.data/scripts` directory.
+    #[must_use]
+    pub fn with_scripts(self, scripts: bool) -> Self {
+        Self { scripts, ..self }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -92,6 +102,7 @@ impl<'a> Installer<'a> {
             reporter,
             name: installer_name,
             metadata: installer_metadata,
+            scripts,
             preview,
         } = self;
 
@@ -105,7 +116,7 @@ impl<'a> Installer<'a> {
 
         let (tx, rx) = oneshot::channel();
 
-        let layout = venv.interpreter().layout();
+        let layout = venv.layout();
         let relocatable = venv.relocatable();
         // Initialize the threadpool with the user settings.
         LazyLock::force(&RAYON_INITIALIZE);
@@ -118,6 +129,7 @@ impl<'a> Installer<'a> {
                 reporter.as_ref(),
                 relocatable,
                 installer_metadata,
+                scripts,
                 preview,
             );
 
@@ -143,12 +155,13 @@ impl<'a> Installer<'a> {
 
         install(
             wheels,
-            &self.venv.interpreter().layout(),
+            &self.venv.layout(),
             self.name.as_deref(),
             self.link_mode,
             self.reporter.as_ref(),
             self.venv.relocatable(),
             self.metadata,
+            self.scripts,
             self.preview,
         )
     }
@@ -164,6 +177,7 @@ fn install(
     reporter: Option<&Arc<dyn Reporter>>,
     relocatable: bool,
     installer_metadata: bool,
+    scripts: bool,
     preview: Preview,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
@@ -173,12 +187,14 @@ fn install(
         uv_install_wheel::install_wheel(
             layout,
             relocatable,
+            scripts,
             wheel.path(),
             wheel.filename(),
             wheel
                 .parsed_url()
                 .map(uv_pypi_types::DirectUrl::from)
                 .as_ref(),
+            wheel.hashes(),
             if wheel.cache_info().is_empty() {
                 None
             } else {
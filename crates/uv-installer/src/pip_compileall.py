@@ -9,11 +9,105 @@ which contains some vendored Python 2 code which fails to compile.
 """
 
 import compileall
+import hashlib
+import importlib.util
 import os
 import py_compile
+import shutil
 import sys
 import warnings
 
+
+def compile_cached(path, bytecode_cache, invalidation_mode, force, link):
+    """Compile a file, reusing the bytecode of an identical source file from the cache.
+
+    The cache stores complete `.pyc` files, keyed by the `.pyc` header (i.e., the magic number, the
+    invalidation mode, and the source's timestamp or hash), the optimization level and the source.
+    On a hit, the cached `.pyc` is hard linked into the `__pycache__` directory if `link` is set,
+    such that environments share its storage, and copied otherwise. The filename embedded in the
+    code object is fixed up by the import system when loading the `.pyc`.
+    """
+    cfile = importlib.util.cache_from_source(path)
+    if not force and os.path.exists(cfile):
+        # Let `compileall` decide whether the existing `.pyc` is up-to-date.
+        return compileall.compile_file(
+            path, invalidation_mode=invalidation_mode, force=force, quiet=2
+        )
+
+    with open(path, "rb") as f:
+        source = f.read()
+    header = pyc_header(path, source, invalidation_mode)
+    key = hashlib.sha256(
+        header + str(sys.flags.optimize).encode() + b"\0" + source
+    ).hexdigest()
+    entry = os.path.join(bytecode_cache, key)
+
+    if not os.path.exists(entry):
+        success = compileall.compile_file(
+            path, invalidation_mode=invalidation_mode, force=force, quiet=2
+        )
+        if success:
+            try:
+                # Skip `.pyc` files that don't match the source we read, e.g., if the source was
+                # modified in the meantime.
+                with open(cfile, "rb") as f:
+                    matches = f.read(16) == header
+                if matches:
+                    link_or_copy(cfile, entry, link)
+            except OSError:
+                pass
+        return success
+
+    try:
+        os.makedirs(os.path.dirname(cfile), exist_ok=True)
+        link_or_copy(entry, cfile, link)
+    except OSError:
+        return False
+    return True
+
+
+def pyc_header(path, source, invalidation_mode):
+    """Return the 16 byte `.pyc` header for a source file, as written by `py_compile`."""
+    if invalidation_mode == py_compile.PycInvalidationMode.TIMESTAMP:
+        stat = os.stat(path)
+        return (
+            importlib.util.MAGIC_NUMBER
+            + (0).to_bytes(4, "little")
+            + (int(stat.st_mtime) & 0xFFFFFFFF).to_bytes(4, "little")
+            + (stat.st_size & 0xFFFFFFFF).to_bytes(4, "little")
+        )
+    checked = invalidation_mode == py_compile.PycInvalidationMode.CHECKED_HASH
+    return (
+        importlib.util.MAGIC_NUMBER
+        + (0b1 | (checked << 1)).to_bytes(4, "little")
+        + importlib.util.source_hash(source)
+    )
+
+
+def link_or_copy(src, dst, link):
+    """Hard link (if `link` is set) or copy a file, replacing the destination atomically.
+
+    Hard linking falls back to copying, e.g., if the files are on different devices. Python
+    replaces `.pyc` files rather than writing to them, so linked files are never modified.
+    """
+    temp = f"{dst}.{os.getpid()}.tmp"
+    try:
+        if link:
+            try:
+                os.link(src, temp)
+            except OSError:
+                shutil.copyfile(src, temp)
+        else:
+            shutil.copyfile(src, temp)
+        os.replace(temp, dst)
+    except OSError:
+        try:
+            os.unlink(temp)
+        except OSError:
+            pass
+        raise
+
+
 with warnings.catch_warnings():
     warnings.filterwarnings("ignore")
 
@@ -51,6 +145,14 @@ with warnings.catch_warnings():
         # based and has a matching mtime (unless force=True).
         force = True
 
+    # The cache for bytecode that's shared across environments, and whether to hard link from it.
+    # The `.pyc` layout is only known for CPython, and older versions don't expose an invalidation
+    # mode.
+    bytecode_cache = sys.argv[1] if len(sys.argv) > 1 else None
+    link = len(sys.argv) > 2 and sys.argv[2] == "link"
+    if sys.implementation.name != "cpython" or invalidation_mode is None:
+        bytecode_cache = None
+
     # In rust, we provide one line per file to compile.
     for path in sys.stdin:
         # Remove trailing newlines.
@@ -60,8 +162,16 @@ with warnings.catch_warnings():
         # Unlike pip, we set quiet=2, so we don't have to capture stdout.
         # We'd like to show those errors, but given that pip thinks that's totally fine,
         # we can't really change that.
-        success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
-        )
+        if bytecode_cache is None:
+            success = compileall.compile_file(
+                path, invalidation_mode=invalidation_mode, force=force, quiet=2
+            )
+        else:
+            try:
+                success = compile_cached(
+                    path, bytecode_cache, invalidation_mode, force, link
+                )
+            except OSError:
+                success = False
         # We're ready for the next file.
         print(path)
@@ -249,7 +249,11 @@ impl<'a> Planner<'a> {
                         .entry(format!("{}.http", wheel.filename.cache_key()));
 
                     // Read the HTTP pointer.
-                    match HttpArchivePointer::read_from(&cache_entry) {
+                    match cache_entry
+                        .materialize_sync()
+                        .map_err(uv_distribution::Error::CacheRead)
+                        .and_then(|()| HttpArchivePointer::read_from(&cache_entry))
+                    {
                         Ok(Some(pointer)) => {
                             let cache_info = pointer.to_cache_info();
                             let build_info = pointer.to_build_info();
@@ -264,7 +268,7 @@ impl<'a> Planner<'a> {
                                     hashes: archive.hashes,
                                     cache_info,
                                     build_info,
-                                    path: cache.archive(&archive.id).into_boxed_path(),
+                                    path: cache.find_archive(&archive.id).into_boxed_path(),
                                 };
 
                                 debug!("URL wheel requirement already cached: {cached_dist}");
@@ -322,7 +326,11 @@ impl<'a> Planner<'a> {
                         )
                         .entry(format!("{}.rev", wheel.filename.cache_key()));
 
-                    match LocalArchivePointer::read_from(&cache_entry) {
+                    match cache_entry
+                        .materialize_sync()
+                        .map_err(uv_distribution::Error::CacheRead)
+                        .and_then(|()| LocalArchivePointer::read_from(&cache_entry))
+                    {
                         Ok(Some(pointer)) => match Timestamp::from_path(&wheel.install_path) {
                             Ok(timestamp) => {
                                 if pointer.is_up_to_date(timestamp) {
@@ -339,7 +347,7 @@ impl<'a> Planner<'a> {
                                             hashes: archive.hashes,
                                             cache_info,
                                             build_info,
-                                            path: cache.archive(&archive.id).into_boxed_path(),
+                                            path: cache.find_archive(&archive.id).into_boxed_path(),
                                         };
 
                                         debug!(
@@ -578,6 +578,8 @@ pub enum Scheme {
     Http,
     /// `https://...`
     Https,
+    /// `oci://...`
+    Oci,
 }
 
 impl Scheme {
@@ -607,6 +609,7 @@ impl Scheme {
             "svn+file" => Some(Self::SvnFile),
             "http" => Some(Self::Http),
             "https" => Some(Self::Https),
+            "oci" => Some(Self::Oci),
             _ => None,
         }
     }
@@ -641,6 +644,7 @@ impl std::fmt::Display for Scheme {
             Self::SvnFile => write!(f, "svn+file"),
             Self::Http => write!(f, "http"),
             Self::Https => write!(f, "https"),
+            Self::Oci => write!(f, "oci"),
         }
     }
 }
@@ -9,7 +9,7 @@ use thiserror::Error;
 use uv_warnings::warn_user_once;
 
 #[bitflags]
-#[repr(u32)]
+#[repr(u64)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PreviewFeature {
     PythonInstallDefault = 1 << 0,
@@ -36,6 +36,23 @@ pub enum PreviewFeature {
     GcsEndpoint = 1 << 21,
     AdjustUlimit = 1 << 22,
     SpecialCondaEnvNames = 1 << 23,
+    RemoteCache = 1 << 24,
+    CacheInfo = 1 << 25,
+    PipSize = 1 << 26,
+    CacheVerify = 1 << 27,
+    CacheWarm = 1 << 28,
+    CacheMetrics = 1 << 29,
+    AuditLog = 1 << 30,
+    GenerateDevcontainer = 1 << 31,
+    EnvInfo = 1 << 32,
+    Hooks = 1 << 33,
+    KernelInstall = 1 << 34,
+    ConfigCommand = 1 << 35,
+    RedactLogs = 1 << 36,
+    InteractiveResolution = 1 << 37,
+    UsageLog = 1 << 38,
+    Localization = 1 << 39,
+    ErrorCodes = 1 << 40,
 }
 
 impl PreviewFeature {
@@ -66,6 +83,23 @@ impl PreviewFeature {
             Self::GcsEndpoint => "gcs-endpoint",
             Self::AdjustUlimit => "adjust-ulimit",
             Self::SpecialCondaEnvNames => "special-conda-env-names",
+            Self::RemoteCache => "remote-cache",
+            Self::CacheInfo => "cache-info",
+            Self::PipSize => "pip-size",
+            Self::CacheVerify => "cache-verify",
+            Self::CacheWarm => "cache-warm",
+            Self::CacheMetrics => "cache-metrics",
+            Self::AuditLog => "audit-log",
+            Self::GenerateDevcontainer => "generate-devcontainer",
+            Self::EnvInfo => "env-info",
+            Self::Hooks => "hooks",
+            Self::KernelInstall => "kernel-install",
+            Self::ConfigCommand => "config-command",
+            Self::RedactLogs => "redact-logs",
+            Self::InteractiveResolution => "interactive-resolution",
+            Self::UsageLog => "usage-log",
+            Self::Localization => "localization",
+            Self::ErrorCodes => "error-codes",
         }
     }
 }
@@ -109,6 +143,23 @@ impl FromStr for PreviewFeature {
             "metadata-json" => Self::MetadataJson,
             "adjust-ulimit" => Self::AdjustUlimit,
             "special-conda-env-names" => Self::SpecialCondaEnvNames,
+            "remote-cache" => Self::RemoteCache,
+            "cache-info" => Self::CacheInfo,
+            "pip-size" => Self::PipSize,
+            "cache-verify" => Self::CacheVerify,
+            "cache-warm" => Self::CacheWarm,
+            "cache-metrics" => Self::CacheMetrics,
+            "audit-log" => Self::AuditLog,
+            "generate-devcontainer" => Self::GenerateDevcontainer,
+            "env-info" => Self::EnvInfo,
+            "hooks" => Self::Hooks,
+            "kernel-install" => Self::KernelInstall,
+            "config-command" => Self::ConfigCommand,
+            "redact-logs" => Self::RedactLogs,
+            "interactive-resolution" => Self::InteractiveResolution,
+            "usage-log" => Self::UsageLog,
+            "localization" => Self::Localization,
+            "error-codes" => Self::ErrorCodes,
             _ => return Err(PreviewFeatureParseError),
         })
     }
@@ -151,6 +202,14 @@ impl Preview {
         Self::new(preview_features)
     }
 
+    /// Enable a single feature, in addition to the enabled features.
+    #[must_use]
+    pub fn with(self, flag: PreviewFeature) -> Self {
+        Self {
+            flags: self.flags | flag,
+        }
+    }
+
     /// Check if a single feature is enabled
     pub fn is_enabled(&self, flag: PreviewFeature) -> bool {
         self.flags.contains(flag)
@@ -336,5 +395,28 @@ mod tests {
             PreviewFeature::SpecialCondaEnvNames.as_str(),
             "special-conda-env-names"
         );
+        assert_eq!(PreviewFeature::RemoteCache.as_str(), "remote-cache");
+        assert_eq!(PreviewFeature::CacheInfo.as_str(), "cache-info");
+        assert_eq!(PreviewFeature::PipSize.as_str(), "pip-size");
+        assert_eq!(PreviewFeature::CacheVerify.as_str(), "cache-verify");
+        assert_eq!(PreviewFeature::CacheWarm.as_str(), "cache-warm");
+        assert_eq!(PreviewFeature::CacheMetrics.as_str(), "cache-metrics");
+        assert_eq!(PreviewFeature::AuditLog.as_str(), "audit-log");
+        assert_eq!(
+            PreviewFeature::GenerateDevcontainer.as_str(),
+            "generate-devcontainer"
+        );
+        assert_eq!(PreviewFeature::EnvInfo.as_str(), "env-info");
+        assert_eq!(PreviewFeature::Hooks.as_str(), "hooks");
+        assert_eq!(PreviewFeature::KernelInstall.as_str(), "kernel-install");
+        assert_eq!(PreviewFeature::ConfigCommand.as_str(), "config-command");
+        assert_eq!(PreviewFeature::RedactLogs.as_str(), "redact-logs");
+        assert_eq!(
+            PreviewFeature::InteractiveResolution.as_str(),
+            "interactive-resolution"
+        );
+        assert_eq!(PreviewFeature::UsageLog.as_str(), "usage-log");
+        assert_eq!(PreviewFeature::Localization.as_str(), "localization");
+        assert_eq!(PreviewFeature::ErrorCodes.as_str(), "error-codes");
     }
 }
@@ -0,0 +1,477 @@
+//! Check the metadata of a distribution before uploading it, similar to `twine check`.
+//!
+//! The checks cover the problems that cause an index to reject an upload or to fail rendering the
+//! project description: the description content type, common reStructuredText errors, malformed
+//! classifiers, and invalid license expressions.
+
+use std::fmt;
+
+use uv_pypi_types::Metadata23;
+
+/// The directives that docutils supports out of the box.
+const RST_DIRECTIVES: &[&str] = &[
+    "admonition",
+    "attention",
+    "caution",
+    "class",
+    "code",
+    "code-block",
+    "compound",
+    "container",
+    "contents",
+    "csv-table",
+    "danger",
+    "date",
+    "default-role",
+    "epigraph",
+    "error",
+    "figure",
+    "footer",
+    "header",
+    "highlights",
+    "hint",
+    "image",
+    "important",
+    "include",
+    "line-block",
+    "list-table",
+    "math",
+    "meta",
+    "note",
+    "parsed-literal",
+    "pull-quote",
+    "raw",
+    "replace",
+    "role",
+    "rubric",
+    "section-numbering",
+    "sectnum",
+    "sidebar",
+    "sourcecode",
+    "table",
+    "target-notes",
+    "tip",
+    "title",
+    "topic",
+    "unicode",
+    "warning",
+];
+
+/// The directives that are disabled when rendering descriptions on PyPI.
+const DISABLED_RST_DIRECTIVES: &[&str] = &["include", "raw"];
+
+/// The top-level categories of the trove classifiers.
+const CLASSIFIER_CATEGORIES: &[&str] = &[
+    "Development Status",
+    "Environment",
+    "Framework",
+    "Intended Audience",
+    "License",
+    "Natural Language",
+    "Operating System",
+    "Private",
+    "Programming Language",
+    "Topic",
+    "Typing",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The index accepts the upload, but the project page may be incomplete.
+    Warning,
+    /// The index rejects the upload or fails to render the description.
+    Error,
+}
+
+/// A problem with the metadata of a distribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataDiagnostic {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl MetadataDiagnostic {
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for MetadataDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severity {
+            Severity::Warning => write!(f, "warning: {}", self.message),
+            Severity::Error => write!(f, "error: {}", self.message),
+        }
+    }
+}
+
+/// Check the metadata of a distribution.
+pub(crate) fn check_metadata(metadata: &Metadata23) -> Vec<MetadataDiagnostic> {
+    let mut diagnostics = Vec::new();
+    check_description(metadata, &mut diagnostics);
+    check_classifiers(metadata, &mut diagnostics);
+    check_license_expression(metadata, &mut diagnostics);
+    diagnostics
+}
+
+/// Check that the description has a supported content type and renders.
+fn check_description(metadata: &Metadata23, diagnostics: &mut Vec<MetadataDiagnostic>) {
+    let description = metadata
+        .description
+        .as_deref()
+        .filter(|description| !description.trim().is_empty());
+    if description.is_none() {
+        diagnostics.push(MetadataDiagnostic::warning(
+            "`long_description` missing, the project page will be empty",
+        ));
+    }
+
+    let Some(content_type) = metadata.description_content_type.as_deref() else {
+        if description.is_some() {
+            diagnostics.push(MetadataDiagnostic::warning(
+                "`long_description_content_type` missing, defaulting to `text/x-rst`",
+            ));
+        }
+        if let Some(description) = description {
+            check_rst(description, diagnostics);
+        }
+        return;
+    };
+
+    let mut parts = content_type.split(';').map(str::trim);
+    let mime_type = parts.next().unwrap_or_default().to_ascii_lowercase();
+    for parameter in parts.filter(|parameter| !parameter.is_empty()) {
+        let Some((key, value)) = parameter.split_once('=') else {
+            diagnostics.push(MetadataDiagnostic::error(format!(
+                "Invalid parameter in `long_description_content_type`: `{parameter}`"
+            )));
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        match key.trim().to_ascii_lowercase().as_str() {
+            "charset" if !value.eq_ignore_ascii_case("utf-8") => {
+                diagnostics.push(MetadataDiagnostic::error(format!(
+                    "Unsupported charset in `long_description_content_type`: `{value}` \
+                    (expected `UTF-8`)"
+                )));
+            }
+            "variant" if mime_type == "text/markdown" && !matches!(value, "GFM" | "CommonMark") => {
+                diagnostics.push(MetadataDiagnostic::error(format!(
+                    "Unsupported Markdown variant in `long_description_content_type`: `{value}` \
+                    (expected `GFM` or `CommonMark`)"
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    match mime_type.as_str() {
+        "text/x-rst" => {
+            if let Some(description) = description {
+                check_rst(description, diagnostics);
+            }
+        }
+        "text/markdown" | "text/plain" => {}
+        _ => {
+            diagnostics.push(MetadataDiagnostic::error(format!(
+                "Unsupported `long_description_content_type`: `{mime_type}` (expected \
+                `text/plain`, `text/x-rst`, or `text/markdown`)"
+            )));
+        }
+    }
+}
+
+/// Check a reStructuredText description for errors that fail rendering on PyPI.
+///
+/// PyPI renders descriptions with docutils and rejects descriptions that produce warnings. This
+/// covers the most common causes, not the full reStructuredText syntax.
+fn check_rst(description: &str, diagnostics: &mut Vec<MetadataDiagnostic>) {
+    let lines = description.lines().collect::<Vec<_>>();
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+
+        // Section titles, e.g., `Title\n=====`.
+        if let Some(title) = index.checked_sub(1).map(|previous| lines[previous]) {
+            if is_adornment(line) && is_title(title) {
+                let underline = line.trim_end().chars().count();
+                let title_width = title.trim_end().chars().count();
+                if underline < title_width {
+                    diagnostics.push(MetadataDiagnostic::error(format!(
+                        "The description failed to render (line {line_number}): Title underline \
+                        too short"
+                    )));
+                }
+            }
+        }
+
+        // Directives, e.g., `.. note::`, including those in substitution definitions like
+        // `.. |logo| image:: logo.png`.
+        let Some(directive) = line.trim_start().strip_prefix("..") else {
+            continue;
+        };
+        if !directive.starts_with(' ') {
+            continue;
+        }
+        let directive = directive.trim_start();
+        let directive = if let Some(substitution) = directive.strip_prefix('|') {
+            let Some((_, directive)) = substitution.split_once('|') else {
+                continue;
+            };
+            directive.trim_start()
+        } else {
+            directive
+        };
+        let Some((name, _)) = directive.split_once("::") else {
+            continue;
+        };
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            continue;
+        }
+        let name = name.to_ascii_lowercase();
+        if DISABLED_RST_DIRECTIVES.contains(&name.as_str()) {
+            diagnostics.push(MetadataDiagnostic::error(format!(
+                "The description failed to render (line {line_number}): The `{name}` directive \
+                is disabled"
+            )));
+        } else if !RST_DIRECTIVES.contains(&name.as_str()) {
+            diagnostics.push(MetadataDiagnostic::error(format!(
+                "The description failed to render (line {line_number}): Unknown directive type \
+                `{name}`"
+            )));
+        }
+    }
+}
+
+/// Whether the line is a section adornment, i.e., a repeated punctuation character.
+fn is_adornment(line: &str) -> bool {
+    let line = line.trim_end();
+    let mut chars = line.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    // Shorter adornments are parsed as regular text by docutils.
+    line.chars().count() >= 4 && first.is_ascii_punctuation() && chars.all(|c| c == first)
+}
+
+/// Whether the line can be the text of a section title.
+fn is_title(line: &str) -> bool {
+    !line.trim().is_empty() && !line.starts_with(char::is_whitespace) && !is_adornment(line)
+}
+
+/// Check that the classifiers are well-formed and consistent with the license expression.
+fn check_classifiers(metadata: &Metadata23, diagnostics: &mut Vec<MetadataDiagnostic>) {
+    for (index, classifier) in metadata.classifiers.iter().enumerate() {
+        if metadata.classifiers[..index].contains(classifier) {
+            diagnostics.push(MetadataDiagnostic::warning(format!(
+                "Duplicate classifier: `{classifier}`"
+            )));
+            continue;
+        }
+
+        let segments = classifier.split(" :: ").collect::<Vec<_>>();
+        if classifier.trim() != classifier
+            || segments.len() < 2
+            || segments
+                .iter()
+                .any(|segment| segment.trim().is_empty() || segment.trim() != *segment)
+        {
+            diagnostics.push(MetadataDiagnostic::error(format!(
+                "Invalid classifier: `{classifier}` (expected segments separated by ` :: `)"
+            )));
+            continue;
+        }
+
+        match segments[0] {
+            "Private" => {
+                diagnostics.push(MetadataDiagnostic::warning(format!(
+                    "Private classifier: `{classifier}`, uploads to PyPI will be rejected"
+                )));
+            }
+            "License" if metadata.license_expression.is_some() => {
+                diagnostics.push(MetadataDiagnostic::error(format!(
+                    "License classifiers are not allowed with `License-Expression`: \
+                    `{classifier}`"
+                )));
+            }
+            category if !CLASSIFIER_CATEGORIES.contains(&category) => {
+                diagnostics.push(MetadataDiagnostic::error(format!(
+                    "Unknown classifier category `{category}` in `{classifier}`"
+                )));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Check that the license expression is a valid SPDX expression.
+fn check_license_expression(metadata: &Metadata23, diagnostics: &mut Vec<MetadataDiagnostic>) {
+    let Some(license_expression) = metadata.license_expression.as_deref() else {
+        return;
+    };
+    if let Err(err) = spdx::Expression::parse(license_expression) {
+        diagnostics.push(MetadataDiagnostic::error(format!(
+            "Invalid license expression `{license_expression}`: {}",
+            err.reason
+        )));
+    }
+    let supports_license_expression = metadata
+        .metadata_version
+        .split_once('.')
+        .and_then(|(major, minor)| Some((major.parse::<u8>().ok()?, minor.parse::<u8>().ok()?)))
+        .is_some_and(|version| version >= (2, 4));
+    if !supports_license_expression {
+        diagnostics.push(MetadataDiagnostic::error(format!(
+            "`License-Expression` requires metadata version 2.4 or later, found: {}",
+            metadata.metadata_version
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use insta::assert_snapshot;
+    use itertools::Itertools;
+
+    fn check(metadata: &str) -> String {
+        let metadata = Metadata23::parse(metadata.as_bytes()).unwrap();
+        check_metadata(&metadata).iter().join("\n")
+    }
+
+    #[test]
+    fn valid() {
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.4
+            Name: foo
+            Version: 1.0.0
+            Classifier: Programming Language :: Python :: 3
+            License-Expression: MIT OR Apache-2.0
+            Description-Content-Type: text/x-rst; charset=UTF-8
+
+            Foo
+            ===
+
+            .. note:: This is fine.
+
+            .. |logo| image:: logo.png
+        "};
+        assert_snapshot!(check(metadata), @"");
+    }
+
+    #[test]
+    fn description() {
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.4
+            Name: foo
+            Version: 1.0.0
+        "};
+        assert_snapshot!(check(metadata), @"warning: `long_description` missing, the project page will be empty");
+
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.4
+            Name: foo
+            Version: 1.0.0
+            Description-Content-Type: text/html; charset=latin-1
+
+            <p>Foo</p>
+        "};
+        assert_snapshot!(check(metadata), @r"
+        error: Unsupported charset in `long_description_content_type`: `latin-1` (expected `UTF-8`)
+        error: Unsupported `long_description_content_type`: `text/html` (expected `text/plain`, `text/x-rst`, or `text/markdown`)
+        ");
+
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.4
+            Name: foo
+            Version: 1.0.0
+            Description-Content-Type: text/markdown; variant=Markua
+
+            # Foo
+        "};
+        assert_snapshot!(check(metadata), @"error: Unsupported Markdown variant in `long_description_content_type`: `Markua` (expected `GFM` or `CommonMark`)");
+    }
+
+    #[test]
+    fn rst() {
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.4
+            Name: foo
+            Version: 1.0.0
+
+            A long title
+            ======
+
+            .. raw:: html
+
+               <b>Bold</b>
+
+            .. automodule:: foo
+
+            .. NOTE:: Directive names are case-insensitive.
+
+            .. A comment:: is not a directive.
+        "};
+        assert_snapshot!(check(metadata), @r"
+        warning: `long_description_content_type` missing, defaulting to `text/x-rst`
+        error: The description failed to render (line 2): Title underline too short
+        error: The description failed to render (line 4): The `raw` directive is disabled
+        error: The description failed to render (line 8): Unknown directive type `automodule`
+        ");
+    }
+
+    #[test]
+    fn classifiers() {
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.4
+            Name: foo
+            Version: 1.0.0
+            Classifier: Programming Language :: Python
+            Classifier: Programming Language :: Python
+            Classifier: Programming Language::Python
+            Classifier: Programming Language ::
+            Classifier: Private :: Do Not Upload
+            Classifier: Languages :: Python
+            Classifier: License :: OSI Approved :: MIT License
+            License-Expression: MIT
+            Description-Content-Type: text/markdown
+
+            # Foo
+        "};
+        assert_snapshot!(check(metadata), @r"
+        warning: Duplicate classifier: `Programming Language :: Python`
+        error: Invalid classifier: `Programming Language::Python` (expected segments separated by ` :: `)
+        error: Invalid classifier: `Programming Language ::` (expected segments separated by ` :: `)
+        warning: Private classifier: `Private :: Do Not Upload`, uploads to PyPI will be rejected
+        error: Unknown classifier category `Languages` in `Languages :: Python`
+        error: License classifiers are not allowed with `License-Expression`: `License :: OSI Approved :: MIT License`
+        ");
+    }
+
+    #[test]
+    fn license_expression() {
+        let metadata = indoc::indoc! {"
+            Metadata-Version: 2.3
+            Name: foo
+            Version: 1.0.0
+            License-Expression: MIT OR
+            Description-Content-Type: text/plain
+
+            Foo
+        "};
+        assert_snapshot!(check(metadata), @r"
+        error: Invalid license expression `MIT OR`: expected one of `<license>`, `(` here
+        error: `License-Expression` requires metadata version 2.4 or later, found: 2.3
+        ");
+    }
+}
@@ -1,9 +1,10 @@
+mod check;
 mod trusted_publishing;
 
 use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::{fmt, io};
+use std::{env, fmt, io};
 
 use fs_err::tokio::File;
 use futures::TryStreamExt;
@@ -13,7 +14,6 @@ use reqwest::header::{AUTHORIZATION, LOCATION, ToStrError};
 use reqwest::multipart::Part;
 use reqwest::{Body, Response, StatusCode};
 use reqwest_retry::RetryError;
-use reqwest_retry::policies::ExponentialBackoff;
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 use thiserror::Error;
@@ -27,7 +27,7 @@ use uv_auth::{Credentials, PyxTokenStore, Realm};
 use uv_cache::{Cache, Refresh};
 use uv_client::{
     BaseClient, DEFAULT_MAX_REDIRECTS, MetadataFormat, OwnedArchive, RegistryClientBuilder,
-    RequestBuilder, RetryParsingError, RetryState,
+    RequestBuilder, RetryParsingError, RetryState, UvRetryPolicy,
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
@@ -37,8 +37,10 @@ use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
+pub use crate::check::{MetadataDiagnostic, Severity};
 use crate::trusted_publishing::pypi::PyPIPublishingService;
 use crate::trusted_publishing::pyx::PyxPublishingService;
 use crate::trusted_publishing::{
@@ -423,19 +425,7 @@ pub async fn check_trusted_publishing(
             debug!("Attempting to get a token for trusted publishing");
 
             // Attempt to get a token for trusted publishing.
-            let token = if token_store.is_known_url(registry) {
-                debug!("Using trusted publishing flow for pyx");
-                PyxPublishingService::new(registry, client)
-                    .get_token()
-                    .await
-            } else {
-                debug!("Using trusted publishing flow for PyPI");
-                PyPIPublishingService::new(registry, client)
-                    .get_token()
-                    .await
-            };
-
-            match token {
+            match trusted_publishing_token(token_store, registry, client).await {
                 // Success: we have a token for trusted publishing.
                 Ok(Some(token)) => Ok(TrustedPublishResult::Configured(token)),
                 // Failed to discover an ambient OIDC token.
@@ -447,7 +437,7 @@ pub async fn check_trusted_publishing(
             }
         }
         TrustedPublishing::Always => {
-            debug!("Using trusted publishing for GitHub Actions");
+            debug!("Using trusted publishing");
 
             let mut conflicts = Vec::new();
             if username.is_some() {
@@ -464,19 +454,9 @@ pub async fn check_trusted_publishing(
             }
 
             // Attempt to get a token for trusted publishing.
-            let token = if token_store.is_known_url(registry) {
-                debug!("Using trusted publishing flow for pyx");
-                PyxPublishingService::new(registry, client)
-                    .get_token()
-                    .await
-                    .map_err(Box::new)?
-            } else {
-                debug!("Using trusted publishing flow for PyPI");
-                PyPIPublishingService::new(registry, client)
-                    .get_token()
-                    .await
-                    .map_err(Box::new)?
-            };
+            let token = trusted_publishing_token(token_store, registry, client)
+                .await
+                .map_err(Box::new)?;
 
             let Some(token) = token else {
                 return Err(PublishError::TrustedPublishing(
@@ -490,6 +470,30 @@ pub async fn check_trusted_publishing(
     }
 }
 
+/// Exchange an OIDC token for an upload token, using the token exchange flow of the registry.
+///
+/// pyx registries use pyx's token exchange, all other registries use PyPI's. A token exchange
+/// endpoint configured through `UV_PUBLISH_OIDC_EXCHANGE_URL` always uses PyPI's protocol.
+async fn trusted_publishing_token(
+    token_store: &PyxTokenStore,
+    registry: &DisplaySafeUrl,
+    client: &BaseClient,
+) -> Result<Option<TrustedPublishingToken>, TrustedPublishingError> {
+    if token_store.is_known_url(registry)
+        && env::var_os(EnvVars::UV_PUBLISH_OIDC_EXCHANGE_URL).is_none_or(|url| url.is_empty())
+    {
+        debug!("Using trusted publishing flow for pyx");
+        PyxPublishingService::new(registry, client)
+            .get_token()
+            .await
+    } else {
+        debug!("Using trusted publishing flow for PyPI");
+        PyPIPublishingService::new(registry, client)?
+            .get_token()
+            .await
+    }
+}
+
 /// Upload a file to a registry.
 ///
 /// Returns `true` if the file was newly uploaded and `false` if it already existed.
@@ -500,7 +504,7 @@ pub async fn upload(
     form_metadata: &FormMetadata,
     registry: &DisplaySafeUrl,
     client: &BaseClient,
-    retry_policy: ExponentialBackoff,
+    retry_policy: UvRetryPolicy,
     credentials: &Credentials,
     check_url_client: Option<&CheckUrlClient<'_>>,
     download_concurrency: &Semaphore,
@@ -731,7 +735,7 @@ pub async fn upload_two_phase(
     registry: &DisplaySafeUrl,
     client: &BaseClient,
     s3_client: &BaseClient,
-    retry_policy: ExponentialBackoff,
+    retry_policy: UvRetryPolicy,
     credentials: &Credentials,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
@@ -1101,6 +1105,16 @@ async fn source_dist_pkg_info(file: &Path) -> Result<Vec<u8>, PublishPrepareErro
     }
 }
 
+/// Check the metadata of a distribution for problems that would cause the index to reject the
+/// upload or fail to render the description, similar to `twine check`.
+pub async fn check_metadata(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<Vec<MetadataDiagnostic>, PublishPrepareError> {
+    let metadata = metadata(file, filename).await?;
+    Ok(check::check_metadata(&metadata))
+}
+
 async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, PublishPrepareError> {
     let contents = match filename {
         DistFilename::SourceDistFilename(source_dist) => {
@@ -1,15 +1,19 @@
-//! Trusted publishing (via OIDC) with GitHub Actions and GitLab CI.
+//! Trusted publishing (via OIDC) with GitHub Actions, GitLab CI, Buildkite, or an OIDC token
+//! provided through `UV_PUBLISH_OIDC_TOKEN` by any other CI provider.
 
 use base64::Engine;
 use base64::prelude::BASE64_URL_SAFE_NO_PAD;
 use reqwest::StatusCode;
 use reqwest_middleware::ClientWithMiddleware;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 use std::fmt::Display;
 use thiserror::Error;
+use tracing::debug;
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_static::EnvVars;
+use uv_warnings::warn_user;
 
 pub(crate) mod pypi;
 pub(crate) mod pyx;
@@ -85,6 +89,9 @@ pub enum OidcTokenClaims {
     GitHub(GitHubTokenClaims),
     GitLab(GitLabTokenClaims),
     Buildkite(BuildkiteTokenClaims),
+    /// The claims of a token from a provider without dedicated support, e.g., a token passed
+    /// through `UV_PUBLISH_OIDC_TOKEN`.
+    Other(BTreeMap<String, serde_json::Value>),
 }
 
 /// The relevant payload of a GitHub OIDC token.
@@ -119,6 +126,24 @@ pub struct BuildkiteTokenClaims {
     organization_slug: String,
 }
 
+/// An OIDC identity token to exchange for an upload token.
+pub(crate) enum OidcToken {
+    /// A token discovered in a supported CI environment.
+    Ambient(ambient_id::IdToken),
+    /// A token provided by the user through `UV_PUBLISH_OIDC_TOKEN`.
+    Provided(String),
+}
+
+impl OidcToken {
+    /// Reveal the token, which is a secret and must be handled with care.
+    pub(crate) fn reveal(&self) -> &str {
+        match self {
+            Self::Ambient(token) => token.reveal(),
+            Self::Provided(token) => token,
+        }
+    }
+}
+
 /// A service (i.e. uploadable index) that supports trusted publishing.
 ///
 /// Interactions should go through the default [`get_token`]; implementors
@@ -130,10 +155,10 @@ pub(crate) trait TrustedPublishingService {
     /// Retrieve the service's expected OIDC audience.
     async fn audience(&self) -> Result<String, TrustedPublishingError>;
 
-    /// Exchange an ambient OIDC identity token for a short-lived upload token on the service.
+    /// Exchange an OIDC identity token for a short-lived upload token on the service.
     async fn exchange_token(
         &self,
-        oidc_token: ambient_id::IdToken,
+        oidc_token: OidcToken,
     ) -> Result<TrustedPublishingToken, TrustedPublishingError>;
 
     /// Perform the full trusted publishing token exchange.
@@ -141,10 +166,16 @@ pub(crate) trait TrustedPublishingService {
         // Get the OIDC token's audience from the registry.
         let audience = self.audience().await?;
 
-        // Perform ambient OIDC token discovery.
+        // Use the user-provided OIDC token, or perform ambient OIDC token discovery.
         // Depending on the host (GitHub Actions, GitLab CI, etc.)
         // this may perform additional network requests.
-        let oidc_token = get_oidc_token(&audience, self.client()).await?;
+        let oidc_token = if let Some(oidc_token) = provided_oidc_token(&audience) {
+            Some(oidc_token)
+        } else {
+            get_oidc_token(&audience, self.client())
+                .await?
+                .map(OidcToken::Ambient)
+        };
 
         // Exchange the OIDC token for a short-lived upload token,
         // if OIDC token discovery succeeded.
@@ -165,6 +196,35 @@ pub(crate) trait TrustedPublishingService {
     }
 }
 
+/// Read an OIDC token minted by a CI provider without dedicated support from
+/// `UV_PUBLISH_OIDC_TOKEN`.
+fn provided_oidc_token(audience: &str) -> Option<OidcToken> {
+    let oidc_token = env::var(EnvVars::UV_PUBLISH_OIDC_TOKEN)
+        .ok()
+        .filter(|token| !token.is_empty())?;
+    debug!(
+        "Using the OIDC token from `{}`",
+        EnvVars::UV_PUBLISH_OIDC_TOKEN
+    );
+
+    // The registry rejects tokens for a different audience, point out the likely cause early.
+    let token_audiences = decode_oidc_token_audiences(&oidc_token);
+    if let Some(token_audiences) = token_audiences {
+        if !token_audiences.iter().any(|aud| aud == audience) {
+            warn_user!(
+                "The OIDC token in `{}` was minted for the audience {}, but the index expects the audience `{audience}`",
+                EnvVars::UV_PUBLISH_OIDC_TOKEN,
+                token_audiences
+                    .iter()
+                    .map(|aud| format!("`{aud}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+    }
+    Some(OidcToken::Provided(oidc_token))
+}
+
 /// Perform ambient OIDC token discovery.
 async fn get_oidc_token(
     audience: &str,
@@ -185,14 +245,41 @@ async fn get_oidc_token(
     }
 }
 
-/// Parse the JSON Web Token that the OIDC token is.
-///
-/// See: <https://github.com/pypa/gh-action-pypi-publish/blob/db8f07d3871a0a180efa06b95d467625c19d5d5f/oidc-exchange.py#L165-L184>
-fn decode_oidc_token(oidc_token: &str) -> Option<OidcTokenClaims> {
+/// Decode the payload of the JSON Web Token that the OIDC token is.
+fn decode_oidc_token_payload(oidc_token: &str) -> Option<Vec<u8>> {
     let token_segments = oidc_token.splitn(3, '.').collect::<Vec<&str>>();
     let [_header, payload, _signature] = *token_segments.into_boxed_slice() else {
         return None;
     };
-    let decoded = BASE64_URL_SAFE_NO_PAD.decode(payload).ok()?;
-    serde_json::from_slice(&decoded).ok()
+    BASE64_URL_SAFE_NO_PAD.decode(payload).ok()
+}
+
+/// Read the `aud` claim of the OIDC token, which is either a single audience or a list of
+/// audiences.
+fn decode_oidc_token_audiences(oidc_token: &str) -> Option<Vec<String>> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Audiences {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    #[derive(Deserialize)]
+    struct AudienceClaim {
+        aud: Audiences,
+    }
+
+    let payload = decode_oidc_token_payload(oidc_token)?;
+    match serde_json::from_slice::<AudienceClaim>(&payload).ok()?.aud {
+        Audiences::One(aud) => Some(vec![aud]),
+        Audiences::Many(aud) => Some(aud),
+    }
+}
+
+/// Parse the JSON Web Token that the OIDC token is.
+///
+/// See: <https://github.com/pypa/gh-action-pypi-publish/blob/db8f07d3871a0a180efa06b95d467625c19d5d5f/oidc-exchange.py#L165-L184>
+fn decode_oidc_token(oidc_token: &str) -> Option<OidcTokenClaims> {
+    let payload = decode_oidc_token_payload(oidc_token)?;
+    serde_json::from_slice(&payload).ok()
 }
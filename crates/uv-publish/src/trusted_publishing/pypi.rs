@@ -1,27 +1,48 @@
 //! Services that implement PyPI's Trusted Publishing interfaces.
 
+use std::env;
+
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::{debug, trace};
 use url::Url;
 use uv_client::BaseClient;
-use uv_redacted::DisplaySafeUrl;
+use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+use uv_static::EnvVars;
 
 use crate::trusted_publishing::{
-    Audience, MintTokenRequest, PublishToken, TrustedPublishingError, TrustedPublishingService,
-    decode_oidc_token,
+    Audience, MintTokenRequest, OidcToken, PublishToken, TrustedPublishingError,
+    TrustedPublishingService, decode_oidc_token,
 };
 
 pub(crate) struct PyPIPublishingService<'a> {
     client: &'a ClientWithMiddleware,
     registry: &'a DisplaySafeUrl,
+    /// The audience from `UV_PUBLISH_OIDC_AUDIENCE`, overriding the registry's audience.
+    audience: Option<String>,
+    /// The token exchange endpoint from `UV_PUBLISH_OIDC_EXCHANGE_URL`, overriding the
+    /// registry's mint token endpoint.
+    exchange_url: Option<DisplaySafeUrl>,
 }
 
 impl<'a> PyPIPublishingService<'a> {
-    pub(crate) fn new(registry: &'a DisplaySafeUrl, client: &'a BaseClient) -> Self {
-        Self {
+    pub(crate) fn new(
+        registry: &'a DisplaySafeUrl,
+        client: &'a BaseClient,
+    ) -> Result<Self, DisplaySafeUrlError> {
+        let audience = env::var(EnvVars::UV_PUBLISH_OIDC_AUDIENCE)
+            .ok()
+            .filter(|audience| !audience.is_empty());
+        let exchange_url = env::var(EnvVars::UV_PUBLISH_OIDC_EXCHANGE_URL)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .map(|url| DisplaySafeUrl::parse(&url))
+            .transpose()?;
+        Ok(Self {
             client: client.for_host(registry).raw_client(),
             registry,
-        }
+            audience,
+            exchange_url,
+        })
     }
 }
 
@@ -31,6 +52,14 @@ impl TrustedPublishingService for PyPIPublishingService<'_> {
     }
 
     async fn audience(&self) -> Result<String, super::TrustedPublishingError> {
+        if let Some(audience) = &self.audience {
+            debug!(
+                "Using the trusted publishing audience from `{}`",
+                EnvVars::UV_PUBLISH_OIDC_AUDIENCE
+            );
+            return Ok(audience.clone());
+        }
+
         // `pypa/gh-action-pypi-publish` uses `netloc` (RFC 1808), which is deprecated for authority
         // (RFC 3986).
         // Prefer HTTPS for OIDC discovery; allow HTTP only in test builds
@@ -63,19 +92,23 @@ impl TrustedPublishingService for PyPIPublishingService<'_> {
 
     async fn exchange_token(
         &self,
-        oidc_token: ambient_id::IdToken,
+        oidc_token: OidcToken,
     ) -> Result<super::TrustedPublishingToken, super::TrustedPublishingError> {
-        // Prefer HTTPS for OIDC minting; allow HTTP only in test builds
-        let scheme: &str = if cfg!(feature = "test") {
-            self.registry.scheme()
+        let mint_token_url = if let Some(exchange_url) = &self.exchange_url {
+            exchange_url.clone()
         } else {
-            "https"
+            // Prefer HTTPS for OIDC minting; allow HTTP only in test builds
+            let scheme: &str = if cfg!(feature = "test") {
+                self.registry.scheme()
+            } else {
+                "https"
+            };
+            DisplaySafeUrl::parse(&format!(
+                "{}://{}/_/oidc/mint-token",
+                scheme,
+                self.registry.authority()
+            ))?
         };
-        let mint_token_url = DisplaySafeUrl::parse(&format!(
-            "{}://{}/_/oidc/mint-token",
-            scheme,
-            self.registry.authority()
-        ))?;
         debug!("Querying the trusted publishing upload token from {mint_token_url}");
         let mint_token_payload = MintTokenRequest {
             token: oidc_token.reveal().to_string(),
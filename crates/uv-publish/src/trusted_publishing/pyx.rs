@@ -7,8 +7,8 @@ use url::Url;
 use uv_redacted::DisplaySafeUrl;
 
 use crate::trusted_publishing::{
-    Audience, MintTokenRequest, PublishToken, TrustedPublishingError, TrustedPublishingService,
-    TrustedPublishingToken, decode_oidc_token,
+    Audience, MintTokenRequest, OidcToken, PublishToken, TrustedPublishingError,
+    TrustedPublishingService, TrustedPublishingToken, decode_oidc_token,
 };
 
 pub(crate) struct PyxPublishingService<'a> {
@@ -68,7 +68,7 @@ impl TrustedPublishingService for PyxPublishingService<'_> {
 
     async fn exchange_token(
         &self,
-        oidc_token: ambient_id::IdToken,
+        oidc_token: OidcToken,
     ) -> Result<TrustedPublishingToken, TrustedPublishingError> {
         // Prefer HTTPS for OIDC minting; allow HTTP only in test builds
         let scheme: &str = if cfg!(feature = "test") {
@@ -8,6 +8,8 @@ pub use marker_environment::*;
 pub use metadata::*;
 pub use parsed_url::*;
 pub use project_status::*;
+pub use provenance::*;
+pub use repository_tracking::*;
 pub use scheme::*;
 pub use simple_json::*;
 pub use supported_environments::*;
@@ -22,6 +24,8 @@ mod marker_environment;
 mod metadata;
 mod parsed_url;
 mod project_status;
+mod provenance;
+mod repository_tracking;
 mod scheme;
 mod simple_json;
 mod supported_environments;
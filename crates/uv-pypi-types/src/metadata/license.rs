@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::metadata::Headers;
+
+/// The license of a distribution, as declared in its metadata.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[rkyv(derive(Debug))]
+pub enum License {
+    /// An SPDX license expression, as declared in the `License-Expression` field (PEP 639).
+    Expression(String),
+    /// The names of the licenses, as derived from the `License ::` Trove classifiers or, absent
+    /// any such classifiers, the legacy `License` field.
+    Names(Vec<String>),
+}
+
+impl License {
+    /// Derive the [`License`] from the license fields and classifiers of a distribution's
+    /// metadata.
+    ///
+    /// The legacy `License` field is only used if it spans a single line, as it often contains the
+    /// full text of the license.
+    pub fn from_fields<'a>(
+        expression: Option<&str>,
+        license: Option<&str>,
+        classifiers: impl IntoIterator<Item = &'a str>,
+    ) -> Option<Self> {
+        if let Some(expression) = expression.map(str::trim).filter(|value| !value.is_empty()) {
+            return Some(Self::Expression(expression.to_string()));
+        }
+
+        let names = classifiers
+            .into_iter()
+            .filter_map(|classifier| {
+                let mut segments = classifier.split("::").map(str::trim);
+                if segments.next() != Some("License") {
+                    return None;
+                }
+                // Skip classifiers that only categorize the license, e.g.,
+                // `License :: OSI Approved`.
+                let name = segments.last()?;
+                if name.is_empty() || name == "OSI Approved" {
+                    return None;
+                }
+                Some(name.to_string())
+            })
+            .collect::<Vec<_>>();
+        if !names.is_empty() {
+            return Some(Self::Names(names));
+        }
+
+        let license = license
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && !value.contains('\n'))?;
+        Some(Self::Names(vec![license.to_string()]))
+    }
+
+    /// Derive the [`License`] from the headers of a `METADATA` or `PKG-INFO` file.
+    pub(super) fn from_headers(headers: &Headers) -> Option<Self> {
+        let classifiers = headers.get_all_values("Classifier").collect::<Vec<_>>();
+        Self::from_fields(
+            headers.get_first_value("License-Expression").as_deref(),
+            headers.get_first_value("License").as_deref(),
+            classifiers.iter().map(String::as_str),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::License;
+
+    #[test]
+    fn from_fields() {
+        assert_eq!(
+            License::from_fields(
+                Some("MIT OR Apache-2.0"),
+                Some("MIT"),
+                ["License :: OSI Approved :: MIT License"],
+            ),
+            Some(License::Expression("MIT OR Apache-2.0".to_string()))
+        );
+        assert_eq!(
+            License::from_fields(
+                None,
+                Some("MIT"),
+                [
+                    "License :: OSI Approved",
+                    "License :: OSI Approved :: MIT License",
+                    "Programming Language :: Python",
+                ],
+            ),
+            Some(License::Names(vec!["MIT License".to_string()]))
+        );
+        assert_eq!(
+            License::from_fields(None, Some("BSD-3-Clause"), []),
+            Some(License::Names(vec!["BSD-3-Clause".to_string()]))
+        );
+        assert_eq!(
+            License::from_fields(None, Some("Copyright (c)\nAll rights reserved."), []),
+            None
+        );
+    }
+}
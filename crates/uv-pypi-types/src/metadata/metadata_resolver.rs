@@ -11,8 +11,8 @@ use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::Requirement;
 
 use crate::lenient_requirement::LenientRequirement;
-use crate::metadata::Headers;
 use crate::metadata::pyproject_toml::PyProjectToml;
+use crate::metadata::{Headers, License, ProjectLinks};
 use crate::{LenientVersionSpecifiers, MetadataError, VerbatimParsedUrl, metadata};
 
 /// A subset of the full core metadata specification, including only the
@@ -36,6 +36,12 @@ pub struct ResolutionMetadata {
     /// Whether the version field is dynamic.
     #[serde(default)]
     pub dynamic: bool,
+    /// The license of the distribution, if declared.
+    #[serde(default)]
+    pub license: Option<License>,
+    /// The homepage and source repository of the distribution, if declared.
+    #[serde(default)]
+    pub project_links: Option<ProjectLinks>,
 }
 
 /// From <https://github.com/PyO3/python-pkginfo-rs/blob/d719988323a0cfea86d4737116d7917f30e819e2/src/metadata.rs#LL78C2-L91C26>
@@ -80,6 +86,8 @@ impl ResolutionMetadata {
         let dynamic = headers
             .get_all_values("Dynamic")
             .any(|field| field == "Version");
+        let license = License::from_headers(&headers);
+        let project_links = ProjectLinks::from_headers(&headers);
 
         Ok(Self {
             name,
@@ -88,6 +96,8 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license,
+            project_links,
         })
     }
 
@@ -157,6 +167,8 @@ impl ResolutionMetadata {
                 },
             )
             .collect::<Box<_>>();
+        let license = License::from_headers(&headers);
+        let project_links = ProjectLinks::from_headers(&headers);
 
         Ok(Self {
             name,
@@ -165,6 +177,8 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license,
+            project_links,
         })
     }
 
@@ -257,6 +271,20 @@ impl ResolutionMetadata {
             .into_keys()
             .collect::<Box<_>>();
 
+        let license = License::from_fields(
+            project.license_expression.as_deref(),
+            project.license_text.as_deref(),
+            project.classifiers.iter().flatten().map(String::as_str),
+        );
+        let project_links = ProjectLinks::from_fields(
+            None,
+            project
+                .urls
+                .iter()
+                .flatten()
+                .map(|(label, url)| (label.as_str(), url.as_str())),
+        );
+
         Ok(Self {
             name,
             version,
@@ -264,6 +292,8 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license,
+            project_links,
         })
     }
 }
@@ -1,7 +1,9 @@
 mod build_requires;
+mod license;
 mod metadata10;
 mod metadata23;
 mod metadata_resolver;
+mod project_links;
 mod pyproject_toml;
 mod requires_dist;
 mod requires_txt;
@@ -18,9 +20,11 @@ use uv_pep508::Pep508Error;
 use crate::VerbatimParsedUrl;
 
 pub use build_requires::BuildRequires;
+pub use license::License;
 pub use metadata_resolver::ResolutionMetadata;
 pub use metadata10::Metadata10;
 pub use metadata23::{Keywords, Metadata23, ProjectUrls};
+pub use project_links::ProjectLinks;
 pub use pyproject_toml::PyProjectToml;
 pub use requires_dist::RequiresDist;
 pub use requires_txt::RequiresTxt;
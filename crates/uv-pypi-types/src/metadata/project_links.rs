@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::metadata::Headers;
+
+/// The homepage and source repository of a distribution, as declared in its metadata.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[rkyv(derive(Debug))]
+pub struct ProjectLinks {
+    /// The URL of the project's homepage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// The URL of the project's source repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+}
+
+impl ProjectLinks {
+    /// Derive the [`ProjectLinks`] from the legacy `Home-page` field and the labeled project URLs
+    /// of a distribution's metadata.
+    ///
+    /// Labels are normalized and matched against the well-known labels, and their aliases, defined
+    /// in the [core metadata specification](https://packaging.python.org/en/latest/specifications/well-known-project-urls/).
+    pub fn from_fields<'a>(
+        home_page: Option<&str>,
+        urls: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Option<Self> {
+        let mut links = Self::default();
+        for (label, url) in urls {
+            let url = url.trim();
+            if url.is_empty() {
+                continue;
+            }
+            let label = label
+                .chars()
+                .filter(|c| !c.is_whitespace() && !c.is_ascii_punctuation())
+                .flat_map(char::to_lowercase)
+                .collect::<String>();
+            match label.as_str() {
+                "homepage" => {
+                    links.homepage.get_or_insert_with(|| url.to_string());
+                }
+                "source" | "repository" | "sourcecode" | "github" => {
+                    links.repository.get_or_insert_with(|| url.to_string());
+                }
+                _ => {}
+            }
+        }
+        if links.homepage.is_none()
+            && let Some(home_page) = home_page.map(str::trim).filter(|url| !url.is_empty())
+        {
+            links.homepage = Some(home_page.to_string());
+        }
+
+        if links == Self::default() {
+            None
+        } else {
+            Some(links)
+        }
+    }
+
+    /// Derive the [`ProjectLinks`] from the headers of a `METADATA` or `PKG-INFO` file.
+    pub(super) fn from_headers(headers: &Headers) -> Option<Self> {
+        let urls = headers.get_all_values("Project-URL").collect::<Vec<_>>();
+        Self::from_fields(
+            headers.get_first_value("Home-page").as_deref(),
+            urls.iter().filter_map(|value| {
+                let (label, url) = value.split_once(',')?;
+                Some((label, url))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectLinks;
+
+    #[test]
+    fn from_fields() {
+        assert_eq!(
+            ProjectLinks::from_fields(
+                Some("https://legacy.example.com"),
+                [
+                    ("Documentation", "https://docs.example.com"),
+                    ("Home-Page", "https://example.com"),
+                    ("Source Code", " https://github.com/example/example "),
+                ],
+            ),
+            Some(ProjectLinks {
+                homepage: Some("https://example.com".to_string()),
+                repository: Some("https://github.com/example/example".to_string()),
+            })
+        );
+        assert_eq!(
+            ProjectLinks::from_fields(Some("https://example.com"), []),
+            Some(ProjectLinks {
+                homepage: Some("https://example.com".to_string()),
+                repository: None,
+            })
+        );
+        assert_eq!(
+            ProjectLinks::from_fields(Some(""), [("Changelog", "https://example.com/changes")]),
+            None
+        );
+    }
+}
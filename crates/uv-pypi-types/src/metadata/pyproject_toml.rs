@@ -49,6 +49,14 @@ pub struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified
     /// so another tool can/will provide such metadata dynamically.
     pub dynamic: Option<Vec<String>>,
+    /// The SPDX license expression of the project (PEP 639)
+    pub license_expression: Option<String>,
+    /// The license text of the project, as declared in the legacy `license.text` table
+    pub license_text: Option<String>,
+    /// Trove classifiers which apply to the project
+    pub classifiers: Option<Vec<String>>,
+    /// The labeled URLs of the project, e.g., its homepage
+    pub urls: Option<IndexMap<String, String>>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -60,6 +68,24 @@ struct PyprojectTomlWire {
     dependencies: Option<Vec<String>>,
     optional_dependencies: Option<IndexMap<ExtraName, Vec<String>>>,
     dynamic: Option<Vec<String>>,
+    #[serde(default)]
+    license: Option<ProjectLicense>,
+    #[serde(default)]
+    classifiers: Option<Vec<String>>,
+    #[serde(default)]
+    urls: Option<IndexMap<String, String>>,
+}
+
+/// The `project.license` field, which is either an SPDX license expression (PEP 639) or a legacy
+/// table.
+///
+/// Unrecognized values are ignored, as the license isn't relevant to dependency resolution.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum ProjectLicense {
+    Expression(String),
+    Text { text: String },
+    Other(serde::de::IgnoredAny),
 }
 
 impl TryFrom<PyprojectTomlWire> for Project {
@@ -67,6 +93,11 @@ impl TryFrom<PyprojectTomlWire> for Project {
 
     fn try_from(wire: PyprojectTomlWire) -> Result<Self, Self::Error> {
         let name = wire.name.ok_or(MetadataError::MissingName)?;
+        let (license_expression, license_text) = match wire.license {
+            Some(ProjectLicense::Expression(expression)) => (Some(expression), None),
+            Some(ProjectLicense::Text { text }) => (None, Some(text)),
+            Some(ProjectLicense::Other(_)) | None => (None, None),
+        };
         Ok(Self {
             name,
             version: wire.version,
@@ -74,6 +105,10 @@ impl TryFrom<PyprojectTomlWire> for Project {
             dependencies: wire.dependencies,
             optional_dependencies: wire.optional_dependencies,
             dynamic: wire.dynamic,
+            license_expression,
+            license_text,
+            classifiers: wire.classifiers,
+            urls: wire.urls,
         })
     }
 }
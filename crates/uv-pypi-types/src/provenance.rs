@@ -0,0 +1,151 @@
+//! PEP 740 provenance and attestation types.
+//!
+//! See: <https://peps.python.org/pep-0740/>
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use uv_small_str::SmallString;
+
+/// A provenance object, as served at a file's `provenance` URL.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Provenance {
+    /// The provenance object's version, which is always `1`.
+    pub version: u8,
+    /// The attestation bundles for the file, one per publisher.
+    pub attestation_bundles: Vec<AttestationBundle>,
+}
+
+/// A set of attestations produced by a single Trusted Publisher.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AttestationBundle {
+    /// The Trusted Publisher that produced the attestations.
+    pub publisher: Publisher,
+    /// The attestations themselves.
+    pub attestations: Vec<Attestation>,
+}
+
+/// The Trusted Publisher identity associated with an [`AttestationBundle`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Publisher {
+    /// The kind of publisher, e.g., `GitHub` or `GitLab`.
+    pub kind: SmallString,
+    /// The repository that published the file, e.g., `owner/project`.
+    #[serde(default)]
+    pub repository: Option<SmallString>,
+    /// The workflow that published the file, e.g., `release.yml`.
+    #[serde(default, alias = "workflow_filepath")]
+    pub workflow: Option<SmallString>,
+    /// The deployment environment of the workflow, if any.
+    #[serde(default)]
+    pub environment: Option<SmallString>,
+}
+
+impl std::fmt::Display for Publisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.repository, &self.workflow) {
+            (Some(repository), Some(workflow)) => {
+                write!(f, "{} ({repository}, {workflow})", self.kind)
+            }
+            (Some(repository), None) => write!(f, "{} ({repository})", self.kind),
+            _ => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+/// A single PEP 740 attestation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Attestation {
+    /// The attestation's version, which is always `1`.
+    pub version: u8,
+    /// The material needed to verify the attestation's signature.
+    pub verification_material: VerificationMaterial,
+    /// The signed DSSE envelope.
+    pub envelope: Envelope,
+}
+
+/// The material needed to verify an [`Attestation`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerificationMaterial {
+    /// The base64-encoded DER signing certificate.
+    pub certificate: String,
+}
+
+/// A DSSE envelope containing an in-toto statement and its signature.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Envelope {
+    /// The base64-encoded in-toto statement.
+    pub statement: String,
+    /// The base64-encoded signature over the statement.
+    pub signature: String,
+}
+
+/// An in-toto statement, as embedded in an [`Envelope`].
+///
+/// See: <https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md>
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statement {
+    #[serde(rename = "_type")]
+    pub type_: String,
+    /// The artifacts the statement applies to.
+    pub subject: Vec<Subject>,
+    /// The type of the predicate, e.g., `https://docs.pypi.org/attestations/publish/v1`.
+    pub predicate_type: String,
+}
+
+/// An artifact referenced by a [`Statement`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct Subject {
+    /// The artifact name, which is the distribution filename.
+    pub name: String,
+    /// The artifact digests, keyed by algorithm.
+    pub digest: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Provenance;
+
+    #[test]
+    fn test_deserialize_provenance() {
+        let json = r#"
+        {
+            "version": 1,
+            "attestation_bundles": [
+                {
+                    "publisher": {
+                        "kind": "GitHub",
+                        "repository": "pypa/sampleproject",
+                        "workflow": "release.yml",
+                        "environment": null,
+                        "claims": null
+                    },
+                    "attestations": [
+                        {
+                            "version": 1,
+                            "verification_material": {
+                                "certificate": "MIIC",
+                                "transparency_entries": []
+                            },
+                            "envelope": {
+                                "statement": "eyJ9",
+                                "signature": "MEUC"
+                            }
+                        }
+                    ]
+                }
+            ]
+        }
+        "#;
+
+        let provenance: Provenance = serde_json::from_str(json).unwrap();
+        let bundle = &provenance.attestation_bundles[0];
+        assert_eq!(bundle.publisher.kind.as_ref(), "GitHub");
+        assert_eq!(
+            bundle.publisher.to_string(),
+            "GitHub (pypa/sampleproject, release.yml)"
+        );
+        assert_eq!(bundle.attestations[0].envelope.statement, "eyJ9");
+    }
+}
@@ -0,0 +1,72 @@
+//! PEP 708 repository tracking metadata types.
+//!
+//! See: <https://peps.python.org/pep-0708/>
+
+use serde::Deserialize;
+use uv_small_str::SmallString;
+
+/// The repository tracking metadata for a project.
+///
+/// Repositories use this metadata to declare that a project with the same name on another
+/// repository is either a mirror of this one (`tracks`), or part of the same namespace
+/// (`alternate-locations`), such that installers can safely merge their files.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize,
+)]
+#[rkyv(derive(Debug))]
+pub struct RepositoryTracking {
+    /// The URLs of the project pages on other repositories that this project tracks.
+    pub tracks: Vec<SmallString>,
+    /// The URLs of the project pages that are part of the same namespace as this project.
+    pub alternate_locations: Vec<SmallString>,
+}
+
+/// The `meta` key of a project detail response from the Simple API.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SimpleDetailMeta {
+    /// The PEP 708 URLs of the project pages on other repositories that this project tracks.
+    #[serde(default)]
+    pub tracks: Vec<SmallString>,
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_small_str::SmallString;
+
+    use crate::PypiSimpleDetail;
+
+    #[test]
+    fn test_deserialize_tracking() {
+        let json = r#"
+        {
+            "meta": {
+                "api-version": "1.2",
+                "tracks": ["https://pypi.org/simple/holygrail/"]
+            },
+            "alternate-locations": ["https://example.com/simple/holygrail/"],
+            "files": []
+        }
+        "#;
+
+        let detail: PypiSimpleDetail = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            detail.meta.tracks,
+            vec![SmallString::from("https://pypi.org/simple/holygrail/")]
+        );
+        assert_eq!(
+            detail.alternate_locations,
+            vec![SmallString::from("https://example.com/simple/holygrail/")]
+        );
+    }
+
+    /// Responses without tracking metadata are accepted.
+    #[test]
+    fn test_deserialize_no_tracking() {
+        let json = r#"{ "meta": { "api-version": "1.0" }, "files": [] }"#;
+
+        let detail: PypiSimpleDetail = serde_json::from_str(json).unwrap();
+        assert!(detail.meta.tracks.is_empty());
+        assert!(detail.alternate_locations.is_empty());
+    }
+}
@@ -11,16 +11,22 @@ use uv_pep508::Requirement;
 use uv_small_str::SmallString;
 
 use crate::lenient_requirement::LenientVersionSpecifiers;
-use crate::{ProjectStatus, VerbatimParsedUrl};
+use crate::{ProjectStatus, SimpleDetailMeta, VerbatimParsedUrl};
 
 /// A collection of "files" from `PyPI`'s JSON API for a single package, as served by the
 /// `vnd.pypi.simple.v1` media type.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct PypiSimpleDetail {
+    /// Metadata about the response.
+    #[serde(default)]
+    pub meta: SimpleDetailMeta,
     /// PEP 792 project status information.
     #[serde(default)]
     pub project_status: ProjectStatus,
+    /// PEP 708 alternate locations for the project.
+    #[serde(default)]
+    pub alternate_locations: Vec<SmallString>,
     /// The list of [`PypiFile`]s available for download sorted by filename.
     #[serde(deserialize_with = "sorted_simple_json_files")]
     pub files: Vec<PypiFile>,
@@ -51,6 +57,8 @@ pub struct PypiFile {
     pub core_metadata: Option<CoreMetadata>,
     pub filename: SmallString,
     pub hashes: Hashes,
+    /// The URL of the PEP 740 provenance object for this file, if any.
+    pub provenance: Option<SmallString>,
     pub requires_python: Option<Result<VersionSpecifiers, VersionSpecifiersParseError>>,
     pub size: Option<u64>,
     pub upload_time: Option<Timestamp>,
@@ -79,6 +87,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                 let mut core_metadata = None;
                 let mut filename = None;
                 let mut hashes = None;
+                let mut provenance = None;
                 let mut requires_python = None;
                 let mut size = None;
                 let mut upload_time = None;
@@ -96,6 +105,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                         }
                         "filename" => filename = Some(access.next_value()?),
                         "hashes" => hashes = Some(access.next_value()?),
+                        "provenance" => provenance = access.next_value()?,
                         "requires-python" => {
                             requires_python =
                                 access.next_value::<Option<Cow<'_, str>>>()?.map(|s| {
@@ -118,6 +128,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                     filename: filename
                         .ok_or_else(|| serde::de::Error::missing_field("filename"))?,
                     hashes: hashes.ok_or_else(|| serde::de::Error::missing_field("hashes"))?,
+                    provenance,
                     requires_python,
                     size,
                     upload_time,
@@ -1,7 +1,6 @@
 use itertools::{Either, Itertools};
 use owo_colors::AnsiColors;
 use regex::Regex;
-use reqwest_retry::policies::ExponentialBackoff;
 use rustc_hash::{FxBuildHasher, FxHashSet};
 use same_file::is_same_file;
 use std::borrow::Cow;
@@ -12,7 +11,7 @@ use std::{path::Path, path::PathBuf, str::FromStr};
 use thiserror::Error;
 use tracing::{debug, instrument, trace};
 use uv_cache::Cache;
-use uv_client::BaseClient;
+use uv_client::{BaseClient, UvRetryPolicy};
 use uv_fs::Simplified;
 use uv_fs::which::is_executable;
 use uv_pep440::{
@@ -1460,7 +1459,7 @@ pub(crate) async fn find_best_python_installation(
     downloads_enabled: bool,
     download_list: &ManagedPythonDownloadList,
     client: &BaseClient,
-    retry_policy: &ExponentialBackoff,
+    retry_policy: &UvRetryPolicy,
     cache: &Cache,
     reporter: Option<&dyn crate::downloads::Reporter>,
     python_install_mirror: Option<&str>,
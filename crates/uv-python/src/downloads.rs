@@ -11,7 +11,6 @@ use futures::TryStreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use reqwest_retry::RetryError;
-use reqwest_retry::policies::ExponentialBackoff;
 use serde::Deserialize;
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufWriter, ReadBuf};
@@ -20,7 +19,7 @@ use tokio_util::either::Either;
 use tracing::{debug, instrument};
 use url::Url;
 
-use uv_client::{BaseClient, RetryState, WrappedReqwestError};
+use uv_client::{BaseClient, RetryState, UvRetryPolicy, WrappedReqwestError};
 use uv_distribution_filename::{ExtensionError, SourceDistExtension};
 use uv_extract::hash::Hasher;
 use uv_fs::{Simplified, rename_with_retry};
@@ -1096,7 +1095,7 @@ impl ManagedPythonDownload {
     pub async fn fetch_with_retry(
         &self,
         client: &BaseClient,
-        retry_policy: &ExponentialBackoff,
+        retry_policy: &UvRetryPolicy,
         installation_dir: &Path,
         scratch_dir: &Path,
         reinstall: bool,
@@ -1105,7 +1104,7 @@ impl ManagedPythonDownload {
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
         let mut retry_state = RetryState::start(
-            *retry_policy,
+            retry_policy.clone(),
             self.download_url(python_install_mirror, pypy_install_mirror)?,
         );
 
@@ -1727,8 +1726,8 @@ async fn read_url(
             .map_err(|err| Error::from_reqwest(url.clone(), err, retry_count))?;
 
         let size = response.content_length();
-        let stream = response
-            .bytes_stream()
+        let stream = client
+            .resumable_stream(response)
             .map_err(io::Error::other)
             .into_async_read();
 
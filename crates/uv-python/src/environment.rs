@@ -8,6 +8,7 @@ use tracing::debug;
 
 use uv_cache::Cache;
 use uv_fs::{LockedFile, LockedFileError, Simplified};
+use uv_install_wheel::Layout;
 use uv_pep440::Version;
 use uv_preview::Preview;
 
@@ -27,6 +28,7 @@ pub struct PythonEnvironment(Arc<PythonEnvironmentShared>);
 struct PythonEnvironmentShared {
     root: PathBuf,
     interpreter: Interpreter,
+    relocatable: bool,
 }
 
 /// The result of failed environment discovery.
@@ -223,6 +225,7 @@ impl PythonEnvironment {
         Ok(Self(Arc::new(PythonEnvironmentShared {
             root: interpreter.sys_prefix().to_path_buf(),
             interpreter,
+            relocatable: false,
         })))
     }
 
@@ -236,6 +239,7 @@ impl PythonEnvironment {
         Self(Arc::new(PythonEnvironmentShared {
             root: interpreter.sys_prefix().to_path_buf(),
             interpreter,
+            relocatable: false,
         }))
     }
 
@@ -257,6 +261,17 @@ impl PythonEnvironment {
         })))
     }
 
+    /// Create a [`PythonEnvironment`] that installs relocatable scripts, regardless of whether
+    /// it's marked as relocatable in its `pyvenv.cfg`.
+    #[must_use]
+    pub fn with_relocatable(self) -> Self {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Self(Arc::new(PythonEnvironmentShared {
+            relocatable: true,
+            ..inner
+        }))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
@@ -287,7 +302,37 @@ impl PythonEnvironment {
 
     /// Returns `true` if the environment is "relocatable".
     pub fn relocatable(&self) -> bool {
-        self.cfg().is_ok_and(|cfg| cfg.is_relocatable())
+        self.0.relocatable || self.cfg().is_ok_and(|cfg| cfg.is_relocatable())
+    }
+
+    /// Returns the [`Layout`] used to install packages into this environment.
+    ///
+    /// Relocatable scripts refer to the interpreter relative to the scripts directory. When
+    /// installing into a `--prefix` directory, the interpreter is assumed to be at the same
+    /// location relative to the prefix as it is relative to `sys.prefix`, i.e., as if the prefix
+    /// were mounted at `sys.prefix`.
+    pub fn layout(&self) -> Layout {
+        let interpreter = &self.0.interpreter;
+        let mut layout = interpreter.layout();
+        if self.relocatable() {
+            if let Some(prefix) = interpreter.prefix() {
+                // The executable may be a symlink into `sys.prefix` (e.g., `/usr/bin/python3`).
+                let executable = layout
+                    .sys_executable
+                    .strip_prefix(interpreter.sys_prefix())
+                    .map(Path::to_path_buf)
+                    .ok()
+                    .or_else(|| {
+                        let executable = fs_err::canonicalize(&layout.sys_executable).ok()?;
+                        let executable = executable.strip_prefix(interpreter.sys_prefix()).ok()?;
+                        Some(executable.to_path_buf())
+                    });
+                if let Some(executable) = executable {
+                    layout.sys_executable = prefix.root().join(executable);
+                }
+            }
+        }
+        layout
     }
 
     /// Returns the location of the Python executable.
@@ -5,12 +5,11 @@ use std::str::FromStr;
 
 use indexmap::IndexMap;
 use ref_cast::RefCast;
-use reqwest_retry::policies::ExponentialBackoff;
 use tracing::{debug, info};
 use uv_warnings::warn_user;
 
 use uv_cache::Cache;
-use uv_client::{BaseClient, BaseClientBuilder};
+use uv_client::{BaseClient, BaseClientBuilder, UvRetryPolicy};
 use uv_pep440::{Prerelease, Version};
 use uv_platform::{Arch, Libc, Os, Platform};
 use uv_preview::Preview;
@@ -273,7 +272,7 @@ impl PythonInstallation {
     pub async fn fetch(
         download: &ManagedPythonDownload,
         client: &BaseClient,
-        retry_policy: &ExponentialBackoff,
+        retry_policy: &UvRetryPolicy,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
         python_install_mirror: Option<&str>,
@@ -7,6 +7,12 @@ use std::str::FromStr;
 use thiserror::Error;
 use url::Url;
 
+pub use crate::redact::{
+    enable_redaction, is_redaction_enabled, redact_output, redact_text, register_private_host,
+};
+
+mod redact;
+
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum DisplaySafeUrlError {
     /// Failed to parse a URL.
@@ -23,7 +23,7 @@ use uv_types::{BuildContext, HashStrategy};
 
 #[derive(Debug, Clone)]
 pub enum SourceTree {
-    PyProjectToml(PathBuf, PyProjectToml),
+    PyProjectToml(PathBuf, Box<PyProjectToml>),
     SetupPy(PathBuf),
     SetupCfg(PathBuf),
 }
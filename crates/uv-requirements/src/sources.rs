@@ -312,6 +312,21 @@ impl RequirementsSource {
     pub fn allows_groups(&self) -> bool {
         matches!(self, Self::PylockToml(_) | Self::PyprojectToml(_))
     }
+
+    /// Returns the path of the file that provides the requirements, if any.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Package(_) | Self::Editable(_) => None,
+            Self::PylockToml(path)
+            | Self::RequirementsTxt(path)
+            | Self::Pep723Script(path)
+            | Self::PyprojectToml(path)
+            | Self::SetupPy(path)
+            | Self::SetupCfg(path)
+            | Self::EnvironmentYml(path)
+            | Self::Extensionless(path) => Some(path),
+        }
+    }
 }
 
 impl std::fmt::Display for RequirementsSource {
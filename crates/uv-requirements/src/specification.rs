@@ -38,14 +38,15 @@ use url::Url;
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DependencyGroups, NoBinary, NoBuild};
-use uv_distribution_types::{Index, Requirement};
+use uv_distribution_types::{Index, Requirement, RequirementsHash};
 use uv_distribution_types::{
     IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
     UnresolvedRequirementSpecification,
 };
+use uv_extract::hash::Hasher;
 use uv_fs::{CWD, Simplified};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
-use uv_pypi_types::PyProjectToml;
+use uv_pypi_types::{HashAlgorithm, HashDigest, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement, SourceCache};
 use uv_scripts::Pep723Metadata;
@@ -288,7 +289,10 @@ impl RequirementsSpecification {
                     .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
 
                 Self {
-                    source_trees: vec![SourceTree::PyProjectToml(path.clone(), pyproject_toml)],
+                    source_trees: vec![SourceTree::PyProjectToml(
+                        path.clone(),
+                        Box::new(pyproject_toml),
+                    )],
                     ..Self::default()
                 }
             }
@@ -392,6 +396,7 @@ impl RequirementsSpecification {
         overrides: &[RequirementsSource],
         excludes: &[RequirementsSource],
         groups: Option<&GroupsSpecification>,
+        requirements_hashes: &[RequirementsHash],
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
         let mut spec = Self::default();
@@ -530,6 +535,36 @@ impl RequirementsSpecification {
             spec.groups = group_specs;
         }
 
+        // Verify the requirements files against the provided hashes. The verified contents are
+        // stored in the cache, such that the file is parsed from the same contents (which is
+        // required for stdin, and avoids a second fetch for remote files).
+        for requirements_hash in requirements_hashes {
+            if !requirements
+                .iter()
+                .filter_map(RequirementsSource::path)
+                .any(|path| is_same_path(path, requirements_hash.path()))
+            {
+                return Err(anyhow::anyhow!(
+                    "`--requirements-hash` was provided for `{}`, but it was not provided as a requirements file",
+                    requirements_hash.path().user_display()
+                ));
+            }
+        }
+        for source in requirements {
+            let Some(path) = source.path() else {
+                continue;
+            };
+            let hashes = requirements_hashes
+                .iter()
+                .filter(|requirements_hash| is_same_path(path, requirements_hash.path()))
+                .map(RequirementsHash::hash)
+                .cloned()
+                .collect::<Vec<_>>();
+            if !hashes.is_empty() {
+                verify_requirements_hash(source, &hashes, client_builder, &mut cache).await?;
+            }
+        }
+
         // Resolve sources into specifications so we know their `source_tree`.
         let mut requirement_sources = Vec::new();
         for source in requirements {
@@ -676,7 +711,7 @@ impl RequirementsSpecification {
         requirements: &[RequirementsSource],
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
-        Self::from_sources(requirements, &[], &[], &[], None, client_builder).await
+        Self::from_sources(requirements, &[], &[], &[], None, &[], client_builder).await
     }
 
     /// Initialize a [`RequirementsSpecification`] from a list of [`Requirement`].
@@ -745,6 +780,98 @@ pub struct GroupsSpecification {
     pub groups: Vec<PipGroupName>,
 }
 
+/// Returns `true` if two requirements file paths (as provided on the command line) refer to the
+/// same file.
+fn is_same_path(left: &Path, right: &Path) -> bool {
+    left == right
+        || std::path::absolute(left)
+            .ok()
+            .zip(std::path::absolute(right).ok())
+            .is_some_and(|(left, right)| {
+                uv_fs::normalize_path(&left) == uv_fs::normalize_path(&right)
+            })
+}
+
+/// Verify that the contents of a requirements file match at least one of the given hashes.
+///
+/// On success, the contents are inserted into the [`SourceCache`].
+async fn verify_requirements_hash(
+    source: &RequirementsSource,
+    hashes: &[HashDigest],
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &mut SourceCache,
+) -> Result<()> {
+    let path = match source {
+        RequirementsSource::RequirementsTxt(path) | RequirementsSource::Extensionless(path) => path,
+        RequirementsSource::Package(_) | RequirementsSource::Editable(_) => return Ok(()),
+        RequirementsSource::Pep723Script(path)
+        | RequirementsSource::PylockToml(path)
+        | RequirementsSource::PyprojectToml(path)
+        | RequirementsSource::SetupPy(path)
+        | RequirementsSource::SetupCfg(path)
+        | RequirementsSource::EnvironmentYml(path) => {
+            return Err(anyhow::anyhow!(
+                "`--requirements-hash` is only supported for `requirements.txt` files, but `{}` was provided",
+                path.user_display()
+            ));
+        }
+    };
+
+    let content = if let Some(content) = cache.get(path.as_path()) {
+        content.clone()
+    } else {
+        let content = read_file(path, client_builder)
+            .await
+            .with_context(|| format!("Failed to read `{}`", path.user_display()))?;
+        cache.insert(path.clone(), content.clone());
+        content
+    };
+
+    // Compute a digest for every algorithm that was requested.
+    let mut algorithms = hashes
+        .iter()
+        .map(HashDigest::algorithm)
+        .collect::<Vec<HashAlgorithm>>();
+    algorithms.sort();
+    algorithms.dedup();
+    let actual = algorithms
+        .into_iter()
+        .map(|algorithm| {
+            let mut hasher = Hasher::from(algorithm);
+            hasher.update(content.as_bytes());
+            HashDigest::from(hasher)
+        })
+        .collect::<Vec<_>>();
+
+    if actual.iter().any(|digest| {
+        hashes.iter().any(|expected| {
+            expected.algorithm == digest.algorithm
+                && expected.digest.eq_ignore_ascii_case(&digest.digest)
+        })
+    }) {
+        return Ok(());
+    }
+
+    let source = if path == Path::new("-") {
+        "stdin".to_string()
+    } else {
+        path.user_display().to_string()
+    };
+    let expected = hashes
+        .iter()
+        .map(|hash| format!("  {hash}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let actual = actual
+        .iter()
+        .map(|hash| format!("  {hash}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Err(anyhow::anyhow!(
+        "Hash mismatch for `{source}`\n\nExpected:\n{expected}\n\nComputed:\n{actual}"
+    ))
+}
+
 /// Read the contents of a path, fetching over HTTP(S) if necessary.
 async fn read_file(path: &Path, client_builder: &BaseClientBuilder<'_>) -> Result<String> {
     // If the path is a URL, fetch it over HTTP(S).
@@ -14,13 +14,14 @@ use tracing::trace;
 use uv_distribution_types::{
     DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexUrl, RequestedDist,
 };
-use uv_normalize::{ExtraName, InvalidNameError, PackageName};
+use uv_normalize::{ExtraName, GroupName, InvalidNameError, PackageName};
 use uv_pep440::{LocalVersionSlice, LowerBound, Version};
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Tags;
 use uv_pypi_types::ParsedUrl;
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
+use uv_warnings::locale::Message;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::dependency_provider::UvDependencyProvider;
@@ -380,6 +381,64 @@ impl NoSolutionError {
             .filter_map(|p| p.name())
             .unique()
     }
+
+    /// Get the dependency declarations that are involved in this error, i.e., the edges of the
+    /// derivation tree, omitting any edges to or from proxy packages.
+    pub fn conflicting_dependencies(&self) -> BTreeSet<ConflictingDependency> {
+        fn find(
+            derivation_tree: &ErrorTree,
+            workspace_members: &BTreeSet<PackageName>,
+            dependencies: &mut BTreeSet<ConflictingDependency>,
+        ) {
+            match derivation_tree {
+                DerivationTree::Derived(derived) => {
+                    find(derived.cause1.as_ref(), workspace_members, dependencies);
+                    find(derived.cause2.as_ref(), workspace_members, dependencies);
+                }
+                DerivationTree::External(External::FromDependencyOf(package, _, dependency, _)) => {
+                    let PubGrubPackageInner::Package {
+                        name, extra, group, ..
+                    } = &**package
+                    else {
+                        return;
+                    };
+                    let Some(dependency) = dependency.name() else {
+                        return;
+                    };
+                    if dependency == name {
+                        return;
+                    }
+                    dependencies.insert(ConflictingDependency {
+                        dependent: name.clone(),
+                        extra: extra.clone(),
+                        group: group.clone(),
+                        dependency: dependency.clone(),
+                        workspace_member: workspace_members.contains(name),
+                    });
+                }
+                DerivationTree::External(_) => {}
+            }
+        }
+
+        let mut dependencies = BTreeSet::new();
+        find(&self.error, &self.workspace_members, &mut dependencies);
+        dependencies
+    }
+}
+
+/// A dependency declaration that contributed to a resolution failure.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConflictingDependency {
+    /// The package that declares the dependency.
+    pub dependent: PackageName,
+    /// The extra of the dependent package in which the dependency is declared, if any.
+    pub extra: Option<ExtraName>,
+    /// The dependency group of the dependent package in which the dependency is declared, if any.
+    pub group: Option<GroupName>,
+    /// The name of the dependency.
+    pub dependency: PackageName,
+    /// Whether the dependent package is a workspace member.
+    pub workspace_member: bool,
 }
 
 impl std::fmt::Debug for NoSolutionError {
@@ -1283,21 +1342,15 @@ impl NoSolutionHeader {
 
 impl std::fmt::Display for NoSolutionHeader {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match (self.context, self.env.end_user_fork_display()) {
-            (None, None) => write!(f, "No solution found when resolving dependencies:"),
-            (Some(context), None) => write!(
-                f,
-                "No solution found when resolving {context} dependencies:"
-            ),
-            (None, Some(split)) => write!(
-                f,
-                "No solution found when resolving dependencies for {split}:"
-            ),
-            (Some(context), Some(split)) => write!(
-                f,
-                "No solution found when resolving {context} dependencies for {split}:"
-            ),
-        }
+        let header = match (self.context, self.env.end_user_fork_display()) {
+            (None, None) => Message::NoSolution.format(&[]),
+            (Some(context), None) => Message::NoSolutionContext.format(&[("context", &context)]),
+            (None, Some(split)) => Message::NoSolutionSplit.format(&[("split", &split)]),
+            (Some(context), Some(split)) => {
+                Message::NoSolutionContextSplit.format(&[("context", &context), ("split", &split)])
+            }
+        };
+        f.write_str(&header)
     }
 }
 
@@ -1,27 +1,61 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsStr;
 use std::path::Path;
 
-use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::errors::{JsonReadError, JsonWriteError, XmlReadError, XmlWriteError};
+use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::models::component::{Classification, Scope};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use cyclonedx_bom::models::license::{LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
 use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
-use cyclonedx_bom::prelude::{Bom, Component, Components, NormalizedString};
+use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+use cyclonedx_bom::models::vulnerability_rating::{
+    ScoreMethod, VulnerabilityRating, VulnerabilityRatings,
+};
+use cyclonedx_bom::models::vulnerability_reference::{
+    VulnerabilityReference, VulnerabilityReferences,
+};
+use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
+use cyclonedx_bom::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+use cyclonedx_bom::prelude::{
+    Bom, Component, Components, DateTime, NormalizedString, Uri, UrnUuid,
+};
 use itertools::Itertools;
+use jiff::Timestamp;
 use percent_encoding::{AsciiSet, CONTROLS, percent_encode};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use sha1::{Digest, Sha1};
+use uuid::Uuid;
 
+use uv_client::AttestationStatus;
 use uv_configuration::{
-    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+    CycloneDxEncoding, CycloneDxVersion, DependencyGroupsWithDefaults,
+    ExtrasSpecificationWithDefaults, InstallOptions, SbomComponentType, SbomContact, SbomMetadata,
+    SbomOrganization,
+};
+use uv_distribution_types::{
+    InstalledDist, InstalledDistKind, Name, SimplifiedMarkerTree, UrlString,
 };
 use uv_fs::PortablePath;
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{DirectUrl, HashDigest, License, ProjectLinks, ResolutionMetadata, VcsInfo};
+use uv_python::Interpreter;
 use uv_warnings::warn_user;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
-use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source};
+use crate::lock::{
+    GitSource, Lock, LockErrorKind, Package, PackageId, RegistrySource, Source, SourceDist,
+};
 use crate::{Installable, LockError};
 
 /// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>).
@@ -46,15 +80,70 @@ const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'^')
     .add(b'|');
 
+/// The results of verifying the PEP 740 attestations for registry packages, keyed by package name
+/// and version.
+pub type Attestations = FxHashMap<(PackageName, Version), AttestationStatus>;
+
+/// The known vulnerabilities affecting registry packages, keyed by package name and version.
+pub type Advisories = FxHashMap<(PackageName, Version), Vec<Advisory>>;
+
+/// A known vulnerability, as reported by an advisory database.
+#[derive(Debug, Clone)]
+pub struct Advisory {
+    /// The identifier of the vulnerability in the database, e.g., `GHSA-34jh-p97f-mpxf`.
+    pub id: String,
+    /// Other identifiers of the vulnerability, e.g., `CVE-2024-37891`.
+    pub aliases: Vec<String>,
+    /// A one-line summary of the vulnerability.
+    pub summary: Option<String>,
+    /// A detailed description of the vulnerability.
+    pub details: Option<String>,
+    /// The CVSS vectors that describe the severity of the vulnerability.
+    pub cvss: Vec<String>,
+    /// When the vulnerability was published.
+    pub published: Option<Timestamp>,
+    /// When the vulnerability was last modified.
+    pub modified: Option<Timestamp>,
+    /// The name of the database, e.g., `OSV`.
+    pub source: String,
+    /// The URL of the vulnerability in the database.
+    pub url: String,
+}
+
+/// The build requirements resolved for the packages that are built from local source trees, keyed
+/// by the name of the package.
+pub type BuildDependencies = FxHashMap<PackageName, Vec<BuildDependency>>;
+
+/// A package required to build another package from source, but not to install it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BuildDependency {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The version of the package.
+    pub version: Version,
+}
+
 /// Creates `CycloneDX` components, registering them in a `HashMap` so that they can be retrieved by `PackageId`.
 /// Also ensures uniqueness when generating bom-refs by using a numeric prefix which is incremented for each component.
-#[derive(Default)]
 struct ComponentBuilder<'a> {
     id_counter: usize, // Used as prefix in bom-ref generation, to ensure uniqueness
-    package_to_component_map: HashMap<&'a PackageId, Component>,
+    /// The bom-ref of the component created for each package.
+    bom_refs: FxHashMap<&'a PackageId, String>,
+    attestations: &'a Attestations,
+    /// Whether to include the hashes of each package's distributions.
+    hashes: bool,
 }
 
 impl<'a> ComponentBuilder<'a> {
+    fn new(attestations: &'a Attestations, hashes: bool) -> Self {
+        Self {
+            id_counter: 0,
+            bom_refs: FxHashMap::default(),
+            attestations,
+            hashes,
+        }
+    }
+
     /// Creates a bom-ref string in the format "{package_name}-{id}@{version}" or "{package_name}-{id}" if no version is provided.
     fn create_bom_ref(&mut self, name: &str, version: Option<&str>) -> String {
         self.id_counter += 1;
@@ -80,83 +169,47 @@ impl<'a> ComponentBuilder<'a> {
         package.id.name.as_str()
     }
 
-    /// Generate a Package URL (purl) from a package. Returns `None` for local sources.
-    fn create_purl(package: &Package) -> Option<String> {
-        let name = percent_encode(Self::get_package_name(package).as_bytes(), PURL_ENCODE_SET);
-
-        let version = Self::get_version_string(package)
-            .map(|v| format!("@{}", percent_encode(v.as_bytes(), PURL_ENCODE_SET)))
-            .unwrap_or_default();
-
-        let (purl_type, qualifiers) = match &package.id.source {
-            // By convention all Python packages use the "pypi" purl type, regardless of their source. For packages
-            // from non-default repositories, we add a qualifier to indicate their source explicitly.
-            // See the specs at
-            // https://github.com/package-url/purl-spec/blob/9041aa7/types/pypi-definition.json
-            // and https://github.com/package-url/purl-spec/blob/9041aa7/purl-specification.md
-            Source::Registry(registry_source) => {
-                let qualifiers = match registry_source {
-                    RegistrySource::Url(url) => {
-                        // Only add repository_url qualifier for non-default registries
-                        if !url.as_ref().starts_with("https://pypi.org/") {
-                            vec![("repository_url", url.as_ref())]
-                        } else {
-                            vec![]
-                        }
-                    }
-                    RegistrySource::Path(_) => vec![],
-                };
-                ("pypi", qualifiers)
-            }
-            Source::Git(url, _) => ("pypi", vec![("vcs_url", url.as_ref())]),
-            Source::Direct(url, _) => ("pypi", vec![("download_url", url.as_ref())]),
-            // No purl for local sources
-            Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
-                return None;
-            }
-        };
-
-        let qualifiers = if qualifiers.is_empty() {
-            String::new()
-        } else {
-            Self::format_qualifiers(&qualifiers)
-        };
-
-        Some(format!("pkg:{purl_type}/{name}{version}{qualifiers}"))
-    }
-
-    fn format_qualifiers(qualifiers: &[(&str, &str)]) -> String {
-        let joined_qualifiers = qualifiers
-            .iter()
-            .map(|(key, value)| {
-                format!(
-                    "{key}={}",
-                    percent_encode(value.as_bytes(), PURL_ENCODE_SET)
-                )
-            })
-            .join("&");
-        format!("?{joined_qualifiers}")
-    }
-
     fn create_component(
         &mut self,
         package: &'a Package,
         package_type: PackageType,
         marker: Option<&MarkerTree>,
+        scope: Option<Scope>,
     ) -> Component {
-        let component = self.create_component_from_package(package, package_type, marker);
-        self.package_to_component_map
-            .insert(&package.id, component.clone());
+        let component = self.create_component_from_package(package, package_type, marker, scope);
+        if let Some(bom_ref) = &component.bom_ref {
+            self.bom_refs.insert(&package.id, bom_ref.clone());
+        }
         component
     }
 
     fn create_synthetic_root_component(&mut self, root: Option<&Package>) -> Component {
         let name = root.map(Self::get_package_name).unwrap_or("uv-workspace");
+        // No need to register as we manually add dependencies in `if all_packages` check in `from_lock`
+        self.create_synthetic_component(name, Classification::Library)
+    }
+
+    /// Create the root component for a PEP 723 script, named after the script file.
+    fn create_script_component(&mut self, script: &Path) -> Component {
+        let name = script
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap_or("script");
+        // No need to register as we manually add dependencies on the script's requirements in
+        // `from_lock`
+        self.create_synthetic_component(name, Classification::Application)
+    }
+
+    /// Create a component that doesn't correspond to a package in the lockfile.
+    fn create_synthetic_component(
+        &mut self,
+        name: &str,
+        component_type: Classification,
+    ) -> Component {
         let bom_ref = self.create_bom_ref(name, None);
 
-        // No need to register as we manually add dependencies in `if all_packages` check in `from_lock`
         Component {
-            component_type: Classification::Library,
+            component_type,
             name: NormalizedString::new(name),
             version: None,
             bom_ref: Some(bom_ref),
@@ -190,11 +243,12 @@ impl<'a> ComponentBuilder<'a> {
         package: &Package,
         package_type: PackageType,
         marker: Option<&MarkerTree>,
+        scope: Option<Scope>,
     ) -> Component {
         let name = Self::get_package_name(package);
         let version = Self::get_version_string(package);
         let bom_ref = self.create_bom_ref(name, version.as_deref());
-        let purl = Self::create_purl(package).and_then(|purl_string| purl_string.parse().ok());
+        let purl = create_purl(package).and_then(|purl_string| purl_string.parse().ok());
         let mut properties = vec![];
 
         match package_type {
@@ -207,6 +261,15 @@ impl<'a> ComponentBuilder<'a> {
             PackageType::Root | PackageType::Dependency => {}
         }
 
+        // Record the local registry (e.g., a `--find-links` directory) that provided the package,
+        // which has no other representation in the purl.
+        if let Source::Registry(RegistrySource::Path(path)) = &package.id.source {
+            properties.push(Property::new(
+                "uv:registry:path",
+                &PortablePath::from(path).to_string(),
+            ));
+        }
+
         if let Some(marker_contents) = marker.and_then(|marker| marker.contents()) {
             properties.push(Property::new(
                 "uv:package:marker",
@@ -214,6 +277,19 @@ impl<'a> ComponentBuilder<'a> {
             ));
         }
 
+        if let Some(status) = package.id.version.as_ref().and_then(|version| {
+            self.attestations
+                .get(&(package.id.name.clone(), version.clone()))
+        }) {
+            properties.push(Property::new("uv:attestation:status", status.as_str()));
+            if let AttestationStatus::Signed(publisher) = status {
+                properties.push(Property::new(
+                    "uv:attestation:publisher",
+                    &publisher.to_string(),
+                ));
+            }
+        }
+
         Component {
             component_type: Classification::Library,
             name: NormalizedString::new(name),
@@ -226,15 +302,19 @@ impl<'a> ComponentBuilder<'a> {
             publisher: None,
             group: None,
             description: None,
-            scope: None,
-            hashes: None,
-            licenses: None,
+            scope,
+            hashes: if self.hashes {
+                create_hashes(package)
+            } else {
+                None
+            },
+            licenses: package.license.as_ref().map(create_licenses),
             copyright: None,
             cpe: None,
             swid: None,
             modified: None,
             pedigree: None,
-            external_references: None,
+            external_references: create_external_references(package, self.hashes),
             properties: if !properties.is_empty() {
                 Some(Properties(properties))
             } else {
@@ -248,9 +328,326 @@ impl<'a> ComponentBuilder<'a> {
         }
     }
 
-    fn get_component(&self, id: &PackageId) -> Option<&Component> {
-        self.package_to_component_map.get(id)
+    /// Create a component for a package that's only required to build the given packages from
+    /// source, which is excluded from the runtime environment.
+    fn create_build_dependency_component(
+        &mut self,
+        dependency: &BuildDependency,
+        dependents: &BTreeSet<&PackageName>,
+    ) -> Component {
+        let name = dependency.name.as_str();
+        let version = dependency.version.to_string();
+        let bom_ref = self.create_bom_ref(name, Some(&version));
+        let purl = format!(
+            "pkg:pypi/{}@{}",
+            percent_encode(name.as_bytes(), PURL_ENCODE_SET),
+            percent_encode(version.as_bytes(), PURL_ENCODE_SET)
+        );
+
+        let mut component = Component::new(Classification::Library, name, &version, Some(bom_ref));
+        component.purl = purl.parse().ok();
+        component.scope = Some(Scope::Excluded);
+        component.properties = Some(Properties(
+            dependents
+                .iter()
+                .map(|dependent| Property::new("uv:build:dependency-of", dependent.as_str()))
+                .collect(),
+        ));
+        component
     }
+
+    /// Create a component for a distribution installed in an environment.
+    fn create_installed_component(&mut self, package: &InstalledPackage) -> Component {
+        let name = package.dist.name().as_str();
+        let version = package.dist.version().to_string();
+        let bom_ref = self.create_bom_ref(name, Some(&version));
+
+        let mut component = Component::new(Classification::Library, name, &version, Some(bom_ref));
+        component.purl =
+            create_installed_purl(package.dist).and_then(|purl_string| purl_string.parse().ok());
+        if self.hashes && !package.hashes.is_empty() {
+            component.hashes = Some(Hashes(package.hashes.iter().map(create_hash).collect()));
+        }
+        component.licenses = package.metadata.license.as_ref().map(create_licenses);
+
+        let mut references = package
+            .metadata
+            .project_links
+            .as_ref()
+            .map(create_link_references)
+            .unwrap_or_default();
+        if let InstalledDistKind::Url(dist) = &package.dist.kind {
+            match &*dist.direct_url {
+                DirectUrl::VcsUrl {
+                    url,
+                    vcs_info,
+                    subdirectory,
+                } => {
+                    references.push(ExternalReference::new(
+                        ExternalReferenceType::Vcs,
+                        Uri::new(&create_installed_vcs_url(
+                            url,
+                            vcs_info,
+                            subdirectory.as_deref(),
+                        )),
+                    ));
+                }
+                DirectUrl::ArchiveUrl { url, .. } if dist.url.scheme() != "file" => {
+                    references.push(ExternalReference::new(
+                        ExternalReferenceType::Distribution,
+                        Uri::new(url),
+                    ));
+                }
+                DirectUrl::ArchiveUrl { .. } | DirectUrl::LocalDirectory { .. } => {}
+            }
+        }
+        if !references.is_empty() {
+            component.external_references = Some(ExternalReferences(references));
+        }
+
+        component
+    }
+
+    fn get_bom_ref(&self, id: &PackageId) -> Option<&str> {
+        self.bom_refs.get(id).map(String::as_str)
+    }
+}
+
+/// Convert the license of a package to `CycloneDX` licenses, as either a single SPDX expression or
+/// a list of named licenses.
+fn create_licenses(license: &License) -> Licenses {
+    match license {
+        License::Expression(expression) => Licenses(vec![LicenseChoice::expression(expression)]),
+        License::Names(names) => Licenses(
+            names
+                .iter()
+                .map(|name| LicenseChoice::license(name))
+                .collect(),
+        ),
+    }
+}
+
+/// Collect the external references of a package: the homepage and source repository declared in
+/// its metadata, followed by the index, repository, or URL it was resolved from. If `distributions`
+/// is set, the source distribution and wheels of registry packages are included too, alongside
+/// their hashes.
+fn create_external_references(
+    package: &Package,
+    distributions: bool,
+) -> Option<ExternalReferences> {
+    let mut references = package
+        .project_links
+        .as_ref()
+        .map(create_link_references)
+        .unwrap_or_default();
+
+    match &package.id.source {
+        Source::Registry(RegistrySource::Url(index)) => {
+            references.push(ExternalReference {
+                comment: Some("index".to_string()),
+                ..ExternalReference::new(
+                    ExternalReferenceType::Distribution,
+                    Uri::new(index.as_ref()),
+                )
+            });
+        }
+        Source::Git(url, git) => {
+            references.push(ExternalReference::new(
+                ExternalReferenceType::Vcs,
+                Uri::new(&create_vcs_url(url, git)),
+            ));
+        }
+        Source::Direct(url, _) => {
+            references.push(ExternalReference {
+                hashes: if distributions {
+                    create_hashes(package)
+                } else {
+                    None
+                },
+                ..ExternalReference::new(
+                    ExternalReferenceType::Distribution,
+                    Uri::new(url.as_ref()),
+                )
+            });
+        }
+        Source::Registry(RegistrySource::Path(_))
+        | Source::Path(_)
+        | Source::Directory(_)
+        | Source::Editable(_)
+        | Source::Virtual(_) => {}
+    }
+
+    // Distributions from local registries are located relative to the registry, which is itself
+    // relative to the workspace root.
+    if distributions && let Source::Registry(registry) = &package.id.source {
+        let location = |url: Option<&UrlString>, path: Option<&Path>| match (url, registry) {
+            (Some(url), _) => Some(url.to_string()),
+            (None, RegistrySource::Path(registry)) => {
+                path.map(|path| PortablePath::from(&registry.join(path)).to_string())
+            }
+            (None, RegistrySource::Url(_)) => None,
+        };
+        let sdist = package
+            .sdist
+            .as_ref()
+            .and_then(|sdist| Some((location(sdist.url(), sdist.path())?, sdist.hash())));
+        let wheels = package
+            .wheels
+            .iter()
+            .filter_map(|wheel| Some((location(wheel.url(), wheel.path())?, wheel.hash.as_ref())));
+        for (location, hash) in sdist.into_iter().chain(wheels) {
+            references.push(ExternalReference {
+                hashes: hash.map(|hash| Hashes(vec![create_hash(&hash.0)])),
+                ..ExternalReference::new(ExternalReferenceType::Distribution, Uri::new(&location))
+            });
+        }
+    }
+
+    if references.is_empty() {
+        None
+    } else {
+        Some(ExternalReferences(references))
+    }
+}
+
+/// Create the external references for the homepage and source repository declared in a package's
+/// metadata.
+fn create_link_references(links: &ProjectLinks) -> Vec<ExternalReference> {
+    let mut references = Vec::new();
+    if let Some(homepage) = &links.homepage {
+        references.push(ExternalReference::new(
+            ExternalReferenceType::Website,
+            Uri::new(homepage),
+        ));
+    }
+    if let Some(repository) = &links.repository {
+        references.push(ExternalReference::new(
+            ExternalReferenceType::Vcs,
+            Uri::new(repository),
+        ));
+    }
+    references
+}
+
+/// Collect the hashes of a package's source distribution and wheels from the lockfile. Returns
+/// `None` if the lockfile doesn't include any hashes for the package.
+fn create_hashes(package: &Package) -> Option<Hashes> {
+    let hashes = package
+        .sdist
+        .as_ref()
+        .and_then(SourceDist::hash)
+        .into_iter()
+        .chain(
+            package
+                .wheels
+                .iter()
+                .filter_map(|wheel| wheel.hash.as_ref()),
+        )
+        .map(|hash| create_hash(&hash.0))
+        .collect::<Vec<_>>();
+    if hashes.is_empty() {
+        None
+    } else {
+        Some(Hashes(hashes))
+    }
+}
+
+/// Convert a [`HashDigest`] to a `CycloneDX` [`Hash`].
+fn create_hash(digest: &HashDigest) -> Hash {
+    let alg = match digest.algorithm {
+        uv_pypi_types::HashAlgorithm::Md5 => HashAlgorithm::MD5,
+        uv_pypi_types::HashAlgorithm::Sha256 => HashAlgorithm::SHA_256,
+        uv_pypi_types::HashAlgorithm::Sha384 => HashAlgorithm::SHA_384,
+        uv_pypi_types::HashAlgorithm::Sha512 => HashAlgorithm::SHA_512,
+        uv_pypi_types::HashAlgorithm::Blake2b => HashAlgorithm::BLAKE2b_256,
+    };
+    Hash {
+        alg,
+        content: HashValue(digest.digest.to_string()),
+    }
+}
+
+/// Generate a Package URL (purl) from a package. Returns `None` for local sources.
+pub(super) fn create_purl(package: &Package) -> Option<String> {
+    let name = percent_encode(
+        ComponentBuilder::get_package_name(package).as_bytes(),
+        PURL_ENCODE_SET,
+    );
+
+    let version = ComponentBuilder::get_version_string(package)
+        .map(|v| format!("@{}", percent_encode(v.as_bytes(), PURL_ENCODE_SET)))
+        .unwrap_or_default();
+
+    let vcs_url;
+    let (purl_type, qualifiers) = match &package.id.source {
+        // By convention all Python packages use the "pypi" purl type, regardless of their source. For packages
+        // from non-default repositories, we add a qualifier to indicate their source explicitly.
+        // See the specs at
+        // https://github.com/package-url/purl-spec/blob/9041aa7/types/pypi-definition.json
+        // and https://github.com/package-url/purl-spec/blob/9041aa7/purl-specification.md
+        Source::Registry(registry_source) => {
+            let qualifiers = match registry_source {
+                RegistrySource::Url(url) => {
+                    // Only add repository_url qualifier for non-default registries
+                    if !url.as_ref().starts_with("https://pypi.org/") {
+                        vec![("repository_url", url.as_ref())]
+                    } else {
+                        vec![]
+                    }
+                }
+                RegistrySource::Path(_) => vec![],
+            };
+            ("pypi", qualifiers)
+        }
+        // The `vcs_url` qualifier uses the SPDX download location format, pinned to the commit.
+        Source::Git(url, git) => {
+            vcs_url = create_vcs_url(url, git);
+            ("pypi", vec![("vcs_url", vcs_url.as_str())])
+        }
+        Source::Direct(url, _) => ("pypi", vec![("download_url", url.as_ref())]),
+        // Local archives are identified by their filename, as they aren't available from any
+        // repository.
+        Source::Path(path) => {
+            let file_name = path.file_name().and_then(OsStr::to_str)?;
+            ("pypi", vec![("file_name", file_name)])
+        }
+        // No purl for local source trees
+        Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
+            return None;
+        }
+    };
+
+    let qualifiers = if qualifiers.is_empty() {
+        String::new()
+    } else {
+        format_qualifiers(&qualifiers)
+    };
+
+    Some(format!("pkg:{purl_type}/{name}{version}{qualifiers}"))
+}
+
+/// Generate a VCS URL for a Git source, in the SPDX download location format (e.g.,
+/// `git+https://github.com/user/repo@<commit>#<subdirectory>`).
+pub(super) fn create_vcs_url(url: &UrlString, git: &GitSource) -> String {
+    let mut vcs_url = format!("git+{}@{}", url.base_str(), git.precise);
+    if let Some(subdirectory) = &git.subdirectory {
+        vcs_url.push('#');
+        vcs_url.push_str(&PortablePath::from(subdirectory.as_ref()).to_string());
+    }
+    vcs_url
+}
+
+fn format_qualifiers(qualifiers: &[(&str, &str)]) -> String {
+    let joined_qualifiers = qualifiers
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{key}={}",
+                percent_encode(value.as_bytes(), PURL_ENCODE_SET)
+            )
+        })
+        .join("&");
+    format!("?{joined_qualifiers}")
 }
 
 pub fn from_lock<'lock>(
@@ -259,9 +656,17 @@ pub fn from_lock<'lock>(
     extras: &ExtrasSpecificationWithDefaults,
     groups: &DependencyGroupsWithDefaults,
     annotate: bool,
+    hashes: bool,
     install_options: &'lock InstallOptions,
     preview: Preview,
     all_packages: bool,
+    attestations: &Attestations,
+    source_date_epoch: Option<Timestamp>,
+    script: Option<&Path>,
+    advisories: &Advisories,
+    build_dependencies: &BuildDependencies,
+    sbom: &SbomMetadata,
+    interpreter: Option<&Interpreter>,
 ) -> Result<Bom, LockError> {
     if !preview.is_enabled(PreviewFeature::SbomExport) {
         warn_user!(
@@ -294,19 +699,30 @@ pub fn from_lock<'lock>(
     }
     .or_else(|| target.lock().root()); // Fallback to project root
 
-    let mut component_builder = ComponentBuilder::default();
+    let mut component_builder = ComponentBuilder::new(attestations, hashes);
 
     let mut metadata = Metadata {
-        component: root
-            .map(|package| component_builder.create_component(package, PackageType::Root, None)),
-        timestamp: cyclonedx_bom::prelude::DateTime::now().ok(),
-        tools: Some(Tools::List(vec![Tool {
-            vendor: Some(NormalizedString::new("Astral Software Inc.")),
-            name: Some(NormalizedString::new("uv")),
-            version: Some(NormalizedString::new(uv_version::version())),
-            hashes: None,
-            external_references: None,
-        }])),
+        component: match (root, script) {
+            (Some(package), _) => {
+                Some(component_builder.create_component(package, PackageType::Root, None, None))
+            }
+            (None, Some(script)) => Some(component_builder.create_script_component(script)),
+            (None, None) => None,
+        },
+        timestamp: match source_date_epoch {
+            Some(timestamp) => {
+                DateTime::try_from(timestamp.strftime("%Y-%m-%dT%H:%M:%SZ").to_string()).ok()
+            }
+            None => DateTime::now().ok(),
+        },
+        tools: Some(Tools::Object {
+            services: None,
+            components: Some(Components(vec![create_uv_component()])),
+        }),
+        properties: Some(Properties(create_resolution_properties(
+            target.lock(),
+            interpreter,
+        ))),
         ..Metadata::default()
     };
 
@@ -343,12 +759,67 @@ pub fn from_lock<'lock>(
             } else {
                 PackageType::Dependency
             };
-            Ok(component_builder.create_component(node.package, package_type, Some(&node.marker)))
+            // Packages that are only required by dependency groups or extras are optional; absent a
+            // scope, components are assumed to be required.
+            let scope = (!node.required).then_some(Scope::Optional);
+            Ok(component_builder.create_component(
+                node.package,
+                package_type,
+                Some(&node.marker),
+                scope,
+            ))
         })
         .collect::<Result<Vec<_>, LockError>>()?;
 
+    // Record the packages required to build the exported packages from source. As they aren't
+    // installed, they're excluded from the dependency graph.
+    let mut build_dependents = BTreeMap::<&BuildDependency, BTreeSet<&PackageName>>::new();
+    for node in &nodes {
+        for dependency in build_dependencies
+            .get(&node.package.id.name)
+            .into_iter()
+            .flatten()
+        {
+            build_dependents
+                .entry(dependency)
+                .or_default()
+                .insert(&node.package.id.name);
+        }
+    }
+    components.extend(build_dependents.iter().map(|(dependency, dependents)| {
+        component_builder.create_build_dependency_component(dependency, dependents)
+    }));
+
     let mut dependencies = create_dependencies(&nodes, &component_builder);
 
+    // A script depends on the packages it requires directly, which aren't represented in the
+    // lockfile as the dependencies of any package.
+    if root.is_none()
+        && script.is_some()
+        && let Some(script_bom_ref) = metadata
+            .component
+            .as_ref()
+            .and_then(|component| component.bom_ref.clone())
+    {
+        let requirements = target
+            .lock()
+            .requirements()
+            .iter()
+            .map(|requirement| &requirement.name)
+            .collect::<FxHashSet<_>>();
+        dependencies.push(Dependency {
+            dependency_ref: script_bom_ref,
+            dependencies: nodes
+                .iter()
+                .filter(|node| requirements.contains(&node.package.id.name))
+                .filter_map(|node| component_builder.get_bom_ref(&node.package.id))
+                .map(ToString::to_string)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+        });
+    }
+
     // With `--all-packages`, use synthetic root which depends on root and all workspace members.
     // This ensures that we don't have any dangling components resulting from workspace packages not depended on by the workspace root.
     if all_packages {
@@ -361,73 +832,598 @@ pub fn from_lock<'lock>(
 
         let mut synthetic_root_deps = workspace_member_ids
             .iter()
-            .filter_map(|c| component_builder.get_component(c))
-            .map(|c| c.bom_ref.clone().expect("bom-ref should always exist"))
-            .collect::<Vec<_>>();
+            .filter_map(|id| component_builder.get_bom_ref(id))
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>();
         if let Some(ref root_component) = root
             && let Some(ref root_bom_ref) = root_component.bom_ref
         {
-            synthetic_root_deps.push(root_bom_ref.clone());
+            synthetic_root_deps.insert(root_bom_ref.clone());
         }
 
         if let Some(workspace_root) = root {
             components.push(workspace_root);
         }
 
+        // Each workspace member is an application in its own right, rather than a library of the
+        // synthetic root.
+        for component in &mut components {
+            if component
+                .bom_ref
+                .as_ref()
+                .is_some_and(|bom_ref| synthetic_root_deps.contains(bom_ref))
+            {
+                component.component_type = Classification::Application;
+            }
+        }
+
         dependencies.push(Dependency {
             dependency_ref: synthetic_root_bom_ref,
-            dependencies: synthetic_root_deps
-                .into_iter()
-                .sorted_unstable()
-                .unique()
-                .collect(),
+            dependencies: synthetic_root_deps.into_iter().collect(),
         });
     }
 
+    // For reproducible builds, derive the serial number from the lockfile rather than generating
+    // a random one, such that repeated exports are identical.
+    let serial_number = if source_date_epoch.is_some() {
+        let lock = target.lock().to_toml().map_err(LockErrorKind::Serialize)?;
+        Some(reproducible_serial_number(&lock, &components))
+    } else {
+        Some(UrnUuid::generate())
+    };
+
+    apply_metadata(&mut metadata, sbom);
+
+    let vulnerabilities = create_vulnerabilities(&nodes, &component_builder, advisories);
+
     let bom = Bom {
+        serial_number,
         metadata: Some(metadata),
         components: Some(Components(components)),
         dependencies: Some(Dependencies(dependencies)),
+        vulnerabilities: (!vulnerabilities.is_empty()).then_some(Vulnerabilities(vulnerabilities)),
         ..Bom::default()
     };
 
     Ok(bom)
 }
 
+/// A distribution installed in an environment, alongside its metadata and the hashes recorded at
+/// installation time.
+#[derive(Debug)]
+pub struct InstalledPackage<'a> {
+    pub dist: &'a InstalledDist,
+    pub metadata: &'a ResolutionMetadata,
+    pub hashes: Vec<HashDigest>,
+}
+
+/// Create a `CycloneDX` BOM for the distributions installed in an environment, such as one that
+/// isn't managed by a lockfile.
+///
+/// The components mirror those exported from a lockfile, with the dependencies of each
+/// distribution evaluated against the environment's interpreter. The interpreter itself is only
+/// recorded in the metadata if `include_interpreter` is set, as it varies across machines.
+pub fn from_installed(
+    packages: &[InstalledPackage<'_>],
+    interpreter: &Interpreter,
+    hashes: bool,
+    include_interpreter: bool,
+    source_date_epoch: Option<Timestamp>,
+) -> Bom {
+    let attestations = Attestations::default();
+    let mut component_builder = ComponentBuilder::new(&attestations, hashes);
+
+    let mut bom_refs = FxHashMap::default();
+    let components = packages
+        .iter()
+        .map(|package| {
+            let component = component_builder.create_installed_component(package);
+            if let Some(bom_ref) = &component.bom_ref {
+                bom_refs.insert(package.dist.name(), bom_ref.clone());
+            }
+            component
+        })
+        .collect::<Vec<_>>();
+
+    // Only the unconditional dependencies of each distribution are known to be required, as the
+    // extras it was installed with aren't recorded.
+    let dependencies = packages
+        .iter()
+        .filter_map(|package| {
+            let bom_ref = bom_refs.get(package.dist.name())?;
+            let dependencies = package
+                .metadata
+                .requires_dist
+                .iter()
+                .filter(|requirement| requirement.marker.evaluate(interpreter.markers(), &[]))
+                .filter_map(|requirement| bom_refs.get(&requirement.name))
+                .cloned()
+                .collect::<BTreeSet<_>>();
+            Some(Dependency {
+                dependency_ref: bom_ref.clone(),
+                dependencies: dependencies.into_iter().collect(),
+            })
+        })
+        .collect();
+
+    let metadata = Metadata {
+        timestamp: match source_date_epoch {
+            Some(timestamp) => {
+                DateTime::try_from(timestamp.strftime("%Y-%m-%dT%H:%M:%SZ").to_string()).ok()
+            }
+            None => DateTime::now().ok(),
+        },
+        tools: Some(Tools::Object {
+            services: None,
+            components: Some(Components(vec![create_uv_component()])),
+        }),
+        properties: include_interpreter.then(|| {
+            Properties(vec![
+                Property::new(
+                    "uv:python:implementation",
+                    interpreter.implementation_name(),
+                ),
+                Property::new(
+                    "uv:python:version",
+                    &interpreter.python_full_version().to_string(),
+                ),
+            ])
+        }),
+        ..Metadata::default()
+    };
+
+    // For reproducible builds, derive the serial number from the installed distributions rather
+    // than generating a random one.
+    let serial_number = if source_date_epoch.is_some() {
+        Some(reproducible_serial_number(
+            &interpreter.python_full_version().to_string(),
+            &components,
+        ))
+    } else {
+        Some(UrnUuid::generate())
+    };
+
+    Bom {
+        serial_number,
+        metadata: Some(metadata),
+        components: Some(Components(components)),
+        dependencies: Some(Dependencies(dependencies)),
+        ..Bom::default()
+    }
+}
+
+/// Create a package URL for an installed distribution.
+///
+/// As for packages in a lockfile, distributions installed from Git repositories, direct URLs, and
+/// local archives are qualified by their source, and local directories have no package URL.
+fn create_installed_purl(dist: &InstalledDist) -> Option<String> {
+    let name = percent_encode(dist.name().as_str().as_bytes(), PURL_ENCODE_SET);
+    let version = dist.version().to_string();
+    let version = percent_encode(version.as_bytes(), PURL_ENCODE_SET);
+
+    let vcs_url;
+    let qualifiers = match &dist.kind {
+        InstalledDistKind::Registry(_)
+        | InstalledDistKind::EggInfoFile(_)
+        | InstalledDistKind::EggInfoDirectory(_) => vec![],
+        InstalledDistKind::Url(dist) => match &*dist.direct_url {
+            DirectUrl::VcsUrl {
+                url,
+                vcs_info,
+                subdirectory,
+            } => {
+                vcs_url = create_installed_vcs_url(url, vcs_info, subdirectory.as_deref());
+                vec![("vcs_url", vcs_url.as_str())]
+            }
+            DirectUrl::ArchiveUrl { url, .. } if dist.url.scheme() == "file" => {
+                let file_name = url.rsplit('/').next()?;
+                vec![("file_name", file_name)]
+            }
+            DirectUrl::ArchiveUrl { url, .. } => vec![("download_url", url.as_str())],
+            DirectUrl::LocalDirectory { .. } => return None,
+        },
+        InstalledDistKind::LegacyEditable(_) => return None,
+    };
+
+    let qualifiers = if qualifiers.is_empty() {
+        String::new()
+    } else {
+        format_qualifiers(&qualifiers)
+    };
+
+    Some(format!("pkg:pypi/{name}@{version}{qualifiers}"))
+}
+
+/// Generate a VCS URL for a distribution installed from a repository, in the same format as for
+/// Git sources in a lockfile (e.g., `git+https://github.com/user/repo@<commit>#<subdirectory>`).
+fn create_installed_vcs_url(url: &str, vcs_info: &VcsInfo, subdirectory: Option<&Path>) -> String {
+    let mut vcs_url = format!("{}+{url}", vcs_info.vcs);
+    if let Some(commit_id) = &vcs_info.commit_id {
+        vcs_url.push('@');
+        vcs_url.push_str(commit_id);
+    }
+    if let Some(subdirectory) = subdirectory {
+        vcs_url.push('#');
+        vcs_url.push_str(&PortablePath::from(subdirectory).to_string());
+    }
+    vcs_url
+}
+
+/// An error that occurred while writing a `CycloneDX` document.
+#[derive(Debug, thiserror::Error)]
+pub enum WriteError {
+    #[error(transparent)]
+    Json(#[from] JsonWriteError),
+    #[error(transparent)]
+    Xml(#[from] XmlWriteError),
+}
+
+/// Write a `CycloneDX` BOM to the given writer, in the given specification version and encoding.
+pub fn write(
+    bom: Bom,
+    version: CycloneDxVersion,
+    encoding: CycloneDxEncoding,
+    writer: &mut impl std::io::Write,
+) -> Result<(), WriteError> {
+    let mut bom = bom;
+    let version = match version {
+        CycloneDxVersion::V1_4 => {
+            // Version 1.4 predates `tools.components`, so the tools are listed in the legacy form.
+            if let Some(metadata) = &mut bom.metadata
+                && let Some(Tools::Object { components, .. }) = &metadata.tools
+            {
+                let tools = components
+                    .iter()
+                    .flat_map(|components| &components.0)
+                    .map(|component| Tool {
+                        vendor: component.publisher.clone(),
+                        name: Some(component.name.clone()),
+                        version: component.version.clone(),
+                        hashes: None,
+                        external_references: None,
+                    })
+                    .collect();
+                metadata.tools = Some(Tools::List(tools));
+            }
+            SpecVersion::V1_4
+        }
+        CycloneDxVersion::V1_5 => SpecVersion::V1_5,
+    };
+    match encoding {
+        CycloneDxEncoding::Json => bom.output_as_json(writer, version)?,
+        CycloneDxEncoding::Xml => bom.output_as_xml(writer, version)?,
+    }
+    Ok(())
+}
+
+/// An error that occurred while reading a `CycloneDX` document.
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError {
+    #[error(transparent)]
+    Json(#[from] JsonReadError),
+    #[error(transparent)]
+    Xml(#[from] XmlReadError),
+}
+
+/// Read a `CycloneDX` BOM in any supported specification version, encoded as JSON or XML.
+pub fn read(contents: &[u8]) -> Result<Bom, ReadError> {
+    if !contents.trim_ascii_start().starts_with(b"<") {
+        return Ok(Bom::parse_from_json(contents)?);
+    }
+
+    // XML documents declare the specification version in the namespace of the root element.
+    let text = String::from_utf8_lossy(contents);
+    let version = if text.contains("http://cyclonedx.org/schema/bom/1.3") {
+        SpecVersion::V1_3
+    } else if text.contains("http://cyclonedx.org/schema/bom/1.4") {
+        SpecVersion::V1_4
+    } else {
+        SpecVersion::V1_5
+    };
+    Ok(Bom::parse_from_xml_with_version(contents, version)?)
+}
+
+/// Derive a version 5 UUID from the contents of the lockfile and the exported components.
+///
+/// The exported components are included, as exports of the same lockfile with different options
+/// (e.g., `--no-dev`) describe different sets of packages.
+fn reproducible_serial_number(lock: &str, components: &[Component]) -> UrnUuid {
+    let mut name = lock.to_string();
+    for component in components {
+        if let Some(bom_ref) = &component.bom_ref {
+            name.push('\n');
+            name.push_str(bom_ref);
+        }
+    }
+
+    let mut hasher = Sha1::new();
+    hasher.update(Uuid::NAMESPACE_URL.as_bytes());
+    hasher.update(name.as_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    let uuid = uuid::Builder::from_sha1_bytes(bytes).into_uuid();
+
+    UrnUuid(format!("urn:uuid:{uuid}"))
+}
+
+/// Apply the user-provided metadata from `[tool.uv.sbom]` to the SBOM metadata.
+fn apply_metadata(metadata: &mut Metadata, sbom: &SbomMetadata) {
+    if let Some(supplier) = &sbom.supplier {
+        metadata.supplier = Some(organizational_entity(supplier));
+    }
+    if let Some(manufacturer) = &sbom.manufacturer {
+        metadata.manufacture = Some(organizational_entity(manufacturer));
+    }
+    if !sbom.authors.is_empty() {
+        metadata.authors = Some(sbom.authors.iter().map(organizational_contact).collect());
+    }
+    if let Some(component_type) = sbom.component_type
+        && let Some(component) = &mut metadata.component
+    {
+        component.component_type = classification(component_type);
+    }
+    if let Some(Tools::Object {
+        components: Some(Components(tools)),
+        ..
+    }) = &mut metadata.tools
+    {
+        tools.extend(sbom.tools.iter().map(|tool| {
+            let mut component = Component::new(Classification::Application, &tool.name, "", None);
+            component.version = tool.version.as_deref().map(NormalizedString::new);
+            component.publisher = tool.vendor.as_deref().map(NormalizedString::new);
+            component
+        }));
+    }
+    if !sbom.properties.is_empty() {
+        metadata
+            .properties
+            .get_or_insert_with(|| Properties(vec![]))
+            .0
+            .extend(
+                sbom.properties
+                    .iter()
+                    .map(|(name, value)| Property::new(name, value)),
+            );
+    }
+}
+
+/// Create the component that represents uv in the tools that generated the SBOM.
+fn create_uv_component() -> Component {
+    let mut component = Component::new(
+        Classification::Application,
+        "uv",
+        uv_version::version(),
+        None,
+    );
+    component.publisher = Some(NormalizedString::new("Astral Software Inc."));
+    component.purl = format!("pkg:pypi/uv@{}", uv_version::version())
+        .parse()
+        .ok();
+    component
+}
+
+/// Describe the environment that the lockfile was resolved for, and the interpreter used for the
+/// export, if any, as the set of exported components depends on both.
+fn create_resolution_properties(lock: &Lock, interpreter: Option<&Interpreter>) -> Vec<Property> {
+    let mut properties = vec![
+        Property::new(
+            "uv:resolution:requires-python",
+            &lock.requires_python().to_string(),
+        ),
+        Property::new("uv:resolution:mode", &lock.resolution_mode().to_string()),
+    ];
+    // As in the lockfile, the environments are simplified with respect to `requires-python`.
+    for (name, environments) in [
+        ("uv:resolution:environment", lock.supported_environments()),
+        (
+            "uv:resolution:required-environment",
+            lock.required_environments(),
+        ),
+    ] {
+        properties.extend(
+            environments
+                .iter()
+                .filter_map(|marker| {
+                    SimplifiedMarkerTree::new(lock.requires_python(), *marker).try_to_string()
+                })
+                .map(|marker| Property::new(name, &marker)),
+        );
+    }
+    if let Some(interpreter) = interpreter {
+        properties.push(Property::new(
+            "uv:python:implementation",
+            interpreter.implementation_name(),
+        ));
+        properties.push(Property::new(
+            "uv:python:version",
+            &interpreter.python_full_version().to_string(),
+        ));
+    }
+    properties
+}
+
+fn organizational_entity(organization: &SbomOrganization) -> OrganizationalEntity {
+    OrganizationalEntity {
+        bom_ref: None,
+        name: Some(NormalizedString::new(&organization.name)),
+        url: (!organization.url.is_empty())
+            .then(|| organization.url.iter().map(|url| Uri::new(url)).collect()),
+        contact: (!organization.contact.is_empty()).then(|| {
+            organization
+                .contact
+                .iter()
+                .map(organizational_contact)
+                .collect()
+        }),
+    }
+}
+
+fn organizational_contact(contact: &SbomContact) -> OrganizationalContact {
+    OrganizationalContact {
+        bom_ref: None,
+        name: contact.name.as_deref().map(NormalizedString::new),
+        email: contact.email.as_deref().map(NormalizedString::new),
+        phone: contact.phone.as_deref().map(NormalizedString::new),
+    }
+}
+
+fn classification(component_type: SbomComponentType) -> Classification {
+    match component_type {
+        SbomComponentType::Application => Classification::Application,
+        SbomComponentType::Framework => Classification::Framework,
+        SbomComponentType::Library => Classification::Library,
+        SbomComponentType::Container => Classification::Container,
+        SbomComponentType::Platform => Classification::Platform,
+        SbomComponentType::OperatingSystem => Classification::OperatingSystem,
+        SbomComponentType::Device => Classification::Device,
+        SbomComponentType::DeviceDriver => Classification::DeviceDriver,
+        SbomComponentType::Firmware => Classification::Firmware,
+        SbomComponentType::File => Classification::File,
+        SbomComponentType::MachineLearningModel => Classification::MachineLearningModel,
+        SbomComponentType::Data => Classification::Data,
+    }
+}
+
+/// Create a `CycloneDX` vulnerability for each advisory affecting the exported packages, referencing
+/// the affected components.
+fn create_vulnerabilities(
+    nodes: &[ExportableRequirement<'_>],
+    component_builder: &ComponentBuilder,
+    advisories: &Advisories,
+) -> Vec<Vulnerability> {
+    // Collect the components affected by each advisory.
+    let mut affected = BTreeMap::<&str, (&Advisory, BTreeSet<&str>)>::new();
+    for node in nodes {
+        let Some(version) = node.package.id.version.as_ref() else {
+            continue;
+        };
+        let Some(bom_ref) = component_builder.get_bom_ref(&node.package.id) else {
+            continue;
+        };
+        for advisory in advisories
+            .get(&(node.package.id.name.clone(), version.clone()))
+            .into_iter()
+            .flatten()
+        {
+            affected
+                .entry(advisory.id.as_str())
+                .or_insert_with(|| (advisory, BTreeSet::new()))
+                .1
+                .insert(bom_ref);
+        }
+    }
+
+    affected
+        .into_values()
+        .map(|(advisory, bom_refs)| {
+            let source = VulnerabilitySource {
+                name: Some(NormalizedString::new(&advisory.source)),
+                url: Some(Uri::new(&advisory.url)),
+            };
+            let references = advisory
+                .aliases
+                .iter()
+                .map(|alias| VulnerabilityReference {
+                    id: NormalizedString::new(alias),
+                    vulnerability_source: source.clone(),
+                })
+                .collect::<Vec<_>>();
+            let ratings = advisory
+                .cvss
+                .iter()
+                .map(|vector| VulnerabilityRating {
+                    vulnerability_source: None,
+                    score: None,
+                    severity: None,
+                    score_method: Some(cvss_method(vector)),
+                    vector: Some(NormalizedString::new(vector)),
+                    justification: None,
+                })
+                .collect::<Vec<_>>();
+            let date_time = |timestamp: Option<Timestamp>| {
+                timestamp.and_then(|timestamp| {
+                    DateTime::try_from(timestamp.strftime("%Y-%m-%dT%H:%M:%SZ").to_string()).ok()
+                })
+            };
+
+            Vulnerability {
+                bom_ref: Some(advisory.id.clone()),
+                id: Some(NormalizedString::new(&advisory.id)),
+                vulnerability_source: Some(source),
+                vulnerability_references: (!references.is_empty())
+                    .then_some(VulnerabilityReferences(references)),
+                vulnerability_ratings: (!ratings.is_empty())
+                    .then_some(VulnerabilityRatings(ratings)),
+                cwes: None,
+                description: advisory.summary.clone(),
+                detail: advisory.details.clone(),
+                recommendation: None,
+                workaround: None,
+                proof_of_concept: None,
+                advisories: None,
+                created: None,
+                published: date_time(advisory.published),
+                updated: date_time(advisory.modified),
+                rejected: None,
+                vulnerability_credits: None,
+                tools: None,
+                vulnerability_analysis: None,
+                vulnerability_targets: Some(VulnerabilityTargets(
+                    bom_refs
+                        .into_iter()
+                        .map(|bom_ref| VulnerabilityTarget {
+                            bom_ref: bom_ref.to_string(),
+                            versions: None,
+                        })
+                        .collect(),
+                )),
+                properties: None,
+            }
+        })
+        .collect()
+}
+
+/// Determine the CVSS version of a CVSS vector, e.g., `CVSS:3.1/AV:N/AC:L/...`.
+fn cvss_method(vector: &str) -> ScoreMethod {
+    if vector.starts_with("CVSS:4.0/") {
+        ScoreMethod::CVSSv4
+    } else if vector.starts_with("CVSS:3.1/") {
+        ScoreMethod::CVSSv31
+    } else if vector.starts_with("CVSS:3.0/") {
+        ScoreMethod::CVSSv3
+    } else if vector.starts_with("AV:") {
+        // CVSS v2 vectors have no version prefix.
+        ScoreMethod::CVSSv2
+    } else {
+        ScoreMethod::Unknown(vector.to_string())
+    }
+}
+
 fn create_dependencies(
     nodes: &[ExportableRequirement<'_>],
     component_builder: &ComponentBuilder,
 ) -> Vec<Dependency> {
     nodes
         .iter()
-        .map(|node| {
-            let component = component_builder
-                .get_component(&node.package.id)
-                .expect("All nodes should have been added to map");
-
-            let immediate_deps = &node.package.dependencies;
-            let optional_deps = node.package.optional_dependencies.values().flatten();
-            let dep_groups = node.package.dependency_groups.values().flatten();
+        .filter_map(|node| {
+            let bom_ref = component_builder.get_bom_ref(&node.package.id)?;
 
-            let package_deps = immediate_deps
+            // Look up the bom-ref of each dependency by its package ID, skipping any that aren't
+            // part of the export (e.g., due to markers or `--prune`).
+            let dependencies = node
+                .package
+                .dependencies
                 .iter()
-                .chain(optional_deps)
-                .chain(dep_groups)
-                .filter_map(|dep| component_builder.get_component(&dep.package_id));
-
-            let bom_refs = package_deps
-                .map(|p| p.bom_ref.clone().expect("bom-ref should always exist"))
-                .sorted_unstable()
-                .unique()
-                .collect();
-
-            Dependency {
-                dependency_ref: component
-                    .bom_ref
-                    .clone()
-                    .expect("bom-ref should always exist"),
-                dependencies: bom_refs,
-            }
+                .chain(node.package.optional_dependencies.values().flatten())
+                .chain(node.package.dependency_groups.values().flatten())
+                .filter_map(|dep| component_builder.get_bom_ref(&dep.package_id))
+                .collect::<BTreeSet<_>>();
+
+            Some(Dependency {
+                dependency_ref: bom_ref.to_string(),
+                dependencies: dependencies.into_iter().map(ToString::to_string).collect(),
+            })
         })
         .collect()
 }
@@ -0,0 +1,240 @@
+//! Export a lockfile as a GitHub dependency submission snapshot.
+//!
+//! See: <https://docs.github.com/en/rest/dependency-graph/dependency-submission>
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_normalize::PackageName;
+
+use crate::lock::export::cyclonedx_json::create_purl;
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{Package, PackageId};
+use crate::{Installable, LockError};
+
+/// The GitHub Actions job that produced a [`GitHubSnapshot`].
+#[derive(Debug, Clone)]
+pub struct GitHubJob {
+    /// The commit SHA that the snapshot describes.
+    pub sha: String,
+    /// The Git reference that the snapshot describes, e.g., `refs/heads/main`.
+    pub git_ref: String,
+    /// The key used to group snapshots from the same workflow job over time.
+    pub correlator: String,
+    /// The ID of the job run.
+    pub id: String,
+}
+
+/// A dependency snapshot, as accepted by the GitHub dependency submission API.
+#[derive(Debug, Serialize)]
+pub struct GitHubSnapshot {
+    version: u32,
+    sha: String,
+    #[serde(rename = "ref")]
+    git_ref: String,
+    job: Job,
+    detector: Detector,
+    scanned: jiff::Timestamp,
+    manifests: BTreeMap<String, Manifest>,
+}
+
+#[derive(Debug, Serialize)]
+struct Job {
+    correlator: String,
+    id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Detector {
+    name: &'static str,
+    version: &'static str,
+    url: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Manifest {
+    name: String,
+    file: ManifestFile,
+    resolved: BTreeMap<String, ResolvedDependency>,
+}
+
+#[derive(Debug, Serialize)]
+struct ManifestFile {
+    source_location: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ResolvedDependency {
+    package_url: String,
+    relationship: Relationship,
+    scope: Scope,
+    dependencies: Vec<String>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Relationship {
+    /// The package is declared by a workspace member.
+    Direct,
+    /// The package is only required by other dependencies.
+    Indirect,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Scope {
+    /// The package is required at runtime.
+    Runtime,
+    /// The package is only required by dependency groups.
+    Development,
+}
+
+impl GitHubSnapshot {
+    /// Generate a [`GitHubSnapshot`] describing the locked packages in `source_location` (e.g.,
+    /// `uv.lock`).
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        groups: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+        source_location: String,
+        job: GitHubJob,
+    ) -> Result<Self, LockError> {
+        let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+            target,
+            prune,
+            extras,
+            groups,
+            // Track the dependents of each package, to distinguish direct dependencies.
+            true,
+            install_options,
+        )?;
+        nodes.sort_unstable_by_key(|node| &node.package.id);
+
+        let exported = nodes
+            .iter()
+            .map(|node| &node.package.id)
+            .collect::<FxHashSet<_>>();
+        let runtime = runtime_packages(target);
+
+        let resolved = nodes
+            .iter()
+            .filter_map(|node| {
+                let package_url = create_purl(node.package)?;
+                let dependencies = node
+                    .package
+                    .dependencies
+                    .iter()
+                    .chain(node.package.optional_dependencies.values().flatten())
+                    .chain(node.package.dependency_groups.values().flatten())
+                    .filter(|dep| exported.contains(&dep.package_id))
+                    .filter_map(|dep| create_purl(target.lock().find_by_id(&dep.package_id)))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect();
+                let dependency = ResolvedDependency {
+                    package_url: package_url.clone(),
+                    relationship: relationship(target, node),
+                    scope: if runtime
+                        .as_ref()
+                        .is_none_or(|runtime| runtime.contains(&node.package.id))
+                    {
+                        Scope::Runtime
+                    } else {
+                        Scope::Development
+                    },
+                    dependencies,
+                };
+                Some((package_url, dependency))
+            })
+            .collect();
+
+        let manifest = Manifest {
+            name: source_location.clone(),
+            file: ManifestFile {
+                source_location: source_location.clone(),
+            },
+            resolved,
+        };
+
+        Ok(Self {
+            version: 0,
+            sha: job.sha,
+            git_ref: job.git_ref,
+            job: Job {
+                correlator: job.correlator,
+                id: job.id,
+            },
+            detector: Detector {
+                name: "uv",
+                version: uv_version::version(),
+                url: "https://github.com/astral-sh/uv",
+            },
+            scanned: jiff::Timestamp::now(),
+            manifests: BTreeMap::from([(source_location, manifest)]),
+        })
+    }
+}
+
+/// Returns `true` if the package is a workspace member.
+fn is_member<'lock>(target: &impl Installable<'lock>, package: &Package) -> bool {
+    target.lock().members().contains(&package.id.name)
+        || target.roots().any(|root| *root == package.id.name)
+}
+
+/// A package is a direct dependency if it's required by the export target itself or by a
+/// workspace member.
+fn relationship<'lock>(
+    target: &impl Installable<'lock>,
+    node: &ExportableRequirement<'lock>,
+) -> Relationship {
+    if node.dependents.is_empty()
+        || node
+            .dependents
+            .iter()
+            .any(|dependent| is_member(target, dependent))
+    {
+        Relationship::Direct
+    } else {
+        Relationship::Indirect
+    }
+}
+
+/// Collect the packages that are reachable from the workspace members without traversing any
+/// dependency groups.
+///
+/// Returns `None` if the target has no workspace members (e.g., for scripts), in which case all
+/// packages are required at runtime.
+fn runtime_packages<'lock>(
+    target: &impl Installable<'lock>,
+) -> Option<FxHashSet<&'lock PackageId>> {
+    let lock = target.lock();
+    let mut seen = FxHashSet::default();
+    let mut queue = lock
+        .packages()
+        .iter()
+        .filter(|package| is_member(target, package))
+        .collect::<Vec<_>>();
+    if queue.is_empty() {
+        return None;
+    }
+    while let Some(package) = queue.pop() {
+        if !seen.insert(&package.id) {
+            continue;
+        }
+        for dep in package
+            .dependencies
+            .iter()
+            .chain(package.optional_dependencies.values().flatten())
+        {
+            queue.push(lock.find_by_id(&dep.package_id));
+        }
+    }
+    Some(seen)
+}
@@ -17,15 +17,19 @@ use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::{Reachable, marker_reachability};
 use crate::lock::LockErrorKind;
+pub use crate::lock::export::github_snapshot::{GitHubJob, GitHubSnapshot};
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
 pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlErrorKind};
 pub use crate::lock::export::requirements_txt::RequirementsTxtExport;
+pub use crate::lock::export::spdx::SpdxDocument;
 use crate::universal_marker::resolve_conflicts;
 use crate::{Installable, LockError, Package};
 
 pub mod cyclonedx_json;
+mod github_snapshot;
 mod pylock_toml;
 mod requirements_txt;
+mod spdx;
 
 /// A flat requirement, with its associated marker.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,6 +40,9 @@ struct ExportableRequirement<'lock> {
     marker: MarkerTree,
     /// The list of packages that depend on this package.
     dependents: Vec<&'lock Package>,
+    /// Whether the package is required by the exported packages, as opposed to only by their
+    /// dependency groups or extras.
+    required: bool,
 }
 
 /// A set of flattened, exportable requirements, generated from a lockfile.
@@ -163,6 +170,7 @@ impl<'lock> ExportableRequirements<'lock> {
             .lock()
             .requirements()
             .iter()
+            .map(|dep| (dep, None))
             .chain(
                 target
                     .lock()
@@ -170,14 +178,14 @@ impl<'lock> ExportableRequirements<'lock> {
                     .iter()
                     .filter_map(|(group, deps)| {
                         if groups.contains(group) {
-                            Some(deps)
+                            Some(deps.iter().map(move |dep| (dep, Some(group))))
                         } else {
                             None
                         }
                     })
                     .flatten(),
             )
-            .filter(|dep| !prune.contains(&dep.name))
+            .filter(|(dep, _)| !prune.contains(&dep.name))
             .collect::<Vec<_>>();
 
         // Index the lockfile by package name, to avoid making multiple passes over the lockfile.
@@ -185,7 +193,7 @@ impl<'lock> ExportableRequirements<'lock> {
             let by_name: FxHashMap<_, Vec<_>> = {
                 let names = root_requirements
                     .iter()
-                    .map(|dep| &dep.name)
+                    .map(|(dep, _)| &dep.name)
                     .collect::<FxHashSet<_>>();
                 target.lock().packages().iter().fold(
                     FxHashMap::with_capacity_and_hasher(size_guess, FxBuildHasher),
@@ -198,7 +206,7 @@ impl<'lock> ExportableRequirements<'lock> {
                 )
             };
 
-            for requirement in root_requirements {
+            for (requirement, group) in root_requirements {
                 for dist in by_name.get(&requirement.name).into_iter().flatten() {
                     // Determine whether this entry is "relevant" for the requirement, by intersecting
                     // the markers.
@@ -226,7 +234,15 @@ impl<'lock> ExportableRequirements<'lock> {
                         .or_insert_with(|| graph.add_node(Node::Package(dist)));
 
                     // Add an edge from the root.
-                    graph.add_edge(root, dep_index, Edge::Prod(marker));
+                    graph.add_edge(
+                        root,
+                        dep_index,
+                        if let Some(group) = group {
+                            Edge::Dev(group, marker)
+                        } else {
+                            Edge::Prod(marker)
+                        },
+                    );
 
                     // Push its dependencies on the queue.
                     if seen.insert((&dist.id, None)) {
@@ -300,6 +316,29 @@ impl<'lock> ExportableRequirements<'lock> {
             marker_reachability(&graph, &[])
         };
 
+        // Determine the packages that are required, i.e., reachable from the root without traversing
+        // a dependency group or an extra of an exported root package. Extras of other packages are
+        // considered required, as they're only traversed if requested by another dependency.
+        let mut required = FxHashSet::default();
+        let mut stack = vec![root];
+        while let Some(index) = stack.pop() {
+            for edge in graph.edges_directed(index, Direction::Outgoing) {
+                let traverse = match edge.weight() {
+                    Edge::Prod(_) => true,
+                    Edge::Optional(..) => match &graph[index] {
+                        Node::Package(package) => {
+                            !target.roots().any(|root| *root == package.id.name)
+                        }
+                        Node::Root => false,
+                    },
+                    Edge::Dev(..) => false,
+                };
+                if traverse && required.insert(edge.target()) {
+                    stack.push(edge.target());
+                }
+            }
+        }
+
         // Collect all packages.
         let nodes = graph
             .node_references()
@@ -332,6 +371,7 @@ impl<'lock> ExportableRequirements<'lock> {
                 } else {
                     Vec::new()
                 },
+                required: required.contains(&index),
             })
             .filter(|requirement| !requirement.marker.is_false())
             .collect::<Vec<_>>();
@@ -38,7 +38,7 @@ use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
 use crate::lock::export::ExportableRequirements;
-use crate::lock::{Source, WheelTagHint, each_element_on_its_line_array};
+use crate::lock::{RegistrySource, Source, WheelTagHint, each_element_on_its_line_array};
 use crate::resolution::ResolutionGraphNode;
 use crate::{Installable, LockError, ResolverOutput};
 
@@ -732,6 +732,7 @@ impl<'lock> PylockToml {
                                     .url
                                     .to_url()
                                     .map_err(PylockTomlErrorKind::ToUrl)?;
+                                let path = local_registry_path(source, &url, target.install_path());
                                 Ok(PylockTomlWheel {
                                     // Optional "when the last component of path/ url would be the same value".
                                     name: if url
@@ -747,8 +748,8 @@ impl<'lock> PylockToml {
                                         .upload_time_utc_ms
                                         .map(Timestamp::from_millisecond)
                                         .transpose()?,
-                                    url: Some(url),
-                                    path: None,
+                                    url: if path.is_some() { None } else { Some(url) },
+                                    path,
                                     size: wheel.file.size,
                                     hashes: Hashes::from(wheel.file.hashes),
                                 })
@@ -876,6 +877,12 @@ impl<'lock> PylockToml {
                         .url
                         .to_url()
                         .map_err(PylockTomlErrorKind::ToUrl)?;
+                    let path = match &package.id.source {
+                        Source::Registry(source) => {
+                            local_registry_path(source, &url, target.install_path())
+                        }
+                        _ => None,
+                    };
                     Some(PylockTomlSdist {
                         // Optional "when the last component of path/ url would be the same value".
                         name: if url
@@ -891,8 +898,8 @@ impl<'lock> PylockToml {
                             .upload_time_utc_ms
                             .map(Timestamp::from_millisecond)
                             .transpose()?,
-                        url: Some(url),
-                        path: None,
+                        url: if path.is_some() { None } else { Some(url) },
+                        path,
                         size,
                         hashes: hash.cloned().map(Hashes::from).unwrap_or_default(),
                     })
@@ -1415,6 +1422,7 @@ impl PylockTomlWheel {
             dist_info_metadata: false,
             filename: SmallString::from(filename.to_string()),
             hashes: HashDigests::from(self.hashes.clone()),
+            provenance: None,
             requires_python: None,
             size: self.size,
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -1573,6 +1581,7 @@ impl PylockTomlSdist {
             dist_info_metadata: false,
             filename,
             hashes: HashDigests::from(self.hashes.clone()),
+            provenance: None,
             requires_python: None,
             size: self.size,
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -1690,6 +1699,26 @@ impl PylockTomlArchive {
     }
 }
 
+/// Return the path to a file in a local registry (e.g., a `--find-links` directory), relative to
+/// the install path.
+///
+/// Returns `None` if the registry is remote.
+fn local_registry_path(
+    source: &RegistrySource,
+    url: &DisplaySafeUrl,
+    install_path: &Path,
+) -> Option<PortablePathBuf> {
+    if !matches!(source, RegistrySource::Path(_)) || url.scheme() != "file" {
+        return None;
+    }
+    let path = url.to_file_path().ok()?;
+    Some(PortablePathBuf::from(
+        relative_to(&path, install_path)
+            .unwrap_or(path)
+            .into_boxed_path(),
+    ))
+}
+
 /// Convert a Jiff timestamp to a TOML datetime.
 #[expect(clippy::ref_option)]
 fn timestamp_to_toml_datetime<S>(
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::fmt::Formatter;
 use std::path::{Component, Path, PathBuf};
 
@@ -16,7 +17,7 @@ use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
-use crate::lock::{Package, PackageId, Source};
+use crate::lock::{Package, PackageId, RegistrySource, Source};
 use crate::{Installable, LockError};
 
 /// An export of a [`Lock`] that renders in `requirements.txt` format.
@@ -63,11 +64,34 @@ impl<'lock> RequirementsTxtExport<'lock> {
 
 impl std::fmt::Display for RequirementsTxtExport<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Write out any local registries (e.g., `--find-links` directories), without which the
+        // packages they provide can't be found.
+        let find_links = self
+            .nodes
+            .iter()
+            .filter_map(|node| match &node.package.id.source {
+                Source::Registry(RegistrySource::Path(path)) => Some(&**path),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+        for path in find_links {
+            if path.is_absolute() {
+                writeln!(
+                    f,
+                    "--find-links {}",
+                    Url::from_file_path(path).map_err(|()| std::fmt::Error)?
+                )?;
+            } else {
+                writeln!(f, "--find-links {}", anchor(path).portable_display())?;
+            }
+        }
+
         // Write out each package.
         for ExportableRequirement {
             package,
             marker,
             dependents,
+            required: _,
         } in &self.nodes
         {
             match &package.id.source {
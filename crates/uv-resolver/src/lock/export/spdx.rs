@@ -0,0 +1,362 @@
+//! Export a lockfile as an SPDX 2.3 JSON document.
+//!
+//! See: <https://spdx.github.io/spdx-spec/v2.3/>
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use jiff::Timestamp;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_fs::PortablePath;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigest, License};
+use uv_warnings::warn_user;
+
+use crate::lock::Source;
+use crate::lock::export::cyclonedx_json::{create_purl, create_vcs_url};
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{Package, PackageId, SourceDist, WheelWireSource};
+use crate::{Installable, LockError};
+
+/// The value of SPDX fields for which no information is available.
+const NOASSERTION: &str = "NOASSERTION";
+
+/// An SPDX 2.3 document describing the locked packages.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    spdx_version: &'static str,
+    data_license: &'static str,
+    #[serde(rename = "SPDXID")]
+    spdx_id: &'static str,
+    name: String,
+    document_namespace: String,
+    creation_info: CreationInfo,
+    packages: Vec<SpdxPackage>,
+    relationships: Vec<Relationship>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreationInfo {
+    created: String,
+    creators: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxPackage {
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_info: Option<String>,
+    download_location: String,
+    files_analyzed: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    checksums: Vec<Checksum>,
+    license_concluded: &'static str,
+    license_declared: String,
+    copyright_text: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    external_refs: Vec<ExternalRef>,
+    primary_package_purpose: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Checksum {
+    algorithm: &'static str,
+    checksum_value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExternalRef {
+    #[serde(rename = "referenceCategory")]
+    category: &'static str,
+    #[serde(rename = "referenceType")]
+    kind: &'static str,
+    #[serde(rename = "referenceLocator")]
+    locator: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Relationship {
+    spdx_element_id: String,
+    #[serde(rename = "relationshipType")]
+    kind: &'static str,
+    related_spdx_element: String,
+}
+
+impl SpdxDocument {
+    /// Generate an [`SpdxDocument`] describing the locked packages.
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        groups: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+        preview: Preview,
+        source_date_epoch: Option<Timestamp>,
+        script: Option<&Path>,
+    ) -> Result<Self, LockError> {
+        if !preview.is_enabled(PreviewFeature::SbomExport) {
+            warn_user!(
+                "`uv export --format=spdx2.3+json` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::SbomExport
+            );
+        }
+
+        let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+            target,
+            prune,
+            extras,
+            groups,
+            // Track the dependents of each package, to identify the packages the document describes.
+            true,
+            install_options,
+        )?;
+        nodes.sort_unstable_by_key(|node| &node.package.id);
+
+        // Assign a unique SPDX identifier to each package.
+        let mut seen = FxHashSet::default();
+        let ids = nodes
+            .iter()
+            .map(|node| {
+                let base = spdx_id(node.package);
+                let mut id = base.clone();
+                let mut suffix = 1;
+                while !seen.insert(id.clone()) {
+                    suffix += 1;
+                    id = format!("{base}-{suffix}");
+                }
+                (&node.package.id, id)
+            })
+            .collect::<FxHashMap<&PackageId, String>>();
+
+        let packages = nodes
+            .iter()
+            .map(|node| SpdxPackage::from_package(node.package, ids[&node.package.id].clone()))
+            .collect::<Vec<_>>();
+
+        // The document describes the workspace members that are being exported or, if there are
+        // none (e.g., for scripts), the packages they require directly.
+        let roots = nodes
+            .iter()
+            .filter(|node| target.roots().any(|root| *root == node.package.id.name))
+            .collect::<Vec<_>>();
+        let described = if roots.is_empty() {
+            nodes
+                .iter()
+                .filter(|node| node.dependents.is_empty())
+                .collect::<Vec<_>>()
+        } else {
+            roots
+        };
+
+        let mut relationships = described
+            .iter()
+            .map(|node| Relationship {
+                spdx_element_id: "SPDXRef-DOCUMENT".to_string(),
+                kind: "DESCRIBES",
+                related_spdx_element: ids[&node.package.id].clone(),
+            })
+            .collect::<Vec<_>>();
+        for node in &nodes {
+            relationships.extend(dependencies(node, &ids).into_iter().map(|dependency| {
+                Relationship {
+                    spdx_element_id: ids[&node.package.id].clone(),
+                    kind: "DEPENDS_ON",
+                    related_spdx_element: dependency.to_string(),
+                }
+            }));
+        }
+
+        // Name the document after the project or, for scripts, the script file.
+        let name = target
+            .roots()
+            .next()
+            .or_else(|| target.lock().root().map(Package::name))
+            .map(ToString::to_string)
+            .or_else(|| {
+                script
+                    .and_then(Path::file_name)
+                    .and_then(OsStr::to_str)
+                    .map(ToString::to_string)
+            })
+            .unwrap_or_else(|| "uv-workspace".to_string());
+
+        // Derive the namespace from the document's contents, such that it's unique to the set of
+        // packages it describes.
+        let digest = uv_cache_key::cache_digest(
+            &packages
+                .iter()
+                .map(|package| {
+                    format!(
+                        "{}:{}:{}",
+                        package.spdx_id,
+                        package.version_info.as_deref().unwrap_or_default(),
+                        package.download_location
+                    )
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(Self {
+            spdx_version: "SPDX-2.3",
+            data_license: "CC0-1.0",
+            spdx_id: "SPDXRef-DOCUMENT",
+            document_namespace: format!("https://spdx.org/spdxdocs/{name}-{digest}"),
+            name,
+            creation_info: CreationInfo {
+                created: source_date_epoch
+                    .unwrap_or_else(Timestamp::now)
+                    .strftime("%Y-%m-%dT%H:%M:%SZ")
+                    .to_string(),
+                creators: vec![format!("Tool: uv-{}", uv_version::version())],
+            },
+            packages,
+            relationships,
+        })
+    }
+}
+
+impl SpdxPackage {
+    fn from_package(package: &Package, spdx_id: String) -> Self {
+        let (download_location, hash) = download_location(package);
+        let checksums = hash.map(Checksum::from_hash).into_iter().collect();
+        let external_refs = create_purl(package)
+            .map(|purl| ExternalRef {
+                category: "PACKAGE-MANAGER",
+                kind: "purl",
+                locator: purl,
+            })
+            .into_iter()
+            .collect();
+
+        // Record the path of local packages, which have no download location.
+        let comment = match &package.id.source {
+            Source::Path(path)
+            | Source::Directory(path)
+            | Source::Editable(path)
+            | Source::Virtual(path) => Some(format!("Local path: {}", PortablePath::from(path))),
+            Source::Registry(_) | Source::Git(..) | Source::Direct(..) => None,
+        };
+
+        Self {
+            spdx_id,
+            name: package.id.name.to_string(),
+            version_info: package.id.version.as_ref().map(ToString::to_string),
+            download_location,
+            files_analyzed: false,
+            checksums,
+            license_concluded: NOASSERTION,
+            // Only SPDX expressions can be represented; license names are free-form.
+            license_declared: match &package.license {
+                Some(License::Expression(expression)) => expression.clone(),
+                Some(License::Names(_)) | None => NOASSERTION.to_string(),
+            },
+            copyright_text: NOASSERTION,
+            external_refs,
+            primary_package_purpose: "LIBRARY",
+            comment,
+        }
+    }
+}
+
+impl Checksum {
+    /// Convert a [`HashDigest`] to an SPDX [`Checksum`].
+    fn from_hash(hash: &HashDigest) -> Self {
+        let algorithm = match hash.algorithm {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha256 => "SHA256",
+            HashAlgorithm::Sha384 => "SHA384",
+            HashAlgorithm::Sha512 => "SHA512",
+            HashAlgorithm::Blake2b => "BLAKE2b-256",
+        };
+        Self {
+            algorithm,
+            checksum_value: hash.digest.to_string(),
+        }
+    }
+}
+
+/// Generate an SPDX identifier for a package, which may only contain letters, numbers, `.`, and
+/// `-`.
+fn spdx_id(package: &Package) -> String {
+    let id = match &package.id.version {
+        Some(version) => format!("SPDXRef-Package-{}-{version}", package.id.name),
+        None => format!("SPDXRef-Package-{}", package.id.name),
+    };
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Return the download location of a package, along with the hash of the artifact at that
+/// location, if known.
+///
+/// For registry packages, the source distribution is preferred over wheels.
+fn download_location(package: &Package) -> (String, Option<&HashDigest>) {
+    // Select the artifact that represents the package: the source distribution, if any, or the
+    // first wheel.
+    let (url, hash) = if let Some(sdist) = &package.sdist {
+        (
+            match sdist {
+                SourceDist::Url { url, .. } => Some(url.without_fragment().as_ref().to_string()),
+                SourceDist::Path { .. } | SourceDist::Metadata { .. } => None,
+            },
+            sdist.hash().map(|hash| &hash.0),
+        )
+    } else if let Some(wheel) = package.wheels.first() {
+        (
+            match &wheel.url {
+                WheelWireSource::Url { url } => Some(url.without_fragment().as_ref().to_string()),
+                WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+            },
+            wheel.hash.as_ref().map(|hash| &hash.0),
+        )
+    } else {
+        (None, None)
+    };
+
+    match &package.id.source {
+        Source::Registry(_) => (url.unwrap_or_else(|| NOASSERTION.to_string()), hash),
+        Source::Direct(url, _) => (url.without_fragment().as_ref().to_string(), hash),
+        Source::Git(url, git) => (create_vcs_url(url, git), None),
+        Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
+            (NOASSERTION.to_string(), hash)
+        }
+    }
+}
+
+/// Return the SPDX identifiers of the exported packages that a package depends on.
+fn dependencies<'a>(
+    node: &ExportableRequirement<'_>,
+    ids: &'a FxHashMap<&PackageId, String>,
+) -> BTreeSet<&'a str> {
+    node.package
+        .dependencies
+        .iter()
+        .chain(node.package.optional_dependencies.values().flatten())
+        .chain(node.package.dependency_groups.values().flatten())
+        .filter_map(|dependency| ids.get(&dependency.package_id).map(String::as_str))
+        .collect()
+}
@@ -41,8 +41,8 @@ use uv_platform_tags::{
     AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagPriority, Tags,
 };
 use uv_pypi_types::{
-    ConflictKind, Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, ParsedArchiveUrl,
-    ParsedGitUrl, PyProjectToml,
+    ConflictKind, Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, License,
+    ParsedArchiveUrl, ParsedGitUrl, ProjectLinks, PyProjectToml,
 };
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_small_str::SmallString;
@@ -53,7 +53,9 @@ use crate::exclude_newer::ExcludeNewerSpan;
 use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
-pub use crate::lock::export::{PylockToml, PylockTomlErrorKind, cyclonedx_json};
+pub use crate::lock::export::{
+    GitHubJob, GitHubSnapshot, PylockToml, PylockTomlErrorKind, SpdxDocument, cyclonedx_json,
+};
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
 pub use crate::lock::tree::TreeDisplay;
@@ -290,6 +292,12 @@ pub struct Lock {
     /// that exists in this map. That is, there are no dependencies that don't
     /// have a corresponding locked package entry in the same lockfile.
     by_id: FxHashMap<PackageId, usize>,
+    /// A map from package name to the indices in `packages` of every package
+    /// with that name.
+    ///
+    /// Most names map to a single package, but a name can map to several
+    /// packages when the resolution forked (e.g., on platform markers).
+    by_name: FxHashMap<PackageName, Vec<usize>>,
     /// The input requirements to the resolution.
     manifest: ResolverManifest,
 }
@@ -775,9 +783,10 @@ impl Lock {
         }
         packages.sort_by(|dist1, dist2| dist1.id.cmp(&dist2.id));
 
-        // Check for duplicate package IDs and also build up the map for
-        // packages keyed by their ID.
+        // Check for duplicate package IDs and also build up the maps for
+        // packages keyed by their ID and by their name.
         let mut by_id = FxHashMap::default();
+        let mut by_name: FxHashMap<PackageName, Vec<usize>> = FxHashMap::default();
         for (i, dist) in packages.iter().enumerate() {
             if by_id.insert(dist.id.clone(), i).is_some() {
                 return Err(LockErrorKind::DuplicatePackage {
@@ -785,6 +794,7 @@ impl Lock {
                 }
                 .into());
             }
+            by_name.entry(dist.id.name.clone()).or_default().push(i);
         }
 
         // Build up a map from ID to extras.
@@ -880,6 +890,7 @@ impl Lock {
             options,
             packages,
             by_id,
+            by_name,
             manifest,
         };
         Ok(lock)
@@ -928,6 +939,16 @@ impl Lock {
         self
     }
 
+    /// Omit the license and project URLs of each package from this lock.
+    #[must_use]
+    pub fn without_sbom_metadata(mut self) -> Self {
+        for package in &mut self.packages {
+            package.license = None;
+            package.project_links = None;
+        }
+        self
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -1486,17 +1507,24 @@ impl Lock {
     /// matching packages, then `Ok(None)` is returned.
     pub fn find_by_name(&self, name: &PackageName) -> Result<Option<&Package>, String> {
         let mut found_dist = None;
-        for dist in &self.packages {
-            if &dist.id.name == name {
-                if found_dist.is_some() {
-                    return Err(format!("found multiple packages matching `{name}`"));
-                }
-                found_dist = Some(dist);
+        for dist in self.find_all_by_name(name) {
+            if found_dist.is_some() {
+                return Err(format!("found multiple packages matching `{name}`"));
             }
+            found_dist = Some(dist);
         }
         Ok(found_dist)
     }
 
+    /// Returns every package with the given name, in lockfile order.
+    pub fn find_all_by_name(&self, name: &PackageName) -> impl Iterator<Item = &Package> {
+        self.by_name
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter_map(|index| self.packages.get(*index))
+    }
+
     /// Returns the package with the given name.
     ///
     /// If there are multiple matching packages, returns the package that
@@ -1512,19 +1540,17 @@ impl Lock {
         marker_env: &MarkerEnvironment,
     ) -> Result<Option<&Package>, String> {
         let mut found_dist = None;
-        for dist in &self.packages {
-            if &dist.id.name == name {
-                if dist.fork_markers.is_empty()
-                    || dist
-                        .fork_markers
-                        .iter()
-                        .any(|marker| marker.evaluate_no_extras(marker_env))
-                {
-                    if found_dist.is_some() {
-                        return Err(format!("found multiple packages matching `{name}`"));
-                    }
-                    found_dist = Some(dist);
+        for dist in self.find_all_by_name(name) {
+            if dist.fork_markers.is_empty()
+                || dist
+                    .fork_markers
+                    .iter()
+                    .any(|marker| marker.evaluate_no_extras(marker_env))
+            {
+                if found_dist.is_some() {
+                    return Err(format!("found multiple packages matching `{name}`"));
                 }
+                found_dist = Some(dist);
             }
         }
         Ok(found_dist)
@@ -2599,6 +2625,12 @@ pub struct Package {
     dependency_groups: BTreeMap<GroupName, Vec<Dependency>>,
     /// The exact requirements from the package metadata.
     metadata: PackageMetadata,
+    /// The license of the package, as declared in its metadata.
+    license: Option<License>,
+    /// The homepage and source repository of the package, as declared in its metadata.
+    ///
+    /// Named `urls` in `uv.lock`.
+    project_links: Option<ProjectLinks>,
 }
 
 impl Package {
@@ -2654,6 +2686,14 @@ impl Package {
                 })
                 .collect::<Result<_, _>>()?
         };
+        let license = annotated_dist
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.license.clone());
+        let project_links = annotated_dist
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.project_links.clone());
         Ok(Self {
             id,
             sdist,
@@ -2667,6 +2707,8 @@ impl Package {
                 provides_extra,
                 dependency_groups,
             },
+            license,
+            project_links,
         })
     }
 
@@ -3090,6 +3132,7 @@ impl Package {
                     hashes: sdist.hash().map_or(HashDigests::empty(), |hash| {
                         HashDigests::from(hash.0.clone())
                     }),
+                    provenance: None,
                     requires_python: None,
                     size: sdist.size(),
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
@@ -3165,6 +3208,7 @@ impl Package {
                     hashes: sdist.hash().map_or(HashDigests::empty(), |hash| {
                         HashDigests::from(hash.0.clone())
                     }),
+                    provenance: None,
                     requires_python: None,
                     size: sdist.size(),
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
@@ -3202,6 +3246,26 @@ impl Package {
 
         self.id.to_toml(None, &mut table);
 
+        if let Some(license) = &self.license {
+            table.insert(
+                "license",
+                value(serde::Serialize::serialize(
+                    license,
+                    toml_edit::ser::ValueSerializer::new(),
+                )?),
+            );
+        }
+
+        if let Some(project_links) = &self.project_links {
+            table.insert(
+                "urls",
+                value(serde::Serialize::serialize(
+                    project_links,
+                    toml_edit::ser::ValueSerializer::new(),
+                )?),
+            );
+        }
+
         if !self.fork_markers.is_empty() {
             let fork_markers = each_element_on_its_line_array(
                 simplified_universal_markers(&self.fork_markers, requires_python).into_iter(),
@@ -3385,6 +3449,15 @@ impl Package {
         }
     }
 
+    /// Returns the path to the source tree that the package is built from, relative to the
+    /// workspace root, if it is a directory or editable source.
+    pub fn source_tree(&self) -> Option<&Path> {
+        match &self.id.source {
+            Source::Directory(path) | Source::Editable(path) => Some(path),
+            _ => None,
+        }
+    }
+
     /// Return the fork markers for this package, if any.
     pub fn fork_markers(&self) -> &[UniversalMarker] {
         self.fork_markers.as_slice()
@@ -3410,6 +3483,27 @@ impl Package {
         }
     }
 
+    /// Returns the files for the package's source distribution and wheels, if it is a registry
+    /// source.
+    pub fn registry_files(
+        &self,
+        root: &Path,
+    ) -> Result<Vec<uv_distribution_types::File>, LockError> {
+        let Source::Registry(source) = &self.id.source else {
+            return Ok(Vec::new());
+        };
+        let mut files = Vec::with_capacity(self.wheels.len() + 1);
+        if let Some(uv_distribution_types::SourceDist::Registry(sdist)) =
+            self.to_source_dist(root)?
+        {
+            files.push(*sdist.file);
+        }
+        for wheel in &self.wheels {
+            files.push(*wheel.to_registry_wheel(source, root)?.file);
+        }
+        Ok(files)
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
@@ -3523,6 +3617,10 @@ struct PackageWire {
     optional_dependencies: BTreeMap<ExtraName, Vec<DependencyWire>>,
     #[serde(default, rename = "dev-dependencies", alias = "dependency-groups")]
     dependency_groups: BTreeMap<GroupName, Vec<DependencyWire>>,
+    #[serde(default)]
+    license: Option<License>,
+    #[serde(default, rename = "urls")]
+    project_links: Option<ProjectLinks>,
 }
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, serde::Deserialize)]
@@ -3589,6 +3687,8 @@ impl PackageWire {
                 .into_iter()
                 .map(|(group, deps)| Ok((group, unwire_deps(deps)?)))
                 .collect::<Result<_, LockError>>()?,
+            license: self.license,
+            project_links: self.project_links,
         })
     }
 }
@@ -4288,7 +4388,7 @@ impl SourceDist {
         }
     }
 
-    fn url(&self) -> Option<&UrlString> {
+    pub(crate) fn url(&self) -> Option<&UrlString> {
         match self {
             Self::Metadata { .. } => None,
             Self::Url { url, .. } => Some(url),
@@ -4296,6 +4396,15 @@ impl SourceDist {
         }
     }
 
+    /// Returns the path of the source distribution, relative to its (local) registry.
+    pub(crate) fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Metadata { .. } => None,
+            Self::Url { .. } => None,
+            Self::Path { path, .. } => Some(path),
+        }
+    }
+
     pub(crate) fn hash(&self) -> Option<&Hash> {
         match self {
             Self::Metadata { metadata } => metadata.hash.as_ref(),
@@ -4861,6 +4970,7 @@ impl Wheel {
                     dist_info_metadata: false,
                     filename: SmallString::from(filename.to_string()),
                     hashes: self.hash.iter().map(|h| h.0.clone()).collect(),
+                    provenance: None,
                     requires_python: None,
                     size: self.size,
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -4911,6 +5021,7 @@ impl Wheel {
                     dist_info_metadata: false,
                     filename: SmallString::from(filename.to_string()),
                     hashes: self.hash.iter().map(|h| h.0.clone()).collect(),
+                    provenance: None,
                     requires_python: None,
                     size: self.size,
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -4991,6 +5102,22 @@ enum WheelWireSource {
 }
 
 impl Wheel {
+    /// Returns the URL of the wheel, if it was found at a remote location.
+    pub(crate) fn url(&self) -> Option<&UrlString> {
+        match &self.url {
+            WheelWireSource::Url { url } => Some(url),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+        }
+    }
+
+    /// Returns the path of the wheel, relative to its (local) registry.
+    pub(crate) fn path(&self) -> Option<&Path> {
+        match &self.url {
+            WheelWireSource::Path { path } => Some(path),
+            WheelWireSource::Url { .. } | WheelWireSource::Filename { .. } => None,
+        }
+    }
+
     /// Returns the TOML representation of this wheel.
     fn to_toml(&self) -> Result<InlineTable, toml_edit::ser::Error> {
         let mut table = InlineTable::new();
@@ -6240,6 +6367,9 @@ enum LockErrorKind {
         /// The ID of the workspace member with an invalid source.
         id: PackageId,
     },
+    /// An error that occurs when the lockfile can't be serialized.
+    #[error("Failed to serialize the lockfile")]
+    Serialize(#[source] toml_edit::ser::Error),
 }
 
 /// An error that occurs when a source string could not be parsed.
@@ -6669,4 +6799,53 @@ source = { editable = "path/to/dir" }
         let result: Result<Lock, _> = toml::from_str(data);
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn find_by_name_forked() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+resolution-markers = [
+    "sys_platform == 'win32'",
+    "sys_platform != 'win32'",
+]
+
+[[package]]
+name = "a"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = ["sys_platform == 'win32'"]
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+
+[[package]]
+name = "a"
+version = "0.2.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = ["sys_platform != 'win32'"]
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+
+[[package]]
+name = "b"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+
+        let a = PackageName::from_str("a").unwrap();
+        let versions = lock
+            .find_all_by_name(&a)
+            .map(|package| package.id.version.as_ref().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(versions, ["0.1.0", "0.2.0"]);
+        assert!(lock.find_by_name(&a).is_err());
+
+        let b = PackageName::from_str("b").unwrap();
+        assert_eq!(lock.find_all_by_name(&b).count(), 1);
+        assert!(lock.find_by_name(&b).unwrap().is_some());
+
+        let c = PackageName::from_str("c").unwrap();
+        assert_eq!(lock.find_all_by_name(&c).count(), 0);
+        assert!(lock.find_by_name(&c).unwrap().is_none());
+    }
 }
@@ -8,6 +8,8 @@ use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::EdgeRef;
 use petgraph::{Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use serde::Serialize;
+use serde::ser::SerializeSeq;
 
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_console::human_readable_bytes;
@@ -167,20 +169,9 @@ impl<'env> TreeDisplay<'env> {
         //    dependencies.
         // - `dependencies` in PEP 723 scripts.
         {
-            // Index the lockfile by name.
-            let by_name: FxHashMap<_, Vec<_>> = {
-                lock.packages().iter().fold(
-                    FxHashMap::with_capacity_and_hasher(lock.len(), FxBuildHasher),
-                    |mut map, package| {
-                        map.entry(&package.id.name).or_default().push(package);
-                        map
-                    },
-                )
-            };
-
             // Identify any requirements attached to the workspace itself.
             for requirement in lock.requirements() {
-                for package in by_name.get(&requirement.name).into_iter().flatten() {
+                for package in lock.find_all_by_name(&requirement.name) {
                     // Determine whether this entry is "relevant" for the requirement, by intersecting
                     // the markers.
                     let marker = if package.fork_markers.is_empty() {
@@ -217,7 +208,7 @@ impl<'env> TreeDisplay<'env> {
             // Identify any dependency groups attached to the workspace itself.
             for (group, requirements) in lock.dependency_groups() {
                 for requirement in requirements {
-                    for package in by_name.get(&requirement.name).into_iter().flatten() {
+                    for package in lock.find_all_by_name(&requirement.name) {
                         // Determine whether this entry is "relevant" for the requirement, by intersecting
                         // the markers.
                         let marker = if package.fork_markers.is_empty() {
@@ -507,22 +498,7 @@ impl<'env> TreeDisplay<'env> {
             line
         };
 
-        let mut dependencies = self
-            .graph
-            .edges_directed(cursor.node(), Direction::Outgoing)
-            .filter_map(|edge| match self.graph[edge.target()] {
-                Node::Root => None,
-                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
-            })
-            .collect::<Vec<_>>();
-        dependencies.sort_by_key(|cursor| {
-            let node = &self.graph[cursor.node()];
-            let edge = cursor
-                .edge()
-                .map(|edge_id| &self.graph[edge_id])
-                .map(Edge::kind);
-            (edge, node)
-        });
+        let dependencies = self.dependencies(cursor);
 
         let mut lines = vec![line];
 
@@ -580,37 +556,174 @@ impl<'env> TreeDisplay<'env> {
         lines
     }
 
-    /// Depth-first traverse the nodes to render the tree.
-    fn render(&self) -> Vec<String> {
-        let mut path = Vec::new();
-        let mut lines = Vec::with_capacity(self.graph.node_count());
-        let mut visited =
-            FxHashMap::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
+    /// Perform a depth-first traversal of the given package and its dependencies, collecting
+    /// the structured [`TreeNode`] for each package rather than the rendered lines.
+    fn visit_node(
+        &'env self,
+        cursor: Cursor,
+        visited: &mut FxHashMap<&'env PackageId, Vec<&'env PackageId>>,
+        path: &mut Vec<&'env PackageId>,
+    ) -> Option<TreeNode<'env>> {
+        // Short-circuit if the current path is longer than the provided depth.
+        if path.len() > self.depth {
+            return None;
+        }
+
+        let Node::Package(package_id) = self.graph[cursor.node()] else {
+            return None;
+        };
+        let edge = cursor.edge().map(|edge_id| &self.graph[edge_id]);
+
+        let mut node = TreeNode {
+            name: &package_id.name,
+            version: package_id.version.as_ref(),
+            extras: edge
+                .and_then(Edge::extras)
+                .filter(|extras| !extras.is_empty()),
+            extra: match edge {
+                Some(Edge::Optional(extra, _)) => Some(*extra),
+                _ => None,
+            },
+            group: match edge {
+                Some(Edge::Dev(group, _)) => Some(*group),
+                _ => None,
+            },
+            size: if self.show_sizes {
+                self.lock
+                    .find_by_id(package_id)
+                    .wheels
+                    .iter()
+                    .find_map(|wheel| wheel.size)
+            } else {
+                None
+            },
+            latest: None,
+            deduplicated: false,
+            dependencies: Vec::new(),
+        };
 
+        // Skip the traversal if the package is part of a cycle, or has already been visited.
+        if let Some(requirements) = visited.get(package_id) {
+            if !self.no_dedupe || path.contains(&package_id) {
+                node.deduplicated = !requirements.is_empty();
+                return Some(node);
+            }
+        }
+
+        // Incorporate the latest version of the package, if known.
+        node.latest = self.latest.get(package_id);
+
+        let dependencies = self.dependencies(cursor);
+
+        // Keep track of the dependency path to avoid cycles.
+        visited.insert(
+            package_id,
+            dependencies
+                .iter()
+                .filter_map(|node| match self.graph[node.node()] {
+                    Node::Package(package_id) => Some(package_id),
+                    Node::Root => None,
+                })
+                .collect(),
+        );
+        path.push(package_id);
+
+        node.dependencies = dependencies
+            .iter()
+            .filter_map(|dep| self.visit_node(*dep, visited, path))
+            .collect();
+
+        path.pop();
+
+        Some(node)
+    }
+
+    /// Return the dependencies of the given node, in display order.
+    fn dependencies(&self, cursor: Cursor) -> Vec<Cursor> {
+        let mut dependencies = self
+            .graph
+            .edges_directed(cursor.node(), Direction::Outgoing)
+            .filter_map(|edge| match self.graph[edge.target()] {
+                Node::Root => None,
+                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|cursor| {
+            let node = &self.graph[cursor.node()];
+            let edge = cursor
+                .edge()
+                .map(|edge_id| &self.graph[edge_id])
+                .map(Edge::kind);
+            (edge, node)
+        });
+        dependencies
+    }
+
+    /// Return the cursors from which to start each top-level traversal of the tree.
+    fn root_cursors(&self) -> Vec<Cursor> {
+        let mut cursors = Vec::with_capacity(self.roots.len());
         for node in &self.roots {
             match self.graph[*node] {
                 Node::Root => {
                     for edge in self.graph.edges_directed(*node, Direction::Outgoing) {
-                        let node = edge.target();
-                        path.clear();
-                        lines.extend(self.visit(
-                            Cursor::new(node, edge.id()),
-                            &mut visited,
-                            &mut path,
-                        ));
+                        cursors.push(Cursor::new(edge.target(), edge.id()));
                     }
                 }
                 Node::Package(_) => {
-                    path.clear();
-                    lines.extend(self.visit(Cursor::root(*node), &mut visited, &mut path));
+                    cursors.push(Cursor::root(*node));
                 }
             }
         }
+        cursors
+    }
+
+    /// Depth-first traverse the nodes to render the tree.
+    fn render(&self) -> Vec<String> {
+        let mut path = Vec::new();
+        let mut lines = Vec::with_capacity(self.graph.node_count());
+        let mut visited =
+            FxHashMap::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
+
+        for cursor in self.root_cursors() {
+            path.clear();
+            lines.extend(self.visit(cursor, &mut visited, &mut path));
+        }
 
         lines
     }
 }
 
+/// A package in the dependency tree, as serialized by [`TreeDisplay`].
+#[derive(Debug, Serialize)]
+struct TreeNode<'env> {
+    /// The name of the package.
+    name: &'env PackageName,
+    /// The version of the package, if it's not dynamic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'env Version>,
+    /// The extras of the package that are enabled by the dependent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extras: Option<&'env BTreeSet<ExtraName>>,
+    /// The extra of the dependent through which the package is required, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<&'env ExtraName>,
+    /// The dependency group through which the package is required, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'env GroupName>,
+    /// The compressed size of the package's first wheel, in bytes, with `--show-sizes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    /// The latest available version of the package, with `--outdated`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<&'env Version>,
+    /// Whether the dependencies were omitted, since the package was already displayed or forms a
+    /// cycle.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deduplicated: bool,
+    /// The dependencies of the package.
+    dependencies: Vec<Self>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
 enum Node<'env> {
     /// The synthetic root node.
@@ -699,3 +812,23 @@ impl std::fmt::Display for TreeDisplay<'_> {
         Ok(())
     }
 }
+
+impl Serialize for TreeDisplay<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut path = Vec::new();
+        let mut visited =
+            FxHashMap::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
+
+        let mut seq = serializer.serialize_seq(None)?;
+        for cursor in self.root_cursors() {
+            path.clear();
+            if let Some(node) = self.visit_node(cursor, &mut visited, &mut path) {
+                seq.serialize_element(&node)?;
+            }
+        }
+        seq.end()
+    }
+}
@@ -329,6 +329,22 @@ impl Preferences {
         })
     }
 
+    /// Returns an iterator over the versions pinned by a previous resolution, i.e., a lockfile or
+    /// the existing output file of `uv pip compile`.
+    pub(crate) fn previous_resolution(&self) -> impl Iterator<Item = (&PackageName, &Version)> {
+        self.0.iter().flat_map(|(name, entries)| {
+            entries
+                .iter()
+                .filter(|entry| match entry.source {
+                    // Registry packages are locked with their index, other sources are skipped.
+                    PreferenceSource::Lock => matches!(entry.index, PreferenceIndex::Explicit(_)),
+                    PreferenceSource::RequirementsTxt => true,
+                    PreferenceSource::Environment | PreferenceSource::Resolver => false,
+                })
+                .map(move |entry| (name, entry.pin.version()))
+        })
+    }
+
     /// Return the pinned version for a package, if any.
     pub(crate) fn get(&self, package_name: &PackageName) -> &[Entry] {
         self.0
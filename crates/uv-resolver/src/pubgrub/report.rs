@@ -17,6 +17,7 @@ use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion};
 use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, Tags};
+use uv_warnings::locale::Message;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::error::{ErrorTree, PrefixMatch};
@@ -82,9 +83,9 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                 }
 
                 if set == &Range::full() {
-                    format!("there are no versions of {package}")
+                    Message::NoVersions.format(&[("package", package)])
                 } else if set.as_singleton().is_some() {
-                    format!("there is no version of {package}{set}")
+                    Message::NoVersion.format(&[("package", package), ("set", set)])
                 } else {
                     let complement = set.complement();
                     let range =
@@ -97,7 +98,7 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                             complement
                         };
                     if range.is_empty() {
-                        return format!("there are no versions of {package}");
+                        return Message::NoVersions.format(&[("package", package)]);
                     }
                     if range.iter().count() == 1 {
                         format!(
@@ -174,24 +175,25 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         // by package first.
         terms_vec.sort_by(|&(pkg1, _), &(pkg2, _)| pkg1.cmp(pkg2));
         match terms_vec.as_slice() {
-            [] => "the requirements are unsatisfiable".into(),
+            [] => Message::RequirementsUnsatisfiable.text().to_string(),
             [(root, _)] if matches!(&**(*root), PubGrubPackageInner::Root(_)) => {
                 let root = self.format_root(root).unwrap();
-                format!("{root} are unsatisfiable")
+                Message::Unsatisfiable.format(&[("subject", &root)])
             }
             [(package, Term::Positive(range))]
                 if matches!(&**(*package), PubGrubPackageInner::Package { .. }) =>
             {
                 if let Some(member) = self.format_workspace_member(package) {
-                    format!("{member}'s requirements are unsatisfiable")
+                    Message::MemberUnsatisfiable.format(&[("member", &member)])
                 } else {
-                    format!("{} cannot be used", self.compatible_range(package, range))
+                    Message::CannotBeUsed
+                        .format(&[("subject", &self.compatible_range(package, range))])
                 }
             }
             [(package, Term::Negative(range))]
                 if matches!(&**(*package), PubGrubPackageInner::Package { .. }) =>
             {
-                format!("{} must be used", self.compatible_range(package, range))
+                Message::MustBeUsed.format(&[("subject", &self.compatible_range(package, range))])
             }
             [(p1, Term::Positive(r1)), (p2, Term::Negative(r2))] => self.format_external(
                 &External::FromDependencyOf((*p1).clone(), r1.clone(), (*p2).clone(), r2.clone()),
@@ -200,6 +202,7 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                 &External::FromDependencyOf((*p2).clone(), r2.clone(), (*p1).clone(), r1.clone()),
             ),
             slice => {
+                let and = format!(" {} ", Message::And.text());
                 let mut result = String::new();
                 let str_terms: Vec<_> = slice
                     .iter()
@@ -209,7 +212,7 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                     result.push_str(term);
                     match str_terms.len().cmp(&2) {
                         Ordering::Equal if index == 0 => {
-                            result.push_str(" and ");
+                            result.push_str(&and);
                         }
                         Ordering::Greater if index + 1 < str_terms.len() => {
                             result.push_str(", ");
@@ -218,11 +221,10 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                     }
                 }
                 if slice.len() == 1 {
-                    result.push_str(" cannot be used");
+                    Message::CannotBeUsed.format(&[("subject", &result)])
                 } else {
-                    result.push_str(" are incompatible");
+                    Message::AreIncompatible.format(&[("subject", &result)])
                 }
-                result
             }
         }
     }
@@ -237,11 +239,10 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         let external = self.format_both_external(external1, external2);
         let terms = self.format_terms(current_terms);
 
-        format!(
-            "Because {}we can conclude that {}",
-            Padded::from_string("", &external, ", "),
-            Padded::from_string("", &terms, "."),
-        )
+        Message::ExplainExternal.format(&[
+            ("cause", &Padded::from_string("", &external, ", ")),
+            ("conclusion", &Padded::from_string("", &terms, ".")),
+        ])
     }
 
     /// Both causes have already been explained so we use their refs.
@@ -259,14 +260,13 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         let derived2_terms = self.format_terms(&derived2.terms);
         let current_terms = self.format_terms(current_terms);
 
-        format!(
-            "Because we know from ({}) that {}and we know from ({}) that {}{}",
-            ref_id1,
-            Padded::new("", &derived1_terms, " "),
-            ref_id2,
-            Padded::new("", &derived2_terms, ", "),
-            Padded::new("", &current_terms, "."),
-        )
+        Message::ExplainRefs.format(&[
+            ("ref1", &ref_id1),
+            ("cause1", &Padded::new("", &derived1_terms, " ")),
+            ("ref2", &ref_id2),
+            ("cause2", &Padded::new("", &derived2_terms, ", ")),
+            ("conclusion", &Padded::new("", &current_terms, ".")),
+        ])
     }
 
     /// One cause is derived (already explained so one-line),
@@ -285,13 +285,12 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         let external = self.format_external(external);
         let current_terms = self.format_terms(current_terms);
 
-        format!(
-            "Because we know from ({}) that {}and {}we can conclude that {}",
-            ref_id,
-            Padded::new("", &derived_terms, " "),
-            Padded::new("", &external, ", "),
-            Padded::new("", &current_terms, "."),
-        )
+        Message::ExplainRefAndExternal.format(&[
+            ("ref", &ref_id),
+            ("derived", &Padded::new("", &derived_terms, " ")),
+            ("cause", &Padded::new("", &external, ", ")),
+            ("conclusion", &Padded::new("", &current_terms, ".")),
+        ])
     }
 
     /// Add an external cause to the chain of explanations.
@@ -303,11 +302,10 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         let external = self.format_external(external);
         let terms = self.format_terms(current_terms);
 
-        format!(
-            "And because {}we can conclude that {}",
-            Padded::from_string("", &external, ", "),
-            Padded::from_string("", &terms, "."),
-        )
+        Message::AndExplainExternal.format(&[
+            ("cause", &Padded::from_string("", &external, ", ")),
+            ("conclusion", &Padded::from_string("", &terms, ".")),
+        ])
     }
 
     /// Add an already explained incompat to the chain of explanations.
@@ -320,12 +318,11 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         let derived = self.format_terms(&derived.terms);
         let current = self.format_terms(current_terms);
 
-        format!(
-            "And because we know from ({}) that {}we can conclude that {}",
-            ref_id,
-            Padded::from_string("", &derived, ", "),
-            Padded::from_string("", &current, "."),
-        )
+        Message::AndExplainRef.format(&[
+            ("ref", &ref_id),
+            ("cause", &Padded::from_string("", &derived, ", ")),
+            ("conclusion", &Padded::from_string("", &current, ".")),
+        ])
     }
 
     /// Add an already explained incompat to the chain of explanations.
@@ -338,11 +335,10 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
         let external = self.format_both_external(prior_external, external);
         let terms = self.format_terms(current_terms);
 
-        format!(
-            "And because {}we can conclude that {}",
-            Padded::from_string("", &external, ", "),
-            Padded::from_string("", &terms, "."),
-        )
+        Message::AndExplainExternal.format(&[
+            ("cause", &Padded::from_string("", &external, ", ")),
+            ("conclusion", &Padded::from_string("", &terms, ".")),
+        ])
     }
 }
 
@@ -355,14 +351,16 @@ impl PubGrubReportFormatter<'_> {
         if self.is_workspace() {
             if matches!(&**package, PubGrubPackageInner::Root(_)) {
                 if self.is_single_project_workspace() {
-                    return Some("your project requires".to_string());
+                    return Some(Message::YourProjectRequires.text().to_string());
                 }
-                return Some("your workspace requires".to_string());
+                return Some(Message::YourWorkspaceRequires.text().to_string());
             }
         }
         match &**package {
-            PubGrubPackageInner::Root(Some(name)) => Some(format!("{name} depends on")),
-            PubGrubPackageInner::Root(None) => Some("you require".to_string()),
+            PubGrubPackageInner::Root(Some(name)) => {
+                Some(Message::RootDependsOn.format(&[("name", name)]))
+            }
+            PubGrubPackageInner::Root(None) => Some(Message::YouRequire.text().to_string()),
             _ => None,
         }
     }
@@ -375,14 +373,16 @@ impl PubGrubReportFormatter<'_> {
         if self.is_workspace() {
             if matches!(&**package, PubGrubPackageInner::Root(_)) {
                 if self.is_single_project_workspace() {
-                    return Some("your project's requirements".to_string());
+                    return Some(Message::YourProjectRequirements.text().to_string());
                 }
-                return Some("your workspace's requirements".to_string());
+                return Some(Message::YourWorkspaceRequirements.text().to_string());
             }
         }
         match &**package {
-            PubGrubPackageInner::Root(Some(_)) => Some("your requirements".to_string()),
-            PubGrubPackageInner::Root(None) => Some("your requirements".to_string()),
+            PubGrubPackageInner::Root(Some(_)) => {
+                Some(Message::YourRequirements.text().to_string())
+            }
+            PubGrubPackageInner::Root(None) => Some(Message::YourRequirements.text().to_string()),
             _ => None,
         }
     }
@@ -405,7 +405,7 @@ impl PubGrubReportFormatter<'_> {
                 name, extra, group, ..
             } if self.workspace_members.contains(name) => {
                 if self.is_single_project_workspace() && extra.is_none() && group.is_none() {
-                    Some("your project".to_string())
+                    Some(Message::YourProject.text().to_string())
                 } else {
                     Some(format!("{package}"))
                 }
@@ -481,8 +481,9 @@ impl PubGrubReportFormatter<'_> {
 
                 if let Some(root) = self.format_root_requires(package1) {
                     return format!(
-                        "{root} {}and {}",
+                        "{root} {}{} {}",
                         Padded::new("", &dependency1, " "),
+                        Message::And.text(),
                         dependency2,
                     );
                 }
@@ -511,8 +512,9 @@ impl PubGrubReportFormatter<'_> {
                 let external2 = self.format_external(external2);
 
                 format!(
-                    "{}and {}",
+                    "{}{} {}",
                     Padded::from_string("", &external1, " "),
+                    Message::And.text(),
                     &external2,
                 )
             }
@@ -1385,7 +1387,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Pre-releases are available for `{}` in the requested range (e.g., {}), but pre-releases weren't enabled (try: `{}`)",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     version.cyan(),
@@ -1397,7 +1399,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Only pre-releases of `{}` (e.g., {}) match these build requirements, and build environments can't enable pre-releases automatically. Add `{}` to `build-system.requires`, `[tool.uv.extra-build-dependencies]`, or supply it via `uv build --build-constraint`.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     version.cyan(),
@@ -1408,7 +1410,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} `{}` was requested with a pre-release marker (e.g., {}), but pre-releases weren't enabled (try: `{}`)",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     name.cyan(),
                     PackageRange::compatibility(&PubGrubPackage::base(name), range, None).cyan(),
@@ -1419,7 +1421,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} `{}` was requested with a pre-release marker (e.g., {}), but build environments can't opt into pre-releases automatically.  Add `{}` to `build-system.requires`, `[tool.uv.extra-build-dependencies]`, or supply it via `uv build --build-constraint`.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     name.cyan(),
                     PackageRange::compatibility(&PubGrubPackage::base(name), range, None).cyan(),
@@ -1430,7 +1432,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `{}`)",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     "--find-links <uri>".green(),
                 )
@@ -1439,7 +1441,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                 )
             }
@@ -1447,7 +1449,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Metadata for `{}` could not be parsed.\n{}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     textwrap::indent(reason.to_string().as_str(), "  ")
@@ -1457,7 +1459,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The structure of `{}` was invalid\n{}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     textwrap::indent(reason.to_string().as_str(), "  ")
@@ -1471,7 +1473,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Metadata for `{}` ({}) could not be parsed:\n{}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     format!("v{version}").cyan(),
@@ -1486,7 +1488,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The structure of `{}` ({}) was invalid:\n{}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     format!("v{version}").cyan(),
@@ -1501,7 +1503,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Metadata for `{}` ({}) was inconsistent:\n{}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     format!("v{version}").cyan(),
@@ -1518,7 +1520,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The `requires-python` value ({}) includes Python versions that are not supported by your dependencies (e.g., {} only supports {}). Consider using a more restrictive `requires-python` value (like {}).",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     requires_python.cyan(),
                     PackageRange::compatibility(&PubGrubPackage::base(name), package_set, None)
@@ -1537,7 +1539,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The `--python-version` value ({}) includes Python versions that are not supported by your dependencies (e.g., {} only supports {}). Consider using a higher `--python-version` value.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     requires_python.cyan(),
                     PackageRange::compatibility(&PubGrubPackage::base(name), package_set, None)
@@ -1555,7 +1557,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The Python interpreter uses a Python version that is not supported by your dependencies (e.g., {} only supports {}). Consider passing a `--python-version` value to raise the minimum supported version.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     PackageRange::compatibility(&PubGrubPackage::base(name), package_set, None)
                         .cyan(),
@@ -1571,7 +1573,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The source distribution for `{}` ({}) does not include static metadata. Generating metadata for this package requires Python {}, but Python {} is installed.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     format!("v{version}").cyan(),
@@ -1597,7 +1599,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The package `{}` depends on the package `{}` but the name is shadowed by {your_project}. Consider changing the name of {the_project}.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     dependency.cyan(),
@@ -1612,7 +1614,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} The {project} `{}` depends on itself at an incompatible version. This is likely a mistake. If you intended to depend on a third-party package named `{}`, consider renaming the {project} `{}` to avoid creating a conflict.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     package.cyan(),
@@ -1628,7 +1630,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} `{}` was found on {}, but not at the requested version ({}). A compatible version may be available on a subsequent index (e.g., {}). By default, uv will only consider versions that are published on the first index that contains a given package, to avoid dependency confusion attacks. If all indexes are equally trusted, use `{}` to consider all versions from all indexes, regardless of the order in which they were defined.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     name.cyan(),
                     found_index.without_credentials().cyan(),
@@ -1641,7 +1643,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} An index URL ({}) could not be queried due to a lack of valid authentication credentials ({}).",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     index.without_credentials().cyan(),
                     "401 Unauthorized".red(),
@@ -1651,7 +1653,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} An index URL ({}) returned a {} error. This could indicate lack of valid authentication credentials, or the package may not exist on this index.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     index.without_credentials().cyan(),
                     "403 Forbidden".red(),
@@ -1668,7 +1670,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Wheels are required for `{}` because building from source is disabled {option}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                 )
@@ -1684,7 +1686,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} A source distribution is required for `{}` because using pre-built wheels is disabled {option}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                 )
@@ -1705,7 +1707,7 @@ impl std::fmt::Display for PubGrubHint {
                     write!(
                         f,
                         "{}{} You require {}, but we only found wheels for `{}` ({}) with the following Python implementation tag{s}: {}",
-                        "hint".bold().cyan(),
+                        Message::Hint.text().bold().cyan(),
                         ":".bold(),
                         best,
                         package.cyan(),
@@ -1719,7 +1721,7 @@ impl std::fmt::Display for PubGrubHint {
                     write!(
                         f,
                         "{}{} Wheels are available for `{}` ({}) with the following Python implementation tag{s}: {}",
-                        "hint".bold().cyan(),
+                        Message::Hint.text().bold().cyan(),
                         ":".bold(),
                         package.cyan(),
                         format!("v{version}").cyan(),
@@ -1745,7 +1747,7 @@ impl std::fmt::Display for PubGrubHint {
                     write!(
                         f,
                         "{}{} You require {}, but we only found wheels for `{}` ({}) with the following Python ABI tag{s}: {}",
-                        "hint".bold().cyan(),
+                        Message::Hint.text().bold().cyan(),
                         ":".bold(),
                         best,
                         package.cyan(),
@@ -1759,7 +1761,7 @@ impl std::fmt::Display for PubGrubHint {
                     write!(
                         f,
                         "{}{} Wheels are available for `{}` ({}) with the following Python ABI tag{s}: {}",
-                        "hint".bold().cyan(),
+                        Message::Hint.text().bold().cyan(),
                         ":".bold(),
                         package.cyan(),
                         format!("v{version}").cyan(),
@@ -1778,7 +1780,7 @@ impl std::fmt::Display for PubGrubHint {
                 write!(
                     f,
                     "{}{} Wheels are available for `{}` ({}) on the following platform{s}: {}",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     package.cyan(),
                     format!("v{version}").cyan(),
@@ -1794,7 +1796,7 @@ impl std::fmt::Display for PubGrubHint {
                     the resolution failed for other Python versions supported by your \
                     project. Consider limiting your project's supported Python versions \
                     using `requires-python`.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                     python_version.cyan(),
                 )
@@ -1804,7 +1806,7 @@ impl std::fmt::Display for PubGrubHint {
                     f,
                     "{}{} The resolution failed for an environment that is not the current one, \
                     consider limiting the environments with `tool.uv.environments`.",
-                    "hint".bold().cyan(),
+                    Message::Hint.text().bold().cyan(),
                     ":".bold(),
                 )
             }
@@ -2174,8 +2176,9 @@ impl std::fmt::Display for DependsOn<'_> {
         match self.dependency2 {
             Some(ref dependency2) => write!(
                 f,
-                "{}and{}",
+                "{}{}{}",
                 Padded::new("", &self.dependency1, " "),
+                Message::And.text(),
                 Padded::new(" ", &dependency2, "")
             )?,
             None => write!(f, "{}", self.dependency1)?,
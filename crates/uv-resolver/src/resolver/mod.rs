@@ -341,6 +341,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             prefetcher,
         );
         let mut preferences = self.preferences.clone();
+
+        // Start fetching the versions of a previous resolution, which are likely to be selected
+        // again, before the solver reaches them.
+        if self.dependency_mode.is_transitive() {
+            self.prefetch_previous_resolution(request_sink)?;
+        }
+
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
 
@@ -1056,6 +1063,35 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 name.clone(),
                 range.clone(),
                 python_requirement.clone(),
+                PrefetchSource::Candidate,
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Prefetch the metadata for the versions pinned by a previous resolution.
+    ///
+    /// Without prefetching, each level of the dependency tree is only discovered once the metadata
+    /// of its parents is available, which results in a long tail of serial metadata fetches on
+    /// cold caches.
+    fn prefetch_previous_resolution(
+        &self,
+        request_sink: &Sender<Request>,
+    ) -> Result<(), ResolveError> {
+        for (name, version) in self.preferences.previous_resolution() {
+            // Packages that use a URL or an explicit index are requested once the solver reaches
+            // them, since we can't tell yet which source they use.
+            if self.urls.any_url(name) || self.indexes.contains_key(name) {
+                continue;
+            }
+            if self.index.implicit().register(name.clone()) {
+                request_sink.blocking_send(Request::Package(name.clone(), None))?;
+            }
+            request_sink.blocking_send(Request::Prefetch(
+                name.clone(),
+                Range::singleton(version.clone()),
+                self.python_requirement.clone(),
+                PrefetchSource::PreviousResolution,
             ))?;
         }
         Ok(())
@@ -2507,7 +2543,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
 
             // Pre-fetch the package and distribution metadata.
-            Request::Prefetch(package_name, range, python_requirement) => {
+            Request::Prefetch(package_name, range, python_requirement, source) => {
                 // Wait for the package metadata to become available.
                 let versions_response = self
                     .index
@@ -2604,6 +2640,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 // often leads to failed attempts to build legacy versions of packages that are
                 // incompatible with modern build tools.
                 if dist.wheel().is_none() {
+                    // A package from a previous resolution may no longer be required, so avoid
+                    // building it.
+                    if source == PrefetchSource::PreviousResolution {
+                        debug!(
+                            "Skipping prefetch for source distribution from previous resolution: {package_name} ({range})"
+                        );
+                        return Ok(None);
+                    }
                     if !self.selector.use_highest_version(&package_name, &env) {
                         if let Some((lower, _)) = range.iter().next() {
                             if lower == &Bound::Unbounded {
@@ -3523,7 +3567,21 @@ pub(crate) enum Request {
     /// A request to fetch the metadata from an already-installed distribution.
     Installed(InstalledDist),
     /// A request to pre-fetch the metadata for a package and the best-guess distribution.
-    Prefetch(PackageName, Range<Version>, PythonRequirement),
+    Prefetch(
+        PackageName,
+        Range<Version>,
+        PythonRequirement,
+        PrefetchSource,
+    ),
+}
+
+/// The reason for a [`Request::Prefetch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrefetchSource {
+    /// A candidate package of the current partial solution.
+    Candidate,
+    /// A version pinned by a previous resolution, e.g., in the lockfile.
+    PreviousResolution,
 }
 
 impl<'a> From<ResolvedDistRef<'a>> for Request {
@@ -3575,7 +3633,7 @@ impl Display for Request {
             Self::Installed(dist) => {
                 write!(f, "Installed metadata {dist}")
             }
-            Self::Prefetch(package_name, range, _) => {
+            Self::Prefetch(package_name, range, ..) => {
                 write!(f, "Prefetch {package_name} {range}")
             }
         }
@@ -169,7 +169,9 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
             .await;
 
         // If a package is pinned to an explicit index, ignore any `--find-links` entries.
-        let flat_index = index.is_none().then_some(&self.flat_index);
+        let flat_index = (index.is_none()
+            && !self.fetcher.client().unmanaged.is_pinned(package_name))
+        .then_some(&self.flat_index);
 
         match result {
             Ok(results) => Ok(VersionsResponse::Found(
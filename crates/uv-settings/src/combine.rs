@@ -4,12 +4,14 @@ use std::{collections::BTreeMap, num::NonZeroUsize};
 use url::Url;
 
 use uv_configuration::{
-    BuildIsolation, ExportFormat, IndexStrategy, KeyringProviderType, NoSources, ProxyUrl,
-    Reinstall, RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
+    BandwidthLimit, BuildFingerprint, BuildIsolation, CacheMaxAge, CacheMaxSize, CacheNamespace,
+    CacheNamespaceMode, EnvFile, ExportFormat, GitFetchStrategy, IndexStrategy,
+    KeyringProviderType, NoSources, ProxyUrl, Reinstall, RequiredVersion, TargetTriple,
+    TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildVariables, Index, IndexUrl, PackageConfigSettings, PipExtraIndex,
-    PipFindLinks, PipIndex,
+    ConfigSettings, ExtraBuildVariables, Index, IndexTrackingPolicy, IndexUrl,
+    PackageConfigSettings, PipExtraIndex, PipFindLinks, PipIndex, RetrySettings,
 };
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
@@ -23,7 +25,7 @@ use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::{FilesystemOptions, Options, PipOptions};
+use crate::{FilesystemOptions, Options, PipOptions, ProfileOptions};
 
 pub trait Combine {
     /// Combine two values, preferring the values in `self`.
@@ -84,12 +86,21 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(BandwidthLimit);
+impl_combine_or!(BuildFingerprint);
+impl_combine_or!(CacheMaxAge);
+impl_combine_or!(CacheMaxSize);
+impl_combine_or!(CacheNamespace);
+impl_combine_or!(CacheNamespaceMode);
+impl_combine_or!(EnvFile);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExcludeNewerValue);
 impl_combine_or!(ExportFormat);
 impl_combine_or!(ForkStrategy);
+impl_combine_or!(GitFetchStrategy);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
+impl_combine_or!(IndexTrackingPolicy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
@@ -144,6 +155,36 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
     }
 }
 
+impl Combine for ProfileOptions {
+    fn combine(self, other: Self) -> Self {
+        Self {
+            frozen: self.frozen.combine(other.frozen),
+            locked: self.locked.combine(other.locked),
+            no_progress: self.no_progress.combine(other.no_progress),
+            options: self.options.combine(other.options),
+        }
+    }
+}
+
+impl Combine for Option<BTreeMap<String, ProfileOptions>> {
+    /// Combine two maps of profiles, combining the settings of profiles with the same name.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (name, profile) in b {
+                    let profile = match a.remove(&name) {
+                        Some(existing) => existing.combine(profile),
+                        None => profile,
+                    };
+                    a.insert(name, profile);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ExcludeNewerPackage> {
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
@@ -182,6 +223,16 @@ impl Combine for Option<PackageConfigSettings> {
     }
 }
 
+impl Combine for Option<RetrySettings> {
+    /// Combine two retry policies key by key, with the values in `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<NoSources> {
     /// Combine two source strategies by using the `combine` method if they're both `Some`.
     fn combine(self, other: Self) -> Self {
@@ -3,16 +3,20 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use uv_configuration::BandwidthLimit;
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_flags::EnvironmentFlags;
 use uv_fs::Simplified;
+use uv_options_metadata::OptionsMetadata;
 use uv_static::{EnvVars, InvalidEnvironmentVariable, parse_boolish_environment_variable};
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
+pub use crate::policy::{Policy, PolicyOptions, init_policy, policy};
 pub use crate::settings::*;
 
 mod combine;
+mod policy;
 mod settings;
 
 /// The [`Options`] as loaded from a configuration file on disk.
@@ -24,6 +28,19 @@ impl FilesystemOptions {
     pub fn into_options(self) -> Options {
         self.0
     }
+
+    /// The settings that are locked by this configuration, i.e., that are ignored in any
+    /// lower-precedence configuration file.
+    pub fn locked_settings(&self) -> &[String] {
+        self.0.locked_settings.as_deref().unwrap_or_default()
+    }
+
+    /// Apply the profile with the given name on top of the [`FilesystemOptions`].
+    ///
+    /// Returns `None` if no such profile is defined.
+    pub fn with_profile(self, name: &str) -> Option<Self> {
+        self.0.with_profile(name).map(Self)
+    }
 }
 
 impl Deref for FilesystemOptions {
@@ -35,8 +52,8 @@ impl Deref for FilesystemOptions {
 }
 
 impl FilesystemOptions {
-    /// Load the user [`FilesystemOptions`].
-    pub fn user() -> Result<Option<Self>, Error> {
+    /// Load the user [`FilesystemOptions`], ignoring any of the `locked` settings.
+    pub fn user(locked: &[String]) -> Result<Option<Self>, Error> {
         let Some(dir) = user_config_dir() else {
             return Ok(None);
         };
@@ -44,10 +61,11 @@ impl FilesystemOptions {
         let file = root.join("uv.toml");
 
         tracing::debug!("Searching for user configuration in: `{}`", file.display());
-        match read_file(&file) {
+        match read_file(&file, locked) {
             Ok(options) => {
                 tracing::debug!("Found user configuration in: `{}`", file.display());
                 validate_uv_toml(&file, &options)?;
+                validate_non_system(&file, &options)?;
                 Ok(Some(Self(options)))
             }
             Err(Error::Io(err))
@@ -64,24 +82,67 @@ impl FilesystemOptions {
         }
     }
 
+    /// Load the system [`FilesystemOptions`].
     pub fn system() -> Result<Option<Self>, Error> {
         let Some(file) = system_config_file() else {
             return Ok(None);
         };
 
         tracing::debug!("Found system configuration in: `{}`", file.display());
-        let options = read_file(&file)?;
+        let options = read_file(&file, &[])?;
+        validate_uv_toml(&file, &options)?;
+        validate_locked_settings(&file, &options)?;
+        Ok(Some(Self(options)))
+    }
+
+    /// Load the settings that are locked by the system [`FilesystemOptions`], ignoring any
+    /// settings that aren't locked.
+    ///
+    /// Locked settings are applied even if the user and project configuration are bypassed, e.g.,
+    /// with `--no-config` or `--config-file`.
+    pub fn system_locked() -> Result<Option<Self>, Error> {
+        let Some(file) = system_config_file() else {
+            return Ok(None);
+        };
+
+        tracing::debug!("Found system configuration in: `{}`", file.display());
+        let content = fs_err::read_to_string(&file)?;
+        let options = parse_uv_toml(&file, &content, &[])?;
         validate_uv_toml(&file, &options)?;
+        validate_locked_settings(&file, &options)?;
+
+        let mut table = toml::from_str::<toml::Table>(&content)
+            .map_err(|err| Error::UvToml(file.clone(), Box::new(err)))?;
+        let mut retained = toml::Table::new();
+        for key in options
+            .locked_settings
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .chain(["locked-settings"])
+        {
+            if let Some(value) = take_dotted_key(&mut table, key) {
+                insert_dotted_key(&mut retained, key, value);
+            }
+        }
+        let options = toml::Value::Table(retained)
+            .try_into::<Options>()
+            .map_err(|err| Error::UvToml(file.clone(), Box::new(err)))?;
+        let options = if let Some(parent) = std::path::absolute(&file)?.parent() {
+            options.relative_to(parent)?
+        } else {
+            options
+        };
         Ok(Some(Self(options)))
     }
 
     /// Find the [`FilesystemOptions`] for the given path.
     ///
     /// The search starts at the given path and goes up the directory tree until a `uv.toml` file or
-    /// `pyproject.toml` file is found.
-    pub fn find(path: &Path) -> Result<Option<Self>, Error> {
+    /// `pyproject.toml` file is found. Any of the `locked` settings are ignored.
+    pub fn find(path: &Path, locked: &[String]) -> Result<Option<Self>, Error> {
         for ancestor in path.ancestors() {
-            match Self::from_directory(ancestor) {
+            match Self::from_directory(ancestor, locked) {
                 Ok(Some(options)) => {
                     return Ok(Some(options));
                 }
@@ -106,14 +167,13 @@ impl FilesystemOptions {
     }
 
     /// Load a [`FilesystemOptions`] from a directory, preferring a `uv.toml` file over a
-    /// `pyproject.toml` file.
-    pub fn from_directory(dir: &Path) -> Result<Option<Self>, Error> {
+    /// `pyproject.toml` file. Any of the `locked` settings are ignored.
+    pub fn from_directory(dir: &Path, locked: &[String]) -> Result<Option<Self>, Error> {
         // Read a `uv.toml` file in the current directory.
         let path = dir.join("uv.toml");
         match fs_err::read_to_string(&path) {
             Ok(content) => {
-                let options = toml::from_str::<Options>(&content)
-                    .map_err(|err| Error::UvToml(path.clone(), Box::new(err)))?
+                let options = parse_uv_toml(&path, &content, locked)?
                     .relative_to(&std::path::absolute(dir)?)?;
 
                 // If the directory also contains a `[tool.uv]` table in a `pyproject.toml` file,
@@ -131,6 +191,7 @@ impl FilesystemOptions {
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 validate_uv_toml(&path, &options)?;
+                validate_non_system(&path, &options)?;
                 return Ok(Some(Self(options)));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -159,6 +220,31 @@ impl FilesystemOptions {
                     return Ok(None);
                 };
 
+                validate_non_system(&path, &options)?;
+
+                // Ignore any settings that are locked by the system configuration.
+                let options = if locked.is_empty() {
+                    options
+                } else {
+                    let mut table = toml::from_str::<toml::Table>(&content)
+                        .map_err(|err| Error::PyprojectToml(path.clone(), Box::new(err)))?;
+                    match table
+                        .get_mut("tool")
+                        .and_then(toml::Value::as_table_mut)
+                        .and_then(|tool| tool.remove("uv"))
+                    {
+                        Some(toml::Value::Table(uv)) => {
+                            match without_locked_settings(&path, uv, locked) {
+                                Some(options) => options.map_err(|err| {
+                                    Error::PyprojectToml(path.clone(), Box::new(err))
+                                })?,
+                                None => options,
+                            }
+                        }
+                        _ => options,
+                    }
+                };
+
                 let options = options.relative_to(&std::path::absolute(dir)?)?;
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
@@ -171,28 +257,63 @@ impl FilesystemOptions {
         Ok(None)
     }
 
-    /// Load a [`FilesystemOptions`] from a `uv.toml` file.
-    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+    /// Load a [`FilesystemOptions`] from a `uv.toml` file, ignoring any of the `locked` settings.
+    pub fn from_file(path: impl AsRef<Path>, locked: &[String]) -> Result<Self, Error> {
         let path = path.as_ref();
         tracing::debug!("Reading user configuration from: `{}`", path.display());
 
-        let options = read_file(path)?;
+        let options = read_file(path, locked)?;
         validate_uv_toml(path, &options)?;
+        validate_non_system(path, &options)?;
         Ok(Self(options))
     }
 }
 
+/// The level of a configuration file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigLevel {
+    /// A project-level `uv.toml` or `pyproject.toml` file.
+    Project,
+    /// The user-level `uv.toml` file.
+    User,
+    /// The system-level `uv.toml` file.
+    System,
+}
+
+/// Validate the contents of a configuration file at the given level, e.g., prior to writing them
+/// to disk.
+pub fn validate_config(path: &Path, content: &str, level: ConfigLevel) -> Result<(), Error> {
+    if path
+        .file_name()
+        .is_some_and(|name| name == "pyproject.toml")
+    {
+        let pyproject = toml::from_str::<PyProjectToml>(content)
+            .map_err(|err| Error::PyprojectToml(path.to_path_buf(), Box::new(err)))?;
+        if let Some(options) = pyproject.tool.and_then(|tool| tool.uv) {
+            validate_non_system(path, &options)?;
+        }
+        return Ok(());
+    }
+    let options = parse_uv_toml(path, content, &[])?;
+    validate_uv_toml(path, &options)?;
+    if level == ConfigLevel::System {
+        validate_locked_settings(path, &options)?;
+    } else {
+        validate_non_system(path, &options)?;
+    }
+    Ok(())
+}
+
 impl From<Options> for FilesystemOptions {
     fn from(options: Options) -> Self {
         Self(options)
     }
 }
 
-/// Load [`Options`] from a `uv.toml` file.
-fn read_file(path: &Path) -> Result<Options, Error> {
+/// Load [`Options`] from a `uv.toml` file, ignoring any of the `locked` settings.
+fn read_file(path: &Path, locked: &[String]) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
-    let options = toml::from_str::<Options>(&content)
-        .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+    let options = parse_uv_toml(path, &content, locked)?;
     let options = if let Some(parent) = std::path::absolute(path)?.parent() {
         options.relative_to(parent)?
     } else {
@@ -201,6 +322,130 @@ fn read_file(path: &Path) -> Result<Options, Error> {
     Ok(options)
 }
 
+/// Parse the [`Options`] in a `uv.toml` file, ignoring any of the `locked` settings.
+fn parse_uv_toml(path: &Path, content: &str, locked: &[String]) -> Result<Options, Error> {
+    let options = toml::from_str::<Options>(content)
+        .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+    if locked.is_empty() {
+        return Ok(options);
+    }
+    let table = toml::from_str::<toml::Table>(content)
+        .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+    match without_locked_settings(path, table, locked) {
+        Some(options) => options.map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err))),
+        None => Ok(options),
+    }
+}
+
+/// Remove the `locked` settings from a table of [`Options`], warning for each setting that is
+/// ignored.
+///
+/// Returns `None` if the table doesn't contain any of the `locked` settings. Otherwise, returns
+/// the [`Options`] parsed from the remaining table.
+///
+/// The settings are removed prior to deserialization (rather than from the parsed [`Options`]),
+/// since a locked setting should not be combined with the value in the system configuration.
+fn without_locked_settings(
+    path: &Path,
+    mut table: toml::Table,
+    locked: &[String],
+) -> Option<Result<Options, toml::de::Error>> {
+    let mut removed = false;
+    for key in locked {
+        if remove_dotted_key(&mut table, key) {
+            warn_user!(
+                "Ignoring `{}` in `{}`, since the setting is locked by the system configuration",
+                key.cyan(),
+                path.user_display().cyan()
+            );
+            removed = true;
+        }
+
+        // Locked settings can't be set in a profile, either.
+        let profiles = table
+            .get_mut("profiles")
+            .and_then(toml::Value::as_table_mut)
+            .into_iter()
+            .flat_map(|profiles| profiles.iter_mut());
+        for (name, profile) in profiles {
+            if profile
+                .as_table_mut()
+                .is_some_and(|profile| remove_dotted_key(profile, key))
+            {
+                warn_user!(
+                    "Ignoring `{}` in `{}`, since the setting is locked by the system configuration",
+                    format!("profiles.{name}.{key}").cyan(),
+                    path.user_display().cyan()
+                );
+                removed = true;
+            }
+        }
+    }
+    removed.then(|| toml::Value::Table(table).try_into::<Options>())
+}
+
+/// Remove a dotted key (e.g., `pip.index-url`) from a table, returning `true` if it was present.
+fn remove_dotted_key(table: &mut toml::Table, key: &str) -> bool {
+    match key.split_once('.') {
+        Some((head, rest)) => table
+            .get_mut(head)
+            .and_then(toml::Value::as_table_mut)
+            .is_some_and(|table| remove_dotted_key(table, rest)),
+        None => table.remove(key).is_some(),
+    }
+}
+
+/// Remove a dotted key (e.g., `pip.index-url`) from a table, returning its value if it was present.
+fn take_dotted_key(table: &mut toml::Table, key: &str) -> Option<toml::Value> {
+    match key.split_once('.') {
+        Some((head, rest)) => table
+            .get_mut(head)
+            .and_then(toml::Value::as_table_mut)
+            .and_then(|table| take_dotted_key(table, rest)),
+        None => table.remove(key),
+    }
+}
+
+/// Insert a value at a dotted key (e.g., `pip.index-url`), creating any intermediate tables.
+fn insert_dotted_key(table: &mut toml::Table, key: &str, value: toml::Value) {
+    match key.split_once('.') {
+        Some((head, rest)) => {
+            let entry = table
+                .entry(head)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let Some(table) = entry.as_table_mut() {
+                insert_dotted_key(table, rest, value);
+            }
+        }
+        None => {
+            table.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// Validate that an [`Options`] schema from a file other than the system configuration doesn't
+/// include any system-only fields.
+fn validate_non_system(path: &Path, options: &Options) -> Result<(), Error> {
+    if options.locked_settings.is_some() {
+        return Err(Error::SystemOnlyField(
+            path.to_path_buf(),
+            "locked-settings",
+        ));
+    }
+    Ok(())
+}
+
+/// Validate that the `locked-settings` of the system configuration are known settings.
+fn validate_locked_settings(path: &Path, options: &Options) -> Result<(), Error> {
+    let metadata = Options::metadata();
+    for key in options.locked_settings.iter().flatten() {
+        if metadata.find(key).is_none() {
+            return Err(Error::UnknownLockedSetting(path.to_path_buf(), key.clone()));
+        }
+    }
+    Ok(())
+}
+
 /// Validate that an [`Options`] schema is compatible with `uv.toml`.
 fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     let Options {
@@ -211,6 +456,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         add: _,
         pip: _,
         cache_keys: _,
+        env_file: _,
+        locked_settings: _,
+        profiles: _,
+        frozen: _,
+        locked: _,
+        no_progress: _,
         override_dependencies: _,
         exclude_dependencies: _,
         constraint_dependencies: _,
@@ -226,6 +477,8 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         managed,
         package,
         build_backend,
+        hooks,
+        sbom,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
     // permitted by the schema since they _can_ be included in `pyproject.toml` files
@@ -269,6 +522,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "build-backend",
         ));
     }
+    if hooks.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "hooks"));
+    }
+    if sbom.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "sbom"));
+    }
     if environments.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -296,12 +555,24 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_age,
+                cache_max_size,
+                cache_namespace,
+                cache_namespace_mode,
+                remote_cache,
+                git_fetch_strategy,
+                git_reference_repositories,
+                git_shared_cache_dir,
+                build_fingerprint,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_downloads_per_host,
+                bandwidth_limit,
+                retry,
                 allow_insecure_host,
                 http_proxy,
                 https_proxy,
@@ -315,6 +586,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 no_index,
                 find_links,
                 index_strategy,
+                index_tracking_policy,
                 keyring_provider,
                 resolution,
                 prerelease,
@@ -357,6 +629,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         add: AddOptions { add_bounds },
         pip,
         cache_keys,
+        env_file,
+        locked_settings: _,
+        profiles,
+        frozen: _,
+        locked: _,
+        no_progress: _,
         override_dependencies,
         exclude_dependencies,
         constraint_dependencies,
@@ -372,6 +650,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         managed: _,
         package: _,
         build_backend: _,
+        hooks: _,
+        sbom: _,
     } = options;
 
     let mut masked_fields = vec![];
@@ -391,6 +671,33 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if cache_max_age.is_some() {
+        masked_fields.push("cache-max-age");
+    }
+    if cache_max_size.is_some() {
+        masked_fields.push("cache-max-size");
+    }
+    if cache_namespace.is_some() {
+        masked_fields.push("cache-namespace");
+    }
+    if cache_namespace_mode.is_some() {
+        masked_fields.push("cache-namespace-mode");
+    }
+    if remote_cache.is_some() {
+        masked_fields.push("remote-cache");
+    }
+    if git_fetch_strategy.is_some() {
+        masked_fields.push("git-fetch-strategy");
+    }
+    if git_reference_repositories.is_some() {
+        masked_fields.push("git-reference-repositories");
+    }
+    if git_shared_cache_dir.is_some() {
+        masked_fields.push("git-shared-cache-dir");
+    }
+    if build_fingerprint.is_some() {
+        masked_fields.push("build-fingerprint");
+    }
     if preview.is_some() {
         masked_fields.push("preview");
     }
@@ -409,6 +716,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if concurrent_installs.is_some() {
         masked_fields.push("concurrent-installs");
     }
+    if concurrent_downloads_per_host.is_some() {
+        masked_fields.push("concurrent-downloads-per-host");
+    }
+    if bandwidth_limit.is_some() {
+        masked_fields.push("bandwidth-limit");
+    }
+    if retry.is_some() {
+        masked_fields.push("retry");
+    }
     if allow_insecure_host.is_some() {
         masked_fields.push("allow-insecure-host");
     }
@@ -439,6 +755,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if index_strategy.is_some() {
         masked_fields.push("index-strategy");
     }
+    if index_tracking_policy.is_some() {
+        masked_fields.push("index-tracking-policy");
+    }
     if keyring_provider.is_some() {
         masked_fields.push("keyring-provider");
     }
@@ -541,6 +860,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
+    if env_file.is_some() {
+        masked_fields.push("env-file");
+    }
+    if profiles.is_some() {
+        masked_fields.push("profiles");
+    }
     if override_dependencies.is_some() {
         masked_fields.push("override-dependencies");
     }
@@ -576,10 +901,19 @@ pub enum Error {
     #[error("Failed to parse: `{}`", _0.user_display())]
     UvToml(PathBuf, #[source] Box<toml::de::Error>),
 
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    PolicyToml(PathBuf, #[source] Box<toml::de::Error>),
+
     #[error("Failed to parse: `{}`. The `{}` field is not allowed in a `uv.toml` file. `{}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display(), _1, _1
     )]
     PyprojectOnlyField(PathBuf, &'static str),
 
+    #[error("Failed to parse: `{}`. The `{}` field is only allowed in the system-level `uv.toml` file.", _0.user_display(), _1)]
+    SystemOnlyField(PathBuf, &'static str),
+
+    #[error("Failed to parse: `{}`. Unknown setting in `locked-settings`: `{}`", _0.user_display(), _1)]
+    UnknownLockedSetting(PathBuf, String),
+
     #[error(transparent)]
     InvalidEnvironmentVariable(#[from] InvalidEnvironmentVariable),
 }
@@ -589,6 +923,7 @@ pub struct Concurrency {
     pub downloads: Option<NonZeroUsize>,
     pub builds: Option<NonZeroUsize>,
     pub installs: Option<NonZeroUsize>,
+    pub downloads_per_host: Option<NonZeroUsize>,
 }
 
 /// A boolean flag parsed from an environment variable.
@@ -610,6 +945,33 @@ impl EnvFlag {
     }
 }
 
+/// The environment variables that are read outside of the command-line interface and override a
+/// setting, along with the name of the setting that each overrides.
+pub const ENVIRONMENT_SETTINGS: &[(&str, &str)] = &[
+    (EnvVars::UV_CONCURRENT_DOWNLOADS, "concurrent-downloads"),
+    (EnvVars::UV_CONCURRENT_BUILDS, "concurrent-builds"),
+    (EnvVars::UV_CONCURRENT_INSTALLS, "concurrent-installs"),
+    (
+        EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+        "concurrent-downloads-per-host",
+    ),
+    (EnvVars::UV_BANDWIDTH_LIMIT, "bandwidth-limit"),
+    (EnvVars::UV_PYTHON_INSTALL_MIRROR, "python-install-mirror"),
+    (EnvVars::UV_PYPY_INSTALL_MIRROR, "pypy-install-mirror"),
+    (
+        EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL,
+        "python-downloads-json-url",
+    ),
+    (EnvVars::UV_FROZEN, "frozen"),
+    (EnvVars::UV_LOCKED, "locked"),
+    (EnvVars::UV_OFFLINE, "offline"),
+    (EnvVars::UV_NATIVE_TLS, "native-tls"),
+    (EnvVars::UV_NO_PROGRESS, "no-progress"),
+    (EnvVars::UV_MANAGED_PYTHON, "python-preference"),
+    (EnvVars::UV_NO_MANAGED_PYTHON, "python-preference"),
+    (EnvVars::UV_PYTHON_DOWNLOADS, "python-downloads"),
+];
+
 /// Options loaded from environment variables.
 ///
 /// This is currently a subset of all respected environment variables, most are parsed via Clap at
@@ -624,11 +986,14 @@ pub struct EnvironmentOptions {
     pub log_context: Option<bool>,
     pub lfs: Option<bool>,
     pub http_timeout: Duration,
-    pub http_retries: u32,
+    pub http_retries: Option<u32>,
     pub upload_http_timeout: Duration,
     pub concurrency: Concurrency,
+    pub bandwidth_limit: Option<BandwidthLimit>,
     #[cfg(feature = "tracing-durations-export")]
     pub tracing_durations_file: Option<PathBuf>,
+    #[cfg(feature = "opentelemetry")]
+    pub otel_endpoint: Option<String>,
     pub frozen: EnvFlag,
     pub locked: EnvFlag,
     pub offline: EnvFlag,
@@ -674,7 +1039,11 @@ impl EnvironmentOptions {
                 downloads: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_DOWNLOADS)?,
                 builds: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_BUILDS)?,
                 installs: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_INSTALLS)?,
+                downloads_per_host: parse_integer_environment_variable(
+                    EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+                )?,
             },
+            bandwidth_limit: parse_integer_environment_variable(EnvVars::UV_BANDWIDTH_LIMIT)?,
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: parse_string_environment_variable(
                     EnvVars::UV_PYTHON_INSTALL_MIRROR,
@@ -695,12 +1064,13 @@ impl EnvironmentOptions {
             .or(http_timeout)
             .unwrap_or(Duration::from_mins(15)),
             http_timeout: http_timeout.unwrap_or(Duration::from_secs(30)),
-            http_retries: parse_integer_environment_variable(EnvVars::UV_HTTP_RETRIES)?
-                .unwrap_or(uv_client::DEFAULT_RETRIES),
+            http_retries: parse_integer_environment_variable(EnvVars::UV_HTTP_RETRIES)?,
             #[cfg(feature = "tracing-durations-export")]
             tracing_durations_file: parse_path_environment_variable(
                 EnvVars::TRACING_DURATIONS_FILE,
             ),
+            #[cfg(feature = "opentelemetry")]
+            otel_endpoint: parse_string_environment_variable(EnvVars::UV_OTEL_ENDPOINT)?,
             frozen: EnvFlag::new(EnvVars::UV_FROZEN)?,
             locked: EnvFlag::new(EnvVars::UV_LOCKED)?,
             offline: EnvFlag::new(EnvVars::UV_OFFLINE)?,
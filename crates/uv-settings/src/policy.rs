@@ -0,0 +1,70 @@
+//! Organization policies, read from a `policy.toml` file in the system configuration directory.
+//!
+//! Unlike configuration files, a policy can't be bypassed with `--no-config` or `--config-file`,
+//! and its settings take precedence over the command line.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use uv_configuration::RequiredVersion;
+use uv_dirs::system_policy_file;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::Error;
+
+/// The [`Policy`] enforced for the current process, if any.
+static POLICY: OnceLock<Policy> = OnceLock::new();
+
+/// An organization policy, as read from a `policy.toml` file.
+#[derive(Debug, Clone)]
+pub struct Policy {
+    /// The path to the `policy.toml` file.
+    pub path: PathBuf,
+    /// The settings enforced by the policy.
+    pub options: PolicyOptions,
+}
+
+/// The settings enforced by an organization [`Policy`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PolicyOptions {
+    /// The indexes that uv is allowed to read from, including `--find-links` locations.
+    ///
+    /// An index is allowed if its URL is equal to, or nested under, one of the given URLs.
+    pub allowed_indexes: Option<Vec<DisplaySafeUrl>>,
+    /// Require a matching hash for every registry and direct URL distribution that is installed.
+    #[serde(default)]
+    pub require_hashes: bool,
+    /// Disallow building any source distribution, i.e., only install pre-built wheels.
+    #[serde(default)]
+    pub no_build: bool,
+    /// The version of uv that is allowed to run, e.g., `>=0.9.0`.
+    pub required_version: Option<RequiredVersion>,
+}
+
+impl Policy {
+    /// Load the system [`Policy`], if a `policy.toml` file exists.
+    pub fn system() -> Result<Option<Self>, Error> {
+        let Some(path) = system_policy_file() else {
+            return Ok(None);
+        };
+
+        tracing::debug!("Found organization policy in: `{}`", path.display());
+        let content = fs_err::read_to_string(&path)?;
+        let options = toml::from_str::<PolicyOptions>(&content)
+            .map_err(|err| Error::PolicyToml(path.clone(), Box::new(err)))?;
+        Ok(Some(Self { path, options }))
+    }
+}
+
+/// Enforce the given [`Policy`] for the current process.
+pub fn init_policy(policy: Policy) -> Result<(), Policy> {
+    POLICY.set(policy)
+}
+
+/// The [`Policy`] enforced for the current process, if any.
+pub fn policy() -> Option<&'static Policy> {
+    POLICY.get()
+}
@@ -1,15 +1,17 @@
-use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, IndexStrategy, KeyringProviderType, PackageNameSpecifier, ProxyUrl, Reinstall,
-    RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    BandwidthLimit, BuildFingerprint, BuildIsolation, CacheMaxAge, CacheMaxSize, CacheNamespace,
+    CacheNamespaceMode, EnvFile, GitFetchStrategy, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, PackageConfigSettings,
-    PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
+    ConfigSettings, ExtraBuildVariables, Index, IndexTrackingPolicy, IndexUrl, IndexUrlError,
+    PackageConfigSettings, PipExtraIndex, PipFindLinks, PipIndex, RetrySettings, StaticMetadata,
 };
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
@@ -26,6 +28,8 @@ use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
+use crate::Combine;
+
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -76,6 +80,10 @@ pub struct Options {
     /// cache-keys = [{ file = "pyproject.toml" }, { file = "setup.py" }, { file = "setup.cfg" }, { dir = "src" }]
     /// ```
     ///
+    /// For projects built with `maturin`, `meson-python`, or `scikit-build-core`, the defaults also
+    /// include the backend's native sources (e.g., `Cargo.toml`, `Cargo.lock`, and `src/**/*.rs`
+    /// for `maturin`).
+    ///
     /// As an example: if a project uses dynamic metadata to read its dependencies from a
     /// `requirements.txt` file, you can specify `cache-keys = [{ file = "requirements.txt" }, { file = "pyproject.toml" }]`
     /// to ensure that the project is rebuilt whenever the `requirements.txt` file is modified (in
@@ -109,6 +117,80 @@ pub struct Options {
     )]
     pub cache_keys: Option<Vec<CacheKey>>,
 
+    /// Load environment variables from `.env` files when executing commands with `uv run`.
+    ///
+    /// Paths are resolved relative to the directory containing the configuration file. Files are
+    /// loaded in order, with subsequent files overriding values defined in previous files, and
+    /// variables that are already set in the environment take precedence over any file.
+    ///
+    /// When set in both the project and user configuration, the project value replaces the user
+    /// value. The `--env-file` command-line argument and `UV_ENV_FILE` environment variable
+    /// replace this setting, while `--no-env-file` and `UV_NO_ENV_FILE` disable it.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            env-file = [".env", ".env.local"]
+        "#
+    )]
+    pub env_file: Option<EnvFile>,
+
+    /// Settings that can only be set in the system-level configuration file.
+    ///
+    /// Locked settings are ignored, with a warning, in project and user configuration files, such
+    /// that the value in the system-level `uv.toml` applies to every project on the machine, e.g.,
+    /// to require the use of an internal package index on managed developer machines. Nested
+    /// settings are referenced by their dotted name, e.g., `pip.index-url`.
+    ///
+    /// Locked settings also apply when the user and project configuration are bypassed with
+    /// `--no-config` or `--config-file`, and uv exits with an error if a locked setting is
+    /// overridden by a command-line argument or environment variable.
+    ///
+    /// Only allowed in the system-level `uv.toml` file.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        uv_toml_only = true,
+        example = r#"
+            locked-settings = ["index", "index-url", "keyring-provider"]
+        "#
+    )]
+    pub locked_settings: Option<Vec<String>>,
+
+    /// Named profiles of settings, to apply on top of the other settings when selected with
+    /// `--profile` or `UV_PROFILE`.
+    ///
+    /// A profile accepts any setting, along with the `frozen`, `locked`, and `no-progress` flags,
+    /// which are otherwise only available on the command line. For example, a `ci` profile could
+    /// avoid updating the lockfile and disable progress output in CI, while keeping local defaults
+    /// unchanged.
+    ///
+    /// The settings in a selected profile take precedence over the other settings in all
+    /// configuration files, but not over command-line arguments or environment variables. Profiles
+    /// with the same name in the project, user, and system configuration are merged.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, dict]",
+        example = r#"
+            [profiles.ci]
+            frozen = true
+            no-progress = true
+            compile-bytecode = true
+        "#
+    )]
+    pub profiles: Option<BTreeMap<String, ProfileOptions>>,
+
+    // NOTE: These fields can only be set within a profile, and are populated when the profile is
+    // applied via [`Options::with_profile`].
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub frozen: Option<bool>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub locked: Option<bool>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub no_progress: Option<bool>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -159,6 +241,12 @@ pub struct Options {
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub build_backend: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub hooks: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub sbom: Option<serde::de::IgnoredAny>,
 }
 
 impl Options {
@@ -176,11 +264,131 @@ impl Options {
         Ok(Self {
             top_level: self.top_level.relative_to(root_dir)?,
             pip: self.pip.map(|pip| pip.relative_to(root_dir)).transpose()?,
+            env_file: self.env_file.map(|env_file| env_file.relative_to(root_dir)),
+            profiles: self
+                .profiles
+                .map(|profiles| {
+                    profiles
+                        .into_iter()
+                        .map(|(name, profile)| Ok((name, profile.relative_to(root_dir)?)))
+                        .collect::<Result<_, IndexUrlError>>()
+                })
+                .transpose()?,
+            ..self
+        })
+    }
+
+    /// Apply the profile with the given name on top of the [`Options`].
+    ///
+    /// Returns `None` if no such profile is defined.
+    pub fn with_profile(mut self, name: &str) -> Option<Self> {
+        let profile = self.profiles.as_mut()?.remove(name)?;
+        let ProfileOptions {
+            frozen,
+            locked,
+            no_progress,
+            options,
+        } = profile;
+        Some(
+            Self {
+                frozen,
+                locked,
+                no_progress,
+                // Profiles can't be nested.
+                profiles: None,
+                ..options
+            }
+            .combine(Self {
+                profiles: None,
+                ..self
+            }),
+        )
+    }
+}
+
+/// A named profile of settings, i.e., an entry in the `profiles` table.
+///
+/// In addition to the settings accepted at the top level, a profile can enable the `frozen`,
+/// `locked`, and `no-progress` flags.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileOptions {
+    pub frozen: Option<bool>,
+    pub locked: Option<bool>,
+    pub no_progress: Option<bool>,
+    pub options: Options,
+}
+
+impl ProfileOptions {
+    /// Resolve the [`ProfileOptions`] relative to the given root directory.
+    fn relative_to(self, root_dir: &Path) -> Result<Self, IndexUrlError> {
+        Ok(Self {
+            options: self.options.relative_to(root_dir)?,
             ..self
         })
     }
 }
 
+impl<'de> Deserialize<'de> for ProfileOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        // Extract the profile-only flags, and deserialize the remaining settings as [`Options`].
+        let mut table = toml::Table::deserialize(deserializer)?;
+        let mut flag = |key: &str| {
+            table
+                .remove(key)
+                .map(|value| {
+                    value.as_bool().ok_or_else(|| {
+                        D::Error::custom(format!(
+                            "invalid type for `{key}`: {}, expected a boolean",
+                            value.type_str()
+                        ))
+                    })
+                })
+                .transpose()
+        };
+        let frozen = flag("frozen")?;
+        let locked = flag("locked")?;
+        let no_progress = flag("no-progress")?;
+        let options = Options::deserialize(toml::Value::Table(table)).map_err(D::Error::custom)?;
+
+        Ok(Self {
+            frozen,
+            locked,
+            no_progress,
+            options,
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ProfileOptions {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("ProfileOptions")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "description": "A named profile of settings. Accepts any setting, along with the `frozen`, `locked`, and `no-progress` flags.",
+            "properties": {
+                "frozen": {
+                    "type": "boolean",
+                    "description": "Run without updating the `uv.lock` file, as in `--frozen`."
+                },
+                "locked": {
+                    "type": "boolean",
+                    "description": "Assert that the `uv.lock` will remain unchanged, as in `--locked`."
+                },
+                "no-progress": {
+                    "type": "boolean",
+                    "description": "Hide all progress outputs, as in `--no-progress`."
+                }
+            }
+        })
+    }
+}
+
 /// Global settings, relevant to all invocations.
 #[derive(Debug, Clone, Default, Deserialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
@@ -250,6 +458,158 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The maximum age of unused cache entries (e.g., `30d`).
+    ///
+    /// When set, uv will periodically (at most once per day) evict any unzipped wheels and Python
+    /// downloads that haven't been used within the given duration. Durations support the `s`,
+    /// `m`, `h`, `d`, and `w` suffixes.
+    ///
+    /// Use `uv cache prune --max-age` to apply an age limit on demand.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            cache-max-age = "30d"
+        "#
+    )]
+    pub cache_max_age: Option<CacheMaxAge>,
+    /// The maximum size of the cache (e.g., `10GB`).
+    ///
+    /// When set, uv will periodically (at most once per day) evict the least-recently-used
+    /// unzipped wheels and Python downloads until the cache fits within the given size. Sizes
+    /// support both decimal (e.g., `GB`) and binary (e.g., `GiB`) units.
+    ///
+    /// Use `uv cache prune --max-size` to apply a size limit on demand.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            cache-max-size = "10GB"
+        "#
+    )]
+    pub cache_max_size: Option<CacheMaxSize>,
+    /// An isolated namespace within the cache (e.g., `experiments`).
+    ///
+    /// When set, uv will read from and write to a separate cache under the `namespaces`
+    /// directory of the cache root, rather than the shared cache. This is useful for projects
+    /// that experiment with (e.g.) custom indexes or build settings, to avoid populating the
+    /// shared cache with artifacts that other projects shouldn't reuse.
+    ///
+    /// To read entries from the shared cache while only writing to the namespace, set
+    /// `cache-namespace-mode = "overlay"`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            cache-namespace = "experiments"
+        "#
+    )]
+    pub cache_namespace: Option<CacheNamespace>,
+    /// How the [`cache-namespace`](#cache-namespace) relates to the shared cache.
+    ///
+    /// In `isolated` mode (the default), the namespace is neither read from nor written to the
+    /// shared cache. In `overlay` mode, entries that are missing from the namespace are read from
+    /// the shared cache (e.g., previously downloaded wheels), but new entries are only written to
+    /// the namespace, such that the shared cache is never modified.
+    #[option(
+        default = "\"isolated\"",
+        value_type = "str",
+        example = r#"
+            cache-namespace = "experiments"
+            cache-namespace-mode = "overlay"
+        "#,
+        possible_values = true
+    )]
+    pub cache_namespace_mode: Option<CacheNamespaceMode>,
+    /// The URL of a remote cache to share built and downloaded wheels across machines.
+    ///
+    /// Before building a source distribution, uv will check the remote cache for a wheel built
+    /// from the same source distribution, with the same build settings, for the same
+    /// interpreter and platform. After building a source distribution, uv will upload the
+    /// resulting wheel to the remote cache. Similarly, wheels downloaded from an index are
+    /// shared via the remote cache, keyed by their SHA-256 digest.
+    ///
+    /// The remote cache can be any HTTP server that supports `GET` and `PUT` requests, or an S3
+    /// bucket (e.g., `s3://bucket/prefix`).
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            remote-cache = "https://cache.example.com/uv"
+        "#
+    )]
+    pub remote_cache: Option<DisplaySafeUrl>,
+    /// The strategy to use when fetching Git repositories.
+    ///
+    /// By default, uv fetches the full history of the requested Git references. With `shallow`,
+    /// uv fetches only the requested commit, which can dramatically reduce fetch times for large
+    /// repositories. When a reference can't be resolved without the full set of branches and tags
+    /// (e.g., an abbreviated commit hash), the full history is fetched instead.
+    #[option(
+        default = "\"full\"",
+        value_type = "str",
+        example = r#"
+            git-fetch-strategy = "shallow"
+        "#,
+        possible_values = true
+    )]
+    pub git_fetch_strategy: Option<GitFetchStrategy>,
+    /// Local Git repositories from which to borrow objects when fetching Git dependencies.
+    ///
+    /// When a Git repository is fetched for the first time, any objects that are already available
+    /// in these repositories (e.g., a local mirror of a large internal repository) are used in
+    /// place of fetching them from the remote, via Git's alternates mechanism. Both bare and
+    /// non-bare repositories are supported; repositories that don't exist are ignored.
+    ///
+    /// The reference repositories must not be removed while the cache is in use.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        uv_toml_only = true,
+        example = r#"
+            git-reference-repositories = ["/srv/git/mirror.git"]
+        "#
+    )]
+    pub git_reference_repositories: Option<Vec<PathBuf>>,
+    /// A directory in which to store fetched Git repositories, shared across caches.
+    ///
+    /// By default, fetched Git repositories are stored in the cache. When set, uv stores them in
+    /// the given directory instead, such that multiple caches (e.g., for different users, or
+    /// different cache namespaces) can share a single copy of each repository. Checkouts of
+    /// individual commits are still stored in the cache.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            git-shared-cache-dir = "/srv/uv-git"
+        "#
+    )]
+    pub git_shared_cache_dir: Option<PathBuf>,
+    /// The inputs to a fingerprint of the build environment, used to scope the wheels that uv
+    /// builds from source distributions.
+    ///
+    /// By default, wheels built from source distributions are cached based on the source
+    /// distribution, the Python interpreter, and the build settings (e.g., `config-settings`). When
+    /// the build also depends on the toolchain (e.g., the compiler version or flags), a fingerprint
+    /// of the toolchain can be incorporated into the cache key, such that upgrading the toolchain
+    /// invalidates exactly those wheels that were built with it.
+    ///
+    /// The fingerprint includes the values of the given environment variables (`env`) and the
+    /// output of the given shell commands (`commands`), which are evaluated at most once per
+    /// invocation.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        uv_toml_only = true,
+        example = r#"
+            build-fingerprint = { env = ["CC", "CFLAGS"], commands = ["cc --version"] }
+        "#
+    )]
+    pub build_fingerprint: Option<BuildFingerprint>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -313,6 +673,61 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// The maximum number of in-flight concurrent requests that uv will make to any single host
+    /// at any given time.
+    ///
+    /// Unlike `concurrent-downloads`, which bounds the total number of downloads, this setting
+    /// bounds the number of connections to each host (e.g., to avoid overwhelming a private
+    /// registry or mirror). By default, the number of requests per host is not limited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-downloads-per-host = 4
+        "#
+    )]
+    pub concurrent_downloads_per_host: Option<NonZeroUsize>,
+    /// The maximum bandwidth that uv will use for downloads, in bytes per second, shared across all
+    /// concurrent downloads.
+    ///
+    /// Accepts a number of bytes, optionally followed by a decimal (`KB`, `MB`, `GB`) or binary
+    /// (`KiB`, `MiB`, `GiB`) unit and `/s` (e.g., `10MB` or `500KiB/s`). By default, bandwidth
+    /// is not limited.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            bandwidth-limit = "10MB/s"
+        "#
+    )]
+    pub bandwidth_limit: Option<BandwidthLimit>,
+    /// The policy for retrying failed network requests.
+    ///
+    /// By default, uv retries a failed request up to three times, with exponential backoff,
+    /// if the request failed with a server error (`5xx`), a `408 Request Timeout` or
+    /// `429 Too Many Requests` response, or a network error.
+    ///
+    /// Supports the following keys:
+    ///
+    /// - `retries`: The maximum number of retries. The `UV_HTTP_RETRIES` environment variable
+    ///   takes precedence.
+    /// - `backoff`: Either `exponential` (the default), which doubles the delay after each retry,
+    ///   or `constant`, which waits `min-delay` before each retry.
+    /// - `min-delay`: The delay before the first retry, in seconds. Defaults to `1`.
+    /// - `max-delay`: The maximum delay between retries, in seconds. Defaults to `1800`.
+    /// - `retry-on-status`: The HTTP status codes to retry.
+    /// - `retry-on-error`: The network errors to retry, any of `connect`, `timeout`, and
+    ///   `interrupted`.
+    ///
+    /// Each index can override the policy with its own `retry` setting.
+    #[option(
+        default = "None",
+        value_type = "dict",
+        example = r#"
+            retry = { retries = 5, backoff = "exponential", max-delay = 60, retry-on-status = [429, 502, 503, 504] }
+        "#
+    )]
+    pub retry: Option<RetrySettings>,
     /// The URL of the HTTP proxy to use.
     #[option(
         default = "None",
@@ -370,6 +785,7 @@ pub struct InstallerOptions {
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
     pub index_strategy: Option<IndexStrategy>,
+    pub index_tracking_policy: Option<IndexTrackingPolicy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub config_settings: Option<ConfigSettings>,
     pub exclude_newer: Option<ExcludeNewerValue>,
@@ -394,6 +810,7 @@ pub struct ResolverOptions {
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
     pub index_strategy: Option<IndexStrategy>,
+    pub index_tracking_policy: Option<IndexTrackingPolicy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
@@ -426,6 +843,7 @@ pub struct ResolverInstallerOptions {
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
     pub index_strategy: Option<IndexStrategy>,
+    pub index_tracking_policy: Option<IndexTrackingPolicy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
@@ -460,6 +878,7 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             no_index,
             find_links,
             index_strategy,
+            index_tracking_policy,
             keyring_provider,
             resolution,
             prerelease,
@@ -494,6 +913,7 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             no_index,
             find_links,
             index_strategy,
+            index_tracking_policy,
             keyring_provider,
             resolution,
             prerelease,
@@ -692,6 +1112,23 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub index_strategy: Option<IndexStrategy>,
+    /// The policy to apply when a package is found on multiple indexes that don't declare each
+    /// other as mirrors or alternate locations via [PEP 708](https://peps.python.org/pep-0708/)
+    /// repository tracking metadata.
+    ///
+    /// When resolving with an `unsafe-first-match` or `unsafe-best-match` index strategy, a package
+    /// may be found on multiple indexes. Unless the indexes declare (via `tracks` or
+    /// `alternate-locations`) that their projects are the same, this may indicate a "dependency
+    /// confusion" attack. By default, uv merges the indexes as it would any other package
+    /// (`ignore`); use `warn` to warn in this case, or `deny` to fail instead.
+    #[option(
+        default = "\"ignore\"",
+        value_type = "str",
+        example = r#"
+            index-tracking-policy = "deny"
+        "#
+    )]
+    pub index_tracking_policy: Option<IndexTrackingPolicy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
     /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
@@ -1265,6 +1702,23 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub index_strategy: Option<IndexStrategy>,
+    /// The policy to apply when a package is found on multiple indexes that don't declare each
+    /// other as mirrors or alternate locations via [PEP 708](https://peps.python.org/pep-0708/)
+    /// repository tracking metadata.
+    ///
+    /// When resolving with an `unsafe-first-match` or `unsafe-best-match` index strategy, a package
+    /// may be found on multiple indexes. Unless the indexes declare (via `tracks` or
+    /// `alternate-locations`) that their projects are the same, this may indicate a "dependency
+    /// confusion" attack. By default, uv merges the indexes as it would any other package
+    /// (`ignore`); use `warn` to warn in this case, or `deny` to fail instead.
+    #[option(
+        default = "\"ignore\"",
+        value_type = "str",
+        example = r#"
+            index-tracking-policy = "deny"
+        "#
+    )]
+    pub index_tracking_policy: Option<IndexTrackingPolicy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
     /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
@@ -1947,6 +2401,7 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             no_index: value.no_index,
             find_links: value.find_links,
             index_strategy: value.index_strategy,
+            index_tracking_policy: value.index_tracking_policy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
@@ -1999,6 +2454,7 @@ impl From<ResolverInstallerSchema> for InstallerOptions {
             no_index: value.no_index,
             find_links: value.find_links,
             index_strategy: value.index_strategy,
+            index_tracking_policy: value.index_tracking_policy,
             keyring_provider: value.keyring_provider,
             config_settings: value.config_settings,
             exclude_newer: ExcludeNewer::from_args(
@@ -2047,6 +2503,7 @@ pub struct ToolOptions {
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
     pub index_strategy: Option<IndexStrategy>,
+    pub index_tracking_policy: Option<IndexTrackingPolicy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
@@ -2079,6 +2536,7 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             no_index: value.no_index,
             find_links: value.find_links,
             index_strategy: value.index_strategy,
+            index_tracking_policy: value.index_tracking_policy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
@@ -2113,6 +2571,7 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             no_index: value.no_index,
             find_links: value.find_links,
             index_strategy: value.index_strategy,
+            index_tracking_policy: value.index_tracking_policy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
@@ -2152,12 +2611,24 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_max_age: Option<CacheMaxAge>,
+    cache_max_size: Option<CacheMaxSize>,
+    cache_namespace: Option<CacheNamespace>,
+    cache_namespace_mode: Option<CacheNamespaceMode>,
+    remote_cache: Option<DisplaySafeUrl>,
+    git_fetch_strategy: Option<GitFetchStrategy>,
+    git_reference_repositories: Option<Vec<PathBuf>>,
+    git_shared_cache_dir: Option<PathBuf>,
+    build_fingerprint: Option<BuildFingerprint>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    concurrent_downloads_per_host: Option<NonZeroUsize>,
+    bandwidth_limit: Option<BandwidthLimit>,
+    retry: Option<RetrySettings>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -2167,6 +2638,7 @@ pub struct OptionsWire {
     no_index: Option<bool>,
     find_links: Option<Vec<PipFindLinks>>,
     index_strategy: Option<IndexStrategy>,
+    index_tracking_policy: Option<IndexTrackingPolicy>,
     keyring_provider: Option<KeyringProviderType>,
     http_proxy: Option<ProxyUrl>,
     https_proxy: Option<ProxyUrl>,
@@ -2216,6 +2688,9 @@ pub struct OptionsWire {
 
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    env_file: Option<EnvFile>,
+    locked_settings: Option<Vec<String>>,
+    profiles: Option<BTreeMap<String, ProfileOptions>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -2238,6 +2713,8 @@ pub struct OptionsWire {
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
+    hooks: Option<serde::de::IgnoredAny>,
+    sbom: Option<serde::de::IgnoredAny>,
 
     // Build backend
     build_backend: Option<serde::de::IgnoredAny>,
@@ -2251,6 +2728,15 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            cache_max_age,
+            cache_max_size,
+            cache_namespace,
+            cache_namespace_mode,
+            remote_cache,
+            git_fetch_strategy,
+            git_reference_repositories,
+            git_shared_cache_dir,
+            build_fingerprint,
             preview,
             python_preference,
             python_downloads,
@@ -2260,12 +2746,16 @@ impl From<OptionsWire> for Options {
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
+            concurrent_downloads_per_host,
+            bandwidth_limit,
+            retry,
             index,
             index_url,
             extra_index_url,
             no_index,
             find_links,
             index_strategy,
+            index_tracking_policy,
             keyring_provider,
             http_proxy,
             https_proxy,
@@ -2296,6 +2786,9 @@ impl From<OptionsWire> for Options {
             torch_backend,
             pip,
             cache_keys,
+            env_file,
+            locked_settings,
+            profiles,
             override_dependencies,
             exclude_dependencies,
             constraint_dependencies,
@@ -2315,6 +2808,8 @@ impl From<OptionsWire> for Options {
             dev_dependencies,
             managed,
             package,
+            hooks,
+            sbom,
             add_bounds: bounds,
             // Used by the build backend
             build_backend,
@@ -2327,12 +2822,24 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_age,
+                cache_max_size,
+                cache_namespace,
+                cache_namespace_mode,
+                remote_cache,
+                git_fetch_strategy,
+                git_reference_repositories,
+                git_shared_cache_dir,
+                build_fingerprint,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_downloads_per_host,
+                bandwidth_limit,
+                retry,
                 http_proxy,
                 https_proxy,
                 no_proxy,
@@ -2346,6 +2853,7 @@ impl From<OptionsWire> for Options {
                 no_index,
                 find_links,
                 index_strategy,
+                index_tracking_policy,
                 keyring_provider,
                 resolution,
                 prerelease,
@@ -2375,7 +2883,15 @@ impl From<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            env_file,
+            locked_settings,
+            profiles,
+            frozen: None,
+            locked: None,
+            no_progress: None,
             build_backend,
+            hooks,
+            sbom,
             override_dependencies,
             exclude_dependencies,
             constraint_dependencies,
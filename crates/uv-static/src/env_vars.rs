@@ -60,6 +60,17 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--remote-cache` command-line argument. If set, uv will share wheels
+    /// built from source distributions and wheels downloaded from indexes via the remote cache at
+    /// this URL.
+    #[attr_added_in("next version")]
+    pub const UV_REMOTE_CACHE: &'static str = "UV_REMOTE_CACHE";
+
+    /// Equivalent to the `--cache-metrics` command-line argument. If set, uv will write its cache
+    /// hit, miss, and eviction counters to this file as JSON.
+    #[attr_added_in("next version")]
+    pub const UV_CACHE_METRICS: &'static str = "UV_CACHE_METRICS";
+
     /// The directory for storage of credentials when using a plain text backend.
     #[attr_added_in("0.8.15")]
     pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
@@ -135,6 +146,22 @@ impl EnvVars {
     #[attr_added_in("0.2.34")]
     pub const UV_BUILD_CONSTRAINT: &'static str = "UV_BUILD_CONSTRAINT";
 
+    /// Set by the uv build backend when running a `tool.uv.build-backend.build-hook`, to the
+    /// directory whose contents are added to the wheel.
+    #[attr_added_in("next version")]
+    pub const UV_BUILD_HOOK_OUTPUT_DIR: &'static str = "UV_BUILD_HOOK_OUTPUT_DIR";
+
+    /// Set by the uv build backend when running a `tool.uv.build-backend.build-hook`, to the
+    /// Python interpreter the wheel is built for.
+    #[attr_added_in("next version")]
+    pub const UV_BUILD_HOOK_PYTHON: &'static str = "UV_BUILD_HOOK_PYTHON";
+
+    /// Set by the uv build backend when running a `tool.uv.build-backend.build-hook`, to the
+    /// filename suffix for extension modules of the target interpreter (e.g.,
+    /// `.cpython-312-x86_64-linux-gnu.so`).
+    #[attr_added_in("next version")]
+    pub const UV_BUILD_HOOK_EXT_SUFFIX: &'static str = "UV_BUILD_HOOK_EXT_SUFFIX";
+
     /// Equivalent to the `--overrides` command-line argument. If set, uv will use this file
     /// as the overrides file. Uses space-separated list of files.
     #[attr_added_in("0.2.22")]
@@ -179,6 +206,11 @@ impl EnvVars {
     #[attr_added_in("0.2.30")]
     pub const UV_NO_CONFIG: &'static str = "UV_NO_CONFIG";
 
+    /// Equivalent to the `--profile` command-line argument. The name of the settings profile to
+    /// apply, as defined in the `profiles` table of the configuration.
+    #[attr_added_in("next version")]
+    pub const UV_PROFILE: &'static str = "UV_PROFILE";
+
     /// Equivalent to the `--isolated` command-line argument. If set, uv will avoid discovering
     /// a `pyproject.toml` or `uv.toml` file.
     #[attr_added_in("0.8.14")]
@@ -217,6 +249,12 @@ impl EnvVars {
     #[attr_added_in("0.3.3")]
     pub const UV_COMPILE_BYTECODE: &'static str = "UV_COMPILE_BYTECODE";
 
+    /// Set by the shell completion scripts registered with `uv generate-shell-completion` to
+    /// request completions for the current command line, rather than running it.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const UV_COMPLETE: &'static str = "UV_COMPLETE";
+
     /// Timeout (in seconds) for bytecode compilation.
     #[attr_added_in("0.7.22")]
     pub const UV_COMPILE_BYTECODE_TIMEOUT: &'static str = "UV_COMPILE_BYTECODE_TIMEOUT";
@@ -285,9 +323,35 @@ impl EnvVars {
 
     /// Equivalent to the `--index` command-line argument in `uv publish`. If
     /// set, uv will use the index with this name in the configuration for publishing.
+    ///
+    /// Accepts a comma-separated list of index names to publish to multiple indexes.
     #[attr_added_in("0.5.8")]
     pub const UV_PUBLISH_INDEX: &'static str = "UV_PUBLISH_INDEX";
 
+    /// An OIDC identity token to use for trusted publishing with `uv publish`.
+    ///
+    /// Use this to publish from CI providers that uv can't obtain an OIDC token from by itself.
+    /// The token is exchanged for a short-lived upload token, and its audience must match the
+    /// audience of the index, e.g., `pypi` for PyPI.
+    #[attr_added_in("next version")]
+    pub const UV_PUBLISH_OIDC_TOKEN: &'static str = "UV_PUBLISH_OIDC_TOKEN";
+
+    /// The URL of the endpoint that exchanges the OIDC identity token for an upload token in
+    /// trusted publishing with `uv publish`.
+    ///
+    /// Defaults to the index's `/_/oidc/mint-token` endpoint. The endpoint receives the OIDC token
+    /// as `{"token": "..."}` and must respond with the upload token as `{"token": "..."}`, like
+    /// PyPI's endpoint does.
+    #[attr_added_in("next version")]
+    pub const UV_PUBLISH_OIDC_EXCHANGE_URL: &'static str = "UV_PUBLISH_OIDC_EXCHANGE_URL";
+
+    /// The audience of the OIDC identity token for trusted publishing with `uv publish`.
+    ///
+    /// Defaults to the audience that the index reports at `/_/oidc/audience`. Set this for token
+    /// exchange endpoints that don't report their audience.
+    #[attr_added_in("next version")]
+    pub const UV_PUBLISH_OIDC_AUDIENCE: &'static str = "UV_PUBLISH_OIDC_AUDIENCE";
+
     /// Equivalent to the `--username` command-line argument in `uv publish`. If
     /// set, uv will use this username for publishing.
     #[attr_added_in("0.4.16")]
@@ -307,6 +371,26 @@ impl EnvVars {
     #[attr_added_in("0.9.12")]
     pub const UV_PUBLISH_NO_ATTESTATIONS: &'static str = "UV_PUBLISH_NO_ATTESTATIONS";
 
+    /// Equivalent to the `--dependency-track` command-line argument in `uv sbom upload`. If
+    /// set, uv will upload SBOMs to the Dependency-Track server at this URL.
+    #[attr_added_in("next version")]
+    pub const UV_DEPENDENCY_TRACK_URL: &'static str = "UV_DEPENDENCY_TRACK_URL";
+
+    /// Equivalent to the `--api-key` command-line argument in `uv sbom upload`. If set, uv
+    /// will use this API key to authenticate with Dependency-Track.
+    #[attr_added_in("next version")]
+    pub const UV_DEPENDENCY_TRACK_API_KEY: &'static str = "UV_DEPENDENCY_TRACK_API_KEY";
+
+    /// Equivalent to the `--osv-url` command-line argument in `uv sbom audit`. If set, uv
+    /// will query this OSV API for known vulnerabilities.
+    #[attr_added_in("next version")]
+    pub const UV_OSV_URL: &'static str = "UV_OSV_URL";
+
+    /// Equivalent to the `--signing-key` command-line argument in `uv export`. If set, uv will
+    /// sign exported SBOMs with the private key at this path.
+    #[attr_added_in("next version")]
+    pub const UV_SIGNING_KEY: &'static str = "UV_SIGNING_KEY";
+
     /// Equivalent to the `--no-sync` command-line argument. If set, uv will skip updating
     /// the environment.
     #[attr_added_in("0.4.18")]
@@ -367,11 +451,54 @@ impl EnvVars {
     #[attr_added_in("0.1.45")]
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Sets the maximum number of in-flight concurrent requests that uv will
+    /// make to any single host at any given time.
+    #[attr_added_in("next version")]
+    pub const UV_CONCURRENT_DOWNLOADS_PER_HOST: &'static str = "UV_CONCURRENT_DOWNLOADS_PER_HOST";
+
+    /// Sets the maximum bandwidth that uv will use for downloads, in bytes per
+    /// second (e.g., `10MB` or `500KiB/s`).
+    #[attr_added_in("next version")]
+    pub const UV_BANDWIDTH_LIMIT: &'static str = "UV_BANDWIDTH_LIMIT";
+
+    /// Equivalent to the `--audit-log` command-line argument. If set, uv will write a record of
+    /// every request made to an index or other remote host to a new file in this directory.
+    #[attr_added_in("next version")]
+    pub const UV_AUDIT_LOG: &'static str = "UV_AUDIT_LOG";
+
+    /// Equivalent to the `--redact-logs` command-line argument. If set, uv will redact credentials,
+    /// tokens, and private index URLs from verbose output, warnings, errors, and the audit log.
+    #[attr_added_in("next version")]
+    pub const UV_REDACT_LOGS: &'static str = "UV_REDACT_LOGS";
+
+    /// Equivalent to the `--usage-log` command-line argument. If set, uv will append a record of
+    /// each invocation to this file.
+    #[attr_added_in("next version")]
+    pub const UV_USAGE_LOG: &'static str = "UV_USAGE_LOG";
+
+    /// Equivalent to the `--error-format` command-line argument. If set to `json`, uv will report
+    /// errors as JSON, including their error codes.
+    #[attr_added_in("next version")]
+    pub const UV_ERROR_FORMAT: &'static str = "UV_ERROR_FORMAT";
+
+    /// The language in which to display errors, warnings, and hints, as a locale name (e.g.,
+    /// `de_DE.UTF-8`) or language tag (e.g., `es`). Takes precedence over the `LC_ALL`,
+    /// `LC_MESSAGES`, and `LANG` environment variables.
+    ///
+    /// Requires the `localization` preview feature.
+    #[attr_added_in("next version")]
+    pub const UV_LOCALE: &'static str = "UV_LOCALE";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     #[attr_added_in("0.2.28")]
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// Equivalent to the `--progress` command-line argument. If set to `json`, uv will report
+    /// progress as JSON events on stderr instead of displaying progress bars.
+    #[attr_added_in("next version")]
+    pub const UV_PROGRESS: &'static str = "UV_PROGRESS";
+
     /// Specifies the directory where uv stores managed tools.
     #[attr_added_in("0.2.16")]
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
@@ -568,6 +695,17 @@ impl EnvVars {
     #[attr_added_in("0.9.15")]
     pub const UV_INTERNAL__TEST_LFS_DISABLED: &'static str = "UV_INTERNAL__TEST_LFS_DISABLED";
 
+    /// Used to read the organization policy from a file other than the system policy file in
+    /// tests. Only respected in debug builds.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const UV_INTERNAL__TEST_POLICY_FILE: &'static str = "UV_INTERNAL__TEST_POLICY_FILE";
+
+    /// Set by the `uv_build` Python shims to the interpreter that invoked the build backend.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const UV_INTERNAL__BUILD_BACKEND_PYTHON: &'static str = "UV_INTERNAL__BUILD_BACKEND_PYTHON";
+
     /// Path to system-level configuration directory on Unix systems.
     #[attr_added_in("0.4.26")]
     pub const XDG_CONFIG_DIRS: &'static str = "XDG_CONFIG_DIRS";
@@ -600,6 +738,11 @@ impl EnvVars {
     #[attr_added_in("0.2.16")]
     pub const XDG_BIN_HOME: &'static str = "XDG_BIN_HOME";
 
+    /// Path to the user-level Jupyter data directory, in which `uv kernel install` registers
+    /// kernels.
+    #[attr_added_in("next version")]
+    pub const JUPYTER_DATA_DIR: &'static str = "JUPYTER_DATA_DIR";
+
     /// Custom certificate bundle file path for SSL connections.
     ///
     /// Takes precedence over `UV_NATIVE_TLS` when set.
@@ -735,6 +878,26 @@ impl EnvVars {
     #[attr_added_in("0.8.16")]
     pub const ANDROID_API_LEVEL: &'static str = "ANDROID_API_LEVEL";
 
+    /// Set by `uv build --python-platform` to instruct build backends to target a different
+    /// platform, overriding the value of `sysconfig.get_platform()` (e.g., `macosx-13.0-arm64`).
+    #[attr_added_in("next version")]
+    pub const _PYTHON_HOST_PLATFORM: &'static str = "_PYTHON_HOST_PLATFORM";
+
+    /// Set by `uv build --python-platform` when targeting macOS, to select the architecture to
+    /// compile extension modules for (e.g., `-arch arm64`).
+    #[attr_added_in("next version")]
+    pub const ARCHFLAGS: &'static str = "ARCHFLAGS";
+
+    /// Set by `uv build --python-platform` when targeting Windows, to select the architecture to
+    /// compile extension modules for with MSVC (e.g., `arm64`).
+    #[attr_added_in("next version")]
+    pub const VSCMD_ARG_TGT_ARCH: &'static str = "VSCMD_ARG_TGT_ARCH";
+
+    /// Set by the uv build backend when running a `tool.uv.build-backend.build-hook`, to build
+    /// PyO3-based extension modules for the target interpreter, unless already set.
+    #[attr_added_in("next version")]
+    pub const PYO3_PYTHON: &'static str = "PYO3_PYTHON";
+
     /// Disables colored output (takes precedence over `FORCE_COLOR`).
     ///
     /// See [no-color.org](https://no-color.org).
@@ -751,6 +914,13 @@ impl EnvVars {
     #[attr_added_in("0.1.32")]
     pub const CLICOLOR_FORCE: &'static str = "CLICOLOR_FORCE";
 
+    /// Used to set the timestamp for reproducible builds, in seconds since the Unix epoch.
+    ///
+    /// `uv build --check` rejects distributions with files newer than this timestamp. `uv export`
+    /// uses it as the creation time of SBOMs, and derives their serial number from the lockfile.
+    #[attr_added_in("next version")]
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// The standard `PATH` env var.
     #[attr_added_in("0.0.5")]
     pub const PATH: &'static str = "PATH";
@@ -848,6 +1018,36 @@ impl EnvVars {
     #[attr_added_in("0.4.16")]
     pub const GITHUB_ACTIONS: &'static str = "GITHUB_ACTIONS";
 
+    /// The commit SHA that triggered the GitHub Actions workflow.
+    ///
+    /// Used by `uv export --format github-dependency-snapshot`.
+    #[attr_added_in("next version")]
+    pub const GITHUB_SHA: &'static str = "GITHUB_SHA";
+
+    /// The Git reference that triggered the GitHub Actions workflow.
+    ///
+    /// Used by `uv export --format github-dependency-snapshot`.
+    #[attr_added_in("next version")]
+    pub const GITHUB_REF: &'static str = "GITHUB_REF";
+
+    /// The name of the running GitHub Actions workflow.
+    ///
+    /// Used by `uv export --format github-dependency-snapshot`.
+    #[attr_added_in("next version")]
+    pub const GITHUB_WORKFLOW: &'static str = "GITHUB_WORKFLOW";
+
+    /// The ID of the running GitHub Actions job.
+    ///
+    /// Used by `uv export --format github-dependency-snapshot`.
+    #[attr_added_in("next version")]
+    pub const GITHUB_JOB: &'static str = "GITHUB_JOB";
+
+    /// The unique ID of the GitHub Actions workflow run.
+    ///
+    /// Used by `uv export --format github-dependency-snapshot`.
+    #[attr_added_in("next version")]
+    pub const GITHUB_RUN_ID: &'static str = "GITHUB_RUN_ID";
+
     /// Indicates that the current process is running in GitLab CI.
     ///
     /// `uv publish` may attempt trusted publishing flows when set
@@ -894,11 +1094,22 @@ impl EnvVars {
     #[attr_added_in("0.7.13")]
     pub const PYVENV_LAUNCHER: &'static str = "__PYVENV_LAUNCHER__";
 
-    /// Used in tests to enforce a consistent locale setting.
+    /// Used in tests to enforce a consistent locale setting, and to detect the language in which
+    /// to display messages.
     #[attr_hidden]
     #[attr_added_in("0.4.28")]
     pub const LC_ALL: &'static str = "LC_ALL";
 
+    /// Used to detect the language in which to display messages.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const LC_MESSAGES: &'static str = "LC_MESSAGES";
+
+    /// Used to detect the language in which to display messages.
+    #[attr_hidden]
+    #[attr_added_in("next version")]
+    pub const LANG: &'static str = "LANG";
+
     /// Typically set by CI runners, used to detect a CI runner.
     #[attr_hidden]
     #[attr_added_in("0.0.5")]
@@ -923,6 +1134,11 @@ impl EnvVars {
     #[attr_added_in("0.1.16")]
     pub const NETRC: &'static str = "NETRC";
 
+    /// The directory containing the Docker configuration file (`config.json`), used to find
+    /// credentials for `oci://` indexes. Defaults to `~/.docker`.
+    #[attr_added_in("next version")]
+    pub const DOCKER_CONFIG: &'static str = "DOCKER_CONFIG";
+
     /// The standard `PAGER` posix env var. Used by `uv` to configure the appropriate pager.
     #[attr_added_in("0.4.18")]
     pub const PAGER: &'static str = "PAGER";
@@ -977,6 +1193,18 @@ impl EnvVars {
     #[attr_added_in("0.6.4")]
     pub const UV_LOG_CONTEXT: &'static str = "UV_LOG_CONTEXT";
 
+    /// The name of the lifecycle event (e.g., `post-lock`) that triggered a hook.
+    ///
+    /// Set by uv when running a `command` hook from `tool.uv.hooks`.
+    #[attr_added_in("next version")]
+    pub const UV_HOOK_EVENT: &'static str = "UV_HOOK_EVENT";
+
+    /// The OTLP/HTTP endpoint to export tracing spans to, e.g., `http://localhost:4318/v1/traces`.
+    ///
+    /// Requires uv to be built with the `opentelemetry` feature.
+    #[attr_added_in("next version")]
+    pub const UV_OTEL_ENDPOINT: &'static str = "UV_OTEL_ENDPOINT";
+
     /// Use to set the stack size used by uv.
     ///
     /// The value is in bytes, and if both `UV_STACK_SIZE` are `RUST_MIN_STACK` unset, uv uses a 4MB
@@ -10,6 +10,12 @@ pub use anstream;
 pub use owo_colors;
 use owo_colors::{DynColor, OwoColorize};
 use rustc_hash::FxHashSet;
+#[doc(hidden)]
+pub use uv_redacted;
+
+use crate::locale::Message;
+
+pub mod locale;
 
 /// Whether user-facing warnings are enabled.
 pub static ENABLED: AtomicBool = AtomicBool::new(false);
@@ -33,8 +39,13 @@ macro_rules! warn_user {
 
         if $crate::ENABLED.load(std::sync::atomic::Ordering::Relaxed) {
             let message = format!("{}", format_args!($($arg)*));
+            let message = $crate::uv_redacted::redact_output(&message);
             let formatted = message.bold();
-            eprintln!("{}{} {formatted}", "warning".yellow().bold(), ":".bold());
+            eprintln!(
+                "{}{} {formatted}",
+                $crate::locale::Message::Warning.text().yellow().bold(),
+                ":".bold()
+            );
         }
     }};
 }
@@ -52,8 +63,14 @@ macro_rules! warn_user_once {
         if $crate::ENABLED.load(std::sync::atomic::Ordering::Relaxed) {
             if let Ok(mut states) = $crate::WARNINGS.lock() {
                 let message = format!("{}", format_args!($($arg)*));
+                let message = $crate::uv_redacted::redact_output(&message).into_owned();
                 if states.insert(message.clone()) {
-                    eprintln!("{}{} {}", "warning".yellow().bold(), ":".bold(), message.bold());
+                    eprintln!(
+                        "{}{} {}",
+                        $crate::locale::Message::Warning.text().yellow().bold(),
+                        ":".bold(),
+                        message.bold()
+                    );
                 }
             }
         }
@@ -94,15 +111,15 @@ pub fn write_error_chain(
         "{}{} {}",
         level.as_ref().color(color).bold(),
         ":".bold(),
-        err.to_string().trim().bold()
+        uv_redacted::redact_output(&err.to_string()).trim().bold()
     )?;
     for source in iter::successors(err.source(), |&err| err.source()) {
-        let msg = source.to_string();
+        let msg = uv_redacted::redact_output(&source.to_string()).into_owned();
         let mut lines = msg.lines();
         if let Some(first) = lines.next() {
             let padding = "  ";
-            let cause = "Caused by";
-            let child_padding = " ".repeat(padding.len() + cause.len() + 2);
+            let cause = Message::CausedBy.text();
+            let child_padding = " ".repeat(padding.len() + cause.chars().count() + 2);
             writeln!(
                 &mut stream,
                 "{}{}: {}",
@@ -0,0 +1,427 @@
+//! A catalog of translations for user-facing messages.
+//!
+//! Messages are identified by a [`Message`], which has a stable key regardless of the selected
+//! [`Locale`]. Messages without a translation for the selected locale fall back to English.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The locale in which to render user-facing messages.
+static LOCALE: AtomicU8 = AtomicU8::new(Locale::English as u8);
+
+/// Set the locale in which to render user-facing messages.
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// Return the locale in which to render user-facing messages.
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::German,
+        2 => Locale::Spanish,
+        3 => Locale::French,
+        _ => Locale::English,
+    }
+}
+
+/// A language in which user-facing messages can be rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Locale {
+    #[default]
+    English = 0,
+    German = 1,
+    Spanish = 2,
+    French = 3,
+}
+
+impl Locale {
+    /// Parse a [`Locale`] from a POSIX locale name (e.g., `de_DE.UTF-8`) or a BCP 47 language tag
+    /// (e.g., `es-MX`), by its language.
+    ///
+    /// Returns `None` if the language isn't supported.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let language = name
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "en" | "c" | "posix" => Some(Self::English),
+            "de" => Some(Self::German),
+            "es" => Some(Self::Spanish),
+            "fr" => Some(Self::French),
+            _ => None,
+        }
+    }
+
+    /// Detect the [`Locale`] from the first of the given locale variables that is set and
+    /// non-empty, e.g., `UV_LOCALE`, `LC_ALL`, `LC_MESSAGES`, and `LANG`.
+    ///
+    /// Defaults to English if the locale isn't set or isn't supported.
+    pub fn from_env(variables: &[&str]) -> Self {
+        variables
+            .iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_name(&value))
+            .unwrap_or_default()
+    }
+}
+
+/// A user-facing message with translations in the message catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message {
+    /// The label for an error, e.g., `error: ...`.
+    Error,
+    /// The label for a warning, e.g., `warning: ...`.
+    Warning,
+    /// The label for the cause of an error, e.g., `Caused by: ...`.
+    CausedBy,
+    /// The label for a hint, e.g., `hint: ...`.
+    Hint,
+    /// The label for the stable code of an error, e.g., `error code: UV1101`.
+    ErrorCode,
+    /// The header for a resolution failure.
+    NoSolution,
+    /// The header for a resolution failure, with `{context}` for the kind of dependencies.
+    NoSolutionContext,
+    /// The header for a resolution failure, with `{split}` for the environment.
+    NoSolutionSplit,
+    /// The header for a resolution failure, with `{context}` for the kind of dependencies and
+    /// `{split}` for the environment.
+    NoSolutionContextSplit,
+    /// A step in a resolution failure that follows from `{cause}`, with `{conclusion}`.
+    ExplainExternal,
+    /// A step in a resolution failure that follows from the earlier steps `{ref1}` and `{ref2}`,
+    /// with `{cause1}`, `{cause2}`, and `{conclusion}`.
+    ExplainRefs,
+    /// A step in a resolution failure that follows from the earlier step `{ref}` and `{cause}`,
+    /// with `{derived}` and `{conclusion}`.
+    ExplainRefAndExternal,
+    /// A step in a resolution failure that continues the previous step, with `{cause}` and
+    /// `{conclusion}`.
+    AndExplainExternal,
+    /// A step in a resolution failure that continues the previous step with the earlier step
+    /// `{ref}`, with `{cause}` and `{conclusion}`.
+    AndExplainRef,
+    /// The conclusion of a resolution failure without any terms.
+    RequirementsUnsatisfiable,
+    /// The conclusion that the requirements in `{subject}` are unsatisfiable.
+    Unsatisfiable,
+    /// The conclusion that the requirements of the workspace member `{member}` are unsatisfiable.
+    MemberUnsatisfiable,
+    /// The conclusion that `{subject}` cannot be used.
+    CannotBeUsed,
+    /// The conclusion that `{subject}` must be used.
+    MustBeUsed,
+    /// The conclusion that the terms in `{subject}` are incompatible.
+    AreIncompatible,
+    /// The conjunction between two terms in a resolution failure.
+    And,
+    /// The cause that there are no versions of `{package}`.
+    NoVersions,
+    /// The cause that there is no version of `{package}` matching the specifier `{set}`.
+    NoVersion,
+    /// The requirements of the user, e.g., `you require anyio>=4`.
+    YouRequire,
+    /// The requirements of a single-project workspace, e.g., `your project requires anyio>=4`.
+    YourProjectRequires,
+    /// The requirements of a workspace, e.g., `your workspace requires anyio>=4`.
+    YourWorkspaceRequires,
+    /// The requirements of the named root package `{name}`.
+    RootDependsOn,
+    /// The subject for the requirements of the user.
+    YourRequirements,
+    /// The subject for the requirements of a single-project workspace.
+    YourProjectRequirements,
+    /// The subject for the requirements of a workspace.
+    YourWorkspaceRequirements,
+    /// The project in a single-project workspace.
+    YourProject,
+}
+
+impl Message {
+    /// The stable key for the message, which doesn't change with the locale.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::CausedBy => "caused-by",
+            Self::Hint => "hint",
+            Self::ErrorCode => "error-code",
+            Self::NoSolution => "no-solution",
+            Self::NoSolutionContext => "no-solution-context",
+            Self::NoSolutionSplit => "no-solution-split",
+            Self::NoSolutionContextSplit => "no-solution-context-split",
+            Self::ExplainExternal => "explain-external",
+            Self::ExplainRefs => "explain-refs",
+            Self::ExplainRefAndExternal => "explain-ref-and-external",
+            Self::AndExplainExternal => "and-explain-external",
+            Self::AndExplainRef => "and-explain-ref",
+            Self::RequirementsUnsatisfiable => "requirements-unsatisfiable",
+            Self::Unsatisfiable => "unsatisfiable",
+            Self::MemberUnsatisfiable => "member-unsatisfiable",
+            Self::CannotBeUsed => "cannot-be-used",
+            Self::MustBeUsed => "must-be-used",
+            Self::AreIncompatible => "are-incompatible",
+            Self::And => "and",
+            Self::NoVersions => "no-versions",
+            Self::NoVersion => "no-version",
+            Self::YouRequire => "you-require",
+            Self::YourProjectRequires => "your-project-requires",
+            Self::YourWorkspaceRequires => "your-workspace-requires",
+            Self::RootDependsOn => "root-depends-on",
+            Self::YourRequirements => "your-requirements",
+            Self::YourProjectRequirements => "your-project-requirements",
+            Self::YourWorkspaceRequirements => "your-workspace-requirements",
+            Self::YourProject => "your-project",
+        }
+    }
+
+    /// The message in the selected locale.
+    pub fn text(self) -> &'static str {
+        self.translate(locale())
+    }
+
+    /// The message in the selected locale, with each `{name}` placeholder replaced by its value.
+    pub fn format(self, args: &[(&str, &dyn Display)]) -> String {
+        args.iter()
+            .fold(self.text().to_string(), |message, (name, value)| {
+                message.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+
+    /// The message in the given locale.
+    pub fn translate(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::English => self.english(),
+            Locale::German => self.german(),
+            Locale::Spanish => self.spanish(),
+            Locale::French => self.french(),
+        }
+    }
+
+    fn english(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::CausedBy => "Caused by",
+            Self::Hint => "hint",
+            Self::ErrorCode => "error code",
+            Self::NoSolution => "No solution found when resolving dependencies:",
+            Self::NoSolutionContext => "No solution found when resolving {context} dependencies:",
+            Self::NoSolutionSplit => "No solution found when resolving dependencies for {split}:",
+            Self::NoSolutionContextSplit => {
+                "No solution found when resolving {context} dependencies for {split}:"
+            }
+            Self::ExplainExternal => "Because {cause}we can conclude that {conclusion}",
+            Self::ExplainRefs => {
+                "Because we know from ({ref1}) that {cause1}and we know from ({ref2}) that {cause2}{conclusion}"
+            }
+            Self::ExplainRefAndExternal => {
+                "Because we know from ({ref}) that {derived}and {cause}we can conclude that {conclusion}"
+            }
+            Self::AndExplainExternal => "And because {cause}we can conclude that {conclusion}",
+            Self::AndExplainRef => {
+                "And because we know from ({ref}) that {cause}we can conclude that {conclusion}"
+            }
+            Self::RequirementsUnsatisfiable => "the requirements are unsatisfiable",
+            Self::Unsatisfiable => "{subject} are unsatisfiable",
+            Self::MemberUnsatisfiable => "{member}'s requirements are unsatisfiable",
+            Self::CannotBeUsed => "{subject} cannot be used",
+            Self::MustBeUsed => "{subject} must be used",
+            Self::AreIncompatible => "{subject} are incompatible",
+            Self::And => "and",
+            Self::NoVersions => "there are no versions of {package}",
+            Self::NoVersion => "there is no version of {package}{set}",
+            Self::YouRequire => "you require",
+            Self::YourProjectRequires => "your project requires",
+            Self::YourWorkspaceRequires => "your workspace requires",
+            Self::RootDependsOn => "{name} depends on",
+            Self::YourRequirements => "your requirements",
+            Self::YourProjectRequirements => "your project's requirements",
+            Self::YourWorkspaceRequirements => "your workspace's requirements",
+            Self::YourProject => "your project",
+        }
+    }
+
+    fn german(self) -> &'static str {
+        match self {
+            Self::Error => "Fehler",
+            Self::Warning => "Warnung",
+            Self::CausedBy => "Verursacht durch",
+            Self::Hint => "Hinweis",
+            Self::ErrorCode => "Fehlercode",
+            Self::NoSolution => "Beim Auflösen der Abhängigkeiten wurde keine Lösung gefunden:",
+            Self::NoSolutionContext => {
+                "Beim Auflösen der {context}-Abhängigkeiten wurde keine Lösung gefunden:"
+            }
+            Self::NoSolutionSplit => {
+                "Beim Auflösen der Abhängigkeiten für {split} wurde keine Lösung gefunden:"
+            }
+            Self::NoSolutionContextSplit => {
+                "Beim Auflösen der {context}-Abhängigkeiten für {split} wurde keine Lösung gefunden:"
+            }
+            Self::ExplainExternal => "Es gilt: {cause}daraus folgt: {conclusion}",
+            Self::ExplainRefs => {
+                "Aus ({ref1}) wissen wir: {cause1}und aus ({ref2}): {cause2}daraus folgt: {conclusion}"
+            }
+            Self::ExplainRefAndExternal => {
+                "Aus ({ref}) wissen wir: {derived}und es gilt: {cause}daraus folgt: {conclusion}"
+            }
+            Self::AndExplainExternal => "Außerdem gilt: {cause}daraus folgt: {conclusion}",
+            Self::AndExplainRef => {
+                "Außerdem wissen wir aus ({ref}): {cause}daraus folgt: {conclusion}"
+            }
+            Self::RequirementsUnsatisfiable => "die Anforderungen sind nicht erfüllbar",
+            Self::Unsatisfiable => "{subject} sind nicht erfüllbar",
+            Self::MemberUnsatisfiable => "die Anforderungen von {member} sind nicht erfüllbar",
+            Self::CannotBeUsed => "{subject} kann nicht verwendet werden",
+            Self::MustBeUsed => "{subject} muss verwendet werden",
+            Self::AreIncompatible => "{subject} sind inkompatibel",
+            Self::And => "und",
+            Self::NoVersions => "es gibt keine Versionen von {package}",
+            Self::NoVersion => "es gibt keine Version {package}{set}",
+            Self::YouRequire => "Sie benötigen",
+            Self::YourProjectRequires => "Ihr Projekt benötigt",
+            Self::YourWorkspaceRequires => "Ihr Arbeitsbereich benötigt",
+            Self::RootDependsOn => "{name} hängt ab von",
+            Self::YourRequirements => "Ihre Anforderungen",
+            Self::YourProjectRequirements => "die Anforderungen Ihres Projekts",
+            Self::YourWorkspaceRequirements => "die Anforderungen Ihres Arbeitsbereichs",
+            Self::YourProject => "Ihr Projekt",
+        }
+    }
+
+    fn spanish(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "advertencia",
+            Self::CausedBy => "Causado por",
+            Self::Hint => "sugerencia",
+            Self::ErrorCode => "código de error",
+            Self::NoSolution => "No se encontró ninguna solución al resolver las dependencias:",
+            Self::NoSolutionContext => {
+                "No se encontró ninguna solución al resolver las dependencias de {context}:"
+            }
+            Self::NoSolutionSplit => {
+                "No se encontró ninguna solución al resolver las dependencias para {split}:"
+            }
+            Self::NoSolutionContextSplit => {
+                "No se encontró ninguna solución al resolver las dependencias de {context} para {split}:"
+            }
+            Self::ExplainExternal => "Como {cause}podemos concluir que {conclusion}",
+            Self::ExplainRefs => {
+                "Como sabemos por ({ref1}) que {cause1}y sabemos por ({ref2}) que {cause2}{conclusion}"
+            }
+            Self::ExplainRefAndExternal => {
+                "Como sabemos por ({ref}) que {derived}y {cause}podemos concluir que {conclusion}"
+            }
+            Self::AndExplainExternal => "Y como {cause}podemos concluir que {conclusion}",
+            Self::AndExplainRef => {
+                "Y como sabemos por ({ref}) que {cause}podemos concluir que {conclusion}"
+            }
+            Self::RequirementsUnsatisfiable => "los requisitos no se pueden satisfacer",
+            Self::Unsatisfiable => "{subject} no se pueden satisfacer",
+            Self::MemberUnsatisfiable => "los requisitos de {member} no se pueden satisfacer",
+            Self::CannotBeUsed => "{subject} no se puede usar",
+            Self::MustBeUsed => "{subject} debe usarse",
+            Self::AreIncompatible => "{subject} son incompatibles",
+            Self::And => "y",
+            Self::NoVersions => "no hay versiones de {package}",
+            Self::NoVersion => "no hay ninguna versión {package}{set}",
+            Self::YouRequire => "usted requiere",
+            Self::YourProjectRequires => "su proyecto requiere",
+            Self::YourWorkspaceRequires => "su espacio de trabajo requiere",
+            Self::RootDependsOn => "{name} depende de",
+            Self::YourRequirements => "sus requisitos",
+            Self::YourProjectRequirements => "los requisitos de su proyecto",
+            Self::YourWorkspaceRequirements => "los requisitos de su espacio de trabajo",
+            Self::YourProject => "su proyecto",
+        }
+    }
+
+    fn french(self) -> &'static str {
+        match self {
+            Self::Error => "erreur",
+            Self::Warning => "avertissement",
+            Self::CausedBy => "Causé par",
+            Self::Hint => "astuce",
+            Self::ErrorCode => "code d'erreur",
+            Self::NoSolution => "Aucune solution trouvée lors de la résolution des dépendances :",
+            Self::NoSolutionContext => {
+                "Aucune solution trouvée lors de la résolution des dépendances {context} :"
+            }
+            Self::NoSolutionSplit => {
+                "Aucune solution trouvée lors de la résolution des dépendances pour {split} :"
+            }
+            Self::NoSolutionContextSplit => {
+                "Aucune solution trouvée lors de la résolution des dépendances {context} pour {split} :"
+            }
+            Self::ExplainExternal => "Comme {cause}nous pouvons conclure que {conclusion}",
+            Self::ExplainRefs => {
+                "Comme nous savons par ({ref1}) que {cause1}et nous savons par ({ref2}) que {cause2}{conclusion}"
+            }
+            Self::ExplainRefAndExternal => {
+                "Comme nous savons par ({ref}) que {derived}et {cause}nous pouvons conclure que {conclusion}"
+            }
+            Self::AndExplainExternal => "Et comme {cause}nous pouvons conclure que {conclusion}",
+            Self::AndExplainRef => {
+                "Et comme nous savons par ({ref}) que {cause}nous pouvons conclure que {conclusion}"
+            }
+            Self::RequirementsUnsatisfiable => "les exigences ne peuvent pas être satisfaites",
+            Self::Unsatisfiable => "{subject} ne peuvent pas être satisfaites",
+            Self::MemberUnsatisfiable => {
+                "les exigences de {member} ne peuvent pas être satisfaites"
+            }
+            Self::CannotBeUsed => "{subject} ne peut pas être utilisé",
+            Self::MustBeUsed => "{subject} doit être utilisé",
+            Self::AreIncompatible => "{subject} sont incompatibles",
+            Self::And => "et",
+            Self::NoVersions => "il n'existe aucune version de {package}",
+            Self::NoVersion => "il n'existe pas de version {package}{set}",
+            Self::YouRequire => "vous avez besoin de",
+            Self::YourProjectRequires => "votre projet a besoin de",
+            Self::YourWorkspaceRequires => "votre espace de travail a besoin de",
+            Self::RootDependsOn => "{name} dépend de",
+            Self::YourRequirements => "vos exigences",
+            Self::YourProjectRequirements => "les exigences de votre projet",
+            Self::YourWorkspaceRequirements => "les exigences de votre espace de travail",
+            Self::YourProject => "votre projet",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Locale, Message};
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Locale::from_name("de_DE.UTF-8"), Some(Locale::German));
+        assert_eq!(Locale::from_name("es-MX"), Some(Locale::Spanish));
+        assert_eq!(Locale::from_name("fr"), Some(Locale::French));
+        assert_eq!(Locale::from_name("C.UTF-8"), Some(Locale::English));
+        assert_eq!(Locale::from_name("ja_JP.UTF-8"), None);
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            Message::NoSolutionContextSplit.format(&[("context", &"build"), ("split", &"Linux")]),
+            "No solution found when resolving build dependencies for Linux:"
+        );
+        assert_eq!(
+            Message::ExplainExternal.format(&[
+                ("cause", &"there are no versions of anyio, "),
+                ("conclusion", &"your requirements are unsatisfiable.")
+            ]),
+            "Because there are no versions of anyio, we can conclude that your requirements are unsatisfiable."
+        );
+        assert_eq!(
+            Message::NoSolutionSplit.translate(Locale::German),
+            "Beim Auflösen der Abhängigkeiten für {split} wurde keine Lösung gefunden:"
+        );
+    }
+}
@@ -22,7 +22,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
 use uv_build_backend::BuildBackendSettings;
-use uv_configuration::GitLfsSetting;
+use uv_configuration::{GitLfsSetting, SbomMetadata};
 use uv_distribution_types::{Index, IndexName, RequirementSource};
 use uv_fs::{PortablePathBuf, relative_to};
 use uv_git_types::GitReference;
@@ -575,9 +575,11 @@ pub struct ToolUv {
     /// graph.
     ///
     /// !!! note
-    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `build-constraint-dependencies` from
-    ///     the `pyproject.toml` at the workspace root, and will ignore any declarations in other
-    ///     workspace members or `uv.toml` files.
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only record `build-constraint-dependencies`
+    ///     from the `pyproject.toml` at the workspace root in the lockfile, and will ignore any
+    ///     declarations in other workspace members. Declarations in `uv.toml` files, e.g., a
+    ///     user-level or system-level `uv.toml`, are not recorded in the lockfile, but are applied
+    ///     to all builds, including those of source distributions of third-party dependencies.
     #[cfg_attr(
         feature = "schemars",
         schemars(
@@ -704,6 +706,33 @@ pub struct ToolUv {
     )]
     pub conflicts: Option<SchemaConflicts>,
 
+    /// Commands to run, or URLs to notify, at points in the project lifecycle.
+    ///
+    /// Hooks are only read from the workspace root.
+    #[option_group]
+    pub hooks: Option<ToolUvHooks>,
+
+    /// Metadata to record in the SBOMs exported for the project, e.g., with
+    /// `uv export --format cyclonedx1.5`.
+    ///
+    /// The `supplier` and `manufacturer` organizations, the `authors` of the SBOM, and custom
+    /// `properties` are recorded in the SBOM's metadata. The `component-type` overrides the type of
+    /// the root component, and `tools` are recorded alongside uv as having generated the SBOM.
+    ///
+    /// The SBOM metadata is only read from the workspace root.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.sbom]
+            supplier = { name = "Acme Corp", url = ["https://acme.example.com"] }
+            authors = [{ name = "Jane Doe", email = "jane@acme.example.com" }]
+            component-type = "application"
+            properties = { "acme:team" = "platform" }
+        "#
+    )]
+    pub sbom: Option<SbomMetadata>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -925,6 +954,61 @@ pub struct ToolUvWorkspace {
     pub exclude: Option<Vec<SerdePattern>>,
 }
 
+#[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolUvHooks {
+    /// Hooks to run after `uv lock` writes the lockfile.
+    ///
+    /// Each hook either runs a `command` in the workspace root, or sends a `POST` request with a
+    /// JSON payload describing the event to a `url`. Hooks run in order, and uv exits with an
+    /// error if a hook fails.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            post-lock = [{ command = ["cyclonedx-py", "environment"] }]
+        "#
+    )]
+    pub post_lock: Option<Vec<Hook>>,
+    /// Hooks to run after `uv sync` syncs the project environment.
+    ///
+    /// Each hook either runs a `command` in the workspace root, or sends a `POST` request with a
+    /// JSON payload describing the event to a `url`.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            post-sync = [{ url = "https://example.com/hooks/uv" }]
+        "#
+    )]
+    pub post_sync: Option<Vec<Hook>>,
+    /// Hooks to run before `uv publish` uploads any files.
+    ///
+    /// If a hook fails, no files are uploaded.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            pre-publish = [{ command = ["python", "scripts/check_release.py"] }]
+        "#
+    )]
+    pub pre_publish: Option<Vec<Hook>>,
+}
+
+/// A command or URL to invoke on a project lifecycle event.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged, rename_all = "kebab-case")]
+pub enum Hook {
+    /// Run a command in the workspace root, with `UV_HOOK_EVENT` set to the name of the event.
+    Command { command: Vec<String> },
+    /// Send a `POST` request with a JSON payload describing the event.
+    Url { url: DisplaySafeUrl },
+}
+
 /// (De)serialize globs as strings.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SerdePattern(Pattern);
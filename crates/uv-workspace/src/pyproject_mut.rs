@@ -7,15 +7,17 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use toml_edit::{
-    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TomlError, Value,
+    Array, ArrayOfTables, DocumentMut, Formatted, InlineTable, Item, RawString, Table, TomlError,
+    Value,
 };
 
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
 use uv_normalize::{ExtraName, GroupName, PackageName};
-use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{Operator, Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
+use uv_pypi_types::{ConflictItem, ConflictKind};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::pyproject::{DependencyType, Source};
@@ -807,6 +809,118 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Relax the version specifiers for any requirements on the given package, dropping upper
+    /// bounds and widening pins to lower bounds (e.g., `flask>=2,<3` and `flask==2.0.0` become
+    /// `flask>=2` and `flask>=2.0.0`, respectively).
+    ///
+    /// Returns the original and relaxed requirement for each modified dependency.
+    pub fn relax_dependency(
+        &mut self,
+        dependency_type: &DependencyType,
+        name: &PackageName,
+    ) -> Result<Vec<(Requirement, Requirement)>, Error> {
+        if !self.find_dependency(name, None).contains(dependency_type) {
+            return Ok(Vec::new());
+        }
+
+        let group = match dependency_type {
+            DependencyType::Production => self.dependencies_array()?,
+            DependencyType::Dev => self.dev_dependencies_array()?,
+            DependencyType::Optional(extra) => self.optional_dependencies_array(extra)?,
+            DependencyType::Group(group) => self.dependency_groups_array(group)?,
+        };
+
+        let mut relaxed = Vec::new();
+        for (index, req) in find_dependencies(name, None, group) {
+            let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &req.version_or_url else {
+                continue;
+            };
+            let specifiers = relax_specifiers(specifiers);
+            if req.version_or_url == Some(VersionOrUrl::VersionSpecifier(specifiers.clone())) {
+                continue;
+            }
+            let mut new = req.clone();
+            new.version_or_url = if specifiers.is_empty() {
+                None
+            } else {
+                Some(VersionOrUrl::VersionSpecifier(specifiers))
+            };
+            group.replace(index, new.to_string());
+            relaxed.push((req, new));
+        }
+
+        Ok(relaxed)
+    }
+
+    /// Adds a requirement to `tool.uv.override-dependencies`, replacing any existing override for
+    /// the same package.
+    pub fn add_override_dependency(&mut self, req: &Requirement) -> Result<ArrayEdit, Error> {
+        // Get or create `tool.uv.override-dependencies`.
+        let overrides = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedSources)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedSources)?
+            .entry("override-dependencies")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedDependencies)?;
+
+        add_dependency(req, overrides, false, false)
+    }
+
+    /// Adds a set of conflicting extras or groups to `tool.uv.conflicts`.
+    ///
+    /// Items that belong to the given project are written without an explicit `package`.
+    pub fn add_conflict(
+        &mut self,
+        project: &PackageName,
+        items: &[ConflictItem],
+    ) -> Result<(), Error> {
+        // Get or create `tool.uv.conflicts`.
+        let conflicts = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedSources)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedSources)?
+            .entry("conflicts")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedDependencies)?;
+
+        let mut set = Array::new();
+        for item in items {
+            let mut table = InlineTable::new();
+            if item.package() != project {
+                table.insert("package", Value::from(item.package().to_string()));
+            }
+            match item.kind() {
+                ConflictKind::Extra(extra) => {
+                    table.insert("extra", Value::from(extra.to_string()));
+                }
+                ConflictKind::Group(group) => {
+                    table.insert("group", Value::from(group.to_string()));
+                }
+                ConflictKind::Project => {}
+            }
+            set.push(table);
+        }
+        conflicts.push(set);
+        reformat_array_multiline(conflicts);
+
+        Ok(())
+    }
+
     /// Get the TOML array for `project.dependencies`.
     fn dependencies_array(&mut self) -> Result<&mut Array, Error> {
         // Get or create `project.dependencies`.
@@ -1543,6 +1657,25 @@ fn update_requirement(old: &mut Requirement, new: &Requirement, has_source: bool
     }
 }
 
+/// Relax a set of version specifiers by dropping upper bounds and exact pins, retaining (or
+/// introducing) lower bounds.
+fn relax_specifiers(specifiers: &VersionSpecifiers) -> VersionSpecifiers {
+    specifiers
+        .iter()
+        .filter_map(|specifier| match specifier.operator() {
+            Operator::GreaterThan
+            | Operator::GreaterThanEqual
+            | Operator::NotEqual
+            | Operator::NotEqualStar => Some(specifier.clone()),
+            Operator::Equal | Operator::EqualStar | Operator::TildeEqual => Some(
+                VersionSpecifier::greater_than_equal_version(specifier.version().clone()),
+            ),
+            Operator::ExactEqual | Operator::LessThan | Operator::LessThanEqual => None,
+        })
+        .unique()
+        .collect()
+}
+
 /// Removes all occurrences of dependencies with the given name from the given `deps` array.
 fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
     // Remove matching dependencies.
@@ -1750,10 +1883,16 @@ fn split_specifiers(req: &str) -> (&str, &str) {
 
 #[cfg(test)]
 mod test {
-    use super::{AddBoundsKind, reformat_array_multiline, split_specifiers};
+    use super::{
+        AddBoundsKind, DependencyTarget, PyProjectTomlMut, reformat_array_multiline,
+        relax_specifiers, split_specifiers,
+    };
+    use crate::pyproject::DependencyType;
     use std::str::FromStr;
     use toml_edit::DocumentMut;
-    use uv_pep440::Version;
+    use uv_normalize::{ExtraName, PackageName};
+    use uv_pep440::{Version, VersionSpecifiers};
+    use uv_pypi_types::ConflictItem;
 
     #[test]
     fn split() {
@@ -1927,4 +2066,112 @@ dependencies = [
             assert_eq!(actual, expected, "{version}");
         }
     }
+
+    #[test]
+    fn relax() {
+        let tests = [
+            (">=1.0, <2.0", ">=1.0"),
+            ("==1.0.0", ">=1.0.0"),
+            ("==1.*", ">=1"),
+            ("~=1.4", ">=1.4"),
+            (">1, !=1.5, <=2", ">1, !=1.5"),
+            ("<2", ""),
+            ("===1.0", ""),
+        ];
+
+        for (specifiers, expected) in tests {
+            let actual = relax_specifiers(&VersionSpecifiers::from_str(specifiers).unwrap());
+            assert_eq!(actual.to_string(), expected, "{specifiers}");
+        }
+    }
+
+    #[test]
+    fn relax_dependency() {
+        let mut toml = PyProjectTomlMut::from_toml(
+            r#"
+[project]
+name = "project"
+dependencies = [
+    "anyio>=3,<4 ; python_version >= '3.12'",
+    "idna",
+]
+
+[project.optional-dependencies]
+test = ["anyio==3.7.0"]
+"#,
+            DependencyTarget::PyProjectToml,
+        )
+        .unwrap();
+
+        let anyio = PackageName::from_str("anyio").unwrap();
+        let relaxed = toml
+            .relax_dependency(&DependencyType::Production, &anyio)
+            .unwrap();
+        assert_eq!(relaxed.len(), 1);
+        assert_eq!(
+            relaxed[0].1.to_string(),
+            "anyio>=3 ; python_full_version >= '3.12'"
+        );
+
+        // `idna` has no bounds to relax, and `sniffio` isn't a dependency.
+        for name in ["idna", "sniffio"] {
+            let name = PackageName::from_str(name).unwrap();
+            assert!(
+                toml.relax_dependency(&DependencyType::Production, &name)
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+
+        let extra = ExtraName::from_str("test").unwrap();
+        toml.relax_dependency(&DependencyType::Optional(extra), &anyio)
+            .unwrap();
+
+        insta::assert_snapshot!(toml.to_string(), @r#"
+
+        [project]
+        name = "project"
+        dependencies = [
+            "anyio>=3 ; python_full_version >= '3.12'",
+            "idna",
+        ]
+
+        [project.optional-dependencies]
+        test = ["anyio>=3.7.0"]
+        "#);
+    }
+
+    #[test]
+    fn add_conflict() {
+        let mut toml = PyProjectTomlMut::from_toml(
+            r#"
+[project]
+name = "project"
+"#,
+            DependencyTarget::PyProjectToml,
+        )
+        .unwrap();
+
+        let project = PackageName::from_str("project").unwrap();
+        let child = PackageName::from_str("child").unwrap();
+        toml.add_conflict(
+            &project,
+            &[
+                ConflictItem::from((project.clone(), ExtraName::from_str("cpu").unwrap())),
+                ConflictItem::from((child, ExtraName::from_str("gpu").unwrap())),
+            ],
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(toml.to_string(), @r#"
+
+        [project]
+        name = "project"
+
+        [tool.uv]
+        conflicts = [
+            [{ extra = "cpu" }, { package = "child", extra = "gpu" }],
+        ]
+        "#);
+    }
 }
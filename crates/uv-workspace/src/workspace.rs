@@ -2079,6 +2079,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "hooks": null,
+                      "sbom": null,
                       "build-backend": null
                     }
                   },
@@ -2180,6 +2182,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "hooks": null,
+                      "sbom": null,
                       "build-backend": null
                     }
                   },
@@ -2394,6 +2398,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "hooks": null,
+                      "sbom": null,
                       "build-backend": null
                     }
                   },
@@ -2504,6 +2510,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "hooks": null,
+                      "sbom": null,
                       "build-backend": null
                     }
                   },
@@ -2627,6 +2635,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "hooks": null,
+                      "sbom": null,
                       "build-backend": null
                     }
                   },
@@ -2724,6 +2734,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "hooks": null,
+                      "sbom": null,
                       "build-backend": null
                     }
                   },
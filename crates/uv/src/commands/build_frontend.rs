@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::ffi::OsString;
 use std::fmt::Write as _;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
@@ -6,7 +7,9 @@ use std::str::FromStr;
 use std::{fmt, io};
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 use tracing::instrument;
 
@@ -16,6 +19,7 @@ use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildKind, BuildOptions, BuildOutput, Concurrency, Constraints,
     DependencyGroupsWithDefaults, HashCheckingMode, IndexStrategy, KeyringProviderType, NoSources,
+    TargetTriple,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
@@ -30,21 +34,25 @@ use uv_fs::{Simplified, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_preview::Preview;
+use uv_platform_tags::{Arch, Os, Platform, Tags, TagsError};
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
     VersionRequest,
 };
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildStack, HashStrategy};
+use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::ExitStatus;
-use crate::commands::pip::operations;
+use crate::commands::build_sbom::{BuildEnvironment, write_build_sbom};
+use crate::commands::pip::{operations, resolution_tags};
 use crate::commands::project::{ProjectError, find_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
@@ -76,6 +84,8 @@ enum Error {
     BuildFrontend(#[from] uv_build_frontend::Error),
     #[error(transparent)]
     Project(#[from] ProjectError),
+    #[error(transparent)]
+    Tags(#[from] TagsError),
     #[error("Failed to write message")]
     Fmt(#[from] fmt::Error),
     #[error("Can't use `--force-pep517` with `--list`")]
@@ -93,6 +103,22 @@ enum Error {
     InvalidBuiltWheelFilename(#[source] uv_distribution_filename::WheelFilenameError),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error(
+        "The built wheel `{0}` is not compatible with the target platform (`{os}` on `{arch}`)",
+        os = .1.os(),
+        arch = .1.arch()
+    )]
+    IncompatibleWheel(String, Platform),
+    #[error(
+        "The built distribution `{filename}` failed the checks:\n- {}",
+        failures.iter().join("\n- ")
+    )]
+    ArtifactCheck {
+        filename: String,
+        failures: Vec<uv_build_backend::ArtifactCheckError>,
+    },
+    #[error("Failed to write an SBOM of the build environment")]
+    BuildSbom(#[source] anyhow::Error),
 }
 
 /// Build source distributions and wheels.
@@ -110,9 +136,13 @@ pub(crate) async fn build_frontend(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check: bool,
+    sbom: bool,
+    record_sdist: bool,
     build_constraints: Vec<RequirementsSource>,
     hash_checking: Option<HashCheckingMode>,
     python: Option<String>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -124,6 +154,13 @@ pub(crate) async fn build_frontend(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if sbom && !preview.is_enabled(PreviewFeature::SbomExport) {
+        warn_user_once!(
+            "`uv build --sbom` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::SbomExport
+        );
+    }
+
     let build_result = build_impl(
         project_dir,
         src.as_deref(),
@@ -137,9 +174,13 @@ pub(crate) async fn build_frontend(
         gitignore,
         force_pep517,
         clear,
+        check,
+        sbom,
+        record_sdist,
         &build_constraints,
         hash_checking,
         python.as_deref(),
+        python_platform,
         install_mirrors,
         settings,
         client_builder,
@@ -184,9 +225,13 @@ async fn build_impl(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check: bool,
+    sbom: bool,
+    record_sdist: bool,
     build_constraints: &[RequirementsSource],
     hash_checking: Option<HashCheckingMode>,
     python_request: Option<&str>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -200,6 +245,7 @@ async fn build_impl(
 ) -> Result<BuildResult> {
     // Extract the resolver settings.
     let ResolverSettings {
+        build_constraints: _,
         index_locations,
         index_strategy,
         keyring_provider,
@@ -338,6 +384,7 @@ async fn build_impl(
             source.clone(),
             output_dir,
             python_request,
+            python_platform,
             install_mirrors.clone(),
             no_config,
             workspace.as_ref(),
@@ -352,6 +399,9 @@ async fn build_impl(
             gitignore,
             force_pep517,
             clear,
+            check,
+            sbom,
+            record_sdist,
             build_constraints,
             build_isolation,
             extra_build_dependencies,
@@ -398,7 +448,12 @@ async fn build_impl(
                     help: Option<String>,
                 }
 
-                let help = if let Error::Extract(uv_extract::Error::Tar(err)) = &err {
+                let help = if let Error::IncompatibleWheel(..) = &err {
+                    Some(
+                        "The build backend may not support building for other platforms, or may require a cross-compiler (e.g., set `CC` via `extra-build-variables`)"
+                            .to_string(),
+                    )
+                } else if let Error::Extract(uv_extract::Error::Tar(err)) = &err {
                     // TODO(konsti): astral-tokio-tar should use a proper error instead of
                     // encoding everything in strings
                     // NOTE(ww): We check for both messages below because the both indicate
@@ -447,6 +502,7 @@ async fn build_package(
     source: AnnotatedSource<'_>,
     output_dir: Option<&Path>,
     python_request: Option<&str>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     no_config: bool,
     workspace: Result<&Workspace, &WorkspaceError>,
@@ -461,6 +517,9 @@ async fn build_package(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check: bool,
+    sbom: bool,
+    record_sdist: bool,
     build_constraints: &[RequirementsSource],
     build_isolation: &BuildIsolation,
     extra_build_dependencies: &ExtraBuildDependencies,
@@ -544,6 +603,11 @@ async fn build_package(
     .await?
     .into_interpreter();
 
+    // If targeting another platform, determine the tags that the built wheels must match.
+    let target = python_platform
+        .map(|python_platform| BuildTarget::new(python_platform, &interpreter))
+        .transpose()?;
+
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
@@ -664,7 +728,7 @@ async fn build_package(
     let version_id = source.path().file_name().and_then(|name| name.to_str());
 
     let build_output = match printer {
-        Printer::Default | Printer::NoProgress | Printer::Verbose => {
+        Printer::Default | Printer::NoProgress | Printer::JsonProgress | Printer::Verbose => {
             if build_logs && !uv_flags::contains(uv_flags::EnvironmentFlags::HIDE_BUILD_OUTPUT) {
                 BuildOutput::Stderr
             } else {
@@ -693,6 +757,7 @@ async fn build_package(
                     subdirectory,
                     version_id,
                     build_output,
+                    false,
                 )
                 .await?;
                 build_results.push(sdist_list);
@@ -710,6 +775,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                sbom,
             )
             .await?;
             build_results.push(sdist_build.clone());
@@ -729,6 +795,12 @@ async fn build_package(
                 Err(err) => return Err(err.into()),
             };
 
+            let source_dist = if sbom || record_sdist {
+                Some(SourceDistDigest::from_path(&path).await?)
+            } else {
+                None
+            };
+
             let wheel_build = build_wheel(
                 &extracted,
                 &output_dir,
@@ -743,6 +815,10 @@ async fn build_package(
                 version_id,
                 build_output,
                 Some(sdist_build.normalized_filename().version()),
+                target.as_ref(),
+                source_dist.as_ref(),
+                record_sdist,
+                sbom,
                 preview,
             )
             .await?;
@@ -762,6 +838,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                sbom,
             )
             .await?;
             build_results.push(sdist_build);
@@ -781,6 +858,10 @@ async fn build_package(
                 version_id,
                 build_output,
                 None,
+                target.as_ref(),
+                None,
+                false,
+                sbom,
                 preview,
             )
             .await?;
@@ -800,6 +881,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                sbom,
             )
             .await?;
 
@@ -817,6 +899,10 @@ async fn build_package(
                 version_id,
                 build_output,
                 Some(sdist_build.normalized_filename().version()),
+                target.as_ref(),
+                None,
+                false,
+                sbom,
                 preview,
             )
             .await?;
@@ -840,6 +926,12 @@ async fn build_package(
                 .and_then(|filename| SourceDistFilename::parsed_normalized_filename(filename).ok())
                 .map(|filename| filename.version);
 
+            let source_dist = if sbom || record_sdist {
+                Some(SourceDistDigest::from_path(source.path()).await?)
+            } else {
+                None
+            };
+
             // Extract the top-level directory from the archive.
             let extracted = match uv_extract::strip_component(temp_dir.path()) {
                 Ok(top_level) => top_level,
@@ -861,6 +953,10 @@ async fn build_package(
                 version_id,
                 build_output,
                 version.as_ref(),
+                target.as_ref(),
+                source_dist.as_ref(),
+                record_sdist,
+                sbom,
                 preview,
             )
             .await?;
@@ -868,9 +964,49 @@ async fn build_package(
         }
     }
 
+    if check {
+        for build_result in &build_results {
+            check_distribution(build_result).await?;
+        }
+    }
+
     Ok(build_results)
 }
 
+/// Check a built distribution for common packaging problems.
+async fn check_distribution(build_message: &BuildMessage) -> Result<(), Error> {
+    let BuildMessage::Build {
+        normalized_filename,
+        raw_filename,
+        output_dir,
+        ..
+    } = build_message
+    else {
+        return Ok(());
+    };
+    let path = output_dir.join(raw_filename);
+    let source_date_epoch = std::env::var(EnvVars::SOURCE_DATE_EPOCH)
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok());
+    let is_wheel = matches!(normalized_filename, DistFilename::WheelFilename(_));
+    let failures = tokio::task::spawn_blocking(move || {
+        if is_wheel {
+            uv_build_backend::check_wheel(&path, source_date_epoch)
+        } else {
+            uv_build_backend::check_source_dist(&path, source_date_epoch)
+        }
+    })
+    .await??;
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::ArtifactCheck {
+            filename: raw_filename.clone(),
+            failures,
+        })
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 enum BuildAction {
     /// Only list the files that would be included, don't actually build.
@@ -909,8 +1045,11 @@ async fn build_sdist(
     subdirectory: Option<&Path>,
     version_id: Option<&str>,
     build_output: BuildOutput,
+    // Write an SBOM of the build environment
+    sbom: bool,
 ) -> Result<BuildMessage, Error> {
-    let build_result = match action {
+    let mut build_environment = None;
+    let mut build_result = match action {
         BuildAction::List => {
             let source_tree_ = source_tree.to_path_buf();
             let sources_enabled = sources.is_none();
@@ -941,6 +1080,9 @@ async fn build_sdist(
                 )
                 .bold()
             )?;
+            if sbom {
+                build_environment = Some(BuildEnvironment::uv_build());
+            }
             let source_tree = source_tree.to_path_buf();
             let output_dir_ = output_dir.to_path_buf();
             let sources_enabled = sources.is_none();
@@ -962,6 +1104,7 @@ async fn build_sdist(
                 ),
                 raw_filename: filename,
                 output_dir: output_dir.to_path_buf(),
+                sbom: None,
             }
         }
         BuildAction::Pep517 => {
@@ -989,6 +1132,10 @@ async fn build_sdist(
                 )
                 .await
                 .map_err(|err| Error::BuildDispatch(err.into()))?;
+            if sbom {
+                build_environment =
+                    Some(BuildEnvironment::from_source_build(&builder).map_err(Error::BuildSbom)?);
+            }
             let filename = builder.build(output_dir).await?;
             BuildMessage::Build {
                 normalized_filename: DistFilename::SourceDistFilename(
@@ -997,9 +1144,15 @@ async fn build_sdist(
                 ),
                 raw_filename: filename,
                 output_dir: output_dir.to_path_buf(),
+                sbom: None,
             }
         }
     };
+    if let Some(environment) = build_environment {
+        build_result
+            .write_sbom(&environment, None)
+            .map_err(Error::BuildSbom)?;
+    }
     Ok(build_result)
 }
 
@@ -1021,9 +1174,18 @@ async fn build_wheel(
     build_output: BuildOutput,
     // Used for checking version consistency
     version: Option<&Version>,
+    // Used for cross-platform builds
+    target: Option<&BuildTarget>,
+    // The source distribution the wheel is built from, if any
+    source_dist: Option<&SourceDistDigest>,
+    // Record the source distribution in the wheel metadata
+    record_sdist: bool,
+    // Write an SBOM of the build environment
+    sbom: bool,
     preview: Preview,
 ) -> Result<BuildMessage, Error> {
-    let build_message = match action {
+    let mut build_environment = None;
+    let mut build_message = match action {
         BuildAction::List => {
             let source_tree_ = source_tree.to_path_buf();
             let sources_enabled = sources.is_none();
@@ -1055,6 +1217,9 @@ async fn build_wheel(
                 )
                 .bold()
             )?;
+            if sbom {
+                build_environment = Some(BuildEnvironment::uv_build());
+            }
             let source_tree = source_tree.to_path_buf();
             let output_dir_ = output_dir.to_path_buf();
             let sources_enabled = sources.is_none();
@@ -1075,6 +1240,7 @@ async fn build_wheel(
                 normalized_filename: DistFilename::WheelFilename(filename),
                 raw_filename,
                 output_dir: output_dir.to_path_buf(),
+                sbom: None,
             }
         }
         BuildAction::Pep517 => {
@@ -1102,6 +1268,15 @@ async fn build_wheel(
                 )
                 .await
                 .map_err(|err| Error::BuildDispatch(err.into()))?;
+            let builder = if let Some(target) = target {
+                builder.with_environment_variables(target.environment_variables())
+            } else {
+                builder
+            };
+            if sbom {
+                build_environment =
+                    Some(BuildEnvironment::from_source_build(&builder).map_err(Error::BuildSbom)?);
+            }
             let filename = builder.build(output_dir).await?;
             BuildMessage::Build {
                 normalized_filename: DistFilename::WheelFilename(
@@ -1109,6 +1284,7 @@ async fn build_wheel(
                 ),
                 raw_filename: filename,
                 output_dir: output_dir.to_path_buf(),
+                sbom: None,
             }
         }
     };
@@ -1118,9 +1294,124 @@ async fn build_wheel(
             return Err(Error::VersionMismatch(expected.clone(), actual.clone()));
         }
     }
+    if let Some(target) = target {
+        if let DistFilename::WheelFilename(filename) = build_message.normalized_filename() {
+            if !filename.is_compatible(&target.tags) {
+                return Err(Error::IncompatibleWheel(
+                    build_message.raw_filename().to_string(),
+                    target.platform.clone(),
+                ));
+            }
+        }
+    }
+    if record_sdist {
+        if let (Some(source_dist), BuildMessage::Build { raw_filename, .. }) =
+            (source_dist, &build_message)
+        {
+            let wheel_path = output_dir.join(raw_filename);
+            let source_dist = source_dist.clone();
+            tokio::task::spawn_blocking(move || {
+                uv_build_backend::record_source_distribution(
+                    &wheel_path,
+                    &source_dist.filename,
+                    &source_dist.sha256,
+                )
+            })
+            .await??;
+        }
+    }
+    if let Some(environment) = build_environment {
+        build_message
+            .write_sbom(&environment, source_dist)
+            .map_err(Error::BuildSbom)?;
+    }
     Ok(build_message)
 }
 
+/// The filename and hash of the source distribution a wheel was built from.
+#[derive(Debug, Clone)]
+pub(crate) struct SourceDistDigest {
+    pub(crate) filename: String,
+    /// The hex-encoded SHA-256 digest of the source distribution.
+    pub(crate) sha256: String,
+}
+
+impl SourceDistDigest {
+    async fn from_path(path: &Path) -> Result<Self, Error> {
+        let filename = path
+            .file_name()
+            .map(|filename| filename.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let digest = Sha256::digest(fs_err::tokio::read(path).await?);
+        Ok(Self {
+            filename,
+            sha256: format!("{digest:x}"),
+        })
+    }
+}
+
+/// The platform to build wheels for, when it differs from the platform of the build interpreter.
+struct BuildTarget {
+    platform: Platform,
+    /// The tags that any built wheel must be compatible with.
+    tags: Tags,
+}
+
+impl BuildTarget {
+    fn new(python_platform: TargetTriple, interpreter: &Interpreter) -> Result<Self, TagsError> {
+        let tags = resolution_tags(None, Some(&python_platform), interpreter)?.into_owned();
+        Ok(Self {
+            platform: python_platform.platform(),
+            tags,
+        })
+    }
+
+    /// The environment variables that instruct PEP 517 build backends to build for the target
+    /// platform, following the conventions of `cibuildwheel`.
+    ///
+    /// Setuptools (and other backends that rely on `sysconfig.get_platform()`) derive the wheel's
+    /// platform tag from `_PYTHON_HOST_PLATFORM`; compilers are configured via `ARCHFLAGS` and
+    /// `MACOSX_DEPLOYMENT_TARGET` on macOS, and via `VSCMD_ARG_TGT_ARCH` on Windows.
+    fn environment_variables(&self) -> Vec<(OsString, OsString)> {
+        let arch = self.platform.arch();
+        let mut variables = Vec::new();
+        match self.platform.os() {
+            Os::Macos { major, minor } => {
+                let arch = match arch {
+                    Arch::Aarch64 => "arm64",
+                    arch => arch.name(),
+                };
+                variables.push((
+                    EnvVars::_PYTHON_HOST_PLATFORM,
+                    format!("macosx-{major}.{minor}-{arch}"),
+                ));
+                variables.push((EnvVars::ARCHFLAGS, format!("-arch {arch}")));
+                variables.push((
+                    EnvVars::MACOSX_DEPLOYMENT_TARGET,
+                    format!("{major}.{minor}"),
+                ));
+            }
+            Os::Manylinux { .. } | Os::Musllinux { .. } => {
+                variables.push((EnvVars::_PYTHON_HOST_PLATFORM, format!("linux-{arch}")));
+            }
+            Os::Windows => {
+                let (platform, msvc_arch) = match arch {
+                    Arch::X86 => ("win32", "x86"),
+                    Arch::Aarch64 => ("win-arm64", "arm64"),
+                    _ => ("win-amd64", "x64"),
+                };
+                variables.push((EnvVars::_PYTHON_HOST_PLATFORM, platform.to_string()));
+                variables.push((EnvVars::VSCMD_ARG_TGT_ARCH, msvc_arch.to_string()));
+            }
+            _ => {}
+        }
+        variables
+            .into_iter()
+            .map(|(key, value)| (OsString::from(key), OsString::from(value)))
+            .collect()
+    }
+}
+
 /// Create the output directory and add a `.gitignore`.
 async fn prepare_output_directory(output_dir: &Path, gitignore: bool) -> Result<(), Error> {
     // Create the output directory.
@@ -1222,6 +1513,8 @@ enum BuildMessage {
         raw_filename: String,
         /// The location of the built distribution.
         output_dir: PathBuf,
+        /// The SBOM of the build environment, if requested.
+        sbom: Option<PathBuf>,
     },
     /// Show the list of files that would be included in a distribution.
     List {
@@ -1263,11 +1556,36 @@ impl BuildMessage {
         }
     }
 
+    /// Write an SBOM of the build environment next to the built distribution.
+    fn write_sbom(
+        &mut self,
+        environment: &BuildEnvironment,
+        source_dist: Option<&SourceDistDigest>,
+    ) -> Result<()> {
+        if let Self::Build {
+            normalized_filename,
+            raw_filename,
+            output_dir,
+            sbom,
+        } = self
+        {
+            *sbom = Some(write_build_sbom(
+                environment,
+                output_dir,
+                raw_filename,
+                normalized_filename,
+                source_dist,
+            )?);
+        }
+        Ok(())
+    }
+
     fn print(&self, printer: Printer) -> Result<()> {
         match self {
             Self::Build {
                 raw_filename,
                 output_dir,
+                sbom,
                 ..
             } => {
                 writeln!(
@@ -1275,6 +1593,13 @@ impl BuildMessage {
                     "Successfully built {}",
                     output_dir.join(raw_filename).user_display().bold().cyan()
                 )?;
+                if let Some(sbom) = sbom {
+                    writeln!(
+                        printer.stderr(),
+                        "Wrote build environment SBOM to {}",
+                        sbom.user_display().bold().cyan()
+                    )?;
+                }
             }
             Self::List {
                 raw_filename,
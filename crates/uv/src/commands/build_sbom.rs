@@ -0,0 +1,273 @@
+//! `CycloneDX` SBOMs describing the environment in which a distribution was built.
+//!
+//! Unlike `uv export --format cyclonedx1.5`, which describes the runtime dependencies of a
+//! project, these SBOMs describe the build backend, the packages in the build environment and the
+//! interpreter that produced a distribution.
+
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::Result;
+use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::property::{Properties, Property};
+use cyclonedx_bom::models::tool::{Tool, Tools};
+use cyclonedx_bom::prelude::{Bom, Component, Components, NormalizedString};
+use percent_encoding::{AsciiSet, CONTROLS, percent_encode};
+use sha2::{Digest, Sha256};
+
+use uv_build_frontend::SourceBuild;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::Name;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::commands::build_frontend::SourceDistDigest;
+
+/// Character set for percent-encoding purl components.
+const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'+')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'@')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The environment in which a distribution was built.
+#[derive(Debug)]
+pub(crate) struct BuildEnvironment {
+    /// The build backend, e.g., `setuptools.build_meta`.
+    backend: String,
+    /// The packages installed in the build environment.
+    packages: Vec<(PackageName, Version)>,
+    /// The implementation name and version of the interpreter running the build backend, if the
+    /// build used an interpreter.
+    interpreter: Option<(String, String)>,
+}
+
+impl BuildEnvironment {
+    /// Read the build environment of a PEP 517 build.
+    pub(crate) fn from_source_build(builder: &SourceBuild) -> Result<Self> {
+        let environment = builder.environment();
+        let mut packages = Vec::new();
+        for environment in std::iter::once(environment).chain(builder.requirements_environment()) {
+            let site_packages = SitePackages::from_environment(environment)?;
+            packages.extend(
+                site_packages
+                    .iter()
+                    .map(|dist| (dist.name().clone(), dist.version().clone())),
+            );
+        }
+        packages.sort();
+        packages.dedup();
+        let interpreter = environment.interpreter();
+        Ok(Self {
+            backend: builder.backend().to_string(),
+            packages,
+            interpreter: Some((
+                interpreter.implementation_name().to_string(),
+                interpreter.python_full_version().to_string(),
+            )),
+        })
+    }
+
+    /// The build environment of the uv build backend, which uv runs directly, without a Python
+    /// interpreter.
+    pub(crate) fn uv_build() -> Self {
+        Self {
+            backend: "uv_build".to_string(),
+            packages: vec![(
+                PackageName::from_str("uv-build").expect("`uv-build` is a valid package name"),
+                Version::from_str(uv_version::version()).expect("uv has a valid version"),
+            )],
+            interpreter: None,
+        }
+    }
+}
+
+/// Write a `CycloneDX` SBOM of the build environment next to the built distribution, as
+/// `<distribution>.build.cdx.json`.
+pub(crate) fn write_build_sbom(
+    environment: &BuildEnvironment,
+    output_dir: &Path,
+    raw_filename: &str,
+    filename: &DistFilename,
+    source_dist: Option<&SourceDistDigest>,
+) -> Result<PathBuf> {
+    let distribution = output_dir.join(raw_filename);
+    let digest = Sha256::digest(fs_err::read(&distribution)?);
+
+    let mut id_counter = 0;
+    let mut bom_ref = |name: &str, version: &str| {
+        id_counter += 1;
+        format!("{name}-{id_counter}@{version}")
+    };
+
+    // The built distribution is the subject of the SBOM.
+    let name = filename.name().as_str();
+    let version = filename.version().to_string();
+    let mut properties = vec![Property::new("uv:build:backend", &environment.backend)];
+    if let Some((implementation, version)) = &environment.interpreter {
+        properties.push(Property::new(
+            "uv:build:interpreter",
+            &format!("{implementation} {version}"),
+        ));
+    }
+    let root = Component {
+        hashes: Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue(format!("{digest:x}")),
+        }])),
+        properties: Some(Properties(properties)),
+        ..component(
+            Classification::Library,
+            name,
+            Some(&version),
+            bom_ref(name, &version),
+            Some(format!(
+                "pkg:pypi/{}@{}?file_name={}",
+                percent_encode(name.as_bytes(), PURL_ENCODE_SET),
+                percent_encode(version.as_bytes(), PURL_ENCODE_SET),
+                percent_encode(raw_filename.as_bytes(), PURL_ENCODE_SET)
+            )),
+        )
+    };
+
+    let mut components = environment
+        .packages
+        .iter()
+        .map(|(name, version)| {
+            let version = version.to_string();
+            component(
+                Classification::Library,
+                name.as_str(),
+                Some(&version),
+                bom_ref(name.as_str(), &version),
+                Some(format!(
+                    "pkg:pypi/{}@{}",
+                    percent_encode(name.as_str().as_bytes(), PURL_ENCODE_SET),
+                    percent_encode(version.as_bytes(), PURL_ENCODE_SET)
+                )),
+            )
+        })
+        .collect::<Vec<_>>();
+    // A wheel built from a source distribution, which is the input of the build.
+    if let Some(source_dist) = source_dist {
+        components.push(Component {
+            hashes: Some(Hashes(vec![Hash {
+                alg: HashAlgorithm::SHA_256,
+                content: HashValue(source_dist.sha256.clone()),
+            }])),
+            properties: Some(Properties(vec![Property::new(
+                "uv:build:source-distribution",
+                "true",
+            )])),
+            ..component(
+                Classification::Library,
+                name,
+                Some(&version),
+                bom_ref(name, &version),
+                Some(format!(
+                    "pkg:pypi/{}@{}?file_name={}",
+                    percent_encode(name.as_bytes(), PURL_ENCODE_SET),
+                    percent_encode(version.as_bytes(), PURL_ENCODE_SET),
+                    percent_encode(source_dist.filename.as_bytes(), PURL_ENCODE_SET)
+                )),
+            )
+        });
+    }
+    if let Some((implementation, version)) = &environment.interpreter {
+        components.push(component(
+            Classification::Platform,
+            implementation,
+            Some(version),
+            bom_ref(implementation, version),
+            None,
+        ));
+    }
+
+    // The distribution was built with everything in the build environment.
+    let dependencies = vec![Dependency {
+        dependency_ref: root.bom_ref.clone().expect("bom-ref should always exist"),
+        dependencies: components
+            .iter()
+            .map(|component| {
+                component
+                    .bom_ref
+                    .clone()
+                    .expect("bom-ref should always exist")
+            })
+            .collect(),
+    }];
+
+    let bom = Bom {
+        metadata: Some(Metadata {
+            component: Some(root),
+            timestamp: cyclonedx_bom::prelude::DateTime::now().ok(),
+            tools: Some(Tools::List(vec![Tool {
+                vendor: Some(NormalizedString::new("Astral Software Inc.")),
+                name: Some(NormalizedString::new("uv")),
+                version: Some(NormalizedString::new(uv_version::version())),
+                hashes: None,
+                external_references: None,
+            }])),
+            ..Metadata::default()
+        }),
+        components: Some(Components(components)),
+        dependencies: Some(Dependencies(dependencies)),
+        ..Bom::default()
+    };
+
+    let path = output_dir.join(format!("{raw_filename}.build.cdx.json"));
+    let mut writer = BufWriter::new(fs_err::File::create(&path)?);
+    bom.output_as_json_v1_5(&mut writer)?;
+    Ok(path)
+}
+
+/// Create a [`Component`] with only the identifying fields set.
+fn component(
+    component_type: Classification,
+    name: &str,
+    version: Option<&str>,
+    bom_ref: String,
+    purl: Option<String>,
+) -> Component {
+    Component {
+        component_type,
+        name: NormalizedString::new(name),
+        version: version.map(NormalizedString::new),
+        bom_ref: Some(bom_ref),
+        purl: purl.and_then(|purl| purl.parse().ok()),
+        mime_type: None,
+        supplier: None,
+        author: None,
+        publisher: None,
+        group: None,
+        description: None,
+        scope: None,
+        hashes: None,
+        licenses: None,
+        copyright: None,
+        cpe: None,
+        swid: None,
+        modified: None,
+        pedigree: None,
+        external_references: None,
+        properties: None,
+        components: None,
+        evidence: None,
+        signature: None,
+        model_card: None,
+        data: None,
+    }
+}
@@ -13,9 +13,12 @@ use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Clear the cache, removing all entries or those linked to specific packages.
+///
+/// If `built_only` is set, only the wheels built from source distributions are removed.
 pub(crate) async fn cache_clean(
     packages: &[PackageName],
     force: bool,
+    built_only: bool,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -43,7 +46,23 @@ pub(crate) async fn cache_clean(
         }
     };
 
-    let summary = if packages.is_empty() {
+    let summary = if built_only {
+        writeln!(
+            printer.stderr(),
+            "Clearing built wheels from cache at: {}",
+            cache.root().user_display().cyan()
+        )?;
+
+        if packages.is_empty() {
+            cache.remove_built_wheels(None)?
+        } else {
+            let mut summary = Removal::default();
+            for package in packages {
+                summary += cache.remove_built_wheels(Some(package))?;
+            }
+            summary
+        }
+    } else if packages.is_empty() {
         writeln!(
             printer.stderr(),
             "Clearing cache at: {}",
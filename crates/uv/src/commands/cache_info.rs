@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::CacheInfoFormat;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Display a breakdown of the cache size, by bucket and by package.
+pub(crate) fn cache_info(
+    cache: &Cache,
+    format: CacheInfoFormat,
+    top: usize,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheInfo) {
+        warn_user!(
+            "`uv cache info` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheInfo
+        );
+    }
+
+    let usage = cache.usage()?;
+
+    let report = CacheInfoReport {
+        root: cache.root().simplified_display().to_string(),
+        total_bytes: usage.total_bytes,
+        buckets: usage
+            .buckets
+            .iter()
+            .map(|(bucket, bytes)| BucketReport {
+                name: bucket.name(),
+                path: cache.bucket(*bucket).simplified_display().to_string(),
+                bytes: *bytes,
+            })
+            .collect(),
+        packages: usage
+            .packages
+            .into_iter()
+            .take(top)
+            .map(|(name, bytes)| PackageReport { name, bytes })
+            .collect(),
+    };
+
+    match format {
+        CacheInfoFormat::Json => {
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+        CacheInfoFormat::Text => {
+            let (bytes, unit) = human_readable_bytes(report.total_bytes);
+            writeln!(
+                printer.stdout_important(),
+                "{}: {}",
+                "Cache directory".bold(),
+                report.root.cyan()
+            )?;
+            writeln!(
+                printer.stdout_important(),
+                "{}: {bytes:.1}{unit}",
+                "Total size".bold()
+            )?;
+
+            writeln!(printer.stdout_important())?;
+            writeln!(printer.stdout_important(), "{}:", "Buckets".bold())?;
+            let width = report
+                .buckets
+                .iter()
+                .map(|bucket| bucket.name.len())
+                .max()
+                .unwrap_or(0);
+            for bucket in &report.buckets {
+                let (bytes, unit) = human_readable_bytes(bucket.bytes);
+                writeln!(
+                    printer.stdout_important(),
+                    "  {:width$}  {bytes:>7.1}{unit}",
+                    bucket.name
+                )?;
+            }
+
+            if !report.packages.is_empty() {
+                writeln!(printer.stdout_important())?;
+                writeln!(printer.stdout_important(), "{}:", "Packages".bold())?;
+                let width = report
+                    .packages
+                    .iter()
+                    .map(|package| package.name.as_str().len())
+                    .max()
+                    .unwrap_or(0);
+                for package in &report.packages {
+                    let (bytes, unit) = human_readable_bytes(package.bytes);
+                    writeln!(
+                        printer.stdout_important(),
+                        "  {:width$}  {bytes:>7.1}{unit}",
+                        package.name.as_str()
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+#[derive(Debug, Serialize)]
+struct CacheInfoReport {
+    /// The root of the cache.
+    root: String,
+    /// The total size of the cache, in bytes.
+    total_bytes: u64,
+    /// The size of each cache bucket.
+    buckets: Vec<BucketReport>,
+    /// The packages that occupy the most space in the cache, from largest to smallest.
+    packages: Vec<PackageReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct BucketReport {
+    /// The kind of data stored in the bucket (e.g., `wheels`).
+    name: &'static str,
+    /// The path to the bucket.
+    path: String,
+    /// The size of the bucket, in bytes.
+    bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageReport {
+    /// The name of the package.
+    name: PackageName,
+    /// The size attributed to the package, in bytes.
+    bytes: u64,
+}
@@ -5,6 +5,7 @@ use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_cache::{Cache, Removal};
+use uv_configuration::{CacheMaxAge, CacheMaxSize};
 use uv_fs::Simplified;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
@@ -13,6 +14,8 @@ use crate::printer::Printer;
 /// Prune all unreachable objects from the cache.
 pub(crate) async fn cache_prune(
     ci: bool,
+    max_age: Option<CacheMaxAge>,
+    max_size: Option<CacheMaxSize>,
     force: bool,
     cache: Cache,
     printer: Printer,
@@ -58,6 +61,14 @@ pub(crate) async fn cache_prune(
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Evict any entries that exceed the age or size limits.
+    summary += cache
+        .evict(
+            max_age.map(CacheMaxAge::as_duration),
+            max_size.map(CacheMaxSize::as_bytes),
+        )
+        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -92,3 +103,40 @@ pub(crate) async fn cache_prune(
 
     Ok(ExitStatus::Success)
 }
+
+/// Evict stale entries from the cache, if the cache hasn't been evicted within the last day.
+///
+/// Eviction is skipped if the cache is in use by another uv process. Any errors are logged,
+/// rather than surfaced, since the eviction is incidental to the current command.
+pub(crate) fn cache_auto_prune(
+    cache: &Cache,
+    max_age: Option<CacheMaxAge>,
+    max_size: Option<CacheMaxSize>,
+) {
+    if !cache.root().exists() {
+        return;
+    }
+
+    let Ok(cache) = cache.clone().with_exclusive_lock_no_wait() else {
+        debug!("Skipping automatic cache pruning, since the cache is in use");
+        return;
+    };
+
+    match cache.evict_periodically(
+        max_age.map(CacheMaxAge::as_duration),
+        max_size.map(CacheMaxSize::as_bytes),
+    ) {
+        Ok(Some(summary)) => {
+            debug!(
+                "Automatically pruned {} files ({} bytes) from the cache",
+                summary.num_files, summary.total_bytes
+            );
+        }
+        Ok(None) => {
+            debug!("Skipping automatic cache pruning, since the cache was pruned recently");
+        }
+        Err(err) => {
+            debug!("Failed to automatically prune the cache: {err}");
+        }
+    }
+}
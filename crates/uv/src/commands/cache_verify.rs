@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Verify the integrity of the unzipped wheels in the cache, quarantining any corrupt entries.
+pub(crate) async fn cache_verify(
+    dry_run: bool,
+    force: bool,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheVerify) {
+        warn_user!(
+            "`uv cache verify` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheVerify
+        );
+    }
+
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let cache = match cache.with_exclusive_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) if force => {
+            debug!("Cache is currently in use, proceeding due to `--force`");
+            cache
+        }
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently in-use, waiting for other uv processes to finish (use `--force` to override)"
+            )?;
+            cache.with_exclusive_lock().await?
+        }
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let archives = cache
+        .archives()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    let mut corrupt = 0;
+    for id in &archives {
+        let path = cache.archive(id);
+        let Err(err) = uv_install_wheel::verify_wheel(&path) else {
+            continue;
+        };
+        corrupt += 1;
+
+        if dry_run {
+            writeln!(
+                printer.stderr(),
+                "Found corrupt entry {}: {err}",
+                path.user_display().cyan()
+            )?;
+        } else {
+            let target = cache.quarantine(id).with_context(|| {
+                format!("Failed to quarantine cache entry: {}", path.user_display())
+            })?;
+            writeln!(
+                printer.stderr(),
+                "Quarantined corrupt entry {} to {}: {err}",
+                path.user_display().cyan(),
+                target.user_display().cyan()
+            )?;
+        }
+    }
+
+    let s = if archives.len() == 1 { "y" } else { "ies" };
+    match corrupt {
+        0 => {
+            writeln!(
+                printer.stderr(),
+                "Verified {} entr{s}; no corruption found",
+                archives.len()
+            )?;
+            Ok(ExitStatus::Success)
+        }
+        _ if dry_run => {
+            writeln!(
+                printer.stderr(),
+                "Verified {} entr{s}; found {} corrupt",
+                archives.len(),
+                corrupt.to_string().red()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
+        _ => {
+            writeln!(
+                printer.stderr(),
+                "Verified {} entr{s}; quarantined {} corrupt (to be replaced on next use)",
+                archives.len(),
+                corrupt.to_string().red()
+            )?;
+            Ok(ExitStatus::Success)
+        }
+    }
+}
@@ -0,0 +1,527 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use clap::CommandFactory;
+use owo_colors::OwoColorize;
+
+use uv_cli::Cli;
+use uv_dirs::{system_config_file, user_uv_config_dir};
+use uv_fs::Simplified;
+use uv_options_metadata::{OptionEntry, OptionsMetadata};
+use uv_preview::{Preview, PreviewFeature};
+use uv_settings::{ConfigLevel, Options, validate_config};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Where a configuration file was passed or discovered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Source {
+    /// The file passed with `--config-file`, which replaces the user and system configuration.
+    ConfigFile,
+    Level(ConfigLevel),
+}
+
+/// A configuration file, with the `[tool.uv]` table extracted from a `pyproject.toml`.
+#[derive(Debug)]
+struct ConfigFile {
+    path: PathBuf,
+    source: Source,
+    settings: Vec<(String, toml::Value)>,
+}
+
+/// The origin of a setting.
+#[derive(Debug)]
+enum Origin<'a> {
+    File(&'a Path),
+    Env(&'a str),
+}
+
+impl std::fmt::Display for Origin<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file:{}", path.user_display()),
+            Self::Env(var) => write!(f, "env:{var}"),
+        }
+    }
+}
+
+/// The configuration files and environment variables that uv reads settings from.
+#[derive(Debug)]
+struct Sources {
+    /// The configuration files, from lowest to highest precedence.
+    files: Vec<ConfigFile>,
+    /// The settings set via environment variables.
+    env: Vec<(String, String, toml::Value)>,
+    /// The settings locked by the system configuration.
+    locked: Vec<String>,
+}
+
+impl Sources {
+    async fn read(project_dir: &Path, config_file: Option<&Path>, no_config: bool) -> Result<Self> {
+        let mut files = Vec::new();
+        let system = system_config_file()
+            .map(|path| ConfigFile::read(&path, Source::Level(ConfigLevel::System)))
+            .transpose()?;
+        if config_file.is_some() || no_config {
+            // Settings locked by the system configuration still apply.
+            if let Some(mut system) = system {
+                let locked = Self::locked_settings(&system);
+                system.settings.retain(|(key, _)| {
+                    key == "locked-settings" || Self::matches_locked(&locked, key)
+                });
+                files.push(system);
+            }
+            if let Some(config_file) = config_file {
+                files.push(ConfigFile::read(config_file, Source::ConfigFile)?);
+            }
+        } else {
+            files.extend(system);
+            if let Some(path) = user_uv_config_dir().map(|dir| dir.join("uv.toml")) {
+                if path.is_file() {
+                    files.push(ConfigFile::read(&path, Source::Level(ConfigLevel::User))?);
+                }
+            }
+            if let Some(path) = find_project_config(project_dir).await? {
+                files.push(ConfigFile::read(
+                    &path,
+                    Source::Level(ConfigLevel::Project),
+                )?);
+            }
+        }
+
+        let locked = files
+            .iter()
+            .filter(|file| file.source == Source::Level(ConfigLevel::System))
+            .flat_map(Self::locked_settings)
+            .collect();
+
+        Ok(Self {
+            files,
+            env: env_settings(),
+            locked,
+        })
+    }
+
+    /// The settings locked by the given system configuration file.
+    fn locked_settings(system: &ConfigFile) -> Vec<String> {
+        system
+            .settings
+            .iter()
+            .filter(|(key, _)| key == "locked-settings")
+            .filter_map(|(_, value)| value.as_array())
+            .flatten()
+            .filter_map(|value| value.as_str().map(ToString::to_string))
+            .collect()
+    }
+
+    /// Returns `true` if the setting is one of the `locked` settings, or nested within one.
+    fn matches_locked(locked: &[String], key: &str) -> bool {
+        locked.iter().any(|locked| {
+            key == locked
+                || key
+                    .strip_prefix(locked.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+
+    /// Returns `true` if the setting is locked by the system configuration.
+    fn is_locked(&self, key: &str) -> bool {
+        Self::matches_locked(&self.locked, key)
+    }
+
+    /// Returns `true` if the setting in the given file is ignored, since it's locked by the system
+    /// configuration.
+    fn is_ignored(&self, file: &ConfigFile, key: &str) -> bool {
+        matches!(
+            file.source,
+            Source::ConfigFile | Source::Level(ConfigLevel::User | ConfigLevel::Project)
+        ) && self.is_locked(key)
+    }
+}
+
+impl ConfigFile {
+    fn read(path: &Path, source: Source) -> Result<Self> {
+        let content = fs_err::read_to_string(path)?;
+        let mut table = toml::from_str::<toml::Table>(&content)
+            .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+        if is_pyproject(path) {
+            table = match table
+                .remove("tool")
+                .and_then(|tool| tool.as_table()?.get("uv").cloned())
+            {
+                Some(toml::Value::Table(uv)) => uv,
+                _ => toml::Table::new(),
+            };
+        }
+        let mut settings = Vec::new();
+        flatten("", table, &mut settings);
+        Ok(Self {
+            path: path.to_path_buf(),
+            source,
+            settings,
+        })
+    }
+}
+
+/// Show the effective value of a setting.
+pub(crate) async fn get(
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    no_config: bool,
+    key: &str,
+    show_origin: bool,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    warn_preview("get", preview);
+    validate_key(key)?;
+
+    let sources = Sources::read(project_dir, config_file, no_config).await?;
+
+    // Environment variables take precedence over all configuration files, unless the setting is
+    // locked by the system configuration (in which case, the environment variable is rejected).
+    if let Some((_, var, value)) = sources
+        .env
+        .iter()
+        .find(|(name, ..)| name == key && !sources.is_locked(name))
+    {
+        let origin = Origin::Env(var);
+        print_value(printer, show_origin.then_some(&origin), value)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Otherwise, read from the configuration files, from highest to lowest precedence.
+    let values = sources
+        .files
+        .iter()
+        .rev()
+        .filter(|file| !sources.is_ignored(file, key))
+        .flat_map(|file| {
+            file.settings
+                .iter()
+                .filter(|(name, _)| name == key)
+                .map(|(_, value)| (Origin::File(&file.path), value))
+        })
+        .collect::<Vec<_>>();
+
+    let Some((origin, value)) = values.first() else {
+        return Ok(ExitStatus::Failure);
+    };
+
+    if value.is_array() {
+        // Lists are combined across configuration files, with higher-precedence values first.
+        if show_origin {
+            for (origin, value) in &values {
+                print_value(printer, Some(origin), value)?;
+            }
+        } else {
+            let combined = values
+                .iter()
+                .filter_map(|(_, value)| value.as_array())
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>();
+            print_value(printer, None, &toml::Value::Array(combined))?;
+        }
+    } else {
+        print_value(printer, show_origin.then_some(origin), value)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the settings in each configuration file and environment variable.
+pub(crate) async fn list(
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    no_config: bool,
+    show_origin: bool,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    warn_preview("list", preview);
+
+    let sources = Sources::read(project_dir, config_file, no_config).await?;
+
+    for file in &sources.files {
+        let origin = Origin::File(&file.path);
+        for (key, value) in &file.settings {
+            let mut line = String::new();
+            if show_origin {
+                write!(line, "{origin}\t")?;
+            }
+            write!(line, "{key} = {value}")?;
+            if sources.is_ignored(file, key) {
+                write!(line, "  # ignored: locked by the system configuration")?;
+            }
+            writeln!(printer.stdout(), "{line}")?;
+        }
+    }
+
+    for (key, var, value) in &sources.env {
+        let mut line = String::new();
+        if show_origin {
+            write!(line, "{}\t", Origin::Env(var))?;
+        }
+        write!(line, "{key} = {value}")?;
+        if sources.is_locked(key) {
+            write!(line, "  # rejected: locked by the system configuration")?;
+        }
+        writeln!(printer.stdout(), "{line}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write a setting to a configuration file.
+pub(crate) async fn set(
+    project_dir: &Path,
+    key: &str,
+    value: &str,
+    level: ConfigLevel,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    warn_preview("set", preview);
+    validate_key(key)?;
+
+    let path = match level {
+        ConfigLevel::System => {
+            let Some(path) = system_config_file() else {
+                bail!(
+                    "No system configuration file found; create a `uv.toml` in the system configuration directory (e.g., `/etc/uv/uv.toml`) first"
+                );
+            };
+            path
+        }
+        ConfigLevel::User => {
+            let Some(dir) = user_uv_config_dir() else {
+                bail!("Failed to determine the user configuration directory");
+            };
+            dir.join("uv.toml")
+        }
+        ConfigLevel::Project => {
+            if let Some(path) = find_project_config(project_dir).await? {
+                path
+            } else {
+                let root = workspace_root(project_dir).await;
+                let pyproject = root.join("pyproject.toml");
+                if pyproject.is_file() {
+                    pyproject
+                } else {
+                    project_dir.join("uv.toml")
+                }
+            }
+        }
+    };
+
+    if level != ConfigLevel::System {
+        let sources = Sources::read(project_dir, None, false).await?;
+        if sources.is_locked(key) {
+            warn_user!(
+                "The `{}` setting is locked by the system configuration and will be ignored",
+                key.cyan()
+            );
+        }
+    }
+
+    // Parse the value as TOML, falling back to a string.
+    let mut value = toml_edit::Value::from_str(value).unwrap_or_else(|_| value.into());
+    value.decor_mut().clear();
+
+    let content = match fs_err::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+    let mut document = toml_edit::DocumentMut::from_str(&content)
+        .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+
+    let mut parts = if is_pyproject(&path) {
+        vec!["tool", "uv"]
+    } else {
+        vec![]
+    };
+    parts.extend(key.split('.'));
+    let (last, parents) = parts.split_last().expect("setting names are non-empty");
+
+    let mut table = document.as_table_mut() as &mut dyn toml_edit::TableLike;
+    for part in parents {
+        let item = table.entry(part).or_insert_with(|| {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        });
+        let Some(child) = item.as_table_like_mut() else {
+            bail!(
+                "Failed to set `{key}`: `{part}` is not a table in `{}`",
+                path.user_display()
+            );
+        };
+        table = child;
+    }
+    table.insert(last, toml_edit::Item::Value(value));
+
+    let content = document.to_string();
+    validate_config(&path, &content, level)?;
+
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    fs_err::write(&path, content)?;
+
+    writeln!(
+        printer.stderr(),
+        "Set `{}` in `{}`",
+        key.cyan(),
+        path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+fn warn_preview(command: &str, preview: Preview) {
+    if !preview.is_enabled(PreviewFeature::ConfigCommand) {
+        warn_user!(
+            "`uv config {command}` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::ConfigCommand
+        );
+    }
+}
+
+/// Ensure that the key refers to a setting (rather than, e.g., a table of settings).
+fn validate_key(key: &str) -> Result<()> {
+    match Options::metadata().find(key) {
+        Some(OptionEntry::Field(_)) => Ok(()),
+        Some(OptionEntry::Set(_)) => bail!(
+            "`{key}` is a table of settings; use a dotted name to refer to a setting, e.g., `{key}.index-url`"
+        ),
+        None => bail!("Unknown setting: `{key}`"),
+    }
+}
+
+fn print_value(printer: Printer, origin: Option<&Origin>, value: &toml::Value) -> Result<()> {
+    let value = match value {
+        toml::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    };
+    if let Some(origin) = origin {
+        writeln!(printer.stdout(), "{origin}\t{value}")?;
+    } else {
+        writeln!(printer.stdout(), "{value}")?;
+    }
+    Ok(())
+}
+
+fn is_pyproject(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "pyproject.toml")
+}
+
+/// Flatten a table of settings into dotted names, e.g., `pip.index-url`.
+fn flatten(prefix: &str, table: toml::Table, settings: &mut Vec<(String, toml::Value)>) {
+    for (key, value) in table {
+        let name = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::Table(table)
+                if matches!(Options::metadata().find(&name), Some(OptionEntry::Set(_))) =>
+            {
+                flatten(&name, table, settings);
+            }
+            value => settings.push((name, value)),
+        }
+    }
+}
+
+/// The root of the workspace containing the given directory, or the directory itself.
+async fn workspace_root(project_dir: &Path) -> PathBuf {
+    match Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(workspace) => workspace.install_path().clone(),
+        Err(_) => project_dir.to_path_buf(),
+    }
+}
+
+/// Find the project configuration file, i.e., the nearest `uv.toml` or `pyproject.toml` with a
+/// `[tool.uv]` section, starting at the workspace root.
+///
+/// Mirrors the discovery in [`uv_settings::FilesystemOptions::find`], such that the settings of a
+/// workspace member are ignored in favor of those of the workspace root.
+async fn find_project_config(project_dir: &Path) -> Result<Option<PathBuf>> {
+    let root = workspace_root(project_dir).await;
+    for ancestor in root.ancestors() {
+        let uv_toml = ancestor.join("uv.toml");
+        if uv_toml.is_file() {
+            return Ok(Some(uv_toml));
+        }
+        let pyproject = ancestor.join("pyproject.toml");
+        if let Ok(content) = fs_err::read_to_string(&pyproject) {
+            let Ok(table) = toml::from_str::<toml::Table>(&content) else {
+                continue;
+            };
+            if table.get("tool").and_then(|tool| tool.get("uv")).is_some() {
+                return Ok(Some(pyproject));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// The settings set via environment variables, as `(setting, variable, value)`.
+///
+/// The environment variable for each setting is read from the command-line argument of the same
+/// name, e.g., `UV_INDEX_URL` for `--index-url`.
+fn env_settings() -> Vec<(String, String, toml::Value)> {
+    fn collect(command: &clap::Command, vars: &mut BTreeMap<String, String>) {
+        for arg in command.get_arguments() {
+            if let (Some(long), Some(env)) = (arg.get_long(), arg.get_env()) {
+                if let Some(env) = env.to_str() {
+                    vars.entry(long.to_string())
+                        .or_insert_with(|| env.to_string());
+                }
+            }
+        }
+        for subcommand in command.get_subcommands() {
+            collect(subcommand, vars);
+        }
+    }
+
+    let mut vars = BTreeMap::new();
+    collect(&Cli::command(), &mut vars);
+
+    let metadata = Options::metadata();
+    let mut settings = Vec::new();
+    for (long, var) in vars {
+        let name = if matches!(metadata.find(&long), Some(OptionEntry::Field(_))) {
+            long
+        } else if matches!(
+            metadata.find(&format!("pip.{long}")),
+            Some(OptionEntry::Field(_))
+        ) {
+            format!("pip.{long}")
+        } else {
+            continue;
+        };
+        let Some(value) = std::env::var_os(&var).filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        let value = toml::Value::String(value.to_string_lossy().into_owned());
+        settings.push((name, var, value));
+    }
+    settings
+}
@@ -1,3 +1,4 @@
+use std::error::Error;
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
@@ -5,13 +6,16 @@ use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use version_ranges::Ranges;
 
+use uv_cli::ErrorFormat;
 use uv_distribution_types::{
     DerivationChain, DerivationStep, Dist, DistErrorKind, Name, RequestedDist,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_redacted::redact_output;
 use uv_resolver::SentinelRange;
 
+use crate::commands::error_code::{self, ErrorCode};
 use crate::commands::pip;
 
 static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::new(|| {
@@ -72,6 +76,48 @@ impl OperationDiagnostic {
     ///
     /// Returns `Some` if the error was not handled.
     pub(crate) fn report(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
+        let code = ErrorCode::from_operation(&err);
+        if error_code::format() == Some(ErrorFormat::Json) {
+            if !self.renders(&err) {
+                return Some(err);
+            }
+            error_code::report_json(
+                code,
+                std::iter::successors(Some(&err as &(dyn Error + 'static)), |err| (*err).source()),
+            );
+            return None;
+        }
+        let err = self.render(err);
+        if err.is_none() {
+            error_code::report_code(code);
+        }
+        err
+    }
+
+    /// Returns `true` if the error would be rendered with rich diagnostic context.
+    fn renders(&self, err: &pip::operations::Error) -> bool {
+        match err {
+            pip::operations::Error::Resolve(
+                uv_resolver::ResolveError::NoSolution(..)
+                | uv_resolver::ResolveError::Dist(..)
+                | uv_resolver::ResolveError::Dependencies(..),
+            )
+            | pip::operations::Error::Prepare(uv_installer::PrepareError::Dist(..))
+            | pip::operations::Error::OutdatedEnvironment => true,
+            pip::operations::Error::Requirements(err) => {
+                self.context.is_some() || matches!(err, uv_requirements::Error::Dist(..))
+            }
+            pip::operations::Error::Resolve(uv_resolver::ResolveError::Client(err)) => {
+                !self.native_tls && err.is_ssl()
+            }
+            _ => false,
+        }
+    }
+
+    /// Render an error with rich diagnostic context.
+    ///
+    /// Returns `Some` if the error was not handled.
+    fn render(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
                 if let Some(context) = self.context {
@@ -124,7 +170,7 @@ impl OperationDiagnostic {
                 if let Some(context) = self.context {
                     let err = miette::Report::msg(format!("{err}"))
                         .context(format!("Failed to resolve {context} requirement"));
-                    anstream::eprint!("{err:?}");
+                    anstream::eprint!("{}", redact_output(&format!("{err:?}")));
                     None
                 } else {
                     Some(pip::operations::Error::Requirements(err))
@@ -137,7 +183,7 @@ impl OperationDiagnostic {
                 None
             }
             pip::operations::Error::OutdatedEnvironment => {
-                anstream::eprintln!("{}", err);
+                anstream::eprintln!("{}", redact_output(&err.to_string()));
                 None
             }
             err => Some(err),
@@ -192,7 +238,7 @@ pub(crate) fn dist_error(
         cause,
         help,
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Render a requested distribution failure (read, download or build) with a help message.
@@ -242,7 +288,7 @@ pub(crate) fn requested_dist_error(
         cause,
         help,
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Render an error in fetching a package's dependencies.
@@ -292,19 +338,19 @@ pub(crate) fn dependencies_error(
         cause: error,
         help,
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`].
 pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
     let report = miette::Report::msg(format!("{err}")).context(err.header());
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with dedicated context.
 pub(crate) fn no_solution_context(err: &uv_resolver::NoSolutionError, context: &'static str) {
     let report = miette::Report::msg(format!("{err}")).context(err.header().with_context(context));
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
@@ -329,7 +375,7 @@ pub(crate) fn no_solution_hint(err: Box<uv_resolver::NoSolutionError>, help: Str
 
     let header = err.header();
     let report = miette::Report::new(Error { header, err, help });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
@@ -366,7 +412,7 @@ pub(crate) fn native_tls_hint(err: uv_client::Error) {
             "--native-tls".green()
         ),
     });
-    anstream::eprint!("{report:?}");
+    anstream::eprint!("{}", redact_output(&format!("{report:?}")));
 }
 
 /// Format a [`DerivationChain`] as a human-readable error message.
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use uv_cache::Cache;
+use uv_cli::EnvInfoFormat;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the interpreter, paths, and lockfile digest of the project environment.
+pub(crate) async fn env_info(
+    project_dir: &Path,
+    format: EnvInfoFormat,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::EnvInfo) {
+        warn_user!(
+            "`uv env info` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::EnvInfo
+        );
+    }
+
+    let workspace_cache = WorkspaceCache::default();
+    let workspace =
+        Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache).await?;
+
+    // Never create or sync the environment; consumers expect this command to be read-only.
+    let root = workspace.venv(None);
+    if !root.is_dir() {
+        bail!(
+            "No project environment found at `{}`; run `uv sync` to create it",
+            root.user_display().cyan()
+        );
+    }
+    let environment = PythonEnvironment::from_root(&root, cache)?;
+    let interpreter = environment.interpreter();
+
+    let lock_path = workspace.install_path().join("uv.lock");
+    let lock = if lock_path.is_file() {
+        let digest = Sha256::digest(fs_err::tokio::read(&lock_path).await?);
+        Some(LockReport {
+            path: lock_path.simplified_display().to_string(),
+            sha256: format!("{digest:x}"),
+        })
+    } else {
+        None
+    };
+
+    let root = environment.root().simplified_display().to_string();
+    let report = EnvInfoReport {
+        schema: SchemaReport::default(),
+        python: PythonReport {
+            executable: interpreter
+                .sys_executable()
+                .simplified_display()
+                .to_string(),
+            implementation: interpreter.implementation_name().to_string(),
+            version: interpreter.python_full_version().to_string(),
+        },
+        site_packages: interpreter
+            .site_packages()
+            .map(|path| path.simplified_display().to_string())
+            .collect(),
+        scripts: interpreter.scripts().simplified_display().to_string(),
+        env: BTreeMap::from([(EnvVars::VIRTUAL_ENV, root.clone())]),
+        root,
+        lock,
+    };
+
+    match format {
+        EnvInfoFormat::Json => {
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+        EnvInfoFormat::Text => {
+            writeln!(
+                printer.stdout_important(),
+                "{}: {}",
+                "Environment".bold(),
+                report.root.cyan()
+            )?;
+            writeln!(
+                printer.stdout_important(),
+                "{}: {} ({} {})",
+                "Python".bold(),
+                report.python.executable.cyan(),
+                report.python.implementation,
+                report.python.version
+            )?;
+            for path in &report.site_packages {
+                writeln!(
+                    printer.stdout_important(),
+                    "{}: {}",
+                    "Site packages".bold(),
+                    path.cyan()
+                )?;
+            }
+            writeln!(
+                printer.stdout_important(),
+                "{}: {}",
+                "Scripts".bold(),
+                report.scripts.cyan()
+            )?;
+            if let Some(lock) = &report.lock {
+                writeln!(
+                    printer.stdout_important(),
+                    "{}: {} (sha256:{})",
+                    "Lockfile".bold(),
+                    lock.path.cyan(),
+                    lock.sha256
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The schema version for the environment report.
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    /// An unstable, experimental schema.
+    #[default]
+    Preview,
+}
+
+/// The schema metadata for the environment report.
+#[derive(Serialize, Debug, Default)]
+struct SchemaReport {
+    /// The version of the schema.
+    version: SchemaVersion,
+}
+
+#[derive(Serialize, Debug)]
+struct EnvInfoReport {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The root of the environment.
+    root: String,
+    /// The interpreter of the environment.
+    python: PythonReport,
+    /// The directories from which the environment's packages are imported.
+    site_packages: Vec<String>,
+    /// The directory containing the environment's executables, to be prepended to `PATH`.
+    scripts: String,
+    /// The environment variables to set when running commands in the environment.
+    env: BTreeMap<&'static str, String>,
+    /// The project lockfile, if any.
+    lock: Option<LockReport>,
+}
+
+#[derive(Serialize, Debug)]
+struct PythonReport {
+    /// The path to the Python executable.
+    executable: String,
+    /// The name of the Python implementation (e.g., `cpython`).
+    implementation: String,
+    /// The full version of the interpreter.
+    version: String,
+}
+
+#[derive(Serialize, Debug)]
+struct LockReport {
+    /// The path to the lockfile.
+    path: String,
+    /// The SHA-256 digest of the lockfile contents.
+    sha256: String,
+}
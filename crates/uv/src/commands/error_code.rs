@@ -0,0 +1,297 @@
+//! Stable error codes, to allow CI systems and wrappers to branch on the class of a failure
+//! without matching on the error message.
+//!
+//! Codes are grouped by category: `UV1xxx` for resolution and locking, `UV2xxx` for
+//! distributions, `UV3xxx` for projects, `UV4xxx` for the network, and `UV5xxx` for Python
+//! interpreters. Once assigned, a code is never reused for a different class of failure.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::ErrorFormat;
+use uv_redacted::redact_output;
+use uv_warnings::locale::Message;
+use uv_workspace::WorkspaceError;
+
+use crate::commands::pip;
+use crate::commands::project::ProjectError;
+
+/// The format in which errors are reported with their error codes, if enabled.
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+/// Enable error codes, reporting errors in the given format.
+pub(crate) fn enable(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
+/// Return the format in which errors are reported with their error codes, or `None` if error
+/// codes are disabled.
+pub(crate) fn format() -> Option<ErrorFormat> {
+    ERROR_FORMAT.get().copied()
+}
+
+/// A stable code identifying a class of failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ErrorCode {
+    /// The requirements could not be satisfied.
+    ResolutionConflict,
+    /// The resolver failed for a reason other than unsatisfiable requirements.
+    ResolutionFailed,
+    /// The lockfile needs to be updated, but `--locked` or `--frozen` was provided.
+    LockfileOutdated,
+    /// The lockfile doesn't exist, but `--locked` or `--frozen` was provided.
+    LockfileMissing,
+    /// The lockfile could not be parsed.
+    LockfileInvalid,
+    /// A source distribution failed to build.
+    BuildFailed,
+    /// A distribution could not be found at its URL or path.
+    DistributionNotFound,
+    /// Hash-checking is enabled, but a distribution is missing hashes.
+    MissingHashes,
+    /// The hash of a distribution didn't match the expected hash.
+    HashMismatch,
+    /// No project was found in the current directory or any parent directory.
+    ProjectNotFound,
+    /// The project's `pyproject.toml` is invalid.
+    InvalidProject,
+    /// A network request failed.
+    NetworkError,
+    /// A TLS connection could not be established.
+    TlsError,
+    /// No Python interpreter satisfying the request was found.
+    PythonNotFound,
+    /// The Python interpreter is incompatible with the project or script.
+    PythonIncompatible,
+}
+
+impl ErrorCode {
+    /// The numeric code, e.g., `1101`.
+    pub(crate) fn number(self) -> u16 {
+        match self {
+            Self::ResolutionConflict => 1101,
+            Self::ResolutionFailed => 1102,
+            Self::LockfileOutdated => 1201,
+            Self::LockfileMissing => 1202,
+            Self::LockfileInvalid => 1203,
+            Self::BuildFailed => 2101,
+            Self::DistributionNotFound => 2201,
+            Self::MissingHashes => 2202,
+            Self::HashMismatch => 2203,
+            Self::ProjectNotFound => 3001,
+            Self::InvalidProject => 3002,
+            Self::NetworkError => 4001,
+            Self::TlsError => 4002,
+            Self::PythonNotFound => 5001,
+            Self::PythonIncompatible => 5002,
+        }
+    }
+
+    /// The name of the code, e.g., `ResolutionConflict`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::ResolutionConflict => "ResolutionConflict",
+            Self::ResolutionFailed => "ResolutionFailed",
+            Self::LockfileOutdated => "LockfileOutdated",
+            Self::LockfileMissing => "LockfileMissing",
+            Self::LockfileInvalid => "LockfileInvalid",
+            Self::BuildFailed => "BuildFailed",
+            Self::DistributionNotFound => "DistributionNotFound",
+            Self::MissingHashes => "MissingHashes",
+            Self::HashMismatch => "HashMismatch",
+            Self::ProjectNotFound => "ProjectNotFound",
+            Self::InvalidProject => "InvalidProject",
+            Self::NetworkError => "NetworkError",
+            Self::TlsError => "TlsError",
+            Self::PythonNotFound => "PythonNotFound",
+            Self::PythonIncompatible => "PythonIncompatible",
+        }
+    }
+
+    /// Classify an error by the first error in its chain with a known class.
+    pub(crate) fn from_error(err: &anyhow::Error) -> Option<Self> {
+        err.chain().find_map(Self::from_source)
+    }
+
+    /// Classify a single error, looking through transparent wrappers, which are skipped when
+    /// walking the chain of sources.
+    fn from_source(err: &(dyn Error + 'static)) -> Option<Self> {
+        if let Some(err) = err.downcast_ref::<ProjectError>() {
+            Self::from_project(err)
+        } else if let Some(err) = err.downcast_ref::<pip::operations::Error>() {
+            Self::from_operation(err)
+        } else if let Some(err) = err.downcast_ref::<uv_resolver::ResolveError>() {
+            Self::from_resolve(err)
+        } else if let Some(err) = err.downcast_ref::<uv_requirements::Error>() {
+            Self::from_requirements(err)
+        } else if let Some(err) = err.downcast_ref::<uv_distribution::Error>() {
+            Self::from_distribution(err)
+        } else if let Some(err) = err.downcast_ref::<WorkspaceError>() {
+            Self::from_workspace(err)
+        } else if let Some(err) = err.downcast_ref::<uv_python::Error>() {
+            Self::from_python(err)
+        } else {
+            err.downcast_ref::<uv_client::Error>()
+                .map(Self::from_client)
+        }
+    }
+
+    fn from_project(err: &ProjectError) -> Option<Self> {
+        match err {
+            ProjectError::LockMismatch(..) | ProjectError::LockWorkspaceMismatch(..) => {
+                Some(Self::LockfileOutdated)
+            }
+            ProjectError::MissingLockfile(..) => Some(Self::LockfileMissing),
+            ProjectError::UnsupportedLockVersion(..)
+            | ProjectError::UnparsableLockVersion(..)
+            | ProjectError::UvLockParse(..) => Some(Self::LockfileInvalid),
+            ProjectError::PyprojectTomlParse(..) => Some(Self::InvalidProject),
+            ProjectError::LockedPythonIncompatibility(..)
+            | ProjectError::RequestedPythonProjectIncompatibility(..)
+            | ProjectError::DotPythonVersionProjectIncompatibility(..)
+            | ProjectError::RequiresPythonProjectIncompatibility(..)
+            | ProjectError::RequestedPythonScriptIncompatibility(..)
+            | ProjectError::DotPythonVersionScriptIncompatibility(..)
+            | ProjectError::RequiresPythonScriptIncompatibility(..) => {
+                Some(Self::PythonIncompatible)
+            }
+            ProjectError::Operation(err) => Self::from_operation(err),
+            ProjectError::Requirements(err) => Self::from_requirements(err),
+            ProjectError::Workspace(err) => Self::from_workspace(err),
+            ProjectError::Python(err) => Self::from_python(err),
+            ProjectError::Client(err) => Some(Self::from_client(err)),
+            ProjectError::Anyhow(err) => Self::from_error(err),
+            _ => None,
+        }
+    }
+
+    /// Classify an error from an installation or resolution operation.
+    pub(crate) fn from_operation(err: &pip::operations::Error) -> Option<Self> {
+        match err {
+            pip::operations::Error::Resolve(err) => Self::from_resolve(err),
+            pip::operations::Error::Requirements(err) => Self::from_requirements(err),
+            pip::operations::Error::Prepare(uv_installer::PrepareError::Dist(.., err)) => {
+                Self::from_distribution(err)
+            }
+            pip::operations::Error::Anyhow(err) => Self::from_error(err),
+            _ => None,
+        }
+    }
+
+    fn from_resolve(err: &uv_resolver::ResolveError) -> Option<Self> {
+        match err {
+            uv_resolver::ResolveError::NoSolution(..) => Some(Self::ResolutionConflict),
+            uv_resolver::ResolveError::Dependencies(err, ..) => Self::from_resolve(err),
+            uv_resolver::ResolveError::Dist(.., err) => Self::from_distribution(err),
+            uv_resolver::ResolveError::Distribution(err) => Self::from_distribution(err),
+            uv_resolver::ResolveError::Client(err) => Some(Self::from_client(err)),
+            _ => Some(Self::ResolutionFailed),
+        }
+    }
+
+    fn from_requirements(err: &uv_requirements::Error) -> Option<Self> {
+        match err {
+            uv_requirements::Error::Dist(.., err) | uv_requirements::Error::Distribution(err) => {
+                Self::from_distribution(err)
+            }
+            _ => None,
+        }
+    }
+
+    fn from_distribution(err: &uv_distribution::Error) -> Option<Self> {
+        match err {
+            uv_distribution::Error::Build(..) => Some(Self::BuildFailed),
+            uv_distribution::Error::NotFound(..) => Some(Self::DistributionNotFound),
+            uv_distribution::Error::MissingHashes { .. }
+            | uv_distribution::Error::MissingActualHashes { .. }
+            | uv_distribution::Error::MissingExpectedHashes { .. } => Some(Self::MissingHashes),
+            uv_distribution::Error::MismatchedHashes { .. } => Some(Self::HashMismatch),
+            uv_distribution::Error::Client(err) => Some(Self::from_client(err)),
+            uv_distribution::Error::Reqwest(..) => Some(Self::NetworkError),
+            _ => None,
+        }
+    }
+
+    fn from_workspace(err: &WorkspaceError) -> Option<Self> {
+        match err {
+            WorkspaceError::MissingPyprojectToml | WorkspaceError::MissingProject(..) => {
+                Some(Self::ProjectNotFound)
+            }
+            WorkspaceError::Toml(..) => Some(Self::InvalidProject),
+            _ => None,
+        }
+    }
+
+    fn from_python(err: &uv_python::Error) -> Option<Self> {
+        match err {
+            uv_python::Error::MissingPython(..) => Some(Self::PythonNotFound),
+            _ => None,
+        }
+    }
+
+    fn from_client(err: &uv_client::Error) -> Self {
+        if err.is_ssl() {
+            Self::TlsError
+        } else {
+            Self::NetworkError
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UV{}", self.number())
+    }
+}
+
+/// An error, as reported with `--error-format json`.
+#[derive(Debug, Serialize)]
+struct ErrorReport<'a> {
+    /// The error code, e.g., `UV1101`.
+    code: Option<String>,
+    /// The name of the error code, e.g., `ResolutionConflict`.
+    name: Option<&'a str>,
+    /// The error message.
+    message: String,
+    /// The messages of the errors that caused this error, outermost first.
+    causes: Vec<String>,
+}
+
+/// Report the code of an error that was already displayed to the user, if error codes are enabled.
+pub(crate) fn report_code(code: Option<ErrorCode>) {
+    if format() == Some(ErrorFormat::Human)
+        && let Some(code) = code
+    {
+        anstream::eprintln!(
+            "  {}: {code} ({})",
+            Message::ErrorCode.text().bold(),
+            code.name()
+        );
+    }
+}
+
+/// Report an error as a single line of JSON on stderr.
+pub(crate) fn report_json<'a>(
+    code: Option<ErrorCode>,
+    chain: impl Iterator<Item = &'a (dyn Error + 'static)>,
+) {
+    // Strip any styling from the messages, which are rendered for a terminal.
+    let mut messages = chain.map(|err| {
+        let message = redact_output(&err.to_string()).into_owned();
+        anstream::adapter::strip_str(message.trim()).to_string()
+    });
+    let report = ErrorReport {
+        code: code.map(|code| code.to_string()),
+        name: code.map(ErrorCode::name),
+        message: messages.next().unwrap_or_default(),
+        causes: messages.collect(),
+    };
+    match serde_json::to_string(&report) {
+        Ok(report) => anstream::eprintln!("{report}"),
+        Err(err) => anstream::eprintln!("{err}"),
+    }
+}
@@ -0,0 +1,92 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, bail};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+use crate::child::run_to_completion;
+use crate::commands::ExitStatus;
+
+/// Find the executable for an external subcommand, e.g., `uv-foo` for `uv foo`.
+///
+/// Returns `None` if the subcommand name is not a plain name or if no matching executable exists
+/// on the `PATH`.
+pub(crate) fn find_external(subcommand: &str) -> Option<PathBuf> {
+    if subcommand.is_empty() || subcommand.starts_with('-') || subcommand.contains(['/', '\\']) {
+        return None;
+    }
+    which::which(format!("uv-{subcommand}")).ok()
+}
+
+/// Run an external subcommand, forwarding the remaining arguments.
+pub(crate) async fn external(executable: &Path, args: &[OsString]) -> anyhow::Result<ExitStatus> {
+    debug!(
+        "Running external subcommand: `{}`",
+        executable.user_display()
+    );
+
+    let handle = Command::new(executable)
+        .args(args)
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", executable.user_display()))?;
+
+    run_to_completion(handle).await
+}
+
+/// Find the executable for an external export format, e.g., `uv-export-foo` for
+/// `uv export --format ext:foo`.
+pub(crate) fn find_external_exporter(name: &str) -> Option<PathBuf> {
+    find_external(&format!("export-{name}"))
+}
+
+/// Run an external exporter in the given directory, writing the lockfile to its stdin and
+/// returning its stdout.
+///
+/// The exporter's stderr is inherited, so that its diagnostics are shown to the user.
+pub(crate) async fn external_export(
+    executable: &Path,
+    lock: &str,
+    cwd: &Path,
+) -> anyhow::Result<Vec<u8>> {
+    debug!("Running external exporter: `{}`", executable.user_display());
+
+    let mut child = Command::new(executable)
+        .current_dir(cwd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", executable.user_display()))?;
+
+    // Write the lockfile concurrently with reading the output, to avoid blocking on a full pipe.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let lock = lock.to_string();
+    let writer = tokio::spawn(async move {
+        stdin.write_all(lock.as_bytes()).await?;
+        stdin.shutdown().await
+    });
+
+    let output = child
+        .wait_with_output()
+        .await
+        .with_context(|| format!("Failed to run: `{}`", executable.user_display()))?;
+
+    // An exporter that exits without reading all of stdin is fine, as long as it succeeds.
+    if let Err(err) = writer.await? {
+        debug!("Failed to write the lockfile to the external exporter: {err}");
+    }
+
+    if !output.status.success() {
+        bail!(
+            "`{}` failed with {}",
+            executable.user_display(),
+            output.status
+        );
+    }
+
+    Ok(output.stdout)
+}
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use indoc::formatdoc;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_configuration::DependencyGroupsWithDefaults;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{
+    PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
+};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::find_requires_python;
+use crate::printer::Printer;
+
+/// The name of the service that hosts the development container in the Compose file.
+const COMPOSE_SERVICE: &str = "dev";
+
+/// The name of the volume that persists the uv cache across container rebuilds.
+const CACHE_VOLUME: &str = "uv-cache";
+
+/// The location of the uv cache in the uv images.
+const CACHE_DIR: &str = "/root/.cache/uv";
+
+/// The contents of a `devcontainer.json` file.
+///
+/// See: <https://containers.dev/implementors/json_reference/>
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DevcontainerJson<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docker_compose_file: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    service: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace_folder: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mounts: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    container_env: BTreeMap<&'static str, &'static str>,
+    post_create_command: &'a str,
+}
+
+/// Generate a development container configuration for the project.
+pub(crate) async fn devcontainer(
+    project_dir: &Path,
+    python: Option<String>,
+    compose: bool,
+    force: bool,
+    no_config: bool,
+    preview: Preview,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::GenerateDevcontainer) {
+        warn_user!(
+            "The `uv generate devcontainer` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::GenerateDevcontainer
+        );
+    }
+
+    let workspace_cache = WorkspaceCache::default();
+    let workspace =
+        Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache).await?;
+    let root = workspace.install_path();
+
+    let python_version = python_version(python, &workspace, no_config).await?;
+    let image = format!("ghcr.io/astral-sh/uv:python{python_version}-bookworm");
+    debug!("Using image `{image}` for the development container");
+
+    // Assert that the lockfile is up-to-date on creation, if the project is locked.
+    let post_create_command = if root.join("uv.lock").is_file() {
+        "uv sync --locked"
+    } else {
+        "uv sync"
+    };
+
+    let name = root
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("workspace");
+    let workspace_folder = format!("/workspaces/{name}");
+
+    let output_dir = root.join(".devcontainer");
+    let devcontainer_json = output_dir.join("devcontainer.json");
+    let compose_yaml = output_dir.join("compose.yaml");
+
+    let mut files: Vec<(PathBuf, String)> = Vec::new();
+    if compose {
+        let devcontainer = DevcontainerJson {
+            name,
+            image: None,
+            docker_compose_file: Some("compose.yaml"),
+            service: Some(COMPOSE_SERVICE),
+            workspace_folder: Some(&workspace_folder),
+            mounts: Vec::new(),
+            container_env: BTreeMap::new(),
+            post_create_command,
+        };
+        files.push((
+            devcontainer_json,
+            serde_json::to_string_pretty(&devcontainer)? + "\n",
+        ));
+        files.push((
+            compose_yaml,
+            formatdoc! {r"
+                services:
+                  {COMPOSE_SERVICE}:
+                    image: {image}
+                    command: sleep infinity
+                    environment:
+                      UV_LINK_MODE: copy
+                    volumes:
+                      - ..:{workspace_folder}:cached
+                      - {workspace_folder}/.venv
+                      - {CACHE_VOLUME}:{CACHE_DIR}
+
+                volumes:
+                  {CACHE_VOLUME}:
+            "},
+        ));
+    } else {
+        let devcontainer = DevcontainerJson {
+            name,
+            image: Some(image),
+            docker_compose_file: None,
+            service: None,
+            workspace_folder: None,
+            mounts: vec![
+                format!("source={CACHE_VOLUME},target={CACHE_DIR},type=volume"),
+                "target=${containerWorkspaceFolder}/.venv,type=volume".to_string(),
+            ],
+            // The cache and the project are on different volumes, so hard links are unavailable.
+            container_env: BTreeMap::from([("UV_LINK_MODE", "copy")]),
+            post_create_command,
+        };
+        files.push((
+            devcontainer_json,
+            serde_json::to_string_pretty(&devcontainer)? + "\n",
+        ));
+    }
+
+    if !force {
+        for (path, _) in &files {
+            if path.exists() {
+                bail!(
+                    "`{}` already exists; use `--force` to overwrite it",
+                    path.user_display()
+                );
+            }
+        }
+    }
+
+    fs_err::tokio::create_dir_all(&output_dir).await?;
+    for (path, contents) in files {
+        fs_err::tokio::write(&path, contents).await?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Generated development container for Python {} at `{}`",
+        python_version.cyan(),
+        output_dir.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Determine the `<major>.<minor>` Python version to use in the container image.
+async fn python_version(
+    python: Option<String>,
+    workspace: &Workspace,
+    no_config: bool,
+) -> Result<String> {
+    // (1) Explicit request from the user
+    if let Some(python) = python {
+        let request = PythonRequest::parse(&python);
+        let Some(version) = minor_version(&request) else {
+            bail!(
+                "`{python}` is not a supported Python version for a development container; provide a version like `3.12`"
+            );
+        };
+        return Ok(version);
+    }
+
+    // (2) Request from `.python-version`
+    if let Some(file) = PythonVersionFile::discover(
+        workspace.install_path(),
+        &VersionFileDiscoveryOptions::default()
+            .with_stop_discovery_at(Some(workspace.install_path().as_ref()))
+            .with_no_config(no_config),
+    )
+    .await?
+    {
+        if let Some(version) = file.version().and_then(minor_version) {
+            debug!(
+                "Using Python version `{version}` from `{}`",
+                file.path().user_display()
+            );
+            return Ok(version);
+        }
+    }
+
+    // (3) The lower bound of the workspace's `requires-python`
+    if let Some(requires_python) =
+        find_requires_python(workspace, &DependencyGroupsWithDefaults::none())?
+    {
+        if let Bound::Included(version) | Bound::Excluded(version) =
+            &**requires_python.range().lower()
+        {
+            if let [major, minor, ..] = &*version.release() {
+                debug!("Using Python version `{major}.{minor}` from `requires-python`");
+                return Ok(format!("{major}.{minor}"));
+            }
+        }
+    }
+
+    bail!(
+        "Unable to determine a Python version for the development container; provide one with `--python`, pin one with `uv python pin`, or set a lower bound in `requires-python`"
+    )
+}
+
+/// Return the `<major>.<minor>` version of a request, if it refers to a specific CPython version.
+fn minor_version(request: &PythonRequest) -> Option<String> {
+    match request {
+        PythonRequest::Version(
+            VersionRequest::MajorMinor(major, minor, PythonVariant::Default)
+            | VersionRequest::MajorMinorPatch(major, minor, _, PythonVariant::Default)
+            | VersionRequest::MajorMinorPrerelease(major, minor, _, PythonVariant::Default),
+        ) => Some(format!("{major}.{minor}")),
+        _ => None,
+    }
+}
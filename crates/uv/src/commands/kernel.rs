@@ -0,0 +1,251 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The package that provides the Python kernel.
+const IPYKERNEL: &str = "ipykernel";
+
+/// The contents of a `kernel.json` file.
+///
+/// See: <https://jupyter-client.readthedocs.io/en/stable/kernels.html#kernel-specs>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct KernelSpec {
+    argv: Vec<String>,
+    display_name: String,
+    language: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    env: BTreeMap<String, String>,
+    #[serde(default)]
+    metadata: KernelSpecMetadata,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+struct KernelSpecMetadata {
+    #[serde(default)]
+    debugger: bool,
+    /// Present if the kernel was installed by uv.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    uv: Option<UvKernelMetadata>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct UvKernelMetadata {
+    /// The root of the environment that the kernel runs in.
+    environment: PathBuf,
+    /// The display name requested by the user, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+}
+
+impl KernelSpec {
+    /// Create the kernelspec for an `ipykernel` kernel in the given environment.
+    fn new(name: &str, environment: &PythonEnvironment, display_name: Option<String>) -> Self {
+        let interpreter = environment.interpreter();
+        Self {
+            argv: vec![
+                interpreter.sys_executable().to_string_lossy().into_owned(),
+                "-m".to_string(),
+                "ipykernel_launcher".to_string(),
+                "-f".to_string(),
+                "{connection_file}".to_string(),
+            ],
+            display_name: display_name.clone().unwrap_or_else(|| {
+                format!(
+                    "{name} (Python {}.{})",
+                    interpreter.python_major(),
+                    interpreter.python_minor()
+                )
+            }),
+            language: "python".to_string(),
+            // Target the environment with uv commands run in the notebook, e.g., `!uv pip install`.
+            env: BTreeMap::from([(
+                EnvVars::VIRTUAL_ENV.to_string(),
+                environment.root().to_string_lossy().into_owned(),
+            )]),
+            metadata: KernelSpecMetadata {
+                debugger: true,
+                uv: Some(UvKernelMetadata {
+                    environment: environment.root().to_path_buf(),
+                    display_name,
+                }),
+            },
+        }
+    }
+
+    /// Write the kernelspec to the given kernel directory.
+    async fn write(&self, kernel_dir: &Path) -> Result<()> {
+        fs_err::tokio::create_dir_all(kernel_dir).await?;
+        fs_err::tokio::write(
+            kernel_dir.join("kernel.json"),
+            serde_json::to_string_pretty(self)? + "\n",
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Register the project environment as a Jupyter kernel.
+pub(crate) async fn install(
+    project_dir: &Path,
+    name: Option<String>,
+    display_name: Option<String>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::KernelInstall) {
+        warn_user!(
+            "`uv kernel install` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::KernelInstall
+        );
+    }
+
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    let name = match name {
+        Some(name) => name,
+        None => match project.project_name() {
+            Some(name) => name.to_string(),
+            None => bail!(
+                "Unable to determine a name for the kernel; provide one with `{}`",
+                "--name".green()
+            ),
+        },
+    };
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        bail!(
+            "`{name}` is not a valid kernel name; kernel names may only contain ASCII letters, digits, `-`, `_`, and `.`"
+        );
+    }
+
+    // Never create or sync the environment, as for `uv env info`.
+    let root = project.workspace().venv(None);
+    if !root.is_dir() {
+        bail!(
+            "No project environment found at `{}`; run `uv sync` to create it",
+            root.user_display().cyan()
+        );
+    }
+    let environment = PythonEnvironment::from_root(&root, cache)?;
+
+    if !has_ipykernel(&environment)? {
+        bail!(
+            "`{IPYKERNEL}` is not installed in the project environment; add it with `{}`",
+            format!("uv add --dev {IPYKERNEL}").green()
+        );
+    }
+
+    let Some(data_dir) = uv_dirs::user_jupyter_data_dir() else {
+        bail!("Unable to determine the Jupyter data directory; set `JUPYTER_DATA_DIR`");
+    };
+    let kernel_dir = data_dir.join("kernels").join(&name);
+
+    KernelSpec::new(&name, &environment, display_name)
+        .write(&kernel_dir)
+        .await?;
+
+    writeln!(
+        printer.stderr(),
+        "Installed kernel {} for `{}` at `{}`",
+        name.cyan(),
+        root.user_display().cyan(),
+        kernel_dir.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Update the kernels registered for the given environment by `uv kernel install`.
+///
+/// The kernelspecs are rewritten to match the environment's interpreter, and a warning is shown if
+/// `ipykernel` is no longer installed in the environment.
+pub(crate) async fn refresh(environment: &PythonEnvironment) -> Result<()> {
+    let Some(data_dir) = uv_dirs::user_jupyter_data_dir() else {
+        return Ok(());
+    };
+    let kernels_dir = data_dir.join("kernels");
+    let entries = match fs_err::read_dir(&kernels_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut registered = None;
+    for entry in entries {
+        let kernel_dir = entry?.path();
+        let Ok(contents) = fs_err::tokio::read(kernel_dir.join("kernel.json")).await else {
+            continue;
+        };
+        let spec = match serde_json::from_slice::<KernelSpec>(&contents) {
+            Ok(spec) => spec,
+            Err(err) => {
+                debug!(
+                    "Ignoring kernelspec at `{}`: {err}",
+                    kernel_dir.user_display()
+                );
+                continue;
+            }
+        };
+        let Some(metadata) = spec.metadata.uv.as_ref() else {
+            continue;
+        };
+        if metadata.environment != environment.root() {
+            continue;
+        }
+        let Some(name) = kernel_dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        let updated = KernelSpec::new(name, environment, metadata.display_name.clone());
+        if updated != spec {
+            debug!("Updating kernel `{name}` at: {}", kernel_dir.user_display());
+            updated.write(&kernel_dir).await?;
+        }
+        registered.get_or_insert_with(|| name.to_string());
+    }
+
+    if let Some(name) = registered {
+        if !has_ipykernel(environment)? {
+            warn_user_once!(
+                "The project environment is registered as the Jupyter kernel `{name}`, but `{IPYKERNEL}` is not installed; add it with `{}`",
+                format!("uv add --dev {IPYKERNEL}").green()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if `ipykernel` is installed in the environment.
+fn has_ipykernel(environment: &PythonEnvironment) -> Result<bool> {
+    let site_packages = SitePackages::from_environment(environment)?;
+    Ok(!site_packages
+        .get_packages(&PackageName::from_str(IPYKERNEL)?)
+        .is_empty())
+}
@@ -17,15 +17,24 @@ pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
-pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_info::cache_info;
+pub(crate) use cache_prune::{cache_auto_prune, cache_prune};
 pub(crate) use cache_size::cache_size;
+pub(crate) use cache_verify::cache_verify;
+pub(crate) use config::{get as config_get, list as config_list, set as config_set};
+pub(crate) use env_info::env_info;
+pub(crate) use external::{external, external_export, find_external, find_external_exporter};
+pub(crate) use generate::devcontainer::devcontainer as generate_devcontainer;
 pub(crate) use help::help;
+pub(crate) use kernel::install as kernel_install;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::download::pip_download;
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
 pub(crate) use pip::show::pip_show;
+pub(crate) use pip::size::pip_size;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
@@ -36,9 +45,10 @@ pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
-pub(crate) use project::sync::sync;
+pub(crate) use project::sync::{SyncWatcher, sync};
 pub(crate) use project::tree::tree;
 pub(crate) use project::version::{project_version, self_version};
+pub(crate) use project::warm::cache_warm;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
@@ -49,6 +59,9 @@ pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
+pub(crate) use sbom_audit::{OSV_URL, query_advisories, sbom_audit};
+pub(crate) use sbom_upload::sbom_upload;
+pub(crate) use sbom_verify::{VerifyTarget, sbom_verify};
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
@@ -63,6 +76,7 @@ use uv_cache::Cache;
 use uv_configuration::Concurrency;
 pub(crate) use uv_console::human_readable_bytes;
 use uv_fs::{CWD, Simplified};
+use uv_install_wheel::LinkMode;
 use uv_installer::compile_tree;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
@@ -77,17 +91,29 @@ use crate::printer::Printer;
 mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
+mod build_sbom;
 mod cache_clean;
 mod cache_dir;
+mod cache_info;
 mod cache_prune;
 mod cache_size;
+mod cache_verify;
+mod config;
 mod diagnostics;
+mod env_info;
+pub(crate) mod error_code;
+mod external;
+mod generate;
 mod help;
+mod kernel;
 pub(crate) mod pip;
 mod project;
 mod publish;
 mod python;
 pub(crate) mod reporters;
+mod sbom_audit;
+mod sbom_upload;
+mod sbom_verify;
 #[cfg(feature = "self-update")]
 mod self_update;
 mod tool;
@@ -109,17 +135,23 @@ pub(crate) enum ExitStatus {
     External(u8),
 }
 
-impl From<ExitStatus> for ExitCode {
+impl From<ExitStatus> for u8 {
     fn from(status: ExitStatus) -> Self {
         match status {
-            ExitStatus::Success => Self::from(0),
-            ExitStatus::Failure => Self::from(1),
-            ExitStatus::Error => Self::from(2),
-            ExitStatus::External(code) => Self::from(code),
+            ExitStatus::Success => 0,
+            ExitStatus::Failure => 1,
+            ExitStatus::Error => 2,
+            ExitStatus::External(code) => code,
         }
     }
 }
 
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        Self::from(u8::from(status))
+    }
+}
+
 /// Format a duration as a human-readable string, Cargo-style.
 pub(super) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
@@ -160,6 +192,7 @@ pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
     concurrency: &Concurrency,
     cache: &Cache,
+    link_mode: LinkMode,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
@@ -177,7 +210,8 @@ pub(super) async fn compile_bytecode(
             &site_packages,
             venv.python_executable(),
             concurrency,
-            cache.root(),
+            cache,
+            link_mode,
         )
         .await
         .with_context(|| {
@@ -22,8 +22,8 @@ use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequiresPython,
-    UnresolvedRequirementSpecification, Verbatim,
+    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequirementsHash,
+    RequiresPython, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -63,6 +63,7 @@ use crate::printer::Printer;
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_compile(
     requirements: &[RequirementsSource],
+    requirements_hashes: &[RequirementsHash],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     excludes: &[RequirementsSource],
@@ -226,6 +227,7 @@ pub(crate) async fn pip_compile(
         overrides,
         excludes,
         Some(&groups),
+        requirements_hashes,
         &client_builder,
     )
     .await?;
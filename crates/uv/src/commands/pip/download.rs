@@ -0,0 +1,532 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, anyhow};
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tokio::io::AsyncWriteExt;
+use tokio_util::io::StreamReader;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildIsolation, BuildOptions, Concurrency, Constraints, ExtrasSpecification, HashCheckingMode,
+    IndexStrategy, NoSources, Reinstall, Upgrade,
+};
+use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::LoweredExtraBuildDependencies;
+use uv_distribution_types::{
+    BuiltDist, ConfigSettings, DependencyMetadata, Dist, ExtraBuildVariables, HashPolicy, Index,
+    IndexLocations, Name, NameRequirementSpecification, Origin, PackageConfigSettings, Resolution,
+    ResolvedDist, SourceDist,
+};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_preview::Preview;
+use uv_pypi_types::{Conflicts, HashDigest};
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion,
+};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
+    ResolutionMode, ResolverEnvironment,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::WorkspaceCache;
+use uv_workspace::pyproject::ExtraBuildDependencies;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::operations::report_interpreter;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+
+/// Download packages into a directory, without installing them.
+pub(crate) async fn pip_download(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    extras: &ExtrasSpecification,
+    groups: &GroupsSpecification,
+    dest: &Path,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PrereleaseMode,
+    dependency_mode: DependencyMode,
+    upgrade: Upgrade,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    hash_checking: Option<HashCheckingMode>,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_isolation: BuildIsolation,
+    extra_build_dependencies: &ExtraBuildDependencies,
+    extra_build_variables: &ExtraBuildVariables,
+    build_options: BuildOptions,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    python_downloads: PythonDownloads,
+    install_mirrors: PythonInstallMirrors,
+    exclude_newer: ExcludeNewer,
+    sources: NoSources,
+    python: Option<String>,
+    system: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> anyhow::Result<ExitStatus> {
+    let client_builder = client_builder.clone().keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        excludes,
+        pylock,
+        source_trees,
+        groups,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+        extras: _,
+    } = operations::read_requirements(
+        requirements,
+        constraints,
+        overrides,
+        &[],
+        extras,
+        Some(groups),
+        &[],
+        &client_builder,
+    )
+    .await?;
+
+    if pylock.is_some() {
+        return Err(anyhow!(
+            "`uv pip download` does not support `pylock.toml` files"
+        ));
+    }
+
+    // Read build constraints.
+    let build_constraints: Vec<NameRequirementSpecification> =
+        operations::read_constraints(build_constraints, &client_builder).await?;
+
+    // Find an interpreter to use for resolution and for building distributions.
+    let python_request = python.as_deref().map(PythonRequest::parse);
+    let reporter = PythonDownloadReporter::single(printer);
+    let installation = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::from_system_flag(system, false),
+        python_preference.with_system_flag(system),
+        python_downloads,
+        &client_builder,
+        &cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+        preview,
+    )
+    .await?;
+    report_interpreter(&installation, true, printer)?;
+    let interpreter = installation.into_interpreter();
+
+    // Determine the markers and tags to use for the resolution.
+    let marker_env = resolution_markers(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    );
+    let tags = resolution_tags(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    )?;
+
+    // Determine the Python requirement, if the user requested a specific version.
+    let python_requirement = if let Some(python_version) = python_version.as_ref() {
+        PythonRequirement::from_python_version(&interpreter, python_version)
+    } else {
+        PythonRequirement::from_interpreter(&interpreter)
+    };
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options = build_options.combine(no_binary, no_build);
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+    };
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let types_build_isolation = match build_isolation {
+        BuildIsolation::Isolate => uv_types::BuildIsolation::Isolated,
+        BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::Shared(&environment)
+        }
+        BuildIsolation::SharedPackage(ref packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Don't enforce hashes for build dependencies, which are never downloaded.
+    let build_hasher = HashStrategy::None;
+    let build_constraints = Constraints::from_requirements(
+        build_constraints
+            .iter()
+            .map(|constraint| constraint.requirement.clone()),
+    );
+
+    // Lower the extra build dependencies, if any.
+    let extra_build_requires =
+        LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
+            .into_inner();
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        &build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state.clone(),
+        index_strategy,
+        config_settings,
+        config_settings_package,
+        types_build_isolation,
+        &extra_build_requires,
+        extra_build_variables,
+        LinkMode::default(),
+        &build_options,
+        &build_hasher,
+        exclude_newer.clone(),
+        sources,
+        WorkspaceCache::default(),
+        concurrency,
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .build_options(build_options.clone())
+        .build();
+
+    // Resolve the requirements.
+    let resolution = match operations::resolve(
+        requirements,
+        constraints,
+        overrides,
+        excludes,
+        source_trees,
+        project,
+        BTreeSet::default(),
+        extras,
+        &groups,
+        Vec::default(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::None,
+        &upgrade,
+        Some(&tags),
+        ResolverEnvironment::specific(marker_env),
+        python_requirement,
+        interpreter.markers(),
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        state.index(),
+        &build_dispatch,
+        concurrency,
+        options,
+        Box::new(DefaultResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok(graph) => Resolution::from(graph),
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    // Download the distributions into the destination directory.
+    let start = std::time::Instant::now();
+
+    fs_err::tokio::create_dir_all(dest).await?;
+
+    let downloads = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist.as_ref()),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .filter(|dist| match dist {
+            Dist::Source(SourceDist::Git(_)) => {
+                warn_user!(
+                    "Skipping `{}`, as Git dependencies cannot be downloaded",
+                    dist.name()
+                );
+                false
+            }
+            Dist::Source(SourceDist::Directory(_)) => {
+                debug!("Skipping local directory dependency: `{}`", dist.name());
+                false
+            }
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+
+    let filenames = futures::stream::iter(downloads)
+        .map(|dist| download(dist, &client, &hasher, dest))
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let s = if filenames.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Downloaded {} {}",
+            format!("{} package{s}", filenames.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    for filename in filenames.iter().sorted() {
+        writeln!(printer.stderr(), " {} {}", "+".green(), filename.bold())?;
+    }
+
+    // Notify the user of any resolution diagnostics.
+    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Download (or copy) the archive for a [`Dist`] into the destination directory, returning the
+/// filename of the archive.
+async fn download(
+    dist: &Dist,
+    client: &RegistryClient,
+    hasher: &HashStrategy,
+    dest: &Path,
+) -> anyhow::Result<String> {
+    let (location, filename) = match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => {
+            let wheel = wheels.best_wheel();
+            (
+                Location::Url(wheel.file.url.to_url()?),
+                wheel.filename.to_string(),
+            )
+        }
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => (
+            Location::Url((*wheel.location).clone()),
+            wheel.filename.to_string(),
+        ),
+        Dist::Built(BuiltDist::Path(wheel)) => (
+            Location::Path(wheel.install_path.to_path_buf()),
+            wheel.filename.to_string(),
+        ),
+        Dist::Source(SourceDist::Registry(sdist)) => (
+            Location::Url(sdist.file.url.to_url()?),
+            sdist.file.filename.to_string(),
+        ),
+        Dist::Source(SourceDist::DirectUrl(sdist)) => {
+            let filename = sdist
+                .location
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .filter(|segment| !segment.is_empty())
+                .ok_or_else(|| anyhow!("Failed to determine filename for `{}`", sdist.location))?
+                .to_string();
+            (Location::Url((*sdist.location).clone()), filename)
+        }
+        Dist::Source(SourceDist::Path(sdist)) => {
+            let filename = sdist
+                .install_path
+                .file_name()
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Failed to determine filename for `{}`",
+                        sdist.install_path.user_display()
+                    )
+                })?
+                .to_string_lossy()
+                .to_string();
+            (Location::Path(sdist.install_path.to_path_buf()), filename)
+        }
+        Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_)) => {
+            return Err(anyhow!("Distribution `{dist}` cannot be downloaded"));
+        }
+    };
+
+    // Local files (e.g., from a `--find-links` directory) are copied, rather than downloaded.
+    let location = match location {
+        Location::Url(url) if url.scheme() == "file" => Location::Path(
+            url.to_file_path()
+                .map_err(|()| anyhow!("Invalid file URL: `{url}`"))?,
+        ),
+        location => location,
+    };
+
+    let policy = hasher.get(dist);
+    let mut hashers = policy
+        .algorithms()
+        .into_iter()
+        .map(Hasher::from)
+        .collect::<Vec<_>>();
+
+    // Write to a temporary file, such that a failed or mismatched download never leaves a partial
+    // archive in the destination directory.
+    let (file, temp_path) = tempfile::NamedTempFile::new_in(dest)?.into_parts();
+    let mut writer = fs_err::tokio::File::from_std(fs_err::File::from_parts(file, &temp_path));
+
+    // Hash the archive as it's written, rather than reading it into memory.
+    match location {
+        Location::Url(url) => {
+            debug!("Downloading `{dist}` from: {url}");
+            let response = client
+                .uncached_client(&url)
+                .get(url::Url::from(url.clone()))
+                .header(
+                    "accept-encoding",
+                    reqwest::header::HeaderValue::from_static("identity"),
+                )
+                .send()
+                .await
+                .with_context(|| format!("Failed to download `{dist}` from: {url}"))?
+                .error_for_status()
+                .with_context(|| format!("Failed to download `{dist}` from: {url}"))?;
+            let reader = StreamReader::new(response.bytes_stream().map_err(std::io::Error::other));
+            let mut reader = HashReader::new(reader, &mut hashers);
+            tokio::io::copy(&mut reader, &mut writer)
+                .await
+                .with_context(|| format!("Failed to download `{dist}` from: {url}"))?;
+        }
+        Location::Path(path) => {
+            debug!("Copying `{dist}` from: {}", path.user_display());
+            let reader = fs_err::tokio::File::open(&path).await?;
+            let mut reader = HashReader::new(reader, &mut hashers);
+            tokio::io::copy(&mut reader, &mut writer)
+                .await
+                .with_context(|| {
+                    format!("Failed to copy `{dist}` from: {}", path.user_display())
+                })?;
+        }
+    }
+    writer.flush().await?;
+    drop(writer);
+
+    // Validate the hashes, if necessary.
+    if let HashPolicy::Validate(expected) = policy {
+        let computed = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+        if !computed.iter().any(|digest| expected.contains(digest)) {
+            return Err(anyhow!(
+                "Hash mismatch for `{dist}`\n\nExpected:\n{}\n\nComputed:\n{}",
+                expected
+                    .iter()
+                    .map(|digest| format!("  {digest}"))
+                    .join("\n"),
+                computed
+                    .iter()
+                    .map(|digest| format!("  {digest}"))
+                    .join("\n"),
+            ));
+        }
+    }
+
+    temp_path
+        .persist(dest.join(&filename))
+        .with_context(|| format!("Failed to write `{filename}` to: {}", dest.user_display()))?;
+
+    Ok(filename)
+}
+
+/// The location from which a distribution archive should be retrieved.
+enum Location {
+    Url(DisplaySafeUrl),
+    Path(PathBuf),
+}
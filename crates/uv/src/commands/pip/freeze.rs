@@ -1,5 +1,6 @@
 use std::fmt::Write;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -8,22 +9,31 @@ use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_distribution_types::{Diagnostic, InstalledDistKind, Name};
+use uv_configuration::PipFreezeFormat;
+use uv_distribution_types::{Diagnostic, InstalledDist, InstalledDistKind, Name};
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{DirectUrl, HashAlgorithm, HashDigest};
 use uv_python::PythonPreference;
 use uv_python::{EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, Target};
+use uv_resolver::cyclonedx_json;
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations::report_target_environment;
+use crate::commands::project::export::source_date_epoch;
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
     exclude: &FxHashSet<PackageName>,
+    format: PipFreezeFormat,
+    hashes: bool,
+    include_interpreter: bool,
     strict: bool,
     python: Option<&str>,
     system: bool,
@@ -80,7 +90,7 @@ pub(crate) fn pip_freeze(
         None => vec![SitePackages::from_environment(&environment)?],
     };
 
-    site_packages
+    let dists = site_packages
         .iter()
         .flat_map(uv_installer::SitePackages::iter)
         .filter(|dist| {
@@ -93,29 +103,105 @@ pub(crate) fn pip_freeze(
             true
         })
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
-        .map(|dist| match &dist.kind {
-            InstalledDistKind::Registry(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDistKind::Url(dist) => {
-                if dist.editable {
-                    format!("-e {}", dist.url)
-                } else {
-                    format!("{} @ {}", dist.name().bold(), dist.url)
+        .collect::<Vec<_>>();
+
+    let mut missing_hashes = Vec::new();
+    if let Some((version, encoding)) = format.cyclonedx() {
+        if !preview.is_enabled(PreviewFeature::SbomExport) {
+            warn_user!(
+                "`uv pip freeze --format={format}` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::SbomExport
+            );
+        }
+
+        // A distribution may be found in multiple `--path` directories, but is only recorded once.
+        let packages = dists
+            .iter()
+            .dedup_by(|a, b| a.name() == b.name() && a.version() == b.version())
+            .map(|dist| {
+                let digests = read_hashes(dist)?;
+                if hashes && digests.is_empty() && is_hashable(dist) {
+                    missing_hashes.push(dist.name().clone());
                 }
-            }
-            InstalledDistKind::EggInfoFile(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDistKind::EggInfoDirectory(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDistKind::LegacyEditable(dist) => {
-                format!("-e {}", dist.target.display())
-            }
-        })
-        .dedup()
-        .try_for_each(|dist| writeln!(printer.stdout_important(), "{dist}"))?;
+                Ok(cyclonedx_json::InstalledPackage {
+                    dist,
+                    metadata: dist.read_metadata()?,
+                    hashes: digests,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let bom = cyclonedx_json::from_installed(
+            &packages,
+            environment.interpreter(),
+            hashes,
+            include_interpreter,
+            source_date_epoch()?,
+        );
+        let mut output = Vec::new();
+        cyclonedx_json::write(bom, version, encoding, &mut output)?;
+        write!(
+            printer.stdout_important(),
+            "{}",
+            String::from_utf8_lossy(&output)
+        )?;
+    } else {
+        dists
+            .iter()
+            .map(|dist| {
+                let requirement = match &dist.kind {
+                    InstalledDistKind::Registry(dist) => {
+                        format!("{}=={}", dist.name().bold(), dist.version)
+                    }
+                    InstalledDistKind::Url(dist) => {
+                        if dist.editable {
+                            format!("-e {}", dist.url)
+                        } else {
+                            format!("{} @ {}", dist.name().bold(), dist.url)
+                        }
+                    }
+                    InstalledDistKind::EggInfoFile(dist) => {
+                        format!("{}=={}", dist.name().bold(), dist.version)
+                    }
+                    InstalledDistKind::EggInfoDirectory(dist) => {
+                        format!("{}=={}", dist.name().bold(), dist.version)
+                    }
+                    InstalledDistKind::LegacyEditable(dist) => {
+                        format!("-e {}", dist.target.display())
+                    }
+                };
+                if !hashes {
+                    return Ok(requirement);
+                }
+                let digests = read_hashes(dist)?;
+                if digests.is_empty() {
+                    if is_hashable(dist) {
+                        missing_hashes.push(dist.name().clone());
+                    }
+                    return Ok(requirement);
+                }
+                let mut requirement = requirement;
+                for digest in digests {
+                    write!(requirement, " \\\n    --hash={digest}")?;
+                }
+                Ok(requirement)
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .dedup()
+            .try_for_each(|dist| writeln!(printer.stdout_important(), "{dist}"))?;
+    }
+
+    if !missing_hashes.is_empty() {
+        missing_hashes.dedup();
+        warn_user!(
+            "No hashes were recorded at installation time for: {}",
+            missing_hashes
+                .iter()
+                .map(|name| format!("`{}`", name.cyan()))
+                .join(", ")
+        );
+    }
 
     // Validate that the environment is consistent.
     if strict {
@@ -138,3 +224,54 @@ pub(crate) fn pip_freeze(
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the archive hashes for an installed distribution.
+///
+/// Prefers the hashes recorded by uv at installation time, falling back to the hashes in
+/// `direct_url.json` (as written by other installers for archive URLs).
+pub(crate) fn read_hashes(dist: &InstalledDist) -> Result<Vec<HashDigest>> {
+    let path = match &dist.kind {
+        InstalledDistKind::Registry(dist) => &dist.path,
+        InstalledDistKind::Url(dist) => &dist.path,
+        InstalledDistKind::EggInfoFile(_)
+        | InstalledDistKind::EggInfoDirectory(_)
+        | InstalledDistKind::LegacyEditable(_) => return Ok(Vec::new()),
+    };
+
+    let hashes = InstalledDist::read_hashes(path)?;
+    if !hashes.is_empty() {
+        return Ok(hashes);
+    }
+
+    if let InstalledDistKind::Url(dist) = &dist.kind
+        && let DirectUrl::ArchiveUrl { archive_info, .. } = &*dist.direct_url
+        && let Some(hashes) = &archive_info.hashes
+    {
+        return hashes
+            .iter()
+            .map(|(algorithm, digest)| {
+                Ok(HashDigest {
+                    algorithm: HashAlgorithm::from_str(algorithm)?,
+                    digest: digest.as_str().into(),
+                })
+            })
+            .collect();
+    }
+
+    Ok(Vec::new())
+}
+
+/// Returns `true` if the distribution was installed from an archive, and so could have been
+/// installed with hash-checking enabled.
+fn is_hashable(dist: &InstalledDist) -> bool {
+    match &dist.kind {
+        InstalledDistKind::Registry(_) => true,
+        InstalledDistKind::Url(dist) => !matches!(
+            &*dist.direct_url,
+            DirectUrl::LocalDirectory { .. } | DirectUrl::VcsUrl { .. }
+        ),
+        InstalledDistKind::EggInfoFile(_)
+        | InstalledDistKind::EggInfoDirectory(_)
+        | InstalledDistKind::LegacyEditable(_) => false,
+    }
+}
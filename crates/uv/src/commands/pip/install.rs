@@ -16,8 +16,8 @@ use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
-    UnresolvedRequirementSpecification,
+    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequirementsHash,
+    Resolution, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
@@ -53,6 +53,7 @@ use crate::printer::Printer;
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_install(
     requirements: &[RequirementsSource],
+    requirements_hashes: &[RequirementsHash],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     excludes: &[RequirementsSource],
@@ -78,6 +79,7 @@ pub(crate) async fn pip_install(
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
+    scripts: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     build_isolation: BuildIsolation,
@@ -141,6 +143,7 @@ pub(crate) async fn pip_install(
         excludes,
         extras,
         Some(groups),
+        requirements_hashes,
         &client_builder,
     )
     .await?;
@@ -673,6 +676,7 @@ pub(crate) async fn pip_install(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        scripts,
         dry_run,
         printer,
         preview,
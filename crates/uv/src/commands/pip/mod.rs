@@ -7,6 +7,7 @@ use uv_python::{Interpreter, PythonVersion};
 
 pub(crate) mod check;
 pub(crate) mod compile;
+pub(crate) mod download;
 pub(crate) mod freeze;
 pub(crate) mod install;
 pub(crate) mod latest;
@@ -14,6 +15,7 @@ pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
 pub(crate) mod show;
+pub(crate) mod size;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
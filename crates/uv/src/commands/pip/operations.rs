@@ -6,12 +6,15 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::{Context, anyhow};
+use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use tokio::sync::Semaphore;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, RegistryClient};
+use uv_client::{AttestationStatus, BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, Excludes,
     ExtrasSpecification, Overrides, Reinstall, Upgrade,
@@ -19,8 +22,9 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, Diagnostic, Dist, InstalledDist, InstalledVersion, LocalDist,
-    NameRequirementSpecification, Requirement, ResolutionDiagnostic, UnresolvedRequirement,
+    BuiltDist, CachedDist, Diagnostic, Dist, HashPolicy, IndexCapabilities, IndexLocations,
+    IndexVerifyPolicy, InstalledDist, InstalledVersion, LocalDist, NameRequirementSpecification,
+    Requirement, RequirementsHash, ResolutionDiagnostic, SourceDist, UnresolvedRequirement,
     UnresolvedRequirementSpecification, VersionOrUrlRef,
 };
 use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
@@ -32,7 +36,7 @@ use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin, VerbatimUrl};
 use uv_platform_tags::Tags;
 use uv_preview::Preview;
-use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
+use uv_pypi_types::{Conflicts, HashAlgorithm, ResolverMarkerEnvironment};
 use uv_python::{PythonEnvironment, PythonInstallation};
 use uv_requirements::{
     GroupsSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
@@ -59,6 +63,7 @@ pub(crate) async fn read_requirements(
     excludes: &[RequirementsSource],
     extras: &ExtrasSpecification,
     groups: Option<&GroupsSpecification>,
+    requirements_hashes: &[RequirementsHash],
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<RequirementsSpecification, Error> {
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
@@ -85,6 +90,7 @@ pub(crate) async fn read_requirements(
         overrides,
         excludes,
         groups,
+        requirements_hashes,
         client_builder,
     )
     .await?)
@@ -95,11 +101,17 @@ pub(crate) async fn read_constraints(
     constraints: &[RequirementsSource],
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<Vec<NameRequirementSpecification>, Error> {
-    Ok(
-        RequirementsSpecification::from_sources(&[], constraints, &[], &[], None, client_builder)
-            .await?
-            .constraints,
+    Ok(RequirementsSpecification::from_sources(
+        &[],
+        constraints,
+        &[],
+        &[],
+        None,
+        &[],
+        client_builder,
     )
+    .await?
+    .constraints)
 }
 
 /// Resolve a set of requirements, similar to running `pip compile`.
@@ -549,6 +561,7 @@ pub(crate) async fn install(
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    scripts: bool,
     dry_run: DryRun,
     printer: Printer,
     preview: Preview,
@@ -611,6 +624,18 @@ pub(crate) async fn install(
         return Ok(Changelog::default());
     }
 
+    // Verify the attestations for any distributions we're about to download, binding each signed
+    // distribution to its attested digest.
+    let hasher = &verify_attestations(
+        &remote,
+        hasher,
+        build_dispatch.locations(),
+        client,
+        build_dispatch.capabilities(),
+        concurrency,
+    )
+    .await?;
+
     // Partition into two sets: those that require build isolation, and those that disable it. This
     // is effectively a heuristic to make `--no-build-isolation` work "more often" by way of giving
     // `--no-build-isolation` packages "access" to the rest of the environment.
@@ -646,6 +671,7 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            scripts,
             printer,
             preview,
         )
@@ -675,6 +701,7 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            scripts,
             printer,
             preview,
         )
@@ -684,7 +711,7 @@ pub(crate) async fn install(
     }
 
     if compile {
-        compile_bytecode(venv, &concurrency, cache, printer).await?;
+        compile_bytecode(venv, &concurrency, cache, link_mode, printer).await?;
     }
 
     // Construct a summary of the changes made to the environment.
@@ -696,6 +723,121 @@ pub(crate) async fn install(
     Ok(changelog)
 }
 
+/// Verify the [PEP 740](https://peps.python.org/pep-0740/) attestations for the given remote
+/// distributions, per the `verify` policy of the index that serves each distribution.
+///
+/// Returns the [`HashStrategy`] to use when downloading the distributions, such that each
+/// distribution with a signed attestation is validated against its attested digest.
+async fn verify_attestations(
+    remote: &[Arc<Dist>],
+    hasher: &HashStrategy,
+    locations: &IndexLocations,
+    client: &RegistryClient,
+    capabilities: &IndexCapabilities,
+    concurrency: Concurrency,
+) -> Result<HashStrategy, Error> {
+    if !locations.verifies_attestations() {
+        return Ok(hasher.clone());
+    }
+
+    let semaphore = Semaphore::new(concurrency.downloads);
+    let results = futures::stream::iter(remote.iter().filter_map(|dist| {
+        let (version, file, index) = match &**dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (&wheel.filename.version, &*wheel.file, &wheel.index)
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => {
+                (&sdist.version, &*sdist.file, &sdist.index)
+            }
+            _ => return None,
+        };
+        let policy = locations.verify_for(index);
+        if !policy.is_enabled() {
+            return None;
+        }
+        let semaphore = &semaphore;
+        Some(async move {
+            let status = client
+                .verify_attestations(dist.name(), version, file, index, capabilities, semaphore)
+                .await?;
+            Ok::<_, uv_client::Error>((&**dist, file, index, policy, status))
+        })
+    }))
+    .buffer_unordered(concurrency.downloads)
+    .try_collect::<Vec<_>>()
+    .await
+    .map_err(anyhow::Error::from)?;
+
+    let mut attested = FxHashMap::default();
+    for (dist, file, index, policy, status) in results {
+        let status = match status {
+            AttestationStatus::Signed(publisher) => {
+                let digest = file
+                    .hashes
+                    .iter()
+                    .find(|digest| digest.algorithm() == HashAlgorithm::Sha256)
+                    .cloned()
+                    .expect("signed distributions have a SHA-256 digest");
+                // If hashes were provided, the attested digest must be among them.
+                match hasher.get(dist) {
+                    HashPolicy::Validate(expected)
+                        if !expected.is_empty() && !expected.contains(&digest) =>
+                    {
+                        AttestationStatus::Invalid(format!(
+                            "the attested digest `{digest}` does not match the expected hashes"
+                        ))
+                    }
+                    _ => {
+                        debug!("Checked attestation signature for `{dist}` from {publisher}");
+                        attested.insert(dist.version_id(), vec![digest]);
+                        continue;
+                    }
+                }
+            }
+            status => status,
+        };
+
+        match (policy, status) {
+            (IndexVerifyPolicy::Require, AttestationStatus::Missing) => {
+                return Err(Error::MissingAttestation(
+                    dist.to_string(),
+                    index.without_credentials().to_string(),
+                ));
+            }
+            (IndexVerifyPolicy::Require, AttestationStatus::Invalid(reason)) => {
+                return Err(Error::InvalidAttestation(
+                    dist.to_string(),
+                    index.without_credentials().to_string(),
+                    reason,
+                ));
+            }
+            (_, AttestationStatus::Missing) => {
+                warn_user!(
+                    "No attestations were found for `{}` on {}",
+                    dist.cyan(),
+                    index.without_credentials().cyan()
+                );
+            }
+            (_, AttestationStatus::Invalid(reason)) => {
+                warn_user!(
+                    "Failed to verify attestations for `{}` from {}: {reason}",
+                    dist.cyan(),
+                    index.without_credentials().cyan()
+                );
+            }
+            (_, AttestationStatus::Signed(_)) => {}
+        }
+    }
+
+    // Bind each signed distribution to its attested digest, unless hashes were already provided
+    // (in which case they were checked against the attested digest above).
+    match hasher {
+        HashStrategy::None if !attested.is_empty() => Ok(HashStrategy::Verify(Arc::new(attested))),
+        _ => Ok(hasher.clone()),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum InstallPhase {
     /// A dedicated phase for building and installing packages with build-isolation disabled.
@@ -727,6 +869,7 @@ async fn execute_plan(
     venv: &PythonEnvironment,
     logger: &dyn InstallLogger,
     installer_metadata: bool,
+    scripts: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<(Vec<CachedDist>, Vec<InstalledDist>), Error> {
@@ -817,6 +960,7 @@ async fn execute_plan(
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
+            .with_scripts(scripts)
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
             ))
@@ -1083,4 +1227,10 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment,
+
+    #[error("No attestations were found for `{0}` on {1}, but the index requires them")]
+    MissingAttestation(String, String),
+
+    #[error("Failed to verify attestations for `{0}` from {1}: {2}")]
+    InvalidAttestation(String, String, String),
 }
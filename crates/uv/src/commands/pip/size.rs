@@ -0,0 +1,199 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cli::PipSizeFormat;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_warnings::warn_user;
+
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Show the disk usage of the installed packages.
+pub(crate) fn pip_size(
+    python: Option<&str>,
+    system: bool,
+    format: PipSizeFormat,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::PipSize) {
+        warn_user!(
+            "`uv pip size` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::PipSize
+        );
+    }
+
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        PythonPreference::default().with_system_flag(system),
+        cache,
+        preview,
+    )?;
+
+    report_target_environment(&environment, cache, printer)?;
+
+    let mut usage = EnvironmentUsage::new(cache)?;
+    for site_packages in environment.site_packages() {
+        usage.add(&site_packages)?;
+    }
+
+    let report = PipSizeReport {
+        path: environment.root().simplified_display().to_string(),
+        total_bytes: usage.total_bytes,
+        shared_bytes: usage.shared_bytes,
+        unique_bytes: usage.total_bytes - usage.shared_bytes,
+        bytecode_bytes: usage.bytecode_bytes,
+    };
+
+    match format {
+        PipSizeFormat::Json => {
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+        PipSizeFormat::Text => {
+            for (label, bytes) in [
+                ("Total size", report.total_bytes),
+                ("Shared with the cache", report.shared_bytes),
+                ("Unique to the environment", report.unique_bytes),
+                ("Bytecode", report.bytecode_bytes),
+            ] {
+                let (bytes, unit) = human_readable_bytes(bytes);
+                writeln!(
+                    printer.stdout_important(),
+                    "{}: {bytes:.1}{unit}",
+                    label.bold()
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The disk usage of a Python environment's installed packages.
+#[derive(Debug, Default)]
+struct EnvironmentUsage {
+    /// The total size of the installed files, in bytes.
+    total_bytes: u64,
+    /// The size of the installed files that are linked from the cache, in bytes.
+    shared_bytes: u64,
+    /// The size of the compiled bytecode, in bytes.
+    bytecode_bytes: u64,
+    /// The files in the cache's unzipped wheels and compiled bytecode, by device and inode.
+    #[cfg(unix)]
+    cached: rustc_hash::FxHashSet<(u64, u64)>,
+    /// The files that have already been counted, by device and inode.
+    #[cfg(unix)]
+    seen: rustc_hash::FxHashSet<(u64, u64)>,
+}
+
+impl EnvironmentUsage {
+    /// Create an empty [`EnvironmentUsage`], indexing the files in the cache's unzipped wheels and
+    /// compiled bytecode.
+    ///
+    /// A file is only considered shared if it's one of these files, rather than if it has multiple
+    /// links, as files may also be linked from outside the cache (e.g., by a backup tool).
+    #[cfg(unix)]
+    fn new(cache: &Cache) -> Result<Self, walkdir::Error> {
+        use std::os::unix::fs::MetadataExt;
+
+        let mut cached = rustc_hash::FxHashSet::default();
+        for bucket in [CacheBucket::Archive, CacheBucket::Bytecode] {
+            let bucket = cache.bucket(bucket);
+            if !bucket.is_dir() {
+                continue;
+            }
+            for entry in WalkDir::new(bucket) {
+                let entry = entry?;
+                if entry.file_type().is_file() {
+                    let metadata = entry.metadata()?;
+                    cached.insert((metadata.dev(), metadata.ino()));
+                }
+            }
+        }
+        Ok(Self {
+            cached,
+            ..Self::default()
+        })
+    }
+
+    /// Create an empty [`EnvironmentUsage`].
+    ///
+    /// Hard links can't be detected on other platforms, so the cache isn't indexed.
+    #[cfg(not(unix))]
+    #[expect(clippy::unnecessary_wraps)]
+    fn new(_cache: &Cache) -> Result<Self, walkdir::Error> {
+        Ok(Self::default())
+    }
+
+    /// Add the files in the given `site-packages` directory to the usage.
+    fn add(&mut self, site_packages: &Path) -> Result<(), walkdir::Error> {
+        if !site_packages.is_dir() {
+            return Ok(());
+        }
+        for entry in WalkDir::new(site_packages) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+
+            // Count each file once, even if it's linked multiple times within the environment.
+            #[cfg(unix)]
+            let shared = {
+                use std::os::unix::fs::MetadataExt;
+                let inode = (metadata.dev(), metadata.ino());
+                if !self.seen.insert(inode) {
+                    continue;
+                }
+                self.cached.contains(&inode)
+            };
+            // Hard links can't be detected on other platforms, so every file is considered unique.
+            #[cfg(not(unix))]
+            let shared = false;
+
+            let size = metadata.len();
+            self.total_bytes += size;
+            if shared {
+                self.shared_bytes += size;
+            }
+            if entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "pyc")
+            {
+                self.bytecode_bytes += size;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PipSizeReport {
+    /// The root of the environment.
+    path: String,
+    /// The total size of the installed files, in bytes.
+    total_bytes: u64,
+    /// The size of the installed files that are shared with the cache, in bytes.
+    shared_bytes: u64,
+    /// The size of the installed files that are unique to the environment, in bytes.
+    unique_bytes: u64,
+    /// The size of the compiled bytecode, in bytes.
+    bytecode_bytes: u64,
+}
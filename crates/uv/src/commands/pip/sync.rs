@@ -68,6 +68,7 @@ pub(crate) async fn pip_sync(
     client_builder: &BaseClientBuilder<'_>,
     allow_empty_requirements: bool,
     installer_metadata: bool,
+    scripts: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     build_isolation: BuildIsolation,
@@ -136,6 +137,7 @@ pub(crate) async fn pip_sync(
         excludes,
         extras,
         Some(groups),
+        &[],
         &client_builder,
     )
     .await?;
@@ -564,6 +566,7 @@ pub(crate) async fn pip_sync(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        scripts,
         dry_run,
         printer,
         preview,
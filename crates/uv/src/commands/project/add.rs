@@ -10,10 +10,12 @@ use anyhow::{Context, Result, bail};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::Serialize;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
 use uv_cache_key::RepositoryUrl;
+use uv_cli::OutputFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DevMode, DryRun,
@@ -25,15 +27,18 @@ use uv_distribution_types::{
     Index, IndexName, IndexUrl, IndexUrls, NameRequirementSpecification, Requirement,
     RequirementSource, UnresolvedRequirement, VersionId,
 };
-use uv_fs::{LockedFile, LockedFileError, Simplified};
+use uv_fs::{LockedFile, LockedFileError, PortablePathBuf, Simplified};
 use uv_git::GIT_STORE;
-use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, ExtraName, PackageName};
+use uv_normalize::{
+    DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, ExtraName, GroupName, PackageName,
+};
+use uv_pep440::Version;
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
-use uv_resolver::FlatIndex;
+use uv_resolver::{FlatIndex, Lock};
 use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
@@ -46,15 +51,17 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::fix;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::SchemaReport;
 use crate::commands::project::{
     PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter,
     UniversalState, default_dependency_groups, init_script_python_requirement,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
-use crate::commands::{ExitStatus, ScriptPath, diagnostics, project};
+use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip, project};
 use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, ResolverInstallerSettings};
 
@@ -64,8 +71,10 @@ pub(crate) async fn add(
     project_dir: &Path,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
+    interactive: bool,
     active: Option<bool>,
     no_sync: bool,
+    output_format: OutputFormat,
     no_install_project: bool,
     only_install_project: bool,
     no_install_workspace: bool,
@@ -110,6 +119,22 @@ pub(crate) async fn add(
         );
     }
 
+    if !preview.is_enabled(PreviewFeature::JsonOutput)
+        && matches!(output_format, OutputFormat::Json)
+    {
+        warn_user_once!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    if interactive && !preview.is_enabled(PreviewFeature::InteractiveResolution) {
+        warn_user_once!(
+            "The `--interactive` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::InteractiveResolution
+        );
+    }
+
     if !preview.is_enabled(PreviewFeature::ExtraBuildDependencies)
         && !settings.resolver.extra_build_dependencies.is_empty()
     {
@@ -325,6 +350,7 @@ pub(crate) async fn add(
                 no_sync,
                 no_config,
                 active,
+                false,
                 cache,
                 DryRun::Disabled,
                 printer,
@@ -360,6 +386,7 @@ pub(crate) async fn add(
         &[],
         &[],
         None,
+        &[],
         &client_builder,
     )
     .await?;
@@ -706,6 +733,14 @@ pub(crate) async fn add(
     // If `--frozen`, exit early. There's no reason to lock and sync, since we don't need a `uv.lock`
     // to exist at all.
     if frozen.is_some() {
+        if matches!(output_format, OutputFormat::Json) {
+            let report = AddReport::new(&target, &edits, None);
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
         return Ok(ExitStatus::Success);
     }
 
@@ -719,7 +754,7 @@ pub(crate) async fn add(
     };
 
     // Update the `pypackage.toml` in-memory.
-    let target = target.update(&content)?;
+    let mut target = target.update(&content)?;
 
     // Set the Ctrl-C handler to revert changes on exit.
     let _ = ctrlc::set_handler({
@@ -738,51 +773,109 @@ pub(crate) async fn add(
         }
     });
 
-    // Use separate state for locking and syncing.
-    let lock_state = state.fork();
-    let sync_state = state;
+    loop {
+        // Use separate state for locking and syncing.
+        let lock_state = state.fork();
+        let sync_state = state.clone();
+
+        let result = Box::pin(lock_and_sync(
+            target.clone(),
+            &mut toml,
+            &edits,
+            lock_state,
+            sync_state,
+            lock_check,
+            no_install_project,
+            only_install_project,
+            no_install_workspace,
+            only_install_workspace,
+            no_install_local,
+            only_install_local,
+            no_install_package.clone(),
+            only_install_package.clone(),
+            &defaulted_extras,
+            &defaulted_groups,
+            raw,
+            bounds,
+            dry_run,
+            constraints.clone(),
+            &settings,
+            &client_builder,
+            installer_metadata,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        ))
+        .await;
 
-    match Box::pin(lock_and_sync(
-        target,
-        &mut toml,
-        &edits,
-        lock_state,
-        sync_state,
-        lock_check,
-        no_install_project,
-        only_install_project,
-        no_install_workspace,
-        only_install_workspace,
-        no_install_local,
-        only_install_local,
-        no_install_package.clone(),
-        only_install_package.clone(),
-        &defaulted_extras,
-        &defaulted_groups,
-        raw,
-        bounds,
-        dry_run,
-        constraints,
-        &settings,
-        &client_builder,
-        installer_metadata,
-        concurrency,
-        cache,
-        printer,
-        preview,
-    ))
-    .await
-    {
-        Ok(()) => Ok(ExitStatus::Success),
-        Err(err) => {
-            if modified {
-                let _ = snapshot.revert();
+        let err = match result {
+            Ok(lock) => {
+                if matches!(output_format, OutputFormat::Json) {
+                    let report = AddReport::new(&target, &edits, Some(&lock));
+                    writeln!(
+                        printer.stdout_important(),
+                        "{}",
+                        serde_json::to_string_pretty(&report)?
+                    )?;
+                }
+                return Ok(ExitStatus::Success);
+            }
+            Err(err) => err,
+        };
+
+        // If requested, propose fixes for the resolution failure.
+        let fixes = match (&target, &err) {
+            (
+                AddTarget::Project(project, _),
+                ProjectError::Operation(pip::operations::Error::Resolve(
+                    uv_resolver::ResolveError::NoSolution(no_solution),
+                )),
+            ) if interactive => fix::propose(no_solution, project.workspace()).map(Some),
+            _ => Ok(None),
+        };
+
+        let result = match err {
+            ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls()).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
+            err => Err(err.into()),
+        };
+
+        // If any fixes were applied, retry with the updated requirements.
+        let applied = fixes.and_then(|fixes| match fixes {
+            Some(fixes) => fix::prompt(&fixes, printer),
+            None => Ok(false),
+        });
+        match applied {
+            Ok(true) => {
+                modified = true;
+                let AddTarget::Project(project, python_target) = target else {
+                    unreachable!("fixes are only proposed for projects");
+                };
+                let project = VirtualProject::discover(
+                    project.root(),
+                    &DiscoveryOptions::default(),
+                    &WorkspaceCache::default(),
+                )
+                .await?;
+                toml = PyProjectTomlMut::from_toml(
+                    &project.pyproject_toml().raw,
+                    DependencyTarget::PyProjectToml,
+                )?;
+                target = AddTarget::Project(project, python_target);
             }
-            match err {
-                ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls()).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
-                    .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
-                err => Err(err.into()),
+            Ok(false) => {
+                if modified {
+                    let _ = snapshot.revert();
+                }
+                return result;
+            }
+            Err(err) => {
+                if modified {
+                    let _ = snapshot.revert();
+                }
+                return Err(err);
             }
         }
     }
@@ -1012,7 +1105,7 @@ async fn lock_and_sync(
     cache: &Cache,
     printer: Printer,
     preview: Preview,
-) -> Result<(), ProjectError> {
+) -> Result<Lock, ProjectError> {
     let mut lock = Box::pin(
         project::lock::LockOperation::new(
             if let LockCheck::Enabled(lock_check) = lock_check {
@@ -1166,12 +1259,12 @@ async fn lock_and_sync(
 
     let AddTarget::Project(project, environment) = target else {
         // If we're not adding to a project, exit early.
-        return Ok(());
+        return Ok(lock);
     };
 
     let PythonTarget::Environment(venv) = &*environment else {
         // If we're not syncing, exit early.
-        return Ok(());
+        return Ok(lock);
     };
 
     // Identify the installation target.
@@ -1219,7 +1312,7 @@ async fn lock_and_sync(
     )
     .await?;
 
-    Ok(())
+    Ok(lock)
 }
 
 /// Resolves the source for a requirement and processes it into a PEP 508 compliant format.
@@ -1447,6 +1540,103 @@ impl AddTargetSnapshot {
     }
 }
 
+/// The report for `uv add --output-format json`.
+#[derive(Debug, Serialize)]
+struct AddReport<'a> {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The path to the `pyproject.toml` or script that was modified.
+    path: PortablePathBuf,
+    /// The dependencies that were added or updated.
+    dependencies: Vec<AddDependencyReport<'a>>,
+}
+
+/// A single dependency that was added or updated.
+#[derive(Debug, Serialize)]
+struct AddDependencyReport<'a> {
+    /// The normalized package name.
+    name: &'a PackageName,
+    /// Whether the dependency was added or an existing entry was updated.
+    action: AddAction,
+    /// The table to which the dependency was added.
+    section: AddSection,
+    /// The optional dependency extra, for `--optional`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<&'a ExtraName>,
+    /// The dependency group, for `--group`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'a GroupName>,
+    /// The versions of the package in the lockfile, unless locking was skipped with `--frozen`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<Vec<&'a Version>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AddAction {
+    Added,
+    Updated,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum AddSection {
+    /// `project.dependencies`, or the `dependencies` of a script.
+    Dependencies,
+    /// `project.optional-dependencies`.
+    OptionalDependencies,
+    /// `dependency-groups`.
+    DependencyGroups,
+    /// `tool.uv.dev-dependencies`.
+    DevDependencies,
+}
+
+impl<'a> AddReport<'a> {
+    fn new(target: &AddTarget, edits: &'a [DependencyEdit], lock: Option<&'a Lock>) -> Self {
+        let path = match target {
+            AddTarget::Script(script, _) => script.path.clone(),
+            AddTarget::Project(project, _) => project.root().join("pyproject.toml"),
+        };
+        let dependencies = edits
+            .iter()
+            .map(|edit| {
+                let (section, extra, group) = match &edit.dependency_type {
+                    DependencyType::Production => (AddSection::Dependencies, None, None),
+                    DependencyType::Dev => (AddSection::DevDependencies, None, None),
+                    DependencyType::Optional(extra) => {
+                        (AddSection::OptionalDependencies, Some(extra), None)
+                    }
+                    DependencyType::Group(group) => {
+                        (AddSection::DependencyGroups, None, Some(group))
+                    }
+                };
+                AddDependencyReport {
+                    name: &edit.requirement.name,
+                    action: match edit.edit {
+                        ArrayEdit::Add(_) => AddAction::Added,
+                        ArrayEdit::Update(_) => AddAction::Updated,
+                    },
+                    section,
+                    extra,
+                    group,
+                    locked: lock.map(|lock| {
+                        lock.packages()
+                            .iter()
+                            .filter(|package| *package.name() == edit.requirement.name)
+                            .filter_map(|package| package.version())
+                            .collect()
+                    }),
+                }
+            })
+            .collect();
+        Self {
+            schema: SchemaReport::default(),
+            path: path.as_path().into(),
+            dependencies,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct DependencyEdit {
     dependency_type: DependencyType,
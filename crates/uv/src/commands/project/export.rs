@@ -1,36 +1,58 @@
 use std::env;
 use std::ffi::OsStr;
+use std::fmt::Write as _;
+use std::io;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Context, Result, anyhow};
 use clap::ValueEnum;
+use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::sync::Semaphore;
 
 use uv_cache::Cache;
-use uv_client::BaseClientBuilder;
+use uv_cli::ExportFormatArg;
+use uv_client::{AttestationStatus, BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
+    Concurrency, Constraints, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification,
+    InstallOptions,
 };
+use uv_distribution_types::{IndexCapabilities, IndexLocations, Name, Requirement, Resolution};
+use uv_fs::Simplified;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
-use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_python::{Interpreter, PythonDownloads, PythonPreference, PythonRequest};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::{RequirementsSpecification, is_pylock_toml};
+use uv_resolver::{
+    GitHubJob, GitHubSnapshot, Installable, Lock, PylockToml, RequirementsTxtExport, SpdxDocument,
+    cyclonedx_json,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
-use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::loggers::{DefaultResolveLogger, SummaryResolveLogger};
+use crate::commands::project::attest::{SigningKey, attest};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
-    ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, default_dependency_groups,
-    detect_conflicts,
+    EnvironmentSpecification, PlatformState, ProjectError, ProjectInterpreter, ScriptInterpreter,
+    UniversalState, default_dependency_groups, detect_conflicts, resolve_environment,
+};
+use crate::commands::{
+    ExitStatus, OutputWriter, diagnostics, external_export, find_external_exporter,
+    query_advisories,
 };
-use crate::commands::{ExitStatus, OutputWriter, diagnostics};
 use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
 
@@ -57,11 +79,13 @@ impl<'lock> From<&'lock ExportTarget> for LockTarget<'lock> {
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn export(
     project_dir: &Path,
-    format: Option<ExportFormat>,
+    format: Option<ExportFormatArg>,
     all_packages: bool,
     package: Vec<PackageName>,
     prune: Vec<PackageName>,
-    hashes: bool,
+    hashes: Option<bool>,
+    include_build_dependencies: bool,
+    include_interpreter: bool,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
     extras: ExtrasSpecification,
@@ -81,6 +105,8 @@ pub(crate) async fn export(
     concurrency: Concurrency,
     no_config: bool,
     quiet: bool,
+    osv_url: Option<DisplaySafeUrl>,
+    signing_key: Option<PathBuf>,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -227,6 +253,8 @@ pub(crate) async fn export(
         Err(err) => return Err(err.into()),
     };
 
+    let lock_path = LockTarget::from(&target).lock_path();
+
     // Identify the installation target.
     let target = match &target {
         ExportTarget::Project(VirtualProject::Project(project)) => {
@@ -288,6 +316,12 @@ pub(crate) async fn export(
         },
     };
 
+    // The path to the script, if any, which identifies the root of an SBOM.
+    let script = match &target {
+        InstallTarget::Script { script, .. } => Some(script.path.as_path()),
+        _ => None,
+    };
+
     // Validate that the set of requested extras and development groups are defined in the lockfile.
     target.validate_extras(&extras)?;
     target.validate_groups(&groups)?;
@@ -312,27 +346,101 @@ pub(crate) async fn export(
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file.as_deref());
 
     // Determine the output format.
-    let format = format.unwrap_or_else(|| {
-        if output_file
-            .as_deref()
-            .and_then(Path::extension)
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
-        {
-            ExportFormat::RequirementsTxt
-        } else if output_file
-            .as_deref()
-            .and_then(Path::file_name)
-            .and_then(OsStr::to_str)
-            .is_some_and(is_pylock_toml)
-        {
-            ExportFormat::PylockToml
-        } else {
-            ExportFormat::RequirementsTxt
+    let format = match format {
+        Some(ExportFormatArg::Builtin(format)) => format,
+        Some(ExportFormatArg::External(name)) => {
+            if signing_key.is_some() {
+                return Err(anyhow!(
+                    "`{}` is not supported for external export formats",
+                    "--signing-key".green()
+                ));
+            }
+            if include_build_dependencies {
+                return Err(anyhow!(
+                    "`{}` is not supported for external export formats",
+                    "--include-build-dependencies".green()
+                ));
+            }
+            let Some(executable) = find_external_exporter(&name) else {
+                return Err(anyhow!(
+                    "No executable named `{}` was found on the `PATH` for `{}`",
+                    format!("uv-export-{name}").cyan(),
+                    format!("--format ext:{name}").green()
+                ));
+            };
+
+            // Pass the entire lockfile to the exporter, which is responsible for its own
+            // selection of packages.
+            let output =
+                external_export(&executable, &lock.to_toml()?, target.install_path()).await?;
+            writer.write_all(&output)?;
+            writer.commit().await?;
+
+            return Ok(ExitStatus::Success);
         }
-    });
+        None => infer_format(output_file.as_deref())?,
+    };
 
-    // Skip conflict detection for CycloneDX exports, as SBOMs are meant to document all dependencies including conflicts.
-    if !matches!(format, ExportFormat::CycloneDX1_5) {
+    // Read the key to sign the export with, if any, before generating the export.
+    let signing_key = if let Some(signing_key) = signing_key {
+        let predicate_type = match format {
+            ExportFormat::CycloneDX1_5 | ExportFormat::CycloneDX1_4 => CYCLONEDX_PREDICATE_TYPE,
+            ExportFormat::SpdxJson => SPDX_PREDICATE_TYPE,
+            _ => {
+                return Err(anyhow!(
+                    "`{}` is only supported for JSON-encoded SBOMs (`{}`, `{}`, or `{}`)",
+                    "--signing-key".green(),
+                    "cyclonedx1.5".cyan(),
+                    "cyclonedx1.4".cyan(),
+                    "spdx2.3+json".cyan(),
+                ));
+            }
+        };
+        let Some(output_file) = output_file.as_deref() else {
+            return Err(anyhow!(
+                "`{}` requires `{}`",
+                "--signing-key".green(),
+                "--output-file".green()
+            ));
+        };
+        let pem = fs_err::tokio::read_to_string(&signing_key).await?;
+        let key = SigningKey::from_pem(&pem).with_context(|| {
+            format!(
+                "Failed to read signing key from `{}`",
+                signing_key.user_display()
+            )
+        })?;
+        Some((key, predicate_type, output_file))
+    } else {
+        None
+    };
+
+    // Build requirements are resolved for the current interpreter, and only recorded in
+    // `CycloneDX` SBOMs.
+    if include_build_dependencies {
+        if format.cyclonedx().is_none() {
+            return Err(anyhow!(
+                "`{}` is only supported for `CycloneDX` formats",
+                "--include-build-dependencies".green()
+            ));
+        }
+        if interpreter.is_none() {
+            return Err(anyhow!(
+                "`{}` can't be used with `{}`, as resolving build requirements requires an interpreter",
+                "--include-build-dependencies".green(),
+                "--frozen".green()
+            ));
+        }
+    }
+
+    // Skip conflict detection for SBOM exports and dependency snapshots, as they're meant to
+    // document all dependencies including conflicts.
+    if format.cyclonedx().is_none()
+        && !matches!(
+            format,
+            ExportFormat::SpdxJson | ExportFormat::GitHubDependencySnapshot
+        )
+    {
         detect_conflicts(&target, &extras, &groups)?;
     }
 
@@ -361,7 +469,7 @@ pub(crate) async fn export(
                 &groups,
                 include_annotations,
                 editable,
-                hashes,
+                hashes.unwrap_or(true),
                 &install_options,
             )?;
 
@@ -396,27 +504,442 @@ pub(crate) async fn export(
             }
             write!(writer, "{}", export.to_toml()?)?;
         }
-        ExportFormat::CycloneDX1_5 => {
+        ExportFormat::CycloneDX1_5
+        | ExportFormat::CycloneDX1_5Xml
+        | ExportFormat::CycloneDX1_4
+        | ExportFormat::CycloneDX1_4Xml => {
+            // Record the attestation status of each registry package from an index that enables
+            // attestation verification.
+            let attestations = if settings.index_locations.verifies_attestations() {
+                verify_attestations(
+                    &lock,
+                    target.install_path(),
+                    &settings.index_locations,
+                    &client_builder,
+                    cache,
+                    concurrency,
+                )
+                .await?
+            } else {
+                cyclonedx_json::Attestations::default()
+            };
+
+            // Record the known vulnerabilities of each registry package, if requested.
+            let advisories = if let Some(osv_url) = osv_url {
+                query_advisories(
+                    &lock,
+                    target.install_path(),
+                    &osv_url,
+                    &client_builder,
+                    concurrency,
+                )
+                .await?
+            } else {
+                cyclonedx_json::Advisories::default()
+            };
+
+            // Resolve the build requirements of each package built from a local source tree, if
+            // requested.
+            let build_dependencies =
+                if include_build_dependencies && let Some(interpreter) = interpreter.as_ref() {
+                    resolve_build_dependencies(
+                        &lock,
+                        target.install_path(),
+                        target.build_constraints(),
+                        interpreter,
+                        &settings,
+                        &client_builder,
+                        concurrency,
+                        cache,
+                        printer,
+                        preview,
+                    )
+                    .await?
+                } else {
+                    cyclonedx_json::BuildDependencies::default()
+                };
+
+            // Read the SBOM metadata from the workspace root, if any.
+            let sbom_metadata = match &target {
+                InstallTarget::Project { workspace, .. }
+                | InstallTarget::Projects { workspace, .. }
+                | InstallTarget::Workspace { workspace, .. }
+                | InstallTarget::NonProjectWorkspace { workspace, .. } => workspace
+                    .pyproject_toml()
+                    .tool
+                    .as_ref()
+                    .and_then(|tool| tool.uv.as_ref())
+                    .and_then(|uv| uv.sbom.clone()),
+                InstallTarget::Script { .. } => None,
+            }
+            .unwrap_or_default();
+
             let export = cyclonedx_json::from_lock(
                 &target,
                 &prune,
                 &extras,
                 &groups,
                 include_annotations,
+                // Unlike `requirements.txt`, hashes are omitted from SBOMs unless requested.
+                hashes.unwrap_or(false),
                 &install_options,
                 preview,
                 all_packages,
+                &attestations,
+                source_date_epoch()?,
+                script,
+                &advisories,
+                &build_dependencies,
+                &sbom_metadata,
+                interpreter.as_ref().filter(|_| include_interpreter),
+            )?;
+
+            if let Some((version, encoding)) = format.cyclonedx() {
+                cyclonedx_json::write(export, version, encoding, &mut writer)?;
+            }
+        }
+        ExportFormat::SpdxJson => {
+            let export = SpdxDocument::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                &install_options,
+                preview,
+                source_date_epoch()?,
+                script,
             )?;
 
-            export.output_as_json_v1_5(&mut writer)?;
+            writeln!(writer, "{}", serde_json::to_string_pretty(&export)?)?;
+        }
+        ExportFormat::GitHubDependencySnapshot => {
+            let export = GitHubSnapshot::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                &install_options,
+                lock_path.portable_display().to_string(),
+                github_job()?,
+            )?;
+
+            writeln!(writer, "{}", serde_json::to_string_pretty(&export)?)?;
+        }
+        ExportFormat::CacheKey => {
+            // Hash the `requirements.txt` export, which includes the version, source, markers, and
+            // hashes of each selected package, but omits the rest of the lockfile. As such, the
+            // key only changes when the packages that would be installed change.
+            let export = RequirementsTxtExport::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                false,
+                editable,
+                true,
+                &install_options,
+            )?;
+
+            writeln!(writer, "{:x}", Sha256::digest(export.to_string()))?;
         }
     }
 
     writer.commit().await?;
 
+    // Write a detached attestation to the SBOM, signed with the key.
+    if let Some((key, predicate_type, output_file)) = signing_key {
+        let contents = fs_err::tokio::read(output_file).await?;
+        let name = output_file
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap_or_default();
+        let envelope = attest(
+            name,
+            &contents,
+            predicate_type,
+            &serde_json::from_slice(&contents)?,
+            &key,
+        )?;
+
+        let mut path = output_file.as_os_str().to_owned();
+        path.push(".intoto.jsonl");
+        let path = PathBuf::from(path);
+        fs_err::tokio::write(&path, format!("{}\n", serde_json::to_string(&envelope)?)).await?;
+        writeln!(
+            printer.stderr(),
+            "Wrote attestation to `{}`",
+            path.user_display().cyan()
+        )?;
+    }
+
     Ok(ExitStatus::Success)
 }
 
+/// The in-toto predicate type for `CycloneDX` SBOMs.
+const CYCLONEDX_PREDICATE_TYPE: &str = "https://cyclonedx.org/bom";
+
+/// The in-toto predicate type for SPDX documents.
+const SPDX_PREDICATE_TYPE: &str = "https://spdx.dev/Document";
+
+/// Infer the export format from the name of the output file, defaulting to `requirements.txt`.
+///
+/// SBOMs are recognized by the conventional `.cdx.json`, `.cdx.xml`, and `.spdx.json` extensions;
+/// other JSON and XML files are rejected, as they could be in any of several formats.
+fn infer_format(output_file: Option<&Path>) -> Result<ExportFormat> {
+    let Some(file_name) = output_file
+        .and_then(Path::file_name)
+        .and_then(OsStr::to_str)
+    else {
+        return Ok(ExportFormat::RequirementsTxt);
+    };
+    let lowercase = file_name.to_ascii_lowercase();
+    if lowercase.ends_with(".cdx.json") {
+        return Ok(ExportFormat::CycloneDX1_5);
+    }
+    if lowercase.ends_with(".cdx.xml") {
+        return Ok(ExportFormat::CycloneDX1_5Xml);
+    }
+    if lowercase.ends_with(".spdx.json") {
+        return Ok(ExportFormat::SpdxJson);
+    }
+    if is_pylock_toml(file_name) {
+        return Ok(ExportFormat::PylockToml);
+    }
+    let candidates: &[&str] = match Path::new(&lowercase).extension().and_then(OsStr::to_str) {
+        Some("json") => &["cyclonedx1.5", "spdx2.3+json", "github-dependency-snapshot"],
+        Some("xml") => &["cyclonedx1.5+xml", "cyclonedx1.4+xml"],
+        _ => return Ok(ExportFormat::RequirementsTxt),
+    };
+    Err(anyhow!(
+        "Unable to infer the export format from `{file_name}` (candidate formats: {}); pass `{}` to select one",
+        candidates.join(", "),
+        "--format".green(),
+    ))
+}
+
+/// Read the GitHub Actions job that a dependency snapshot describes from the environment.
+fn github_job() -> Result<GitHubJob> {
+    let var = |name: &'static str| {
+        env::var(name).with_context(|| {
+            format!(
+                "`{}` requires `{name}` to be set, as in a GitHub Actions workflow",
+                "uv export --format github-dependency-snapshot".green()
+            )
+        })
+    };
+    Ok(GitHubJob {
+        sha: var(EnvVars::GITHUB_SHA)?,
+        git_ref: var(EnvVars::GITHUB_REF)?,
+        correlator: format!(
+            "{}_{}",
+            var(EnvVars::GITHUB_WORKFLOW)?,
+            var(EnvVars::GITHUB_JOB)?
+        ),
+        id: var(EnvVars::GITHUB_RUN_ID)?,
+    })
+}
+
+/// Read the timestamp to use for reproducible SBOMs from `SOURCE_DATE_EPOCH`, if set.
+pub(crate) fn source_date_epoch() -> Result<Option<jiff::Timestamp>> {
+    let Ok(epoch) = env::var(EnvVars::SOURCE_DATE_EPOCH) else {
+        return Ok(None);
+    };
+    let timestamp = epoch
+        .parse::<i64>()
+        .ok()
+        .and_then(|seconds| jiff::Timestamp::from_second(seconds).ok())
+        .with_context(|| {
+            format!(
+                "`{}` must be a number of seconds since the Unix epoch, found `{epoch}`",
+                EnvVars::SOURCE_DATE_EPOCH
+            )
+        })?;
+    Ok(Some(timestamp))
+}
+
+/// Verify the PEP 740 attestations for the registry packages in the lockfile, for each index that
+/// enables attestation verification.
+///
+/// A package is considered signed if the attestations for all of its distributions pass.
+async fn verify_attestations(
+    lock: &Lock,
+    root: &Path,
+    index_locations: &IndexLocations,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    concurrency: Concurrency,
+) -> Result<cyclonedx_json::Attestations> {
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(index_locations.clone())
+        .build();
+    let capabilities = IndexCapabilities::default();
+    let semaphore = Semaphore::new(concurrency.downloads);
+
+    let mut attestations = cyclonedx_json::Attestations::default();
+    for package in lock.packages() {
+        let Some(version) = package.version() else {
+            continue;
+        };
+        let Some(index) = package.index(root)? else {
+            continue;
+        };
+        if !index_locations.verify_for(&index).is_enabled() {
+            continue;
+        }
+
+        let statuses = futures::stream::iter(package.registry_files(root)?)
+            .map(|file| {
+                let client = &client;
+                let index = &index;
+                let capabilities = &capabilities;
+                let semaphore = &semaphore;
+                async move {
+                    client
+                        .verify_attestations(
+                            package.name(),
+                            version,
+                            &file,
+                            index,
+                            capabilities,
+                            semaphore,
+                        )
+                        .await
+                }
+            })
+            .buffered(concurrency.downloads)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        // Report the first failure, if any; otherwise, the package is signed.
+        let status = statuses
+            .iter()
+            .find(|status| matches!(status, AttestationStatus::Invalid(_)))
+            .or_else(|| {
+                statuses
+                    .iter()
+                    .find(|status| matches!(status, AttestationStatus::Missing))
+            })
+            .or_else(|| statuses.first())
+            .cloned();
+        if let Some(status) = status {
+            attestations.insert((package.name().clone(), version.clone()), status);
+        }
+    }
+
+    Ok(attestations)
+}
+
+/// The `[build-system]` table of a `pyproject.toml`, as specified in PEP 518.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PyProjectToml {
+    build_system: Option<BuildSystem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BuildSystem {
+    requires: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+}
+
+/// Resolve the build requirements of each package in the lockfile that's built from a local source
+/// tree, i.e., a directory or editable source, for the given interpreter.
+///
+/// Build requirements are read from the `[build-system]` table of the package's `pyproject.toml`,
+/// falling back to `setuptools` in its absence, as when building the package.
+async fn resolve_build_dependencies(
+    lock: &Lock,
+    root: &Path,
+    build_constraints: Constraints,
+    interpreter: &Interpreter,
+    settings: &ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<cyclonedx_json::BuildDependencies> {
+    let state = PlatformState::default();
+
+    // Packages commonly share a build backend, so each set of requirements is only resolved once.
+    let mut resolutions =
+        FxHashMap::<Vec<Requirement>, Vec<cyclonedx_json::BuildDependency>>::default();
+
+    let mut build_dependencies = cyclonedx_json::BuildDependencies::default();
+    for package in lock.packages() {
+        let Some(source_tree) = package.source_tree() else {
+            continue;
+        };
+        let requires = read_build_requires(&root.join(source_tree)).await?;
+
+        let dependencies = if let Some(dependencies) = resolutions.get(&requires) {
+            dependencies.clone()
+        } else {
+            let spec = RequirementsSpecification::from_requirements(requires.clone());
+            let resolution = Resolution::from(
+                resolve_environment(
+                    EnvironmentSpecification::from(spec),
+                    interpreter,
+                    None,
+                    build_constraints.clone(),
+                    settings,
+                    client_builder,
+                    &state,
+                    Box::new(SummaryResolveLogger),
+                    concurrency,
+                    cache,
+                    printer,
+                    preview,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to resolve the build requirements of `{}`",
+                        package.name()
+                    )
+                })?,
+            );
+            let dependencies = resolution
+                .distributions()
+                .filter_map(|dist| {
+                    Some(cyclonedx_json::BuildDependency {
+                        name: dist.name().clone(),
+                        version: dist.version()?.clone(),
+                    })
+                })
+                .sorted()
+                .collect::<Vec<_>>();
+            resolutions.insert(requires, dependencies.clone());
+            dependencies
+        };
+
+        build_dependencies.insert(package.name().clone(), dependencies);
+    }
+
+    Ok(build_dependencies)
+}
+
+/// Read the build requirements of the source tree at the given path.
+async fn read_build_requires(source_tree: &Path) -> Result<Vec<Requirement>> {
+    let path = source_tree.join("pyproject.toml");
+    let build_system = match fs_err::tokio::read_to_string(&path).await {
+        Ok(contents) => {
+            toml::from_str::<PyProjectToml>(&contents)
+                .with_context(|| format!("Failed to parse `{}`", path.user_display()))?
+                .build_system
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    // Absent a `[build-system]` table, packages are built with the default backend, `setuptools`.
+    let requires = match build_system {
+        Some(build_system) => build_system.requires,
+        None => vec![uv_pep508::Requirement::from_str("setuptools >= 40.8.0")?],
+    };
+
+    Ok(requires.into_iter().map(Requirement::from).collect())
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd() -> String {
     let args = env::args_os()
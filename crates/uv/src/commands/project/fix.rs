@@ -0,0 +1,314 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::Result;
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_normalize::{DEV_DEPENDENCIES, PackageName};
+use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
+use uv_pypi_types::{ConflictItem, ConflictKind};
+use uv_resolver::{ConflictingDependency, NoSolutionError};
+use uv_workspace::Workspace;
+use uv_workspace::pyproject::DependencyType;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+
+use crate::printer::Printer;
+
+/// A proposed edit to a `pyproject.toml` that may resolve a resolution failure.
+#[derive(Debug, Clone)]
+pub(crate) enum ResolutionFix {
+    /// Relax the version specifiers on a dependency declared by a workspace member.
+    Relax {
+        path: PathBuf,
+        dependency_type: DependencyType,
+        name: PackageName,
+        changes: Vec<(Requirement, Requirement)>,
+    },
+    /// Override all declarations of a dependency with the workspace's own requirement.
+    Override {
+        path: PathBuf,
+        requirement: Requirement,
+    },
+    /// Declare a pair of extras or dependency groups as conflicting, such that they're resolved
+    /// independently.
+    Conflict {
+        path: PathBuf,
+        project: PackageName,
+        items: [ConflictItem; 2],
+    },
+}
+
+impl ResolutionFix {
+    /// Apply the fix to the `pyproject.toml` on disk.
+    fn apply(&self) -> Result<()> {
+        let path = match self {
+            Self::Relax { path, .. }
+            | Self::Override { path, .. }
+            | Self::Conflict { path, .. } => path,
+        };
+        let content = fs_err::read_to_string(path)?;
+        let mut toml = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)?;
+        match self {
+            Self::Relax {
+                dependency_type,
+                name,
+                ..
+            } => {
+                toml.relax_dependency(dependency_type, name)?;
+            }
+            Self::Override { requirement, .. } => {
+                toml.add_override_dependency(requirement)?;
+            }
+            Self::Conflict { project, items, .. } => {
+                toml.add_conflict(project, items)?;
+            }
+        }
+        fs_err::write(path, toml.to_string())?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ResolutionFix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Relax {
+                dependency_type,
+                changes,
+                ..
+            } => {
+                let section = match dependency_type {
+                    DependencyType::Production => "project.dependencies".to_string(),
+                    DependencyType::Dev => "tool.uv.dev-dependencies".to_string(),
+                    DependencyType::Optional(extra) => {
+                        format!("project.optional-dependencies.{extra}")
+                    }
+                    DependencyType::Group(group) => format!("dependency-groups.{group}"),
+                };
+                let changes = changes
+                    .iter()
+                    .map(|(from, to)| format!("`{from}` to `{to}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "Relax {changes} in `{section}`")
+            }
+            Self::Override { requirement, .. } => {
+                write!(
+                    f,
+                    "Override all requirements on `{}` with `{requirement}` via `tool.uv.override-dependencies`",
+                    requirement.name
+                )
+            }
+            Self::Conflict { items, .. } => {
+                let [first, second] = items;
+                write!(
+                    f,
+                    "Declare `{}` and `{}` as conflicting via `tool.uv.conflicts`",
+                    DisplayConflictItem(first),
+                    DisplayConflictItem(second)
+                )
+            }
+        }
+    }
+}
+
+/// Display a [`ConflictItem`] as `package[extra]` or `package:group`.
+struct DisplayConflictItem<'a>(&'a ConflictItem);
+
+impl std::fmt::Display for DisplayConflictItem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.kind() {
+            ConflictKind::Extra(extra) => write!(f, "{}[{extra}]", self.0.package()),
+            ConflictKind::Group(group) => write!(f, "{}:{group}", self.0.package()),
+            ConflictKind::Project => write!(f, "{}", self.0.package()),
+        }
+    }
+}
+
+/// Propose a set of fixes for a resolution failure in the given workspace.
+pub(crate) fn propose(err: &NoSolutionError, workspace: &Workspace) -> Result<Vec<ResolutionFix>> {
+    let dependencies = err.conflicting_dependencies();
+    let mut fixes = Vec::new();
+
+    // Relax the bounds on any dependencies declared by a workspace member.
+    for dependency in dependencies
+        .iter()
+        .filter(|dependency| dependency.workspace_member)
+    {
+        let Some(member) = workspace.packages().get(&dependency.dependent) else {
+            continue;
+        };
+        let dependency_types = match (&dependency.extra, &dependency.group) {
+            (Some(extra), _) => vec![DependencyType::Optional(extra.clone())],
+            (None, Some(group)) if *group == *DEV_DEPENDENCIES => {
+                vec![DependencyType::Group(group.clone()), DependencyType::Dev]
+            }
+            (None, Some(group)) => vec![DependencyType::Group(group.clone())],
+            (None, None) => vec![DependencyType::Production],
+        };
+        let path = member.root().join("pyproject.toml");
+        let content = fs_err::read_to_string(&path)?;
+        for dependency_type in dependency_types {
+            let mut toml = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)?;
+            let changes = toml.relax_dependency(&dependency_type, &dependency.dependency)?;
+            if !changes.is_empty() {
+                fixes.push(ResolutionFix::Relax {
+                    path: path.clone(),
+                    dependency_type,
+                    name: dependency.dependency.clone(),
+                    changes,
+                });
+            }
+        }
+    }
+
+    // If a workspace member conflicts with a transitive dependency, override the transitive
+    // requirement with that of the workspace member.
+    let transitive = dependencies
+        .iter()
+        .filter(|dependency| !dependency.workspace_member)
+        .map(|dependency| &dependency.dependency)
+        .collect::<BTreeSet<_>>();
+    let mut overrides = BTreeSet::new();
+    for dependency in dependencies.iter().filter(|dependency| {
+        dependency.workspace_member && transitive.contains(&dependency.dependency)
+    }) {
+        for requirement in declared_requirements(workspace, dependency) {
+            let Some(VersionOrUrl::VersionSpecifier(specifiers)) = requirement.version_or_url
+            else {
+                continue;
+            };
+            if specifiers.is_empty() {
+                continue;
+            }
+            let requirement = Requirement {
+                name: requirement.name,
+                extras: Box::default(),
+                version_or_url: Some(VersionOrUrl::VersionSpecifier(specifiers)),
+                marker: MarkerTree::TRUE,
+                origin: None,
+            };
+            if overrides.insert(requirement.to_string()) {
+                fixes.push(ResolutionFix::Override {
+                    path: workspace.install_path().join("pyproject.toml"),
+                    requirement,
+                });
+            }
+        }
+    }
+
+    // If multiple extras or groups declare conflicting requirements on the same package, declare
+    // them as conflicting.
+    let mut items: BTreeMap<&PackageName, BTreeSet<ConflictItem>> = BTreeMap::new();
+    for dependency in dependencies
+        .iter()
+        .filter(|dependency| dependency.workspace_member)
+    {
+        let item = match (&dependency.extra, &dependency.group) {
+            (Some(extra), _) => ConflictItem::from((dependency.dependent.clone(), extra.clone())),
+            (None, Some(group)) => {
+                ConflictItem::from((dependency.dependent.clone(), group.clone()))
+            }
+            (None, None) => continue,
+        };
+        items
+            .entry(&dependency.dependency)
+            .or_default()
+            .insert(item);
+    }
+    let mut conflicts = BTreeSet::new();
+    for items in items.values() {
+        for (index, first) in items.iter().enumerate() {
+            for second in items.iter().skip(index + 1) {
+                if !conflicts.insert((first.clone(), second.clone())) {
+                    continue;
+                }
+                let Some(member) = workspace.packages().get(first.package()) else {
+                    continue;
+                };
+                fixes.push(ResolutionFix::Conflict {
+                    path: member.root().join("pyproject.toml"),
+                    project: first.package().clone(),
+                    items: [first.clone(), second.clone()],
+                });
+            }
+        }
+    }
+
+    Ok(fixes)
+}
+
+/// Return the requirements that a workspace member declares on a dependency, in the
+/// `project.dependencies` or `project.optional-dependencies` table.
+fn declared_requirements(
+    workspace: &Workspace,
+    dependency: &ConflictingDependency,
+) -> Vec<Requirement> {
+    let Some(project) = workspace
+        .packages()
+        .get(&dependency.dependent)
+        .and_then(|member| member.pyproject_toml().project.as_ref())
+    else {
+        return Vec::new();
+    };
+    let requirements = match (&dependency.extra, &dependency.group) {
+        (None, None) => project.dependencies.as_ref(),
+        (Some(extra), None) => project
+            .optional_dependencies
+            .as_ref()
+            .and_then(|optional_dependencies| optional_dependencies.get(extra)),
+        (_, Some(_)) => None,
+    };
+    requirements
+        .into_iter()
+        .flatten()
+        .filter_map(|requirement| Requirement::from_str(requirement).ok())
+        .filter(|requirement| requirement.name == dependency.dependency)
+        .collect()
+}
+
+/// Offer the proposed fixes to the user, applying any that they accept.
+///
+/// If the terminal is non-interactive, the fixes are listed but not applied.
+///
+/// Returns `true` if any fix was applied.
+pub(crate) fn prompt(fixes: &[ResolutionFix], printer: Printer) -> Result<bool> {
+    let mut stderr = printer.stderr();
+
+    if fixes.is_empty() {
+        writeln!(
+            stderr,
+            "\n{}{} No fixes could be proposed for this resolution failure",
+            "hint".bold().cyan(),
+            ":".bold(),
+        )?;
+        return Ok(false);
+    }
+
+    let term = Term::stderr();
+    if !term.is_term() {
+        writeln!(
+            stderr,
+            "\n{}{} The following changes may resolve the conflict; re-run in an interactive terminal to apply them:",
+            "hint".bold().cyan(),
+            ":".bold(),
+        )?;
+        for fix in fixes {
+            writeln!(stderr, "  - {fix}")?;
+        }
+        return Ok(false);
+    }
+
+    writeln!(stderr)?;
+    let mut applied = false;
+    for fix in fixes {
+        if uv_console::confirm(&fix.to_string(), &term, false)? {
+            fix.apply()?;
+            applied = true;
+        }
+    }
+
+    Ok(applied)
+}
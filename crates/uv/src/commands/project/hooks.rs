@@ -0,0 +1,178 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_client::BaseClientBuilder;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+use uv_workspace::Workspace;
+use uv_workspace::pyproject::Hook;
+
+use crate::printer::Printer;
+
+/// A project lifecycle event that triggers the hooks in `tool.uv.hooks`.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub(crate) enum HookEvent<'a> {
+    /// The lockfile was written by `uv lock`.
+    PostLock {
+        /// The path to the lockfile.
+        lock: &'a Path,
+    },
+    /// The project environment was synced by `uv sync`.
+    PostSync {
+        /// The root of the project environment.
+        environment: &'a Path,
+    },
+    /// Files are about to be uploaded by `uv publish`.
+    PrePublish {
+        /// The distributions to upload.
+        files: Vec<PathBuf>,
+    },
+}
+
+impl HookEvent<'_> {
+    /// The name of the event, as used in `tool.uv.hooks`.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::PostLock { .. } => "post-lock",
+            Self::PostSync { .. } => "post-sync",
+            Self::PrePublish { .. } => "pre-publish",
+        }
+    }
+}
+
+/// The JSON payload sent to `url` hooks.
+#[derive(Debug, Serialize)]
+struct HookPayload<'a> {
+    #[serde(flatten)]
+    event: &'a HookEvent<'a>,
+    /// The root of the workspace.
+    workspace: &'a Path,
+    /// The version of uv that triggered the hook.
+    uv_version: &'static str,
+}
+
+/// Run the hooks configured for the given event in the workspace root.
+pub(crate) async fn run_hooks(
+    workspace: &Workspace,
+    event: HookEvent<'_>,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+    preview: Preview,
+) -> Result<()> {
+    let Some(hooks) = workspace
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.hooks.as_ref())
+    else {
+        return Ok(());
+    };
+    let hooks = match &event {
+        HookEvent::PostLock { .. } => hooks.post_lock.as_deref(),
+        HookEvent::PostSync { .. } => hooks.post_sync.as_deref(),
+        HookEvent::PrePublish { .. } => hooks.pre_publish.as_deref(),
+    }
+    .unwrap_or_default();
+    if hooks.is_empty() {
+        return Ok(());
+    }
+
+    if !preview.is_enabled(PreviewFeature::Hooks) {
+        warn_user_once!(
+            "The `tool.uv.hooks` setting is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Hooks
+        );
+    }
+
+    let root = workspace.install_path();
+    for hook in hooks {
+        match hook {
+            Hook::Command { command } => {
+                let Some((program, args)) = command.split_first() else {
+                    bail!("The `{}` hook has an empty `command`", event.name());
+                };
+                let display = command.join(" ");
+                writeln!(
+                    printer.stderr(),
+                    "Running {} hook: {}",
+                    event.name(),
+                    display.cyan()
+                )?;
+                // Forward the hook's output to stderr, to avoid interfering with uv's own output.
+                let status = tokio::process::Command::new(program)
+                    .args(args)
+                    .current_dir(root)
+                    .env(EnvVars::UV_HOOK_EVENT, event.name())
+                    .stdout(Stdio::from(std::io::stderr()))
+                    .status()
+                    .await
+                    .with_context(|| {
+                        format!("Failed to spawn `{}` hook: `{display}`", event.name())
+                    })?;
+                if !status.success() {
+                    bail!(
+                        "The `{}` hook `{display}` failed with {status}",
+                        event.name()
+                    );
+                }
+            }
+            Hook::Url { url } => {
+                writeln!(
+                    printer.stderr(),
+                    "Notifying {} hook: {}",
+                    event.name(),
+                    url.cyan()
+                )?;
+                notify(url, &event, root, client_builder)
+                    .await
+                    .with_context(|| {
+                        format!("Failed to notify `{}` hook: `{url}`", event.name())
+                    })?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Send the JSON payload for an event to a `url` hook.
+async fn notify(
+    url: &DisplaySafeUrl,
+    event: &HookEvent<'_>,
+    root: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+) -> Result<()> {
+    let payload = HookPayload {
+        event,
+        workspace: root,
+        uv_version: uv_version::version(),
+    };
+    debug!(
+        "Sending `{}` payload for `{}` to: {url}",
+        event.name(),
+        root.user_display()
+    );
+
+    let client = client_builder.build();
+    let response = client
+        .for_host(url)
+        .post(url.as_str())
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(&payload)?)
+        .send()
+        .await?;
+    response.error_for_status()?;
+
+    Ok(())
+}
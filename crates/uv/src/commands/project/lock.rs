@@ -7,9 +7,11 @@ use std::sync::Arc;
 
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::Serialize;
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
+use uv_cli::OutputFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Reinstall,
@@ -21,6 +23,7 @@ use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
     Requirement, RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::PortablePathBuf;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
 use uv_normalize::{GroupName, PackageName};
@@ -41,7 +44,10 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Editability, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::fix;
+use crate::commands::project::hooks::{HookEvent, run_hooks};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::{LockAction, SchemaReport};
 use crate::commands::project::{
     MissingLockfileSource, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     init_script_python_requirement, script_extra_build_requires,
@@ -83,6 +89,8 @@ pub(crate) async fn lock(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    interactive: bool,
+    output_format: OutputFormat,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -97,6 +105,22 @@ pub(crate) async fn lock(
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::JsonOutput)
+        && matches!(output_format, OutputFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    if interactive && !preview.is_enabled(PreviewFeature::InteractiveResolution) {
+        warn_user!(
+            "The `--interactive` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::InteractiveResolution
+        );
+    }
+
     // If necessary, initialize the PEP 723 script.
     let script = match script {
         Some(ScriptPath::Path(path)) => {
@@ -208,7 +232,34 @@ pub(crate) async fn lock(
     .await
     {
         Ok(lock) => {
-            if dry_run.enabled() {
+            if matches!(output_format, OutputFormat::Json) {
+                let report = LockCommandReport {
+                    schema: SchemaReport::default(),
+                    path: target.lock_path().as_path().into(),
+                    action: match &lock {
+                        LockResult::Unchanged(..) if matches!(mode, LockMode::Frozen(_)) => {
+                            LockAction::Use
+                        }
+                        LockResult::Unchanged(..) => LockAction::Check,
+                        LockResult::Changed(None, ..) => LockAction::Create,
+                        LockResult::Changed(Some(_), ..) => LockAction::Update,
+                    },
+                    changes: match &lock {
+                        LockResult::Unchanged(..) => Vec::new(),
+                        LockResult::Changed(previous, lock) => {
+                            LockEvent::detect_changes(previous.as_ref(), lock, dry_run)
+                                .map(LockChangeReport::from)
+                                .collect()
+                        }
+                    },
+                    dry_run: dry_run.enabled(),
+                };
+                writeln!(
+                    printer.stdout_important(),
+                    "{}",
+                    serde_json::to_string_pretty(&report)?
+                )?;
+            } else if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
                 if let LockResult::Changed(previous, lock) = &lock {
                     let mut changed = false;
@@ -236,6 +287,20 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Run the `post-lock` hooks, if the lockfile was written.
+            if let (LockTarget::Workspace(workspace), LockMode::Write(_)) = (target, mode) {
+                run_hooks(
+                    workspace,
+                    HookEvent::PostLock {
+                        lock: &target.lock_path(),
+                    },
+                    &client_builder,
+                    printer,
+                    preview,
+                )
+                .await?;
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(err @ ProjectError::LockMismatch(..)) => {
@@ -243,9 +308,51 @@ pub(crate) async fn lock(
             Ok(ExitStatus::Failure)
         }
         Err(ProjectError::Operation(err)) => {
-            diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
-                .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+            // If requested, propose fixes for the resolution failure.
+            let fixes = match (target, &err) {
+                (
+                    LockTarget::Workspace(workspace),
+                    pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(
+                        no_solution,
+                    )),
+                ) if interactive => Some(fix::propose(no_solution, workspace)?),
+                _ => None,
+            };
+
+            let result =
+                diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+
+            // If any fixes were applied, retry with the updated requirements.
+            if let Some(fixes) = fixes {
+                if fix::prompt(&fixes, printer)? {
+                    return Box::pin(lock(
+                        project_dir,
+                        lock_check,
+                        None,
+                        dry_run,
+                        interactive,
+                        output_format,
+                        refresh,
+                        python,
+                        install_mirrors,
+                        settings,
+                        client_builder,
+                        None,
+                        python_preference,
+                        python_downloads,
+                        concurrency,
+                        no_config,
+                        cache,
+                        printer,
+                        preview,
+                    ))
+                    .await;
+                }
+            }
+
+            result
         }
         Err(err) => Err(err.into()),
     }
@@ -452,6 +559,7 @@ async fn do_lock(
 
     // Extract the project settings.
     let ResolverSettings {
+        build_constraints: configured_build_constraints,
         index_locations,
         index_strategy,
         keyring_provider,
@@ -750,8 +858,15 @@ async fn do_lock(
     }
     .into_inner();
 
-    // Convert to the `Constraints` format.
-    let dispatch_constraints = Constraints::from_requirements(build_constraints.iter().cloned());
+    // Convert to the `Constraints` format, adding any configured build constraints (e.g., from a
+    // user-level `uv.toml`). Unlike the workspace build constraints, these aren't recorded in the
+    // lockfile.
+    let dispatch_constraints = Constraints::from_requirements(
+        build_constraints
+            .iter()
+            .cloned()
+            .chain(configured_build_constraints.iter().cloned()),
+    );
 
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
@@ -980,6 +1095,14 @@ async fn do_lock(
                         .unwrap_or_default(),
                 );
 
+            // Licenses and project URLs are only recorded for inclusion in SBOMs, which are in
+            // preview.
+            let lock = if preview.is_enabled(PreviewFeature::SbomExport) {
+                lock
+            } else {
+                lock.without_sbom_metadata()
+            };
+
             if previous.as_ref().is_some_and(|previous| *previous == lock) {
                 Ok(LockResult::Unchanged(lock))
             } else {
@@ -1421,11 +1544,12 @@ impl ValidatedLock {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 struct LockEventVersion<'lock> {
     /// The version of the package, or `None` if the package has a dynamic version.
     version: Option<&'lock Version>,
     /// The short Git SHA of the package, if it was installed from a Git repository.
+    #[serde(rename = "git_sha", skip_serializing_if = "Option::is_none")]
     sha: Option<&'lock str>,
 }
 
@@ -1449,6 +1573,68 @@ impl std::fmt::Display for LockEventVersion<'_> {
     }
 }
 
+/// The report for `uv lock --output-format json`.
+#[derive(Debug, Serialize)]
+struct LockCommandReport<'lock> {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The path to the lockfile.
+    path: PortablePathBuf,
+    /// Whether the lockfile was used, checked, created, or updated.
+    action: LockAction,
+    /// The packages that changed in the lockfile.
+    changes: Vec<LockChangeReport<'lock>>,
+    /// Whether this is a dry run.
+    dry_run: bool,
+}
+
+/// A change to a single package in the lockfile.
+#[derive(Debug, Serialize)]
+struct LockChangeReport<'lock> {
+    /// The normalized package name.
+    name: PackageName,
+    /// Whether the package was added, updated, or removed.
+    action: LockChangeAction,
+    /// The locked versions of the package before the change.
+    from: BTreeSet<LockEventVersion<'lock>>,
+    /// The locked versions of the package after the change.
+    to: BTreeSet<LockEventVersion<'lock>>,
+}
+
+/// The kind of change to a package in the lockfile.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum LockChangeAction {
+    Added,
+    Updated,
+    Removed,
+}
+
+impl<'lock> From<LockEvent<'lock>> for LockChangeReport<'lock> {
+    fn from(event: LockEvent<'lock>) -> Self {
+        match event {
+            LockEvent::Update(_, name, from, to) => Self {
+                name,
+                action: LockChangeAction::Updated,
+                from,
+                to,
+            },
+            LockEvent::Add(_, name, to) => Self {
+                name,
+                action: LockChangeAction::Added,
+                from: BTreeSet::new(),
+                to,
+            },
+            LockEvent::Remove(_, name, from) => Self {
+                name,
+                action: LockChangeAction::Removed,
+                from,
+                to: BTreeSet::new(),
+            },
+        }
+    }
+}
+
 /// A modification to a lockfile.
 #[derive(Debug, Clone)]
 enum LockEvent<'lock> {
@@ -13,13 +13,14 @@ use uv_cache_key::cache_digest;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    GitLfsSetting, Reinstall, TargetTriple, Upgrade,
+    GitLfsSetting, HashCheckingMode, Reinstall, TargetTriple, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
 use uv_distribution_types::{
-    ExtraBuildRequirement, ExtraBuildRequires, Index, Requirement, RequiresPython, Resolution,
-    UnresolvedRequirement, UnresolvedRequirementSpecification,
+    Dist, ExtraBuildRequirement, ExtraBuildRequires, Index, Name, Requirement, RequiresPython,
+    Resolution, ResolvedDist, SourceDist, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
 };
 use uv_fs::{CWD, LockedFile, LockedFileError, LockedFileMode, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -63,18 +64,23 @@ use crate::settings::{
 };
 
 pub(crate) mod add;
+mod attest;
 pub(crate) mod environment;
 pub(crate) mod export;
+mod fix;
 pub(crate) mod format;
+pub(crate) mod hooks;
 pub(crate) mod init;
 mod install_target;
 pub(crate) mod lock;
 mod lock_target;
+mod release;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod version;
+pub(crate) mod warm;
 
 /// The source of a missing lockfile error.
 #[derive(Debug, Clone, Copy)]
@@ -1338,6 +1344,7 @@ impl ProjectEnvironment {
         no_sync: bool,
         no_config: bool,
         active: Option<bool>,
+        relocatable: bool,
         cache: &Cache,
         dry_run: DryRun,
         printer: Printer,
@@ -1375,10 +1382,24 @@ impl ProjectEnvironment {
         .await?
         {
             // If we found an existing, compatible environment, use it.
-            ProjectInterpreter::Environment(environment) => Ok(Self::Existing(environment)),
+            ProjectInterpreter::Environment(environment)
+                if !relocatable || environment.relocatable() =>
+            {
+                Ok(Self::Existing(environment))
+            }
 
             // Otherwise, create a virtual environment with the discovered interpreter.
-            ProjectInterpreter::Interpreter(interpreter) => {
+            project_interpreter => {
+                let interpreter = match project_interpreter {
+                    ProjectInterpreter::Environment(environment) => {
+                        debug!(
+                            "The project environment at `{}` is not relocatable; recreating",
+                            environment.root().user_display()
+                        );
+                        environment.into_interpreter()
+                    }
+                    ProjectInterpreter::Interpreter(interpreter) => interpreter,
+                };
                 let root = workspace.venv(active);
 
                 // Avoid removing things that are not virtual environments
@@ -1440,7 +1461,7 @@ impl ProjectEnvironment {
                         uv_virtualenv::OnExisting::Remove(
                             uv_virtualenv::RemovalReason::ManagedEnvironment,
                         ),
-                        false,
+                        relocatable,
                         false,
                         upgradeable,
                         preview,
@@ -1482,7 +1503,7 @@ impl ProjectEnvironment {
                     uv_virtualenv::OnExisting::Remove(
                         uv_virtualenv::RemovalReason::ManagedEnvironment,
                     ),
-                    false,
+                    relocatable,
                     false,
                     upgradeable,
                     preview,
@@ -1766,6 +1787,7 @@ pub(crate) async fn resolve_names(
     let ResolverInstallerSettings {
         resolver:
             ResolverSettings {
+                build_constraints: _,
                 build_options,
                 config_setting,
                 config_settings_package,
@@ -1940,6 +1962,7 @@ pub(crate) async fn resolve_environment(
     warn_on_requirements_txt_setting(&spec.requirements, settings);
 
     let ResolverSettings {
+        build_constraints: _,
         index_locations,
         index_strategy,
         keyring_provider,
@@ -2137,6 +2160,47 @@ pub(crate) async fn resolve_environment(
     .await?)
 }
 
+/// Return an error if the organization policy requires hashes, but a registry or direct URL
+/// distribution in the [`Resolution`] has none.
+///
+/// Local and Git distributions can't be hashed, and are exempt.
+pub(crate) fn check_hash_policy(
+    resolution: &Resolution,
+) -> Result<(), uv_types::HashStrategyError> {
+    if !uv_settings::policy().is_some_and(|policy| policy.options.require_hashes) {
+        return Ok(());
+    }
+    if let Some((dist, _)) = resolution.hashes().find(|(dist, digests)| {
+        digests.is_empty()
+            && !dist.is_local()
+            && !matches!(
+                dist,
+                ResolvedDist::Installable { dist, .. }
+                    if matches!(&**dist, Dist::Source(SourceDist::Git(_)))
+            )
+    }) {
+        return Err(uv_types::HashStrategyError::MissingHashes(
+            dist.name().to_string(),
+            HashCheckingMode::Require,
+        ));
+    }
+    Ok(())
+}
+
+/// Return the [`HashStrategy`] with which to install a [`Resolution`] that wasn't read from a
+/// lockfile.
+///
+/// If the organization policy requires hashes, the distributions are verified against the hashes
+/// reported by the index; otherwise, they aren't verified.
+fn install_hasher(resolution: &Resolution) -> Result<HashStrategy, uv_types::HashStrategyError> {
+    if uv_settings::policy().is_some_and(|policy| policy.options.require_hashes) {
+        check_hash_policy(resolution)?;
+        HashStrategy::from_resolution(resolution, HashCheckingMode::Verify)
+    } else {
+        Ok(HashStrategy::default())
+    }
+}
+
 /// Sync a [`PythonEnvironment`] with a set of resolved requirements.
 pub(crate) async fn sync_environment(
     venv: PythonEnvironment,
@@ -2154,6 +2218,7 @@ pub(crate) async fn sync_environment(
     preview: Preview,
 ) -> Result<PythonEnvironment, ProjectError> {
     let InstallerSettingsRef {
+        build_constraints: _,
         index_locations,
         index_strategy,
         keyring_provider,
@@ -2200,7 +2265,7 @@ pub(crate) async fn sync_environment(
     // optional on the downstream APIs.
     let build_hasher = HashStrategy::default();
     let dry_run = DryRun::default();
-    let hasher = HashStrategy::default();
+    let hasher = install_hasher(resolution)?;
     let workspace_cache = WorkspaceCache::default();
 
     // Resolve the flat indexes from `--find-links`.
@@ -2263,6 +2328,7 @@ pub(crate) async fn sync_environment(
         &venv,
         logger,
         installer_metadata,
+        true,
         dry_run,
         printer,
         preview,
@@ -2317,6 +2383,7 @@ pub(crate) async fn update_environment(
     let ResolverInstallerSettings {
         resolver:
             ResolverSettings {
+                build_constraints: _,
                 build_options,
                 config_setting,
                 config_settings_package,
@@ -2545,7 +2612,7 @@ pub(crate) async fn update_environment(
         build_options,
         *link_mode,
         *compile_bytecode,
-        &hasher,
+        &install_hasher(&resolution)?,
         &tags,
         &client,
         state.in_flight(),
@@ -2555,6 +2622,7 @@ pub(crate) async fn update_environment(
         &venv,
         install,
         installer_metadata,
+        true,
         dry_run,
         printer,
         preview,
@@ -0,0 +1,340 @@
+//! Release steps for `uv version`: collecting changelog fragments, updating the project version
+//! in the lockfile, and creating a release commit and tag.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, anyhow, bail};
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_git::GIT;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_static::EnvVars;
+
+/// The directory containing the changelog fragments, relative to the project root.
+const FRAGMENT_DIR: &str = "changelog.d";
+
+/// The changelog the fragments are collected into, relative to the project root.
+const CHANGELOG: &str = "CHANGELOG.md";
+
+/// The supported fragment types and their section headings, in the order they are rendered.
+const FRAGMENT_TYPES: &[(&str, &str)] = &[
+    ("feature", "Features"),
+    ("bugfix", "Bug fixes"),
+    ("doc", "Documentation"),
+    ("removal", "Removals"),
+    ("misc", "Miscellaneous"),
+];
+
+/// A changelog entry in `changelog.d`, named `<id>.<type>.md`.
+#[derive(Debug)]
+struct Fragment {
+    path: PathBuf,
+    /// The index of the fragment type in [`FRAGMENT_TYPES`].
+    kind: usize,
+    content: String,
+}
+
+/// The changelog fragments of a project, to be collected into the changelog for a release.
+#[derive(Debug)]
+pub(super) struct Changelog {
+    path: PathBuf,
+    fragments: Vec<Fragment>,
+}
+
+impl Changelog {
+    /// Read the changelog fragments from `changelog.d` in the project root.
+    pub(super) fn read(project_root: &Path) -> Result<Self> {
+        let fragment_dir = project_root.join(FRAGMENT_DIR);
+        let mut fragments = Vec::new();
+        let entries = match fs_err::read_dir(&fragment_dir) {
+            Ok(entries) => entries.collect::<Result<Vec<_>, _>>()?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        for entry in entries {
+            let path = entry.path();
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            // Skip placeholders such as `.gitkeep`.
+            if file_name.starts_with('.') || !entry.file_type()?.is_file() {
+                continue;
+            }
+            let stem = file_name.strip_suffix(".md").unwrap_or(file_name);
+            let kind = stem
+                .rsplit_once('.')
+                .and_then(|(_, kind)| FRAGMENT_TYPES.iter().position(|(name, _)| *name == kind))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Invalid changelog fragment name `{}`, expected `<id>.<type>.md` with one of the types: {}",
+                        path.user_display(),
+                        FRAGMENT_TYPES
+                            .iter()
+                            .map(|(name, _)| format!("`{name}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })?;
+            let content = fs_err::read_to_string(&path)?.trim().to_string();
+            if content.is_empty() {
+                bail!("Changelog fragment is empty: {}", path.user_display());
+            }
+            fragments.push(Fragment {
+                path,
+                kind,
+                content,
+            });
+        }
+        fragments.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.path.cmp(&b.path)));
+
+        Ok(Self {
+            path: project_root.join(CHANGELOG),
+            fragments,
+        })
+    }
+
+    /// The number of collected fragments.
+    pub(super) fn len(&self) -> usize {
+        self.fragments.len()
+    }
+
+    /// The path to the changelog file.
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Add a section for the release to the changelog and remove the collected fragments.
+    ///
+    /// Returns the paths of all modified and removed files.
+    pub(super) fn write(self, version: &Version) -> Result<Vec<PathBuf>> {
+        let existing = match fs_err::read_to_string(&self.path) {
+            Ok(existing) => existing,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let section = self.render(version);
+        fs_err::write(&self.path, insert_section(&existing, &section))?;
+
+        let mut changed = vec![self.path];
+        for fragment in self.fragments {
+            fs_err::remove_file(&fragment.path)?;
+            changed.push(fragment.path);
+        }
+        Ok(changed)
+    }
+
+    /// Render the changelog section for a release.
+    fn render(&self, version: &Version) -> String {
+        let mut section = format!("## {version}\n");
+        let mut current_kind = None;
+        for fragment in &self.fragments {
+            if current_kind != Some(fragment.kind) {
+                let _ = write!(section, "\n### {}\n\n", FRAGMENT_TYPES[fragment.kind].1);
+                current_kind = Some(fragment.kind);
+            }
+            // Indent continuation lines to keep multi-line entries in the list item.
+            let entry = fragment
+                .content
+                .lines()
+                .map(|line| {
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("  {line}")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let _ = writeln!(section, "- {}", entry.trim_start());
+        }
+        section
+    }
+}
+
+/// Insert a release section before the previous release in the changelog, i.e., the first
+/// second-level heading, or at the end if there is no previous release.
+fn insert_section(existing: &str, section: &str) -> String {
+    if existing.trim().is_empty() {
+        return format!("# Changelog\n\n{section}");
+    }
+    let mut offset = 0;
+    for line in existing.split_inclusive('\n') {
+        if line.starts_with("## ") {
+            return format!("{}{section}\n{}", &existing[..offset], &existing[offset..]);
+        }
+        offset += line.len();
+    }
+    format!("{}\n\n{section}", existing.trim_end())
+}
+
+/// Set the version of a workspace member in `uv.lock` without re-locking.
+///
+/// If the project doesn't have a lockfile yet, this is a no-op.
+pub(super) fn update_lock_version(
+    lock_path: &Path,
+    name: &PackageName,
+    version: &Version,
+) -> Result<()> {
+    let content = match fs_err::read_to_string(lock_path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            debug!("No lockfile at `{}`, skipping", lock_path.user_display());
+            return Ok(());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let mut lock = content
+        .parse::<toml_edit::DocumentMut>()
+        .with_context(|| format!("Failed to parse: `{}`", lock_path.user_display()))?;
+
+    // Workspace members are the packages with a local source.
+    let package = lock
+        .get_mut("package")
+        .and_then(|packages| packages.as_array_of_tables_mut())
+        .and_then(|packages| {
+            packages.iter_mut().find(|package| {
+                package.get("name").and_then(|name| name.as_str()) == Some(name.as_str())
+                    && package
+                        .get("source")
+                        .and_then(|source| source.as_inline_table())
+                        .is_some_and(|source| {
+                            source.contains_key("editable")
+                                || source.contains_key("virtual")
+                                || source.contains_key("directory")
+                        })
+            })
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Failed to find `{name}` in `{}`, run `uv lock` to update the lockfile",
+                lock_path.user_display()
+            )
+        })?;
+    package["version"] = toml_edit::value(version.to_string());
+
+    fs_err::write(lock_path, lock.to_string())?;
+    Ok(())
+}
+
+/// A release commit and tag to create in the Git repository of the project.
+#[derive(Debug)]
+pub(super) struct GitRelease {
+    git: PathBuf,
+    project_root: PathBuf,
+    tag: String,
+}
+
+impl GitRelease {
+    /// Check that the project is in a Git repository that doesn't have the release tag yet.
+    ///
+    /// The tag is `v<version>`, or `<name>-v<version>` when releasing a workspace member.
+    pub(super) fn prepare(
+        project_root: &Path,
+        name: &PackageName,
+        version: &Version,
+        is_member: bool,
+    ) -> Result<Self> {
+        let git = GIT
+            .as_ref()
+            .map_err(|_| anyhow!("`--tag` requires Git, but `git` was not found in PATH"))?
+            .clone();
+        let tag = if is_member {
+            format!("{name}-v{version}")
+        } else {
+            format!("v{version}")
+        };
+        let release = Self {
+            git,
+            project_root: project_root.to_path_buf(),
+            tag,
+        };
+
+        release
+            .run(&["rev-parse", "--is-inside-work-tree"])
+            .with_context(|| {
+                format!(
+                    "`--tag` requires a Git repository, but `{}` is not in one",
+                    project_root.user_display()
+                )
+            })?;
+        if release
+            .run(&[
+                "rev-parse",
+                "--quiet",
+                "--verify",
+                &format!("refs/tags/{}", release.tag),
+            ])
+            .is_ok()
+        {
+            bail!("The tag `{}` already exists", release.tag);
+        }
+        Ok(release)
+    }
+
+    /// The name of the release tag.
+    pub(super) fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Commit the given paths and tag the commit.
+    pub(super) fn commit_and_tag(&self, paths: &[PathBuf], message: &str) -> Result<()> {
+        let mut add = vec!["add", "--all", "--"];
+        let paths = paths
+            .iter()
+            .map(|path| path.to_string_lossy())
+            .collect::<Vec<_>>();
+        add.extend(paths.iter().map(AsRef::as_ref));
+        self.run(&add)?;
+
+        // Only commit the release files, even if there are other staged changes.
+        let mut commit = vec!["commit", "--message", message, "--"];
+        commit.extend(paths.iter().map(AsRef::as_ref));
+        self.run(&commit)?;
+
+        self.run(&["tag", "--annotate", &self.tag, "--message", message])?;
+        Ok(())
+    }
+
+    fn run(&self, args: &[&str]) -> Result<()> {
+        debug!("Running `git {}`", args.join(" "));
+        let output = Command::new(&self.git)
+            .args(args)
+            .env(EnvVars::LC_ALL, "C")
+            .current_dir(&self.project_root)
+            .output()?;
+        if !output.status.success() {
+            bail!(
+                "`git {}` failed:\n{}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_changelog_section() {
+        let section = "## 1.1.0\n\n- New\n";
+        assert_eq!(
+            insert_section("", section),
+            "# Changelog\n\n## 1.1.0\n\n- New\n"
+        );
+        assert_eq!(
+            insert_section("# Changelog\n\nIntro.\n\n## 1.0.0\n\n- Old\n", section),
+            "# Changelog\n\nIntro.\n\n## 1.1.0\n\n- New\n\n## 1.0.0\n\n- Old\n"
+        );
+        assert_eq!(
+            insert_section("# Changelog\n\nIntro.\n", section),
+            "# Changelog\n\nIntro.\n\n## 1.1.0\n\n- New\n"
+        );
+    }
+}
@@ -248,6 +248,7 @@ pub(crate) async fn remove(
                     no_sync,
                     no_config,
                     active,
+                    false,
                     cache,
                     DryRun::Disabled,
                     printer,
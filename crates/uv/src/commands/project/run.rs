@@ -41,6 +41,7 @@ use uv_scripts::Pep723Item;
 use uv_settings::PythonInstallMirrors;
 use uv_shell::runnable::WindowsRunnable;
 use uv_static::EnvVars;
+use uv_virtualenv::remove_virtualenv;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache, WorkspaceError};
 
@@ -87,6 +88,7 @@ pub(crate) async fn run(
     frozen: Option<FrozenSource>,
     active: Option<bool>,
     no_sync: bool,
+    refresh_script: bool,
     isolated: bool,
     all_packages: bool,
     package: Option<PackageName>,
@@ -226,6 +228,30 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             }
         }
 
+        // If requested, remove the cached script environment, so that it's rebuilt from scratch.
+        if refresh_script {
+            // Lock the script environment, so that we don't remove it out from under a concurrent
+            // invocation.
+            let _lock = ScriptInterpreter::lock((&script).into())
+                .await
+                .inspect_err(|err| {
+                    warn!("Failed to acquire script environment lock: {err}");
+                })
+                .ok();
+
+            let root = ScriptInterpreter::root((&script).into(), Some(false), &cache);
+            match remove_virtualenv(&root) {
+                Ok(()) => {
+                    debug!(
+                        "Removed script environment at: {}",
+                        root.user_display().cyan()
+                    );
+                }
+                Err(uv_virtualenv::Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
         // If a lockfile already exists, lock the script.
         if let Some(target) = script
             .as_script()
@@ -733,6 +759,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     no_sync,
                     no_config,
                     active,
+                    false,
                     &cache,
                     DryRun::Disabled,
                     printer,
@@ -1,15 +1,17 @@
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use serde::Serialize;
-use tracing::warn;
+use tracing::{debug, warn};
 use uv_cache::Cache;
-use uv_cli::SyncFormat;
+use uv_cache_key::cache_digest;
+use uv_cli::OutputFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
@@ -21,13 +23,16 @@ use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     DirectorySourceDist, Dist, Index, Name, Requirement, Resolution, ResolvedDist, SourceDist,
 };
-use uv_fs::{PortablePathBuf, Simplified};
+use uv_fs::{LockedFile, LockedFileError, LockedFileMode, PortablePathBuf, Simplified};
 use uv_installer::{InstallationStrategy, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_python::{
+    EnvironmentPreference, PYTHON_VERSION_FILENAME, Prefix, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest,
+};
 use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
@@ -40,15 +45,18 @@ use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger,
 use crate::commands::pip::operations::{ChangedDist, Changelog, Modifications};
 use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
+use crate::commands::project::hooks::{HookEvent, run_hooks};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     EnvironmentUpdate, PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment,
-    UniversalState, default_dependency_groups, detect_conflicts, script_extra_build_requires,
-    script_specification, update_environment,
+    UniversalState, WorkspacePython, check_hash_policy, default_dependency_groups,
+    detect_conflicts, script_extra_build_requires, script_specification, update_environment,
+    validate_project_requires_python,
 };
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics, kernel};
 use crate::printer::Printer;
 use crate::settings::{
     FrozenSource, InstallerSettingsRef, LockCheck, LockCheckSource, ResolverInstallerSettings,
@@ -56,12 +64,15 @@ use crate::settings::{
 };
 
 /// Sync the project environment.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn sync(
     project_dir: &Path,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
     active: Option<bool>,
+    relocatable: bool,
+    target_rootfs: Option<PathBuf>,
     all_packages: bool,
     package: Vec<PackageName>,
     extras: ExtrasSpecification,
@@ -83,9 +94,10 @@ pub(crate) async fn sync(
     cache: &Cache,
     printer: Printer,
     preview: Preview,
-    output_format: SyncFormat,
+    output_format: OutputFormat,
 ) -> Result<ExitStatus> {
-    if preview.is_enabled(PreviewFeature::JsonOutput) && matches!(output_format, SyncFormat::Json) {
+    if preview.is_enabled(PreviewFeature::JsonOutput) && matches!(output_format, OutputFormat::Json)
+    {
         warn_user!(
             "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
             PreviewFeature::JsonOutput
@@ -158,8 +170,26 @@ pub(crate) async fn sync(
     let extras = extras.with_defaults(default_extras);
 
     // Discover or create the virtual environment.
-    let environment = match &target {
-        SyncTarget::Project(project) => SyncEnvironment::Project(
+    let environment = match (&target, target_rootfs) {
+        (SyncTarget::Project(project), Some(rootfs)) => SyncEnvironment::Rootfs(
+            rootfs_environment(
+                &rootfs,
+                relocatable,
+                project.workspace(),
+                &groups,
+                python.as_deref().map(PythonRequest::parse),
+                &install_mirrors,
+                &client_builder,
+                python_preference,
+                python_downloads,
+                no_config,
+                cache,
+                printer,
+                preview,
+            )
+            .await?,
+        ),
+        (SyncTarget::Project(project), None) => SyncEnvironment::Project(
             ProjectEnvironment::get_or_init(
                 project.workspace(),
                 &groups,
@@ -171,6 +201,7 @@ pub(crate) async fn sync(
                 false,
                 no_config,
                 active,
+                relocatable,
                 cache,
                 dry_run,
                 printer,
@@ -178,7 +209,7 @@ pub(crate) async fn sync(
             )
             .await?,
         ),
-        SyncTarget::Script(script) => SyncEnvironment::Script(
+        (SyncTarget::Script(script), _) => SyncEnvironment::Script(
             ScriptEnvironment::get_or_init(
                 script.into(),
                 python.as_deref().map(PythonRequest::parse),
@@ -445,6 +476,30 @@ pub(crate) async fn sync(
         writeln!(printer.stdout_important(), "{output}")?;
     }
 
+    // Run the `post-sync` hooks, unless the sync was simulated.
+    if let (SyncTarget::Project(project), Outcome::Success(..)) = (&target, &outcome) {
+        if !dry_run.enabled() {
+            // Keep any Jupyter kernels registered for the environment up-to-date. Kernels aren't
+            // registered for a root filesystem, as it isn't used on this machine.
+            if !matches!(environment, SyncEnvironment::Rootfs(..))
+                && let Err(err) = kernel::refresh(&environment).await
+            {
+                debug!("Failed to update Jupyter kernels: {err}");
+            }
+
+            run_hooks(
+                project.workspace(),
+                HookEvent::PostSync {
+                    environment: environment.install_root(),
+                },
+                &client_builder,
+                printer,
+                preview,
+            )
+            .await?;
+        }
+    }
+
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
         Outcome::LockMismatch(prev, cur, lock_source) => {
@@ -460,6 +515,182 @@ pub(crate) async fn sync(
     }
 }
 
+/// Watches the files that determine the result of `uv sync` for changes, for `uv sync --watch`.
+pub(crate) struct SyncWatcher {
+    project_dir: PathBuf,
+    files: Vec<PathBuf>,
+}
+
+impl SyncWatcher {
+    pub(crate) fn new(project_dir: &Path) -> Self {
+        Self {
+            project_dir: project_dir.to_path_buf(),
+            files: vec![project_dir.join("pyproject.toml")],
+        }
+    }
+
+    /// Wait until the project changes.
+    pub(crate) async fn wait(&mut self, printer: Printer) -> Result<()> {
+        // Determine the files to watch after each sync, which may have created the lockfile. If
+        // the workspace can't be discovered, e.g., due to an invalid `pyproject.toml`, keep
+        // watching the same files.
+        if let Some(files) = watched_files(&self.project_dir).await {
+            self.files = files;
+        }
+
+        // Record the state of the files before announcing the watch, so that no change is missed
+        // while the watch is being set up.
+        let before = self
+            .files
+            .iter()
+            .map(|path| file_state(path))
+            .collect::<Vec<_>>();
+
+        writeln!(printer.stderr(), "{}", "Watching for changes...".dimmed())?;
+        let changed = wait_for_change(&self.files, before).await?;
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Detected a change to `{}`", changed.user_display()).dimmed()
+        )?;
+        Ok(())
+    }
+}
+
+/// The files that determine the result of syncing the project in `project_dir`.
+///
+/// Configuration files (i.e., `uv.toml`) are excluded, since the settings are only read once, when
+/// the watch starts.
+async fn watched_files(project_dir: &Path) -> Option<Vec<PathBuf>> {
+    let project = match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(project) => project,
+        Err(err) => {
+            debug!("Failed to discover the workspace to watch: {err}");
+            return None;
+        }
+    };
+    let workspace = project.workspace();
+
+    let mut files = workspace
+        .packages()
+        .values()
+        .map(|member| member.root().join("pyproject.toml"))
+        .collect::<Vec<_>>();
+    for dir in [project_dir, workspace.install_path().as_path()] {
+        files.push(dir.join("pyproject.toml"));
+        files.push(dir.join(PYTHON_VERSION_FILENAME));
+    }
+    files.push(workspace.install_path().join("uv.lock"));
+    files.sort();
+    files.dedup();
+    Some(files)
+}
+
+/// Wait until one of the given files is modified, created, or removed, relative to the given
+/// states, returning its path.
+///
+/// On Linux, changes are observed with inotify. Elsewhere, or if inotify is unavailable, the files
+/// are polled instead.
+async fn wait_for_change(files: &[PathBuf], before: Vec<FileState>) -> Result<PathBuf> {
+    #[cfg(target_os = "linux")]
+    let before = {
+        let files = files.to_vec();
+        match tokio::task::spawn_blocking(move || {
+            inotify_wait_for_change(&files, &before).map_err(|err| (err, before))
+        })
+        .await?
+        {
+            Ok(path) => return Ok(path),
+            Err((err, before)) => {
+                debug!("Failed to watch for changes with inotify; polling instead: {err}");
+                before
+            }
+        }
+    };
+
+    poll_for_change(files, &before).await
+}
+
+/// Return the first file whose state differs from the given states, if any.
+fn find_change<'a>(files: &'a [PathBuf], before: &[FileState]) -> Option<&'a PathBuf> {
+    files
+        .iter()
+        .zip(before)
+        .find(|(path, state)| file_state(path) != **state)
+        .map(|(path, _)| path)
+}
+
+/// Wait until one of the given files changes, using inotify.
+///
+/// The parent directories are watched, rather than the files themselves, to observe files that are
+/// created, removed, or replaced via a rename (as many editors do on save).
+#[cfg(target_os = "linux")]
+fn inotify_wait_for_change(files: &[PathBuf], before: &[FileState]) -> std::io::Result<PathBuf> {
+    use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify};
+    use rustc_hash::FxHashMap;
+
+    let inotify = Inotify::init(InitFlags::IN_CLOEXEC)?;
+    let mut directories = FxHashMap::default();
+    for directory in files.iter().filter_map(|file| file.parent()) {
+        if directories.values().any(|existing| *existing == directory) {
+            continue;
+        }
+        let watch = inotify.add_watch(
+            directory,
+            AddWatchFlags::IN_CLOSE_WRITE
+                | AddWatchFlags::IN_MOVED_TO
+                | AddWatchFlags::IN_MOVED_FROM
+                | AddWatchFlags::IN_DELETE,
+        )?;
+        directories.insert(watch, directory);
+    }
+
+    // Account for any changes made before the watches were added.
+    if let Some(path) = find_change(files, before) {
+        return Ok(path.clone());
+    }
+
+    loop {
+        for event in inotify.read_events()? {
+            let (Some(directory), Some(name)) = (directories.get(&event.wd), event.name) else {
+                continue;
+            };
+            let path = directory.join(name);
+            if files.contains(&path) {
+                return Ok(path);
+            }
+        }
+    }
+}
+
+/// The interval at which files are polled for changes, if they can't be watched.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Wait until one of the given files changes, by polling their modification time and size.
+async fn poll_for_change(files: &[PathBuf], before: &[FileState]) -> Result<PathBuf> {
+    loop {
+        if let Some(path) = find_change(files, before) {
+            return Ok(path.clone());
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// The modification time and size of a file, or `None` if it doesn't exist.
+type FileState = Option<(SystemTime, u64)>;
+
+/// Return the [`FileState`] of the file at the given path.
+fn file_state(path: &Path) -> FileState {
+    let metadata = fs_err::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
 /// The outcome of a `lock` operation within a `sync` operation.
 #[derive(Debug)]
 #[expect(clippy::large_enum_variant)]
@@ -576,6 +807,9 @@ enum SyncEnvironment {
     Project(ProjectEnvironment),
     /// A Python environment for a script.
     Script(ScriptEnvironment),
+    /// A system Python interpreter, installing into its prefix within a `--target-rootfs`
+    /// directory.
+    Rootfs(PythonEnvironment),
 }
 
 impl SyncEnvironment {
@@ -583,6 +817,37 @@ impl SyncEnvironment {
         match self {
             Self::Project(env) => env.dry_run_target(),
             Self::Script(env) => env.dry_run_target(),
+            Self::Rootfs(..) => None,
+        }
+    }
+
+    /// Return the directory into which packages are installed.
+    fn install_root(&self) -> &Path {
+        match self {
+            Self::Project(env) => env.root(),
+            Self::Script(env) => env.root(),
+            Self::Rootfs(env) => env
+                .interpreter()
+                .prefix()
+                .map_or_else(|| env.root(), Prefix::root),
+        }
+    }
+
+    /// Grab a file lock for the environment to prevent concurrent writes across processes.
+    async fn lock(&self) -> Result<LockedFile, LockedFileError> {
+        match self {
+            Self::Project(env) => env.lock().await,
+            Self::Script(env) => env.lock().await,
+            // Lock outside of the root filesystem, such that the lockfile isn't included in it.
+            Self::Rootfs(..) => {
+                let root = self.install_root();
+                LockedFile::acquire(
+                    std::env::temp_dir().join(format!("uv-{}.lock", cache_digest(&root))),
+                    LockedFileMode::Exclusive,
+                    root.simplified_display(),
+                )
+                .await
+            }
         }
     }
 }
@@ -594,10 +859,115 @@ impl Deref for SyncEnvironment {
         match self {
             Self::Project(environment) => environment,
             Self::Script(environment) => environment,
+            Self::Rootfs(environment) => environment,
         }
     }
 }
 
+/// Discover the interpreter for a `--target-rootfs` sync, and return an environment that installs
+/// into the interpreter's prefix within the root filesystem.
+async fn rootfs_environment(
+    rootfs: &Path,
+    relocatable: bool,
+    workspace: &Workspace,
+    groups: &DependencyGroupsWithDefaults,
+    python_request: Option<PythonRequest>,
+    install_mirrors: &PythonInstallMirrors,
+    client_builder: &BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<PythonEnvironment, ProjectError> {
+    let WorkspacePython {
+        source,
+        python_request,
+        requires_python,
+    } = WorkspacePython::from_request(
+        python_request,
+        Some(workspace),
+        groups,
+        workspace.install_path(),
+        no_config,
+    )
+    .await?;
+
+    // Unlike the project environment, a virtual environment is never used, as it wouldn't exist in
+    // the root filesystem.
+    let reporter = PythonDownloadReporter::single(printer);
+    let python = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+        preview,
+    )
+    .await?;
+    let implementation = python.implementation();
+    let interpreter = python.into_interpreter();
+
+    if let Some(requires_python) = requires_python.as_ref() {
+        validate_project_requires_python(
+            &interpreter,
+            Some(workspace),
+            groups,
+            requires_python,
+            &source,
+        )?;
+    }
+
+    // Mirror the interpreter's prefix within the root filesystem (e.g., `/usr/local` becomes
+    // `<rootfs>/usr/local`), such that packages are installed into its `site-packages` once the
+    // root filesystem is mounted.
+    let prefix = interpreter
+        .sys_prefix()
+        .components()
+        .filter(|component| matches!(component, Component::Normal(..)))
+        .fold(std::path::absolute(rootfs)?, |prefix, component| {
+            prefix.join(component)
+        });
+
+    writeln!(
+        printer.stderr(),
+        "Using {} {}{} interpreter at: {}",
+        implementation.pretty(),
+        interpreter.python_version(),
+        interpreter.variant().display_suffix(),
+        interpreter.sys_executable().user_display().cyan()
+    )?;
+
+    let environment =
+        PythonEnvironment::from_interpreter(interpreter).with_prefix(Prefix::from(prefix))?;
+    if !relocatable {
+        return Ok(environment);
+    }
+
+    // Relocatable scripts can only refer to an interpreter within its own prefix.
+    let environment = environment.with_relocatable();
+    if !environment.layout().sys_executable.starts_with(
+        environment
+            .interpreter()
+            .prefix()
+            .map_or(rootfs, Prefix::root),
+    ) {
+        return Err(anyhow::anyhow!(
+            "The Python interpreter at `{}` is not located within its prefix (`{}`), so scripts can't be made relocatable",
+            environment.interpreter().sys_executable().user_display(),
+            environment.interpreter().sys_prefix().user_display(),
+        )
+        .into());
+    }
+    Ok(environment)
+}
+
 /// Sync a lockfile with an environment.
 pub(super) async fn do_sync(
     target: InstallTarget<'_>,
@@ -622,6 +992,7 @@ pub(super) async fn do_sync(
 ) -> Result<Changelog, ProjectError> {
     // Extract the project settings.
     let InstallerSettingsRef {
+        build_constraints: configured_build_constraints,
         index_locations,
         index_strategy,
         keyring_provider,
@@ -665,6 +1036,7 @@ pub(super) async fn do_sync(
         InstallTarget::Script { script, .. } => {
             // Try to get extra build dependencies from the script metadata
             let resolver_settings = ResolverSettings {
+                build_constraints: configured_build_constraints.to_vec(),
                 build_options: build_options.clone(),
                 config_setting: config_setting.clone(),
                 config_settings_package: config_settings_package.clone(),
@@ -783,13 +1155,25 @@ pub(super) async fn do_sync(
         }
     };
 
-    // Read the build constraints from the lockfile.
-    let build_constraints = target.build_constraints();
+    // Read the build constraints from the lockfile, and add any configured build constraints (e.g.,
+    // from a user-level `uv.toml`), which apply to all builds, including those of third-party
+    // source distributions.
+    let build_constraints = Constraints::from_requirements(
+        target
+            .build_constraints()
+            .requirements()
+            .cloned()
+            .chain(configured_build_constraints.iter().cloned()),
+    );
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
     let build_hasher = HashStrategy::default();
 
+    // If required by the organization policy, every registry and direct URL distribution must have
+    // a hash in the lockfile.
+    check_hash_policy(&resolution)?;
+
     // Extract the hashes from the lockfile.
     let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
 
@@ -850,6 +1234,7 @@ pub(super) async fn do_sync(
         venv,
         logger,
         installer_metadata,
+        true,
         dry_run,
         printer,
         preview,
@@ -948,7 +1333,10 @@ fn apply_editable_mode(resolution: Resolution, editable: Option<EditableMode>) -
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_target(target: InstallTarget<'_>, client_builder: &BaseClientBuilder) {
+pub(super) fn store_credentials_from_target(
+    target: InstallTarget<'_>,
+    client_builder: &BaseClientBuilder,
+) {
     // Iterate over any indexes in the target.
     for index in target.indexes() {
         if let Some(credentials) = index.credentials() {
@@ -1045,14 +1433,14 @@ impl From<&Pep723Script> for ScriptReport {
 
 #[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "snake_case")]
-enum SchemaVersion {
+pub(super) enum SchemaVersion {
     /// An unstable, experimental schema.
     #[default]
     Preview,
 }
 
 #[derive(Serialize, Debug, Default)]
-struct SchemaReport {
+pub(super) struct SchemaReport {
     /// The version of the schema.
     version: SchemaVersion,
 }
@@ -1123,6 +1511,7 @@ impl From<&SyncEnvironment> for SyncAction {
             SyncEnvironment::Script(ScriptEnvironment::WouldCreate(..)) => Self::Create,
             SyncEnvironment::Script(ScriptEnvironment::WouldReplace(..)) => Self::Replace,
             SyncEnvironment::Script(ScriptEnvironment::Replaced(..)) => Self::Update,
+            SyncEnvironment::Rootfs(..) => Self::Check,
         }
     }
 }
@@ -1156,7 +1545,7 @@ impl SyncAction {
 /// Represents the action taken during a lock.
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
-enum LockAction {
+pub(super) enum LockAction {
     /// The lockfile was used without checking.
     Use,
     /// The lockfile was checked and required no updates.
@@ -1228,6 +1617,13 @@ impl From<&PythonEnvironment> for EnvironmentReport {
 
 impl From<&SyncEnvironment> for EnvironmentReport {
     fn from(env: &SyncEnvironment) -> Self {
+        // Report the directory within the root filesystem, rather than the interpreter's prefix.
+        if let SyncEnvironment::Rootfs(environment) = env {
+            return Self {
+                python: PythonReport::from(environment.interpreter()),
+                path: env.install_root().into(),
+            };
+        }
         let report = Self::from(&**env);
         // Replace the path if necessary; we construct a temporary virtual environment during dry
         // run invocations and want to report the path we _would_ use.
@@ -1273,11 +1669,11 @@ struct SyncReport {
 }
 
 impl SyncReport {
-    fn format(&self, output_format: SyncFormat) -> Option<String> {
+    fn format(&self, output_format: OutputFormat) -> Option<String> {
         match output_format {
             // This is an intermediate report, when using JSON, it's only rendered at the end
-            SyncFormat::Json => None,
-            SyncFormat::Text => self.to_human_readable_string(),
+            OutputFormat::Json => None,
+            OutputFormat::Text => self.to_human_readable_string(),
         }
     }
 
@@ -1405,10 +1801,10 @@ impl From<(&LockTarget<'_>, &LockMode<'_>, &Outcome)> for LockReport {
 }
 
 impl LockReport {
-    fn format(&self, output_format: SyncFormat) -> Option<String> {
+    fn format(&self, output_format: OutputFormat) -> Option<String> {
         match output_format {
-            SyncFormat::Json => None,
-            SyncFormat::Text => self.to_human_readable_string(),
+            OutputFormat::Json => None,
+            OutputFormat::Text => self.to_human_readable_string(),
         }
     }
 
@@ -1434,10 +1830,10 @@ impl LockReport {
 }
 
 impl Report {
-    fn format(&self, output_format: SyncFormat) -> Option<String> {
+    fn format(&self, output_format: OutputFormat) -> Option<String> {
         match output_format {
-            SyncFormat::Json => serde_json::to_string_pretty(self).ok(),
-            SyncFormat::Text => None,
+            OutputFormat::Json => serde_json::to_string_pretty(self).ok(),
+            OutputFormat::Text => None,
         }
     }
 }
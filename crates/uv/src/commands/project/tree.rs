@@ -1,21 +1,24 @@
 use std::path::Path;
 
-use anstream::print;
+use anstream::{print, println};
 use anyhow::{Error, Result};
 use futures::StreamExt;
+use serde::Serialize;
 use tokio::sync::Semaphore;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::OutputFormat;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, DependencyGroups, TargetTriple};
 use uv_distribution_types::IndexCapabilities;
 use uv_normalize::DefaultGroups;
 use uv_normalize::PackageName;
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_resolver::{PackageMap, TreeDisplay};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::pip::latest::LatestClient;
@@ -23,6 +26,7 @@ use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::resolution_markers;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::SchemaReport;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, default_dependency_groups,
 };
@@ -48,6 +52,7 @@ pub(crate) async fn tree(
     invert: bool,
     outdated: bool,
     show_sizes: bool,
+    output_format: OutputFormat,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python: Option<String>,
@@ -63,6 +68,15 @@ pub(crate) async fn tree(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::JsonOutput)
+        && matches!(output_format, OutputFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
     // Find the project requirements.
     let workspace_cache = WorkspaceCache::default();
     let workspace;
@@ -196,6 +210,7 @@ pub(crate) async fn tree(
             PackageMap::default()
         } else {
             let ResolverSettings {
+                build_constraints: _,
                 index_locations,
                 index_strategy: _,
                 keyring_provider,
@@ -287,7 +302,25 @@ pub(crate) async fn tree(
         show_sizes,
     );
 
-    print!("{tree}");
+    match output_format {
+        OutputFormat::Text => print!("{tree}"),
+        OutputFormat::Json => {
+            let report = TreeReport {
+                schema: SchemaReport::default(),
+                packages: &tree,
+            };
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+    }
 
     Ok(ExitStatus::Success)
 }
+
+/// The report for `uv tree --output-format json`.
+#[derive(Debug, Serialize)]
+struct TreeReport<'a> {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The root packages of the tree, each with its nested dependencies.
+    packages: &'a TreeDisplay<'a>,
+}
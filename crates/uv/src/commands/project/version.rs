@@ -8,7 +8,7 @@ use owo_colors::OwoColorize;
 use tracing::debug;
 use uv_cache::Cache;
 use uv_cli::version::VersionInfo;
-use uv_cli::{VersionBump, VersionBumpSpec, VersionFormat};
+use uv_cli::{OutputFormat, VersionBump, VersionBumpSpec};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
@@ -21,6 +21,7 @@ use uv_pep440::{BumpCommand, PrereleaseKind, Version};
 use uv_preview::Preview;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
 use uv_workspace::pyproject_mut::Error;
 use uv_workspace::{
     DiscoveryOptions, WorkspaceCache, WorkspaceError,
@@ -33,6 +34,7 @@ use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
+use crate::commands::project::release::{self, Changelog, GitRelease};
 use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, UniversalState, default_dependency_groups,
 };
@@ -43,7 +45,7 @@ use crate::settings::{FrozenSource, LockCheck, ResolverInstallerSettings};
 /// Display version information for uv itself (`uv self version`)
 pub(crate) fn self_version(
     short: bool,
-    output_format: VersionFormat,
+    output_format: OutputFormat,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let version_info = uv_cli::version::uv_self_version();
@@ -58,7 +60,7 @@ pub(crate) async fn project_version(
     value: Option<String>,
     mut bump: Vec<VersionBumpSpec>,
     short: bool,
-    output_format: VersionFormat,
+    output_format: OutputFormat,
     project_dir: &Path,
     package: Option<PackageName>,
     explicit_project: bool,
@@ -67,6 +69,9 @@ pub(crate) async fn project_version(
     frozen: Option<FrozenSource>,
     active: Option<bool>,
     no_sync: bool,
+    sync_lock: bool,
+    changelog: bool,
+    tag: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
@@ -309,37 +314,101 @@ pub(crate) async fn project_version(
         None
     };
 
+    // Check the release steps before modifying anything.
+    let release = if dry_run {
+        None
+    } else if let Some(new_version) = &new_version {
+        let changelog = if changelog {
+            let changelog = Changelog::read(project.root())?;
+            if changelog.len() == 0 {
+                warn_user!(
+                    "No changelog fragments found in `{}`",
+                    project.root().join("changelog.d").user_display()
+                );
+            }
+            Some(changelog)
+        } else {
+            None
+        };
+        let git = if tag {
+            Some(GitRelease::prepare(
+                project.root(),
+                &name,
+                new_version,
+                package.is_some(),
+            )?)
+        } else {
+            None
+        };
+        Some((changelog, git))
+    } else {
+        None
+    };
+
     // Update the toml and lock
+    let lock_path = project.workspace().install_path().join("uv.lock");
     let status = if dry_run {
         ExitStatus::Success
     } else if let Some(new_version) = &new_version {
         let project = update_project(project, new_version, &mut toml, &pyproject_path)?;
-        Box::pin(lock_and_sync(
-            project,
-            project_dir,
-            lock_check,
-            frozen,
-            active,
-            no_sync,
-            python,
-            install_mirrors,
-            &settings,
-            client_builder,
-            python_preference,
-            python_downloads,
-            installer_metadata,
-            concurrency,
-            no_config,
-            cache,
-            printer,
-            preview,
-        ))
-        .await?
+        if sync_lock {
+            release::update_lock_version(&lock_path, &name, new_version)?;
+            ExitStatus::Success
+        } else {
+            Box::pin(lock_and_sync(
+                project,
+                project_dir,
+                lock_check,
+                frozen,
+                active,
+                no_sync,
+                python,
+                install_mirrors,
+                &settings,
+                client_builder,
+                python_preference,
+                python_downloads,
+                installer_metadata,
+                concurrency,
+                no_config,
+                cache,
+                printer,
+                preview,
+            ))
+            .await?
+        }
     } else {
         debug!("No changes to version; skipping update");
         ExitStatus::Success
     };
 
+    // Collect the changelog and tag the release.
+    if let (Some((changelog, git)), Some(new_version)) = (release, &new_version) {
+        let mut changed = vec![pyproject_path.clone()];
+        if lock_path.is_file() {
+            changed.push(lock_path);
+        }
+        if let Some(changelog) = changelog {
+            let entries = changelog.len();
+            let changelog_path = changelog.path().to_path_buf();
+            changed.extend(changelog.write(new_version)?);
+            writeln!(
+                printer.stderr(),
+                "Added {} to `{}`",
+                format!(
+                    "{entries} {}",
+                    if entries == 1 { "entry" } else { "entries" }
+                )
+                .bold(),
+                changelog_path.user_display().cyan()
+            )?;
+        }
+        if let Some(git) = git {
+            git.commit_and_tag(&changed, &format!("Release {new_version}"))?;
+            writeln!(printer.stderr(), "Created tag `{}`", git.tag().cyan())?;
+        }
+    }
+
     // Report the results
     let old_version = VersionInfo::new(Some(&name), &old_version);
     let new_version = new_version.map(|version| VersionInfo::new(Some(&name), &version));
@@ -441,7 +510,7 @@ async fn print_frozen_version(
     no_config: bool,
     cache: &Cache,
     short: bool,
-    output_format: VersionFormat,
+    output_format: OutputFormat,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -589,6 +658,7 @@ async fn lock_and_sync(
             no_sync,
             no_config,
             active,
+            false,
             cache,
             DryRun::Disabled,
             printer,
@@ -704,11 +774,11 @@ fn print_version(
     old_version: VersionInfo,
     new_version: Option<VersionInfo>,
     short: bool,
-    output_format: VersionFormat,
+    output_format: OutputFormat,
     printer: Printer,
 ) -> Result<()> {
     match output_format {
-        VersionFormat::Text => {
+        OutputFormat::Text => {
             if let Some(name) = &old_version.package_name {
                 if !short {
                     write!(printer.stdout(), "{name} ")?;
@@ -729,7 +799,7 @@ fn print_version(
                 writeln!(printer.stdout(), "{}", old_version.cyan())?;
             }
         }
-        VersionFormat::Json => {
+        OutputFormat::Json => {
             let final_version = new_version.unwrap_or(old_version);
             let string = serde_json::to_string_pretty(&final_version)?;
             writeln!(printer.stdout(), "{string}")?;
@@ -0,0 +1,347 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, ExtrasSpecification, HashCheckingMode, InstallOptions,
+    TargetTriple,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution_types::{Dist, Index, Name, ResolvedDist, SourceDist};
+use uv_installer::Preparer;
+use uv_normalize::DefaultExtras;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::store_credentials_from_target;
+use crate::commands::project::{
+    ProjectError, ProjectInterpreter, UniversalState, default_dependency_groups,
+};
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
+
+/// Populate the cache with every distribution in the project's lockfile, without installing.
+pub(crate) async fn cache_warm(
+    project_dir: &Path,
+    lock_check: LockCheck,
+    frozen: Option<FrozenSource>,
+    python_platforms: Vec<TargetTriple>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheWarm) {
+        warn_user!(
+            "`uv cache warm` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheWarm
+        );
+    }
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Include every extra and dependency group, to capture every package in the lockfile.
+    let default_groups = default_dependency_groups(project.pyproject_toml())?;
+    let groups = DependencyGroups::from_all_groups().with_defaults(default_groups);
+    let extras = ExtrasSpecification::from_all_extras().with_defaults(DefaultExtras::default());
+
+    // Find an interpreter for the project, which is used to build source distributions.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Determine the lock mode.
+    let mode = if let Some(frozen_source) = frozen {
+        LockMode::Frozen(frozen_source.into())
+    } else if let LockCheck::Enabled(lock_check) = lock_check {
+        LockMode::Locked(&interpreter, lock_check)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = UniversalState::default();
+
+    // Lock the project.
+    let lock = match Box::pin(
+        LockOperation::new(
+            mode,
+            &settings,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(LockTarget::Workspace(project.workspace())),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Warm the cache for every package in the workspace.
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Workspace {
+            workspace: project.workspace(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    let ResolverSettings {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        dependency_metadata,
+        config_setting,
+        config_settings_package,
+        build_isolation,
+        extra_build_dependencies,
+        extra_build_variables,
+        exclude_newer,
+        link_mode,
+        build_options,
+        sources,
+        ..
+    } = settings;
+
+    // Lower the extra build dependencies with source resolution.
+    let extra_build_requires = LoweredExtraBuildDependencies::from_workspace(
+        extra_build_dependencies,
+        project.workspace(),
+        &index_locations,
+        &sources,
+        client_builder.credentials_cache(),
+    )?
+    .into_inner();
+
+    let client_builder = client_builder.keyring(keyring_provider);
+
+    // Populate credentials from the target.
+    store_credentials_from_target(target, &client_builder);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder, cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = match build_isolation {
+        uv_configuration::BuildIsolation::Isolate => BuildIsolation::Isolated,
+        uv_configuration::BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            BuildIsolation::Shared(&environment)
+        }
+        uv_configuration::BuildIsolation::SharedPackage(ref packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Read the build constraints from the lockfile.
+    let build_constraints = target.build_constraints();
+    let build_hasher = HashStrategy::default();
+
+    // By default, warm the cache for the current platform.
+    let python_platforms = if python_platforms.is_empty() {
+        vec![None]
+    } else {
+        python_platforms.into_iter().map(Some).collect()
+    };
+
+    for python_platform in python_platforms {
+        let start = std::time::Instant::now();
+
+        // Determine the markers and tags for the platform.
+        let marker_env = resolution_markers(None, python_platform.as_ref(), &interpreter);
+        let tags = resolution_tags(None, python_platform.as_ref(), &interpreter)?;
+        let platform = python_platform
+            .and_then(|platform| clap::ValueEnum::to_possible_value(&platform))
+            .map(|platform| platform.get_name().to_string());
+
+        // Skip any platforms that aren't supported by the lockfile.
+        let environments = lock.supported_environments();
+        if !environments.is_empty()
+            && !environments
+                .iter()
+                .any(|env| env.evaluate(&marker_env, &[]))
+        {
+            warn_user!(
+                "Skipping `{}`, which is not supported by the lockfile",
+                platform.as_deref().unwrap_or("the current platform")
+            );
+            continue;
+        }
+
+        let resolution = target.to_resolution(
+            &marker_env,
+            &tags,
+            &extras,
+            &groups,
+            &build_options,
+            &InstallOptions::default(),
+        )?;
+
+        // Collect the distributions to cache. Local directories (like workspace members) are
+        // skipped, as are source distributions for other platforms, which can't be built.
+        let distributions = resolution
+            .distributions()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable { dist, .. } => Some(dist.clone()),
+                ResolvedDist::Installed { .. } => None,
+            })
+            .filter(|dist| match dist.as_ref() {
+                Dist::Source(SourceDist::Directory(_)) => {
+                    debug!("Skipping local directory dependency: `{}`", dist.name());
+                    false
+                }
+                Dist::Source(_) if tags.is_cross() => {
+                    debug!(
+                        "Skipping source distribution for other platform: `{}`",
+                        dist.name()
+                    );
+                    false
+                }
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+
+        // Constrain any build requirements marked as `match-runtime = true`.
+        let extra_build_requires = extra_build_requires.clone().match_runtime(&resolution)?;
+
+        // Extract the hashes from the lockfile.
+        let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
+        // Resolve the flat indexes from `--find-links`.
+        let flat_index = {
+            let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+            let entries = client
+                .fetch_all(index_locations.flat_indexes().map(Index::url))
+                .await?;
+            FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        };
+
+        let state = state.fork();
+
+        // Create a build dispatch.
+        let build_dispatch = BuildDispatch::new(
+            &client,
+            cache,
+            &build_constraints,
+            &interpreter,
+            &index_locations,
+            &flat_index,
+            &dependency_metadata,
+            state.clone().into_inner(),
+            index_strategy,
+            &config_setting,
+            &config_settings_package,
+            build_isolation,
+            &extra_build_requires,
+            &extra_build_variables,
+            link_mode,
+            &build_options,
+            &build_hasher,
+            exclude_newer.clone(),
+            sources.clone(),
+            workspace_cache.clone(),
+            concurrency,
+            preview,
+        );
+
+        // Download (or build) the distributions into the cache.
+        let preparer = Preparer::new(
+            cache,
+            &tags,
+            &hasher,
+            &build_options,
+            DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+        )
+        .with_reporter(Arc::new(
+            PrepareReporter::from(printer).with_length(distributions.len() as u64),
+        ));
+        let wheels = preparer
+            .prepare(distributions, state.in_flight(), &resolution)
+            .await?;
+
+        let s = if wheels.len() == 1 { "" } else { "s" };
+        let message = match platform {
+            Some(platform) => format!(
+                "Cached {} for {} {}",
+                format!("{} package{s}", wheels.len()).bold(),
+                platform.cyan(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            ),
+            None => format!(
+                "Cached {} {}",
+                format!("{} package{s}", wheels.len()).bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            ),
+        };
+        writeln!(printer.stderr(), "{}", message.dimmed())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
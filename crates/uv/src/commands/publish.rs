@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result, bail};
@@ -13,20 +14,24 @@ use uv_client::{
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_types::{IndexCapabilities, IndexLocations, IndexUrl};
+use uv_fs::Simplified;
 use uv_preview::{Preview, PreviewFeature};
 use uv_publish::{
-    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, check_trusted_publishing,
-    group_files_for_publishing, upload, upload_two_phase,
+    CheckUrlClient, FormMetadata, PublishError, Severity, TrustedPublishResult, UploadDistribution,
+    check_trusted_publishing, group_files_for_publishing, upload, upload_two_phase,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_settings::EnvironmentOptions;
 use uv_warnings::{warn_user_once, write_error_chain};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
+use crate::commands::project::hooks::{HookEvent, run_hooks};
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 pub(crate) async fn publish(
+    project_dir: &Path,
     paths: Vec<String>,
     publish_url: DisplaySafeUrl,
     trusted_publishing: TrustedPublishing,
@@ -36,7 +41,7 @@ pub(crate) async fn publish(
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
-    index: Option<String>,
+    index: Vec<String>,
     index_locations: IndexLocations,
     dry_run: bool,
     no_attestations: bool,
@@ -59,8 +64,255 @@ pub(crate) async fn publish(
 
     let token_store = PyxTokenStore::from_settings()?;
 
-    let (publish_url, check_url) = if let Some(index_name) = index {
-        // If the user provided an index by name, look it up.
+    let targets = if index.is_empty() {
+        vec![PublishTarget {
+            name: None,
+            publish_url,
+            check_url,
+            username,
+            password,
+        }]
+    } else {
+        // Each index has its own credentials, so we can't use the same credentials for all of
+        // them.
+        if index.len() > 1 && (username.is_some() || password.is_some()) {
+            bail!(
+                "Credentials from `--username`, `--password` or `--token` can't be used when \
+                publishing to multiple indexes. Instead, provide the credentials for each index \
+                with `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD`."
+            );
+        }
+        index
+            .into_iter()
+            .map(|index_name| {
+                PublishTarget::from_index(
+                    index_name,
+                    &index_locations,
+                    &token_store,
+                    username.clone(),
+                    password.clone(),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let groups = group_files_for_publishing(paths, no_attestations)?;
+    if groups.is_empty() {
+        bail!("No files found to publish");
+    }
+
+    // Run the `pre-publish` hooks of the current project, if any.
+    if !dry_run {
+        if let Ok(workspace) = Workspace::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            &WorkspaceCache::default(),
+        )
+        .await
+        {
+            run_hooks(
+                &workspace,
+                HookEvent::PrePublish {
+                    files: groups.iter().map(|group| group.file.clone()).collect(),
+                },
+                client_builder,
+                printer,
+                preview,
+            )
+            .await?;
+        }
+    }
+
+    // In a dry run, check the metadata of each file, similar to `twine check`.
+    let mut metadata_errors = 0;
+    if dry_run {
+        for group in &groups {
+            writeln!(
+                printer.stderr(),
+                "{} {}",
+                "Checking metadata".bold().cyan(),
+                group.filename
+            )?;
+            let diagnostics = uv_publish::check_metadata(&group.file, &group.filename)
+                .await
+                .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)))?;
+            for diagnostic in diagnostics {
+                match diagnostic.severity {
+                    Severity::Warning => writeln!(
+                        printer.stderr(),
+                        "  {}{} {}",
+                        "warning".yellow().bold(),
+                        ":".bold(),
+                        diagnostic.message
+                    )?,
+                    Severity::Error => {
+                        metadata_errors += 1;
+                        writeln!(
+                            printer.stderr(),
+                            "  {}{} {}",
+                            "error".red().bold(),
+                            ":".bold(),
+                            diagnostic.message
+                        )?;
+                    }
+                }
+            }
+        }
+    }
+
+    // * For the uploads themselves, we roll our own retries due to
+    //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
+    //   the default retries. We set the retries to 0 here and manually construct the retry policy
+    //   in the upload loop.
+    // * We want to allow configuring TLS for the registry, while for trusted publishing we know the
+    //   defaults are correct.
+    // * For the uploads themselves, we know we need an authorization header and we can't nor
+    //   shouldn't try cloning the request to make an unauthenticated request first, but we want
+    //   keyring integration. For trusted publishing, we use an OIDC auth routine without keyring
+    //   or other auth integration.
+    let upload_client = client_builder
+        .clone()
+        .retries(0)
+        .keyring(keyring_provider)
+        // Don't try cloning the request to make an unauthenticated request first.
+        .auth_integration(AuthIntegration::OnlyAuthenticated)
+        // Disable automatic redirect, as the streaming publish request is not cloneable.
+        // Rely on custom redirect logic instead.
+        .redirect(RedirectPolicy::NoRedirect)
+        .timeout(environment.upload_http_timeout)
+        .build();
+    // For OIDC (trusted publishing), we need retries (GitHub's networking is unreliable)
+    // and default timeouts.
+    let oidc_client = client_builder
+        .clone()
+        .auth_integration(AuthIntegration::NoAuthMiddleware)
+        .build();
+    // For S3 uploads, we roll our own retry loop, use upload timeouts, and no auth middleware.
+    let s3_client = client_builder
+        .clone()
+        .retries(0)
+        .auth_integration(AuthIntegration::NoAuthMiddleware)
+        .timeout(environment.upload_http_timeout)
+        .build();
+
+    let clients = PublishClients {
+        upload_client: &upload_client,
+        oidc_client: &oidc_client,
+        s3_client: &s3_client,
+        client_builder,
+        token_store: &token_store,
+    };
+
+    // With a single target, fail directly.
+    if let [target] = targets.as_slice() {
+        Box::pin(publish_to_target(
+            target,
+            &groups,
+            &clients,
+            trusted_publishing,
+            keyring_provider,
+            &index_locations,
+            dry_run,
+            direct,
+            cache,
+            printer,
+        ))
+        .await?;
+        return metadata_check_status(metadata_errors);
+    }
+
+    // With multiple targets, publish to all of them, even if one of them fails, and report the
+    // outcome for each target at the end.
+    let mut outcomes = Vec::with_capacity(targets.len());
+    for target in &targets {
+        let outcome = Box::pin(publish_to_target(
+            target,
+            &groups,
+            &clients,
+            trusted_publishing,
+            keyring_provider,
+            &index_locations,
+            dry_run,
+            direct,
+            cache,
+            printer,
+        ))
+        .await;
+        if let Err(err) = &outcome {
+            trace!("Error trace: {err:?}");
+            write_error_chain(err.as_ref(), printer.stderr(), "error", AnsiColors::Red)?;
+        }
+        outcomes.push(outcome);
+    }
+
+    writeln!(printer.stderr(), "{}", "Summary:".bold())?;
+    for (target, outcome) in targets.iter().zip(&outcomes) {
+        let name = target.name.as_deref().unwrap_or_default();
+        match outcome {
+            Ok(summary) => {
+                let verb = if dry_run { "checked" } else { "uploaded" };
+                writeln!(
+                    printer.stderr(),
+                    "  {} {}: {verb} {} {}, skipped {} existing",
+                    name.cyan(),
+                    format!("({})", target.publish_url).dimmed(),
+                    summary.uploaded,
+                    if summary.uploaded == 1 {
+                        "file"
+                    } else {
+                        "files"
+                    },
+                    summary.skipped,
+                )?;
+            }
+            Err(_) => {
+                writeln!(
+                    printer.stderr(),
+                    "  {} {}: {}",
+                    name.cyan(),
+                    format!("({})", target.publish_url).dimmed(),
+                    "failed".bold().red(),
+                )?;
+            }
+        }
+    }
+
+    if outcomes.iter().any(Result::is_err) {
+        Ok(ExitStatus::Failure)
+    } else {
+        metadata_check_status(metadata_errors)
+    }
+}
+
+/// Fail if the metadata checks of a dry run found errors.
+fn metadata_check_status(metadata_errors: usize) -> Result<ExitStatus> {
+    match metadata_errors {
+        0 => Ok(ExitStatus::Success),
+        1 => bail!("Metadata checks failed with 1 error"),
+        n => bail!("Metadata checks failed with {n} errors"),
+    }
+}
+
+/// An index to publish the files to.
+#[derive(Debug)]
+struct PublishTarget {
+    /// The name of the index, if publishing to a named index with `--index`.
+    name: Option<String>,
+    publish_url: DisplaySafeUrl,
+    check_url: Option<IndexUrl>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl PublishTarget {
+    /// Look up a named index from the configuration.
+    fn from_index(
+        index_name: String,
+        index_locations: &IndexLocations,
+        token_store: &PyxTokenStore,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<Self> {
         debug!("Publishing with index {index_name}");
         let index = index_locations
             .simple_indexes()
@@ -91,19 +343,79 @@ pub(crate) async fn publish(
 
         // pyx has the same behavior as PyPI where uploads of identical
         // files + contents are idempotent, so we don't need to pre-check.
-        if token_store.is_known_url(&publish_url) {
-            (publish_url, None)
+        let check_url = if token_store.is_known_url(&publish_url) {
+            None
         } else {
-            let check_url = index.url.clone();
-            (publish_url, Some(check_url))
-        }
-    } else {
-        (publish_url, check_url)
-    };
+            Some(index.url.clone())
+        };
+
+        // Prefer the credentials for the index from the environment, e.g.,
+        // `UV_INDEX_INTERNAL_USERNAME` and `UV_INDEX_INTERNAL_PASSWORD`.
+        let (username, password) = if let Some(credentials) = index
+            .name
+            .as_ref()
+            .and_then(|name| Credentials::from_env(name.to_env_var()))
+        {
+            debug!("Using credentials for index `{index_name}` from the environment");
+            (
+                credentials.username().map(ToString::to_string),
+                credentials.password().map(ToString::to_string),
+            )
+        } else {
+            (username, password)
+        };
+
+        Ok(Self {
+            name: Some(index_name),
+            publish_url,
+            check_url,
+            username,
+            password,
+        })
+    }
+}
+
+/// The clients used for publishing, shared between all targets.
+struct PublishClients<'a> {
+    upload_client: &'a BaseClient,
+    oidc_client: &'a BaseClient,
+    s3_client: &'a BaseClient,
+    client_builder: &'a BaseClientBuilder<'a>,
+    token_store: &'a PyxTokenStore,
+}
+
+/// The number of files that were uploaded to a target, or that were skipped since they already
+/// exist on the target.
+#[derive(Debug, Default)]
+struct PublishSummary {
+    uploaded: usize,
+    skipped: usize,
+}
+
+/// Publish the files to a single index.
+async fn publish_to_target(
+    target: &PublishTarget,
+    groups: &[UploadDistribution],
+    clients: &PublishClients<'_>,
+    trusted_publishing: TrustedPublishing,
+    keyring_provider: KeyringProviderType,
+    index_locations: &IndexLocations,
+    dry_run: bool,
+    direct: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PublishSummary> {
+    let PublishClients {
+        upload_client,
+        oidc_client,
+        s3_client,
+        client_builder,
+        token_store,
+    } = clients;
+    let publish_url = &target.publish_url;
+    let check_url = &target.check_url;
 
-    let groups = group_files_for_publishing(paths, no_attestations)?;
     match groups.len() {
-        0 => bail!("No files found to publish"),
         1 => {
             if dry_run {
                 writeln!(printer.stderr(), "Checking 1 file against {publish_url}")?;
@@ -120,71 +432,45 @@ pub(crate) async fn publish(
         }
     }
 
-    // * For the uploads themselves, we roll our own retries due to
-    //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
-    //   the default retries. We set the retries to 0 here and manually construct the retry policy
-    //   in the upload loop.
-    // * We want to allow configuring TLS for the registry, while for trusted publishing we know the
-    //   defaults are correct.
-    // * For the uploads themselves, we know we need an authorization header and we can't nor
-    //   shouldn't try cloning the request to make an unauthenticated request first, but we want
-    //   keyring integration. For trusted publishing, we use an OIDC auth routine without keyring
-    //   or other auth integration.
-    let upload_client = client_builder
-        .clone()
-        .retries(0)
-        .keyring(keyring_provider)
-        // Don't try cloning the request to make an unauthenticated request first.
-        .auth_integration(AuthIntegration::OnlyAuthenticated)
-        // Disable automatic redirect, as the streaming publish request is not cloneable.
-        // Rely on custom redirect logic instead.
-        .redirect(RedirectPolicy::NoRedirect)
-        .timeout(environment.upload_http_timeout)
-        .build();
-    // For OIDC (trusted publishing), we need retries (GitHub's networking is unreliable)
-    // and default timeouts.
-    let oidc_client = client_builder
-        .clone()
-        .auth_integration(AuthIntegration::NoAuthMiddleware)
-        .build();
-    // For S3 uploads, we roll our own retry loop, use upload timeouts, and no auth middleware.
-    let s3_client = client_builder
-        .clone()
-        .retries(0)
-        .auth_integration(AuthIntegration::NoAuthMiddleware)
-        .timeout(environment.upload_http_timeout)
-        .build();
-
     let retry_policy = client_builder.retry_policy();
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
 
-    // Load credentials.
+    // Load credentials. In a dry run, we don't prompt for credentials or mint a trusted
+    // publishing token, since we don't upload anything.
     let (publish_url, credentials) = gather_credentials(
-        publish_url,
-        username,
-        password,
-        trusted_publishing,
+        publish_url.clone(),
+        target.username.clone(),
+        target.password.clone(),
+        if dry_run {
+            TrustedPublishing::Never
+        } else {
+            trusted_publishing
+        },
         keyring_provider,
-        &token_store,
-        &oidc_client,
-        &upload_client,
+        token_store,
+        oidc_client,
+        upload_client,
         check_url.as_ref(),
-        Prompt::Enabled,
+        if dry_run {
+            Prompt::Disabled
+        } else {
+            Prompt::Enabled
+        },
         printer,
     )
     .await?;
 
     // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = &check_url {
+    let check_url_client = if let Some(index_url) = check_url {
         let registry_client_builder =
-            RegistryClientBuilder::new(client_builder.clone(), cache.clone())
-                .index_locations(index_locations)
+            RegistryClientBuilder::new((*client_builder).clone(), cache.clone())
+                .index_locations(index_locations.clone())
                 .keyring(keyring_provider);
         Some(CheckUrlClient {
             index_url: index_url.clone(),
             registry_client_builder,
-            client: &upload_client,
+            client: upload_client,
             index_capabilities: IndexCapabilities::default(),
             cache,
         })
@@ -192,6 +478,7 @@ pub(crate) async fn publish(
         None
     };
 
+    let mut summary = PublishSummary::default();
     for group in groups {
         if let Some(check_url_client) = &check_url_client {
             if uv_publish::check_url(
@@ -207,6 +494,7 @@ pub(crate) async fn publish(
                     "File {} already exists, skipping",
                     group.filename
                 )?;
+                summary.skipped += 1;
                 continue;
             }
         }
@@ -236,71 +524,81 @@ pub(crate) async fn publish(
             .await
             .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)))?;
 
-        let uploaded = if direct {
-            if dry_run {
-                // For dry run, call validate since we won't call reserve.
-                let should_upload = uv_publish::validate(
-                    &group.file,
-                    &form_metadata,
-                    &group.raw_filename,
-                    &publish_url,
-                    &token_store,
-                    &upload_client,
-                    &credentials,
-                )
-                .await?;
-                if !should_upload {
+        let uploaded = if dry_run {
+            // Run validation checks on the file, but don't upload it (if possible). In direct
+            // mode, this replaces the reservation.
+            let should_upload = uv_publish::validate(
+                &group.file,
+                &form_metadata,
+                &group.raw_filename,
+                &publish_url,
+                token_store,
+                upload_client,
+                &credentials,
+            )
+            .await?;
+            if should_upload {
+                writeln!(
+                    printer.stderr(),
+                    "Would upload {} to {publish_url}",
+                    group.filename
+                )?;
+                for attestation in &group.attestations {
                     writeln!(
                         printer.stderr(),
-                        "{}",
-                        "File already exists, skipping".dimmed()
+                        "  with attestation {}",
+                        attestation.user_display()
                     )?;
                 }
-                continue;
+                summary.uploaded += 1;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    "File already exists, skipping".dimmed()
+                )?;
+                summary.skipped += 1;
             }
-
+            continue;
+        } else if direct {
             debug!("Using two-phase upload (direct mode)");
             let reporter = PublishReporter::single(printer);
             upload_two_phase(
-                &group,
+                group,
                 &form_metadata,
                 &publish_url,
-                &upload_client,
-                &s3_client,
-                retry_policy,
+                upload_client,
+                s3_client,
+                retry_policy.clone(),
                 &credentials,
                 // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
                 Arc::new(reporter),
             )
             .await?
         } else {
-            // Run validation checks on the file, but don't upload it (if possible).
+            // Run validation checks on the file before uploading it (if possible).
             let should_upload = uv_publish::validate(
                 &group.file,
                 &form_metadata,
                 &group.raw_filename,
                 &publish_url,
-                &token_store,
-                &upload_client,
+                token_store,
+                upload_client,
                 &credentials,
             )
             .await?;
 
-            if dry_run {
-                continue;
-            }
-
             // If validation indicates the file already exists, skip the upload.
             if !should_upload {
                 false
             } else {
                 let reporter = PublishReporter::single(printer);
                 upload(
-                    &group,
+                    group,
                     &form_metadata,
                     &publish_url,
-                    &upload_client,
-                    retry_policy,
+                    upload_client,
+                    retry_policy.clone(),
                     &credentials,
                     check_url_client.as_ref(),
                     &download_concurrency,
@@ -312,23 +610,25 @@ pub(crate) async fn publish(
         };
         info!("Upload succeeded");
 
-        if !uploaded {
+        if uploaded {
+            summary.uploaded += 1;
+        } else {
             writeln!(
                 printer.stderr(),
                 "{}",
                 "File already exists, skipping".dimmed()
             )?;
+            summary.skipped += 1;
         }
     }
 
-    Ok(ExitStatus::Success)
+    Ok(summary)
 }
 
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
     Enabled,
-    #[allow(dead_code)]
     Disabled,
 }
 
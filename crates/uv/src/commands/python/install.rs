@@ -18,6 +18,7 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::Concurrency;
 use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
 use uv_platform::{Arch, Libc};
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::downloads::{
@@ -1254,7 +1255,8 @@ async fn compile_stdlib_bytecode(
         &stdlib_path,
         &installation.executable(false),
         concurrency,
-        cache.root(),
+        cache,
+        LinkMode::default(),
     )
     .await
     .with_context(|| format!("Error compiling bytecode in: {}", stdlib_path.display()))?;
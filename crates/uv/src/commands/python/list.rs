@@ -1,7 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
-use uv_cli::PythonListFormat;
+use uv_cli::OutputFormat;
 use uv_pep440::Version;
 use uv_preview::Preview;
 
@@ -60,7 +60,7 @@ pub(crate) async fn list(
     all_platforms: bool,
     all_arches: bool,
     show_urls: bool,
-    output_format: PythonListFormat,
+    output_format: OutputFormat,
     python_downloads_json_url: Option<String>,
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
@@ -225,7 +225,7 @@ pub(crate) async fn list(
     }
 
     match output_format {
-        PythonListFormat::Json => {
+        OutputFormat::Json => {
             let data = include
                 .iter()
                 .map(|(key, uri)| -> Result<_> {
@@ -271,7 +271,7 @@ pub(crate) async fn list(
                 .collect::<Result<Vec<_>>>()?;
             writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
         }
-        PythonListFormat::Text => {
+        OutputFormat::Text => {
             // Compute the width of the first column.
             let width = include
                 .iter()
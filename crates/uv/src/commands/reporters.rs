@@ -8,6 +8,7 @@ use std::time::Duration;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use crate::commands::human_readable_bytes;
 use crate::printer::Printer;
@@ -26,6 +27,80 @@ use uv_static::EnvVars;
 static HAS_UV_TEST_NO_CLI_PROGRESS: LazyLock<bool> =
     LazyLock::new(|| env::var(EnvVars::UV_TEST_NO_CLI_PROGRESS).is_ok());
 
+/// A machine-readable progress event, reported with `--progress json`.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum ProgressEvent<'a> {
+    /// The resolver selected a version or URL for a package.
+    Resolve {
+        package: &'a PackageName,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    },
+    /// The resolver finished.
+    ResolveComplete,
+    /// A distribution was prepared for installation, i.e., downloaded or built.
+    PrepareProgress {
+        package: &'a PackageName,
+        completed: u64,
+        total: Option<u64>,
+    },
+    /// All distributions were prepared for installation.
+    PrepareComplete,
+    /// A wheel was installed.
+    InstallProgress {
+        package: &'a PackageName,
+        completed: u64,
+        total: Option<u64>,
+    },
+    /// A source distribution started building.
+    BuildStart { id: usize, source: String },
+    /// A source distribution finished building.
+    BuildComplete { id: usize, source: String },
+    /// A Git repository started updating.
+    CheckoutStart {
+        id: usize,
+        url: String,
+        rev: &'a str,
+    },
+    /// A Git repository finished updating.
+    CheckoutComplete {
+        id: usize,
+        url: String,
+        rev: &'a str,
+    },
+    /// A download, upload, or extraction started.
+    RequestStart {
+        id: usize,
+        direction: Direction,
+        name: &'a str,
+        size: Option<u64>,
+    },
+    /// A download, upload, or extraction made progress, with the total number of bytes so far.
+    RequestProgress {
+        id: usize,
+        bytes: u64,
+        size: Option<u64>,
+    },
+    /// A download, upload, or extraction finished.
+    RequestComplete { id: usize, direction: Direction },
+}
+
+/// Write a progress event to stderr as a single line of JSON, if the printer reports progress
+/// as JSON.
+fn emit(printer: Printer, event: &ProgressEvent) {
+    if printer != Printer::JsonProgress {
+        return;
+    }
+    if let Ok(mut line) = serde_json::to_string(event) {
+        // Write the event in a single call, such that concurrent events aren't interleaved.
+        line.push('\n');
+        let _ = printer.stderr().write_str(&line);
+    }
+}
+
 #[derive(Debug)]
 struct ProgressReporter {
     printer: Printer,
@@ -103,7 +178,8 @@ impl BarState {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 enum Direction {
     Upload,
     Download,
@@ -131,17 +207,18 @@ impl From<uv_python::downloads::Direction> for Direction {
 
 impl ProgressReporter {
     fn new(root: ProgressBar, multi_progress: MultiProgress, printer: Printer) -> Self {
-        let mode = if env::var(EnvVars::JPY_SESSION_NAME).is_ok() {
-            // Disable concurrent progress bars when running inside a Jupyter notebook
-            // because the Jupyter terminal does not support clearing previous lines.
-            // See: https://github.com/astral-sh/uv/issues/3887.
-            ProgressMode::Single
-        } else {
-            ProgressMode::Multi {
-                state: Arc::default(),
-                multi_progress,
-            }
-        };
+        let mode =
+            if env::var(EnvVars::JPY_SESSION_NAME).is_ok() && printer != Printer::JsonProgress {
+                // Disable concurrent progress bars when running inside a Jupyter notebook
+                // because the Jupyter terminal does not support clearing previous lines.
+                // See: https://github.com/astral-sh/uv/issues/3887.
+                ProgressMode::Single
+            } else {
+                ProgressMode::Multi {
+                    state: Arc::default(),
+                    multi_progress,
+                }
+            };
 
         Self {
             printer,
@@ -150,6 +227,14 @@ impl ProgressReporter {
         }
     }
 
+    /// Returns `true` if progress should be logged to stderr as plain lines, since the progress
+    /// bars are hidden.
+    fn log_to_stderr(&self, multi_progress: &MultiProgress) -> bool {
+        multi_progress.is_hidden()
+            && !*HAS_UV_TEST_NO_CLI_PROGRESS
+            && self.printer != Printer::JsonProgress
+    }
+
     fn on_build_start(&self, source: &BuildableSource) -> usize {
         let ProgressMode::Multi {
             multi_progress,
@@ -173,11 +258,19 @@ impl ProgressReporter {
             "Building".bold().cyan(),
             source.to_color_string()
         );
-        if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
+        if self.log_to_stderr(multi_progress) {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
         progress.set_message(message);
 
+        emit(
+            self.printer,
+            &ProgressEvent::BuildStart {
+                id,
+                source: source.to_string(),
+            },
+        );
+
         state.headers += 1;
         state.bars.insert(id, ProgressBarKind::Spinner { progress });
         id
@@ -198,12 +291,20 @@ impl ProgressReporter {
             state.bars.remove(&id).unwrap()
         };
 
+        emit(
+            self.printer,
+            &ProgressEvent::BuildComplete {
+                id,
+                source: source.to_string(),
+            },
+        );
+
         let message = format!(
             "      {} {}",
             "Built".bold().green(),
             source.to_color_string()
         );
-        if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
+        if self.log_to_stderr(multi_progress) {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
         progress.finish_with_message(message);
@@ -260,7 +361,7 @@ impl ProgressReporter {
             );
             // If the file is larger than 1MB, show a message to indicate that this may take
             // a while keeping the log concise.
-            if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS && size > 1024 * 1024 {
+            if self.log_to_stderr(multi_progress) && size > 1024 * 1024 {
                 let (bytes, unit) = human_readable_bytes(size);
                 let _ = writeln!(
                     self.printer.stderr(),
@@ -273,7 +374,7 @@ impl ProgressReporter {
             progress.set_message(name);
         } else {
             progress.set_style(ProgressStyle::with_template("{wide_msg:.dim} ....").unwrap());
-            if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
+            if self.log_to_stderr(multi_progress) {
                 let _ = writeln!(
                     self.printer.stderr(),
                     "{} {}",
@@ -286,6 +387,15 @@ impl ProgressReporter {
         }
 
         let id = state.id();
+        emit(
+            self.printer,
+            &ProgressEvent::RequestStart {
+                id,
+                direction,
+                name: &progress.message(),
+                size,
+            },
+        );
         state
             .bars
             .insert(id, ProgressBarKind::Numeric { progress, size });
@@ -303,6 +413,14 @@ impl ProgressReporter {
         // is fixed
         if let Some(bar) = state.lock().unwrap().bars.get(&id) {
             bar.inc(bytes);
+            emit(
+                self.printer,
+                &ProgressEvent::RequestProgress {
+                    id,
+                    bytes: bar.position(),
+                    size: bar.length(),
+                },
+            );
         }
     }
 
@@ -316,11 +434,12 @@ impl ProgressReporter {
         };
 
         let mut state = state.lock().unwrap();
+        emit(
+            self.printer,
+            &ProgressEvent::RequestComplete { id, direction },
+        );
         if let ProgressBarKind::Numeric { progress, size } = state.bars.remove(&id).unwrap() {
-            if multi_progress.is_hidden()
-                && !*HAS_UV_TEST_NO_CLI_PROGRESS
-                && size.is_none_or(|size| size > 1024 * 1024)
-            {
+            if self.log_to_stderr(multi_progress) && size.is_none_or(|size| size > 1024 * 1024) {
                 let _ = writeln!(
                     self.printer.stderr(),
                     " {} {}",
@@ -382,8 +501,17 @@ impl ProgressReporter {
         );
 
         progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
+        emit(
+            self.printer,
+            &ProgressEvent::CheckoutStart {
+                id,
+                url: url.to_string(),
+                rev,
+            },
+        );
+
         let message = format!("   {} {} ({})", "Updating".bold().cyan(), url, rev.dimmed());
-        if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
+        if self.log_to_stderr(multi_progress) {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
         progress.set_message(message);
@@ -409,13 +537,22 @@ impl ProgressReporter {
             state.bars.remove(&id).unwrap()
         };
 
+        emit(
+            self.printer,
+            &ProgressEvent::CheckoutComplete {
+                id,
+                url: url.to_string(),
+                rev,
+            },
+        );
+
         let message = format!(
             "    {} {} ({})",
             "Updated".bold().green(),
             url,
             rev.dimmed()
         );
-        if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
+        if self.log_to_stderr(multi_progress) {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
         progress.finish_with_message(message);
@@ -453,11 +590,20 @@ impl PrepareReporter {
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
-    fn on_progress(&self, _dist: &CachedDist) {
+    fn on_progress(&self, dist: &CachedDist) {
         self.reporter.root.inc(1);
+        emit(
+            self.reporter.printer,
+            &ProgressEvent::PrepareProgress {
+                package: dist.name(),
+                completed: self.reporter.root.position(),
+                total: self.reporter.root.length(),
+            },
+        );
     }
 
     fn on_complete(&self) {
+        emit(self.reporter.printer, &ProgressEvent::PrepareComplete);
         // Need an extra call to `set_message` here to fully clear avoid leaving ghost output
         // in Jupyter notebooks.
         self.reporter.root.set_message("");
@@ -525,6 +671,20 @@ impl From<Printer> for ResolverReporter {
 
 impl uv_resolver::ResolverReporter for ResolverReporter {
     fn on_progress(&self, name: &PackageName, version_or_url: &VersionOrUrlRef) {
+        emit(
+            self.reporter.printer,
+            &ProgressEvent::Resolve {
+                package: name,
+                version: match version_or_url {
+                    VersionOrUrlRef::Version(version) => Some(version.to_string()),
+                    VersionOrUrlRef::Url(_) => None,
+                },
+                url: match version_or_url {
+                    VersionOrUrlRef::Version(_) => None,
+                    VersionOrUrlRef::Url(url) => Some(url.to_string()),
+                },
+            },
+        );
         match version_or_url {
             VersionOrUrlRef::Version(version) => {
                 self.reporter.root.set_message(format!("{name}=={version}"));
@@ -536,6 +696,7 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
     }
 
     fn on_complete(&self) {
+        emit(self.reporter.printer, &ProgressEvent::ResolveComplete);
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
     }
@@ -601,6 +762,7 @@ impl uv_distribution::Reporter for ResolverReporter {
 
 #[derive(Debug)]
 pub(crate) struct InstallReporter {
+    printer: Printer,
     progress: ProgressBar,
 }
 
@@ -611,7 +773,7 @@ impl From<Printer> for InstallReporter {
             ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
         );
         progress.set_message("Installing wheels...");
-        Self { progress }
+        Self { printer, progress }
     }
 }
 
@@ -627,6 +789,14 @@ impl uv_installer::InstallReporter for InstallReporter {
     fn on_install_progress(&self, wheel: &CachedDist) {
         self.progress.set_message(format!("{wheel}"));
         self.progress.inc(1);
+        emit(
+            self.printer,
+            &ProgressEvent::InstallProgress {
+                package: wheel.name(),
+                completed: self.progress.position(),
+                total: self.progress.length(),
+            },
+        );
     }
 
     fn on_install_complete(&self) {
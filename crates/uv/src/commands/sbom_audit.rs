@@ -0,0 +1,319 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use futures::{StreamExt, TryStreamExt};
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_client::BaseClientBuilder;
+use uv_configuration::Concurrency;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_redacted::DisplaySafeUrl;
+use uv_resolver::{Lock, cyclonedx_json};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The default URL of the OSV API.
+pub(crate) const OSV_URL: &str = "https://api.osv.dev";
+
+/// The maximum number of queries in a single request to the OSV `querybatch` endpoint.
+const OSV_BATCH_SIZE: usize = 1000;
+
+/// The request body of the OSV `POST /v1/querybatch` endpoint.
+///
+/// See: <https://google.github.io/osv.dev/post-v1-querybatch/>
+#[derive(Serialize, Debug)]
+struct QueryBatchRequest<'a> {
+    queries: Vec<Query<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+struct Query<'a> {
+    package: QueryPackage<'a>,
+    version: String,
+}
+
+#[derive(Serialize, Debug)]
+struct QueryPackage<'a> {
+    name: &'a PackageName,
+    ecosystem: &'static str,
+}
+
+/// The response body of the OSV `POST /v1/querybatch` endpoint, with a result for each query.
+#[derive(Deserialize, Debug)]
+struct QueryBatchResponse {
+    results: Vec<QueryResult>,
+}
+
+#[derive(Deserialize, Debug)]
+struct QueryResult {
+    #[serde(default)]
+    vulns: Vec<VulnerabilityId>,
+}
+
+#[derive(Deserialize, Debug)]
+struct VulnerabilityId {
+    id: String,
+}
+
+/// The response body of the OSV `GET /v1/vulns/{id}` endpoint.
+///
+/// See: <https://ossf.github.io/osv-schema/>
+#[derive(Deserialize, Debug)]
+struct OsvVulnerability {
+    id: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    summary: Option<String>,
+    details: Option<String>,
+    published: Option<String>,
+    modified: Option<String>,
+    #[serde(default)]
+    severity: Vec<OsvSeverity>,
+}
+
+#[derive(Deserialize, Debug)]
+struct OsvSeverity {
+    #[serde(rename = "type")]
+    kind: String,
+    score: String,
+}
+
+/// Query the OSV API for the known vulnerabilities affecting the registry packages in the
+/// lockfile.
+pub(crate) async fn query_advisories(
+    lock: &Lock,
+    root: &Path,
+    osv_url: &DisplaySafeUrl,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: Concurrency,
+) -> Result<cyclonedx_json::Advisories> {
+    // Only packages from registries are covered by the `PyPI` ecosystem.
+    let mut packages = Vec::new();
+    for package in lock.packages() {
+        let Some(version) = package.version() else {
+            continue;
+        };
+        if package.index(root)?.is_none() {
+            continue;
+        }
+        packages.push((package.name(), version));
+    }
+
+    let client = client_builder.build();
+    let endpoint = |segments: &[&str]| -> Result<DisplaySafeUrl> {
+        let mut url = osv_url.clone();
+        url.path_segments_mut()
+            .map_err(|()| anyhow::anyhow!("`{osv_url}` is not a valid OSV URL"))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    };
+
+    // Find the vulnerabilities affecting each package.
+    let url = endpoint(&["v1", "querybatch"])?;
+    let mut affected = Vec::new();
+    for chunk in packages.chunks(OSV_BATCH_SIZE) {
+        let request = QueryBatchRequest {
+            queries: chunk
+                .iter()
+                .map(|(name, version)| Query {
+                    package: QueryPackage {
+                        name,
+                        ecosystem: "PyPI",
+                    },
+                    version: version.to_string(),
+                })
+                .collect(),
+        };
+        debug!(
+            "Querying {} packages for vulnerabilities at: {url}",
+            chunk.len()
+        );
+        let response = client
+            .for_host(&url)
+            .post(url.as_str())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&request)?)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status().map_err(Into::into))
+            .with_context(|| format!("Failed to query vulnerabilities from `{osv_url}`"))?;
+        let response: QueryBatchResponse = response
+            .json()
+            .await
+            .with_context(|| format!("Failed to parse the response from `{osv_url}`"))?;
+        if response.results.len() != chunk.len() {
+            bail!(
+                "Expected {} results from `{osv_url}`, but received {}",
+                chunk.len(),
+                response.results.len()
+            );
+        }
+        for ((name, version), result) in chunk.iter().zip(response.results) {
+            for vuln in result.vulns {
+                affected.push((*name, *version, vuln.id));
+            }
+        }
+    }
+
+    // Fetch the details of each vulnerability.
+    let ids = affected
+        .iter()
+        .map(|(.., id)| id.as_str())
+        .collect::<BTreeSet<_>>();
+    let vulnerabilities = futures::stream::iter(ids)
+        .map(|id| {
+            let client = &client;
+            async move {
+                let url = endpoint(&["v1", "vulns", id])?;
+                debug!("Fetching vulnerability `{id}` from: {url}");
+                let response = client
+                    .for_host(&url)
+                    .get(url.as_str())
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status().map_err(Into::into))
+                    .with_context(|| {
+                        format!("Failed to fetch vulnerability `{id}` from `{osv_url}`")
+                    })?;
+                let vulnerability: OsvVulnerability = response
+                    .json()
+                    .await
+                    .with_context(|| format!("Failed to parse the response from `{osv_url}`"))?;
+                Ok::<_, anyhow::Error>((id, vulnerability))
+            }
+        })
+        .buffered(concurrency.downloads)
+        .try_collect::<FxHashMap<_, _>>()
+        .await?;
+
+    let mut advisories = cyclonedx_json::Advisories::default();
+    for (name, version, id) in &affected {
+        let Some(vulnerability) = vulnerabilities.get(id.as_str()) else {
+            continue;
+        };
+        advisories
+            .entry(((*name).clone(), (*version).clone()))
+            .or_default()
+            .push(advisory(vulnerability));
+    }
+
+    Ok(advisories)
+}
+
+/// Convert an OSV vulnerability to an [`cyclonedx_json::Advisory`].
+fn advisory(vulnerability: &OsvVulnerability) -> cyclonedx_json::Advisory {
+    let timestamp = |timestamp: Option<&String>| {
+        timestamp.and_then(|timestamp| timestamp.parse::<Timestamp>().ok())
+    };
+    cyclonedx_json::Advisory {
+        id: vulnerability.id.clone(),
+        aliases: vulnerability.aliases.clone(),
+        summary: vulnerability.summary.clone(),
+        details: vulnerability.details.clone(),
+        cvss: vulnerability
+            .severity
+            .iter()
+            .filter(|severity| severity.kind.starts_with("CVSS_"))
+            .map(|severity| severity.score.clone())
+            .collect(),
+        published: timestamp(vulnerability.published.as_ref()),
+        modified: timestamp(vulnerability.modified.as_ref()),
+        source: "OSV".to_string(),
+        url: format!("https://osv.dev/vulnerability/{}", vulnerability.id),
+    }
+}
+
+/// Report the vulnerabilities recorded in a `CycloneDX` SBOM.
+pub(crate) async fn sbom_audit(sbom: &Path, printer: Printer) -> Result<ExitStatus> {
+    let contents = fs_err::tokio::read(sbom).await?;
+    let bom: serde_json::Value = serde_json::from_slice(&contents)
+        .with_context(|| format!("Failed to parse SBOM at `{}`", sbom.user_display()))?;
+
+    // Identify the name and version of each component.
+    let components = bom["components"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|component| {
+            let bom_ref = component["bom-ref"].as_str()?;
+            let name = component["name"].as_str()?;
+            let version = component["version"].as_str().unwrap_or_default();
+            Some((bom_ref, format!("{name} {version}")))
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    let mut findings = Vec::new();
+    for vulnerability in bom["vulnerabilities"].as_array().into_iter().flatten() {
+        let Some(id) = vulnerability["id"].as_str() else {
+            continue;
+        };
+        let aliases = vulnerability["references"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|reference| reference["id"].as_str())
+            .collect::<Vec<_>>();
+        let summary = vulnerability["description"].as_str();
+        for target in vulnerability["affects"].as_array().into_iter().flatten() {
+            let Some(component) = target["ref"]
+                .as_str()
+                .and_then(|bom_ref| components.get(bom_ref))
+            else {
+                continue;
+            };
+            findings.push((component, id, aliases.clone(), summary));
+        }
+    }
+    findings.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+    if findings.is_empty() {
+        writeln!(printer.stderr(), "No known vulnerabilities found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (component, id, aliases, summary) in &findings {
+        if aliases.is_empty() {
+            writeln!(printer.stdout(), "{}: {}", component.bold(), id.red())?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "{}: {} ({})",
+                component.bold(),
+                id.red(),
+                aliases.join(", ")
+            )?;
+        }
+        if let Some(summary) = summary {
+            writeln!(printer.stdout(), "    {summary}")?;
+        }
+    }
+
+    let packages = findings
+        .iter()
+        .map(|(component, ..)| component)
+        .collect::<BTreeSet<_>>()
+        .len();
+    writeln!(
+        printer.stderr(),
+        "Found {} known {} in {} {}",
+        findings.len(),
+        if findings.len() == 1 {
+            "vulnerability"
+        } else {
+            "vulnerabilities"
+        },
+        packages,
+        if packages == 1 { "package" } else { "packages" }
+    )?;
+
+    Ok(ExitStatus::Failure)
+}
@@ -0,0 +1,135 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_client::BaseClientBuilder;
+use uv_fs::Simplified;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The request body of the Dependency-Track `PUT /api/v1/bom` endpoint.
+///
+/// See: <https://docs.dependencytrack.org/usage/cicd/>
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct BomSubmitRequest<'a> {
+    project_name: &'a str,
+    project_version: &'a str,
+    /// Create the project if it does not exist.
+    auto_create: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    project_tags: Vec<ProjectTag<'a>>,
+    is_latest: bool,
+    /// The Base64-encoded SBOM.
+    bom: String,
+}
+
+#[derive(Serialize, Debug)]
+struct ProjectTag<'a> {
+    name: &'a str,
+}
+
+/// The response body of the Dependency-Track `PUT /api/v1/bom` endpoint.
+#[derive(Deserialize, Debug)]
+struct BomUploadResponse {
+    /// The token of the task that processes the SBOM.
+    token: String,
+}
+
+/// Upload a `CycloneDX` SBOM to a Dependency-Track server.
+pub(crate) async fn sbom_upload(
+    sbom: &Path,
+    server: &DisplaySafeUrl,
+    api_key: &str,
+    project_name: Option<String>,
+    project_version: Option<String>,
+    tags: &[String],
+    latest: bool,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let contents = fs_err::tokio::read(sbom).await?;
+
+    // Default to the name and version of the root component, i.e., the project.
+    let bom: serde_json::Value = serde_json::from_slice(&contents)
+        .with_context(|| format!("Failed to parse SBOM at `{}`", sbom.user_display()))?;
+    let component = &bom["metadata"]["component"];
+    let Some(project_name) =
+        project_name.or_else(|| component["name"].as_str().map(str::to_string))
+    else {
+        bail!(
+            "Unable to determine the project name from the SBOM; provide one with `--project-name`"
+        );
+    };
+    let Some(project_version) =
+        project_version.or_else(|| component["version"].as_str().map(str::to_string))
+    else {
+        bail!(
+            "Unable to determine the version of `{project_name}` from the SBOM; provide one with `--project-version`"
+        );
+    };
+
+    let request = BomSubmitRequest {
+        project_name: &project_name,
+        project_version: &project_version,
+        auto_create: true,
+        project_tags: tags.iter().map(|name| ProjectTag { name }).collect(),
+        is_latest: latest,
+        bom: BASE64_STANDARD.encode(&contents),
+    };
+
+    let mut url = server.clone();
+    url.path_segments_mut()
+        .map_err(|()| anyhow::anyhow!("`{server}` is not a valid Dependency-Track URL"))?
+        .pop_if_empty()
+        .extend(["api", "v1", "bom"]);
+    debug!("Uploading SBOM for `{project_name}` `{project_version}` to: {url}");
+
+    let client = client_builder.build();
+    let response = client
+        .for_host(&url)
+        .put(url.as_str())
+        .header("X-Api-Key", api_key)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(&request)?)
+        .send()
+        .await
+        .with_context(|| format!("Failed to upload SBOM to `{server}`"))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        if body.trim().is_empty() {
+            bail!("Failed to upload SBOM to `{server}` (status code {status})");
+        }
+        bail!(
+            "Failed to upload SBOM to `{server}` (status code {status}): {}",
+            body.trim()
+        );
+    }
+    let response: BomUploadResponse = response
+        .json()
+        .await
+        .with_context(|| format!("Failed to parse the response from `{server}`"))?;
+    debug!(
+        "Dependency-Track is processing the SBOM as task `{}`",
+        response.token
+    );
+
+    writeln!(
+        printer.stderr(),
+        "Uploaded SBOM for {} to {}",
+        format!("{project_name} {project_version}").bold(),
+        server.cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
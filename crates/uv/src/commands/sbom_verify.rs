@@ -0,0 +1,236 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use cyclonedx_bom::models::component::Scope;
+use cyclonedx_bom::models::hash::HashAlgorithm as CycloneDxHashAlgorithm;
+use cyclonedx_bom::prelude::Bom;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_python::PythonEnvironment;
+use uv_resolver::cyclonedx_json;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::freeze::read_hashes;
+use crate::printer::Printer;
+
+/// The packages to compare against an SBOM: the versions of each package, with their hashes.
+type Inventory = BTreeMap<PackageName, BTreeMap<String, BTreeSet<HashDigest>>>;
+
+/// What an SBOM is verified against.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum VerifyTarget<'a> {
+    /// The packages in the lockfile, as exported to the `CycloneDX` SBOM at the given path.
+    Lockfile(&'a Path),
+    /// The packages installed in the project environment.
+    Environment,
+}
+
+/// Compare the components of a `CycloneDX` SBOM against the lockfile or the project environment,
+/// reporting missing, extra, and mismatched packages.
+pub(crate) async fn sbom_verify(
+    sbom: &Path,
+    target: VerifyTarget<'_>,
+    project_dir: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let declared = read_sbom(sbom).await?;
+    let root = declared
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+        .and_then(|component| PackageName::from_str(&component.name).ok());
+    let mut declared = inventory(&declared);
+
+    let (mut actual, source) = match target {
+        VerifyTarget::Lockfile(path) => (inventory(&read_sbom(path).await?), "lockfile"),
+        VerifyTarget::Environment => (installed(project_dir, cache).await?, "environment"),
+    };
+
+    // The root of the SBOM is the project itself, rather than one of its dependencies.
+    if let Some(root) = &root {
+        declared.remove(root);
+        actual.remove(root);
+    }
+
+    let mut discrepancies = 0;
+    for name in declared
+        .keys()
+        .chain(actual.keys())
+        .collect::<BTreeSet<_>>()
+    {
+        match (declared.get(name), actual.get(name)) {
+            (Some(declared), None) => {
+                for version in declared.keys() {
+                    discrepancies += 1;
+                    writeln!(
+                        printer.stdout(),
+                        "{} {} is declared in the SBOM, but missing from the {source}",
+                        name.bold(),
+                        version
+                    )?;
+                }
+            }
+            (None, Some(actual)) => {
+                for version in actual.keys() {
+                    discrepancies += 1;
+                    writeln!(
+                        printer.stdout(),
+                        "{} {} is in the {source}, but not declared in the SBOM",
+                        name.bold(),
+                        version
+                    )?;
+                }
+            }
+            (Some(declared), Some(actual)) => {
+                if declared.keys().ne(actual.keys()) {
+                    discrepancies += 1;
+                    writeln!(
+                        printer.stdout(),
+                        "{} is declared in the SBOM as {}, but the {source} has {}",
+                        name.bold(),
+                        declared.keys().map(|version| version.red()).join(", "),
+                        actual.keys().map(|version| version.green()).join(", "),
+                    )?;
+                    continue;
+                }
+
+                // Any hash in the lockfile or environment must be declared in the SBOM, such that
+                // it vouches for every artifact. Components without hashes aren't compared.
+                for (version, hashes) in declared {
+                    let Some(actual) = actual.get(version) else {
+                        continue;
+                    };
+                    if hashes.is_empty() || actual.is_empty() {
+                        continue;
+                    }
+                    let undeclared = actual.difference(hashes).collect::<Vec<_>>();
+                    if !undeclared.is_empty() {
+                        discrepancies += 1;
+                        writeln!(
+                            printer.stdout(),
+                            "{} {} has hashes in the {source} that aren't declared in the SBOM: {}",
+                            name.bold(),
+                            version,
+                            undeclared.iter().map(|hash| hash.red()).join(", "),
+                        )?;
+                    }
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    if discrepancies == 0 {
+        writeln!(
+            printer.stderr(),
+            "The {source} matches `{}`",
+            sbom.user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Found {discrepancies} {} between the {source} and `{}`",
+        if discrepancies == 1 {
+            "discrepancy"
+        } else {
+            "discrepancies"
+        },
+        sbom.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Read a `CycloneDX` SBOM in JSON or XML format.
+async fn read_sbom(path: &Path) -> Result<Bom> {
+    let contents = fs_err::tokio::read(path).await?;
+    cyclonedx_json::read(&contents)
+        .with_context(|| format!("Failed to parse SBOM at `{}`", path.user_display()))
+}
+
+/// Collect the versions and hashes of the components of an SBOM.
+fn inventory(bom: &Bom) -> Inventory {
+    let mut inventory = Inventory::new();
+    for component in bom.components.iter().flat_map(|components| &components.0) {
+        // Components that are excluded from the runtime environment, e.g., build requirements,
+        // aren't part of the lockfile or the environment.
+        if matches!(component.scope, Some(Scope::Excluded)) {
+            continue;
+        }
+        let Ok(name) = PackageName::from_str(&component.name) else {
+            continue;
+        };
+        let version = component
+            .version
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let hashes = component
+            .hashes
+            .iter()
+            .flat_map(|hashes| &hashes.0)
+            .filter_map(|hash| {
+                let algorithm = match hash.alg {
+                    CycloneDxHashAlgorithm::MD5 => HashAlgorithm::Md5,
+                    CycloneDxHashAlgorithm::SHA_256 => HashAlgorithm::Sha256,
+                    CycloneDxHashAlgorithm::SHA_384 => HashAlgorithm::Sha384,
+                    CycloneDxHashAlgorithm::SHA_512 => HashAlgorithm::Sha512,
+                    CycloneDxHashAlgorithm::BLAKE2b_256 => HashAlgorithm::Blake2b,
+                    _ => return None,
+                };
+                Some(HashDigest {
+                    algorithm,
+                    digest: hash.content.0.to_ascii_lowercase().into(),
+                })
+            });
+        inventory
+            .entry(name)
+            .or_default()
+            .entry(version)
+            .or_default()
+            .extend(hashes);
+    }
+    inventory
+}
+
+/// Collect the versions and hashes of the packages installed in the project environment.
+async fn installed(project_dir: &Path, cache: &Cache) -> Result<Inventory> {
+    let workspace_cache = WorkspaceCache::default();
+    let workspace =
+        Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache).await?;
+
+    let root = workspace.venv(None);
+    if !root.is_dir() {
+        bail!(
+            "No project environment found at `{}`; run `uv sync` to create it",
+            root.user_display().cyan()
+        );
+    }
+    let environment = PythonEnvironment::from_root(&root, cache)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    let mut inventory = Inventory::new();
+    for dist in site_packages.iter() {
+        inventory
+            .entry(dist.name().clone())
+            .or_default()
+            .entry(dist.version().to_string())
+            .or_default()
+            .extend(read_hashes(dist)?);
+    }
+    Ok(inventory)
+}
@@ -326,6 +326,7 @@ pub(crate) async fn install(
         overrides,
         excludes,
         None,
+        &[],
         &client_builder,
     )
     .await?;
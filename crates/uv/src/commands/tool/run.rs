@@ -978,6 +978,7 @@ async fn get_or_create_environment(
         overrides,
         &[],
         None,
+        &[],
         client_builder,
     )
     .await?;
@@ -10,11 +10,12 @@ use std::path::Path;
 use std::process::ExitCode;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
+use std::time::Instant;
 
 use anstream::eprintln;
-use anyhow::{Result, anyhow, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, FromArgMatches};
 use futures::FutureExt;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
@@ -29,12 +30,15 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    CacheInfoFormat, CacheNamespace, Cli, Commands, ConfigCommand, ConfigNamespace, EnvCommand,
+    EnvNamespace, ErrorFormat, ExportFormatArg, GenerateCommand, GenerateNamespace, KernelCommand,
+    KernelNamespace, OutputFormat, PipCommand, PipNamespace, ProgressFormat, ProjectCommand,
+    PythonCommand, PythonNamespace, SbomCommand, SbomNamespace, SelfCommand, SelfNamespace,
+    ToolCommand, ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace,
+    compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
-use uv_configuration::min_stack_size;
+use uv_configuration::{ExportFormat, GitFetchStrategy, RequiredVersion, min_stack_size};
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified};
 #[cfg(feature = "self-update")]
@@ -43,20 +47,23 @@ use uv_pep508::VersionOrUrl;
 use uv_preview::PreviewFeature;
 use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl};
 use uv_python::PythonRequest;
+use uv_redacted::{DisplaySafeUrl, redact_output};
 use uv_requirements::{GroupsSpecification, RequirementsSource};
 use uv_requirements_txt::RequirementsTxtRequirement;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
-use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options};
+use uv_settings::{Combine, ConfigLevel, EnvironmentOptions, FilesystemOptions, Options, Policy};
 use uv_static::EnvVars;
+use uv_warnings::locale::{Locale, Message};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
+use crate::commands::error_code::{self, ErrorCode};
 use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, ExplicitArgument, GlobalSettings, PipCheckSettings, PipCompileSettings,
+    PipDownloadSettings, PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings,
+    PipSizeSettings, PipSyncSettings, PipUninstallSettings, PublishSettings,
 };
 
 pub(crate) mod child;
@@ -66,11 +73,12 @@ mod install_source;
 pub(crate) mod logging;
 pub(crate) mod printer;
 pub(crate) mod settings;
+mod usage_log;
 #[cfg(windows)]
 mod windows_exception;
 
 #[instrument(skip_all)]
-async fn run(mut cli: Cli) -> Result<ExitStatus> {
+async fn run(mut cli: Cli, arguments: Vec<ExplicitArgument>) -> Result<ExitStatus> {
     // Enable flag to pick up warnings generated by workspace loading.
     if cli.top_level.global_args.quiet == 0 {
         uv_warnings::enable();
@@ -142,7 +150,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     };
 
     // Load configuration from the filesystem, prioritizing (in order):
-    // 1. The configuration file specified on the command-line.
+    // 1. The configuration file specified on the command-line, which replaces the user and system
+    //    configuration (apart from any settings locked by the system configuration).
     // 2. The nearest configuration file (`uv.toml` or `pyproject.toml`) above the workspace root.
     //    If found, this file is combined with the user configuration file.
     // 3. The nearest configuration file (`uv.toml` or `pyproject.toml`) in the directory tree,
@@ -157,24 +166,53 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 "The `--config-file` argument expects to receive a `uv.toml` file, not a `pyproject.toml`. If you're trying to run a command from another project, use the `--project` argument instead."
             );
         }
-        Some(FilesystemOptions::from_file(config_file)?)
+        // Settings locked by the system configuration still apply.
+        let system = FilesystemOptions::system_locked()?;
+        let locked = system
+            .as_ref()
+            .map(FilesystemOptions::locked_settings)
+            .unwrap_or_default();
+        Some(FilesystemOptions::from_file(config_file, locked)?).combine(system)
     } else if deprecated_isolated || cli.top_level.no_config {
-        None
-    } else if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
-        // For commands that operate at the user-level, ignore local configuration.
-        FilesystemOptions::user()?.combine(FilesystemOptions::system()?)
-    } else if let Ok(workspace) =
-        Workspace::discover(&project_dir, &DiscoveryOptions::default(), &workspace_cache).await
-    {
-        let project = FilesystemOptions::find(workspace.install_path())?;
-        let system = FilesystemOptions::system()?;
-        let user = FilesystemOptions::user()?;
-        project.combine(user).combine(system)
+        FilesystemOptions::system_locked()?
     } else {
-        let project = FilesystemOptions::find(&project_dir)?;
+        // The system configuration is loaded first, since it can lock settings such that they're
+        // ignored in the user and project configuration.
         let system = FilesystemOptions::system()?;
-        let user = FilesystemOptions::user()?;
-        project.combine(user).combine(system)
+        let locked = system
+            .as_ref()
+            .map(FilesystemOptions::locked_settings)
+            .unwrap_or_default();
+        if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
+            // For commands that operate at the user-level, ignore local configuration.
+            FilesystemOptions::user(locked)?.combine(system)
+        } else if let Ok(workspace) =
+            Workspace::discover(&project_dir, &DiscoveryOptions::default(), &workspace_cache).await
+        {
+            let project = FilesystemOptions::find(workspace.install_path(), locked)?;
+            let user = FilesystemOptions::user(locked)?;
+            project.combine(user).combine(system)
+        } else {
+            let project = FilesystemOptions::find(&project_dir, locked)?;
+            let user = FilesystemOptions::user(locked)?;
+            project.combine(user).combine(system)
+        }
+    };
+
+    // Locked settings can't be overridden on the command-line or with environment variables.
+    if let Some(filesystem) = filesystem.as_ref() {
+        settings::validate_locked_arguments(filesystem.locked_settings(), &arguments)?;
+    }
+
+    // Apply the selected settings profile.
+    let filesystem = if let Some(profile) = cli.top_level.profile.as_deref() {
+        let Some(filesystem) = filesystem.and_then(|filesystem| filesystem.with_profile(profile))
+        else {
+            bail!("Profile `{profile}` is not defined in any configuration file");
+        };
+        Some(filesystem)
+    } else {
+        filesystem
     };
 
     // Parse the external command, if necessary.
@@ -202,7 +240,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .http_proxy(settings.network_settings.http_proxy)
             .https_proxy(settings.network_settings.https_proxy)
-            .no_proxy(settings.network_settings.no_proxy);
+            .no_proxy(settings.network_settings.no_proxy)
+            .concurrent_downloads_per_host(settings.network_settings.concurrent_downloads_per_host)
+            .bandwidth_limit(settings.network_settings.bandwidth_limit)
+            .retry(settings.network_settings.retry.unwrap_or_default());
             Some(
                 RunCommand::from_args(command, client_builder, *module, *script, *gui_script)
                     .await?,
@@ -297,6 +338,33 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 script: Some(script),
                 ..
             }),
+    })
+    | Commands::Sbom(SbomNamespace {
+        command:
+            SbomCommand::Upload(uv_cli::SbomUploadArgs {
+                export:
+                    uv_cli::ExportArgs {
+                        script: Some(script),
+                        ..
+                    },
+                ..
+            })
+            | SbomCommand::Audit(uv_cli::SbomAuditArgs {
+                export:
+                    uv_cli::ExportArgs {
+                        script: Some(script),
+                        ..
+                    },
+                ..
+            })
+            | SbomCommand::Verify(uv_cli::SbomVerifyArgs {
+                export:
+                    uv_cli::ExportArgs {
+                        script: Some(script),
+                        ..
+                    },
+                ..
+            }),
     }) = &*cli.command
     {
         match Pep723Script::read(&script).await {
@@ -349,6 +417,82 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }
     }
 
+    if cli.top_level.cache_args.cache_metrics.is_some()
+        && !globals.preview.is_enabled(PreviewFeature::CacheMetrics)
+    {
+        warn_user!(
+            "Cache metrics are experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheMetrics
+        );
+    }
+
+    // Redact credentials and private index URLs from logs, warnings, and errors, if requested.
+    let redact_logs = cli.top_level.global_args.redact_logs;
+    if redact_logs {
+        uv_redacted::enable_redaction();
+        if !globals.preview.is_enabled(PreviewFeature::RedactLogs) {
+            warn_user!(
+                "Log redaction is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::RedactLogs
+            );
+        }
+    }
+
+    // Enable the audit log, if requested.
+    if let Some(directory) = cli.top_level.global_args.audit_log.as_deref() {
+        if !globals.preview.is_enabled(PreviewFeature::AuditLog) {
+            warn_user!(
+                "The audit log is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::AuditLog
+            );
+        }
+        let path = uv_client::init_audit_log(directory, redact_logs).with_context(|| {
+            format!(
+                "Failed to create audit log in `{}`",
+                directory.user_display()
+            )
+        })?;
+        debug!("Writing audit log to: {}", path.user_display());
+    }
+
+    // Display messages in the user's language, if requested.
+    if globals.preview.is_enabled(PreviewFeature::Localization) {
+        uv_warnings::locale::set_locale(Locale::from_env(&[
+            EnvVars::UV_LOCALE,
+            EnvVars::LC_ALL,
+            EnvVars::LC_MESSAGES,
+            EnvVars::LANG,
+        ]));
+    }
+
+    // Report errors with their error codes, if requested.
+    if let Some(format) = cli.top_level.global_args.error_format {
+        if !globals.preview.is_enabled(PreviewFeature::ErrorCodes) {
+            warn_user!(
+                "Error codes are experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::ErrorCodes
+            );
+        }
+        error_code::enable(format);
+    } else if globals.preview.is_enabled(PreviewFeature::ErrorCodes) {
+        error_code::enable(ErrorFormat::Human);
+    }
+
+    if cli.top_level.global_args.usage_log.is_some()
+        && !globals.preview.is_enabled(PreviewFeature::UsageLog)
+    {
+        warn_user!(
+            "The usage log is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::UsageLog
+        );
+    }
+
+    if globals.output_format == OutputFormat::Json && !supports_output_format(&cli.command) {
+        warn_user!(
+            "`--output-format json` is not supported by this command, and will be ignored. JSON output is supported by `uv sync`, `uv add`, `uv lock`, `uv tree`, `uv version`, `uv python list`, and `uv cache info`."
+        );
+    }
+
     // Resolve the cache settings.
     let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
 
@@ -356,43 +500,36 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     uv_flags::init(EnvironmentFlags::from(&environment))
         .map_err(|()| anyhow::anyhow!("Flags are already initialized"))?;
 
+    // Set the options for fetching Git repositories.
+    uv_git::init_fetch_options(uv_git::GitFetchOptions {
+        shallow: cache_settings.git_fetch_strategy == GitFetchStrategy::Shallow,
+        reference_repositories: cache_settings.git_reference_repositories.clone(),
+        shared_cache_dir: cache_settings.git_shared_cache_dir.clone(),
+    })
+    .map_err(|_| anyhow::anyhow!("Git fetch options are already initialized"))?;
+
+    // Set the inputs to the build environment fingerprint.
+    if let Some(build_fingerprint) = cache_settings.build_fingerprint.clone() {
+        uv_distribution::init_build_fingerprint(build_fingerprint)
+            .map_err(|_| anyhow::anyhow!("Build fingerprint is already initialized"))?;
+    }
+
     // Enforce the required version.
     if let Some(required_version) = globals.required_version.as_ref() {
-        let package_version = uv_pep440::Version::from_str(uv_version::version())?;
-        if !required_version.contains(&package_version) {
-            #[cfg(feature = "self-update")]
-            let hint = {
-                // If the required version range includes a lower bound that's higher than
-                // the current version, suggest `uv self update`.
-                let ranges = release_specifiers_to_ranges(required_version.specifiers().clone());
-
-                if let Some(singleton) = ranges.as_singleton() {
-                    // E.g., `==1.0.0`
-                    format!(
-                        ". Update `uv` by running `{}`.",
-                        format!("uv self update {singleton}").green()
-                    )
-                } else if ranges
-                    .bounding_range()
-                    .iter()
-                    .any(|(lowest, _highest)| match lowest {
-                        Bound::Included(version) => **version > package_version,
-                        Bound::Excluded(version) => **version > package_version,
-                        Bound::Unbounded => false,
-                    })
-                {
-                    // E.g., `>=1.0.0`
-                    format!(". Update `uv` by running `{}`.", "uv self update".cyan())
-                } else {
-                    String::new()
-                }
-            };
-            #[cfg(not(feature = "self-update"))]
-            let hint = "";
-            return Err(anyhow::anyhow!(
-                "Required uv version `{required_version}` does not match the running version `{package_version}`{hint}",
-            ));
+        check_required_version(required_version, None)?;
+    }
+
+    // Enforce the organization policy.
+    if let Some(policy) = Policy::system()? {
+        if let Some(required_version) = policy.options.required_version.as_ref() {
+            check_required_version(required_version, Some(&policy.path))?;
         }
+        if let Some(allowed_indexes) = policy.options.allowed_indexes.clone() {
+            uv_client::init_allowed_indexes(allowed_indexes)
+                .map_err(|_| anyhow::anyhow!("Allowed indexes are already initialized"))?;
+        }
+        uv_settings::init_policy(policy)
+            .map_err(|_| anyhow::anyhow!("Organization policy is already initialized"))?;
     }
 
     // Configure the `tracing` crate, which controls internal logging.
@@ -401,6 +538,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         logging::setup_durations(environment.tracing_durations_file.as_ref())?;
     #[cfg(not(feature = "tracing-durations-export"))]
     let durations_layer = None::<tracing_subscriber::layer::Identity>;
+    #[cfg(feature = "opentelemetry")]
+    let (otel_layer, _otel_guard) = if redact_logs && environment.otel_endpoint.is_some() {
+        // Span attributes are exported as-is, so they can't be redacted.
+        warn_user!(
+            "Ignoring `{}`, since OpenTelemetry spans can't be redacted with `--redact-logs`",
+            EnvVars::UV_OTEL_ENDPOINT
+        );
+        (None, None)
+    } else {
+        logging::setup_opentelemetry(environment.otel_endpoint.as_deref())?
+    };
+    #[cfg(not(feature = "opentelemetry"))]
+    let otel_layer = None::<tracing_subscriber::layer::Identity>;
     logging::setup_logging(
         match globals.verbose {
             0 => logging::Level::Off,
@@ -409,8 +559,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             3.. => logging::Level::TraceAll,
         },
         durations_layer,
+        otel_layer,
         globals.color,
         environment.log_context.unwrap_or_default(),
+        redact_logs,
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
@@ -420,6 +572,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Printer::Silent
     } else if globals.verbose > 0 {
         Printer::Verbose
+    } else if cli.top_level.global_args.progress == Some(ProgressFormat::Json) {
+        if !globals.preview.is_enabled(PreviewFeature::JsonOutput) {
+            warn_user!(
+                "The `--progress json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::JsonOutput
+            );
+        }
+        Printer::JsonProgress
     } else if globals.no_progress {
         Printer::NoProgress
     } else {
@@ -476,7 +636,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_namespace(
+            cache_settings
+                .namespace
+                .as_ref()
+                .map(uv_configuration::CacheNamespace::as_str),
+            cache_settings.namespace_mode == uv_configuration::CacheNamespaceMode::Overlay,
+        )
+        .with_remote(cache_settings.remote_cache);
+    if cache.remote().is_some() && !globals.preview.is_enabled(PreviewFeature::RemoteCache) {
+        warn_user!(
+            "The remote cache is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::RemoteCache
+        );
+    }
+
+    // Periodically evict stale entries from the cache, if an age or size limit is configured.
+    if !cache_settings.no_cache
+        && (cache_settings.max_age.is_some() || cache_settings.max_size.is_some())
+        && !matches!(*cli.command, Commands::Cache(_))
+    {
+        commands::cache_auto_prune(&cache, cache_settings.max_age, cache_settings.max_size);
+    }
 
     // Configure the global network settings.
     let client_builder = BaseClientBuilder::new(
@@ -489,7 +671,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     )
     .http_proxy(globals.network_settings.http_proxy.clone())
     .https_proxy(globals.network_settings.https_proxy.clone())
-    .no_proxy(globals.network_settings.no_proxy.clone());
+    .no_proxy(globals.network_settings.no_proxy.clone())
+    .concurrent_downloads_per_host(globals.network_settings.concurrent_downloads_per_host)
+    .bandwidth_limit(globals.network_settings.bandwidth_limit)
+    .retry(globals.network_settings.retry.clone().unwrap_or_default());
 
     match *cli.command {
         Commands::Auth(AuthNamespace {
@@ -622,6 +807,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::pip_compile(
                 &requirements,
+                &args.requirements_hashes,
                 &constraints,
                 &overrides,
                 &excludes,
@@ -743,6 +929,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &client_builder.subcommand(vec!["pip".to_owned(), "sync".to_owned()]),
                 args.settings.allow_empty_requirements,
                 globals.installer_metadata,
+                args.scripts,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
                 args.settings.build_isolation.clone(),
@@ -875,6 +1062,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             Box::pin(commands::pip_install(
                 &requirements,
+                &args.requirements_hashes,
                 &constraints,
                 &overrides,
                 &excludes,
@@ -900,6 +1088,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
                 globals.installer_metadata,
+                args.scripts,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
                 args.settings.build_isolation.clone(),
@@ -928,6 +1117,94 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             ))
             .await
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Download(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipDownloadSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_constraints = args
+                .build_constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let groups = GroupsSpecification {
+                root: project_dir.to_path_buf(),
+                groups: args.settings.groups,
+            };
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                &args.settings.extras,
+                &groups,
+                args.dest.as_deref().unwrap_or(Path::new(".")),
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.dependency_mode,
+                args.settings.upgrade,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec!["pip".to_owned(), "download".to_owned()]),
+                args.settings.hash_checking,
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.build_isolation.clone(),
+                &args.settings.extra_build_dependencies,
+                &args.settings.extra_build_variables,
+                args.settings.build_options,
+                args.settings.python_version,
+                args.settings.python_platform,
+                globals.python_downloads,
+                args.settings.install_mirrors,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.python,
+                args.settings.system,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         Commands::Pip(PipNamespace {
             command: PipCommand::Uninstall(args),
         }) => {
@@ -977,6 +1254,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::pip_freeze(
                 args.exclude_editable,
                 &args.exclude,
+                args.format,
+                args.hashes,
+                args.include_interpreter,
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
@@ -1099,6 +1379,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.preview,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Size(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipSizeSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::pip_size(
+                args.settings.python.as_deref(),
+                args.settings.system,
+                args.format,
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
         Commands::Pip(PipNamespace {
             command: PipCommand::Debug(_),
         }) => Err(anyhow!(
@@ -1109,13 +1408,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, args.force, cache, printer).await
+            commands::cache_clean(&args.package, args.force, args.built_only, cache, printer).await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, args.force, cache, printer).await
+            commands::cache_prune(
+                args.ci,
+                args.max_age,
+                args.max_size,
+                args.force,
+                cache,
+                printer,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -1123,6 +1430,53 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(args),
+        }) => {
+            let format = match globals.output_format {
+                OutputFormat::Json => CacheInfoFormat::Json,
+                OutputFormat::Text => args.format,
+            };
+            commands::cache_info(&cache, format, args.top, printer, globals.preview)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Warm(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheWarmSettings::resolve(*args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::cache_warm(
+                &project_dir,
+                args.lock_check,
+                args.frozen,
+                args.python_platforms,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder.subcommand(vec!["cache".to_owned(), "warm".to_owned()]),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.dry_run, args.force, cache, printer, globals.preview).await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1159,9 +1513,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.gitignore,
                 args.force_pep517,
                 args.clear,
+                args.check,
+                args.sbom,
+                args.record_sdist,
                 build_constraints,
                 args.hash_checking,
                 args.python,
+                args.python_platform,
                 args.install_mirrors,
                 &args.settings,
                 &client_builder.subcommand(vec!["build".to_owned()]),
@@ -1287,13 +1645,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             .await
         }
         Commands::Self_(SelfNamespace {
-            command:
-                SelfCommand::Version {
-                    short,
-                    output_format,
-                },
+            command: SelfCommand::Version { short },
         }) => {
-            commands::self_version(short, output_format, printer)?;
+            commands::self_version(short, globals.output_format, printer)?;
             Ok(ExitStatus::Success)
         }
         #[cfg(not(feature = "self-update"))]
@@ -1316,7 +1670,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             anyhow::bail!(message);
         }
         Commands::GenerateShellCompletion(args) => {
-            args.shell.generate(&mut Cli::command(), &mut stdout());
+            // Prefer the dynamic completions, which complete values such as package names, falling
+            // back to the static script for shells that don't support them.
+            if !uv_cli::complete::write_registration(args.shell, &mut stdout())? {
+                args.shell.generate(&mut Cli::command(), &mut stdout());
+            }
             Ok(ExitStatus::Success)
         }
         Commands::Tool(ToolNamespace {
@@ -1637,7 +1995,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.all_platforms,
                 args.all_arches,
                 args.show_urls,
-                args.output_format,
+                globals.output_format,
                 args.python_downloads_json_url,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
@@ -1847,7 +2205,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 index_locations,
             } = PublishSettings::resolve(args, filesystem);
 
-            commands::publish(
+            Box::pin(commands::publish(
+                &project_dir,
                 files,
                 publish_url,
                 trusted_publishing,
@@ -1865,7 +2224,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.preview,
                 &cache,
                 printer,
-            )
+            ))
             .await
         }
         Commands::Workspace(WorkspaceNamespace { command }) => match command {
@@ -1879,6 +2238,393 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 commands::list(&project_dir, args.paths, globals.preview, printer).await
             }
         },
+        Commands::Env(EnvNamespace {
+            command: EnvCommand::Info(args),
+        }) => {
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::env_info(&project_dir, args.format, &cache, printer, globals.preview).await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Get(args),
+        }) => {
+            commands::config_get(
+                &project_dir,
+                cli.top_level.config_file.as_deref(),
+                cli.top_level.no_config,
+                &args.key,
+                args.show_origin,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Set(args),
+        }) => {
+            let level = if args.system {
+                ConfigLevel::System
+            } else if args.user {
+                ConfigLevel::User
+            } else {
+                ConfigLevel::Project
+            };
+
+            commands::config_set(
+                &project_dir,
+                &args.key,
+                &args.value,
+                level,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::List(args),
+        }) => {
+            commands::config_list(
+                &project_dir,
+                cli.top_level.config_file.as_deref(),
+                cli.top_level.no_config,
+                args.show_origin,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Kernel(KernelNamespace {
+            command: KernelCommand::Install(args),
+        }) => {
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::kernel_install(
+                &project_dir,
+                args.name,
+                args.display_name,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Sbom(SbomNamespace {
+            command: SbomCommand::Upload(args),
+        }) => {
+            if !globals.preview.is_enabled(PreviewFeature::SbomExport) {
+                warn_user!(
+                    "`uv sbom upload` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                    PreviewFeature::SbomExport
+                );
+            }
+
+            if args.export.format.as_ref().is_some_and(|format| {
+                *format != ExportFormatArg::Builtin(ExportFormat::CycloneDX1_5)
+            }) {
+                bail!(
+                    "`{}` only supports the `{}` format",
+                    "uv sbom upload".green(),
+                    "cyclonedx1.5".cyan()
+                );
+            }
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let export = settings::ExportSettings::resolve(args.export, filesystem, environment);
+            show_settings!(export);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            // Unwrap the script.
+            let script = script.map(|script| match script {
+                Pep723Item::Script(script) => script,
+                Pep723Item::Stdin(..) => unreachable!("`uv sbom upload` does not support stdin"),
+                Pep723Item::Remote(..) => {
+                    unreachable!("`uv sbom upload` does not support remote files")
+                }
+            });
+
+            let client_builder =
+                client_builder.subcommand(vec!["sbom".to_owned(), "upload".to_owned()]);
+
+            // Write the SBOM to the output file, if any, or a temporary file otherwise.
+            let temp_dir = tempfile::tempdir()?;
+            let output_file = export
+                .output_file
+                .unwrap_or_else(|| temp_dir.path().join("sbom.cdx.json"));
+
+            let status = commands::export(
+                &project_dir,
+                Some(ExportFormatArg::Builtin(ExportFormat::CycloneDX1_5)),
+                export.all_packages,
+                export.package,
+                export.prune,
+                export.hashes,
+                export.include_build_dependencies,
+                export.include_interpreter,
+                export.install_options,
+                Some(output_file.clone()),
+                export.extras,
+                export.groups,
+                export.editable,
+                export.lock_check,
+                export.frozen,
+                export.include_annotations,
+                export.include_header,
+                script,
+                export.python,
+                export.install_mirrors,
+                export.settings,
+                client_builder.clone(),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                cli.top_level.no_config,
+                true,
+                None,
+                export.signing_key,
+                &cache,
+                printer,
+                // Avoid repeating the warning above for the export.
+                globals.preview.with(PreviewFeature::SbomExport),
+            )
+            .boxed_local()
+            .await?;
+            if !matches!(status, ExitStatus::Success) {
+                return Ok(status);
+            }
+
+            commands::sbom_upload(
+                &output_file,
+                &args.dependency_track,
+                &args.api_key,
+                args.project_name,
+                args.project_version,
+                &args.tags,
+                args.latest,
+                &client_builder,
+                printer,
+            )
+            .await
+        }
+        Commands::Sbom(SbomNamespace {
+            command: SbomCommand::Audit(args),
+        }) => {
+            if !globals.preview.is_enabled(PreviewFeature::SbomExport) {
+                warn_user!(
+                    "`uv sbom audit` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                    PreviewFeature::SbomExport
+                );
+            }
+
+            if args.export.format.as_ref().is_some_and(|format| {
+                *format != ExportFormatArg::Builtin(ExportFormat::CycloneDX1_5)
+            }) {
+                bail!(
+                    "`{}` only supports the `{}` format",
+                    "uv sbom audit".green(),
+                    "cyclonedx1.5".cyan()
+                );
+            }
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let export = settings::ExportSettings::resolve(args.export, filesystem, environment);
+            show_settings!(export);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            // Unwrap the script.
+            let script = script.map(|script| match script {
+                Pep723Item::Script(script) => script,
+                Pep723Item::Stdin(..) => unreachable!("`uv sbom audit` does not support stdin"),
+                Pep723Item::Remote(..) => {
+                    unreachable!("`uv sbom audit` does not support remote files")
+                }
+            });
+
+            let osv_url = match args.osv_url {
+                Some(osv_url) => osv_url,
+                None => DisplaySafeUrl::parse(commands::OSV_URL)?,
+            };
+
+            // Write the SBOM to the output file, if any, or a temporary file otherwise.
+            let temp_dir = tempfile::tempdir()?;
+            let output_file = export
+                .output_file
+                .unwrap_or_else(|| temp_dir.path().join("sbom.cdx.json"));
+
+            let status = commands::export(
+                &project_dir,
+                Some(ExportFormatArg::Builtin(ExportFormat::CycloneDX1_5)),
+                export.all_packages,
+                export.package,
+                export.prune,
+                export.hashes,
+                export.include_build_dependencies,
+                export.include_interpreter,
+                export.install_options,
+                Some(output_file.clone()),
+                export.extras,
+                export.groups,
+                export.editable,
+                export.lock_check,
+                export.frozen,
+                export.include_annotations,
+                export.include_header,
+                script,
+                export.python,
+                export.install_mirrors,
+                export.settings,
+                client_builder.subcommand(vec!["sbom".to_owned(), "audit".to_owned()]),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                cli.top_level.no_config,
+                true,
+                Some(osv_url),
+                export.signing_key,
+                &cache,
+                printer,
+                // Avoid repeating the warning above for the export.
+                globals.preview.with(PreviewFeature::SbomExport),
+            )
+            .boxed_local()
+            .await?;
+            if !matches!(status, ExitStatus::Success) {
+                return Ok(status);
+            }
+
+            commands::sbom_audit(&output_file, printer).await
+        }
+        Commands::Sbom(SbomNamespace {
+            command: SbomCommand::Verify(args),
+        }) => {
+            if !globals.preview.is_enabled(PreviewFeature::SbomExport) {
+                warn_user!(
+                    "`uv sbom verify` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                    PreviewFeature::SbomExport
+                );
+            }
+
+            if args.export.format.as_ref().is_some_and(|format| {
+                *format != ExportFormatArg::Builtin(ExportFormat::CycloneDX1_5)
+            }) {
+                bail!(
+                    "`{}` only supports the `{}` format",
+                    "uv sbom verify".green(),
+                    "cyclonedx1.5".cyan()
+                );
+            }
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            if args.environment {
+                if script.is_some() {
+                    bail!(
+                        "`{}` is not supported with `{}`",
+                        "--script".green(),
+                        "--environment".green()
+                    );
+                }
+                return commands::sbom_verify(
+                    &args.sbom,
+                    commands::VerifyTarget::Environment,
+                    &project_dir,
+                    &cache,
+                    printer,
+                )
+                .await;
+            }
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let export = settings::ExportSettings::resolve(args.export, filesystem, environment);
+            show_settings!(export);
+
+            // Unwrap the script.
+            let script = script.map(|script| match script {
+                Pep723Item::Script(script) => script,
+                Pep723Item::Stdin(..) => unreachable!("`uv sbom verify` does not support stdin"),
+                Pep723Item::Remote(..) => {
+                    unreachable!("`uv sbom verify` does not support remote files")
+                }
+            });
+
+            // Write the SBOM of the lockfile to the output file, if any, or a temporary file
+            // otherwise.
+            let temp_dir = tempfile::tempdir()?;
+            let output_file = export
+                .output_file
+                .unwrap_or_else(|| temp_dir.path().join("sbom.cdx.json"));
+
+            let status = commands::export(
+                &project_dir,
+                Some(ExportFormatArg::Builtin(ExportFormat::CycloneDX1_5)),
+                export.all_packages,
+                export.package,
+                export.prune,
+                // Include hashes, unless disabled, such that they can be compared.
+                export.hashes.or(Some(true)),
+                export.include_build_dependencies,
+                export.include_interpreter,
+                export.install_options,
+                Some(output_file.clone()),
+                export.extras,
+                export.groups,
+                export.editable,
+                export.lock_check,
+                export.frozen,
+                export.include_annotations,
+                export.include_header,
+                script,
+                export.python,
+                export.install_mirrors,
+                export.settings,
+                client_builder.subcommand(vec!["sbom".to_owned(), "verify".to_owned()]),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                cli.top_level.no_config,
+                true,
+                None,
+                export.signing_key,
+                &cache,
+                printer,
+                // Avoid repeating the warning above for the export.
+                globals.preview.with(PreviewFeature::SbomExport),
+            )
+            .boxed_local()
+            .await?;
+            if !matches!(status, ExitStatus::Success) {
+                return Ok(status);
+            }
+
+            commands::sbom_verify(
+                &args.sbom,
+                commands::VerifyTarget::Lockfile(&output_file),
+                &project_dir,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Generate(GenerateNamespace { command }) => match command {
+            GenerateCommand::Devcontainer(args) => {
+                commands::generate_devcontainer(
+                    &project_dir,
+                    args.python,
+                    args.compose,
+                    args.force,
+                    cli.top_level.no_config,
+                    globals.preview,
+                    printer,
+                )
+                .await
+            }
+        },
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1926,6 +2672,78 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     }
 }
 
+/// Error if the running version of uv doesn't satisfy the given [`RequiredVersion`].
+///
+/// If the requirement stems from an organization policy, `source` is the path to the policy file.
+/// Returns `true` if the command supports the global `--output-format` option.
+fn supports_output_format(command: &Commands) -> bool {
+    match command {
+        Commands::Project(command) => matches!(
+            **command,
+            ProjectCommand::Sync(_)
+                | ProjectCommand::Add(_)
+                | ProjectCommand::Lock(_)
+                | ProjectCommand::Tree(_)
+                | ProjectCommand::Version(_)
+        ),
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::List(_),
+        })
+        | Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(_),
+        })
+        | Commands::Self_(SelfNamespace {
+            command: SelfCommand::Version { .. },
+        }) => true,
+        _ => false,
+    }
+}
+
+fn check_required_version(required_version: &RequiredVersion, source: Option<&Path>) -> Result<()> {
+    let package_version = uv_pep440::Version::from_str(uv_version::version())?;
+    if !required_version.contains(&package_version) {
+        #[cfg(feature = "self-update")]
+        let hint = {
+            // If the required version range includes a lower bound that's higher than
+            // the current version, suggest `uv self update`.
+            let ranges = release_specifiers_to_ranges(required_version.specifiers().clone());
+
+            if let Some(singleton) = ranges.as_singleton() {
+                // E.g., `==1.0.0`
+                format!(
+                    ". Update `uv` by running `{}`.",
+                    format!("uv self update {singleton}").green()
+                )
+            } else if ranges
+                .bounding_range()
+                .iter()
+                .any(|(lowest, _highest)| match lowest {
+                    Bound::Included(version) => **version > package_version,
+                    Bound::Excluded(version) => **version > package_version,
+                    Bound::Unbounded => false,
+                })
+            {
+                // E.g., `>=1.0.0`
+                format!(". Update `uv` by running `{}`.", "uv self update".cyan())
+            } else {
+                String::new()
+            }
+        };
+        #[cfg(not(feature = "self-update"))]
+        let hint = "";
+        if let Some(source) = source {
+            return Err(anyhow::anyhow!(
+                "Required uv version `{required_version}` (from the organization policy at `{}`) does not match the running version `{package_version}`{hint}",
+                source.user_display()
+            ));
+        }
+        return Err(anyhow::anyhow!(
+            "Required uv version `{required_version}` does not match the running version `{package_version}`{hint}",
+        ));
+    }
+    Ok(())
+}
+
 /// Run a [`ProjectCommand`].
 async fn run_project(
     project_command: Box<ProjectCommand>,
@@ -2056,6 +2874,7 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.refresh_script,
                 args.isolated,
                 args.all_packages,
                 args.package,
@@ -2095,6 +2914,7 @@ async fn run_project(
             // Initialize the cache.
             let cache = cache.init().await?.with_refresh(
                 args.refresh
+                    .clone()
                     .combine(Refresh::from(args.settings.reinstall.clone()))
                     .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
             );
@@ -2106,36 +2926,56 @@ async fn run_project(
                 Pep723Item::Remote(..) => unreachable!("`uv lock` does not support remote files"),
             });
 
-            Box::pin(commands::sync(
-                project_dir,
-                args.lock_check,
-                args.frozen,
-                args.dry_run,
-                args.active,
-                args.all_packages,
-                args.package,
-                args.extras,
-                args.groups,
-                args.editable,
-                args.install_options,
-                args.modifications,
-                args.python,
-                args.python_platform,
-                args.install_mirrors,
-                globals.python_preference,
-                globals.python_downloads,
-                args.settings,
-                client_builder.subcommand(vec!["sync".to_owned()]),
-                script,
-                globals.installer_metadata,
-                globals.concurrency,
-                no_config,
-                &cache,
-                printer,
-                globals.preview,
-                args.output_format,
-            ))
-            .await
+            // With `--watch`, sync again whenever the project changes, until interrupted. The
+            // settings are resolved once, so changes to them require restarting the watch.
+            let mut watcher = args.watch.then(|| commands::SyncWatcher::new(project_dir));
+            let client_builder = client_builder.subcommand(vec!["sync".to_owned()]);
+
+            loop {
+                let args = args.clone();
+                let result = Box::pin(commands::sync(
+                    project_dir,
+                    args.lock_check,
+                    args.frozen,
+                    args.dry_run,
+                    args.active,
+                    args.relocatable,
+                    args.target_rootfs,
+                    args.all_packages,
+                    args.package,
+                    args.extras,
+                    args.groups,
+                    args.editable,
+                    args.install_options,
+                    args.modifications,
+                    args.python,
+                    args.python_platform,
+                    args.install_mirrors,
+                    globals.python_preference,
+                    globals.python_downloads,
+                    args.settings,
+                    client_builder.clone(),
+                    script.clone(),
+                    globals.installer_metadata,
+                    globals.concurrency,
+                    no_config,
+                    &cache,
+                    printer,
+                    globals.preview,
+                    globals.output_format,
+                ))
+                .await;
+
+                let Some(watcher) = watcher.as_mut() else {
+                    return result;
+                };
+
+                // Report any failure, but keep watching.
+                if result.is_err() {
+                    exit_code(result);
+                }
+                watcher.wait(printer).await?;
+            }
         }
         ProjectCommand::Lock(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
@@ -2172,6 +3012,8 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.interactive,
+                globals.output_format,
                 args.refresh,
                 args.python,
                 args.install_mirrors,
@@ -2281,8 +3123,10 @@ async fn run_project(
                 project_dir,
                 args.lock_check,
                 args.frozen,
+                args.interactive,
                 args.active,
                 args.no_sync,
+                globals.output_format,
                 args.no_install_project,
                 args.only_install_project,
                 args.no_install_workspace,
@@ -2392,7 +3236,7 @@ async fn run_project(
                 args.value,
                 args.bump,
                 args.short,
-                args.output_format,
+                globals.output_format,
                 project_dir,
                 args.package,
                 explicit_project,
@@ -2401,6 +3245,9 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.sync_lock,
+                args.changelog,
+                args.tag,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -2444,6 +3291,7 @@ async fn run_project(
                 args.invert,
                 args.outdated,
                 args.show_sizes,
+                globals.output_format,
                 args.python_version,
                 args.python_platform,
                 args.python,
@@ -2483,6 +3331,8 @@ async fn run_project(
                 args.package,
                 args.prune,
                 args.hashes,
+                args.include_build_dependencies,
+                args.include_interpreter,
                 args.install_options,
                 args.output_file,
                 args.extras,
@@ -2502,6 +3352,8 @@ async fn run_project(
                 globals.concurrency,
                 no_config,
                 globals.quiet > 0,
+                None,
+                args.signing_key,
                 &cache,
                 printer,
                 globals.preview,
@@ -2568,13 +3420,48 @@ where
         }
     }
 
+    let args = args.into_iter().map(Into::into).collect::<Vec<OsString>>();
+
+    // Respond to completion requests from the scripts registered by `uv generate-shell-completion`.
+    match uv_cli::complete::try_complete(&args) {
+        Ok(true) => return ExitCode::SUCCESS,
+        Ok(false) => {}
+        Err(err) => err.exit(),
+    }
+
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
-        Ok(cli) => cli,
+    let mut command = Cli::command();
+    let parsed = command
+        .try_get_matches_from_mut(&args)
+        .and_then(|mut matches| {
+            // Track the explicitly provided arguments, which can't override locked settings.
+            let arguments = ExplicitArgument::collect(&command, &matches);
+            Cli::from_arg_matches_mut(&mut matches)
+                .map(|cli| (cli, arguments))
+                .map_err(|err| err.format(&mut command))
+        });
+    let (cli, arguments) = match parsed {
+        Ok(parsed) => parsed,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
             {
+                // If a `uv-<subcommand>` executable exists on the `PATH`, defer to it.
+                if let Some(executable) = commands::find_external(subcommand) {
+                    let position = args
+                        .iter()
+                        .skip(1)
+                        .position(|arg| arg.as_os_str() == subcommand.as_str())
+                        .map_or(args.len(), |position| position + 2);
+                    let runtime = tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                        .expect("Failed building the Runtime");
+                    let result =
+                        runtime.block_on(commands::external(&executable, &args[position..]));
+                    return exit_code(result);
+                }
+
                 match subcommand.as_str() {
                     "compile" => {
                         err.insert(
@@ -2619,6 +3506,17 @@ where
         }
     };
 
+    let cache_metrics = cli.top_level.cache_args.cache_metrics.clone();
+
+    // Resolve the usage log path before `--directory` changes the working directory.
+    let usage_log = cli.top_level.global_args.usage_log.as_deref().map(|path| {
+        (
+            std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+            usage_log::command_name(&args),
+        )
+    });
+    let start = Instant::now();
+
     // See `min_stack_size` doc comment about `main2`
     let min_stack_size = min_stack_size();
     let main2 = move || {
@@ -2628,13 +3526,32 @@ where
             .build()
             .expect("Failed building the Runtime");
         // Box the large main future to avoid stack overflows.
-        let result = runtime.block_on(Box::pin(run(cli)));
+        let result = runtime.block_on(Box::pin(run(cli, arguments)));
         // Avoid waiting for pending tasks to complete.
         //
         // The resolver may have kicked off HTTP requests during resolution that
         // turned out to be unnecessary. Waiting for those to complete can cause
         // the CLI to hang before exiting.
         runtime.shutdown_background();
+        // Write out the cache metrics, now that the command has completed.
+        if let Some(path) = cache_metrics
+            && let Err(err) = write_cache_metrics(&path)
+        {
+            warn_user!(
+                "Failed to write cache metrics to `{}`: {err}",
+                path.user_display()
+            );
+        }
+        // Append a record of the invocation to the usage log.
+        if let Some((path, command)) = usage_log
+            && let Err(err) =
+                usage_log::write_usage_record(&path, &command, start.elapsed(), &result)
+        {
+            warn_user!(
+                "Failed to write usage log to `{}`: {err}",
+                path.user_display()
+            );
+        }
         result
     };
     let result = std::thread::Builder::new()
@@ -2645,20 +3562,46 @@ where
         .join()
         .expect("Tokio executor failed, was there a panic?");
 
+    exit_code(result)
+}
+
+/// Convert the result of a command into an [`ExitCode`], reporting any error to stderr.
+fn exit_code(result: Result<ExitStatus>) -> ExitCode {
     match result {
         Ok(code) => code.into(),
         Err(err) => {
             trace!("Error trace: {err:?}");
+            let code = ErrorCode::from_error(&err);
+            if error_code::format() == Some(ErrorFormat::Json) {
+                error_code::report_json(code, err.chain());
+                return ExitStatus::Error.into();
+            }
             let mut causes = err.chain();
             eprintln!(
                 "{}: {}",
-                "error".red().bold(),
-                causes.next().unwrap().to_string().trim()
+                Message::Error.text().red().bold(),
+                redact_output(&causes.next().unwrap().to_string()).trim()
             );
             for err in causes {
-                eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
+                eprintln!(
+                    "  {}: {}",
+                    Message::CausedBy.text().red().bold(),
+                    redact_output(&err.to_string()).trim()
+                );
             }
+            error_code::report_code(code);
             ExitStatus::Error.into()
         }
     }
 }
+
+/// Write the cache metrics for the current process to the given path, or to stderr for `-`.
+fn write_cache_metrics(path: &Path) -> Result<()> {
+    let metrics = serde_json::to_string_pretty(&uv_cache::metrics().snapshot())?;
+    if path == Path::new("-") {
+        eprintln!("{metrics}");
+    } else {
+        fs_err::write(path, metrics + "\n")?;
+    }
+    Ok(())
+}
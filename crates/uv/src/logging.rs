@@ -1,11 +1,22 @@
+use std::io::Write;
 use std::str::FromStr;
 
 use anyhow::Context;
+#[cfg(feature = "opentelemetry")]
+use opentelemetry::trace::TracerProvider;
+#[cfg(feature = "opentelemetry")]
+use opentelemetry_otlp::WithExportConfig;
+#[cfg(feature = "opentelemetry")]
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
 #[cfg(feature = "tracing-durations-export")]
 use tracing_durations_export::{
     DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard, plot::PlotConfig,
 };
+#[cfg(feature = "opentelemetry")]
+use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::filter::Directive;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Layer, Registry};
@@ -14,6 +25,7 @@ use tracing_tree::time::Uptime;
 
 use uv_cli::ColorChoice;
 use uv_logging::UvFormat;
+use uv_redacted::redact_text;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
@@ -30,11 +42,15 @@ pub(crate) enum Level {
 /// The [`Level`] is used to dictate the default filters (which can be overridden by the `RUST_LOG`
 /// environment variable) along with the formatting of the output. For example, [`Level::Verbose`]
 /// includes targets and timestamps, along with all `uv=debug` messages by default.
+///
+/// If `redact` is set, credentials and private index URLs are redacted from each message.
 pub(crate) fn setup_logging(
     level: Level,
     durations_layer: Option<impl Layer<Registry> + Send + Sync>,
+    otel_layer: Option<impl Layer<Registry> + Send + Sync>,
     color: ColorChoice,
     detailed_logging: bool,
+    redact: bool,
 ) -> anyhow::Result<()> {
     // We use directives here to ensure `RUST_LOG` can override them
     let default_directive = match level {
@@ -64,6 +80,13 @@ pub(crate) fn setup_logging(
                 .with_target("", tracing::level_filters::LevelFilter::INFO),
         )
     });
+    let otel_layer = otel_layer.map(|otel_layer| {
+        otel_layer.with_filter(
+            tracing_subscriber::filter::Targets::new()
+                .with_target("", tracing::level_filters::LevelFilter::INFO),
+        )
+    });
+    let span_layer = Layer::and_then(durations_layer, otel_layer);
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
@@ -86,11 +109,16 @@ pub(crate) fn setup_logging(
             ColorChoice::Auto => unreachable!("anstream can't return auto as choice"),
         };
     let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
+    let writer = if redact {
+        BoxMakeWriter::new(RedactingMakeWriter(writer))
+    } else {
+        BoxMakeWriter::new(writer)
+    };
 
     if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
         tracing_subscriber::registry()
-            .with(durations_layer)
+            .with(span_layer)
             .with(
                 HierarchicalLayer::default()
                     .with_targets(true)
@@ -102,7 +130,7 @@ pub(crate) fn setup_logging(
             .init();
     } else {
         tracing_subscriber::registry()
-            .with(durations_layer)
+            .with(span_layer)
             .with(
                 tracing_subscriber::fmt::layer()
                     .event_format(UvFormat::default())
@@ -116,6 +144,59 @@ pub(crate) fn setup_logging(
     Ok(())
 }
 
+/// A [`MakeWriter`] that redacts credentials and private index URLs from each log message.
+struct RedactingMakeWriter<M>(M);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter {
+            inner: self.0.make_writer(),
+            buffer: Vec::new(),
+        }
+    }
+}
+
+/// A writer that buffers a log message, and redacts it before writing it to the inner writer.
+///
+/// A writer is created for each message, so the buffered message is redacted and written when the
+/// writer is flushed or dropped.
+struct RedactingWriter<W: Write> {
+    inner: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> RedactingWriter<W> {
+    fn write_buffer(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let message = String::from_utf8_lossy(&self.buffer);
+        self.inner.write_all(redact_text(&message).as_bytes())?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.write_buffer()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for RedactingWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.write_buffer();
+    }
+}
+
 /// Setup the `TRACING_DURATIONS_FILE` environment variable to enable tracing durations.
 #[cfg(feature = "tracing-durations-export")]
 pub(crate) fn setup_durations(
@@ -150,3 +231,52 @@ pub(crate) fn setup_durations(
         Ok((None, None))
     }
 }
+
+/// A `tracing` layer that records spans to OpenTelemetry.
+#[cfg(feature = "opentelemetry")]
+pub(crate) type OpenTelemetryTracingLayer = OpenTelemetryLayer<Registry, SdkTracer>;
+
+/// Flushes and shuts down the OpenTelemetry tracer provider when dropped.
+#[cfg(feature = "opentelemetry")]
+pub(crate) struct OpenTelemetryGuard(SdkTracerProvider);
+
+#[cfg(feature = "opentelemetry")]
+impl Drop for OpenTelemetryGuard {
+    fn drop(&mut self) {
+        if let Err(err) = self.0.shutdown() {
+            uv_warnings::warn_user!("Failed to export OpenTelemetry spans: {err}");
+        }
+    }
+}
+
+/// Setup the `UV_OTEL_ENDPOINT` environment variable to export tracing spans over OTLP/HTTP.
+#[cfg(feature = "opentelemetry")]
+pub(crate) fn setup_opentelemetry(
+    otel_endpoint: Option<&str>,
+) -> anyhow::Result<(
+    Option<OpenTelemetryTracingLayer>,
+    Option<OpenTelemetryGuard>,
+)> {
+    if let Some(endpoint) = otel_endpoint {
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()
+            .context("Couldn't create the UV_OTEL_ENDPOINT exporter")?;
+        let resource = opentelemetry_sdk::Resource::builder()
+            .with_service_name("uv")
+            .with_attribute(opentelemetry::KeyValue::new(
+                "service.version",
+                uv_version::version(),
+            ))
+            .build();
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(resource)
+            .build();
+        let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("uv"));
+        Ok((Some(layer), Some(OpenTelemetryGuard(provider))))
+    } else {
+        Ok((None, None))
+    }
+}
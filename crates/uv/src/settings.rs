@@ -12,16 +12,16 @@ use uv_auth::Service;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, ExternalCommand,
-    GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs,
-    PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
-    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
-    PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
-    VersionBumpSpec, VersionFormat,
+    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, CacheWarmArgs, ColorChoice,
+    ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, OutputFormat, PipCheckArgs,
+    PipCompileArgs, PipDownloadArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
+    PipSizeArgs, PipSizeFormat, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs,
+    PythonInstallArgs, PythonListArgs, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
+    ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
+    AuthorFrom, BuildArgs, ExportArgs, ExportFormatArg, FormatArgs, PublishArgs, PythonDirArgs,
     ResolverInstallerArgs, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, check_conflicts, flag, resolve_flag, resolver_installer_options,
@@ -30,18 +30,20 @@ use uv_cli::{
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode, EnvFile,
-    ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode, IndexStrategy,
+    BandwidthLimit, BuildFingerprint, BuildIsolation, BuildOptions, CacheMaxAge, CacheMaxSize,
+    CacheNamespace, CacheNamespaceMode, Concurrency, DependencyGroups, DryRun, EditableMode,
+    EnvFile, ExtrasSpecification, GitFetchStrategy, GitLfsSetting, HashCheckingMode, IndexStrategy,
     InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, PipCompileFormat,
-    ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
-    TrustedPublishing, Upgrade, VersionControlSystem,
+    PipFreezeFormat, ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion, TargetTriple,
+    TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
-    PackageConfigSettings, Requirement,
+    PackageConfigSettings, Requirement, RequirementsHash, RetrySettings,
 };
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
+use uv_options_metadata::{OptionEntry, OptionField, OptionSet, OptionsMetadata, Visit};
 use uv_pep508::{MarkerTree, RequirementOrigin};
 use uv_preview::Preview;
 use uv_pypi_types::SupportedEnvironments;
@@ -52,8 +54,9 @@ use uv_resolver::{
     PrereleaseMode, ResolutionMode,
 };
 use uv_settings::{
-    Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
-    PythonInstallMirrors, ResolverInstallerOptions, ResolverInstallerSchema, ResolverOptions,
+    Combine, ENVIRONMENT_SETTINGS, EnvFlag, EnvironmentOptions, FilesystemOptions, Options,
+    PipOptions, PublishOptions, PythonInstallMirrors, ResolverInstallerOptions,
+    ResolverInstallerSchema, ResolverOptions,
 };
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -82,6 +85,7 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    pub(crate) output_format: OutputFormat,
 }
 
 impl GlobalSettings {
@@ -124,9 +128,9 @@ impl GlobalSettings {
             },
             network_settings,
             concurrency: Concurrency {
-                downloads: environment
-                    .concurrency
-                    .downloads
+                downloads: args
+                    .max_concurrent_downloads
+                    .combine(environment.concurrency.downloads)
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
@@ -161,8 +165,13 @@ impl GlobalSettings {
             .unwrap_or_default(),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
-            no_progress: resolve_flag(args.no_progress, "no-progress", environment.no_progress)
-                .is_enabled()
+            no_progress: resolve_profile_flag(
+                args.no_progress,
+                "no-progress",
+                environment.no_progress,
+                workspace.and_then(|workspace| workspace.no_progress),
+            )
+            .is_enabled()
                 || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !resolve_flag(
                 args.no_installer_metadata,
@@ -170,6 +179,7 @@ impl GlobalSettings {
                 environment.no_installer_metadata,
             )
             .is_enabled(),
+            output_format: args.output_format.unwrap_or_default(),
         }
     }
 }
@@ -245,8 +255,11 @@ pub(crate) struct NetworkSettings {
     pub(crate) https_proxy: Option<ProxyUrl>,
     pub(crate) no_proxy: Option<Vec<String>>,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) concurrent_downloads_per_host: Option<NonZeroUsize>,
+    pub(crate) bandwidth_limit: Option<BandwidthLimit>,
     pub(crate) timeout: Duration,
     pub(crate) retries: u32,
+    pub(crate) retry: Option<RetrySettings>,
 }
 
 impl NetworkSettings {
@@ -314,6 +327,17 @@ impl NetworkSettings {
         let http_proxy = workspace.and_then(|workspace| workspace.globals.http_proxy.clone());
         let https_proxy = workspace.and_then(|workspace| workspace.globals.https_proxy.clone());
         let no_proxy = workspace.and_then(|workspace| workspace.globals.no_proxy.clone());
+        let concurrent_downloads_per_host = environment.concurrency.downloads_per_host.combine(
+            workspace.and_then(|workspace| workspace.globals.concurrent_downloads_per_host),
+        );
+        let bandwidth_limit = environment
+            .bandwidth_limit
+            .combine(workspace.and_then(|workspace| workspace.globals.bandwidth_limit));
+        let retry = workspace.and_then(|workspace| workspace.globals.retry.clone());
+        let retries = environment
+            .http_retries
+            .or_else(|| retry.as_ref().and_then(|retry| retry.retries))
+            .unwrap_or(uv_client::DEFAULT_RETRIES);
 
         Self {
             connectivity,
@@ -323,8 +347,11 @@ impl NetworkSettings {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            concurrent_downloads_per_host,
+            bandwidth_limit,
             timeout: environment.http_timeout,
-            retries: environment.http_retries,
+            retries,
+            retry,
         }
     }
 
@@ -347,6 +374,15 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) max_age: Option<CacheMaxAge>,
+    pub(crate) max_size: Option<CacheMaxSize>,
+    pub(crate) namespace: Option<CacheNamespace>,
+    pub(crate) namespace_mode: CacheNamespaceMode,
+    pub(crate) remote_cache: Option<DisplaySafeUrl>,
+    pub(crate) git_fetch_strategy: GitFetchStrategy,
+    pub(crate) git_reference_repositories: Vec<PathBuf>,
+    pub(crate) git_shared_cache_dir: Option<PathBuf>,
+    pub(crate) build_fingerprint: Option<BuildFingerprint>,
 }
 
 impl CacheSettings {
@@ -360,6 +396,25 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            max_age: workspace.and_then(|workspace| workspace.globals.cache_max_age),
+            max_size: workspace.and_then(|workspace| workspace.globals.cache_max_size),
+            namespace: workspace.and_then(|workspace| workspace.globals.cache_namespace.clone()),
+            namespace_mode: workspace
+                .and_then(|workspace| workspace.globals.cache_namespace_mode)
+                .unwrap_or_default(),
+            remote_cache: args
+                .remote_cache
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.remote_cache.clone())),
+            git_fetch_strategy: workspace
+                .and_then(|workspace| workspace.globals.git_fetch_strategy)
+                .unwrap_or_default(),
+            git_reference_repositories: workspace
+                .and_then(|workspace| workspace.globals.git_reference_repositories.clone())
+                .unwrap_or_default(),
+            git_shared_cache_dir: workspace
+                .and_then(|workspace| workspace.globals.git_shared_cache_dir.clone()),
+            build_fingerprint: workspace
+                .and_then(|workspace| workspace.globals.build_fingerprint.clone()),
         }
     }
 }
@@ -501,6 +556,42 @@ pub(crate) enum FrozenSource {
     Configuration,
 }
 
+/// The flags enabled by the selected settings profile, if any.
+#[derive(Debug, Default, Clone, Copy)]
+struct ProfileFlags {
+    frozen: Option<bool>,
+    locked: Option<bool>,
+}
+
+impl From<Option<&FilesystemOptions>> for ProfileFlags {
+    fn from(filesystem: Option<&FilesystemOptions>) -> Self {
+        filesystem
+            .map(|filesystem| Self {
+                frozen: filesystem.frozen,
+                locked: filesystem.locked,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Resolve a boolean flag from CLI arguments, an environment variable, and the selected profile.
+///
+/// The CLI argument takes precedence over the environment variable, which takes precedence over
+/// the profile.
+fn resolve_profile_flag(
+    cli_flag: bool,
+    name: &'static str,
+    env_flag: EnvFlag,
+    profile: Option<bool>,
+) -> Flag {
+    let flag = resolve_flag(cli_flag, name, env_flag);
+    if !flag.is_enabled() && env_flag.value.is_none() && profile == Some(true) {
+        Flag::from_config(name)
+    } else {
+        flag
+    }
+}
+
 /// Convert a resolved flag to an optional frozen source.
 fn resolve_frozen(flag: Flag) -> Option<FrozenSource> {
     if flag.is_enabled() {
@@ -546,6 +637,7 @@ pub(crate) struct RunSettings {
     pub(crate) no_project: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) refresh_script: bool,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -596,6 +688,7 @@ impl RunSettings {
             active,
             no_active,
             no_sync,
+            refresh_script,
             locked,
             frozen,
             installer,
@@ -611,15 +704,20 @@ impl RunSettings {
             no_env_file,
             max_recursion_depth,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
 
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
+        let filesystem_env_file = filesystem
+            .as_ref()
+            .and_then(|fs| fs.env_file.clone())
+            .unwrap_or_default();
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
         let no_sync = resolve_flag(no_sync, "no-sync", environment.no_sync);
 
         // Check for conflicts between locked and frozen.
@@ -679,6 +777,7 @@ impl RunSettings {
             package,
             no_project,
             no_sync: no_sync.is_enabled(),
+            refresh_script,
             active: flag(active, no_active, "active"),
             python: python.and_then(Maybe::into_option),
             python_platform,
@@ -687,7 +786,11 @@ impl RunSettings {
                 resolver_installer_options(installer, build),
                 filesystem,
             ),
-            env_file: EnvFile::from_args(env_file, no_env_file),
+            env_file: if no_env_file || !env_file.is_empty() {
+                EnvFile::from_args(env_file, no_env_file)
+            } else {
+                filesystem_env_file
+            },
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -1166,7 +1269,6 @@ pub(crate) struct PythonListSettings {
     pub(crate) all_arches: bool,
     pub(crate) all_versions: bool,
     pub(crate) show_urls: bool,
-    pub(crate) output_format: PythonListFormat,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
@@ -1188,7 +1290,6 @@ impl PythonListSettings {
             only_installed,
             only_downloads,
             show_urls,
-            output_format,
             python_downloads_json_url: python_downloads_json_url_arg,
         } = args;
 
@@ -1240,7 +1341,6 @@ impl PythonListSettings {
             all_arches,
             all_versions,
             show_urls,
-            output_format,
             python_downloads_json_url,
             python_install_mirror,
             pypy_install_mirror,
@@ -1557,6 +1657,9 @@ pub(crate) struct SyncSettings {
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    pub(crate) relocatable: bool,
+    pub(crate) target_rootfs: Option<PathBuf>,
+    pub(crate) watch: bool,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: Option<EditableMode>,
@@ -1569,7 +1672,6 @@ pub(crate) struct SyncSettings {
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
-    pub(crate) output_format: SyncFormat,
 }
 
 impl SyncSettings {
@@ -1608,7 +1710,10 @@ impl SyncSettings {
             frozen,
             active,
             no_active,
+            relocatable,
+            target_rootfs,
             dry_run,
+            watch,
             installer,
             build,
             refresh,
@@ -1619,8 +1724,8 @@ impl SyncSettings {
             python_platform,
             check,
             no_check,
-            output_format,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
@@ -1639,8 +1744,8 @@ impl SyncSettings {
         };
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
 
         // Check for conflicts between locked and frozen.
         check_conflicts(locked, frozen);
@@ -1650,12 +1755,14 @@ impl SyncSettings {
         let no_editable = no_editable || environment.no_editable.value == Some(true);
 
         Self {
-            output_format,
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             dry_run,
             script,
             active: flag(active, no_active, "active"),
+            relocatable,
+            target_rootfs,
+            watch,
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -1710,6 +1817,7 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) interactive: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -1729,12 +1837,14 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            interactive,
             script,
             resolver,
             build,
             refresh,
             python,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
 
         let filesystem_install_mirrors = filesystem
             .clone()
@@ -1742,8 +1852,9 @@ impl LockSettings {
             .unwrap_or_default();
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(check_exists, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen =
+            resolve_profile_flag(check_exists, "frozen", environment.frozen, profile.frozen);
 
         // Check for conflicts between locked and frozen.
         check_conflicts(locked, frozen);
@@ -1758,6 +1869,7 @@ impl LockSettings {
             lock_check,
             frozen: resolve_frozen(frozen),
             dry_run: DryRun::from_args(dry_run),
+            interactive,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -1775,6 +1887,7 @@ impl LockSettings {
 pub(crate) struct AddSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
+    pub(crate) interactive: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<String>,
@@ -1835,6 +1948,7 @@ impl AddSettings {
             no_sync,
             locked,
             frozen,
+            interactive,
             active,
             no_active,
             installer,
@@ -1854,6 +1968,7 @@ impl AddSettings {
             no_install_package,
             only_install_package,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
 
         // Resolve flags from CLI and environment variables.
         let dev = dev || environment.dev.value == Some(true);
@@ -1938,8 +2053,8 @@ impl AddSettings {
         let lfs = GitLfsSetting::new(lfs.then_some(true), environment.lfs);
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
         let no_sync = resolve_flag(no_sync, "no-sync", environment.no_sync);
 
         // Check for conflicts between locked and frozen.
@@ -1951,6 +2066,7 @@ impl AddSettings {
         Self {
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
+            interactive,
             active: flag(active, no_active, "active"),
             no_sync: no_sync.is_enabled(),
             packages,
@@ -2036,6 +2152,7 @@ impl RemoveSettings {
             script,
             python,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
 
         // Resolve flags from CLI and environment variables.
         let dev = dev || environment.dev.value == Some(true);
@@ -2061,8 +2178,8 @@ impl RemoveSettings {
             .collect();
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
         let no_sync = resolve_flag(no_sync, "no-sync", environment.no_sync);
 
         // Check for conflicts between locked and frozen.
@@ -2099,12 +2216,14 @@ pub(crate) struct VersionSettings {
     pub(crate) value: Option<String>,
     pub(crate) bump: Vec<VersionBumpSpec>,
     pub(crate) short: bool,
-    pub(crate) output_format: VersionFormat,
     pub(crate) dry_run: bool,
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) sync_lock: bool,
+    pub(crate) changelog: bool,
+    pub(crate) tag: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -2123,11 +2242,13 @@ impl VersionSettings {
             value,
             bump,
             short,
-            output_format,
             dry_run,
             no_sync,
             locked,
             frozen,
+            sync_lock,
+            changelog,
+            tag,
             active,
             no_active,
             installer,
@@ -2136,6 +2257,7 @@ impl VersionSettings {
             package,
             python,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
 
         let filesystem_install_mirrors = filesystem
             .clone()
@@ -2143,8 +2265,8 @@ impl VersionSettings {
             .unwrap_or_default();
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
         let no_sync = resolve_flag(no_sync, "no-sync", environment.no_sync);
 
         // Check for conflicts between locked and frozen.
@@ -2157,12 +2279,14 @@ impl VersionSettings {
             value,
             bump,
             short,
-            output_format,
             dry_run,
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             active: flag(active, no_active, "active"),
             no_sync: no_sync.is_enabled(),
+            sync_lock,
+            changelog,
+            tag,
             package,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -2227,6 +2351,7 @@ impl TreeSettings {
             python_platform,
             python,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
 
         let filesystem_install_mirrors = filesystem
             .clone()
@@ -2234,8 +2359,8 @@ impl TreeSettings {
             .unwrap_or_default();
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
 
         // Check for conflicts between locked and frozen.
         check_conflicts(locked, frozen);
@@ -2277,19 +2402,22 @@ impl TreeSettings {
 }
 
 /// The resolved settings to use for an `export` invocation.
-#[expect(clippy::struct_excessive_bools, dead_code)]
+#[expect(dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) struct ExportSettings {
-    pub(crate) format: Option<ExportFormat>,
+    pub(crate) format: Option<ExportFormatArg>,
     pub(crate) all_packages: bool,
     pub(crate) package: Vec<PackageName>,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: Option<EditableMode>,
-    pub(crate) hashes: bool,
+    pub(crate) hashes: Option<bool>,
+    pub(crate) include_build_dependencies: bool,
+    pub(crate) include_interpreter: bool,
     pub(crate) install_options: InstallOptions,
     pub(crate) output_file: Option<PathBuf>,
+    pub(crate) signing_key: Option<PathBuf>,
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) include_annotations: bool,
@@ -2333,7 +2461,10 @@ impl ExportSettings {
             no_editable,
             hashes,
             no_hashes,
+            include_build_dependencies,
+            include_interpreter,
             output_file,
+            signing_key,
             no_emit_project,
             only_emit_project,
             no_emit_workspace,
@@ -2350,14 +2481,15 @@ impl ExportSettings {
             script,
             python,
         } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
         // Resolve flags from CLI and environment variables.
-        let locked = resolve_flag(locked, "locked", environment.locked);
-        let frozen = resolve_flag(frozen_cli, "frozen", environment.frozen);
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen_cli, "frozen", environment.frozen, profile.frozen);
 
         // Check for conflicts between locked and frozen.
         check_conflicts(locked, frozen);
@@ -2391,7 +2523,9 @@ impl ExportSettings {
                 all_groups,
             ),
             editable: flag(editable, no_editable, "editable").map(EditableMode::from),
-            hashes: flag(hashes, no_hashes, "hashes").unwrap_or(true),
+            hashes: flag(hashes, no_hashes, "hashes"),
+            include_build_dependencies,
+            include_interpreter,
             install_options: InstallOptions::new(
                 no_emit_project,
                 only_emit_project,
@@ -2403,6 +2537,7 @@ impl ExportSettings {
                 only_emit_package,
             ),
             output_file,
+            signing_key,
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             include_annotations: flag(annotate, no_annotate, "annotate").unwrap_or(true),
@@ -2418,6 +2553,61 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for a `cache warm` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheWarmSettings {
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: Option<FrozenSource>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl CacheWarmSettings {
+    /// Resolve the [`CacheWarmSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: CacheWarmArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let CacheWarmArgs {
+            locked,
+            frozen,
+            python_platform,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+        let profile = ProfileFlags::from(filesystem.as_ref());
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // Resolve flags from CLI and environment variables.
+        let locked = resolve_profile_flag(locked, "locked", environment.locked, profile.locked);
+        let frozen = resolve_profile_flag(frozen, "frozen", environment.frozen, profile.frozen);
+
+        // Check for conflicts between locked and frozen.
+        check_conflicts(locked, frozen);
+
+        Self {
+            lock_check: resolve_lock_check(locked),
+            frozen: resolve_frozen(frozen),
+            python_platforms: python_platform,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `format` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct FormatSettings {
@@ -2454,6 +2644,7 @@ impl FormatSettings {
 pub(crate) struct PipCompileSettings {
     pub(crate) format: Option<PipCompileFormat>,
     pub(crate) src_file: Vec<PathBuf>,
+    pub(crate) requirements_hashes: Vec<RequirementsHash>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
@@ -2476,6 +2667,7 @@ impl PipCompileSettings {
     ) -> Self {
         let PipCompileArgs {
             src_file,
+            requirements_hash,
             constraints,
             overrides,
             excludes,
@@ -2588,6 +2780,7 @@ impl PipCompileSettings {
         Self {
             format,
             src_file,
+            requirements_hashes: requirements_hash,
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -2667,6 +2860,7 @@ pub(crate) struct PipSyncSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) scripts: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2699,6 +2893,7 @@ impl PipSyncSettings {
             no_break_system_packages,
             target,
             prefix,
+            no_scripts,
             allow_empty_requirements,
             no_allow_empty_requirements,
             no_build,
@@ -2725,6 +2920,7 @@ impl PipSyncSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            scripts: !no_scripts,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2768,12 +2964,14 @@ impl PipSyncSettings {
 pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) requirements_hashes: Vec<RequirementsHash>,
     pub(crate) editables: Vec<String>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) scripts: bool,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) excludes_from_workspace: Vec<PackageName>,
@@ -2793,6 +2991,7 @@ impl PipInstallSettings {
         let PipInstallArgs {
             package,
             requirements,
+            requirements_hash,
             editable,
             constraints,
             overrides,
@@ -2817,6 +3016,7 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            no_scripts,
             no_build,
             build,
             no_binary,
@@ -2886,6 +3086,7 @@ impl PipInstallSettings {
         Self {
             package,
             requirements,
+            requirements_hashes: requirements_hash,
             editables: editable,
             constraints: constraints
                 .into_iter()
@@ -2904,6 +3105,7 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            scripts: !no_scripts,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -2947,6 +3149,97 @@ impl PipInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `pip download` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipDownloadSettings {
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) dest: Option<PathBuf>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipDownloadSettings {
+    /// Resolve the [`PipDownloadSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipDownloadArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipDownloadArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            extra,
+            all_extras,
+            no_all_extras,
+            group,
+            dest,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            python_version,
+            python_platform,
+        } = args;
+
+        Self {
+            package,
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_constraints: build_constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            dest,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras, "all-extras"),
+                    group: Some(group),
+                    no_deps: flag(no_deps, deps, "deps"),
+                    python_version,
+                    python_platform,
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip uninstall` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipUninstallSettings {
@@ -3008,6 +3301,9 @@ impl PipUninstallSettings {
 pub(crate) struct PipFreezeSettings {
     pub(crate) exclude_editable: bool,
     pub(crate) exclude: FxHashSet<PackageName>,
+    pub(crate) format: PipFreezeFormat,
+    pub(crate) hashes: bool,
+    pub(crate) include_interpreter: bool,
     pub(crate) paths: Option<Vec<PathBuf>>,
     pub(crate) settings: PipSettings,
 }
@@ -3022,6 +3318,9 @@ impl PipFreezeSettings {
         let PipFreezeArgs {
             exclude_editable,
             exclude,
+            format,
+            hashes,
+            include_interpreter,
             strict,
             no_strict,
             python,
@@ -3036,6 +3335,9 @@ impl PipFreezeSettings {
         Self {
             exclude_editable,
             exclude: exclude.into_iter().collect(),
+            format,
+            hashes,
+            include_interpreter,
             paths,
             settings: PipSettings::combine(
                 PipOptions {
@@ -3247,6 +3549,42 @@ impl PipCheckSettings {
     }
 }
 
+/// The resolved settings to use for a `pip size` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipSizeSettings {
+    pub(crate) format: PipSizeFormat,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipSizeSettings {
+    /// Resolve the [`PipSizeSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipSizeArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipSizeArgs {
+            format,
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            format,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    ..PipOptions::default()
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `build` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct BuildSettings {
@@ -3261,9 +3599,13 @@ pub(crate) struct BuildSettings {
     pub(crate) gitignore: bool,
     pub(crate) force_pep517: bool,
     pub(crate) clear: bool,
+    pub(crate) check: bool,
+    pub(crate) sbom: bool,
+    pub(crate) record_sdist: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -3286,6 +3628,9 @@ impl BuildSettings {
             list,
             force_pep517,
             clear,
+            check,
+            sbom,
+            record_sdist,
             build_constraints,
             require_hashes,
             no_require_hashes,
@@ -3296,6 +3641,7 @@ impl BuildSettings {
             create_gitignore,
             no_create_gitignore,
             python,
+            python_platform,
             build,
             refresh,
             resolver,
@@ -3316,6 +3662,9 @@ impl BuildSettings {
             build_logs: flag(build_logs, no_build_logs, "build-logs").unwrap_or(true),
             force_pep517,
             clear,
+            check,
+            sbom,
+            record_sdist,
             gitignore: flag(create_gitignore, no_create_gitignore, "create-gitignore")
                 .unwrap_or(true),
             build_constraints: build_constraints
@@ -3327,6 +3676,7 @@ impl BuildSettings {
                 flag(verify_hashes, no_verify_hashes, "verify-hashes"),
             ),
             python: python.and_then(Maybe::into_option),
+            python_platform,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors: environment
@@ -3422,6 +3772,7 @@ impl VenvSettings {
 /// ([`InstallerArgs`], represented as [`InstallerOptions`]).
 #[derive(Debug, Clone)]
 pub(crate) struct InstallerSettingsRef<'a> {
+    pub(crate) build_constraints: &'a [Requirement],
     pub(crate) index_locations: &'a IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -3445,6 +3796,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
 /// ([`ResolverArgs`], represented as [`ResolverOptions`]).
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ResolverSettings {
+    pub(crate) build_constraints: Vec<Requirement>,
     pub(crate) build_options: BuildOptions,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
@@ -3468,6 +3820,8 @@ pub(crate) struct ResolverSettings {
 impl ResolverSettings {
     /// Resolve the [`ResolverSettings`] from the CLI and filesystem configuration.
     pub(crate) fn combine(args: ResolverOptions, filesystem: Option<FilesystemOptions>) -> Self {
+        let build_constraints = build_constraints_from_filesystem(filesystem.as_ref());
+
         // The problem is that for `upgrade`... we want to combine the two `Upgrade` structs,
         // not the individual fields.
         let options = args.combine(ResolverOptions::from(
@@ -3477,10 +3831,45 @@ impl ResolverSettings {
                 .unwrap_or_default(),
         ));
 
-        Self::from(options)
+        Self {
+            build_constraints,
+            ..Self::from(options)
+        }
+    }
+}
+
+/// Disallow building source distributions, if required by the organization policy.
+fn enforce_build_policy(build_options: BuildOptions) -> BuildOptions {
+    if uv_settings::policy().is_some_and(|policy| policy.options.no_build) {
+        BuildOptions::new(build_options.no_binary().clone(), NoBuild::All)
+    } else {
+        build_options
+    }
+}
+
+/// Require hashes for all distributions, if required by the organization policy.
+fn enforce_hash_policy(hash_checking: Option<HashCheckingMode>) -> Option<HashCheckingMode> {
+    if uv_settings::policy().is_some_and(|policy| policy.options.require_hashes) {
+        Some(HashCheckingMode::Require)
+    } else {
+        hash_checking
     }
 }
 
+/// Read the `build-constraint-dependencies` from the filesystem configuration.
+///
+/// Unlike the build constraints of a workspace, which are read from the `pyproject.toml` and
+/// recorded in the lockfile, these include the constraints from user- and system-level `uv.toml`
+/// files, and are applied to all builds, including those of third-party source distributions.
+fn build_constraints_from_filesystem(filesystem: Option<&FilesystemOptions>) -> Vec<Requirement> {
+    filesystem
+        .and_then(|configuration| configuration.build_constraint_dependencies.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|requirement| Requirement::from(requirement.with_origin(RequirementOrigin::Workspace)))
+        .collect()
+}
+
 impl From<ResolverOptions> for ResolverSettings {
     fn from(value: ResolverOptions) -> Self {
         let index_locations = IndexLocations::new(
@@ -3498,9 +3887,11 @@ impl From<ResolverOptions> for ResolverSettings {
                 .map(Index::from)
                 .collect(),
             value.no_index.unwrap_or_default(),
-        );
+        )
+        .with_tracking_policy(value.index_tracking_policy.unwrap_or_default());
         Self {
             index_locations,
+            build_constraints: Vec::new(),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
@@ -3522,10 +3913,10 @@ impl From<ResolverOptions> for ResolverSettings {
                 value.no_sources_package.unwrap_or_default(),
             ),
             upgrade: value.upgrade.unwrap_or_default(),
-            build_options: BuildOptions::new(
+            build_options: enforce_build_policy(BuildOptions::new(
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
-            ),
+            )),
         }
     }
 }
@@ -3548,6 +3939,8 @@ impl ResolverInstallerSettings {
         args: ResolverInstallerOptions,
         filesystem: Option<FilesystemOptions>,
     ) -> Self {
+        let build_constraints = build_constraints_from_filesystem(filesystem.as_ref());
+
         let options = args.combine(ResolverInstallerOptions::from(
             filesystem
                 .map(FilesystemOptions::into_options)
@@ -3555,7 +3948,9 @@ impl ResolverInstallerSettings {
                 .unwrap_or_default(),
         ));
 
-        Self::from(options)
+        let mut settings = Self::from(options);
+        settings.resolver.build_constraints = build_constraints;
+        settings
     }
 }
 
@@ -3576,16 +3971,18 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 .map(Index::from)
                 .collect(),
             value.no_index.unwrap_or_default(),
-        );
+        )
+        .with_tracking_policy(value.index_tracking_policy.unwrap_or_default());
         Self {
             resolver: ResolverSettings {
-                build_options: BuildOptions::new(
+                build_constraints: Vec::new(),
+                build_options: enforce_build_policy(BuildOptions::new(
                     NoBinary::from_args(
                         value.no_binary,
                         value.no_binary_package.unwrap_or_default(),
                     ),
                     NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
-                ),
+                )),
                 config_setting: value.config_settings.unwrap_or_default(),
                 config_settings_package: value.config_settings_package.unwrap_or_default(),
                 dependency_metadata: DependencyMetadata::from_entries(
@@ -3708,6 +4105,7 @@ impl PipSettings {
             no_index,
             find_links,
             index_strategy,
+            index_tracking_policy,
             torch_backend,
             keyring_provider,
             no_build,
@@ -3768,6 +4166,7 @@ impl PipSettings {
             no_index: top_level_no_index,
             find_links: top_level_find_links,
             index_strategy: top_level_index_strategy,
+            index_tracking_policy: top_level_index_tracking_policy,
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
@@ -3806,6 +4205,7 @@ impl PipSettings {
         let extra_index_url = extra_index_url.combine(top_level_extra_index_url);
         let find_links = find_links.combine(top_level_find_links);
         let index_strategy = index_strategy.combine(top_level_index_strategy);
+        let index_tracking_policy = index_tracking_policy.combine(top_level_index_tracking_policy);
         let keyring_provider = keyring_provider.combine(top_level_keyring_provider);
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
@@ -3857,6 +4257,11 @@ impl PipSettings {
                     .map(Index::from)
                     .collect(),
                 args.no_index.combine(no_index).unwrap_or_default(),
+            )
+            .with_tracking_policy(
+                args.index_tracking_policy
+                    .combine(index_tracking_policy)
+                    .unwrap_or_default(),
             ),
             extras: ExtrasSpecification::from_args(
                 args.extra.combine(extra).unwrap_or_default(),
@@ -3975,10 +4380,10 @@ impl PipSettings {
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
-            hash_checking: HashCheckingMode::from_args(
+            hash_checking: enforce_hash_policy(HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
                 args.verify_hashes.combine(verify_hashes),
-            ),
+            )),
             python: args.python.combine(python),
             system: args.system.combine(system).unwrap_or_default(),
             break_system_packages: args
@@ -4024,7 +4429,7 @@ impl PipSettings {
                 reinstall_package.unwrap_or_default(),
             ))
             .unwrap_or_default(),
-            build_options: BuildOptions::new(
+            build_options: enforce_build_policy(BuildOptions::new(
                 NoBinary::from_pip_args(args.no_binary.combine(no_binary).unwrap_or_default())
                     .combine(NoBinary::from_args(
                         top_level_no_binary,
@@ -4038,7 +4443,7 @@ impl PipSettings {
                     top_level_no_build,
                     top_level_no_build_package.unwrap_or_default(),
                 )),
-            ),
+            )),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -4049,6 +4454,7 @@ impl PipSettings {
 impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
     fn from(settings: &'a ResolverInstallerSettings) -> Self {
         Self {
+            build_constraints: &settings.resolver.build_constraints,
             index_locations: &settings.resolver.index_locations,
             index_strategy: settings.resolver.index_strategy,
             keyring_provider: settings.resolver.keyring_provider,
@@ -4075,7 +4481,7 @@ pub(crate) struct PublishSettings {
     pub(crate) files: Vec<String>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
-    pub(crate) index: Option<String>,
+    pub(crate) index: Vec<String>,
     pub(crate) dry_run: bool,
     pub(crate) no_attestations: bool,
     pub(crate) direct: bool,
@@ -4208,6 +4614,157 @@ impl AuthLoginSettings {
     }
 }
 
+/// A command-line argument or environment variable that was provided explicitly, and so takes
+/// precedence over the configuration files.
+#[derive(Debug, Clone)]
+pub(crate) struct ExplicitArgument {
+    /// The name of the argument, e.g., `index-url` for `--index-url`.
+    name: String,
+    /// The user-facing source of the argument, e.g., `--index-url` or `UV_INDEX_URL`.
+    source: String,
+}
+
+impl ExplicitArgument {
+    /// Collect the arguments that were provided on the command-line or via environment variables
+    /// for the parsed `matches` of the given `command`.
+    pub(crate) fn collect(command: &clap::Command, matches: &clap::ArgMatches) -> Vec<Self> {
+        fn collect_matches(
+            command: &clap::Command,
+            matches: &clap::ArgMatches,
+            arguments: &mut Vec<ExplicitArgument>,
+        ) {
+            for id in matches.ids() {
+                let Some(arg) = command.get_arguments().find(|arg| arg.get_id() == id) else {
+                    continue;
+                };
+                let Some(long) = arg.get_long() else {
+                    continue;
+                };
+                let source = match matches.value_source(id.as_str()) {
+                    Some(clap::parser::ValueSource::CommandLine) => format!("--{long}"),
+                    Some(clap::parser::ValueSource::EnvVariable) => arg
+                        .get_env()
+                        .map(|env| env.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| format!("--{long}")),
+                    _ => continue,
+                };
+                arguments.push(ExplicitArgument {
+                    name: long.to_string(),
+                    source,
+                });
+            }
+            if let Some((name, matches)) = matches.subcommand()
+                && let Some(command) = command.find_subcommand(name)
+            {
+                collect_matches(command, matches, arguments);
+            }
+        }
+
+        let mut arguments = Vec::new();
+        collect_matches(command, matches, &mut arguments);
+
+        // Include the environment variables that are read outside of the command-line interface.
+        for (var, name) in ENVIRONMENT_SETTINGS {
+            if std::env::var_os(var).is_some_and(|value| !value.is_empty()) {
+                arguments.push(Self {
+                    name: (*name).to_string(),
+                    source: (*var).to_string(),
+                });
+            }
+        }
+
+        arguments
+    }
+}
+
+/// Settings that can be overridden by arguments other than the argument of the same name.
+const RELATED_ARGUMENTS: &[(&str, &[&str])] = &[
+    (
+        "index",
+        &[
+            "default-index",
+            "index-url",
+            "extra-index-url",
+            "find-links",
+            "no-index",
+        ],
+    ),
+    ("index-url", &["default-index", "index", "no-index"]),
+    ("extra-index-url", &["index", "no-index"]),
+    ("find-links", &["no-index"]),
+    (
+        "python-preference",
+        &["managed-python", "no-managed-python"],
+    ),
+    (
+        "python-downloads",
+        &["allow-python-downloads", "no-python-downloads"],
+    ),
+];
+
+/// Validate that none of the `arguments` override a setting that is locked by the system
+/// configuration.
+///
+/// Locked settings take precedence over the command-line and environment variables, so an explicit
+/// argument for a locked setting is an error (rather than being silently ignored).
+pub(crate) fn validate_locked_arguments(
+    locked: &[String],
+    arguments: &[ExplicitArgument],
+) -> anyhow::Result<()> {
+    /// Collect the names of the settings in a locked table, e.g., `pip`.
+    #[derive(Default)]
+    struct FieldNames(Vec<String>);
+
+    impl Visit for FieldNames {
+        fn record_field(&mut self, name: &str, _field: OptionField) {
+            self.0.push(name.to_string());
+        }
+
+        fn record_set(&mut self, _name: &str, set: OptionSet) {
+            set.record(self);
+        }
+    }
+
+    for key in locked {
+        // Arguments are matched by name, e.g., `--index-url` for both `index-url` and
+        // `pip.index-url`.
+        let names = match Options::metadata().find(key) {
+            Some(OptionEntry::Set(set)) => {
+                let mut names = FieldNames::default();
+                set.record(&mut names);
+                names.0
+            }
+            _ => vec![
+                key.rsplit_once('.')
+                    .map_or(key.as_str(), |(_, name)| name)
+                    .to_string(),
+            ],
+        };
+        for name in names.iter().map(String::as_str) {
+            let related = RELATED_ARGUMENTS
+                .iter()
+                .find(|(setting, _)| *setting == name)
+                .map(|(_, related)| *related)
+                .unwrap_or_default();
+            for argument in arguments {
+                let argument_name = argument.name.as_str();
+                if argument_name == name
+                    || argument_name.strip_prefix("no-") == Some(name)
+                    || name.strip_prefix("no-") == Some(argument_name)
+                    || related.contains(&argument_name)
+                {
+                    anyhow::bail!(
+                        "`{}` can't be used, since `{}` is locked by the system configuration",
+                        argument.source,
+                        key
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
     use uv_static::EnvVars;
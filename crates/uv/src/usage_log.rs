@@ -0,0 +1,75 @@
+//! A local log of uv invocations, for measuring adoption and performance across a fleet.
+//!
+//! When enabled, each invocation appends a single JSON record to the usage log. The log is only
+//! ever written to the local filesystem; collecting it is left to the fleet's administrators.
+
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use clap::CommandFactory;
+use jiff::Timestamp;
+use serde::Serialize;
+
+use uv_cache::CacheMetricsSnapshot;
+use uv_cli::Cli;
+
+use crate::commands::ExitStatus;
+
+/// A single entry in the usage log.
+#[derive(Debug, Serialize)]
+struct UsageRecord<'a> {
+    timestamp: Timestamp,
+    version: &'a str,
+    command: &'a str,
+    duration_ms: u64,
+    exit_code: u8,
+    cache: CacheMetricsSnapshot,
+}
+
+/// Return the name of the (sub)command invoked by the given arguments, e.g., `pip install`.
+///
+/// Arguments and options are omitted, since they may include paths, URLs, or credentials.
+pub(crate) fn command_name(args: &[OsString]) -> String {
+    let Ok(matches) = Cli::command().try_get_matches_from(args) else {
+        return String::new();
+    };
+    let mut names = Vec::new();
+    let mut matches = &matches;
+    while let Some((name, submatches)) = matches.subcommand() {
+        names.push(name);
+        matches = submatches;
+    }
+    names.join(" ")
+}
+
+/// Append a record of the current invocation to the usage log at the given path.
+pub(crate) fn write_usage_record(
+    path: &Path,
+    command: &str,
+    duration: Duration,
+    result: &anyhow::Result<ExitStatus>,
+) -> anyhow::Result<()> {
+    let exit_code = match result {
+        Ok(status) => u8::from(*status),
+        Err(_) => u8::from(ExitStatus::Error),
+    };
+    let record = UsageRecord {
+        timestamp: Timestamp::now(),
+        version: uv_version::version(),
+        command,
+        duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        exit_code,
+        cache: uv_cache::metrics().snapshot(),
+    };
+    let mut line = serde_json::to_vec(&record)?;
+    line.push(b'\n');
+    // Write the record in a single call, such that concurrent invocations aren't interleaved.
+    fs_err::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(&line)?;
+    Ok(())
+}
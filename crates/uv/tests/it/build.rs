@@ -2359,3 +2359,86 @@ fn build_no_gitignore() -> Result<()> {
 
     Ok(())
 }
+
+/// Build a platform-specific wheel for another platform with `--python-platform`.
+#[test]
+#[cfg(target_os = "linux")]
+fn build_python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+
+            [build-system]
+            requires = ["setuptools>=42", "wheel"]
+            build-backend = "setuptools.build_meta"
+        "#})?;
+    // Pretend to contain an extension module, such that the wheel is platform-specific.
+    context.temp_dir.child("setup.py").write_str(indoc! {r"
+            from setuptools import setup
+            from setuptools.dist import Distribution
+
+            class BinaryDistribution(Distribution):
+                def has_ext_modules(self):
+                    return True
+
+            setup(distclass=BinaryDistribution)
+        "})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    // The build backend is instructed to target macOS.
+    uv_snapshot!(&context.filters(), context.build()
+        .arg("--wheel")
+        .arg("--no-build-logs")
+        .arg("--python-platform")
+        .arg("aarch64-apple-darwin"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+    Successfully built dist/project-0.1.0-cp312-cp312-macosx_13_0_arm64.whl
+    ");
+
+    context
+        .temp_dir
+        .child("dist")
+        .child("project-0.1.0-cp312-cp312-macosx_13_0_arm64.whl")
+        .assert(predicate::path::is_file());
+
+    // There's no convention for targeting Android, so the wheel is built for the current
+    // platform, and rejected.
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"linux_(x86_64|aarch64)", "linux_[ARCH]")])
+        .collect::<Vec<_>>();
+    uv_snapshot!(&filters, context.build()
+        .arg("--wheel")
+        .arg("--no-build-logs")
+        .arg("--python-platform")
+        .arg("aarch64-linux-android"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+      × Failed to build `[TEMP_DIR]/`
+      ╰─▶ The built wheel `project-0.1.0-cp312-cp312-linux_[ARCH].whl` is not compatible with the target platform (`android` on `aarch64`)
+      help: The build backend may not support building for other platforms, or may require a cross-compiler (e.g., set `CC` via `extra-build-variables`)
+    ");
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::common::{TestContext, uv_snapshot, venv_bin_path};
+use crate::common::{TestContext, apply_filters, uv_snapshot, venv_bin_path};
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::{FileTouch, FileWriteBin, FileWriteStr, PathChild, PathCreateDir};
@@ -6,7 +6,7 @@ use flate2::bufread::GzDecoder;
 use fs_err::File;
 use indoc::{formatdoc, indoc};
 use insta::{assert_json_snapshot, assert_snapshot};
-use std::io::BufReader;
+use std::io::{BufReader, Read};
 use std::path::Path;
 use std::process::Command;
 use tempfile::TempDir;
@@ -1118,6 +1118,296 @@ fn venv_in_source_tree() {
     ");
 }
 
+/// Check the built distributions with `--check`.
+#[test]
+fn build_check() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .init()
+        .arg("--lib")
+        .arg("--name")
+        .arg("foo")
+        .assert()
+        .success();
+
+    // The uv build backend writes reproducible timestamps.
+    uv_snapshot!(context.filters(), context
+        .build()
+        .arg("--check")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built dist/foo-0.1.0.tar.gz
+    Successfully built dist/foo-0.1.0-py3-none-any.whl
+    ");
+
+    context
+        .temp_dir
+        .child("src")
+        .child("foo")
+        .child(".env")
+        .write_str("PASSWORD=hunter2")?;
+
+    uv_snapshot!(context.filters(), context.build().arg("--check").arg("--wheel"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel (uv build backend)...
+      × Failed to build `[TEMP_DIR]/`
+      ╰─▶ The built distribution `foo-0.1.0-py3-none-any.whl` failed the checks:
+          - `foo/.env` should not be part of a distribution
+    ");
+
+    Ok(())
+}
+
+/// Write an SBOM of the build environment with `--sbom`.
+#[test]
+fn build_sbom() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_cyclonedx_filters()
+        .with_filter((
+            regex::escape(env!("CARGO_PKG_VERSION")),
+            "[VERSION]".to_string(),
+        ))
+        .with_filter((r#""content": "[0-9a-f]{64}""#, r#""content": "[HASH]""#));
+
+    context
+        .init()
+        .arg("--lib")
+        .arg("--name")
+        .arg("foo")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context
+        .build()
+        .arg("--wheel")
+        .arg("--sbom")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel (uv build backend)...
+    Successfully built dist/foo-0.1.0-py3-none-any.whl
+    Wrote build environment SBOM to dist/foo-0.1.0-py3-none-any.whl.build.cdx.json
+    ");
+
+    let sbom = context
+        .temp_dir
+        .child("dist")
+        .child("foo-0.1.0-py3-none-any.whl.build.cdx.json");
+    assert_snapshot!(apply_filters(fs_err::read_to_string(sbom)?, context.filters()), @r#"
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "serialNumber": "[SERIAL_NUMBER]",
+      "metadata": {
+        "timestamp": "[TIMESTAMP]",
+        "tools": [
+          {
+            "vendor": "Astral Software Inc.",
+            "name": "uv",
+            "version": "[VERSION]"
+          }
+        ],
+        "component": {
+          "type": "library",
+          "bom-ref": "foo-1@0.1.0",
+          "name": "foo",
+          "version": "0.1.0",
+          "hashes": [
+            {
+              "alg": "SHA-256",
+              "content": "[HASH]"
+            }
+          ],
+          "purl": "pkg:pypi/foo@0.1.0?file_name=foo-0.1.0-py3-none-any.whl",
+          "properties": [
+            {
+              "name": "uv:build:backend",
+              "value": "uv_build"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "uv-build-2@[VERSION]",
+          "name": "uv-build",
+          "version": "[VERSION]",
+          "purl": "pkg:pypi/uv-build@[VERSION]"
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "foo-1@0.1.0",
+          "dependsOn": [
+            "uv-build-2@[VERSION]"
+          ]
+        }
+      ]
+    }
+    "#);
+
+    Ok(())
+}
+
+/// Record the source distribution a wheel was built from with `--record-sdist`.
+#[test]
+fn build_record_sdist() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_cyclonedx_filters()
+        .with_filter((
+            regex::escape(env!("CARGO_PKG_VERSION")),
+            "[VERSION]".to_string(),
+        ))
+        .with_filter((r#""(content|sha256)": "[0-9a-f]{64}""#, r#""$1": "[HASH]""#));
+
+    context
+        .init()
+        .arg("--lib")
+        .arg("--name")
+        .arg("foo")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context
+        .build()
+        .arg("--record-sdist")
+        .arg("--check")
+        .arg("--sbom")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built dist/foo-0.1.0.tar.gz
+    Wrote build environment SBOM to dist/foo-0.1.0.tar.gz.build.cdx.json
+    Successfully built dist/foo-0.1.0-py3-none-any.whl
+    Wrote build environment SBOM to dist/foo-0.1.0-py3-none-any.whl.build.cdx.json
+    ");
+
+    let wheel = context
+        .temp_dir
+        .child("dist")
+        .child("foo-0.1.0-py3-none-any.whl");
+    let mut archive = zip::ZipArchive::new(BufReader::new(File::open(wheel.path())?))?;
+    let mut provenance = String::new();
+    archive
+        .by_name("foo-0.1.0.dist-info/source_distribution.json")?
+        .read_to_string(&mut provenance)?;
+    assert_snapshot!(apply_filters(provenance, context.filters()), @r#"
+    {
+      "filename": "foo-0.1.0.tar.gz",
+      "hashes": {
+        "sha256": "[HASH]"
+      }
+    }
+    "#);
+
+    // The SBOM of the wheel includes the source distribution it was built from.
+    let sbom = context
+        .temp_dir
+        .child("dist")
+        .child("foo-0.1.0-py3-none-any.whl.build.cdx.json");
+    assert_snapshot!(apply_filters(fs_err::read_to_string(sbom)?, context.filters()), @r#"
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "serialNumber": "[SERIAL_NUMBER]",
+      "metadata": {
+        "timestamp": "[TIMESTAMP]",
+        "tools": [
+          {
+            "vendor": "Astral Software Inc.",
+            "name": "uv",
+            "version": "[VERSION]"
+          }
+        ],
+        "component": {
+          "type": "library",
+          "bom-ref": "foo-1@0.1.0",
+          "name": "foo",
+          "version": "0.1.0",
+          "hashes": [
+            {
+              "alg": "SHA-256",
+              "content": "[HASH]"
+            }
+          ],
+          "purl": "pkg:pypi/foo@0.1.0?file_name=foo-0.1.0-py3-none-any.whl",
+          "properties": [
+            {
+              "name": "uv:build:backend",
+              "value": "uv_build"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "uv-build-2@[VERSION]",
+          "name": "uv-build",
+          "version": "[VERSION]",
+          "purl": "pkg:pypi/uv-build@[VERSION]"
+        },
+        {
+          "type": "library",
+          "bom-ref": "foo-3@0.1.0",
+          "name": "foo",
+          "version": "0.1.0",
+          "hashes": [
+            {
+              "alg": "SHA-256",
+              "content": "[HASH]"
+            }
+          ],
+          "purl": "pkg:pypi/foo@0.1.0?file_name=foo-0.1.0.tar.gz",
+          "properties": [
+            {
+              "name": "uv:build:source-distribution",
+              "value": "true"
+            }
+          ]
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "foo-1@0.1.0",
+          "dependsOn": [
+            "uv-build-2@[VERSION]",
+            "foo-3@0.1.0"
+          ]
+        }
+      ]
+    }
+    "#);
+
+    Ok(())
+}
+
 /// Show a warning when the build backend is passed redundant module names
 #[test]
 fn warn_on_redundant_module_names() -> Result<()> {
@@ -111,7 +111,7 @@ fn clean_package_pypi() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v19")
+        .child("simple-v21")
         .child("pypi")
         .child("iniconfig.rkyv");
     assert!(
@@ -189,7 +189,7 @@ fn clean_package_index() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v19")
+        .child("simple-v21")
         .child("index")
         .child("e8208120cae3ba69")
         .child("iniconfig.rkyv");
@@ -0,0 +1,307 @@
+use assert_cmd::assert::OutputAssertExt;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Test that `cache info` reports every bucket for an empty cache.
+#[test]
+fn cache_info_empty() {
+    let context = TestContext::new("3.12");
+
+    // Clean cache first to ensure truly empty state.
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.cache_info()
+        .arg("--preview-features")
+        .arg("cache-info")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "root": "[CACHE_DIR]/",
+      "total_bytes": 0,
+      "buckets": [
+        {
+          "name": "wheels",
+          "path": "[CACHE_DIR]/wheels-v5",
+          "bytes": 0
+        },
+        {
+          "name": "built-wheels",
+          "path": "[CACHE_DIR]/sdists-v9",
+          "bytes": 0
+        },
+        {
+          "name": "flat-index",
+          "path": "[CACHE_DIR]/flat-index-v2",
+          "bytes": 0
+        },
+        {
+          "name": "git",
+          "path": "[CACHE_DIR]/git-v0",
+          "bytes": 0
+        },
+        {
+          "name": "interpreters",
+          "path": "[CACHE_DIR]/interpreter-v4",
+          "bytes": 0
+        },
+        {
+          "name": "metadata",
+          "path": "[CACHE_DIR]/simple-v21",
+          "bytes": 0
+        },
+        {
+          "name": "unzipped-wheels",
+          "path": "[CACHE_DIR]/archive-v0",
+          "bytes": 0
+        },
+        {
+          "name": "builds",
+          "path": "[CACHE_DIR]/builds-v0",
+          "bytes": 0
+        },
+        {
+          "name": "environments",
+          "path": "[CACHE_DIR]/environments-v2",
+          "bytes": 0
+        },
+        {
+          "name": "binaries",
+          "path": "[CACHE_DIR]/binaries-v0",
+          "bytes": 0
+        },
+        {
+          "name": "bytecode",
+          "path": "[CACHE_DIR]/bytecode-v1",
+          "bytes": 0
+        }
+      ],
+      "packages": []
+    }
+
+    ----- stderr -----
+    "#);
+}
+
+/// Test that `cache info` attributes cached wheels to their packages.
+#[test]
+fn cache_info_packages() {
+    let context = TestContext::new("3.12")
+        .with_filter((r#""(total_)?bytes": [1-9]\d*"#, r#""${1}bytes": [SIZE]"#))
+        .with_filter((r"(?m) +\d+\.\d[KMGT]?i?B$", " [SIZE]"));
+
+    // Install a requirement to populate the cache.
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_info()
+        .arg("--preview-features")
+        .arg("cache-info"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache directory: [CACHE_DIR]/
+    Total size: [SIZE]
+
+    Buckets:
+      wheels [SIZE]
+      built-wheels [SIZE]
+      flat-index [SIZE]
+      git [SIZE]
+      interpreters [SIZE]
+      metadata [SIZE]
+      unzipped-wheels [SIZE]
+      builds [SIZE]
+      environments [SIZE]
+      binaries [SIZE]
+      bytecode [SIZE]
+
+    Packages:
+      ok [SIZE]
+
+    ----- stderr -----
+    ");
+
+    // The number of packages can be limited.
+    uv_snapshot!(context.filters(), context.cache_info()
+        .arg("--preview-features")
+        .arg("cache-info")
+        .arg("--format")
+        .arg("json")
+        .arg("--top")
+        .arg("0"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "root": "[CACHE_DIR]/",
+      "total_bytes": [SIZE],
+      "buckets": [
+        {
+          "name": "wheels",
+          "path": "[CACHE_DIR]/wheels-v5",
+          "bytes": [SIZE]
+        },
+        {
+          "name": "built-wheels",
+          "path": "[CACHE_DIR]/sdists-v9",
+          "bytes": 0
+        },
+        {
+          "name": "flat-index",
+          "path": "[CACHE_DIR]/flat-index-v2",
+          "bytes": [SIZE]
+        },
+        {
+          "name": "git",
+          "path": "[CACHE_DIR]/git-v0",
+          "bytes": 0
+        },
+        {
+          "name": "interpreters",
+          "path": "[CACHE_DIR]/interpreter-v4",
+          "bytes": [SIZE]
+        },
+        {
+          "name": "metadata",
+          "path": "[CACHE_DIR]/simple-v21",
+          "bytes": 0
+        },
+        {
+          "name": "unzipped-wheels",
+          "path": "[CACHE_DIR]/archive-v0",
+          "bytes": [SIZE]
+        },
+        {
+          "name": "builds",
+          "path": "[CACHE_DIR]/builds-v0",
+          "bytes": 0
+        },
+        {
+          "name": "environments",
+          "path": "[CACHE_DIR]/environments-v2",
+          "bytes": 0
+        },
+        {
+          "name": "binaries",
+          "path": "[CACHE_DIR]/binaries-v0",
+          "bytes": 0
+        },
+        {
+          "name": "bytecode",
+          "path": "[CACHE_DIR]/bytecode-v1",
+          "bytes": 0
+        }
+      ],
+      "packages": []
+    }
+
+    ----- stderr -----
+    "#);
+}
+
+/// Test that the global `--output-format` option applies to `cache info`, and is ignored, with a
+/// warning, by commands that don't support it.
+#[test]
+fn cache_info_output_format() {
+    let context = TestContext::new("3.12")
+        .with_filter((r#""(total_)?bytes": [1-9]\d*"#, r#""${1}bytes": [SIZE]"#));
+
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.cache_info()
+        .arg("--preview-features")
+        .arg("cache-info")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--top")
+        .arg("0"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "root": "[CACHE_DIR]/",
+      "total_bytes": 0,
+      "buckets": [
+        {
+          "name": "wheels",
+          "path": "[CACHE_DIR]/wheels-v5",
+          "bytes": 0
+        },
+        {
+          "name": "built-wheels",
+          "path": "[CACHE_DIR]/sdists-v9",
+          "bytes": 0
+        },
+        {
+          "name": "flat-index",
+          "path": "[CACHE_DIR]/flat-index-v2",
+          "bytes": 0
+        },
+        {
+          "name": "git",
+          "path": "[CACHE_DIR]/git-v0",
+          "bytes": 0
+        },
+        {
+          "name": "interpreters",
+          "path": "[CACHE_DIR]/interpreter-v4",
+          "bytes": 0
+        },
+        {
+          "name": "metadata",
+          "path": "[CACHE_DIR]/simple-v21",
+          "bytes": 0
+        },
+        {
+          "name": "unzipped-wheels",
+          "path": "[CACHE_DIR]/archive-v0",
+          "bytes": 0
+        },
+        {
+          "name": "builds",
+          "path": "[CACHE_DIR]/builds-v0",
+          "bytes": 0
+        },
+        {
+          "name": "environments",
+          "path": "[CACHE_DIR]/environments-v2",
+          "bytes": 0
+        },
+        {
+          "name": "binaries",
+          "path": "[CACHE_DIR]/binaries-v0",
+          "bytes": 0
+        },
+        {
+          "name": "bytecode",
+          "path": "[CACHE_DIR]/bytecode-v1",
+          "bytes": 0
+        }
+      ],
+      "packages": []
+    }
+
+    ----- stderr -----
+    "#);
+
+    uv_snapshot!(context.filters(), context.clean()
+        .arg("--output-format")
+        .arg("json"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--output-format json` is not supported by this command, and will be ignored. JSON output is supported by `uv sync`, `uv add`, `uv lock`, `uv tree`, `uv version`, `uv python list`, and `uv cache info`.
+    No cache found at: [CACHE_DIR]/
+    ");
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::TestContext;
+
+/// `--cache-metrics` should report cache misses on a cold cache, and hits on a warm cache.
+#[test]
+fn cache_metrics() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let metrics = context.temp_dir.child("metrics.json");
+
+    // On a cold cache, the index page is fetched from the network.
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--cache-metrics")
+        .arg(metrics.path())
+        .arg("--preview-features")
+        .arg("cache-metrics")
+        .assert()
+        .success();
+
+    let report: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(&metrics)?)?;
+    assert!(report["misses"].as_u64().unwrap() > 0);
+    assert_eq!(report["hits"].as_u64(), Some(0));
+    assert_eq!(report["evictions"].as_u64(), Some(0));
+
+    // On a warm cache, the index page is served from the cache.
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--reinstall")
+        .arg("--offline")
+        .arg("--cache-metrics")
+        .arg(metrics.path())
+        .arg("--preview-features")
+        .arg("cache-metrics")
+        .assert()
+        .success();
+
+    let report: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(&metrics)?)?;
+    assert!(report["hits"].as_u64().unwrap() > 0);
+    assert_eq!(report["misses"].as_u64(), Some(0));
+
+    Ok(())
+}
@@ -445,3 +445,84 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --max-age` and `--max-size` should evict unzipped wheels that exceed the limits.
+#[test]
+fn prune_max_age_max_size() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // The wheel was just used, so it should be retained.
+    uv_snapshot!(&filters, context.prune().arg("--max-age").arg("30d"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    ");
+
+    // The cache exceeds the size limit, so the wheel should be evicted.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(
+        fs_err::read_dir(context.cache_dir.child("archive-v0").path())?
+            .next()
+            .is_none()
+    );
+
+    // Reinstalling the package should require a download, since we evicted the wheel.
+    context.venv().arg("--clear").assert().success();
+    uv_snapshot!(&filters, context.pip_sync().arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should reject invalid sizes.
+#[test]
+fn prune_max_size_invalid() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.prune().arg("--max-size").arg("10XB"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '10XB' for '--max-size <SIZE>': Expected a size (e.g., `10GB`, `500MiB`, or `1024`), but found: `10XB`
+
+    For more information, try '--help'.
+    ");
+}
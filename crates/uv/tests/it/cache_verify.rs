@@ -0,0 +1,104 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache verify` should quarantine a corrupt wheel, which is then replaced on next use.
+#[test]
+fn verify_corrupt_wheel() -> Result<()> {
+    let context =
+        TestContext::new("3.12").with_filter((r"quarantine-v0/[\w-]+", "quarantine-v0/[ID]"));
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_verify()
+        .arg("--preview-features")
+        .arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 entry; no corruption found
+    ");
+
+    // Corrupt a file in the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+        .next()
+        .unwrap()?
+        .path();
+    fs_err::write(archive.join("ok").join("__init__.py"), "corrupt")?;
+
+    // In `--dry-run` mode, the corruption is reported, but the entry is retained.
+    uv_snapshot!(context.filters(), context.cache_verify()
+        .arg("--preview-features")
+        .arg("cache-verify")
+        .arg("--dry-run"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Found corrupt entry [CACHE_DIR]/archive-v0/[HASH]: RECORD file doesn't match wheel contents: Hash mismatch for ok/__init__.py: expected `sha256=J-j-u0itpEFT6irdmWmixQqYMadNl1X91TxUmoiLHMI`, found `sha256=EdUQ4GfSzc11Wb2G0novTCC6vUNnA0a5evmbUiwfAHU`
+    Verified 1 entry; found 1 corrupt
+    ");
+    assert!(archive.exists());
+
+    // Otherwise, the entry is quarantined.
+    uv_snapshot!(context.filters(), context.cache_verify()
+        .arg("--preview-features")
+        .arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Quarantined corrupt entry [CACHE_DIR]/archive-v0/[HASH] to [CACHE_DIR]/quarantine-v0/[ID]: RECORD file doesn't match wheel contents: Hash mismatch for ok/__init__.py: expected `sha256=J-j-u0itpEFT6irdmWmixQqYMadNl1X91TxUmoiLHMI`, found `sha256=EdUQ4GfSzc11Wb2G0novTCC6vUNnA0a5evmbUiwfAHU`
+    Verified 1 entry; quarantined 1 corrupt (to be replaced on next use)
+    ");
+    assert!(!archive.exists());
+
+    // On next use, the wheel is unzipped again.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/")), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ ok==1.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.cache_verify()
+        .arg("--preview-features")
+        .arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 entry; no corruption found
+    ");
+
+    Ok(())
+}
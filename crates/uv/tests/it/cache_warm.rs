@@ -0,0 +1,89 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::TestContext;
+
+/// `cache warm` should populate the cache such that the project can be synced offline.
+#[test]
+fn warm_then_sync_offline() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        test = ["sniffio"]
+
+        [dependency-groups]
+        dev = ["typing-extensions"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Start from an empty cache.
+    context.clean().assert().success();
+
+    context
+        .cache_warm()
+        .arg("--preview-features")
+        .arg("cache-warm")
+        .arg("--locked")
+        .assert()
+        .success();
+
+    // The environment should not have been created.
+    assert!(!context.temp_dir.child(".venv").exists());
+
+    // Every extra and group should be installable without network access.
+    context
+        .sync()
+        .arg("--offline")
+        .arg("--all-extras")
+        .arg("--all-groups")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// `cache warm --locked` should fail if the lockfile is out-of-date.
+#[test]
+fn warm_locked_outdated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+    "#})?;
+
+    context
+        .cache_warm()
+        .arg("--preview-features")
+        .arg("cache-warm")
+        .arg("--locked")
+        .assert()
+        .failure();
+
+    Ok(())
+}
@@ -531,11 +531,23 @@ impl TestContext {
             r#""timestamp": "[TIMESTAMP]""#.to_string(),
         ));
         self.filters.push((
-            r#""name": "uv",\s*"version": "\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?""#
+            r#""name": "uv",(\s*)"version": "\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?""#
                 .to_string(),
-            r#""name": "uv",
-        "version": "[VERSION]""#
+            r#""name": "uv",$1"version": "[VERSION]""#.to_string(),
+        ));
+        self.filters.push((
+            r"pkg:pypi/uv@\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?".to_string(),
+            "pkg:pypi/uv@[VERSION]".to_string(),
+        ));
+        self.filters.push((
+            r"<timestamp>[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}\.[0-9]+Z</timestamp>"
+                .to_string(),
+            "<timestamp>[TIMESTAMP]</timestamp>".to_string(),
+        ));
+        self.filters.push((
+            r"<name>uv</name>(\s*)<version>\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?</version>"
                 .to_string(),
+            "<name>uv</name>$1<version>[VERSION]</version>".to_string(),
         ));
         self
     }
@@ -589,6 +601,22 @@ impl TestContext {
         self
     }
 
+    /// Read the organization policy from `[HOME]/config/uv/policy.toml`, rather than the system
+    /// policy file.
+    #[must_use]
+    pub fn with_policy_file(mut self) -> Self {
+        self.extra_env.push((
+            EnvVars::UV_INTERNAL__TEST_POLICY_FILE.into(),
+            self.home_dir
+                .child("config")
+                .child("uv")
+                .child("policy.toml")
+                .as_os_str()
+                .to_owned(),
+        ));
+        self
+    }
+
     pub fn with_versions_as_managed(mut self, versions: &[&str]) -> Self {
         self.extra_env.push((
             EnvVars::UV_INTERNAL__TEST_PYTHON_MANAGED.into(),
@@ -1107,6 +1135,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip size` command with options shared across scenarios.
+    pub fn pip_size(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("pip").arg("size");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     pub fn pip_list(&self) -> Command {
         let mut command = Self::new_command();
         command.arg("pip").arg("list");
@@ -1130,6 +1166,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip download` command with options shared across scenarios.
+    pub fn pip_download(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("pip").arg("download");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip uninstall` command with options shared across scenarios.
     pub fn pip_uninstall(&self) -> Command {
         let mut command = Self::new_command();
@@ -1211,6 +1255,77 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv env info` command with options shared across scenarios.
+    pub fn env_info(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("env").arg("info");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv config` command with options shared across scenarios.
+    pub fn config(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("config");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv kernel install` command with options shared across scenarios.
+    pub fn kernel_install(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("kernel").arg("install");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a command that requests shell completions for the given `uv` arguments, as the
+    /// registered fish script would.
+    ///
+    /// The last argument is the word being completed.
+    pub fn complete(&self, args: &[&str]) -> Command {
+        let mut command = Self::new_command();
+        command
+            .env(EnvVars::UV_COMPLETE, "fish")
+            .arg("--")
+            .arg("uv")
+            .args(args);
+        self.add_shared_env(&mut command, false);
+        command
+    }
+
+    /// Create a `uv sbom upload` command with options shared across scenarios.
+    pub fn sbom_upload(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("sbom").arg("upload");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv sbom audit` command with options shared across scenarios.
+    pub fn sbom_audit(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("sbom").arg("audit");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv sbom verify` command with options shared across scenarios.
+    pub fn sbom_verify(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("sbom").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv generate devcontainer` command with options shared across scenarios.
+    pub fn generate_devcontainer(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("generate").arg("devcontainer");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv export` command with options shared across scenarios.
     pub fn export(&self) -> Command {
         let mut command = Self::new_command();
@@ -1434,6 +1549,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache info` command.
+    pub fn cache_info(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("info");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command with options shared across scenarios.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache warm` command with options shared across scenarios.
+    pub fn cache_warm(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("warm");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -0,0 +1,470 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Set settings in the project and user configuration, and read them back.
+#[test]
+fn config_set_get() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+    "#})?;
+
+    // By default, settings are written to the `[tool.uv]` section of the project.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("index-url")
+        .arg("https://project.example.com/simple")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `index-url` in `pyproject.toml`
+    ");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("pip.extra-index-url")
+        .arg(r#"["https://project.example.com/extra"]"#)
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `pip.extra-index-url` in `pyproject.toml`
+    ");
+
+    insta::assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+
+    [tool.uv]
+    index-url = "https://project.example.com/simple"
+
+    [tool.uv.pip]
+    extra-index-url = ["https://project.example.com/extra"]
+    "#);
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("--user")
+        .arg("pip.extra-index-url")
+        .arg(r#"["https://user.example.com/extra"]"#)
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `pip.extra-index-url` in `[UV_USER_CONFIG_DIR]/uv.toml`
+    ");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("index-url")
+        .arg("--show-origin")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    file:pyproject.toml	https://project.example.com/simple
+
+    ----- stderr -----
+    ");
+
+    // Lists are combined across configuration files, with higher-precedence values first.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("pip.extra-index-url")
+        .arg("--preview-features")
+        .arg("config-command"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ["https://project.example.com/extra", "https://user.example.com/extra"]
+
+    ----- stderr -----
+    "#);
+
+    // Environment variables take precedence over configuration files.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("index-url")
+        .arg("--show-origin")
+        .arg("--preview-features")
+        .arg("config-command")
+        .env(EnvVars::UV_INDEX_URL, "https://env.example.com/simple"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    env:UV_INDEX_URL	https://env.example.com/simple
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--show-origin")
+        .arg("--preview-features")
+        .arg("config-command")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    file:[UV_USER_CONFIG_DIR]/uv.toml	pip.extra-index-url = ["https://user.example.com/extra"]
+    file:pyproject.toml	index-url = "https://project.example.com/simple"
+    file:pyproject.toml	pip.extra-index-url = ["https://project.example.com/extra"]
+
+    ----- stderr -----
+    "#);
+
+    // Unset settings exit with an error.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("keyring-provider")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Reject unknown settings and invalid values.
+#[test]
+fn config_set_invalid() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("index-urls")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unknown setting: `index-urls`
+    ");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("pip")
+        .arg("true")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `pip` is a table of settings; use a dotted name to refer to a setting, e.g., `pip.index-url`
+    ");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("concurrent-downloads")
+        .arg("many")
+        .arg("--preview-features")
+        .arg("config-command"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`
+      Caused by: TOML parse error at line 1, column 24
+      |
+    1 | concurrent-downloads = "many"
+      |                        ^^^^^^
+    invalid type: string "many", expected a nonzero usize
+    "#);
+
+    // The invalid value is not written.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .assert(predicates::path::missing());
+
+    // Locked settings can only be set in the system configuration.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("--user")
+        .arg("locked-settings")
+        .arg(r#"["index-url"]"#)
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `[UV_USER_CONFIG_DIR]/uv.toml`. The `locked-settings` field is only allowed in the system-level `uv.toml` file.
+    ");
+}
+
+/// Settings locked by the system configuration are ignored in the user and project configuration.
+#[test]
+fn config_locked_settings() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .home_dir
+        .child("config")
+        .child("uv")
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+            locked-settings = ["index-url", "pip.extra-index-url"]
+            index-url = "https://system.example.com/simple"
+        "#})?;
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        index-url = "https://project.example.com/simple"
+        keyring-provider = "subprocess"
+
+        [pip]
+        extra-index-url = ["https://project.example.com/extra"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("index-url")
+        .arg("--show-origin")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    file:[HOME]/config/uv/uv.toml	https://system.example.com/simple
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    ");
+
+    // A locked setting that isn't set in the system configuration is unset.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("pip.extra-index-url")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    ");
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--preview-features")
+        .arg("config-command")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    locked-settings = ["index-url", "pip.extra-index-url"]
+    index-url = "https://system.example.com/simple"
+    index-url = "https://project.example.com/simple"  # ignored: locked by the system configuration
+    keyring-provider = "subprocess"
+    pip.extra-index-url = ["https://project.example.com/extra"]  # ignored: locked by the system configuration
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    "#);
+
+    // Setting a locked setting warns.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("--user")
+        .arg("index-url")
+        .arg("https://user.example.com/simple")
+        .arg("--preview-features")
+        .arg("config-command"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: The `index-url` setting is locked by the system configuration and will be ignored
+    Set `index-url` in `[UV_USER_CONFIG_DIR]/uv.toml`
+    ");
+
+    // Environment variables can't override locked settings.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("index-url")
+        .arg("--preview-features")
+        .arg("config-command")
+        .env(EnvVars::UV_INDEX_URL, "https://env.example.com/simple"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://system.example.com/simple
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `index-url` in `[UV_USER_CONFIG_DIR]/uv.toml`, since the setting is locked by the system configuration
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .env(EnvVars::UV_INDEX_URL, "https://env.example.com/simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `index-url` in `[UV_USER_CONFIG_DIR]/uv.toml`, since the setting is locked by the system configuration
+    error: `UV_INDEX_URL` can't be used, since `index-url` is locked by the system configuration
+    ");
+
+    // Neither can command-line arguments, including those that bypass the locked index.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--index-url")
+        .arg("https://cli.example.com/simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `index-url` in `[UV_USER_CONFIG_DIR]/uv.toml`, since the setting is locked by the system configuration
+    error: `--index-url` can't be used, since `index-url` is locked by the system configuration
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--default-index")
+        .arg("https://cli.example.com/simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `index-url` in `[UV_USER_CONFIG_DIR]/uv.toml`, since the setting is locked by the system configuration
+    error: `--default-index` can't be used, since `index-url` is locked by the system configuration
+    ");
+
+    // `--no-config` still applies the locked settings from the system configuration.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("get")
+        .arg("index-url")
+        .arg("--no-config")
+        .arg("--preview-features")
+        .arg("config-command"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://system.example.com/simple
+
+    ----- stderr -----
+    ");
+
+    // As does `--config-file`, which can't override the locked settings.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--config-file")
+        .arg("uv.toml")
+        .arg("--preview-features")
+        .arg("config-command")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    locked-settings = ["index-url", "pip.extra-index-url"]
+    index-url = "https://system.example.com/simple"
+    index-url = "https://project.example.com/simple"  # ignored: locked by the system configuration
+    keyring-provider = "subprocess"
+    pip.extra-index-url = ["https://project.example.com/extra"]  # ignored: locked by the system configuration
+
+    ----- stderr -----
+    warning: Ignoring `index-url` in `uv.toml`, since the setting is locked by the system configuration
+    warning: Ignoring `pip.extra-index-url` in `uv.toml`, since the setting is locked by the system configuration
+    "#);
+
+    Ok(())
+}
+
+/// Locked settings must be known settings, and are only allowed in the system configuration.
+#[test]
+fn config_locked_settings_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let system_config = context
+        .home_dir
+        .child("config")
+        .child("uv")
+        .child("uv.toml");
+    system_config.write_str(r#"locked-settings = ["index-urls"]"#)?;
+
+    uv_snapshot!(context.filters(), context.version(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `[HOME]/config/uv/uv.toml`. Unknown setting in `locked-settings`: `index-urls`
+    ");
+
+    system_config.write_str("")?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [tool.uv]
+        locked-settings = ["index-url"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`. The `locked-settings` field is only allowed in the system-level `uv.toml` file.
+    ");
+
+    Ok(())
+}
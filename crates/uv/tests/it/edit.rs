@@ -14886,3 +14886,157 @@ fn add_git_lfs_error() -> Result<()> {
 
     Ok(())
 }
+
+/// Propose fixes with `uv add --interactive` if the added requirement conflicts with a transitive
+/// requirement.
+#[test]
+fn add_interactive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#})?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok>=2"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.add()
+        .arg("ok<2")
+        .arg("--interactive")
+        .arg("--preview-features")
+        .arg("interactive-resolution")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links")), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only child==0.1.0 is available and child==0.1.0 depends on ok>=2, we can conclude that all versions of child depend on ok>=2.
+          And because your project depends on child and ok<2, we can conclude that your project's requirements are unsatisfiable.
+      help: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing.
+
+    hint: The following changes may resolve the conflict; re-run in an interactive terminal to apply them:
+      - Relax `ok<2` to `ok` in `project.dependencies`
+      - Override all requirements on `ok` with `ok<2` via `tool.uv.override-dependencies`
+    ");
+
+    // The `pyproject.toml` should be reverted.
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["child"]
+
+    [tool.uv.sources]
+    child = { path = "child" }
+    "#);
+
+    Ok(())
+}
+
+/// Report the added dependencies as JSON with `--output-format json`.
+#[test]
+fn add_output_format_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add()
+        .arg("ok")
+        .arg("--group")
+        .arg("dev")
+        .arg("--no-sync")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "path": "[TEMP_DIR]/pyproject.toml",
+      "dependencies": [
+        {
+          "name": "ok",
+          "action": "added",
+          "section": "dependency-groups",
+          "group": "dev",
+          "locked": [
+            "2.0.0"
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "#);
+
+    // Without locking, the locked versions are omitted.
+    uv_snapshot!(context.filters(), context.add()
+        .arg("tqdm")
+        .arg("--optional")
+        .arg("progress")
+        .arg("--frozen")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "path": "[TEMP_DIR]/pyproject.toml",
+      "dependencies": [
+        {
+          "name": "tqdm",
+          "action": "added",
+          "section": "optional-dependencies",
+          "extra": "progress"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Display the details of the project environment.
+#[test]
+#[cfg(unix)]
+fn env_info() -> Result<()> {
+    let context = TestContext::new("3.11")
+        .with_filtered_virtualenv_bin()
+        .with_filtered_python_names();
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+    "#})?;
+    context
+        .temp_dir
+        .child("uv.lock")
+        .write_str("version = 1\n")?;
+
+    uv_snapshot!(context.filters(), context.env_info().arg("--format").arg("json").arg("--preview-features").arg("env-info"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "root": "[VENV]/",
+      "python": {
+        "executable": "[VENV]/[BIN]/[PYTHON]",
+        "implementation": "cpython",
+        "version": "3.11.[X]"
+      },
+      "site_packages": [
+        "[SITE_PACKAGES]/"
+      ],
+      "scripts": "[VENV]/[BIN]",
+      "env": {
+        "VIRTUAL_ENV": "[VENV]/"
+      },
+      "lock": {
+        "path": "[TEMP_DIR]/uv.lock",
+        "sha256": "dbab12665d98aef021ba64953c61b0ed8a908cfb56a1c01e2fcb4b052b71a2a1"
+      }
+    }
+
+    ----- stderr -----
+    "#);
+
+    uv_snapshot!(context.filters(), context.env_info(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Environment: [VENV]/
+    Python: [VENV]/[BIN]/[PYTHON] (cpython 3.11.[X])
+    Site packages: [SITE_PACKAGES]/
+    Scripts: [VENV]/[BIN]
+    Lockfile: [TEMP_DIR]/uv.lock (sha256:dbab12665d98aef021ba64953c61b0ed8a908cfb56a1c01e2fcb4b052b71a2a1)
+
+    ----- stderr -----
+    warning: `uv env info` is experimental and may change without warning. Pass `--preview-features env-info` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+/// The environment is never created.
+#[test]
+fn env_info_missing_environment() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.env_info(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv env info` is experimental and may change without warning. Pass `--preview-features env-info` to disable this warning.
+    error: No project environment found at `.venv`; run `uv sync` to create it
+    ");
+
+    Ok(())
+}
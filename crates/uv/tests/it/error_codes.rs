@@ -0,0 +1,81 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// With the `error-codes` preview feature, errors are reported with their error code.
+#[test]
+fn error_codes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("error-codes"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No `pyproject.toml` found in current directory or any parent directory
+      error code: UV3001 (ProjectNotFound)
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--error-format").arg("json").arg("--preview-features").arg("error-codes"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    {"code":"UV3001","name":"ProjectNotFound","message":"No `pyproject.toml` found in current directory or any parent directory","causes":[]}
+    "#);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=999"]
+        "#,
+    )?;
+
+    // Resolution failures are rendered with rich diagnostics, followed by the code.
+    uv_snapshot!(context.filters(), context.lock().arg("--offline").arg("--preview-features").arg("error-codes"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the cache and your project depends on iniconfig>=999, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+      error code: UV1101 (ResolutionConflict)
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--offline").env(EnvVars::UV_ERROR_FORMAT, "json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Error codes are experimental and may change without warning. Pass `--preview-features error-codes` to disable this warning.
+    {"code":"UV1101","name":"ResolutionConflict","message":"Because iniconfig was not found in the cache and your project depends on iniconfig>=999, we can conclude that your project's requirements are unsatisfiable./n/nhint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.","causes":[]}
+    "#);
+
+    // Without the preview feature, no code is displayed.
+    uv_snapshot!(context.filters(), context.lock().arg("--offline"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the cache and your project depends on iniconfig>=999, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+    ");
+
+    Ok(())
+}
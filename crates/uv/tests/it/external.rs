@@ -0,0 +1,149 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Defer unknown subcommands to a `uv-<name>` executable on the `PATH`.
+#[cfg(unix)]
+#[test]
+fn external_subcommand() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new_with_versions(&[]);
+
+    let executable = context.bin_dir.child("uv-hello");
+    executable.write_str(indoc::indoc! {r#"
+        #!/bin/sh
+        echo "hello: $*"
+        exit 3
+    "#})?;
+    fs_err::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.command().arg("hello").arg("world").arg("--flag"), @"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+    hello: world --flag
+
+    ----- stderr -----
+    ");
+
+    // Unknown subcommands without a matching executable are still rejected.
+    uv_snapshot!(context.filters(), context.command().arg("goodbye"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: unrecognized subcommand 'goodbye'
+
+    Usage: uv [OPTIONS] <COMMAND>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Export the lockfile with a `uv-export-<name>` executable on the `PATH`.
+#[cfg(unix)]
+#[test]
+fn external_export_format() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let executable = context.bin_dir.child("uv-export-names");
+    executable.write_str(indoc::indoc! {r#"
+        #!/bin/sh
+        echo "exported from $(basename "$PWD")"
+        grep '^name = ' | sed 's/^name = //'
+    "#})?;
+    fs_err::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("ext:names"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    exported from temp
+    "project"
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "#);
+
+    // The export can be written to a file.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("ext:names").arg("--output-file").arg("names.txt"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    exported from temp
+    "project"
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "#);
+
+    assert_snapshot!(context.read("names.txt"), @r#"
+    exported from temp
+    "project"
+    "#);
+
+    // A failing exporter is reported as an error.
+    let executable = context.bin_dir.child("uv-export-broken");
+    executable.write_str(indoc::indoc! {r#"
+        #!/bin/sh
+        echo "unsupported lockfile" >&2
+        exit 1
+    "#})?;
+    fs_err::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("ext:broken"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    unsupported lockfile
+    error: `[BIN]/uv-export-broken` failed with exit status: 1
+    ");
+
+    // Without a matching executable, the export fails.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("ext:missing"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: No executable named `uv-export-missing` was found on the `PATH` for `--format ext:missing`
+    ");
+
+    // External format names must be plain names.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("ext:../names"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid external export format `ext:../names`; expected `ext:<name>`, where `uv-export-<name>` is an executable on the `PATH`
+    ");
+
+    Ok(())
+}
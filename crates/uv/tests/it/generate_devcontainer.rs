@@ -0,0 +1,168 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild};
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Generate a `devcontainer.json` using the lower bound of `requires-python`.
+#[test]
+fn generate_devcontainer() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.generate_devcontainer(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `uv generate devcontainer` command is experimental and may change without warning. Pass `--preview-features generate-devcontainer` to disable this warning.
+    Generated development container for Python 3.11 at `.devcontainer`
+    ");
+
+    let devcontainer = context.read(".devcontainer/devcontainer.json");
+    assert_snapshot!(devcontainer, @r#"
+    {
+      "name": "temp",
+      "image": "ghcr.io/astral-sh/uv:python3.11-bookworm",
+      "mounts": [
+        "source=uv-cache,target=/root/.cache/uv,type=volume",
+        "target=${containerWorkspaceFolder}/.venv,type=volume"
+      ],
+      "containerEnv": {
+        "UV_LINK_MODE": "copy"
+      },
+      "postCreateCommand": "uv sync"
+    }
+    "#);
+
+    // Existing configuration is not overwritten without `--force`.
+    uv_snapshot!(context.filters(), context.generate_devcontainer().arg("--python").arg("3.13"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `uv generate devcontainer` command is experimental and may change without warning. Pass `--preview-features generate-devcontainer` to disable this warning.
+    error: `.devcontainer/devcontainer.json` already exists; use `--force` to overwrite it
+    ");
+
+    uv_snapshot!(context.filters(), context.generate_devcontainer().arg("--python").arg("3.13").arg("--force"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `uv generate devcontainer` command is experimental and may change without warning. Pass `--preview-features generate-devcontainer` to disable this warning.
+    Generated development container for Python 3.13 at `.devcontainer`
+    ");
+
+    Ok(())
+}
+
+/// Generate a Compose-based development container using the `.python-version` pin.
+#[test]
+fn generate_devcontainer_compose() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+    "#})?;
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12\n")?;
+    context.temp_dir.child("uv.lock").touch()?;
+
+    uv_snapshot!(context.filters(), context.generate_devcontainer().arg("--compose").arg("--preview-features").arg("generate-devcontainer"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Generated development container for Python 3.12 at `.devcontainer`
+    ");
+
+    let devcontainer = context.read(".devcontainer/devcontainer.json");
+    assert_snapshot!(devcontainer, @r#"
+    {
+      "name": "temp",
+      "dockerComposeFile": "compose.yaml",
+      "service": "dev",
+      "workspaceFolder": "/workspaces/temp",
+      "postCreateCommand": "uv sync --locked"
+    }
+    "#);
+
+    let compose = context.read(".devcontainer/compose.yaml");
+    assert_snapshot!(compose, @"
+    services:
+      dev:
+        image: ghcr.io/astral-sh/uv:python3.12-bookworm
+        command: sleep infinity
+        environment:
+          UV_LINK_MODE: copy
+        volumes:
+          - ..:/workspaces/temp:cached
+          - /workspaces/temp/.venv
+          - uv-cache:/root/.cache/uv
+
+    volumes:
+      uv-cache:
+    ");
+
+    Ok(())
+}
+
+/// The Python version must be a `<major>.<minor>` version.
+#[test]
+fn generate_devcontainer_unsupported_python() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.generate_devcontainer().arg("--python").arg("pypy"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `uv generate devcontainer` command is experimental and may change without warning. Pass `--preview-features generate-devcontainer` to disable this warning.
+    error: `pypy` is not a supported Python version for a development container; provide a version like `3.12`
+    ");
+
+    uv_snapshot!(context.filters(), context.generate_devcontainer(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `uv generate devcontainer` command is experimental and may change without warning. Pass `--preview-features generate-devcontainer` to disable this warning.
+    error: Unable to determine a Python version for the development container; provide one with `--python`, pin one with `uv python pin`, or set a lower bound in `requires-python`
+    ");
+
+    Ok(())
+}
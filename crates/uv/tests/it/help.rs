@@ -62,6 +62,13 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -72,6 +79,9 @@ fn help() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -142,6 +152,13 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -152,6 +169,9 @@ fn help_flag() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -221,6 +241,13 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -231,6 +258,9 @@ fn help_short_flag() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -399,6 +429,37 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads.
+              
+              Defaults to 50. To limit the number of concurrent requests to each host, or the total
+              download bandwidth, see the `concurrent-downloads-per-host` and `bandwidth-limit`
+              settings.
+
+              [env: UV_CONCURRENT_DOWNLOADS=]
+
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors.
+              
+              Usernames, passwords, and query parameter values are masked in every URL, and URLs on
+              hosts other than well-known public indexes (like PyPI) are reduced to their final path
+              segment. The hosts of private indexes, `Authorization` header values, and well-known token
+              formats are masked wherever they appear. Applies to verbose output, warnings, errors, and
+              the audit log, but not to the output of build backends or other subprocesses.
+              
+              [env: UV_REDACT_LOGS=]
+
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command.
+              
+              With `json`, the results are written to stdout as JSON, while warnings and other
+              diagnostics are written to stderr. Supported by `uv sync`, `uv add`, `uv lock`, `uv tree`,
+              `uv version`, `uv python list`, and `uv cache info`.
+
+              Possible values:
+              - text: Display the results in a human-readable format
+              - json: Display the results in JSON format
+
           --no-progress
               Hide all progress outputs.
               
@@ -447,6 +508,14 @@ fn help_subcommand() {
               
               [env: UV_NO_CONFIG=]
 
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration.
+              
+              Profiles are defined in the `profiles` table of a `uv.toml` file, or the
+              `[tool.uv.profiles]` table of a `pyproject.toml` file, e.g., `[tool.uv.profiles.ci]`.
+              
+              [env: UV_PROFILE=]
+
       -h, --help
               Display the concise help for this command
 
@@ -685,6 +754,37 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads.
+              
+              Defaults to 50. To limit the number of concurrent requests to each host, or the total
+              download bandwidth, see the `concurrent-downloads-per-host` and `bandwidth-limit`
+              settings.
+
+              [env: UV_CONCURRENT_DOWNLOADS=]
+
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors.
+              
+              Usernames, passwords, and query parameter values are masked in every URL, and URLs on
+              hosts other than well-known public indexes (like PyPI) are reduced to their final path
+              segment. The hosts of private indexes, `Authorization` header values, and well-known token
+              formats are masked wherever they appear. Applies to verbose output, warnings, errors, and
+              the audit log, but not to the output of build backends or other subprocesses.
+              
+              [env: UV_REDACT_LOGS=]
+
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command.
+              
+              With `json`, the results are written to stdout as JSON, while warnings and other
+              diagnostics are written to stderr. Supported by `uv sync`, `uv add`, `uv lock`, `uv tree`,
+              `uv version`, `uv python list`, and `uv cache info`.
+
+              Possible values:
+              - text: Display the results in a human-readable format
+              - json: Display the results in JSON format
+
           --no-progress
               Hide all progress outputs.
               
@@ -733,6 +833,14 @@ fn help_subsubcommand() {
               
               [env: UV_NO_CONFIG=]
 
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration.
+              
+              Profiles are defined in the `profiles` table of a `uv.toml` file, or the
+              `[tool.uv.profiles]` table of a `pyproject.toml` file, e.g., `[tool.uv.profiles.ci]`.
+              
+              [env: UV_PROFILE=]
+
       -h, --help
               Display the concise help for this command
 
@@ -786,6 +894,13 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -796,6 +911,9 @@ fn help_flag_subcommand() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
 
@@ -869,6 +987,13 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -879,6 +1004,9 @@ fn help_flag_subsubcommand() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
 
@@ -1030,6 +1158,13 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1040,6 +1175,9 @@ fn help_with_global_option() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -1152,6 +1290,13 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --max-concurrent-downloads <N>
+              The maximum number of in-flight concurrent downloads [env: UV_CONCURRENT_DOWNLOADS=]
+          --redact-logs
+              Redact credentials, tokens, and private index URLs from logs, warnings, and errors [env:
+              UV_REDACT_LOGS=]
+          --output-format <OUTPUT_FORMAT>
+              The format in which to display the results of the command [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1162,6 +1307,9 @@ fn help_with_no_pager() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --profile <PROFILE>
+              The name of a settings profile to apply on top of the discovered configuration [env:
+              UV_PROFILE=]
       -h, --help
               Display the concise help for this command
       -V, --version
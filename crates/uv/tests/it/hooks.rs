@@ -0,0 +1,177 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Run a `post-lock` command hook after writing the lockfile.
+#[test]
+#[cfg(unix)]
+fn hooks_post_lock_command() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.hooks]
+        post-lock = [{ command = ["sh", "-c", "echo $UV_HOOK_EVENT > hook.txt"] }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("hooks"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Running post-lock hook: sh -c echo $UV_HOOK_EVENT > hook.txt
+    ");
+
+    context
+        .temp_dir
+        .child("hook.txt")
+        .assert(predicates::str::diff("post-lock\n"));
+
+    // Hooks don't run when the lockfile is only checked.
+    fs_err::remove_file(context.temp_dir.child("hook.txt"))?;
+    uv_snapshot!(context.filters(), context.lock().arg("--check").arg("--preview-features").arg("hooks"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+    context
+        .temp_dir
+        .child("hook.txt")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Send the event to a `post-sync` URL hook.
+#[tokio::test]
+async fn hooks_post_sync_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(body_partial_json(json!({ "event": "post-sync" })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        package = false
+
+        [tool.uv.hooks]
+        post-sync = [{{ url = "{}/hook" }}]
+    "#, server.uri()})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    warning: The `tool.uv.hooks` setting is experimental and may change without warning. Pass `--preview-features hooks` to disable this warning.
+    Notifying post-sync hook: http://[LOCALHOST]/hook
+    ");
+
+    Ok(())
+}
+
+/// A failing hook fails the command.
+#[test]
+#[cfg(unix)]
+fn hooks_failure() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        package = false
+
+        [tool.uv.hooks]
+        post-sync = [{ command = ["sh", "-c", "exit 3"] }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--preview-features").arg("hooks"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Running post-sync hook: sh -c exit 3
+    error: The `post-sync` hook `sh -c exit 3` failed with exit status: 3
+    ");
+
+    Ok(())
+}
+
+/// Hooks are only allowed in `pyproject.toml`.
+#[test]
+fn hooks_uv_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        [hooks]
+        post-lock = [{ command = ["true"] }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`. The `hooks` field is not allowed in a `uv.toml` file. `hooks` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Mark `ipykernel` as installed in the project environment, without installing it.
+fn fake_ipykernel(context: &TestContext) -> Result<()> {
+    let dist_info = context.site_packages().join("ipykernel-6.29.5.dist-info");
+    fs_err::create_dir_all(&dist_info)?;
+    fs_err::write(
+        dist_info.join("METADATA"),
+        "Metadata-Version: 2.1\nName: ipykernel\nVersion: 6.29.5\n",
+    )?;
+    fs_err::write(
+        dist_info.join("RECORD"),
+        "ipykernel-6.29.5.dist-info/METADATA,,\nipykernel-6.29.5.dist-info/RECORD,,\n",
+    )?;
+    Ok(())
+}
+
+/// Register the project environment as a Jupyter kernel.
+#[test]
+#[cfg(unix)]
+fn kernel_install() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let jupyter = context.temp_dir.child("jupyter");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context.sync().assert().success();
+
+    // `ipykernel` must be installed.
+    uv_snapshot!(context.filters(), context.kernel_install().env("JUPYTER_DATA_DIR", jupyter.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv kernel install` is experimental and may change without warning. Pass `--preview-features kernel-install` to disable this warning.
+    error: `ipykernel` is not installed in the project environment; add it with `uv add --dev ipykernel`
+    ");
+
+    fake_ipykernel(&context)?;
+
+    uv_snapshot!(context.filters(), context.kernel_install().env("JUPYTER_DATA_DIR", jupyter.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv kernel install` is experimental and may change without warning. Pass `--preview-features kernel-install` to disable this warning.
+    Installed kernel project for `.venv` at `jupyter/kernels/project`
+    ");
+
+    let kernel_json = context.read("jupyter/kernels/project/kernel.json");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(kernel_json, @r#"
+        {
+          "argv": [
+            "[VENV]/bin/python3",
+            "-m",
+            "ipykernel_launcher",
+            "-f",
+            "{connection_file}"
+          ],
+          "display_name": "project (Python 3.12)",
+          "language": "python",
+          "env": {
+            "VIRTUAL_ENV": "[VENV]/"
+          },
+          "metadata": {
+            "debugger": true,
+            "uv": {
+              "environment": "[VENV]/"
+            }
+          }
+        }
+        "#);
+    });
+
+    Ok(())
+}
+
+/// Kernels with a custom name and display name.
+#[test]
+fn kernel_install_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let jupyter = context.temp_dir.child("jupyter");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context.sync().assert().success();
+    fake_ipykernel(&context)?;
+
+    uv_snapshot!(context.filters(), context.kernel_install()
+        .arg("--name")
+        .arg("analysis")
+        .arg("--display-name")
+        .arg("Analysis")
+        .arg("--preview-features")
+        .arg("kernel-install")
+        .env("JUPYTER_DATA_DIR", jupyter.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed kernel analysis for `.venv` at `jupyter/kernels/analysis`
+    ");
+
+    let kernel_json = context.read("jupyter/kernels/analysis/kernel.json");
+    assert!(kernel_json.contains(r#""display_name": "Analysis""#));
+
+    uv_snapshot!(context.filters(), context.kernel_install()
+        .arg("--name")
+        .arg("my kernel")
+        .arg("--preview-features")
+        .arg("kernel-install")
+        .env("JUPYTER_DATA_DIR", jupyter.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `my kernel` is not a valid kernel name; kernel names may only contain ASCII letters, digits, `-`, `_`, and `.`
+    ");
+
+    Ok(())
+}
+
+/// `uv sync` warns if `ipykernel` is removed from an environment with a registered kernel.
+#[test]
+fn kernel_install_sync() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let jupyter = context.temp_dir.child("jupyter");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context.sync().assert().success();
+    fake_ipykernel(&context)?;
+
+    context
+        .kernel_install()
+        .arg("--preview-features")
+        .arg("kernel-install")
+        .env("JUPYTER_DATA_DIR", jupyter.path())
+        .assert()
+        .success();
+
+    // Simulate a stale kernelspec.
+    let kernel_json = jupyter.child("kernels/project/kernel.json");
+    let stale = context
+        .read("jupyter/kernels/project/kernel.json")
+        .replace(r#""display_name": "project"#, r#""display_name": "stale"#);
+    kernel_json.write_str(&stale)?;
+
+    // Syncing removes the undeclared `ipykernel`.
+    uv_snapshot!(context.filters(), context.sync().env("JUPYTER_DATA_DIR", jupyter.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+     - ipykernel==6.29.5
+    warning: The project environment is registered as the Jupyter kernel `project`, but `ipykernel` is not installed; add it with `uv add --dev ipykernel`
+    ");
+
+    // The kernelspec is updated.
+    kernel_json.assert(predicates::str::contains(
+        r#""display_name": "project (Python 3.12)""#,
+    ));
+
+    Ok(())
+}
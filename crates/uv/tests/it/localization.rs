@@ -0,0 +1,91 @@
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// With the `localization` preview feature, errors and warnings are labeled in the selected
+/// language.
+#[test]
+fn localized_labels() {
+    let context = TestContext::new_with_versions(&[]);
+    let log = context.temp_dir.child("usage.jsonl");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("localization").env(EnvVars::UV_LOCALE, "fr_FR.UTF-8"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    erreur: No `pyproject.toml` found in current directory or any parent directory
+    ");
+
+    uv_snapshot!(context.filters(), context.command().arg("cache").arg("dir").arg("--usage-log").arg(log.path()).arg("--preview-features").arg("localization").env(EnvVars::UV_LOCALE, "de"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/
+
+    ----- stderr -----
+    Warnung: The usage log is experimental and may change without warning. Pass `--preview-features usage-log` to disable this warning.
+    ");
+
+    // The language is detected from `LANG`, if `UV_LOCALE` and `LC_ALL` aren't set.
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("localization").env_remove(EnvVars::LC_ALL).env(EnvVars::LANG, "de_DE.UTF-8"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Fehler: No `pyproject.toml` found in current directory or any parent directory
+    ");
+
+    // `UV_LOCALE` takes precedence over `LANG`.
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("localization").env_remove(EnvVars::LC_ALL).env(EnvVars::LANG, "de_DE.UTF-8").env(EnvVars::UV_LOCALE, "en"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No `pyproject.toml` found in current directory or any parent directory
+    ");
+
+    // Without the preview feature, messages are in English.
+    uv_snapshot!(context.filters(), context.lock().env(EnvVars::UV_LOCALE, "fr"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No `pyproject.toml` found in current directory or any parent directory
+    ");
+}
+
+/// With the `localization` preview feature, resolution failures are explained in the selected
+/// language.
+#[test]
+fn localized_resolution_failure() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok>=3")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--preview-features")
+        .arg("localization")
+        .env(EnvVars::UV_LOCALE, "de"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Beim Auflösen der Abhängigkeiten wurde keine Lösung gefunden:
+      ╰─▶ Es gilt: es gibt keine Versionen von ok und Sie benötigen ok>=3, daraus folgt: Ihre Anforderungen sind nicht erfüllbar.
+    ");
+
+    Ok(())
+}
@@ -11539,7 +11539,7 @@ fn lock_find_links_local_wheel() -> Result<()> {
         version = "1000.0.0"
         source = { registry = "../links" }
         wheels = [
-            { path = "tqdm-1000.0.0-py3-none-any.whl" },
+            { path = "tqdm-1000.0.0-py3-none-any.whl", hash = "sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13", size = 1017 },
         ]
         "#
         );
@@ -11889,7 +11889,7 @@ fn lock_find_links_local_sdist() -> Result<()> {
         name = "tqdm"
         version = "999.0.0"
         source = { registry = "../links" }
-        sdist = { path = "tqdm-999.0.0.tar.gz" }
+        sdist = { path = "tqdm-999.0.0.tar.gz", hash = "sha256:89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b", size = 2127 }
         "#
         );
     });
@@ -12190,7 +12190,7 @@ fn lock_find_links_explicit_index() -> Result<()> {
         version = "1000.0.0"
         source = { registry = "../links" }
         wheels = [
-            { path = "tqdm-1000.0.0-py3-none-any.whl" },
+            { path = "tqdm-1000.0.0-py3-none-any.whl", hash = "sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13", size = 1017 },
         ]
         "#
         );
@@ -12210,6 +12210,84 @@ fn lock_find_links_explicit_index() -> Result<()> {
     Ok(())
 }
 
+/// Packages that match an index's `packages` patterns should only be resolved from that index,
+/// ignoring any `--find-links` entries.
+#[test]
+fn lock_find_links_pinned_package_pattern() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the `--find-links` entries, and an empty index.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    fs_err::create_dir_all(context.temp_dir.join("empty"))?;
+
+    for entry in fs_err::read_dir(context.workspace_root.join("test/links"))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .is_some_and(|file_name| file_name.starts_with("tqdm-"))
+        {
+            let dest = context
+                .temp_dir
+                .join("links")
+                .join(path.file_name().unwrap());
+            fs_err::copy(&path, &dest)?;
+        }
+    }
+
+    let workspace = context.temp_dir.child("workspace");
+
+    // `tqdm` is pinned to the empty index, so the `--find-links` entry is ignored.
+    let pyproject_toml = workspace.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [tool.uv]
+        find-links = ["{}"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        format = "flat"
+        url = "{}"
+        explicit = true
+        packages = ["TQ*"]
+        "#,
+        Url::from_file_path(context.temp_dir.join("links/")).unwrap(),
+        Url::from_file_path(context.temp_dir.join("empty/")).unwrap(),
+    })?;
+
+    let output = context.lock().current_dir(&workspace).output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("tqdm was not found"), "{stderr}");
+
+    // Without the pattern, `tqdm` is resolved from the `--find-links` entry.
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        "#,
+        Url::from_file_path(context.temp_dir.join("links/")).unwrap(),
+    })?;
+
+    context.lock().current_dir(&workspace).assert().success();
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock"))?;
+    assert!(lock.contains(r#"version = "1000.0.0""#), "{lock}");
+
+    Ok(())
+}
+
 /// Use the same index priority rules, interchangeably, for `--find-links` and Simple API indexes.
 #[test]
 fn lock_find_links_higher_priority_index() -> Result<()> {
@@ -12292,7 +12370,7 @@ fn lock_find_links_higher_priority_index() -> Result<()> {
         version = "1000.0.0"
         source = { registry = "../links" }
         wheels = [
-            { path = "tqdm-1000.0.0-py3-none-any.whl" },
+            { path = "tqdm-1000.0.0-py3-none-any.whl", hash = "sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13", size = 1017 },
         ]
         "#
         );
@@ -23599,6 +23677,135 @@ fn lock_dry_run_noop() -> Result<()> {
     Ok(())
 }
 
+/// Report the lockfile changes as JSON with `--output-format json`.
+#[test]
+fn lock_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm==1000.0.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+        context.workspace_root.join("test/links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "path": "[TEMP_DIR]/uv.lock",
+      "action": "create",
+      "changes": [
+        {
+          "name": "project",
+          "action": "added",
+          "from": [],
+          "to": [
+            {
+              "version": "0.1.0"
+            }
+          ]
+        },
+        {
+          "name": "tqdm",
+          "action": "added",
+          "from": [],
+          "to": [
+            {
+              "version": "1000.0.0"
+            }
+          ]
+        }
+      ],
+      "dry_run": false
+    }
+
+    ----- stderr -----
+    warning: The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features json-output` to disable this warning.
+    Resolved 2 packages in [TIME]
+    "#);
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "path": "[TEMP_DIR]/uv.lock",
+      "action": "check",
+      "changes": [],
+      "dry_run": false
+    }
+
+    ----- stderr -----
+    warning: The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features json-output` to disable this warning.
+    Resolved 2 packages in [TIME]
+    "#);
+
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+        context.workspace_root.join("test/links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--output-format").arg("json").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "path": "[TEMP_DIR]/uv.lock",
+      "action": "update",
+      "changes": [
+        {
+          "name": "tqdm",
+          "action": "removed",
+          "from": [
+            {
+              "version": "1000.0.0"
+            }
+          ],
+          "to": []
+        }
+      ],
+      "dry_run": true
+    }
+
+    ----- stderr -----
+    warning: The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features json-output` to disable this warning.
+    Resolved 1 package in [TIME]
+    "#);
+
+    Ok(())
+}
+
 #[test]
 fn lock_group_include() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -33370,3 +33577,112 @@ fn lock_check_multiple_default_indexes_explicit_assignment_dependency_group() ->
 
     Ok(())
 }
+
+/// Propose fixes for a conflict between a direct and a transitive requirement with `--interactive`.
+///
+/// In a non-interactive terminal, the fixes are displayed but not applied.
+#[test]
+fn lock_interactive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok>=1,<2", "child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#})?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok>=2"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--interactive")
+        .arg("--preview-features")
+        .arg("interactive-resolution")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links")), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only child==0.1.0 is available and child==0.1.0 depends on ok>=2, we can conclude that all versions of child depend on ok>=2.
+          And because your project depends on child and ok>=1,<2, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: The following changes may resolve the conflict; re-run in an interactive terminal to apply them:
+      - Relax `ok>=1,<2` to `ok>=1` in `project.dependencies`
+      - Override all requirements on `ok` with `ok>=1,<2` via `tool.uv.override-dependencies`
+    ");
+
+    // The `pyproject.toml` should be unchanged.
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["ok>=1,<2", "child"]
+
+    [tool.uv.sources]
+    child = { path = "child" }
+    "#);
+
+    Ok(())
+}
+
+/// Propose declaring extras as conflicting with `--interactive`.
+#[test]
+fn lock_interactive_conflicting_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        a = ["ok==1.0.0"]
+        b = ["ok==2.0.0"]
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--interactive")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links")), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `--interactive` option is experimental and may change without warning. Pass `--preview-features interactive-resolution` to disable this warning.
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[b] depends on ok==2.0.0 and project[a] depends on ok==1.0.0, we can conclude that project[a] and project[b] are incompatible.
+          And because your project requires project[a] and project[b], we can conclude that your project's requirements are unsatisfiable.
+
+    hint: The following changes may resolve the conflict; re-run in an interactive terminal to apply them:
+      - Relax `ok==1.0.0` to `ok>=1.0.0` in `project.optional-dependencies.a`
+      - Relax `ok==2.0.0` to `ok>=2.0.0` in `project.optional-dependencies.b`
+      - Declare `project[a]` and `project[b]` as conflicting via `tool.uv.conflicts`
+    ");
+
+    Ok(())
+}
@@ -3,6 +3,9 @@
 
 pub(crate) mod common;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod audit_log;
+
 mod auth;
 
 mod branching_urls;
@@ -19,12 +22,27 @@ mod cache;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_info;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_metrics;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_warm;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_size;
 
+#[cfg(feature = "python")]
+mod config;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -34,14 +52,32 @@ mod edit;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod export;
 
+#[cfg(feature = "python")]
+mod env_info;
+
+#[cfg(feature = "python")]
+mod error_codes;
+
+mod external;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod format;
 
+mod generate_devcontainer;
+
 mod help;
 
+#[cfg(feature = "python")]
+mod hooks;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "git"))]
 mod init;
 
+#[cfg(feature = "python")]
+mod kernel_install;
+
+mod localization;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod lock;
 
@@ -63,6 +99,9 @@ mod pip_compile;
 
 mod pip_compile_scenarios;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_download;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod pip_freeze;
 
@@ -75,6 +114,9 @@ mod pip_list;
 
 mod pip_show;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_size;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod pip_sync;
 
@@ -82,6 +124,12 @@ mod pip_debug;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod policy;
+
+#[cfg(feature = "python")]
+mod profile;
+
 #[cfg(feature = "pypi")]
 mod publish;
 
@@ -108,12 +156,24 @@ mod python_upgrade;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod run;
 
+#[cfg(feature = "python")]
+mod sbom_audit;
+
+#[cfg(feature = "python")]
+mod sbom_upload;
+
+#[cfg(feature = "python")]
+mod sbom_verify;
+
 #[cfg(feature = "self-update")]
 mod self_update;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod show_settings;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod shell_completion;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
@@ -138,6 +198,8 @@ mod tool_upgrade;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tree;
 
+mod usage_log;
+
 #[cfg(feature = "python")]
 mod venv;
 
@@ -924,3 +924,111 @@ async fn proxy_schemeless_url_in_uv_toml() {
         "Target should NOT have been called directly when proxy is configured"
     );
 }
+
+/// When a primary index with a retry budget fails with a server error, uv should fail over to a
+/// `fallback` index rather than failing the resolution, and stop querying the primary index once
+/// its retry budget is exhausted.
+#[tokio::test]
+async fn index_fallback_on_server_error() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Expect a single request per resolution, as retries are disabled.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let links = context.workspace_root.join("test/links");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&format!(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "mirror"
+        url = "{}"
+        retry-budget = 1
+
+        [[tool.uv.index]]
+        name = "links"
+        url = "{}"
+        format = "flat"
+        default = true
+        role = "fallback"
+        "#,
+        server.uri(),
+        links.display(),
+    ))?;
+
+    let output = context.lock().env(EnvVars::UV_HTTP_RETRIES, "0").output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("falling back to other indexes"), "{stderr}");
+
+    // Without a retry budget, the index doesn't fail over, and the server error is reported.
+    pyproject_toml.write_str(&format!(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "mirror"
+        url = "{}"
+
+        [[tool.uv.index]]
+        name = "links"
+        url = "{}"
+        format = "flat"
+        default = true
+        role = "fallback"
+        "#,
+        server.uri(),
+        links.display(),
+    ))?;
+
+    let output = context
+        .lock()
+        .arg("--refresh")
+        .env(EnvVars::UV_HTTP_RETRIES, "0")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("503 Service Unavailable"), "{stderr}");
+
+    // Without a fallback index, the server error is reported.
+    pyproject_toml.write_str(&format!(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "mirror"
+        url = "{}"
+        default = true
+        "#,
+        server.uri(),
+    ))?;
+
+    let output = context
+        .lock()
+        .arg("--refresh")
+        .env(EnvVars::UV_HTTP_RETRIES, "0")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("503 Service Unavailable"), "{stderr}");
+
+    Ok(())
+}
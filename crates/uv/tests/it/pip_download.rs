@@ -0,0 +1,179 @@
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Download a package and its dependencies into a directory.
+#[test]
+fn download() {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("anyio==4.3.0")
+        .arg("--dest")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+     + anyio-4.3.0-py3-none-any.whl
+     + idna-3.6-py3-none-any.whl
+     + sniffio-1.3.1-py3-none-any.whl
+    "
+    );
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse
+        .child("anyio-4.3.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheelhouse
+        .child("idna-3.6-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheelhouse
+        .child("sniffio-1.3.1-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // Nothing should be installed into the environment.
+    context.assert_command("import anyio").failure();
+
+    // The wheelhouse can be used for an offline installation.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("wheelhouse")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+}
+
+/// Download wheels for a foreign platform.
+#[test]
+fn download_python_platform() {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("markupsafe==2.1.5")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + MarkupSafe-2.1.5-cp311-cp311-win_amd64.whl
+    "
+    );
+
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("MarkupSafe-2.1.5-cp311-cp311-win_amd64.whl")
+        .assert(predicates::path::is_file());
+}
+
+/// Validate the hashes of downloaded archives, without leaving mismatched archives behind.
+#[test]
+fn download_require_hashes() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "ok==1.0.0 --hash=sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--dest")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + ok-1.0.0-py3-none-any.whl
+    ");
+
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("ok-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    requirements_txt.write_str(
+        "ok==1.0.0 --hash=sha256:0000000000000000000000000000000000000000000000000000000000000000",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--dest")
+        .arg("mismatch"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    error: Hash mismatch for `ok==1.0.0`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f
+    ");
+
+    // Only the (empty) destination directory should remain.
+    assert_eq!(
+        fs_err::read_dir(context.temp_dir.child("mismatch").path())?.count(),
+        0
+    );
+
+    Ok(())
+}
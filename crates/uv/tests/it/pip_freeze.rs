@@ -612,3 +612,233 @@ fn freeze_exclude() {
     "###
     );
 }
+
+/// Include the hashes recorded at installation time with `--hashes`.
+#[test]
+fn freeze_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        iniconfig==2.0.0 \
+            --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "})?;
+
+    // Install with hash-checking enabled, such that the hashes are recorded.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    // Install a second package without hashes.
+    context.pip_install().arg("tomli==2.0.1").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_freeze().arg("--hashes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    tomli==2.0.1
+
+    ----- stderr -----
+    warning: No hashes were recorded at installation time for: `tomli`
+    "
+    );
+
+    Ok(())
+}
+
+/// Export the installed packages as a `CycloneDX` SBOM with `--format cyclonedx1.5`.
+#[test]
+fn freeze_cyclonedx() -> Result<()> {
+    let context = TestContext::new("3.12").with_cyclonedx_filters();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        ok==1.0.0 \
+            --hash=sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f
+    "})?;
+
+    // Install with hash-checking enabled, such that the hashes are recorded.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--no-index")
+        .assert()
+        .success();
+
+    // Manually create a package installed from a Git repository, which depends on `ok`.
+    let dist_info = ChildPath::new(context.site_packages()).child("app-0.1.0.dist-info");
+    dist_info.child("METADATA").write_str(indoc::indoc! {r"
+        Metadata-Version: 2.4
+        Name: app
+        Version: 0.1.0
+        License-Expression: MIT
+        Project-URL: Source, https://github.com/example/app
+        Requires-Dist: ok
+        Requires-Dist: validation ; python_version < '3'
+    "})?;
+    dist_info.child("direct_url.json").write_str(
+        r#"{"url": "https://github.com/example/app", "vcs_info": {"vcs": "git", "commit_id": "0123456789abcdef0123456789abcdef01234567"}}"#,
+    )?;
+    dist_info.child("RECORD").write_str("")?;
+
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--hashes")
+        .arg("--preview-features")
+        .arg("sbom-export"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "serialNumber": "[SERIAL_NUMBER]",
+      "metadata": {
+        "timestamp": "[TIMESTAMP]",
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "publisher": "Astral Software Inc.",
+              "name": "uv",
+              "version": "[VERSION]",
+              "purl": "pkg:pypi/uv@[VERSION]"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "app-1@0.1.0",
+          "name": "app",
+          "version": "0.1.0",
+          "licenses": [
+            {
+              "expression": "MIT"
+            }
+          ],
+          "purl": "pkg:pypi/app@0.1.0?vcs_url=git%2Bhttps://github.com/example/app%400123456789abcdef0123456789abcdef01234567",
+          "externalReferences": [
+            {
+              "type": "vcs",
+              "url": "https://github.com/example/app"
+            },
+            {
+              "type": "vcs",
+              "url": "git+https://github.com/example/app@0123456789abcdef0123456789abcdef01234567"
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "ok-2@1.0.0",
+          "name": "ok",
+          "version": "1.0.0",
+          "hashes": [
+            {
+              "alg": "SHA-256",
+              "content": "79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f"
+            }
+          ],
+          "purl": "pkg:pypi/ok@1.0.0"
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "app-1@0.1.0",
+          "dependsOn": [
+            "ok-2@1.0.0"
+          ]
+        },
+        {
+          "ref": "ok-2@1.0.0",
+          "dependsOn": []
+        }
+      ]
+    }
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+/// The preview warning for SBOM formats names the format that was requested.
+#[test]
+fn freeze_cyclonedx_preview_warning() {
+    let context = TestContext::new("3.12").with_cyclonedx_filters();
+
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--format")
+        .arg("cyclonedx1.4+xml"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    <?xml version="1.0" encoding="UTF-8"?>
+    <bom xmlns="http://cyclonedx.org/schema/bom/1.4" serialNumber="[SERIAL_NUMBER]" version="1">
+      <metadata>
+        <timestamp>[TIMESTAMP]</timestamp>
+        <tools>
+          <tool>
+            <vendor>Astral Software Inc.</vendor>
+            <name>uv</name>
+            <version>[VERSION]</version>
+          </tool>
+        </tools>
+      </metadata>
+      <components />
+      <dependencies />
+    </bom>
+    ----- stderr -----
+    warning: `uv pip freeze --format=cyclonedx1.4+xml` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    "#);
+}
+
+/// The environment's interpreter is only recorded in the SBOM if requested.
+#[test]
+fn freeze_cyclonedx_include_interpreter() {
+    let context = TestContext::new("3.12").with_cyclonedx_filters();
+
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--format")
+        .arg("cyclonedx1.4+xml")
+        .arg("--include-interpreter")
+        .arg("--preview-features")
+        .arg("sbom-export"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    <?xml version="1.0" encoding="UTF-8"?>
+    <bom xmlns="http://cyclonedx.org/schema/bom/1.4" serialNumber="[SERIAL_NUMBER]" version="1">
+      <metadata>
+        <timestamp>[TIMESTAMP]</timestamp>
+        <tools>
+          <tool>
+            <vendor>Astral Software Inc.</vendor>
+            <name>uv</name>
+            <version>[VERSION]</version>
+          </tool>
+        </tools>
+        <properties>
+          <property name="uv:python:implementation">cpython</property>
+          <property name="uv:python:version">3.12.[X]</property>
+        </properties>
+      </metadata>
+      <components />
+      <dependencies />
+    </bom>
+    ----- stderr -----
+    "#);
+}
@@ -522,6 +522,37 @@ fn install_package() {
     context.assert_command("import flask").success();
 }
 
+/// Report progress as JSON events with `--progress json`.
+#[test]
+fn install_package_progress_json() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--progress")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    {"event":"resolve","package":"ok","version":"1.0.0"}
+    {"event":"resolve_complete"}
+    Resolved 1 package in [TIME]
+    {"event":"prepare_progress","package":"ok","completed":1,"total":1}
+    {"event":"prepare_complete"}
+    Prepared 1 package in [TIME]
+    {"event":"install_progress","package":"ok","completed":1,"total":1}
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "#);
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -655,6 +686,169 @@ fn install_from_dev_stdin() -> Result<()> {
     Ok(())
 }
 
+/// Install a package from a `requirements.txt` passed via `-r -`, verifying its contents against
+/// `--requirements-hash`.
+#[test]
+#[expect(clippy::disallowed_types)]
+fn install_from_stdin_requirements_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // A mismatched hash should be rejected before resolution.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("-")
+        .arg("--requirements-hash=-=sha256:0000000000000000000000000000000000000000000000000000000000000000")
+        .stdin(std::fs::File::open(&requirements_txt)?), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash mismatch for `stdin`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6
+    "
+    );
+
+    // A matching hash should be accepted.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("-")
+        .arg("--requirements-hash=-=sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6")
+        .stdin(std::fs::File::open(&requirements_txt)?), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// `--requirements-hash` is only supported for `requirements.txt` files.
+#[test]
+fn install_requirements_hash_pyproject_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+dependencies = ["iniconfig"]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("pyproject.toml")
+        .arg("--requirements-hash")
+        .arg("pyproject.toml=sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--requirements-hash` is only supported for `requirements.txt` files, but `pyproject.toml` was provided
+    "
+    );
+
+    Ok(())
+}
+
+/// Each `--requirements-hash` applies to the requirements file it names.
+#[test]
+fn install_requirements_hash_per_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+    let requirements_dev_txt = context.temp_dir.child("requirements-dev.txt");
+    requirements_dev_txt.write_str("anyio")?;
+
+    // The hash of `requirements.txt` doesn't match `requirements-dev.txt`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-r")
+        .arg("requirements-dev.txt")
+        .arg("--requirements-hash")
+        .arg("requirements.txt=sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6")
+        .arg("--requirements-hash")
+        .arg("requirements-dev.txt=sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash mismatch for `requirements-dev.txt`
+
+    Expected:
+      sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6
+
+    Computed:
+      sha256:d07ebf7465b4aa3ee99060bc5b1347bd3a913a761c1534fdba3b8f844fd80a68
+    ");
+
+    // A hash for a file that wasn't provided via `-r` is rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--requirements-hash")
+        .arg("requirements-dev.txt=sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--requirements-hash` was provided for `requirements-dev.txt`, but it was not provided as a requirements file
+    ");
+
+    // As is a hash without any requirements files.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--requirements-hash")
+        .arg("requirements.txt=sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--requirements-hash` was provided for `requirements.txt`, but it was not provided as a requirements file
+    ");
+
+    // The hash must name a file.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--requirements-hash")
+        .arg("sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6' for '--requirements-hash <FILE=HASH>': Invalid requirements hash: sha256:429e50b8dccfc91a93240890abdc53e2873833a64db9f4a7514793794a381fa6 (expected `FILE=ALGORITHM:DIGEST`)
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 /// Install a package from a remote `requirements.txt` into a virtual environment.
 #[tokio::test]
 async fn install_remote_requirements_txt() -> Result<()> {
@@ -8459,6 +8653,276 @@ fn local_index_fallback() -> Result<()> {
     Ok(())
 }
 
+/// Write a local PEP 503-compatible index that offers `tqdm`, with the given `<meta>` tags.
+fn write_tqdm_index(context: &TestContext, name: &str, meta: &str) -> Result<Url> {
+    let root = context.temp_dir.child(name);
+    let tqdm = root.child("tqdm");
+    fs_err::create_dir_all(&tqdm)?;
+    tqdm.child("index.html").write_str(&indoc::formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1" />
+            {meta}
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+            <a href="{}/tqdm-1000.0.0-py3-none-any.whl">tqdm-1000.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+    "#, Url::from_directory_path(context.workspace_root.join("test/links/")).unwrap().as_str()})?;
+    Ok(Url::from_directory_path(root).unwrap())
+}
+
+/// Merge, warn, or fail, when a package is found on multiple indexes that don't declare each other
+/// via PEP 708 repository tracking metadata.
+#[test]
+fn local_index_untracked() -> Result<()> {
+    let context = TestContext::new("3.11");
+
+    let first = write_tqdm_index(&context, "first", "")?;
+    let second = write_tqdm_index(&context, "second", "")?;
+
+    // By default, uv merges the indexes.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    ");
+
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(r#"index-tracking-policy = "warn""#)?;
+
+    // With the `warn` policy, uv warns.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--reinstall")
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `tqdm` was found on multiple indexes that don't declare each other as mirrors or alternate locations (`file://[TEMP_DIR]/second/`, `file://[TEMP_DIR]/first/`); set `index-tracking-policy = "deny"` to reject it
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tqdm==1000.0.0
+    "#
+    );
+
+    config.write_str(r#"index-tracking-policy = "deny""#)?;
+
+    // With the `deny` policy, uv fails.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--reinstall")
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `tqdm` was found on multiple indexes that don't declare each other as mirrors or alternate locations: `file://[TEMP_DIR]/second/`, `file://[TEMP_DIR]/first/`
+    "
+    );
+
+    Ok(())
+}
+
+/// Merge a package across indexes that list each other as PEP 708 alternate locations.
+#[test]
+fn local_index_alternate_locations() -> Result<()> {
+    let context = TestContext::new("3.11");
+
+    let first_url = Url::from_directory_path(context.temp_dir.join("first"))
+        .unwrap()
+        .join("tqdm/")?;
+    let second_url = Url::from_directory_path(context.temp_dir.join("second"))
+        .unwrap()
+        .join("tqdm/")?;
+    let meta = format!(
+        r#"<meta name="pypi:alternate-locations" content="{first_url}" />
+            <meta name="pypi:alternate-locations" content="{second_url}" />"#
+    );
+    let first = write_tqdm_index(&context, "first", &meta)?;
+    let second = write_tqdm_index(&context, "second", &meta)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(first.as_str())
+        .arg("--extra-index-url")
+        .arg(second.as_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Warn, or fail, when a distribution's PEP 740 attestations are missing or invalid.
+#[test]
+fn local_index_attestations() -> Result<()> {
+    let context = TestContext::new("3.11");
+
+    let root = context.temp_dir.child("index");
+    let tqdm = root.child("tqdm");
+    fs_err::create_dir_all(&tqdm)?;
+    let links = Url::from_directory_path(context.workspace_root.join("test/links/")).unwrap();
+    let index = |provenance: &str| {
+        indoc::formatdoc! {r#"
+            <!DOCTYPE html>
+            <html>
+              <body>
+                <h1>Links for tqdm</h1>
+                <a href="{links}tqdm-1000.0.0-py3-none-any.whl#sha256=a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13" {provenance}>tqdm-1000.0.0-py3-none-any.whl</a>
+              </body>
+            </html>
+        "#}
+    };
+    let index_url = Url::from_directory_path(&root).unwrap();
+
+    // Without any provenance, uv warns.
+    tqdm.child("index.html").write_str(&index(""))?;
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(&indoc::formatdoc! {r#"
+        [[index]]
+        url = "{index_url}"
+        default = true
+        verify = "warn"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--config-file")
+        .arg(config.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: No attestations were found for `tqdm==1000.0.0` on file://[TEMP_DIR]/index/
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "
+    );
+
+    // Invalid attestations are reported, but don't prevent installation.
+    tqdm.child("provenance.json")
+        .write_str(r#"{"version": 1, "attestation_bundles": []}"#)?;
+    tqdm.child("index.html")
+        .write_str(&index(r#"data-provenance="provenance.json""#))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--reinstall")
+        .arg("--config-file")
+        .arg(config.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Failed to verify attestations for `tqdm==1000.0.0` from file://[TEMP_DIR]/index/: The provenance does not contain any attestations
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tqdm==1000.0.0
+    ");
+
+    // With the `require` policy, uv fails if the attestations can't be verified.
+    config.write_str(&indoc::formatdoc! {r#"
+        [[index]]
+        url = "{index_url}"
+        default = true
+        verify = "require"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--reinstall")
+        .arg("--config-file")
+        .arg(config.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to verify attestations for `tqdm==1000.0.0` from file://[TEMP_DIR]/index/: The provenance does not contain any attestations
+    "
+    );
+
+    // With the `require` policy, uv also fails if the attestations are missing.
+    tqdm.child("index.html").write_str(&index(""))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--reinstall")
+        .arg("--config-file")
+        .arg(config.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: No attestations were found for `tqdm==1000.0.0` on file://[TEMP_DIR]/index/, but the index requires them
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn accept_existing_prerelease() -> Result<()> {
     let context = TestContext::new("3.12").with_filtered_counts();
@@ -13805,6 +14269,284 @@ fn install_missing_python_version_with_target() {
     );
 }
 
+/// Install into a `--target` directory without generating any scripts.
+#[test]
+fn install_target_no_scripts() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("flask==3.0.2")
+        .arg("--no-deps")
+        .arg("--no-scripts")
+        .arg("--target")
+        .arg("target"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flask==3.0.2
+    "
+    );
+
+    // The package should be installed, but the `flask` entrypoint should not.
+    let target = context.temp_dir.child("target");
+    assert!(target.child("flask").is_dir());
+    assert!(!target.child("bin").exists());
+
+    // The `RECORD` should not reference any scripts, such that the package can be uninstalled.
+    let record = fs::read_to_string(target.child("flask-3.0.2.dist-info").child("RECORD"))?;
+    assert!(!record.contains("bin/flask"));
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("flask")
+        .arg("--target")
+        .arg("target"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Uninstalled 1 package in [TIME]
+     - flask==3.0.2
+    "
+    );
+
+    assert!(!target.child("flask").exists());
+
+    Ok(())
+}
+
+/// Compile, upgrade, and uninstall packages in a `--target` directory.
+#[test]
+fn install_target_compile_bytecode_upgrade() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--no-index")
+        .arg("--compile-bytecode")
+        .arg("--target")
+        .arg("target"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 1 file in [TIME]
+     + ok==1.0.0
+    ");
+
+    // The bytecode is compiled within the target directory.
+    let target = context.temp_dir.child("target");
+    assert!(
+        fs::read_dir(target.child("ok").child("__pycache__").path())?
+            .filter_map(Result::ok)
+            .any(|entry| entry.path().extension().is_some_and(|ext| ext == "pyc"))
+    );
+
+    // Upgrading replaces the existing version in the target directory.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--upgrade")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--no-index")
+        .arg("--compile-bytecode")
+        .arg("--target")
+        .arg("target"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 1 file in [TIME]
+     - ok==1.0.0
+     + ok==2.0.0
+    ");
+
+    assert!(!target.child("ok-1.0.0.dist-info").exists());
+    assert!(target.child("ok-2.0.0.dist-info").is_dir());
+
+    // Uninstalling removes the package, including its bytecode.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("ok")
+        .arg("--target")
+        .arg("target"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - ok==2.0.0
+    ");
+
+    assert!(!target.child("ok").exists());
+
+    Ok(())
+}
+
+/// Packages with the same build requirements are built in separate environments, such that a build
+/// that modifies its environment can't affect another build.
+#[test]
+fn build_environment_isolated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A build backend that records each build in the environment, and fails if the environment
+    // was used by another build.
+    let build_backend = indoc! {r#"
+        import glob
+        import os
+        import sys
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            name = os.path.basename(os.getcwd())
+            others = [
+                marker
+                for marker in glob.glob(os.path.join(sys.prefix, "built-*"))
+                if os.path.basename(marker) != f"built-{name}"
+            ]
+            if others:
+                raise RuntimeError(f"Build environment was used by another build: {others}")
+            open(os.path.join(sys.prefix, f"built-{name}"), "w").close()
+
+            filename = f"{name}-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr(
+                    f"{name}-0.1.0.dist-info/METADATA",
+                    f"Metadata-Version: 2.1\nName: {name}\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    f"{name}-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nGenerator: test\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr(f"{name}-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#};
+
+    for name in ["first", "second"] {
+        let package = context.temp_dir.child(name);
+        package.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+
+            [build-system]
+            requires = []
+            backend-path = ["."]
+            build-backend = "build_backend"
+        "#})?;
+        package.child("build_backend.py").write_str(build_backend)?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-index")
+        .arg("./first")
+        .arg("./second"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    ");
+
+    Ok(())
+}
+
+/// Build requirements are installed into an environment in the cache once, and reused by builds
+/// with the same build requirements in later invocations.
+#[test]
+fn build_environment_cached() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A build backend that fails unless its build requirement is importable.
+    let build_backend = indoc! {r#"
+        import importlib.metadata
+        import os
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            assert importlib.metadata.version("ok") == "1.0.0"
+
+            name = os.path.basename(os.getcwd())
+            filename = f"{name}-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr(
+                    f"{name}-0.1.0.dist-info/METADATA",
+                    f"Metadata-Version: 2.1\nName: {name}\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    f"{name}-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nGenerator: test\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr(f"{name}-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#};
+
+    for name in ["first", "second"] {
+        let package = context.temp_dir.child(name);
+        package.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+
+            [build-system]
+            requires = ["ok==1.0.0"]
+            backend-path = ["."]
+            build-backend = "build_backend"
+        "#})?;
+        package.child("build_backend.py").write_str(build_backend)?;
+    }
+
+    for name in ["./first", "./second"] {
+        context
+            .pip_install()
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test/links"))
+            .arg(name)
+            .assert()
+            .success();
+    }
+
+    // Both builds used the same cached environment.
+    let environments = fs_err::read_dir(context.cache_dir.child("environments-v2").path())?
+        .map(|entry| Ok(fs_err::read_dir(entry?.path())?.count()))
+        .sum::<Result<usize>>()?;
+    assert_eq!(environments, 1);
+
+    Ok(())
+}
+
 /// Use a wheel that is only compatible with Python 3.13 with Python 3.12 or Python 3.13 to simulate
 /// a wheel build for the wrong platform in a cross-install scenario. Ensure that we catch this case
 /// and error accordingly. Additionally, we ensure that for a build dependency, which builds and
@@ -14117,3 +14859,99 @@ fn abi_compatibility_on_freethreaded_python() {
      + multi-abi-package==1.0.0 (from file://[WORKSPACE]/test/links/multi_abi_package-1.0.0-cp314-cp314t.abi3-manylinux_2_17_x86_64.whl)
     ");
 }
+
+/// An isolated cache namespace doesn't reuse entries from the shared cache, while an overlay
+/// namespace does, without writing to the shared cache.
+#[test]
+fn cache_namespace_modes() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let find_links = context.workspace_root.join("test/links");
+
+    // Populate the shared cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+    uv_snapshot!(context.filters(), context.pip_uninstall().arg("ok"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - ok==1.0.0
+    ");
+
+    // An isolated namespace has to unpack the wheel again.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"cache-namespace = "isolated""#)?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+    uv_snapshot!(context.filters(), context.pip_uninstall().arg("ok"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - ok==1.0.0
+    ");
+
+    // An overlay namespace reuses the wheel from the shared cache.
+    uv_toml.write_str(indoc! {r#"
+        cache-namespace = "overlay"
+        cache-namespace-mode = "overlay"
+    "#})?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+
+    // The isolated namespace unpacked its own copy of the wheel, while the overlay namespace
+    // reused the archive in the shared cache.
+    let archives = |root: &std::path::Path| -> Result<usize> {
+        Ok(fs_err::read_dir(root.join("archive-v0"))
+            .map(Iterator::count)
+            .unwrap_or(0))
+    };
+    let namespaces = context.cache_dir.child("namespaces");
+    assert_eq!(archives(context.cache_dir.path())?, 1);
+    assert_eq!(archives(&namespaces.child("isolated"))?, 1);
+    assert_eq!(archives(&namespaces.child("overlay"))?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use walkdir::WalkDir;
+
+use crate::common::TestContext;
+
+/// Test that `pip size` distinguishes files shared with the cache from files unique to the
+/// environment.
+#[test]
+fn pip_size_hardlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .arg("--compile-bytecode")
+        .assert()
+        .success();
+
+    let output = context
+        .pip_size()
+        .arg("--preview-features")
+        .arg("pip-size")
+        .arg("--format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let total = report["total_bytes"].as_u64().unwrap();
+    let shared = report["shared_bytes"].as_u64().unwrap();
+    let unique = report["unique_bytes"].as_u64().unwrap();
+    let bytecode = report["bytecode_bytes"].as_u64().unwrap();
+    assert_eq!(total, shared + unique);
+
+    // The installed files and the compiled bytecode are linked from the cache, while the
+    // `RECORD` and other installer metadata are not.
+    if cfg!(unix) {
+        assert!(shared > bytecode);
+        assert!(unique > 0);
+    }
+    assert!(bytecode > 0);
+
+    Ok(())
+}
+
+/// Test that files hard-linked from outside the cache are reported as unique to the environment.
+#[test]
+#[cfg(unix)]
+fn pip_size_copy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("ok")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--no-index")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    // Link each installed file from elsewhere, such that it has multiple links.
+    let links = context.temp_dir.child("links");
+    fs_err::create_dir_all(&links)?;
+    for (index, entry) in WalkDir::new(context.site_packages())
+        .into_iter()
+        .enumerate()
+    {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            fs_err::hard_link(entry.path(), links.child(index.to_string()))?;
+        }
+    }
+
+    let output = context
+        .pip_size()
+        .arg("--preview-features")
+        .arg("pip-size")
+        .arg("--format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert!(report["total_bytes"].as_u64().unwrap() > 0);
+    assert_eq!(report["shared_bytes"].as_u64(), Some(0));
+    assert_eq!(report["unique_bytes"], report["total_bytes"]);
+
+    Ok(())
+}
@@ -3361,6 +3361,137 @@ fn recompile() -> Result<()> {
     Ok(())
 }
 
+/// Reuse the bytecode compiled for another environment.
+#[test]
+fn compile_shared_bytecode_cache() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("ok==1.0.0")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--compile")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + ok==1.0.0
+    ");
+
+    let pyc = context
+        .site_packages()
+        .join("ok")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc");
+    let compiled = fs_err::read(&pyc)?;
+
+    // The compiled bytecode is stored in the cache.
+    assert!(
+        fs_err::read_dir(context.cache_dir.child("bytecode-v1").path())?
+            .next()
+            .is_some()
+    );
+
+    // In a new environment, the bytecode is read from the cache.
+    context.reset_venv();
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--compile")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + ok==1.0.0
+    ");
+
+    // Only the header, which contains the timestamp of the source file, may differ.
+    assert_eq!(fs_err::read(&pyc)?[16..], compiled[16..]);
+
+    context.assert_command("import ok").success();
+
+    Ok(())
+}
+
+/// Hard link the bytecode compiled for another environment from the cache.
+#[test]
+#[cfg(unix)]
+fn compile_shared_bytecode_cache_hardlink() -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("ok==1.0.0")?;
+
+    for _ in 0..2 {
+        context.reset_venv();
+        context
+            .pip_sync()
+            .arg("requirements.txt")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test/links/"))
+            .arg("--link-mode")
+            .arg("hardlink")
+            .arg("--compile")
+            .arg("--strict")
+            .assert()
+            .success();
+    }
+
+    // The bytecode is the cached `.pyc` file itself, rather than a copy.
+    let pyc = context
+        .site_packages()
+        .join("ok")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc");
+    let inode = fs_err::metadata(&pyc)?.ino();
+    let cached = fs_err::read_dir(context.cache_dir.child("bytecode-v1").path())?
+        .map(|entry| Ok(entry?.metadata()?.ino()))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(cached.contains(&inode));
+
+    context.assert_command("import ok").success();
+
+    // With `--link-mode copy`, the bytecode is copied from the cache.
+    context.reset_venv();
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("--link-mode")
+        .arg("copy")
+        .arg("--compile")
+        .arg("--strict")
+        .assert()
+        .success();
+    assert!(!cached.contains(&fs_err::metadata(&pyc)?.ino()));
+
+    context.assert_command("import ok").success();
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
@@ -0,0 +1,276 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Write an organization policy to the file read by [`TestContext::with_policy_file`].
+fn write_policy(context: &TestContext, content: &str) -> Result<()> {
+    context
+        .home_dir
+        .child("config")
+        .child("uv")
+        .child("policy.toml")
+        .write_str(content)?;
+    Ok(())
+}
+
+/// The policy's required version is enforced, even with `--no-config`.
+#[test]
+fn policy_required_version() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, r#"required-version = "<0.0.1""#)?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--no-config"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Required uv version `<0.0.1` (from the organization policy at `[HOME]/config/uv/policy.toml`) does not match the running version `0.9.28`
+    ");
+
+    Ok(())
+}
+
+/// Unknown fields in the policy are rejected.
+#[test]
+fn policy_invalid() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, "index-url = \"https://example.com/simple\"")?;
+
+    uv_snapshot!(context.filters(), context.version(), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `[HOME]/config/uv/policy.toml`
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | index-url = "https://example.com/simple"
+      | ^^^^^^^^^
+    unknown field `index-url`, expected one of `allowed-indexes`, `require-hashes`, `no-build`, `required-version`
+    "#);
+
+    Ok(())
+}
+
+/// Indexes that aren't allowed by the policy can't be used, even if passed on the command line.
+#[test]
+fn policy_allowed_indexes() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, r#"allowed-indexes = ["https://pypi.org/simple"]"#)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--index-url")
+        .arg("https://test.pypi.org/simple"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The index `https://test.pypi.org/simple` is not allowed by the organization policy
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--find-links")
+        .arg("https://download.pytorch.org/whl/torch_stable.html"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The index `https://download.pytorch.org/whl/torch_stable.html` is not allowed by the organization policy
+    ");
+
+    // Indexes nested under an allowed index are allowed.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple/"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+
+    Ok(())
+}
+
+/// Direct URL and Git requirements are held to the allowed indexes.
+#[test]
+fn policy_allowed_indexes_direct_url() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, r#"allowed-indexes = ["https://pypi.org/simple"]"#)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok @ https://example.com/ok-1.0.0-py3-none-any.whl"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download `ok @ https://example.com/ok-1.0.0-py3-none-any.whl`
+      ╰─▶ The URL `https://example.com/ok-1.0.0-py3-none-any.whl` is not allowed by the organization policy
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok @ https://example.com/ok-1.0.0.tar.gz"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `ok @ https://example.com/ok-1.0.0.tar.gz`
+      ╰─▶ The URL `https://example.com/ok-1.0.0.tar.gz` is not allowed by the organization policy
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok @ git+https://github.com/example/ok"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `ok @ git+https://github.com/example/ok`
+      ╰─▶ The URL `https://github.com/example/ok` is not allowed by the organization policy
+    ");
+
+    Ok(())
+}
+
+/// Source distributions can't be built if the policy disallows it, even with `--build`.
+#[test]
+fn policy_no_build() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, "no-build = true")?;
+
+    let pyproject_toml = context.temp_dir.child("child").child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--build"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to prepare distributions
+      Caused by: Building source distributions is disabled, but attempted to build `child`
+    ");
+
+    Ok(())
+}
+
+/// Hashes are required if the policy requires them, even with `--no-require-hashes`.
+#[test]
+fn policy_require_hashes() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, "require-hashes = true")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("ok==1.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-require-hashes"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: ok==1.0.0
+    ");
+
+    requirements_txt.write_str(
+        "ok==1.0.0 --hash=sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+
+    Ok(())
+}
+
+/// Hashes are required for the ephemeral environments of `uv run --with` and `uv tool install`.
+#[test]
+fn policy_require_hashes_environments() -> Result<()> {
+    let context = TestContext::new("3.12").with_policy_file();
+
+    write_policy(&context, "require-hashes = true")?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .arg("python")
+        .arg("-c")
+        .arg("import ok"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: ok
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links/"))
+        .env(EnvVars::UV_TOOL_DIR, context.temp_dir.child("tools").as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, context.temp_dir.child("bin").as_os_str()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: ok
+    ");
+
+    Ok(())
+}
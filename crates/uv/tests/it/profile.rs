@@ -0,0 +1,178 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Enable `--frozen` and `--locked` via a profile.
+#[test]
+fn profile_flags() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.profiles.ci]
+        frozen = true
+        no-progress = true
+
+        [tool.uv.profiles.strict]
+        locked = true
+    "#})?;
+
+    // With the `ci` profile, the lockfile must exist.
+    uv_snapshot!(context.filters(), context.sync().arg("--profile").arg("ci"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`, but `frozen` (workspace configuration) was provided. To create a lockfile, run `uv lock` or `uv sync` without the flag.
+    ");
+
+    // Environment variables take precedence over the profile.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--profile")
+        .arg("ci")
+        .env(EnvVars::UV_FROZEN, "0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    // The profile can also be selected with `UV_PROFILE`.
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROFILE, "ci"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited in [TIME]
+    ");
+
+    // Flags from the profile conflict with flags on the command line.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--profile")
+        .arg("strict")
+        .arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument `locked` (workspace configuration) cannot be used with `--frozen`
+    ");
+
+    Ok(())
+}
+
+/// The settings in a profile take precedence over the other settings in the configuration.
+#[test]
+fn profile_settings() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        python-preference = "only-system"
+
+        [profiles.managed]
+        python-preference = "only-managed"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.python_find(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/bin/python3
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("--profile")
+        .arg("managed"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found in virtual environments or managed installations
+    ");
+
+    Ok(())
+}
+
+/// Invalid and undefined profiles are rejected.
+#[test]
+fn profile_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        [profiles.ci]
+        frozen = "yes"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--profile").arg("dev"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | [profiles.ci]
+      | ^^^^^^^^^^^^^
+    invalid type for `frozen`: string, expected a boolean
+    ");
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r"
+        [profiles.ci]
+        frozen = true
+    "})?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--profile").arg("dev"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Profile `dev` is not defined in any configuration file
+    ");
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--profile")
+        .arg("ci")
+        .arg("--no-config"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--profile <PROFILE>' cannot be used with '--no-config'
+
+    Usage: uv version --cache-dir [CACHE_DIR] --profile <PROFILE> --exclude-newer <EXCLUDE_NEWER> [VALUE]
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
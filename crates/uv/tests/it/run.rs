@@ -645,6 +645,92 @@ fn run_pep723_script_metadata() -> Result<()> {
     Ok(())
 }
 
+/// Recreate the cached environment for a PEP 723 script with `--refresh-script`.
+#[test]
+fn run_pep723_script_refresh() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "ok",
+        # ]
+        # ///
+
+        import ok
+       "#
+    })?;
+
+    let find_links = context.workspace_root.join("test/links");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .arg("main.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    // The cached environment is reused on subsequent runs.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .arg("main.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // With `--refresh-script`, the environment is rebuilt from scratch.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .arg("--refresh-script")
+        .arg("main.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    // `--refresh-script` can't be combined with `--active`.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--refresh-script")
+        .arg("--active")
+        .arg("main.py"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--refresh-script' cannot be used with '--active'
+
+    Usage: uv run --cache-dir [CACHE_DIR] --refresh-script --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 /// Run a PEP 723-compatible script with a `[[tool.uv.index]]`.
 #[test]
 fn run_pep723_script_index() -> Result<()> {
@@ -4665,6 +4751,82 @@ fn run_with_multiple_env_files() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_with_env_file_setting() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("test.py").write_str(indoc! { "
+        import os
+        print(os.environ.get('THE_EMPIRE_VARIABLE'))
+        print(os.environ.get('REBEL_1'))
+       "
+    })?;
+
+    context.temp_dir.child(".env1").write_str(indoc! { "
+        THE_EMPIRE_VARIABLE=palpatine
+        REBEL_1=leia_organa
+       "
+    })?;
+
+    context.temp_dir.child(".env2").write_str(indoc! { "
+        REBEL_1=obi_wan_kenobi
+       "
+    })?;
+
+    context.temp_dir.child("uv.toml").write_str(indoc! { r#"
+        env-file = [".env1", ".env2"]
+       "#
+    })?;
+
+    // The files in the setting are loaded in order.
+    uv_snapshot!(context.filters(), context.run().arg("test.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    palpatine
+    obi_wan_kenobi
+
+    ----- stderr -----
+    ");
+
+    // The `--env-file` argument replaces the setting.
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env1").arg("test.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    palpatine
+    leia_organa
+
+    ----- stderr -----
+    ");
+
+    // The `--no-env-file` argument disables the setting.
+    uv_snapshot!(context.filters(), context.run().arg("--no-env-file").arg("test.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+    None
+
+    ----- stderr -----
+    ");
+
+    // The setting is resolved relative to the configuration file.
+    let child = context.temp_dir.child("child");
+    child.create_dir_all()?;
+    uv_snapshot!(context.filters(), context.run().arg("--directory").arg("child").arg("../test.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    palpatine
+    obi_wan_kenobi
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_with_env_omitted() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -0,0 +1,201 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+use insta::assert_json_snapshot;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_fs::Simplified;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Report the known vulnerabilities of a project's dependencies, and record them in the SBOM.
+#[tokio::test]
+async fn sbom_audit() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/querybatch"))
+        .and(body_partial_json(json!({
+            "queries": [
+                { "package": { "name": "ok", "ecosystem": "PyPI" }, "version": "1.0.0" },
+                { "package": { "name": "validation", "ecosystem": "PyPI" }, "version": "1.0.0" },
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                { "vulns": [{ "id": "PYSEC-2024-1", "modified": "2024-06-18T00:00:00Z" }] },
+                {},
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/vulns/PYSEC-2024-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "PYSEC-2024-1",
+            "aliases": ["CVE-2024-12345", "GHSA-xxxx-xxxx-xxxx"],
+            "summary": "`ok` is not ok",
+            "details": "Importing `ok` may be less than ok.",
+            "published": "2024-06-17T19:58:43Z",
+            "modified": "2024-06-18T00:00:00.123456Z",
+            "severity": [
+                { "type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N" },
+            ],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0", "validation==1.0.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+            context.workspace_root.join("test/links/").portable_display(),
+        })?;
+
+    uv_snapshot!(context.filters(), context.sbom_audit()
+        .arg("--osv-url")
+        .arg(server.uri())
+        .arg("--output-file")
+        .arg("sbom.cdx.json"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ok 1.0.0: PYSEC-2024-1 (CVE-2024-12345, GHSA-xxxx-xxxx-xxxx)
+        `ok` is not ok
+
+    ----- stderr -----
+    warning: `uv sbom audit` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    Resolved 3 packages in [TIME]
+    Found 1 known vulnerability in 1 package
+    ");
+
+    // The vulnerabilities are recorded in the SBOM, referencing the affected components.
+    let sbom: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.join("sbom.cdx.json"),
+    )?)?;
+    assert_json_snapshot!(sbom["vulnerabilities"], @r#"
+    [
+      {
+        "affects": [
+          {
+            "ref": "ok-2@1.0.0"
+          }
+        ],
+        "bom-ref": "PYSEC-2024-1",
+        "description": "`ok` is not ok",
+        "detail": "Importing `ok` may be less than ok.",
+        "id": "PYSEC-2024-1",
+        "published": "2024-06-17T19:58:43Z",
+        "ratings": [
+          {
+            "method": "CVSSv31",
+            "vector": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N"
+          }
+        ],
+        "references": [
+          {
+            "id": "CVE-2024-12345",
+            "source": {
+              "name": "OSV",
+              "url": "https://osv.dev/vulnerability/PYSEC-2024-1"
+            }
+          },
+          {
+            "id": "GHSA-xxxx-xxxx-xxxx",
+            "source": {
+              "name": "OSV",
+              "url": "https://osv.dev/vulnerability/PYSEC-2024-1"
+            }
+          }
+        ],
+        "source": {
+          "name": "OSV",
+          "url": "https://osv.dev/vulnerability/PYSEC-2024-1"
+        },
+        "updated": "2024-06-18T00:00:00Z"
+      }
+    ]
+    "#);
+
+    Ok(())
+}
+
+/// Succeed if none of the dependencies have known vulnerabilities.
+#[tokio::test]
+async fn sbom_audit_no_vulnerabilities() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/osv/v1/querybatch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "results": [{}] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+            context.workspace_root.join("test/links/").portable_display(),
+        })?;
+
+    uv_snapshot!(context.filters(), context.sbom_audit()
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .env("UV_OSV_URL", format!("{}/osv/", server.uri())), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    No known vulnerabilities found
+    ");
+
+    Ok(())
+}
+
+/// Only `CycloneDX` SBOMs can be audited.
+#[test]
+fn sbom_audit_format() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.sbom_audit()
+        .arg("--format")
+        .arg("requirements.txt")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv sbom audit` only supports the `cyclonedx1.5` format
+    ");
+}
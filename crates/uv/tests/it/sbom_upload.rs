@@ -0,0 +1,139 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Upload the SBOM of a project to Dependency-Track, creating the project.
+#[tokio::test]
+async fn sbom_upload() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/bom"))
+        .and(header("X-Api-Key", "secret"))
+        .and(body_partial_json(json!({
+            "projectName": "project",
+            "projectVersion": "0.1.0",
+            "autoCreate": true,
+            "projectTags": [{ "name": "ci" }],
+            "isLatest": true,
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "token": "66c60b3c-5d3f-4b7c-9f5c-1f2a8f2b9f3e" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sbom_upload()
+        .arg("--dependency-track")
+        .arg(server.uri())
+        .arg("--tag")
+        .arg("ci")
+        .arg("--latest")
+        .arg("--output-file")
+        .arg("sbom.cdx.json")
+        .env("UV_DEPENDENCY_TRACK_API_KEY", "secret"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sbom upload` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    Resolved 1 package in [TIME]
+    Uploaded SBOM for project 0.1.0 to http://[LOCALHOST]/
+    ");
+
+    // The SBOM is also written to the output file.
+    context
+        .temp_dir
+        .child("sbom.cdx.json")
+        .assert(predicates::str::contains(r#""bomFormat": "CycloneDX""#));
+
+    Ok(())
+}
+
+/// Report the response of Dependency-Track when the upload is rejected.
+#[tokio::test]
+async fn sbom_upload_unauthorized() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/dtrack/api/v1/bom"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("Access is forbidden"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sbom_upload()
+        .arg("--dependency-track")
+        .arg(format!("{}/dtrack", server.uri()))
+        .arg("--api-key")
+        .arg("secret")
+        .arg("--project-name")
+        .arg("my-service")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to upload SBOM to `http://[LOCALHOST]/dtrack` (status code 403 Forbidden): Access is forbidden
+    ");
+
+    Ok(())
+}
+
+/// Only `CycloneDX` SBOMs can be uploaded.
+#[test]
+fn sbom_upload_format() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.sbom_upload()
+        .arg("--dependency-track")
+        .arg("https://dtrack.example.com")
+        .arg("--api-key")
+        .arg("secret")
+        .arg("--format")
+        .arg("requirements.txt")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv sbom upload` only supports the `cyclonedx1.5` format
+    ");
+}
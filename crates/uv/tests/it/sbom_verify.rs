@@ -0,0 +1,230 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+
+use uv_fs::Simplified;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Detect drift between the lockfile and an SBOM exported from an earlier version of it.
+#[test]
+fn sbom_verify() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0", "validation==1.0.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+        context.workspace_root.join("test/links/").portable_display(),
+    })?;
+
+    context
+        .export()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--hashes")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("-o")
+        .arg("sbom.cdx.json")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.sbom_verify()
+        .arg("sbom.cdx.json")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    The lockfile matches `sbom.cdx.json`
+    ");
+
+    // Replace `validation` with `tqdm`.
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0", "tqdm"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+        context.workspace_root.join("test/links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.sbom_verify()
+        .arg("sbom.cdx.json"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    tqdm 1000.0.0 is in the lockfile, but not declared in the SBOM
+    validation 1.0.0 is declared in the SBOM, but missing from the lockfile
+
+    ----- stderr -----
+    warning: `uv sbom verify` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    Resolved 3 packages in [TIME]
+    Found 2 discrepancies between the lockfile and `sbom.cdx.json`
+    ");
+
+    Ok(())
+}
+
+/// Detect artifacts in the lockfile whose hashes aren't declared in the SBOM.
+#[test]
+fn sbom_verify_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+            context.workspace_root.join("test/links/").portable_display(),
+        })?;
+
+    context
+        .export()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--hashes")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("-o")
+        .arg("sbom.cdx.json")
+        .assert()
+        .success();
+
+    // Tamper with the hash of `ok`.
+    let sbom = fs_err::read_to_string(context.temp_dir.join("sbom.cdx.json"))?;
+    fs_err::write(
+        context.temp_dir.join("sbom.cdx.json"),
+        sbom.replace(
+            "79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+    )?;
+
+    uv_snapshot!(context.filters(), context.sbom_verify()
+        .arg("sbom.cdx.json")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ok 1.0.0 has hashes in the lockfile that aren't declared in the SBOM: sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Found 1 discrepancy between the lockfile and `sbom.cdx.json`
+    ");
+
+    Ok(())
+}
+
+/// Compare an SBOM against the packages installed in the project environment.
+#[test]
+fn sbom_verify_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0", "validation==1.0.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+            context.workspace_root.join("test/links/").portable_display(),
+        })?;
+
+    context
+        .export()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--hashes")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("-o")
+        .arg("sbom.cdx.json")
+        .assert()
+        .success();
+
+    // The environment hasn't been synced yet.
+    uv_snapshot!(context.filters(), context.sbom_verify()
+        .arg("sbom.cdx.json")
+        .arg("--environment")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ok 1.0.0 is declared in the SBOM, but missing from the environment
+    validation 1.0.0 is declared in the SBOM, but missing from the environment
+
+    ----- stderr -----
+    Found 2 discrepancies between the environment and `sbom.cdx.json`
+    ");
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.sbom_verify()
+        .arg("sbom.cdx.json")
+        .arg("--environment")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    The environment matches `sbom.cdx.json`
+    ");
+
+    context.pip_uninstall().arg("validation").assert().success();
+
+    uv_snapshot!(context.filters(), context.sbom_verify()
+        .arg("sbom.cdx.json")
+        .arg("--environment")
+        .arg("--preview-features")
+        .arg("sbom-export"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    validation 1.0.0 is declared in the SBOM, but missing from the environment
+
+    ----- stderr -----
+    Found 1 discrepancy between the environment and `sbom.cdx.json`
+    ");
+
+    Ok(())
+}
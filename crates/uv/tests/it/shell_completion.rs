@@ -0,0 +1,174 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Complete the dependency groups of the project.
+#[test]
+fn complete_groups() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [dependency-groups]
+        docs = ["sphinx"]
+        lint = ["ruff"]
+
+        [tool.uv]
+        dev-dependencies = ["pytest"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.complete(&["sync", "--group", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    dev
+    docs
+    lint
+
+    ----- stderr -----
+    ");
+
+    // Candidates are filtered by the current word.
+    uv_snapshot!(context.filters(), context.complete(&["run", "--only-group", "l"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lint
+
+    ----- stderr -----
+    ");
+
+    // Completion never fails, even outside of a project.
+    let context = TestContext::new("3.12");
+    uv_snapshot!(context.filters(), context.complete(&["sync", "--group", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Complete the packages in the lockfile and the indexes of the project.
+#[test]
+fn complete_packages_and_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["idna", "iniconfig"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://example.com/simple"
+        publish-url = "https://example.com/upload"
+        "#,
+    )?;
+
+    context.temp_dir.child("uv.lock").write_str(
+        r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "idna" },
+            { name = "iniconfig" },
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.complete(&["remove", "i"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna
+    iniconfig
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.complete(&["lock", "--upgrade-package", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna
+    iniconfig
+    project
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.complete(&["publish", "--index", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    internal
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Complete the names of the installed tools.
+#[test]
+fn complete_tools() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+
+    tool_dir
+        .child("black")
+        .child("uv-receipt.toml")
+        .write_str("[tool]\nrequirements = [{ name = \"black\" }]\n")?;
+    tool_dir
+        .child("blue")
+        .child("uv-receipt.toml")
+        .write_str("[tool]\nrequirements = [{ name = \"blue\" }]\n")?;
+    // Directories without a receipt are not tools.
+    tool_dir.child("partial").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.complete(&["tool", "uninstall", "b"])
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black
+    blue
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
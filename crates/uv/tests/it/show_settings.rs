@@ -66,8 +66,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -82,18 +85,29 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -144,14 +158,26 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -271,8 +297,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -287,18 +316,29 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -349,14 +389,26 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -477,8 +529,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -493,18 +548,29 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -555,14 +621,26 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -715,8 +793,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -731,18 +812,29 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -793,14 +885,26 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -922,8 +1026,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -938,18 +1045,29 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -974,6 +1092,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1105,8 +1224,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1121,18 +1243,29 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -1183,14 +1316,26 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1337,8 +1482,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1353,18 +1501,29 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -1415,10 +1574,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -1448,14 +1618,26 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1577,8 +1759,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1593,18 +1778,29 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -1657,10 +1853,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -1690,10 +1897,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -1723,14 +1941,26 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1875,8 +2105,11 @@ fn resolve_find_links() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1891,18 +2124,29 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -1954,13 +2198,25 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Flat,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 no_index: true,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2104,8 +2360,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2120,18 +2379,29 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -2156,6 +2426,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2292,8 +2563,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2308,18 +2582,29 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -2370,10 +2655,21 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -2403,14 +2699,26 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2530,8 +2838,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2546,18 +2857,29 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -2608,10 +2930,21 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -2641,14 +2974,26 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2791,8 +3136,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2807,18 +3155,29 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -2843,6 +3202,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2969,8 +3329,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2985,18 +3348,29 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -3021,6 +3395,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3147,8 +3522,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3163,18 +3541,29 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -3199,6 +3588,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3327,8 +3717,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3343,18 +3736,29 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -3379,6 +3783,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3526,8 +3931,11 @@ fn resolve_tool() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3542,12 +3950,22 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3578,6 +3996,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             no_index: None,
             find_links: None,
             index_strategy: None,
+            index_tracking_policy: None,
             keyring_provider: None,
             resolution: Some(
                 LowestDirect,
@@ -3608,6 +4027,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         },
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -3632,6 +4052,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -3720,8 +4141,11 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3736,18 +4160,29 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -3772,6 +4207,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3932,8 +4368,11 @@ fn resolve_both() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3948,18 +4387,29 @@ fn resolve_both() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -4010,14 +4460,26 @@ fn resolve_both() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4183,8 +4645,11 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4199,18 +4664,29 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -4261,14 +4737,26 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4513,8 +5001,11 @@ fn resolve_config_file() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4529,18 +5020,29 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -4591,14 +5093,26 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4725,7 +5239,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `cache-max-age`, `cache-max-size`, `cache-namespace`, `cache-namespace-mode`, `remote-cache`, `git-fetch-strategy`, `git-reference-repositories`, `git-shared-cache-dir`, `build-fingerprint`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `concurrent-downloads-per-host`, `bandwidth-limit`, `retry`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `index-tracking-policy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `locked-settings`, `profiles`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `hooks`, `sbom`, `build-backend`
     "
     );
 
@@ -4818,8 +5332,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4834,18 +5351,29 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -4870,6 +5398,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4999,8 +5528,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5015,18 +5547,29 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -5051,6 +5594,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5199,8 +5743,11 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5215,18 +5762,29 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -5251,6 +5809,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5391,8 +5950,11 @@ fn index_priority() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5407,18 +5969,29 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -5471,10 +6044,21 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -5504,14 +6088,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5631,8 +6227,11 @@ fn index_priority() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5647,18 +6246,29 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -5711,10 +6321,21 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -5744,14 +6365,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5877,8 +6510,11 @@ fn index_priority() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5893,18 +6529,29 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -5957,10 +6604,21 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -5990,14 +6648,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6118,8 +6788,11 @@ fn index_priority() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6134,18 +6807,29 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -6198,10 +6882,21 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -6231,14 +6926,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6366,8 +7073,11 @@ fn index_priority() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6382,18 +7092,29 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -6446,10 +7167,21 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -6479,14 +7211,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6607,8 +7351,11 @@ fn index_priority() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6623,18 +7370,29 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -6687,10 +7445,21 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -6720,14 +7489,26 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         format: Simple,
+                        api: Auto,
+                        verify: Off,
                         publish_url: None,
                         authenticate: Auto,
+                        credential_provider: None,
+                        client_cert: None,
+                        ca_cert: None,
+                        proxy: None,
+                        retry: None,
                         ignore_error_codes: None,
                         cache_control: None,
+                        priority: None,
+                        role: Primary,
+                        retry_budget: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6861,8 +7642,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6877,24 +7661,36 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipInstallSettings {
         package: [],
         requirements: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         editables: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        scripts: true,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -6913,6 +7709,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7032,8 +7829,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7048,24 +7848,36 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipInstallSettings {
         package: [],
         requirements: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         editables: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        scripts: true,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7084,6 +7896,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7201,8 +8014,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7217,24 +8033,36 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipInstallSettings {
         package: [],
         requirements: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         editables: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        scripts: true,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7253,6 +8081,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7372,8 +8201,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7388,24 +8220,36 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipInstallSettings {
         package: [],
         requirements: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         editables: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        scripts: true,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7424,6 +8268,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7541,8 +8386,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7557,24 +8405,36 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipInstallSettings {
         package: [],
         requirements: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         editables: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        scripts: true,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7593,6 +8453,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7711,8 +8572,11 @@ fn verify_hashes() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7727,24 +8591,36 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipInstallSettings {
         package: [],
         requirements: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         editables: [],
         constraints: [],
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        scripts: true,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7763,6 +8639,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7896,8 +8773,11 @@ fn preview_features() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7931,29 +8811,58 @@ fn preview_features() {
                 GcsEndpoint,
                 AdjustUlimit,
                 SpecialCondaEnvNames,
+                RemoteCache,
+                CacheInfo,
+                PipSize,
+                CacheVerify,
+                CacheWarm,
+                CacheMetrics,
+                AuditLog,
+                GenerateDevcontainer,
+                EnvInfo,
+                Hooks,
+                KernelInstall,
+                ConfigCommand,
+                RedactLogs,
+                InteractiveResolution,
+                UsageLog,
+                Localization,
+                ErrorCodes,
             ],
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     VersionSettings {
         value: None,
         bump: [],
         short: false,
-        output_format: Text,
         dry_run: false,
         lock_check: Disabled,
         frozen: None,
         active: None,
         no_sync: false,
+        sync_lock: false,
+        changelog: false,
+        tag: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -7971,6 +8880,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -7995,6 +8905,7 @@ fn preview_features() {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8038,8 +8949,11 @@ fn preview_features() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8054,23 +8968,35 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     VersionSettings {
         value: None,
         bump: [],
         short: false,
-        output_format: Text,
         dry_run: false,
         lock_check: Disabled,
         frozen: None,
         active: None,
         no_sync: false,
+        sync_lock: false,
+        changelog: false,
+        tag: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -8088,6 +9014,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8112,6 +9039,7 @@ fn preview_features() {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8155,8 +9083,11 @@ fn preview_features() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8190,29 +9121,58 @@ fn preview_features() {
                 GcsEndpoint,
                 AdjustUlimit,
                 SpecialCondaEnvNames,
+                RemoteCache,
+                CacheInfo,
+                PipSize,
+                CacheVerify,
+                CacheWarm,
+                CacheMetrics,
+                AuditLog,
+                GenerateDevcontainer,
+                EnvInfo,
+                Hooks,
+                KernelInstall,
+                ConfigCommand,
+                RedactLogs,
+                InteractiveResolution,
+                UsageLog,
+                Localization,
+                ErrorCodes,
             ],
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     VersionSettings {
         value: None,
         bump: [],
         short: false,
-        output_format: Text,
         dry_run: false,
         lock_check: Disabled,
         frozen: None,
         active: None,
         no_sync: false,
+        sync_lock: false,
+        changelog: false,
+        tag: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -8230,6 +9190,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8254,6 +9215,7 @@ fn preview_features() {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8297,8 +9259,11 @@ fn preview_features() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8316,23 +9281,35 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     VersionSettings {
         value: None,
         bump: [],
         short: false,
-        output_format: Text,
         dry_run: false,
         lock_check: Disabled,
         frozen: None,
         active: None,
         no_sync: false,
+        sync_lock: false,
+        changelog: false,
+        tag: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -8350,6 +9327,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8374,6 +9352,7 @@ fn preview_features() {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8417,8 +9396,11 @@ fn preview_features() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8436,23 +9418,35 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     VersionSettings {
         value: None,
         bump: [],
         short: false,
-        output_format: Text,
         dry_run: false,
         lock_check: Disabled,
         frozen: None,
         active: None,
         no_sync: false,
+        sync_lock: false,
+        changelog: false,
+        tag: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -8470,6 +9464,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8494,6 +9489,7 @@ fn preview_features() {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8539,8 +9535,11 @@ fn preview_features() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8555,23 +9554,35 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     VersionSettings {
         value: None,
         bump: [],
         short: false,
-        output_format: Text,
         dry_run: false,
         lock_check: Disabled,
         frozen: None,
         active: None,
         no_sync: false,
+        sync_lock: false,
+        changelog: false,
+        tag: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -8589,6 +9600,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                build_constraints: [],
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8613,6 +9625,7 @@ fn preview_features() {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    tracking_policy: Ignore,
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8677,8 +9690,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8693,18 +9709,29 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -8729,6 +9756,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -8856,8 +9884,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8872,18 +9903,29 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -8908,6 +9950,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9058,8 +10101,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9074,18 +10120,29 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -9110,6 +10167,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9235,8 +10293,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9251,18 +10312,29 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -9287,6 +10359,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9406,8 +10479,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9422,18 +10498,29 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -9458,6 +10545,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9578,8 +10666,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9594,18 +10685,29 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -9630,6 +10732,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9815,8 +10918,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9831,17 +10937,28 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        interactive: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -9858,6 +10975,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            build_constraints: [],
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -9882,6 +11000,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -9937,8 +11056,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9953,17 +11075,28 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        interactive: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -9980,6 +11113,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            build_constraints: [],
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10004,6 +11138,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10082,8 +11217,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10098,17 +11236,28 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        interactive: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10125,6 +11274,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            build_constraints: [],
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10149,6 +11299,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10202,8 +11353,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10218,17 +11372,28 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        interactive: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10245,6 +11410,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            build_constraints: [],
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10269,6 +11435,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10312,8 +11479,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10328,17 +11498,28 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        interactive: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10355,6 +11536,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            build_constraints: [],
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10379,6 +11561,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10423,8 +11606,11 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10439,17 +11625,28 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        interactive: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10466,6 +11663,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            build_constraints: [],
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10490,6 +11688,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10598,8 +11797,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10614,18 +11816,29 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -10650,6 +11863,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -10772,8 +11986,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            concurrent_downloads_per_host: None,
+            bandwidth_limit: None,
             timeout: [TIME],
             retries: 3,
+            retry: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10788,18 +12005,29 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        output_format: Text,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_age: None,
+        max_size: None,
+        namespace: None,
+        namespace_mode: Isolated,
+        remote_cache: None,
+        git_fetch_strategy: Full,
+        git_reference_repositories: [],
+        git_shared_cache_dir: None,
+        build_fingerprint: None,
     }
     PipCompileSettings {
         format: None,
         src_file: [
             "requirements.in",
         ],
+        requirements_hashes: [],
         constraints: [],
         overrides: [],
         excludes: [],
@@ -10824,6 +12052,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                tracking_policy: Ignore,
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
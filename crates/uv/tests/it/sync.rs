@@ -3,7 +3,7 @@ use assert_cmd::prelude::*;
 use assert_fs::{fixture::ChildPath, prelude::*};
 use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
-use predicates::prelude::predicate;
+use predicates::prelude::{PredicateBooleanExt, predicate};
 use tempfile::tempdir_in;
 
 use uv_fs::Simplified;
@@ -10441,9 +10441,9 @@ fn sync_build_tag() -> Result<()> {
         version = "1.0.0"
         source = { registry = "links" }
         wheels = [
-            { path = "build_tag-1.0.0-1-py2.py3-none-any.whl" },
-            { path = "build_tag-1.0.0-3-py2.py3-none-any.whl" },
-            { path = "build_tag-1.0.0-5-py2.py3-none-any.whl" },
+            { path = "build_tag-1.0.0-1-py2.py3-none-any.whl", hash = "sha256:f6e84b0fc75b95ded323a9aa4642ec9c9d01f223b77a9cb558950afddcfad401", size = 932 },
+            { path = "build_tag-1.0.0-3-py2.py3-none-any.whl", hash = "sha256:db548feab5696474e57471e595852d47c9b0806d948f836ca447d8b8618f3377", size = 932 },
+            { path = "build_tag-1.0.0-5-py2.py3-none-any.whl", hash = "sha256:68db9d07e47bda8b8940ff5b0eafc69bb76c7a64b37e7c9cee07f8b20f67c901", size = 932 },
         ]
 
         [[package]]
@@ -12178,6 +12178,70 @@ fn sync_build_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Build constraints from a `uv.toml` (e.g., a user-level configuration file) apply to builds of
+/// third-party source distributions, without being recorded in the lockfile.
+///
+/// `json-merge-patch==0.2` fails to build with `setuptools==78.0.1`, which is the most recent version
+/// as of the `exclude-newer` date.
+#[test]
+fn sync_build_constraints_uv_toml() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-03-24T19:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["json-merge-patch"]
+        "#,
+    )?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"build-constraint-dependencies = ["setuptools<78"]"#)?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--config-file").arg(uv_toml.as_os_str())
+        .arg("--no-binary-package").arg("json-merge-patch"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + json-merge-patch==0.2
+    ");
+
+    // The build constraints are not part of the lockfile.
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("build-constraints"));
+
+    fs_err::remove_dir_all(&context.cache_dir)?;
+    fs_err::remove_dir_all(&context.venv)?;
+
+    // Installing from the lockfile respects the build constraints too.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--config-file").arg(uv_toml.as_os_str())
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + json-merge-patch==0.2
+    ");
+
+    Ok(())
+}
+
 // Test that we recreate a virtual environment when `pyvenv.cfg` version
 // is incompatible with the interpreter version.
 #[test]
@@ -15567,3 +15631,291 @@ fn sync_reinstalls_on_version_change() -> Result<()> {
 
     Ok(())
 }
+
+/// Sync into a relocatable project environment, recreating an existing environment that isn't
+/// relocatable.
+#[test]
+#[cfg(unix)]
+fn sync_relocatable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["simple-launcher==0.1.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+        context.workspace_root.join("test/links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0
+    ");
+
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    pyvenv_cfg.assert(predicate::str::contains("relocatable").not());
+
+    // The existing environment isn't relocatable, so it should be recreated.
+    uv_snapshot!(context.filters(), context.sync().arg("--relocatable"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0
+    ");
+
+    pyvenv_cfg.assert(predicate::str::contains("relocatable = true"));
+    context
+        .venv
+        .child("bin")
+        .child("simple_launcher")
+        .assert(predicate::str::contains(
+            r#"'''exec' "$(dirname -- "$(realpath -- "$0")")"/'python' "$0" "$@""#,
+        ));
+
+    // The environment is now relocatable, so it should be reused.
+    uv_snapshot!(context.filters(), context.sync().arg("--relocatable"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    // Without `--relocatable`, the existing environment is compatible.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Sync into a root filesystem for a container image, rather than the project environment.
+#[test]
+#[cfg(unix)]
+fn sync_target_rootfs() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["simple-launcher==0.1.0"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+        "#,
+        context.workspace_root.join("test/links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--target-rootfs").arg("rootfs"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0
+    ");
+
+    // The project environment is left untouched.
+    context
+        .venv
+        .child("bin")
+        .child("simple_launcher")
+        .assert(predicate::path::missing());
+
+    // The script is installed under the interpreter's prefix within the root filesystem, and
+    // refers to the interpreter at its path outside of the root filesystem.
+    let rootfs = context.temp_dir.child("rootfs");
+    let script = walkdir::WalkDir::new(&rootfs)
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            entry.file_type().is_file()
+                && entry.file_name() == "simple_launcher"
+                && entry
+                    .path()
+                    .parent()
+                    .is_some_and(|parent| parent.ends_with("bin"))
+        })
+        .expect("The script should be installed");
+    let contents = fs_err::read_to_string(script.path())?;
+    let interpreter = contents
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("#!"))
+        .expect("The script should have a shebang");
+    assert!(!interpreter.starts_with(&*rootfs.to_string_lossy()));
+    assert!(std::path::Path::new(interpreter).is_file());
+
+    // With `--relocatable`, the script refers to the interpreter relative to its own location,
+    // once the root filesystem is mounted.
+    uv_snapshot!(context.filters(), context.sync().arg("--target-rootfs").arg("relocatable").arg("--relocatable"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0
+    ");
+
+    let relocatable = context.temp_dir.child("relocatable");
+    let script = walkdir::WalkDir::new(&relocatable)
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_type().is_file() && entry.file_name() == "simple_launcher")
+        .expect("The script should be installed");
+    let contents = fs_err::read_to_string(script.path())?;
+    let relative = contents
+        .lines()
+        .nth(1)
+        .and_then(|line| line.strip_prefix(r#"'''exec' "$(dirname -- "$(realpath -- "$0")")"/'"#))
+        .and_then(|line| line.split_once('\''))
+        .map(|(relative, _)| relative)
+        .expect("The script should refer to a relative interpreter");
+    let mounted = std::path::Path::new("/").join(
+        script
+            .path()
+            .parent()
+            .unwrap()
+            .strip_prefix(relocatable.path())?,
+    );
+    assert!(mounted.join(relative).is_file());
+
+    // Re-syncing leaves the root filesystem as-is.
+    uv_snapshot!(context.filters(), context.sync().arg("--target-rootfs").arg("rootfs"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    // A root filesystem can't be combined with the active environment.
+    uv_snapshot!(context.filters(), context.sync().arg("--target-rootfs").arg("rootfs").arg("--active"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--target-rootfs <TARGET_ROOTFS>' cannot be used with '--active'
+
+    Usage: uv sync --cache-dir [CACHE_DIR] --target-rootfs <TARGET_ROOTFS> --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Sync again whenever the project changes with `--watch`.
+#[test]
+fn sync_watch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject = |dependencies: &str| {
+        formatdoc! { r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+
+            [tool.uv]
+            find-links = ["{}"]
+            no-index = true
+            "#,
+            context.workspace_root.join("test/links/").portable_display(),
+        }
+    };
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&pyproject(""))?;
+
+    let stderr = context.temp_dir.child("stderr.txt");
+    let mut child = context
+        .sync()
+        .arg("--watch")
+        .stderr(fs_err::File::create(stderr.path())?.into_file())
+        .spawn()?;
+
+    // Wait until the given number of syncs have completed.
+    let wait_for_syncs = |count: usize| {
+        let start = std::time::Instant::now();
+        while start.elapsed() < std::time::Duration::from_secs(60) {
+            if fs_err::read_to_string(&stderr)
+                .is_ok_and(|stderr| stderr.matches("Watching for changes...").count() >= count)
+            {
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        false
+    };
+
+    // Adding a dependency syncs it into the environment.
+    let initial = wait_for_syncs(1);
+    if initial {
+        pyproject_toml.write_str(&pyproject(r#""ok==1.0.0""#))?;
+    }
+    let changed = initial && wait_for_syncs(2);
+    child.kill()?;
+    child.wait()?;
+    let stderr = fs_err::read_to_string(&stderr)?;
+    assert!(changed, "{stderr}");
+
+    assert_snapshot!(crate::common::apply_filters(stderr, context.filters()), @"
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Watching for changes...
+    Detected a change to `pyproject.toml`
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    Watching for changes...
+    ");
+
+    context.assert_command("import ok").success();
+
+    Ok(())
+}
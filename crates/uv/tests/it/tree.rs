@@ -1799,3 +1799,107 @@ fn workspace_circular_dependencies() -> Result<()> {
 
     Ok(())
 }
+
+/// Display the tree as JSON with `--output-format json`.
+#[test]
+fn output_format_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#,
+    )?;
+
+    let package_a = context.temp_dir.child("packages").child("package-a");
+    package_a.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "package-a"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["package-b"]
+
+        [dependency-groups]
+        dev = ["package-c"]
+
+        [tool.uv.sources]
+        package-b = { workspace = true }
+        package-c = { workspace = true }
+    "#,
+    )?;
+
+    let package_b = context.temp_dir.child("packages").child("package-b");
+    package_b.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "package-b"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["package-a"]
+
+        [tool.uv.sources]
+        package-a = { workspace = true }
+    "#,
+    )?;
+
+    let package_c = context.temp_dir.child("packages").child("package-c");
+    package_c.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "package-c"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree()
+        .arg("--package")
+        .arg("package-a")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "packages": [
+        {
+          "name": "package-a",
+          "version": "0.1.0",
+          "dependencies": [
+            {
+              "name": "package-b",
+              "version": "0.1.0",
+              "dependencies": [
+                {
+                  "name": "package-a",
+                  "version": "0.1.0",
+                  "deduplicated": true,
+                  "dependencies": []
+                }
+              ]
+            },
+            {
+              "name": "package-c",
+              "version": "0.1.0",
+              "group": "dev",
+              "dependencies": []
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "#);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `--usage-log` should append a record of each invocation, without its arguments.
+#[test]
+fn usage_log() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let log = context.temp_dir.child("usage.jsonl");
+
+    context
+        .command()
+        .arg("cache")
+        .arg("dir")
+        .arg("--usage-log")
+        .arg(log.path())
+        .arg("--preview-features")
+        .arg("usage-log")
+        .assert()
+        .success();
+
+    // Without a project, `uv lock` fails.
+    context
+        .lock()
+        .arg("--usage-log")
+        .arg(log.path())
+        .arg("--preview-features")
+        .arg("usage-log")
+        .assert()
+        .failure();
+
+    let records = fs_err::read_to_string(&log)?
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<serde_json::Value>, _>>()?;
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0]["command"], "cache dir");
+    assert_eq!(records[0]["exit_code"], 0);
+    assert!(records[0]["duration_ms"].is_u64());
+    assert_eq!(records[0]["cache"]["misses"], 0);
+    assert!(records[0]["version"].is_string());
+    assert!(records[0]["timestamp"].is_string());
+
+    assert_eq!(records[1]["command"], "lock");
+    assert_eq!(records[1]["exit_code"], 2);
+
+    Ok(())
+}
+
+/// Without the preview feature, `--usage-log` should warn.
+#[test]
+fn usage_log_preview() {
+    let context = TestContext::new_with_versions(&[]);
+    let log = context.temp_dir.child("usage.jsonl");
+
+    uv_snapshot!(context.filters(), context.command().arg("cache").arg("dir").arg("--usage-log").arg(log.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/
+
+    ----- stderr -----
+    warning: The usage log is experimental and may change without warning. Pass `--preview-features usage-log` to disable this warning.
+    ");
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "git")]
+use std::process::Command;
+
 use anyhow::{Ok, Result};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
@@ -3259,3 +3262,232 @@ conflicts = [[{"extra" = "foo"}, {"extra" = "bar"}]]
 
     Ok(())
 }
+
+/// Update the project version in the lockfile without re-locking.
+#[test]
+fn version_sync_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "myproject"
+        version = "1.10.31"
+        requires-python = ">=3.12"
+        "#,
+    )?;
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--bump").arg("minor")
+        .arg("--sync-lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    myproject 1.10.31 => 1.11.0
+
+    ----- stderr -----
+    ");
+
+    let lock = context.read("uv.lock");
+    assert_snapshot!(lock, @r#"
+    version = 1
+    revision = 3
+    requires-python = ">=3.12"
+
+    [options]
+    exclude-newer = "2024-03-25T00:00:00Z"
+
+    [[package]]
+    name = "myproject"
+    version = "1.11.0"
+    source = { virtual = "." }
+    "#);
+
+    // The lockfile is up-to-date with the new version.
+    context.lock().arg("--locked").assert().success();
+
+    // `--sync-lock` requires a new version.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--sync-lock"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      <VALUE|--bump <BUMP[=VALUE]>>
+
+    Usage: uv version --cache-dir [CACHE_DIR] --sync-lock --exclude-newer <EXCLUDE_NEWER> <VALUE|--bump <BUMP[=VALUE]>>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Collect changelog fragments into `CHANGELOG.md` and tag the release.
+#[test]
+#[cfg(feature = "git")]
+fn version_changelog_tag() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    Command::new("git")
+        .arg("init")
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+    Command::new("git")
+        .args(["config", "--local", "user.name", "Alice"])
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+    Command::new("git")
+        .args(["config", "--local", "user.email", "alice@example.com"])
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "myproject"
+        version = "1.10.31"
+        requires-python = ">=3.12"
+        "#,
+    )?;
+    context.temp_dir.child("CHANGELOG.md").write_str(indoc! {"
+        # Changelog
+
+        ## 1.10.31
+
+        - Initial release
+    "})?;
+    let fragments = context.temp_dir.child("changelog.d");
+    fragments.child("12.feature.md").write_str("Add `--fast`")?;
+    fragments
+        .child("15.bugfix.md")
+        .write_str("Fix the output\nof `--slow`")?;
+    fragments
+        .child("13.feature.md")
+        .write_str("Add `--faster`")?;
+    fragments.child(".gitkeep").touch()?;
+    context.lock().assert().success();
+    Command::new("git")
+        .args(["add", "--all"])
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+    Command::new("git")
+        .args(["commit", "--message", "Initial commit"])
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // An unrelated change is not part of the release commit.
+    context.temp_dir.child("notes.txt").write_str("Unrelated")?;
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--bump").arg("minor")
+        .arg("--sync-lock")
+        .arg("--changelog")
+        .arg("--tag"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    myproject 1.10.31 => 1.11.0
+
+    ----- stderr -----
+    Added 3 entries to `CHANGELOG.md`
+    Created tag `v1.11.0`
+    ");
+
+    let changelog = context.read("CHANGELOG.md");
+    assert_snapshot!(changelog, @"
+    # Changelog
+
+    ## 1.11.0
+
+    ### Features
+
+    - Add `--fast`
+    - Add `--faster`
+
+    ### Bug fixes
+
+    - Fix the output
+      of `--slow`
+
+    ## 1.10.31
+
+    - Initial release
+    ");
+    assert!(!fragments.child("12.feature.md").exists());
+    assert!(fragments.child(".gitkeep").exists());
+
+    let output = Command::new("git")
+        .args(["show", "--stat", "--format=%s", "v1.11.0"])
+        .current_dir(&context.temp_dir)
+        .output()?;
+    assert_snapshot!(String::from_utf8_lossy(&output.stdout), @"
+    tag v1.11.0
+    Tagger: Alice <alice@example.com>
+
+    Release 1.11.0
+    Release 1.11.0
+
+     CHANGELOG.md              | 12 ++++++++++++
+     changelog.d/12.feature.md |  1 -
+     changelog.d/13.feature.md |  1 -
+     changelog.d/15.bugfix.md  |  2 --
+     pyproject.toml            |  2 +-
+     uv.lock                   |  2 +-
+     6 files changed, 14 insertions(+), 6 deletions(-)
+    ");
+
+    // The tag already exists, nothing is modified.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("1.11.0")
+        .arg("--tag"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The tag `v1.11.0` already exists
+    ");
+
+    // Fragments need a known type.
+    fragments.child("16.feat.md").write_str("Add `--fastest`")?;
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--bump").arg("patch")
+        .arg("--changelog"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid changelog fragment name `changelog.d/16.feat.md`, expected `<id>.<type>.md` with one of the types: `feature`, `bugfix`, `doc`, `removal`, `misc`
+    ");
+
+    // `--tag` can't be used with `--dry-run`.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--bump").arg("patch")
+        .arg("--tag")
+        .arg("--dry-run"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--tag' cannot be used with '--dry-run'
+
+    Usage: uv version --cache-dir [CACHE_DIR] --tag --exclude-newer <EXCLUDE_NEWER> <VALUE|--bump <BUMP[=VALUE]>>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}